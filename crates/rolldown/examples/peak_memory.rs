@@ -0,0 +1,84 @@
+use std::{
+  alloc::{GlobalAlloc, Layout, System},
+  fmt::Write,
+  sync::atomic::{AtomicUsize, Ordering},
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, SourceMapType};
+
+/// Tracks the live and the peak number of heap bytes.
+struct CountingAllocator;
+
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+  unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+    let ptr = System.alloc(layout);
+    if !ptr.is_null() {
+      let live = LIVE.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+      PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+    ptr
+  }
+
+  unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+    System.dealloc(ptr, layout);
+    LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+  }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const MODULES: usize = 2000;
+const FUNCTIONS_PER_MODULE: usize = 40;
+
+/// Writes a project of `MODULES` modules, all imported by `main.js`, into a temporary directory.
+fn write_project() -> std::path::PathBuf {
+  let dir = std::env::temp_dir().join("rolldown-peak-memory");
+  if dir.is_dir() {
+    std::fs::remove_dir_all(&dir).unwrap();
+  }
+  std::fs::create_dir_all(&dir).unwrap();
+  let mut main = String::new();
+  for i in 0..MODULES {
+    let mut module = String::new();
+    for j in 0..FUNCTIONS_PER_MODULE {
+      writeln!(
+        module,
+        "export function fn_{i}_{j}(a, b) {{\n  const list = [a, b, {j}, 'module {i}', {{ key: a + b }}];\n  return list.map((item) => typeof item === 'object' ? item.key : item).join(',');\n}}"
+      )
+      .unwrap();
+    }
+    std::fs::write(dir.join(format!("module_{i}.js")), module).unwrap();
+    writeln!(main, "import * as m{i} from './module_{i}.js';\nconsole.log(m{i});").unwrap();
+  }
+  std::fs::write(dir.join("main.js"), main).unwrap();
+  dir
+}
+
+/// Prints the peak heap usage of bundling a project of many modules.
+#[allow(clippy::print_stdout, clippy::cast_precision_loss)]
+#[tokio::main]
+async fn main() {
+  let cwd = write_project();
+  let mut bundler = Bundler::new(BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    sourcemap: Some(SourceMapType::File),
+    ..Default::default()
+  });
+
+  let baseline = LIVE.load(Ordering::Relaxed);
+  PEAK.store(baseline, Ordering::Relaxed);
+  let result = bundler.write().await.unwrap();
+  assert!(result.errors.is_empty(), "failed to bundle: {:?}", result.errors);
+  let peak = PEAK.load(Ordering::Relaxed) - baseline;
+  println!("{MODULES} modules: peak heap {:.1} MiB", peak as f64 / 1024.0 / 1024.0);
+
+  std::fs::remove_dir_all(cwd).unwrap();
+}
@@ -4,7 +4,7 @@ use oxc::{
   ast::{ast::IdentifierReference, visit::walk, Visit},
   codegen::{self, Codegen, CodegenOptions, Gen},
 };
-use rolldown_common::ImportKind;
+use rolldown_common::{ImportKind, ImportRecordMeta, NodeGlobals};
 use rolldown_error::BuildError;
 
 use crate::utils::call_expression_ext::CallExpressionExt;
@@ -16,7 +16,7 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     for (idx, stmt) in program.body.iter().enumerate() {
       self.current_stmt_info.stmt_idx = Some(idx);
       self.current_stmt_info.side_effect =
-        SideEffectDetector::new(self.scopes, self.source, self.trivias)
+        SideEffectDetector::new(self.scopes, self.source, self.trivias, self.pure_functions)
           .detect_side_effect_of_stmt(stmt);
 
       if cfg!(debug_assertions) {
@@ -29,7 +29,13 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
         self.current_stmt_info.debug_label = Some(codegen.into_source_text());
       }
 
+      self.collect_top_level_require_spans(stmt);
       self.visit_statement(stmt);
+      let direct_references = std::mem::take(&mut self.current_stmt_direct_references);
+      self
+        .current_stmt_info
+        .member_only_references
+        .retain(|symbol_ref, _| !direct_references.contains(&symbol_ref.symbol));
       self.result.stmt_infos.add_stmt_info(std::mem::take(&mut self.current_stmt_info));
     }
   }
@@ -47,13 +53,22 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     match symbol_id {
       Some(symbol_id) if self.is_top_level(symbol_id) => {
         self.add_referenced_symbol(symbol_id);
+        self.current_stmt_direct_references.insert(symbol_id);
       }
       None => {
         if ident.name == "module" {
           self.used_module_ref = true;
+          self.commonjs_keyword.get_or_insert(ident.span);
         }
         if ident.name == "exports" {
           self.used_exports_ref = true;
+          self.commonjs_keyword.get_or_insert(ident.span);
+        }
+        if ident.name == "__dirname" {
+          self.result.node_globals.insert(NodeGlobals::DIRNAME);
+        }
+        if ident.name == "__filename" {
+          self.result.node_globals.insert(NodeGlobals::FILENAME);
         }
         if ident.name == "eval" {
           self.result.warnings.push(
@@ -66,6 +81,62 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     }
   }
 
+  fn visit_member_expression(&mut self, expr: &oxc::ast::ast::MemberExpression<'ast>) {
+    if !self.try_add_member_only_reference(expr) {
+      walk::walk_member_expression(self, expr);
+    }
+  }
+
+  fn visit_this_expression(&mut self, expr: &oxc::ast::ast::ThisExpression) {
+    if self.this_binding_depth == 0 {
+      self.result.top_level_this_spans.push(expr.span);
+    }
+  }
+
+  fn visit_function(
+    &mut self,
+    func: &oxc::ast::ast::Function<'ast>,
+    flags: Option<oxc::syntax::scope::ScopeFlags>,
+  ) {
+    self.this_binding_depth += 1;
+    self.function_depth += 1;
+    walk::walk_function(self, func, flags);
+    self.function_depth -= 1;
+    self.this_binding_depth -= 1;
+  }
+
+  fn visit_arrow_expression(&mut self, expr: &oxc::ast::ast::ArrowFunctionExpression<'ast>) {
+    self.function_depth += 1;
+    walk::walk_arrow_expression(self, expr);
+    self.function_depth -= 1;
+  }
+
+  fn visit_await_expression(&mut self, expr: &oxc::ast::ast::AwaitExpression<'ast>) {
+    if self.function_depth == 0 && self.result.top_level_await_span.is_none() {
+      self.result.top_level_await_span = Some(expr.span);
+    }
+    walk::walk_await_expression(self, expr);
+  }
+
+  fn visit_for_of_statement(&mut self, stmt: &oxc::ast::ast::ForOfStatement<'ast>) {
+    if stmt.r#await && self.function_depth == 0 && self.result.top_level_await_span.is_none() {
+      self.result.top_level_await_span = Some(stmt.span);
+    }
+    walk::walk_for_of_statement(self, stmt);
+  }
+
+  fn visit_meta_property(&mut self, meta: &oxc::ast::ast::MetaProperty<'ast>) {
+    if meta.meta.name == "import" && self.result.import_meta_span.is_none() {
+      self.result.import_meta_span = Some(meta.span);
+    }
+  }
+
+  fn visit_class_body(&mut self, body: &oxc::ast::ast::ClassBody<'ast>) {
+    self.this_binding_depth += 1;
+    walk::walk_class_body(self, body);
+    self.this_binding_depth -= 1;
+  }
+
   fn visit_statement(&mut self, stmt: &oxc::ast::ast::Statement<'ast>) {
     if let Some(decl) = stmt.as_module_declaration() {
       self.scan_module_decl(decl);
@@ -81,11 +152,43 @@ impl<'me, 'ast> Visit<'ast> for AstScanner<'me> {
     walk::walk_import_expression(self, expr);
   }
 
+  fn visit_new_expression(&mut self, expr: &oxc::ast::ast::NewExpression<'ast>) {
+    if let Some((url_span, request)) = self.extract_worker_request(expr) {
+      // The worker is bundled as a separate entry, like dynamic imports. The `new URL(...)` is
+      // rewritten to point at the chunk of the worker.
+      let id = self.add_import_record(request, ImportKind::DynamicImport);
+      self.result.imports.insert(url_span, id);
+    } else if let Some(request) = self.extract_new_url_request(expr) {
+      if !self.result.imports.contains_key(&expr.span) {
+        self.result.new_url_references.push((expr.span, request.into()));
+      }
+      // `import.meta.url` in the base argument is rewritten while rendering, so it's not visited.
+      self.visit_expression(&expr.callee);
+      self.visit_argument(&expr.arguments[0]);
+      return;
+    }
+    walk::walk_new_expression(self, expr);
+  }
+
   fn visit_call_expression(&mut self, expr: &oxc::ast::ast::CallExpression<'ast>) {
     if expr.is_global_require_call(self.scopes) {
-      if let Some(oxc::ast::ast::Argument::StringLiteral(request)) = &expr.arguments.first() {
-        let id = self.add_import_record(&request.value, ImportKind::Require);
-        self.result.imports.insert(expr.span, id);
+      match &expr.arguments.first() {
+        Some(oxc::ast::ast::Argument::StringLiteral(request)) => {
+          let id = self.add_import_record(&request.value, ImportKind::Require);
+          self.result.import_records[id]
+            .meta
+            .set(ImportRecordMeta::IS_TOP_LEVEL, self.top_level_require_spans.contains(&expr.span));
+          self.result.imports.insert(expr.span, id);
+        }
+        Some(request) if !self.dynamic_require_targets.is_empty() => {
+          match request.as_expression() {
+            Some(request) => self.add_dynamic_require_records(expr.span, request),
+            None => self.result.non_literal_requires.push(expr.span),
+          }
+        }
+        _ => {
+          self.result.non_literal_requires.push(expr.span);
+        }
       }
     }
 
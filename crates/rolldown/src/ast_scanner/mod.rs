@@ -4,25 +4,33 @@ pub mod side_effect_detector;
 use oxc::{
   ast::{
     ast::{
-      ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, IdentifierReference,
-      ImportDeclaration, ModuleDeclaration, Program,
+      Argument, ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, Expression,
+      IdentifierReference, ImportAttributeKey, ImportDeclaration, MemberExpression,
+      ModuleDeclaration, NewExpression, Program, Statement, WithClause,
     },
     Trivias, Visit,
   },
   semantic::SymbolId,
   span::{Atom, GetSpan, Span},
+  syntax::operator::BinaryOperator,
 };
 use oxc_index::IndexVec;
 use rolldown_common::{
-  AstScopes, ExportsKind, ImportKind, ImportRecordId, LocalExport, ModuleType, NamedImport,
-  NormalModuleId, RawImportRecord, ResourceId, Specifier, StmtInfo, StmtInfos, SymbolRef,
+  AstScopes, ExportsKind, ImportAttributes, ImportKind, ImportRecordId, ImportRecordMeta,
+  LocalExport, ModuleType, NamedImport, NodeGlobals, NormalModuleId, RawImportRecord, ResourceId,
+  Specifier, StmtInfo, StmtInfos, SymbolRef,
 };
 use rolldown_error::BuildError;
 use rolldown_oxc_utils::{BindingIdentifierExt, BindingPatternExt};
 use rolldown_rstr::{Rstr, ToRstr};
 use rolldown_utils::path_ext::PathExt;
-use rustc_hash::FxHashMap;
-use std::sync::Arc;
+
+use crate::utils::call_expression_ext::CallExpressionExt;
+use rustc_hash::{FxHashMap, FxHashSet};
+use std::{
+  path::{Path, PathBuf},
+  sync::Arc,
+};
 use sugar_path::SugarPath;
 
 use super::types::ast_symbols::AstSymbols;
@@ -39,6 +47,21 @@ pub struct ScanResult {
   pub imports: FxHashMap<Span, ImportRecordId>,
   pub exports_kind: ExportsKind,
   pub warnings: Vec<BuildError>,
+  pub errors: Vec<BuildError>,
+  /// Whether the globals `__dirname` and `__filename` are referenced.
+  pub node_globals: NodeGlobals,
+  /// `require(...)` calls with runtime-computed paths and import records of their possible targets.
+  pub dynamic_requires: FxHashMap<Span, Vec<ImportRecordId>>,
+  /// `require(...)` calls whose argument isn't a string literal and not handled by `dynamicRequireTargets`.
+  pub non_literal_requires: Vec<Span>,
+  /// `new URL('./foo.png', import.meta.url)` expressions and the relative paths they reference.
+  pub new_url_references: Vec<(Span, Rstr)>,
+  /// `this` expressions outside of functions and classes, which are `undefined` in ES modules.
+  pub top_level_this_spans: Vec<Span>,
+  /// The first `await` outside of functions, including `for await`.
+  pub top_level_await_span: Option<Span>,
+  /// The first `import.meta`, except the ones in `new URL('./foo.png', import.meta.url)`, which are rewritten.
+  pub import_meta_span: Option<Span>,
 }
 
 pub struct AstScanner<'me> {
@@ -48,15 +71,27 @@ pub struct AstScanner<'me> {
   file_path: &'me ResourceId,
   scopes: &'me AstScopes,
   trivias: &'me Trivias,
+  pure_functions: &'me [String],
+  dynamic_require_targets: &'me [PathBuf],
   symbols: &'me mut AstSymbols,
   current_stmt_info: StmtInfo,
   result: ScanResult,
   esm_export_keyword: Option<Span>,
   esm_import_keyword: Option<Span>,
+  /// The first reference to the global `module` or `exports`.
+  commonjs_keyword: Option<Span>,
   /// Represents [Module Namespace Object](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects)
   pub namespace_object_ref: SymbolRef,
   used_exports_ref: bool,
   used_module_ref: bool,
+  /// Spans of `require(...)` calls that are executed unconditionally in the current top-level statement.
+  top_level_require_spans: Vec<Span>,
+  /// Number of functions and class bodies the visitor is in, which bind their own `this`.
+  this_binding_depth: u32,
+  /// Number of functions, including arrow functions, the visitor is in.
+  function_depth: u32,
+  /// Top-level symbols the current statement references other than by accessing their members.
+  current_stmt_direct_references: FxHashSet<SymbolId>,
 }
 
 impl<'me> AstScanner<'me> {
@@ -70,6 +105,8 @@ impl<'me> AstScanner<'me> {
     source: &'me Arc<str>,
     file_path: &'me ResourceId,
     trivias: &'me Trivias,
+    pure_functions: &'me [String],
+    dynamic_require_targets: &'me [PathBuf],
   ) -> Self {
     // This is used for converting "export default foo;" => "var default_symbol = foo;"
     let symbol_id_for_default_export_ref =
@@ -95,6 +132,14 @@ impl<'me> AstScanner<'me> {
       imports: FxHashMap::default(),
       exports_kind: ExportsKind::None,
       warnings: Vec::new(),
+      errors: Vec::new(),
+      node_globals: NodeGlobals::empty(),
+      dynamic_requires: FxHashMap::default(),
+      non_literal_requires: Vec::new(),
+      new_url_references: Vec::new(),
+      top_level_this_spans: Vec::new(),
+      top_level_await_span: None,
+      import_meta_span: None,
     };
 
     Self {
@@ -105,13 +150,20 @@ impl<'me> AstScanner<'me> {
       result,
       esm_export_keyword: None,
       esm_import_keyword: None,
+      commonjs_keyword: None,
       module_type,
       namespace_object_ref,
       used_exports_ref: false,
       used_module_ref: false,
+      top_level_require_spans: Vec::new(),
+      this_binding_depth: 0,
+      function_depth: 0,
+      current_stmt_direct_references: FxHashSet::default(),
       source,
       file_path,
       trivias,
+      pure_functions,
+      dynamic_require_targets,
     }
   }
 
@@ -123,8 +175,10 @@ impl<'me> AstScanner<'me> {
       exports_kind = ExportsKind::Esm;
     } else if self.used_exports_ref || self.used_module_ref {
       exports_kind = ExportsKind::CommonJs;
+    } else if self.esm_import_keyword.is_some() {
+      // `import` statements make the module an ES module, whatever its format says.
+      exports_kind = ExportsKind::Esm;
     } else {
-      // TODO(hyf0): Should add warnings if the module type doesn't satisfy the exports kind.
       match self.module_type {
         ModuleType::CJS | ModuleType::CjsPackageJson => {
           exports_kind = ExportsKind::CommonJs;
@@ -132,18 +186,64 @@ impl<'me> AstScanner<'me> {
         ModuleType::EsmMjs | ModuleType::EsmPackageJson => {
           exports_kind = ExportsKind::Esm;
         }
-        ModuleType::Unknown => {
-          if self.esm_import_keyword.is_some() {
-            exports_kind = ExportsKind::Esm;
-          }
-        }
+        ModuleType::Unknown => {}
       }
     }
 
+    self.check_module_format(exports_kind);
     self.result.exports_kind = exports_kind;
+    if exports_kind == ExportsKind::Esm {
+      for span in &self.result.top_level_this_spans {
+        self.result.warnings.push(
+          BuildError::this_is_undefined(self.file_path.to_string(), Arc::clone(self.source), *span)
+            .with_severity_warning(),
+        );
+      }
+    } else {
+      // `this` is `module.exports` in CommonJS modules, and is left as it is.
+      self.result.top_level_this_spans.clear();
+    }
     self.result
   }
 
+  /// Like Node.js, `.mjs`/`.cjs` files and files under `package.json` with the `type` field have fixed formats. Using
+  /// the syntax of the other format is an error for the extensions, and a warning for `type`, which is often
+  /// mislabelled by packages that work with bundlers.
+  fn check_module_format(&mut self, exports_kind: ExportsKind) {
+    let (is_esm, reason, is_error) = match self.module_type {
+      ModuleType::EsmMjs => (true, "the \".mjs\" extension", true),
+      ModuleType::EsmPackageJson => (true, "`\"type\": \"module\"` in package.json", false),
+      ModuleType::CJS => (false, "the \".cjs\" extension", true),
+      ModuleType::CjsPackageJson => (false, "`\"type\": \"commonjs\"` in package.json", false),
+      ModuleType::Unknown => return,
+    };
+    let span = if is_esm {
+      // Checks like `typeof module` are fine in ES modules that have exports.
+      self.commonjs_keyword.filter(|_| exports_kind == ExportsKind::CommonJs)
+    } else {
+      self
+        .esm_import_keyword
+        .into_iter()
+        .chain(self.esm_export_keyword)
+        .min_by_key(|span| span.start)
+    };
+    let Some(span) = span else {
+      return;
+    };
+    let error = BuildError::module_format_mismatch(
+      self.file_path.to_string(),
+      Arc::clone(self.source),
+      span,
+      is_esm,
+      reason,
+    );
+    if is_error {
+      self.result.errors.push(error);
+    } else {
+      self.result.warnings.push(error.with_severity_warning());
+    }
+  }
+
   fn set_esm_export_keyword(&mut self, span: Span) {
     self.esm_export_keyword.get_or_insert(span);
   }
@@ -156,7 +256,7 @@ impl<'me> AstScanner<'me> {
     self.scopes.get_root_binding(name).expect("must have")
   }
 
-  fn add_import_record(&mut self, module_request: &Atom, kind: ImportKind) -> ImportRecordId {
+  fn add_import_record(&mut self, module_request: &str, kind: ImportKind) -> ImportRecordId {
     // If 'foo' in `import ... from 'foo'` is finally a commonjs module, we will convert the import statement
     // to `var import_foo = __toESM(require_foo())`, so we create a symbol for `import_foo` here. Notice that we
     // just create the symbol. If the symbol is finally used would be determined in the linking stage.
@@ -169,13 +269,89 @@ impl<'me> AstScanner<'me> {
       ),
     )
       .into();
-    let rec = RawImportRecord::new(module_request.to_rstr(), kind, namespace_ref);
+    let rec = RawImportRecord::new(module_request.into(), kind, namespace_ref);
 
     let id = self.result.import_records.push(rec);
     self.current_stmt_info.import_records.push(id);
     id
   }
 
+  /// Add import records for modules matched by `dynamicRequireTargets`, so they could be looked up by `require(...)`
+  /// calls with runtime-computed paths. Only modules under the directory that the leading string of the request points
+  /// to are added, such as `./locale/` of `require('./locale/' + name)`, or modules under the directory of the importer
+  /// for requests without a leading relative path, so unrelated targets of other packages aren't bundled into it.
+  fn add_dynamic_require_records(&mut self, span: Span, request: &Expression) {
+    let importer_path = self.file_path.as_path();
+    let importer_dir = importer_path.parent().unwrap_or(Path::new(""));
+    let scope_dir = match static_request_prefix(request) {
+      Some(prefix) if prefix.starts_with("./") || prefix.starts_with("../") => {
+        let prefix_dir = &prefix[..prefix.rfind('/').map_or(0, |idx| idx + 1)];
+        importer_dir.join(prefix_dir).normalize()
+      }
+      _ => importer_dir.to_path_buf(),
+    };
+    let requests = self
+      .dynamic_require_targets
+      .iter()
+      .filter(|target| target.as_path() != importer_path && target.starts_with(&scope_dir))
+      .map(|target| {
+        let request = target.relative(importer_dir).as_path().expect_to_slash();
+        if request.starts_with('.') {
+          request
+        } else {
+          format!("./{request}")
+        }
+      })
+      .collect::<Vec<_>>();
+    if requests.is_empty() {
+      self.result.non_literal_requires.push(span);
+      return;
+    }
+    let ids =
+      requests.iter().map(|request| self.add_import_record(request, ImportKind::Require)).collect();
+    self.result.dynamic_requires.insert(span, ids);
+  }
+
+  fn collect_import_attributes(with_clause: Option<&WithClause>) -> ImportAttributes {
+    with_clause.map_or_else(Vec::new, |with_clause| {
+      with_clause
+        .with_entries
+        .iter()
+        .map(|attr| {
+          let key = match &attr.key {
+            ImportAttributeKey::Identifier(ident) => ident.name.to_rstr(),
+            ImportAttributeKey::StringLiteral(lit) => lit.value.to_rstr(),
+          };
+          (key, attr.value.value.to_rstr())
+        })
+        .collect()
+    })
+  }
+
+  /// Collect `require(...)` calls in forms of `require('foo')` and `var foo = require('foo')` at the top level.
+  fn collect_top_level_require_spans(&mut self, stmt: &Statement) {
+    self.top_level_require_spans.clear();
+    match stmt {
+      Statement::ExpressionStatement(expr_stmt) => {
+        if let Expression::CallExpression(call_expr) = &expr_stmt.expression {
+          if call_expr.is_global_require_call(self.scopes) {
+            self.top_level_require_spans.push(call_expr.span);
+          }
+        }
+      }
+      Statement::VariableDeclaration(var_decl) => {
+        for declarator in &var_decl.declarations {
+          if let Some(Expression::CallExpression(call_expr)) = &declarator.init {
+            if call_expr.is_global_require_call(self.scopes) {
+              self.top_level_require_spans.push(call_expr.span);
+            }
+          }
+        }
+      }
+      _ => {}
+    }
+  }
+
   fn add_named_import(
     &mut self,
     local: SymbolId,
@@ -277,7 +453,7 @@ impl<'me> AstScanner<'me> {
       span_imported,
     };
     if name_import.imported.is_default() {
-      self.result.import_records[record_id].contains_import_default = true;
+      self.result.import_records[record_id].meta.insert(ImportRecordMeta::CONTAINS_IMPORT_DEFAULT);
     }
     self.result.named_imports.insert(generated_imported_as_ref, name_import);
     self
@@ -305,7 +481,7 @@ impl<'me> AstScanner<'me> {
       record_id,
     };
     self.result.named_imports.insert(generated_imported_as_ref, name_import);
-    self.result.import_records[record_id].contains_import_star = true;
+    self.result.import_records[record_id].meta.insert(ImportRecordMeta::CONTAINS_IMPORT_STAR);
     self
       .result
       .named_exports
@@ -314,6 +490,8 @@ impl<'me> AstScanner<'me> {
 
   fn scan_export_all_decl(&mut self, decl: &ExportAllDeclaration) {
     let id = self.add_import_record(&decl.source.value, ImportKind::Import);
+    self.result.import_records[id].attributes =
+      Self::collect_import_attributes(decl.with_clause.as_ref());
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
       self.add_star_re_export(exported.name(), id, decl.span);
@@ -327,12 +505,16 @@ impl<'me> AstScanner<'me> {
   fn scan_export_named_decl(&mut self, decl: &ExportNamedDeclaration) {
     if let Some(source) = &decl.source {
       let record_id = self.add_import_record(&source.value, ImportKind::Import);
+      self.result.import_records[record_id].attributes =
+        Self::collect_import_attributes(decl.with_clause.as_ref());
       decl.specifiers.iter().for_each(|spec| {
         self.add_re_export(spec.exported.name(), spec.local.name(), record_id, spec.local.span());
       });
       self.result.imports.insert(decl.span, record_id);
       // `export {} from '...'`
-      self.result.import_records[record_id].is_plain_import = decl.specifiers.is_empty();
+      self.result.import_records[record_id]
+        .meta
+        .set(ImportRecordMeta::IS_PLAIN_IMPORT, decl.specifiers.is_empty());
     } else {
       decl.specifiers.iter().for_each(|spec| {
         self.add_local_export(spec.exported.name(), self.get_root_binding(spec.local.name()));
@@ -364,6 +546,43 @@ impl<'me> AstScanner<'me> {
   }
 
   // If the reference is a global variable, `None` will be returned.
+  /// Match `new URL('./foo.png', import.meta.url)` and return the relative path it references.
+  fn extract_new_url_request<'a>(&self, expr: &'a NewExpression) -> Option<&'a str> {
+    let Expression::Identifier(callee) = &expr.callee else {
+      return None;
+    };
+    if callee.name != "URL" || self.resolve_symbol_from_reference(callee).is_some() {
+      return None;
+    }
+    let [Argument::StringLiteral(request), Argument::StaticMemberExpression(base)] =
+      expr.arguments.as_slice()
+    else {
+      return None;
+    };
+    let is_import_meta_url = base.property.name == "url"
+      && matches!(&base.object, Expression::MetaProperty(meta)
+        if meta.meta.name == "import" && meta.property.name == "meta");
+    let is_relative = request.value.starts_with("./") || request.value.starts_with("../");
+    (is_import_meta_url && is_relative).then_some(request.value.as_str())
+  }
+
+  /// `new Worker(new URL('./worker.js', import.meta.url))` to the span of `new URL(...)` and `./worker.js`.
+  /// `SharedWorker` is handled the same.
+  fn extract_worker_request<'a>(&self, expr: &'a NewExpression) -> Option<(Span, &'a str)> {
+    let Expression::Identifier(callee) = &expr.callee else {
+      return None;
+    };
+    if !matches!(callee.name.as_str(), "Worker" | "SharedWorker")
+      || self.resolve_symbol_from_reference(callee).is_some()
+    {
+      return None;
+    }
+    let Some(Argument::NewExpression(url_expr)) = expr.arguments.first() else {
+      return None;
+    };
+    self.extract_new_url_request(url_expr).map(|request| (url_expr.span, request))
+  }
+
   fn resolve_symbol_from_reference(&self, id_ref: &IdentifierReference) -> Option<SymbolId> {
     let ref_id = id_ref.reference_id.get().expect("must have reference id");
     self.scopes.symbol_id_for(ref_id)
@@ -393,10 +612,14 @@ impl<'me> AstScanner<'me> {
 
   fn scan_import_decl(&mut self, decl: &ImportDeclaration) {
     let rec_id = self.add_import_record(&decl.source.value, ImportKind::Import);
+    self.result.import_records[rec_id].attributes =
+      Self::collect_import_attributes(decl.with_clause.as_ref());
     self.result.imports.insert(decl.span, rec_id);
     // // `import '...'` or `import {} from '...'`
-    self.result.import_records[rec_id].is_plain_import =
-      decl.specifiers.as_ref().map_or(true, |s| s.is_empty());
+    self.result.import_records[rec_id].meta.set(
+      ImportRecordMeta::IS_PLAIN_IMPORT,
+      decl.specifiers.as_ref().map_or(true, |s| s.is_empty()),
+    );
 
     let Some(specifiers) = &decl.specifiers else { return };
     specifiers.iter().for_each(|spec| match spec {
@@ -405,16 +628,16 @@ impl<'me> AstScanner<'me> {
         let imported = spec.imported.name();
         self.add_named_import(sym, imported, rec_id, spec.imported.span());
         if imported == "default" {
-          self.result.import_records[rec_id].contains_import_default = true;
+          self.result.import_records[rec_id].meta.insert(ImportRecordMeta::CONTAINS_IMPORT_DEFAULT);
         }
       }
       oxc::ast::ast::ImportDeclarationSpecifier::ImportDefaultSpecifier(spec) => {
         self.add_named_import(spec.local.expect_symbol_id(), "default", rec_id, spec.span);
-        self.result.import_records[rec_id].contains_import_default = true;
+        self.result.import_records[rec_id].meta.insert(ImportRecordMeta::CONTAINS_IMPORT_DEFAULT);
       }
       oxc::ast::ast::ImportDeclarationSpecifier::ImportNamespaceSpecifier(spec) => {
         self.add_star_import(spec.local.expect_symbol_id(), rec_id, spec.span);
-        self.result.import_records[rec_id].contains_import_star = true;
+        self.result.import_records[rec_id].meta.insert(ImportRecordMeta::CONTAINS_IMPORT_STAR);
       }
     });
   }
@@ -445,6 +668,30 @@ impl<'me> AstScanner<'me> {
     self.current_stmt_info.referenced_symbols.push((self.idx, id).into());
   }
 
+  /// Record `Foo.Bar` or `Foo['Bar']` where `Foo` is imported, which is inlined if `Foo` is a `const enum`.
+  fn try_add_member_only_reference(&mut self, expr: &MemberExpression) -> bool {
+    let (Expression::Identifier(ident), Some(member)) =
+      (expr.object(), expr.static_property_name())
+    else {
+      return false;
+    };
+    let Some(symbol_id) = self.resolve_symbol_from_reference(ident) else {
+      return false;
+    };
+    let symbol_ref: SymbolRef = (self.idx, symbol_id).into();
+    if !self.result.named_imports.contains_key(&symbol_ref) {
+      return false;
+    }
+    self.add_referenced_symbol(symbol_id);
+    self
+      .current_stmt_info
+      .member_only_references
+      .entry(symbol_ref)
+      .or_default()
+      .push(member.into());
+    true
+  }
+
   fn is_top_level(&self, symbol_id: SymbolId) -> bool {
     self.scopes.root_scope_id() == self.symbols.scope_id_for(symbol_id)
   }
@@ -468,3 +715,19 @@ impl<'me> AstScanner<'me> {
     }
   }
 }
+
+/// The leading string of a request computed at runtime, such as `./locale/` of `'./locale/' + name` and
+/// `` `./locale/${name}` ``.
+fn static_request_prefix<'a>(expr: &'a Expression) -> Option<&'a str> {
+  match expr {
+    Expression::StringLiteral(literal) => Some(literal.value.as_str()),
+    Expression::TemplateLiteral(literal) => {
+      literal.quasis.first().and_then(|quasi| quasi.value.cooked.as_ref()).map(Atom::as_str)
+    }
+    Expression::BinaryExpression(expr) if expr.operator == BinaryOperator::Addition => {
+      static_request_prefix(&expr.left)
+    }
+    Expression::ParenthesizedExpression(expr) => static_request_prefix(&expr.expression),
+    _ => None,
+  }
+}
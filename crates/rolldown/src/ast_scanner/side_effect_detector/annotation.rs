@@ -1,5 +1,6 @@
 use once_cell::sync::Lazy;
-use oxc::span::Span;
+use oxc::{ast::ast::Expression, span::Span};
+use rolldown_common::AstScopes;
 
 use super::SideEffectDetector;
 
@@ -23,4 +24,25 @@ impl<'a> SideEffectDetector<'a> {
 
     leading_comment.map_or(false, |(_comment, comment_text)| PURE_COMMENTS.is_match(comment_text))
   }
+
+  /// Check if the callee matches one of the names specified by the `pure` option, such as `foo` or `console.log`. The
+  /// root of the callee must be a global, so local functions of the same names aren't matched.
+  pub fn is_pure_function_name(&self, callee: &Expression) -> bool {
+    fn matches_name(expr: &Expression, name: &str, scope: &AstScopes) -> bool {
+      match expr {
+        Expression::Identifier(ident) => {
+          ident.name == name && ident.reference_id.get().is_some_and(|id| scope.is_unresolved(id))
+        }
+        Expression::StaticMemberExpression(member_expr) => {
+          name.rsplit_once('.').is_some_and(|(object_name, prop_name)| {
+            member_expr.property.name == prop_name
+              && matches_name(&member_expr.object, object_name, scope)
+          })
+        }
+        _ => false,
+      }
+    }
+
+    self.pure_functions.iter().any(|name| matches_name(callee, name, self.scope))
+  }
 }
@@ -37,11 +37,18 @@ pub struct SideEffectDetector<'a> {
   pub scope: &'a AstScopes,
   pub source: &'a Arc<str>,
   pub trivias: &'a Trivias,
+  /// Calls to these functions are considered side effect free. See `BundlerOptions::pure`.
+  pub pure_functions: &'a [String],
 }
 
 impl<'a> SideEffectDetector<'a> {
-  pub fn new(scope: &'a AstScopes, source: &'a Arc<str>, trivias: &'a Trivias) -> Self {
-    Self { scope, source, trivias }
+  pub fn new(
+    scope: &'a AstScopes,
+    source: &'a Arc<str>,
+    trivias: &'a Trivias,
+    pure_functions: &'a [String],
+  ) -> Self {
+    Self { scope, source, trivias, pure_functions }
   }
 
   fn is_unresolved_reference(&mut self, ident_ref: &IdentifierReference) -> bool {
@@ -203,7 +210,8 @@ impl<'a> SideEffectDetector<'a> {
       | Expression::JSXElement(_)
       | Expression::JSXFragment(_) => true,
       Expression::CallExpression(expr) => {
-        let is_pure = self.is_pure_function_or_constructor_call(expr.span);
+        let is_pure = self.is_pure_function_or_constructor_call(expr.span)
+          || self.is_pure_function_name(&expr.callee);
         if is_pure {
           expr.arguments.iter().any(|arg| match arg {
             oxc::ast::ast::Argument::SpreadElement(_) => true,
@@ -363,6 +371,10 @@ mod test {
   use crate::ast_scanner::side_effect_detector::SideEffectDetector;
 
   fn get_statements_side_effect(code: &str) -> bool {
+    get_statements_side_effect_with_pure_functions(code, &[])
+  }
+
+  fn get_statements_side_effect_with_pure_functions(code: &str, pure_functions: &[String]) -> bool {
     let source_type = SourceType::default()
       .with_always_strict(true)
       .with_module(true)
@@ -380,7 +392,7 @@ mod test {
     };
 
     let has_side_effect = ast.program().body.iter().any(|stmt| {
-      SideEffectDetector::new(&ast_scope, ast.source(), &ast.trivias)
+      SideEffectDetector::new(&ast_scope, ast.source(), &ast.trivias, pure_functions)
         .detect_side_effect_of_stmt(stmt)
     });
 
@@ -613,4 +625,23 @@ mod test {
     assert!(get_statements_side_effect("const { url } = import.meta"));
     assert!(get_statements_side_effect("import.meta.url = 'test'"));
   }
+
+  #[test]
+  fn test_pure_functions() {
+    let pure_functions = vec!["foo".to_string(), "console.log".to_string()];
+    assert!(!get_statements_side_effect_with_pure_functions("foo()", &pure_functions));
+    assert!(!get_statements_side_effect_with_pure_functions("console.log()", &pure_functions));
+    assert!(get_statements_side_effect_with_pure_functions("bar()", &pure_functions));
+    assert!(get_statements_side_effect_with_pure_functions("console.warn()", &pure_functions));
+    assert!(get_statements_side_effect_with_pure_functions("foo.bar()", &pure_functions));
+    // Local functions of the same names aren't pure.
+    assert!(get_statements_side_effect_with_pure_functions(
+      "function foo() {} foo()",
+      &pure_functions
+    ));
+    assert!(get_statements_side_effect_with_pure_functions(
+      "const console = { log() {} }; console.log()",
+      &pure_functions
+    ));
+  }
 }
@@ -1,4 +1,4 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc, time::Instant};
 
 use super::stages::{
   link_stage::{LinkStage, LinkStageOutput},
@@ -6,22 +6,55 @@ use super::stages::{
 };
 use crate::{
   bundler_builder::BundlerBuilder,
+  module_loader::{
+    build_state::BuildState, bundle_cache::BundleCache, lazy_compilation::SharedLazyCompilation,
+  },
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
-  types::bundle_output::BundleOutput,
+  types::{bundle_output::BundleOutput, cancellation_token::CancellationToken},
+  utils::{
+    collapse_warnings::collapse_similar_warnings,
+    filename_conflict::filename_conflict_key,
+    fs_glob::glob_files,
+    hmr::render_hmr_update,
+    import_chains::shortest_import_chains,
+    module_query::split_query,
+    profile::{format_profile_summary, profile_to_chrome_trace},
+    size_report::{compute_chunk_sizes, format_size_report},
+    write_output::write_output_file,
+  },
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
-use rolldown_error::BuildError;
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_common::{
+  BundlerOptionsError, Log, LogLevel, LogLocation, ModuleInfo, Output, OutputAsset,
+};
+use rolldown_error::{BuildError, DiagnosticRenderer};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{BoxPlugin, HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver};
+use rolldown_utils::{path_ext::PathExt, rayon::ThreadPool};
+use rustc_hash::{FxHashMap, FxHashSet};
 use sugar_path::SugarPath;
 use tracing_chrome::FlushGuard;
 
 pub struct Bundler {
   pub(crate) options: SharedOptions,
   pub(crate) plugin_driver: SharedPluginDriver,
-  pub(crate) fs: OsFileSystem,
+  pub(crate) fs: SharedFileSystem,
+  /// Where chunks and assets are written, which is `fs` unless `BundlerBuilder::with_output_file_system` is used.
+  pub(crate) output_fs: SharedFileSystem,
   pub(crate) resolver: SharedResolver,
+  pub(crate) build_state: BuildState,
+  pub(crate) thread_pool: ThreadPool,
+  pub(crate) diagnostic_renderer: DiagnosticRenderer,
+  /// Problems found by `BundlerOptions::validate` when the bundler is built, which fail every build.
+  pub(crate) options_errors: Vec<BundlerOptionsError>,
+  /// Deprecated options found by `BundlerOptions::deprecated_options`, which are reported as warnings of every build.
+  pub(crate) deprecated_options: Vec<(&'static str, &'static str)>,
+  /// Modules of the last build, excluding the runtime module.
+  pub(crate) module_infos: Vec<Arc<ModuleInfo>>,
+  /// Ids of modules changed since the last build in watch mode, which are sent as an HMR update with `hmr`. They are
+  /// kept until a build renders the update, so changes before failed or cancelled builds aren't missed.
+  pub(crate) hmr_changed_modules: Vec<String>,
   pub(crate) _log_guard: Option<FlushGuard>,
 }
 
@@ -36,15 +69,60 @@ impl Bundler {
 }
 
 impl Bundler {
+  /// Modules requested by the app with `lazyCompilation`. The dev server calls `request(id)` with the id passed to
+  /// `__rolldown_lazy__.load(id)` in the browser.
+  pub fn lazy_compilation(&self) -> Option<SharedLazyCompilation> {
+    self.build_state.lazy_compilation.clone()
+  }
+
+  /// Cancel the build in progress with it, such as when files change during the build in watch mode.
+  pub fn cancellation_token(&self) -> CancellationToken {
+    self.build_state.cancellation_token.clone()
+  }
+
+  /// Renders errors and warnings of builds with paths relative to `cwd`, and colors if stderr supports them.
+  pub fn diagnostic_renderer(&self) -> &DiagnosticRenderer {
+    &self.diagnostic_renderer
+  }
+
+  /// Modules of the last build with their transformed code, which could be passed to `BundlerBuilder::with_cache` of
+  /// another bundler. Only exists with `BundlerBuilder::with_cache`.
+  pub fn cache(&self) -> Option<BundleCache> {
+    self.build_state.transform_cache.as_ref().map(|cache| cache.to_bundle_cache())
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn write(&mut self) -> Result<BundleOutput> {
     let dir = self.options.cwd.as_path().join(&self.options.dir).to_string_lossy().to_string();
 
     let mut output = self.bundle_up(true).await?;
+    self.emit_logs(&mut output.warnings, &mut output.errors).await;
+    if !output.errors.is_empty() {
+      return Ok(output);
+    }
 
+    let start = self.stage_start();
     self.plugin_driver.write_bundle(&mut output.assets).await?;
 
-    self.fs.create_dir_all(dir.as_path()).map_err(|err| {
+    let public_files = match &self.options.public_dir {
+      Some(public_dir) => collect_public_files(&self.fs, public_dir)?,
+      None => vec![],
+    };
+    // Files that only differ in case or separators are the same file on some file systems.
+    let public_files_by_key = public_files
+      .iter()
+      .map(|file| (filename_conflict_key(file), file))
+      .collect::<FxHashMap<_, _>>();
+    for asset in &output.assets {
+      if let Some(file) = public_files_by_key.get(&filename_conflict_key(asset.filename())) {
+        anyhow::bail!(
+          "{file:?} in the public directory conflicts with the emitted file {:?}",
+          asset.filename()
+        );
+      }
+    }
+
+    self.output_fs.create_dir_all(dir.as_path()).map_err(|err| {
       anyhow::anyhow!(
         "Could not create directory for output chunks: {:?} \ncwd: {}",
         dir.as_path(),
@@ -53,37 +131,72 @@ impl Bundler {
       .context(err)
     })?;
     for chunk in &output.assets {
-      let dest = dir.as_path().join(chunk.filename());
-      if let Some(p) = dest.parent() {
-        if !self.fs.exists(p) {
-          self.fs.create_dir_all(p).unwrap();
-        }
-      };
-      self.fs.write(dest.as_path(), chunk.content().as_bytes()).map_err(|err| {
-        anyhow::anyhow!("Failed to write file in {:?}", dir.as_path().join(chunk.filename()))
-          .context(err)
-      })?;
+      // Chunks are already written while generating them with `streamWrites`.
+      if self.options.stream_writes() && matches!(chunk, Output::Chunk(_)) {
+        continue;
+      }
+      write_output_file(
+        &self.output_fs,
+        dir.as_path(),
+        chunk.filename(),
+        chunk.content_as_bytes(),
+      )?;
+    }
+    if let Some(public_dir) = &self.options.public_dir {
+      for file in &public_files {
+        let content = self.fs.read(&public_dir.join(file)).map_err(|err| {
+          anyhow::anyhow!("Failed to read file in {:?}", public_dir.join(file)).context(err)
+        })?;
+        write_output_file(&self.output_fs, dir.as_path(), file, &content)?;
+      }
     }
+    self.record_stage("write", start);
+    let size_report = self.check_chunk_sizes(&mut output);
+    let profile_summary = self.report_profile()?;
+    self.emit_info_logs(size_report.into_iter().chain(profile_summary)).await?;
 
     Ok(output)
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn generate(&mut self) -> Result<BundleOutput> {
-    self.bundle_up(false).await
+    let mut output = self.bundle_up(false).await?;
+    self.emit_logs(&mut output.warnings, &mut output.errors).await;
+    let profile_summary = self.report_profile()?;
+    self.emit_info_logs(profile_summary).await?;
+    Ok(output)
   }
 
   pub async fn scan(&mut self) -> Result<ScanStageOutput> {
+    if !self.options_errors.is_empty() {
+      let messages = self.options_errors.iter().map(ToString::to_string).collect::<Vec<_>>();
+      anyhow::bail!("{}", messages.join("\n"));
+    }
+    self.build_state.cancellation_token.reset();
+    if let Some(cache) = &self.build_state.transform_cache {
+      cache.start_build();
+    }
     self.plugin_driver.build_start().await?;
 
-    let ret = ScanStage::new(
+    let start = self.stage_start();
+    let mut ret = ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
       self.fs.clone(),
       Arc::clone(&self.resolver),
+      self.build_state.clone(),
     )
     .scan()
     .await;
+    self.record_stage("scan", start);
+    if let Ok(ret) = &mut ret {
+      ret.warnings.extend(
+        self
+          .deprecated_options
+          .iter()
+          .map(|(option, replacement)| BuildError::deprecated_option(option, replacement)),
+      );
+    }
 
     {
       let args =
@@ -92,23 +205,149 @@ impl Bundler {
       self.plugin_driver.build_end(args.as_ref()).await?;
     }
 
+    if let Ok(ret) = &ret {
+      for module in ret.module_table.normal_modules.iter().filter(|module| !module.is_virtual()) {
+        let (path, _query) = split_query(&module.resource_id);
+        self.plugin_driver.add_module_watch_file(&module.resource_id, path);
+      }
+      // Modules removed from the module graph don't trigger rebuilds anymore. The module graph of a failed build might
+      // be incomplete, so the files of the last successful build are kept.
+      if ret.errors.is_empty() {
+        let module_ids = ret
+          .module_table
+          .normal_modules
+          .iter()
+          .map(|module| module.resource_id.as_str())
+          .collect::<FxHashSet<_>>();
+        self.plugin_driver.retain_watch_files(|module_id| module_ids.contains(module_id));
+      }
+    }
+
     ret
   }
 
+  /// Explain why the module with `id` is in the bundle, with the shortest chain of imports to it from each entry. Each
+  /// chain lists stable ids of modules from the entry to the module, and only goes through modules included in the
+  /// bundle. `id` could be relative to `cwd`.
+  pub async fn why(&mut self, id: &str) -> Result<Vec<Vec<String>>> {
+    let link_output = self.try_build().await?;
+    if let Some(error) = link_output.errors.first() {
+      anyhow::bail!("{error}");
+    }
+
+    let modules = &link_output.module_table.normal_modules;
+    let absolute_id = self.options.cwd.join(id).normalize();
+    let Some(target) = modules.iter().find(|module| {
+      module.resource_id.as_str() == id
+        || module.stable_resource_id == id
+        || module.resource_id.as_path() == absolute_id
+    }) else {
+      anyhow::bail!("Module {id:?} is not in the module graph");
+    };
+
+    let entries = link_output.entries.iter().map(|entry| entry.id).collect::<Vec<_>>();
+    let chains = shortest_import_chains(&entries, target.id, |id| {
+      modules[id]
+        .import_records
+        .iter()
+        .filter_map(|record| record.resolved_module.as_normal())
+        .filter(|imported| modules[*imported].is_included)
+        .collect()
+    });
+    Ok(
+      chains
+        .into_iter()
+        .map(|chain| chain.into_iter().map(|id| modules[id].stable_resource_id.clone()).collect())
+        .collect(),
+    )
+  }
+
+  /// Ids of modules in the module graph of the last build, in the order they were loaded.
+  pub fn module_ids(&self) -> Vec<String> {
+    self.module_infos.iter().map(|info| info.id.to_string()).collect()
+  }
+
+  /// Imports, importers and exports of the module with `id` in the module graph of the last build. `id` could be
+  /// relative to `cwd`.
+  pub fn module_info(&self, id: &str) -> Option<Arc<ModuleInfo>> {
+    let absolute_id = self.options.cwd.join(id).normalize();
+    self
+      .module_infos
+      .iter()
+      .find(|info| info.id.as_str() == id || info.id.as_path() == absolute_id)
+      .cloned()
+  }
+
   async fn try_build(&mut self) -> Result<LinkStageOutput> {
     let build_info = self.scan().await?;
+    self.build_state.cancellation_token.check()?;
 
+    let start = self.stage_start();
     let link_stage = LinkStage::new(build_info, &self.options);
-    Ok(link_stage.link())
+    let link_stage_output = self.thread_pool.install(|| link_stage.link());
+    self.record_stage("link", start);
+    self.build_state.cancellation_token.check()?;
+    if !link_stage_output.errors.is_empty() {
+      return Ok(link_stage_output);
+    }
+    self.module_infos = link_stage_output
+      .module_table
+      .normal_modules
+      .iter()
+      .filter(|module| module.id != link_stage_output.runtime.id())
+      .map(|module| {
+        let mut info = module.to_module_info();
+        info.exports.clone_from(
+          &link_stage_output.metas[module.id].sorted_and_non_ambiguous_resolved_exports,
+        );
+        Arc::new(info)
+      })
+      .collect();
+    Ok(link_stage_output)
   }
 
   async fn bundle_up(&mut self, is_write: bool) -> Result<BundleOutput> {
+    if let Some(profiler) = &self.build_state.profiler {
+      profiler.start_build();
+    }
     let mut link_stage_output = self.try_build().await?;
+    // Imports that failed to link have no symbols to render, so stop before generating chunks.
+    if !link_stage_output.errors.is_empty() {
+      return Ok(BundleOutput {
+        assets: vec![],
+        warnings: std::mem::take(&mut link_stage_output.warnings),
+        errors: std::mem::take(&mut link_stage_output.errors),
+        hmr_update: None,
+        streamed_chunk_sizes: vec![],
+      });
+    }
+
+    // Generating chunks releases ASTs, so the update is rendered from sources of them beforehand.
+    let hmr_update = if self.options.hmr() && !self.hmr_changed_modules.is_empty() {
+      let update = render_hmr_update(
+        &self.hmr_changed_modules,
+        &link_stage_output.module_table.normal_modules,
+        &link_stage_output.ast_table,
+      );
+      self.hmr_changed_modules.clear();
+      update
+    } else {
+      None
+    };
 
+    let start = self.stage_start();
     self.plugin_driver.render_start().await?;
 
-    let mut generate_stage =
-      GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver);
+    let mut generate_stage = GenerateStage::new(
+      &mut link_stage_output,
+      &self.options,
+      &self.plugin_driver,
+      &self.build_state.cancellation_token,
+      &self.thread_pool,
+      &self.fs,
+      &self.output_fs,
+      is_write,
+    );
 
     let mut output = {
       let ret = generate_stage.generate().await;
@@ -119,12 +358,148 @@ impl Bundler {
 
       ret?
     };
+    if let Some(update) = hmr_update {
+      output.assets.push(Output::Asset(Box::new(OutputAsset {
+        filename: update.filename.clone(),
+        source: update.code.clone().into(),
+      })));
+      output.hmr_update = Some(update);
+    }
 
     self.plugin_driver.generate_bundle(&mut output.assets, is_write).await?;
+    self.record_stage("render", start);
 
     Ok(output)
   }
 
+  /// Route warnings of the build and logs of plugins through `logLevel` and `onLog`. Logs passed to `onLog` are taken
+  /// out of `warnings`, and the ones it returns an error for are moved to `errors`. Without `onLog`, warnings stay in
+  /// `warnings` along with warnings of plugins. Warnings of the same code are collapsed with `collapseWarnings`.
+  /// The bundler isn't `Sync`, so it's borrowed mutably to keep the futures of `write` and `generate` `Send`.
+  pub async fn emit_logs(&mut self, warnings: &mut Vec<BuildError>, errors: &mut Vec<BuildError>) {
+    let log_level = self.options.log_level;
+    if !LogLevel::Warn.is_enabled_by(log_level) {
+      warnings.clear();
+    }
+    if self.options.collapse_warnings() {
+      *warnings = collapse_similar_warnings(std::mem::take(warnings));
+    }
+    let plugin_logs = self
+      .plugin_driver
+      .take_logs()
+      .into_iter()
+      .filter(|(level, _)| level.is_enabled_by(log_level))
+      .map(|(level, log)| {
+        let plugin = log.plugin.clone().unwrap_or_default();
+        (level, log.clone(), BuildError::plugin_log(plugin, log.message, level == LogLevel::Warn))
+      });
+
+    let Some(on_log) = &self.options.on_log else {
+      warnings.extend(plugin_logs.filter_map(|(level, _, warning)| {
+        (level == LogLevel::Warn).then(|| warning.with_severity_warning())
+      }));
+      return;
+    };
+
+    let opts = self.diagnostic_renderer.options();
+    let build_logs = std::mem::take(warnings).into_iter().map(|warning| {
+      let log = Log {
+        code: warning.kind().to_string(),
+        message: warning.message_with(opts),
+        frame: Some(self.diagnostic_renderer.render(&warning)),
+        ids: warning.ids_with(opts),
+        plugin: None,
+        loc: warning.location_with(opts).map(|loc| LogLocation {
+          file: loc.file,
+          line: loc.line,
+          column: loc.column,
+        }),
+        help: warning.help(),
+      };
+      (LogLevel::Warn, log, warning)
+    });
+    for (level, log, event) in build_logs.chain(plugin_logs) {
+      if on_log.call(level, log).await.is_err() {
+        errors.push(event.with_severity_error());
+      }
+    }
+  }
+
+  /// The start of a stage measured with `profile`. Time isn't read otherwise, since `Instant::now` panics on
+  /// `wasm32-unknown-unknown`.
+  fn stage_start(&self) -> Option<Instant> {
+    self.build_state.profiler.as_ref().map(|_| Instant::now())
+  }
+
+  fn record_stage(&self, name: &'static str, start: Option<Instant>) {
+    if let (Some(profiler), Some(start)) = (&self.build_state.profiler, start) {
+      profiler.record("stage", name, start);
+    }
+  }
+
+  /// Pass reports of the build, such as the ones of `profile` and `reportCompressedSize`, to `onLog` as info logs.
+  /// They are dropped without `onLog`, or if `logLevel` is above `info`.
+  async fn emit_info_logs(&mut self, logs: impl IntoIterator<Item = Log>) -> Result<()> {
+    let Some(on_log) = &self.options.on_log else {
+      return Ok(());
+    };
+    if !LogLevel::Info.is_enabled_by(self.options.log_level) {
+      return Ok(());
+    }
+    for log in logs {
+      on_log.call(LogLevel::Info, log).await?;
+    }
+    Ok(())
+  }
+
+  /// Summarize the times recorded with `profile` as a log, and write them to `profileTraceFile` if it's set.
+  fn report_profile(&self) -> Result<Option<Log>> {
+    let Some(profiler) = &self.build_state.profiler else {
+      return Ok(None);
+    };
+    let events = profiler.events();
+    if let Some(trace_file) = &self.options.profile_trace_file {
+      if let Some(parent) = trace_file.parent() {
+        self.fs.create_dir_all(parent)?;
+      }
+      self.fs.write(trace_file, profile_to_chrome_trace(&events).as_bytes()).map_err(|err| {
+        anyhow::anyhow!("Failed to write the profile to {:?}", trace_file).context(err)
+      })?;
+    }
+    Ok(Some(info_log("PROFILE_SUMMARY", format_profile_summary(&events))))
+  }
+
+  /// Reports sizes of chunks as a log with `reportCompressedSize`, and fails the build with chunks bigger than
+  /// `maxChunkSize`.
+  fn check_chunk_sizes(&self, output: &mut BundleOutput) -> Option<Log> {
+    let compress = self.options.report_compressed_size();
+    if !compress && self.options.max_chunk_size.is_none() {
+      return None;
+    }
+    // Code of chunks with `streamWrites` is dropped once written, so they're measured while being written.
+    let sizes = if self.options.stream_writes() {
+      std::mem::take(&mut output.streamed_chunk_sizes)
+    } else {
+      let chunks = output
+        .assets
+        .iter()
+        .filter(|output| matches!(output, Output::Chunk(_)))
+        .map(|chunk| (chunk.filename(), chunk.content_as_bytes()))
+        .collect::<Vec<_>>();
+      self.thread_pool.install(|| compute_chunk_sizes(&chunks, compress))
+    };
+
+    if let Some(max_chunk_size) = self.options.max_chunk_size {
+      output.errors.extend(
+        sizes
+          .iter()
+          .filter(|size| size.raw > max_chunk_size)
+          .map(|size| BuildError::chunk_too_large(size.filename.clone(), size.raw, max_chunk_size)),
+      );
+    }
+    compress.then(|| info_log("CHUNK_SIZES", format_size_report(&sizes)))
+  }
+
   fn normalize_error<T>(
     ret: &Result<T>,
     errors_fn: impl Fn(&T) -> &[BuildError],
@@ -136,6 +511,32 @@ impl Bundler {
   }
 }
 
+fn info_log(code: &str, message: String) -> Log {
+  Log {
+    code: code.to_string(),
+    message,
+    frame: None,
+    ids: None,
+    plugin: None,
+    loc: None,
+    help: None,
+  }
+}
+
+/// Returns paths of files in the public directory, relative to the directory and separated by `/`.
+fn collect_public_files(fs: &SharedFileSystem, public_dir: &Path) -> Result<Vec<String>> {
+  let pattern = format!(
+    "{}/**/*",
+    glob::Pattern::escape(&public_dir.to_string_lossy()).trim_end_matches(['/', '\\'])
+  );
+  let mut files = glob_files(fs, Path::new(&pattern))?
+    .into_iter()
+    .map(|path| path.relative(public_dir).expect_to_slash())
+    .collect::<Vec<_>>();
+  files.sort();
+  Ok(files)
+}
+
 fn _test_bundler() {
   #[allow(clippy::needless_pass_by_value)]
   fn _assert_send(_foo: impl Send) {}
@@ -146,3 +547,423 @@ fn _test_bundler() {
   let generate_fut = bundler.generate();
   _assert_send(generate_fut);
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use crate::test_utils::{memory_bundler, memory_fs};
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_invalid_options_fail_the_build() {
+    let builder = memory_bundler(
+      &memory_fs(&[]),
+      BundlerOptions { sourcemap_debug_ids: Some(true), ..Default::default() },
+    );
+    let Err(error) = builder.build().generate().await else {
+      panic!("The build should fail with invalid options");
+    };
+    assert_eq!(
+    error.to_string(),
+    "Invalid value for option \"sourcemapDebugIds\": Debug ids are written to sourcemaps, so \"sourcemap\" must be enabled as well."
+  );
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_stream_writes_rejects_output_hooks() {
+    #[derive(Debug)]
+    struct OutputHooks;
+
+    impl rolldown_plugin::Plugin for OutputHooks {
+      fn name(&self) -> std::borrow::Cow<'static, str> {
+        "output-hooks".into()
+      }
+
+      fn has_output_hooks(&self) -> bool {
+        true
+      }
+    }
+
+    let builder = memory_bundler(
+      &memory_fs(&[]),
+      BundlerOptions { stream_writes: Some(true), ..Default::default() },
+    );
+    let Err(error) = builder.with_plugins(vec![Box::new(OutputHooks)]).build().write().await else {
+      panic!("The build should fail with output hooks and `streamWrites`");
+    };
+    assert_eq!(
+    error.to_string(),
+    "Invalid value for option \"streamWrites\": Code of chunks is dropped once written, so it can't be used with plugins that have `generateBundle` or `writeBundle` hooks."
+  );
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_write_to_output_file_system() {
+    let input_fs = memory_fs(&[
+      ("main.js", "import './style.css';\nconsole.log('main');\n"),
+      ("style.css", "body { color: red; }\n"),
+    ]);
+    let builder = memory_bundler(
+      &input_fs,
+      BundlerOptions {
+        sourcemap: Some(rolldown_common::SourceMapType::File),
+        ..Default::default()
+      },
+    );
+    let output_fs = rolldown_fs::MemoryFileSystem::default();
+    let mut bundler = builder.with_output_file_system(output_fs.clone()).build();
+    let output = bundler.write().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+    let read = |path: &str| String::from_utf8(output_fs.read(Path::new(path)).unwrap()).unwrap();
+    let mut filenames =
+      output.assets.iter().map(|asset| asset.filename().to_string()).collect::<Vec<_>>();
+    filenames.sort();
+    let [css, js, map] = filenames.as_slice() else {
+      panic!("A stylesheet, a chunk and its sourcemap should be emitted: {filenames:?}");
+    };
+    assert!(Path::new(css).extension().is_some_and(|ext| ext == "css"), "{css}");
+    assert!(css.starts_with("assets/main-"), "{css}");
+    assert_eq!((js.as_str(), map.as_str()), ("main.js", "main.js.map"));
+    assert!(read("/project/dist/main.js").contains("console.log('main');"));
+    assert!(read("/project/dist/main.js").ends_with("//# sourceMappingURL=main.js.map"));
+    assert!(read("/project/dist/main.js.map").contains("\"sources\":[\"../main.js\"]"));
+    assert!(read(&format!("/project/dist/{css}")).contains("body { color: red; }"));
+    // Nothing is written to the file system that modules are read from.
+    assert!(!input_fs.is_dir(Path::new("/project/dist")));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_public_dir_conflicts() {
+    // A file that has the same name as an emitted chunk fails the build before anything is written.
+    let fs = memory_fs(&[
+      ("main.js", "console.log('main');\n"),
+      ("public/main.js", "console.log('public');\n"),
+    ]);
+    let builder = memory_bundler(
+      &fs,
+      BundlerOptions { public_dir: Some("public".to_string()), ..Default::default() },
+    );
+    let Err(err) = builder.build().write().await else {
+      panic!("The build should fail");
+    };
+    assert_eq!(
+      err.to_string(),
+      "\"main.js\" in the public directory conflicts with the emitted file \"main.js\""
+    );
+    assert!(!fs.exists(Path::new("/project/dist/main.js")));
+
+    // Names that only differ in case are the same file on case-insensitive file systems.
+    let fs = memory_fs(&[
+      ("main.js", "console.log('main');\n"),
+      ("public/Main.js", "console.log('public');\n"),
+    ]);
+    let builder = memory_bundler(
+      &fs,
+      BundlerOptions { public_dir: Some("public".to_string()), ..Default::default() },
+    );
+    let Err(err) = builder.build().write().await else {
+      panic!("The build should fail");
+    };
+    assert_eq!(
+      err.to_string(),
+      "\"Main.js\" in the public directory conflicts with the emitted file \"main.js\""
+    );
+    assert!(!fs.exists(Path::new("/project/dist/main.js")));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_sourcemap_path_transform() {
+    use std::sync::Mutex;
+
+    use rolldown_common::{SourceMapPathTransform, SourceMapType};
+
+    let calls = Arc::new(Mutex::new(vec![]));
+    let recorded_calls = Arc::clone(&calls);
+    let builder = memory_bundler(
+      &memory_fs(&[("src/main.js", "console.log('main');\n")]),
+      BundlerOptions {
+        input: Some(vec![rolldown_common::InputItem {
+          name: Some("main".to_string()),
+          import: "./src/main.js".to_string(),
+        }]),
+        entry_filenames: Some("js/[name].js".to_string()),
+        sourcemap: Some(SourceMapType::File),
+        sourcemap_path_transform: Some(SourceMapPathTransform::new(Box::new(
+          move |source, sourcemap_path| {
+            recorded_calls.lock().unwrap().push((source.to_string(), sourcemap_path.to_string()));
+            let source = format!("webpack://app/{source}");
+            Box::pin(async move { Ok(source) })
+          },
+        ))),
+        ..Default::default()
+      },
+    );
+    let output = builder.build().generate().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+    // Sources are relative to the `.map` file, which is given by its absolute path.
+    assert_eq!(
+      *calls.lock().unwrap(),
+      [("../../src/main.js".to_string(), "/project/dist/js/main.js.map".to_string())]
+    );
+    let Some(map) = output.assets.iter().find(|asset| asset.filename() == "js/main.js.map") else {
+      panic!("js/main.js.map should be emitted");
+    };
+    let map = std::str::from_utf8(map.content_as_bytes()).unwrap();
+    assert!(map.contains("\"sources\":[\"webpack://app/../../src/main.js\"]"), "{map}");
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_sourcemap_ignore_list() {
+    use rolldown_common::{SourceMapIgnoreList, SourceMapType};
+
+    // Sources in `node_modules` are ignored by default, which is covered by the `sourcemap/ignore_list` fixture.
+    let ignore_list = SourceMapIgnoreList::new(Box::new(|source, sourcemap_path| {
+      assert_eq!(sourcemap_path, "/project/dist/main.js.map");
+      let is_ignored = source.ends_with("util.js");
+      Box::pin(async move { Ok(is_ignored) })
+    }));
+    let builder = memory_bundler(
+      &memory_fs(&[
+        ("main.js", "import { util } from './util.js';\nconsole.log(util);\n"),
+        ("util.js", "export const util = 'util';\n"),
+      ]),
+      BundlerOptions {
+        sourcemap: Some(SourceMapType::File),
+        sourcemap_ignore_list: Some(ignore_list),
+        ..Default::default()
+      },
+    );
+    let output = builder.build().generate().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+    let Some(map) = output.assets.iter().find(|asset| asset.filename() == "main.js.map") else {
+      panic!("main.js.map should be emitted");
+    };
+    let map: serde_json::Value = serde_json::from_slice(map.content_as_bytes()).unwrap();
+    assert_eq!(map["sources"], serde_json::json!(["../util.js", "../main.js"]));
+    assert_eq!(map["x_google_ignoreList"], serde_json::json!([0]));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_cancel_build() {
+    use std::{borrow::Cow, sync::Mutex};
+
+    use rolldown_plugin::{HookLoadArgs, HookLoadReturn, Plugin, SharedPluginContext};
+
+    use crate::types::cancellation_token::BuildCancelled;
+
+    /// Records loaded ids, and cancels the build with the token while loading `dep.js`. The token is taken, so only
+    /// one build is cancelled.
+    #[derive(Debug)]
+    struct CancelOnLoad(Arc<Mutex<Option<CancellationToken>>>, Arc<Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl Plugin for CancelOnLoad {
+      fn name(&self) -> std::borrow::Cow<'static, str> {
+        Cow::Borrowed("cancel-on-load")
+      }
+
+      async fn load(&self, _ctx: &SharedPluginContext, args: &HookLoadArgs) -> HookLoadReturn {
+        if args.id.ends_with("dep.js") {
+          if let Some(token) = self.0.lock().unwrap().take() {
+            token.cancel();
+          }
+        }
+        self.1.lock().unwrap().push(args.id.to_string());
+        Ok(None)
+      }
+    }
+
+    let token = Arc::new(Mutex::new(None));
+    let loaded = Arc::new(Mutex::new(vec![]));
+    let builder = memory_bundler(
+      &memory_fs(&[
+        ("main.js", "import './dep.js';\n"),
+        ("dep.js", "import './leaf.js';\n"),
+        ("leaf.js", "console.log('leaf');\n"),
+      ]),
+      BundlerOptions::default(),
+    );
+    let mut bundler = builder
+      .with_plugins(vec![Box::new(CancelOnLoad(Arc::clone(&token), Arc::clone(&loaded)))])
+      .build();
+    *token.lock().unwrap() = Some(bundler.cancellation_token());
+
+    // Modules imported by modules loaded after cancelling aren't loaded.
+    let Err(err) = bundler.generate().await else {
+      panic!("The build should be cancelled");
+    };
+    assert!(err.is::<BuildCancelled>(), "{err}");
+    assert_eq!(*loaded.lock().unwrap(), ["/project/main.js", "/project/dep.js"]);
+
+    // Each build starts uncancelled.
+    loaded.lock().unwrap().clear();
+    let output = bundler.generate().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+    assert_eq!(loaded.lock().unwrap().len(), 3);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_module_graph() {
+    let builder = memory_bundler(&memory_fs(&[
+        (
+          "main.js",
+          "import { foo } from './foo.js';\nexport * from './bar.js';\nexport const main = foo;\nimport('./lazy.js');\n",
+        ),
+        ("foo.js", "export const foo = 'foo';\n"),
+        ("bar.js", "export const bar = 'bar';\n"),
+        ("lazy.js", "export default 'lazy';\n"),
+      ]), BundlerOptions::default(),);
+    let mut bundler = builder.build();
+
+    // There's no module graph before the first build.
+    assert!(bundler.module_ids().is_empty());
+    assert!(bundler.module_info("main.js").is_none());
+
+    let output = bundler.generate().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+    // The runtime module isn't included.
+    let mut ids = bundler.module_ids();
+    ids.sort();
+    assert_eq!(ids, ["/project/bar.js", "/project/foo.js", "/project/lazy.js", "/project/main.js"]);
+
+    let ids =
+      |ids: &[rolldown_common::ResourceId]| ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+    let main = bundler.module_info("main.js").unwrap();
+    assert!(main.is_entry);
+    assert_eq!(ids(&main.imported_ids), ["/project/foo.js", "/project/bar.js"]);
+    assert_eq!(ids(&main.dynamically_imported_ids), ["/project/lazy.js"]);
+    assert!(main.importers.is_empty());
+    // Names re-exported by `export *` are included.
+    assert_eq!(main.exports.iter().map(ToString::to_string).collect::<Vec<_>>(), ["bar", "main"]);
+
+    // Absolute ids work as well.
+    let dep = bundler.module_info("/project/foo.js").unwrap();
+    assert!(!dep.is_entry);
+    assert_eq!(ids(&dep.importers), ["/project/main.js"]);
+    let lazy = bundler.module_info("./lazy.js").unwrap();
+    assert_eq!(ids(&lazy.dynamic_importers), ["/project/main.js"]);
+    assert_eq!(lazy.exports.iter().map(ToString::to_string).collect::<Vec<_>>(), ["default"]);
+
+    assert!(bundler.module_info("missing.js").is_none());
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  #[allow(clippy::too_many_lines)]
+  async fn test_reproducible_across_checkout_paths() {
+    use std::borrow::Cow;
+
+    use rolldown_common::SourceMapType;
+    use rolldown_plugin::{
+      HookResolveIdArgs, HookResolveIdOutput, HookResolveIdReturn, Plugin, SharedPluginContext,
+    };
+
+    /// Resolves `vendor` to an absolute path in the project, which is kept external.
+    #[derive(Debug)]
+    struct Vendor(String);
+
+    #[async_trait::async_trait]
+    impl Plugin for Vendor {
+      fn name(&self) -> Cow<'static, str> {
+        "vendor".into()
+      }
+
+      async fn resolve_id(
+        &self,
+        _ctx: &SharedPluginContext,
+        args: &HookResolveIdArgs,
+      ) -> HookResolveIdReturn {
+        Ok((args.source == "vendor").then(|| HookResolveIdOutput {
+          id: format!("{}/vendor/index.js", self.0),
+          external: Some(true),
+          side_effects: None,
+        }))
+      }
+    }
+
+    /// Builds the same project checked out at `root`, and returns the emitted files sorted by filename.
+    async fn generate(root: &str, hash_salt: Option<&str>) -> Vec<(String, Vec<u8>)> {
+      let files = [
+        (
+          "src/main.js",
+          concat!(
+            "import vendor from 'vendor';\n",
+            "import legacy from './legacy.cjs';\n",
+            "import logo from './logo.svg?url';\n",
+            "import { dep } from './dep.js';\n",
+            "console.log(vendor, legacy, logo, dep, new URL('./logo.svg', import.meta.url));\n",
+            "import('./lazy.js').then(console.log);\n",
+          ),
+        ),
+        ("src/dep.js", "export const dep = 'dep';\n"),
+        ("src/lazy.js", "export const lazy = 'lazy';\n"),
+        ("src/legacy.cjs", "module.exports = __filename;\n"),
+        ("src/logo.svg", "<svg></svg>\n"),
+      ]
+      .map(|(path, code)| (format!("{root}/{path}"), code));
+      let builder = memory_bundler(
+        &memory_fs(&files.iter().map(|(path, code)| (path.as_str(), *code)).collect::<Vec<_>>()),
+        BundlerOptions {
+          input: Some(vec![rolldown_common::InputItem {
+            name: Some("main".to_string()),
+            import: "./src/main.js".to_string(),
+          }]),
+          cwd: Some(root.into()),
+          entry_filenames: Some("[name]-[hash].js".to_string()),
+          chunk_filenames: Some("[name]-[hash].js".to_string()),
+          sourcemap: Some(SourceMapType::File),
+          metafile: Some("meta.json".to_string()),
+          hash_salt: hash_salt.map(ToString::to_string),
+          ..Default::default()
+        },
+      );
+      let mut bundler = builder.with_plugins(vec![Box::new(Vendor(root.to_string()))]).build();
+      let output = bundler.generate().await.unwrap();
+      assert!(output.errors.is_empty(), "{:?}", output.errors);
+      let mut assets = output
+        .assets
+        .iter()
+        .map(|asset| (asset.filename().to_string(), asset.content_as_bytes().to_vec()))
+        .collect::<Vec<_>>();
+      assets.sort();
+      assets
+    }
+
+    let hashed_filenames = |assets: &[(String, Vec<u8>)]| {
+      assets
+        .iter()
+        .map(|(filename, _)| filename.clone())
+        .filter(|filename| filename.contains('-'))
+        .collect::<Vec<_>>()
+    };
+
+    for hash_salt in [None, Some("salt")] {
+      let assets = generate("/home/ci/project", hash_salt).await;
+      // Chunks, the asset, sourcemaps and the metafile are all emitted.
+      assert_eq!(hashed_filenames(&assets).len(), 5);
+      assert!(assets.iter().any(|(filename, _)| filename == "meta.json"));
+      for (filename, content) in &assets {
+        let content = String::from_utf8_lossy(content);
+        assert!(!content.contains("/home/ci"), "{filename} contains an absolute path:\n{content}");
+      }
+      let (_, main) = assets.iter().find(|(filename, _)| filename.starts_with("main-")).unwrap();
+      let main = String::from_utf8_lossy(main);
+      assert!(main.contains("from \"../vendor/index.js\""), "{main}");
+      assert!(main.contains("new URL('./assets/logo-"), "{main}");
+      // Filenames, hashes and contents don't depend on where the project is.
+      assert!(assets == generate("/tmp/checkout/other", hash_salt).await);
+    }
+
+    // The salt changes every hash, and only the hashes.
+    let unsalted = generate("/project", None).await;
+    let salted = generate("/project", Some("salt")).await;
+    let unsalted_filenames = hashed_filenames(&unsalted);
+    let salted_filenames = hashed_filenames(&salted);
+    assert_eq!(unsalted_filenames.len(), salted_filenames.len());
+    for (unsalted, salted) in unsalted_filenames.iter().zip(&salted_filenames) {
+      assert_ne!(unsalted, salted);
+      assert_eq!(unsalted.split('-').next(), salted.split('-').next());
+    }
+  }
+}
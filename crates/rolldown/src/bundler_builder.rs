@@ -1,10 +1,18 @@
 use std::sync::Arc;
 
-use rolldown_fs::OsFileSystem;
+use rolldown_common::{BundlerFlags, BundlerOptionsError};
+use rolldown_error::{DiagnosticOptions, DiagnosticRenderer};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use rolldown_plugin::{BoxPlugin, PluginDriver};
 use rolldown_resolver::Resolver;
+use rolldown_utils::rayon::ThreadPool;
 
 use crate::{
+  module_loader::{
+    build_state::BuildState,
+    bundle_cache::{BundleCache, TransformCache},
+    persistent_cache::PersistentCache,
+  },
   utils::normalize_options::{normalize_options, NormalizeOptionsReturn},
   Bundler, BundlerOptions, SharedResolver,
 };
@@ -13,22 +21,71 @@ use crate::{
 pub struct BundlerBuilder {
   input_options: BundlerOptions,
   plugins: Vec<BoxPlugin>,
+  cache: Option<BundleCache>,
+  fs: SharedFileSystem,
+  output_fs: Option<SharedFileSystem>,
+  test_mode: bool,
 }
 
 impl BundlerBuilder {
   pub fn build(self) -> Bundler {
     let maybe_guard = rolldown_tracing::try_init_tracing();
 
-    let NormalizeOptionsReturn { options, resolve_options } = normalize_options(self.input_options);
+    let mut options_errors = self.input_options.validate().err().unwrap_or_default();
+    // Code of chunks is dropped once written with `streamWrites`, so output hooks would only see empty chunks.
+    if self.input_options.stream_writes == Some(true)
+      && self.plugins.iter().any(|plugin| plugin.has_output_hooks())
+    {
+      options_errors.push(BundlerOptionsError::new(
+        "streamWrites",
+        "Code of chunks is dropped once written, so it can't be used with plugins that have `generateBundle` or `writeBundle` hooks.",
+      ));
+    }
+    let deprecated_options = self.input_options.deprecated_options();
+    let NormalizeOptionsReturn { mut options, resolve_options } =
+      normalize_options(self.input_options);
+    options.flags.set(BundlerFlags::TEST_MODE, self.test_mode);
 
+    let persistent_cache = options.cache_dir.clone().map(|cache_dir| {
+      let plugin_keys =
+        self.plugins.iter().map(|plugin| plugin.cache_key().into_owned()).collect::<Vec<_>>();
+      Arc::new(PersistentCache::new(
+        cache_dir,
+        &plugin_keys,
+        &resolve_options,
+        options.platform,
+        options.sourcemap.is_some(),
+        self.fs.clone(),
+      ))
+    });
     let resolver: SharedResolver =
-      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), self.fs.clone()).into();
+
+    let lazy_compilation = options.lazy_compilation().then(Default::default);
+
+    let profiler = options.profile().then(Default::default);
+    let diagnostic_renderer =
+      DiagnosticRenderer::new(DiagnosticOptions { cwd: options.cwd.clone() });
 
     Bundler {
-      plugin_driver: PluginDriver::new_shared(self.plugins, &resolver),
+      thread_pool: ThreadPool::new(options.threads),
+      plugin_driver: PluginDriver::new_shared(self.plugins, &resolver, profiler.clone()),
       resolver,
       options: Arc::new(options),
-      fs: OsFileSystem,
+      output_fs: self.output_fs.unwrap_or_else(|| self.fs.clone()),
+      fs: self.fs,
+      build_state: BuildState {
+        lazy_compilation,
+        persistent_cache,
+        transform_cache: self.cache.map(|cache| Arc::new(TransformCache::new(cache))),
+        profiler,
+        ..Default::default()
+      },
+      diagnostic_renderer,
+      options_errors,
+      deprecated_options,
+      module_infos: vec![],
+      hmr_changed_modules: vec![],
       _log_guard: maybe_guard,
     }
   }
@@ -39,9 +96,43 @@ impl BundlerBuilder {
     self
   }
 
+  /// Always emit the runtime module as a chunk named `$runtime$`, so snapshots of fixtures don't depend on which chunk
+  /// it would be merged into. Only meant for tests of the bundler.
+  #[doc(hidden)]
+  #[must_use]
+  pub fn with_test_mode(mut self, test_mode: bool) -> Self {
+    self.test_mode = test_mode;
+    self
+  }
+
   #[must_use]
   pub fn with_plugins(mut self, plugins: Vec<BoxPlugin>) -> Self {
     self.plugins = plugins;
     self
   }
+
+  /// Reuse results of the `transform` hook from `Bundler::cache` of a previous bundler. Pass `BundleCache::default()`
+  /// to collect the cache without one.
+  #[must_use]
+  pub fn with_cache(mut self, cache: BundleCache) -> Self {
+    self.cache = Some(cache);
+    self
+  }
+
+  /// Read modules and write the output through `fs` instead of the disk, such as `MemoryFileSystem` or one provided by
+  /// the host when running in browsers. Paths passed to it are absolute paths under `cwd`, which should be set without a
+  /// current directory. Glob patterns, such as the ones of `input` and `import.meta.glob`, are matched through it too.
+  #[must_use]
+  pub fn with_file_system(mut self, fs: impl FileSystem + 'static) -> Self {
+    self.fs = SharedFileSystem::new(fs);
+    self
+  }
+
+  /// Write chunks and assets through `fs` instead of the file system modules are read from, such as a cloned
+  /// `MemoryFileSystem` to capture the output in tests and dev servers without writing to disk.
+  #[must_use]
+  pub fn with_output_file_system(mut self, fs: impl FileSystem + 'static) -> Self {
+    self.output_fs = Some(SharedFileSystem::new(fs));
+    self
+  }
 }
@@ -6,22 +6,36 @@ mod module_finalizers;
 mod module_loader;
 mod runtime;
 mod stages;
+#[cfg(test)]
+mod test_utils;
 mod type_alias;
 mod types;
 mod utils;
+mod watcher;
 
 use std::sync::Arc;
 
-use rolldown_fs::OsFileSystem;
 use rolldown_resolver::Resolver;
 
-pub(crate) type SharedResolver = Arc<Resolver<OsFileSystem>>;
+pub(crate) type SharedResolver = Arc<Resolver>;
 pub(crate) type SharedOptions = Arc<NormalizedBundlerOptions>;
 
 pub use crate::{
-  bundler::Bundler, bundler_builder::BundlerBuilder, types::bundle_output::BundleOutput,
+  bundler::Bundler,
+  bundler_builder::BundlerBuilder,
+  module_loader::{
+    bundle_cache::{BundleCache, CachedModule},
+    lazy_compilation::LazyCompilation,
+  },
+  types::{
+    bundle_output::BundleOutput,
+    cancellation_token::{BuildCancelled, CancellationToken},
+  },
+  utils::hmr::HmrUpdate,
+  watcher::{watch_with_config, LoadedConfig, WatcherEvent},
 };
 
 pub use rolldown_common::bundler_options::*;
 
+pub use rolldown_fs::{FileSystem, MemoryFileSystem, OsFileSystem, SharedFileSystem};
 pub use rolldown_resolver::ResolveOptions;
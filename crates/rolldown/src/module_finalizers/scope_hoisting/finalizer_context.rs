@@ -24,6 +24,7 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub linking_infos: &'me LinkingMetadataVec,
   pub symbols: &'me Symbols,
   pub canonical_names: &'me FxHashMap<SymbolRef, Rstr>,
+  pub live_imports_from_other_chunks: &'me FxHashMap<SymbolRef, (SymbolRef, Rstr)>,
   pub runtime: &'me RuntimeModuleBrief,
   pub chunk_graph: &'me ChunkGraph,
   pub options: &'me SharedOptions,
@@ -9,8 +9,11 @@ use oxc::{
   },
   span::{GetSpan, Span, SPAN},
 };
-use rolldown_common::{ExportsKind, ModuleId, SymbolRef, WrapKind};
+use rolldown_common::{
+  ExportsKind, ImportRecordMeta, ModuleId, OutputFormat, SymbolRef, UnresolvedRequire, WrapKind,
+};
 use rolldown_oxc_utils::{ExpressionExt, IntoIn, StatementExt, TakeIn};
+use sugar_path::SugarPath;
 
 use crate::utils::call_expression_ext::CallExpressionExt;
 
@@ -110,17 +113,18 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
                   ExportsKind::None => {}
                 }
               }
-              ModuleId::External(importee_id) => {
-                let importee = &self.ctx.external_modules[importee_id];
-                // Insert `import * as ns from 'ext'`
+              // ES modules re-export names of external modules statically, unless the namespace is used
+              ModuleId::External(_)
+                if matches!(self.ctx.options.format, OutputFormat::Esm)
+                  && !is_namespace_referenced => {}
+              ModuleId::External(_) => {
+                // `import * as ns from 'ext'` is rendered along with other imports of the chunk, so it's valid in wrapped
+                // modules and CommonJS output too.
                 // Insert `__reExport(exports, ns)`
                 let re_export_fn_name = self.canonical_name_for_runtime("__reExport");
                 let importer_namespace_name =
                   self.canonical_name_for(self.ctx.module.namespace_object_ref);
                 let importee_namespace_name = self.canonical_name_for(rec.namespace_ref);
-                program
-                  .body
-                  .push(self.snippet.import_star_stmt(&importee.name, importee_namespace_name));
                 program.body.push(
                   self
                     .snippet
@@ -215,6 +219,10 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
     walk_mut::walk_program_mut(self, program);
 
+    if self.ctx.options.hmr() && self.ctx.id != self.ctx.runtime.id() {
+      program.body.push(self.generate_hmr_registration_stmt());
+    }
+
     // check if we need to add wrapper
     let needs_wrapper = self
       .ctx
@@ -338,39 +346,85 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
   fn visit_expression(&mut self, expr: &mut ast::Expression<'ast>) {
     if let Some(call_expr) = expr.as_call_expression_mut() {
       if call_expr.is_global_require_call(self.scope) {
-        let rec_id = self.ctx.module.imports[&call_expr.span];
-        let rec = &self.ctx.module.import_records[rec_id];
-        match rec.resolved_module {
-          // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports))`
-          ModuleId::Normal(importee_id) => {
-            let importee = &self.ctx.modules[importee_id];
-            let importee_linking_info = &self.ctx.linking_infos[importee.id];
-            let wrap_ref_name = self.canonical_name_for(importee_linking_info.wrapper_ref.unwrap());
-            if matches!(importee.exports_kind, ExportsKind::CommonJs) {
-              *expr = self.snippet.call_expr_expr(wrap_ref_name);
-            } else {
-              let ns_name = self.canonical_name_for(importee.namespace_object_ref);
-              let to_commonjs_ref_name = self.canonical_name_for_runtime("__toCommonJS");
-              *expr = self.snippet.seq2_in_paren_expr(
-                self.snippet.call_expr_expr(wrap_ref_name),
-                self.snippet.call_expr_with_arg_expr(to_commonjs_ref_name, ns_name),
+        let module = self.ctx.module;
+        if let Some(rec_ids) = module.dynamic_requires.get(&call_expr.span) {
+          // Rewrite `require(path)` to `__dynamicRequire({ './foo.js': () => require_foo() }, path)`
+          let args = call_expr.arguments.take_in(self.alloc);
+          *expr = self.generate_dynamic_require_expr(rec_ids, args);
+        } else if let Some(rec_id) = module.imports.get(&call_expr.span) {
+          let rec = &module.import_records[*rec_id];
+          match rec.resolved_module {
+            // Rewrite `require(...)` to `require_xxx(...)` or `(init_xxx(), __toCommonJS(xxx_exports))`
+            ModuleId::Normal(importee_id) => {
+              *expr = self.generate_finalized_require_expr(importee_id);
+            }
+            ModuleId::External(_)
+              if rec.meta.contains(ImportRecordMeta::IS_UNRESOLVED)
+                && matches!(self.ctx.options.unresolved_require, Some(UnresolvedRequire::Stub)) =>
+            {
+              // Rewrite `require('missing')` to `(() => { throw new Error(...) })()`
+              *expr = self
+                .snippet
+                .throw_error_iife_expr(&format!("Could not resolve \"{}\"", rec.module_request));
+            }
+            ModuleId::External(importee_id) => {
+              let importee = &self.ctx.external_modules[importee_id];
+              let importee_name = self.importer_chunk().external_import_path_for(&importee.name);
+              let request_path =
+                call_expr.arguments.get_mut(0).expect("require should have an argument");
+
+              // Rewrite `require('xxx')` to `require('fs')`, if there is an alias that maps 'xxx' to 'fs'
+              *request_path = ast::Argument::StringLiteral(
+                self
+                  .snippet
+                  .string_literal(&importee_name, request_path.span())
+                  .into_in(self.alloc),
               );
             }
           }
-          ModuleId::External(importee_id) => {
-            let importee = &self.ctx.external_modules[importee_id];
-            let request_path =
-              call_expr.arguments.get_mut(0).expect("require should have an argument");
-
-            // Rewrite `require('xxx')` to `require('fs')`, if there is an alias that maps 'xxx' to 'fs'
-            *request_path = ast::Argument::StringLiteral(
-              self.snippet.string_literal(&importee.name, request_path.span()).into_in(self.alloc),
-            );
-          }
+        } else if matches!(self.ctx.options.unresolved_require, Some(UnresolvedRequire::Stub)) {
+          // `require(...)` with a non-literal argument couldn't be bundled
+          *expr = self.snippet.throw_error_iife_expr("Could not resolve dynamic require");
         }
       }
     }
 
+    if let Some(new_expr) = self.try_rewrite_import_meta_hot(expr) {
+      *expr = new_expr;
+      return;
+    }
+
+    // Rewrite `import('./proxy.js')` to `import('./proxy.js').then((m) => m.default)`
+    if self.is_lazy_proxy_import(expr) {
+      walk_mut::walk_expression_mut(self, expr);
+      let import_expr = expr.take_in(self.alloc);
+      *expr = self.snippet.then_default_expr(import_expr);
+      return;
+    }
+
+    // Rewrite top-level `this` of ES modules to `void 0`
+    if let ast::Expression::ThisExpression(this_expr) = expr {
+      if self.ctx.module.top_level_this_spans.contains(&this_expr.span) {
+        *expr = self.snippet.void_zero();
+        return;
+      }
+    }
+
+    if self.try_rewrite_new_url_expr(expr) {
+      return;
+    }
+    self.try_rewrite_asset_filename_literal(expr);
+
+    if let Some(new_expr) = self.try_shim_dirname_or_filename(expr) {
+      *expr = new_expr;
+      return;
+    }
+
+    if let Some(new_expr) = self.try_inline_const_enum_member(expr) {
+      *expr = new_expr;
+      return;
+    }
+
     self.try_rewrite_identifier_reference_expr(expr, false);
 
     walk_mut::walk_expression_mut(self, expr);
@@ -448,9 +502,7 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
         let importee_id = rec.resolved_module;
         match importee_id {
           ModuleId::Normal(importee_id) => {
-            let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.id]
-              .expect("Normal module should belong to a chunk");
-            let importer_chunk = &self.ctx.chunk_graph.chunks[importer_chunk_id];
+            let importer_chunk = self.importer_chunk();
 
             let importee_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
             let importee_chunk = &self.ctx.chunk_graph.chunks[importee_chunk_id];
@@ -459,8 +511,13 @@ impl<'me, 'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'me, 'ast> {
 
             str.value = self.snippet.atom(&import_path);
           }
-          ModuleId::External(_) => {
-            // external module doesn't belong to any chunk, just keep this as it is
+          ModuleId::External(importee_id) => {
+            // external module doesn't belong to any chunk, just keep this as it is, unless it's an absolute path
+            let importee = &self.ctx.external_modules[importee_id];
+            if importee.name.as_path().is_absolute() {
+              let import_path = self.importer_chunk().external_import_path_for(&importee.name);
+              str.value = self.snippet.atom(&import_path);
+            }
           }
         }
       }
@@ -1,16 +1,28 @@
 use oxc::{
-  allocator::Allocator,
+  allocator::{self, Allocator},
   ast::ast::{self, IdentifierReference, Statement},
-  span::{Atom, SPAN},
+  span::{Atom, GetSpan, SPAN},
+};
+use rolldown_common::{
+  AstScopes, Chunk, ExportsKind, ImportRecordId, ModuleId, NormalModuleId, OutputFormat, Platform,
+  RequireReturnsDefault, SymbolRef, WrapKind,
 };
-use rolldown_common::{AstScopes, ImportRecordId, ModuleId, SymbolRef, WrapKind};
 use rolldown_oxc_utils::{AstSnippet, BindingPatternExt, IntoIn, TakeIn};
 
 mod finalizer_context;
 mod impl_visit_mut;
 pub use finalizer_context::ScopeHoistingFinalizerContext;
 use rolldown_rstr::Rstr;
-use rolldown_utils::ecma_script::is_validate_identifier_name;
+use rolldown_utils::{ecma_script::is_validate_identifier_name, path_buf_ext::PathBufExt};
+use sugar_path::SugarPath;
+
+use crate::utils::{
+  hmr::HMR_RUNTIME_NAME,
+  lazy_compilation::lazy_proxy_target,
+  module_query::split_query,
+  strip_typescript::const_enum_value_expr,
+  worker::{inline_worker_placeholder, parse_worker_query},
+};
 mod rename;
 
 /// Finalizer for emitting output code with scope hoisting.
@@ -35,11 +47,310 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     self.ctx.symbols.canonical_name_for(symbol, self.ctx.canonical_names)
   }
 
+  /// The namespace and the property to access the symbol through, if it isn't a plain variable in the chunk. E.g.
+  /// named imports from CommonJS modules, and live imports from other chunks in CommonJS output.
+  fn namespace_alias_for(&self, canonical_ref: SymbolRef) -> Option<(&'me Rstr, &'me Rstr)> {
+    if let Some((namespace_ref, property_name)) =
+      self.ctx.live_imports_from_other_chunks.get(&canonical_ref)
+    {
+      return Some((self.canonical_name_for(*namespace_ref), property_name));
+    }
+    let ns_alias = self.ctx.symbols.get(canonical_ref).namespace_alias.as_ref()?;
+    Some((self.canonical_name_for(ns_alias.namespace_ref), &ns_alias.property_name))
+  }
+
   pub fn canonical_name_for_runtime(&self, name: &str) -> &Rstr {
     let symbol = self.ctx.runtime.resolve_symbol(name);
     self.canonical_name_for(symbol)
   }
 
+  /// The chunk that the module being finalized belongs to.
+  fn importer_chunk(&self) -> &'me Chunk {
+    let chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.id]
+      .expect("Included module should belong to a chunk");
+    &self.ctx.chunk_graph.chunks[chunk_id]
+  }
+
+  /// Generate the expression that `require(...)` of a bundled module is rewritten to, such as `require_foo()`,
+  /// `(init_foo(), __toCommonJS(foo_exports))` or `__toCommonJS(foo_exports)` for hoisted ES modules.
+  fn generate_finalized_require_expr(&self, importee_id: NormalModuleId) -> ast::Expression<'ast> {
+    let importee = &self.ctx.modules[importee_id];
+    let importee_linking_info = &self.ctx.linking_infos[importee_id];
+    let Some(wrapper_ref) = importee_linking_info.wrapper_ref else {
+      return self.generate_required_esm_value_expr(importee_id);
+    };
+    let wrap_ref_name = self.canonical_name_for(wrapper_ref);
+    if matches!(importee.exports_kind, ExportsKind::CommonJs) {
+      self.snippet.call_expr_expr(wrap_ref_name)
+    } else {
+      self.snippet.seq2_in_paren_expr(
+        self.snippet.call_expr_expr(wrap_ref_name),
+        self.generate_required_esm_value_expr(importee_id),
+      )
+    }
+  }
+
+  /// Generate `__dynamicRequire({ './foo.js': () => require_foo(), './foo': () => require_foo() }, ...args)`.
+  fn generate_dynamic_require_expr(
+    &self,
+    rec_ids: &[ImportRecordId],
+    args: allocator::Vec<'ast, ast::Argument<'ast>>,
+  ) -> ast::Expression<'ast> {
+    let mut map_obj_expr = ast::ObjectExpression::dummy(self.alloc);
+    for rec_id in rec_ids {
+      let rec = &self.ctx.module.import_records[*rec_id];
+      let ModuleId::Normal(importee_id) = rec.resolved_module else {
+        continue;
+      };
+      let request = rec.module_request.as_str();
+      // Allow requiring the module without the extension, such as `require('./locale/' + 'de')`.
+      let request_without_ext = request
+        .rfind('.')
+        .filter(|idx| *idx > request.rfind('/').unwrap_or(0))
+        .map(|idx| &request[..idx]);
+      for key in std::iter::once(request).chain(request_without_ext) {
+        map_obj_expr.properties.push(ast::ObjectPropertyKind::ObjectProperty(
+          ast::ObjectProperty {
+            key: ast::PropertyKey::StringLiteral(
+              self.snippet.string_literal(key, SPAN).into_in(self.alloc),
+            ),
+            value: self
+              .snippet
+              .only_return_arrow_expr(self.generate_finalized_require_expr(importee_id)),
+            ..TakeIn::dummy(self.alloc)
+          }
+          .into_in(self.alloc),
+        ));
+      }
+    }
+
+    let mut call_expr = self.snippet.call_expr(self.canonical_name_for_runtime("__dynamicRequire"));
+    call_expr.arguments.push(ast::Argument::ObjectExpression(map_obj_expr.into_in(self.alloc)));
+    for arg in args {
+      call_expr.arguments.push(arg);
+    }
+    ast::Expression::CallExpression(call_expr.into_in(self.alloc))
+  }
+
+  /// Generate the value of `require('esm-module')` according to the `requireReturnsDefault` option,
+  /// which is either `__toCommonJS(xxx_exports)` or `xxx_exports.default`.
+  fn generate_required_esm_value_expr(&self, importee_id: NormalModuleId) -> ast::Expression<'ast> {
+    let importee = &self.ctx.modules[importee_id];
+    let resolved_exports = &self.ctx.linking_infos[importee_id].resolved_exports;
+    let has_default_export = resolved_exports.contains_key(&Rstr::new("default"));
+    let returns_default = match self.ctx.options.require_returns_default {
+      RequireReturnsDefault::False => false,
+      RequireReturnsDefault::True => true,
+      RequireReturnsDefault::Auto => has_default_export && resolved_exports.len() == 1,
+      RequireReturnsDefault::Preferred => has_default_export,
+    };
+    let ns_name = self.canonical_name_for(importee.namespace_object_ref);
+    if returns_default {
+      self.snippet.literal_prop_access_member_expr_expr(ns_name, "default")
+    } else {
+      let to_commonjs_ref_name = self.canonical_name_for_runtime("__toCommonJS");
+      self.snippet.call_expr_with_arg_expr(to_commonjs_ref_name, ns_name)
+    }
+  }
+
+  /// On platforms other than Node.js, replace the global `__dirname` and `__filename` with constants,
+  /// which are the paths of the module relative to `cwd`.
+  fn try_shim_dirname_or_filename(
+    &self,
+    expr: &ast::Expression<'ast>,
+  ) -> Option<ast::Expression<'ast>> {
+    if matches!(self.ctx.options.platform, Platform::Node) {
+      return None;
+    }
+    let ast::Expression::Identifier(id_ref) = expr else {
+      return None;
+    };
+    if !self.is_global_identifier_reference(id_ref) {
+      return None;
+    }
+    let filename = format!("/{}", self.ctx.module.stable_resource_id.trim_start_matches("./"));
+    let value = match id_ref.name.as_str() {
+      "__filename" => filename,
+      "__dirname" => filename
+        .rsplit_once('/')
+        .map_or("/", |(dir, _)| if dir.is_empty() { "/" } else { dir })
+        .to_string(),
+      _ => return None,
+    };
+    Some(ast::Expression::StringLiteral(
+      self.snippet.string_literal(&value, id_ref.span).into_in(self.alloc),
+    ))
+  }
+
+  /// Rewrite `import.meta.hot` to `__rolldown_hmr__.hot("<stable id>")`, if HMR is enabled.
+  fn try_rewrite_import_meta_hot(
+    &self,
+    expr: &ast::Expression<'ast>,
+  ) -> Option<ast::Expression<'ast>> {
+    if !self.ctx.options.hmr() {
+      return None;
+    }
+    let ast::Expression::StaticMemberExpression(member_expr) = expr else {
+      return None;
+    };
+    let is_import_meta = matches!(&member_expr.object, ast::Expression::MetaProperty(meta)
+      if meta.meta.name == "import" && meta.property.name == "meta");
+    if !is_import_meta || member_expr.property.name != "hot" {
+      return None;
+    }
+    let hmr_runtime_name = self.canonical_name_for_runtime(HMR_RUNTIME_NAME);
+    let mut call_expr = ast::CallExpression {
+      callee: self.snippet.literal_prop_access_member_expr_expr(hmr_runtime_name, "hot"),
+      arguments: allocator::Vec::new_in(self.alloc),
+      span: member_expr.span,
+      ..TakeIn::dummy(self.alloc)
+    };
+    call_expr.arguments.push(ast::Argument::StringLiteral(
+      self.snippet.string_literal(&self.ctx.module.stable_resource_id, SPAN).into_in(self.alloc),
+    ));
+    Some(ast::Expression::CallExpression(call_expr.into_in(self.alloc)))
+  }
+
+  /// `__rolldown_hmr__.register("<stable id>", () => foo_exports)`, which lets HMR updates of other modules import the
+  /// module. `module.exports` is registered for CommonJS modules, which is the parameter of the wrapper.
+  fn generate_hmr_registration_stmt(&self) -> Statement<'ast> {
+    let exports = match self.ctx.module.exports_kind {
+      ExportsKind::Esm => self
+        .snippet
+        .id_ref_expr(self.canonical_name_for(self.ctx.module.namespace_object_ref), SPAN),
+      ExportsKind::CommonJs => {
+        self.snippet.literal_prop_access_member_expr_expr("module", "exports")
+      }
+      ExportsKind::None => ast::Expression::ObjectExpression(TakeIn::dummy(self.alloc)),
+    };
+    let hmr_runtime_name = self.canonical_name_for_runtime(HMR_RUNTIME_NAME);
+    let mut call_expr = ast::CallExpression {
+      callee: self.snippet.literal_prop_access_member_expr_expr(hmr_runtime_name, "register"),
+      arguments: allocator::Vec::new_in(self.alloc),
+      ..TakeIn::dummy(self.alloc)
+    };
+    call_expr.arguments.push(ast::Argument::StringLiteral(
+      self.snippet.string_literal(&self.ctx.module.stable_resource_id, SPAN).into_in(self.alloc),
+    ));
+    call_expr.arguments.push(ast::Argument::from(self.snippet.only_return_arrow_expr(exports)));
+    Statement::ExpressionStatement(
+      ast::ExpressionStatement {
+        expression: ast::Expression::CallExpression(call_expr.into_in(self.alloc)),
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    )
+  }
+
+  /// Whether the expression is `import()` of a proxy module of `lazyCompilation`, which exports a promise of the
+  /// namespace of the actual module as the default export.
+  fn is_lazy_proxy_import(&self, expr: &ast::Expression<'ast>) -> bool {
+    let ast::Expression::ImportExpression(import_expr) = expr else {
+      return false;
+    };
+    let Some(rec_id) = self.ctx.module.imports.get(&import_expr.span) else {
+      return false;
+    };
+    match self.ctx.module.import_records[*rec_id].resolved_module {
+      ModuleId::Normal(importee_id) => {
+        lazy_proxy_target(&self.ctx.modules[importee_id].resource_id).is_some()
+      }
+      ModuleId::External(_) => false,
+    }
+  }
+
+  /// Rewrite the filename exported by an asset module, in case it's redirected to another file with the same content.
+  fn try_rewrite_asset_filename_literal(&self, expr: &mut ast::Expression<'ast>) {
+    let ast::Expression::StringLiteral(literal) = expr else {
+      return;
+    };
+    if let Some(filename) = self.ctx.module.asset_urls.get(&literal.span) {
+      if literal.value != filename.as_str() {
+        let span = literal.span;
+        *expr = ast::Expression::StringLiteral(
+          self.snippet.string_literal(filename, span).into_in(self.alloc),
+        );
+      }
+    }
+  }
+
+  /// Rewrite `new URL('./foo.png', import.meta.url)` to point at the emitted asset, relative to the chunk.
+  /// For `new Worker(new URL('./worker.js', import.meta.url))`, it points at the chunk of the worker instead.
+  /// Workers imported with `?worker&inline` get a placeholder, which is replaced with the code of the worker chunk.
+  /// `import.meta.url` is not available in CommonJS, so it's replaced with `require("url").pathToFileURL(__filename)`.
+  /// Returns whether the expression is rewritten, in which case it has no code of the module left to finalize.
+  fn try_rewrite_new_url_expr(&self, expr: &mut ast::Expression<'ast>) -> bool {
+    let ast::Expression::NewExpression(new_expr) = expr else {
+      return false;
+    };
+    let chunk = self.importer_chunk();
+    let relative_path = if let Some(filename) = self.ctx.module.asset_urls.get(&new_expr.span) {
+      let asset_path = filename
+        .absolutize_with(self.ctx.options.cwd.join(&self.ctx.options.dir))
+        .expect_into_string();
+      chunk.relative_path_for(&asset_path)
+    } else if let Some(rec_id) = self.ctx.module.imports.get(&new_expr.span) {
+      let ModuleId::Normal(importee_id) = self.ctx.module.import_records[*rec_id].resolved_module
+      else {
+        return false;
+      };
+      let worker_chunk_id = self.ctx.chunk_graph.entry_module_to_entry_chunk[&importee_id];
+      let worker_chunk = &self.ctx.chunk_graph.chunks[worker_chunk_id];
+      let is_inline = split_query(&self.ctx.module.resource_id)
+        .1
+        .and_then(parse_worker_query)
+        .is_some_and(|query| query.inline);
+      if is_inline {
+        let placeholder = inline_worker_placeholder(
+          worker_chunk.preliminary_filename.as_deref().expect("should have preliminary filename"),
+        );
+        let span = new_expr.span;
+        *expr = ast::Expression::StringLiteral(
+          self.snippet.string_literal(&placeholder, span).into_in(self.alloc),
+        );
+        return true;
+      }
+      chunk.import_path_for(worker_chunk)
+    } else {
+      return false;
+    };
+
+    let Some(url_arg) = new_expr.arguments.get_mut(0) else {
+      return false;
+    };
+    let span = url_arg.span();
+    *url_arg = ast::Argument::StringLiteral(
+      self.snippet.string_literal(&relative_path, span).into_in(self.alloc),
+    );
+    if matches!(self.ctx.options.format, OutputFormat::Cjs) {
+      let require_url = self.snippet.call_expr_with_arg_expr_expr(
+        "require",
+        ast::Expression::StringLiteral(
+          self.snippet.string_literal("url", SPAN).into_in(self.alloc),
+        ),
+      );
+      let path_to_file_url = ast::Expression::StaticMemberExpression(
+        ast::StaticMemberExpression {
+          object: require_url,
+          property: self.snippet.id_name("pathToFileURL", SPAN),
+          ..TakeIn::dummy(self.alloc)
+        }
+        .into_in(self.alloc),
+      );
+      let mut arguments = allocator::Vec::new_in(self.alloc);
+      arguments.push(ast::Argument::Identifier(
+        self.snippet.id_ref("__filename", SPAN).into_in(self.alloc),
+      ));
+      if let Some(base_arg) = new_expr.arguments.get_mut(1) {
+        *base_arg = ast::Argument::CallExpression(
+          ast::CallExpression { callee: path_to_file_url, arguments, ..TakeIn::dummy(self.alloc) }
+            .into_in(self.alloc),
+        );
+      }
+    }
+    true
+  }
+
   fn should_remove_import_export_stmt(
     &self,
     stmt: &mut Statement<'ast>,
@@ -78,13 +389,33 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     true
   }
 
+  /// Rewrite `Direction.Up` to `0`, if `Direction` is an imported `const enum` whose `Up` member is known.
+  fn try_inline_const_enum_member(
+    &self,
+    expr: &ast::Expression<'ast>,
+  ) -> Option<ast::Expression<'ast>> {
+    if !self.ctx.options.inline_const_enums() {
+      return None;
+    }
+    let member_expr = expr.as_member_expression()?;
+    let (ast::Expression::Identifier(id_ref), Some(member)) =
+      (member_expr.object(), member_expr.static_property_name())
+    else {
+      return None;
+    };
+    let symbol_id = self.scope.symbol_id_for(id_ref.reference_id.get()?)?;
+    let canonical_ref = self.ctx.symbols.par_canonical_ref_for((self.ctx.id, symbol_id).into());
+    let value = self.ctx.modules[canonical_ref.owner]
+      .const_enums
+      .get(&canonical_ref.symbol)?
+      .get(&Rstr::new(member))?;
+    Some(const_enum_value_expr(&self.snippet, value))
+  }
+
   fn generate_finalized_expr_for_symbol_ref(&self, symbol_ref: SymbolRef) -> ast::Expression<'ast> {
     let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
-    let symbol = self.ctx.symbols.get(canonical_ref);
 
-    if let Some(ns_alias) = &symbol.namespace_alias {
-      let canonical_ns_name = self.canonical_name_for(ns_alias.namespace_ref);
-      let prop_name = &ns_alias.property_name;
+    if let Some((canonical_ns_name, prop_name)) = self.namespace_alias_for(canonical_ref) {
       let access_expr =
         self.snippet.literal_prop_access_member_expr_expr(canonical_ns_name, prop_name);
 
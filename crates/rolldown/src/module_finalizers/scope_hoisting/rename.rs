@@ -22,11 +22,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     let symbol_ref: SymbolRef = (self.ctx.id, symbol_id).into();
     let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
-    let symbol = self.ctx.symbols.get(canonical_ref);
 
-    if let Some(ns_alias) = &symbol.namespace_alias {
-      let canonical_ns_name = self.canonical_name_for(ns_alias.namespace_ref);
-      let prop_name = &ns_alias.property_name;
+    if let Some((canonical_ns_name, prop_name)) = self.namespace_alias_for(canonical_ref) {
       let access_expr =
         self.snippet.literal_prop_access_member_expr_expr(canonical_ns_name, prop_name);
 
@@ -65,11 +62,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     let symbol_ref: SymbolRef = (self.ctx.id, symbol_id).into();
     let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
-    let symbol = self.ctx.symbols.get(canonical_ref);
 
-    if let Some(ns_alias) = &symbol.namespace_alias {
-      let canonical_ns_name = self.canonical_name_for(ns_alias.namespace_ref);
-      let prop_name = &ns_alias.property_name;
+    if let Some((canonical_ns_name, prop_name)) = self.namespace_alias_for(canonical_ref) {
       let access_expr = self.snippet.literal_prop_access_member_expr(canonical_ns_name, prop_name);
 
       return Some(ast::SimpleAssignmentTarget::from(access_expr));
@@ -120,11 +114,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
 
     let symbol_ref: SymbolRef = (self.ctx.id, symbol_id).into();
     let canonical_ref = self.ctx.symbols.par_canonical_ref_for(symbol_ref);
-    let symbol = self.ctx.symbols.get(canonical_ref);
 
-    if let Some(ns_alias) = &symbol.namespace_alias {
-      let canonical_ns_name = self.canonical_name_for(ns_alias.namespace_ref);
-      let prop_name = &ns_alias.property_name;
+    if let Some((canonical_ns_name, prop_name)) = self.namespace_alias_for(canonical_ref) {
       let access_expr = self.snippet.literal_prop_access_member_expr(canonical_ns_name, prop_name);
       *simple_target = ast::SimpleAssignmentTarget::from(access_expr);
     } else {
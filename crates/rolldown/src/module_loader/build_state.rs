@@ -0,0 +1,24 @@
+use rolldown_utils::profiler::SharedProfiler;
+
+use crate::types::cancellation_token::CancellationToken;
+
+use super::{
+  bundle_cache::SharedTransformCache, lazy_compilation::SharedLazyCompilation,
+  module_cache::SharedModuleCache, persistent_cache::SharedPersistentCache,
+};
+
+/// State of the `Bundler` that outlives a single build. Module loaders read and update it.
+#[derive(Default, Clone)]
+pub struct BuildState {
+  /// Only exists in watch mode.
+  pub module_cache: Option<SharedModuleCache>,
+  /// Only exists with `lazyCompilation`.
+  pub lazy_compilation: Option<SharedLazyCompilation>,
+  /// Only exists with `cacheDir`.
+  pub persistent_cache: Option<SharedPersistentCache>,
+  /// Only exists with `BundlerBuilder::with_cache`.
+  pub transform_cache: Option<SharedTransformCache>,
+  /// Only exists with `profile`.
+  pub profiler: Option<SharedProfiler>,
+  pub cancellation_token: CancellationToken,
+}
@@ -0,0 +1,238 @@
+use std::sync::{Arc, Mutex};
+
+use rolldown_common::side_effects::HookSideEffects;
+use rolldown_sourcemap::SourceMap;
+use rustc_hash::FxHashMap;
+use serde::{Deserialize, Serialize};
+
+/// Modules of a build with results of the `transform` hook, like Rollup's `cache`. It's serializable, so tools creating
+/// a new bundler for every run could keep it anywhere and pass it to `BundlerBuilder::with_cache` of the next bundler.
+/// Modules whose code returned by `load` is unchanged skip `transform` hooks then, unless plugins ask for
+/// transforming with `shouldTransformCachedModule`.
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct BundleCache {
+  pub modules: Vec<CachedModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CachedModule {
+  pub id: String,
+  /// The code returned by `load`
+  pub original_code: String,
+  /// The code returned by `transform`
+  pub code: String,
+  /// Sourcemaps returned by `transform` hooks in JSON
+  pub sourcemaps: Vec<String>,
+  /// `true`, `false` or `no-treeshake`
+  pub side_effects: Option<String>,
+  /// Files added by `this.addWatchFile` in `transform` hooks
+  #[serde(default)]
+  pub transform_dependencies: Vec<String>,
+}
+
+/// The result of the `transform` hook found in a cache.
+pub struct CachedTransform {
+  pub code: String,
+  /// Sourcemaps returned by `transform` hooks
+  pub sourcemap_chain: Vec<SourceMap>,
+  pub side_effects: Option<HookSideEffects>,
+  /// Files added by `this.addWatchFile` in `transform` hooks, which are watched again when the result is used
+  pub transform_dependencies: Vec<String>,
+}
+
+impl CachedTransform {
+  /// Returns `None` if the sourcemaps are invalid.
+  pub fn from_serialized(
+    code: String,
+    sourcemaps: &[String],
+    side_effects: Option<&str>,
+  ) -> Option<Self> {
+    let sourcemap_chain = sourcemaps
+      .iter()
+      .map(|map| SourceMap::from_json_string(map).ok())
+      .collect::<Option<Vec<_>>>()?;
+    Some(Self {
+      code,
+      sourcemap_chain,
+      side_effects: deserialize_side_effects(side_effects),
+      transform_dependencies: vec![],
+    })
+  }
+}
+
+/// Returns `None` if any sourcemap couldn't be serialized.
+pub fn serialize_sourcemaps(sourcemap_chain: &[SourceMap]) -> Option<Vec<String>> {
+  sourcemap_chain.iter().map(|map| map.to_json_string().ok()).collect()
+}
+
+pub fn serialize_side_effects(side_effects: Option<&HookSideEffects>) -> Option<String> {
+  side_effects.map(|side_effects| {
+    match side_effects {
+      HookSideEffects::True => "true",
+      HookSideEffects::False => "false",
+      HookSideEffects::NoTreeshake => "no-treeshake",
+    }
+    .to_string()
+  })
+}
+
+pub fn deserialize_side_effects(side_effects: Option<&str>) -> Option<HookSideEffects> {
+  match side_effects {
+    Some("true") => Some(HookSideEffects::True),
+    Some("false") => Some(HookSideEffects::False),
+    Some("no-treeshake") => Some(HookSideEffects::NoTreeshake),
+    _ => None,
+  }
+}
+
+pub type SharedTransformCache = Arc<TransformCache>;
+
+/// The `BundleCache` passed to the bundler, and the one collected in the current build.
+#[derive(Default)]
+pub struct TransformCache {
+  previous: Mutex<FxHashMap<String, CachedModule>>,
+  current: Mutex<FxHashMap<String, CachedModule>>,
+}
+
+impl TransformCache {
+  pub fn new(cache: BundleCache) -> Self {
+    let previous = cache.modules.into_iter().map(|module| (module.id.clone(), module)).collect();
+    Self { previous: Mutex::new(previous), current: Mutex::default() }
+  }
+
+  /// Modules of the last build become the cache of the next build.
+  pub fn start_build(&self) {
+    let current =
+      std::mem::take(&mut *self.current.lock().expect("Transform cache should not be poisoned"));
+    self.previous.lock().expect("Transform cache should not be poisoned").extend(current);
+  }
+
+  pub fn get(&self, id: &str, original_code: &str) -> Option<CachedTransform> {
+    let module = self
+      .previous
+      .lock()
+      .expect("Transform cache should not be poisoned")
+      .get(id)
+      .filter(|module| module.original_code == original_code)
+      .cloned()?;
+    let mut cached = CachedTransform::from_serialized(
+      module.code.clone(),
+      &module.sourcemaps,
+      module.side_effects.as_deref(),
+    )?;
+    cached.transform_dependencies.clone_from(&module.transform_dependencies);
+    self
+      .current
+      .lock()
+      .expect("Transform cache should not be poisoned")
+      .insert(module.id.clone(), module);
+    Some(cached)
+  }
+
+  pub fn insert(&self, module: CachedModule) {
+    self
+      .current
+      .lock()
+      .expect("Transform cache should not be poisoned")
+      .insert(module.id.clone(), module);
+  }
+
+  /// Modules of the current build, or the last build if no build is in progress.
+  pub fn to_bundle_cache(&self) -> BundleCache {
+    let current = self.current.lock().expect("Transform cache should not be poisoned");
+    let mut modules = current.values().cloned().collect::<Vec<_>>();
+    modules.sort_by(|a, b| a.id.cmp(&b.id));
+    BundleCache { modules }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_bundle_cache() {
+    use std::{borrow::Cow, path::Path};
+
+    use rolldown_common::{BundlerOptions, Output};
+    use rolldown_fs::FileSystem;
+    use rolldown_plugin::{
+      HookLoadOutput, HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext,
+    };
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    /// Uppercases strings and records transformed ids.
+    #[derive(Debug)]
+    struct Uppercase(Arc<Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl Plugin for Uppercase {
+      fn name(&self) -> Cow<'static, str> {
+        "uppercase".into()
+      }
+
+      async fn transform(
+        &self,
+        _ctx: &TransformPluginContext<'_>,
+        args: &HookTransformArgs,
+      ) -> HookTransformReturn {
+        self.0.lock().unwrap().push(args.id.to_string());
+        Ok(Some(HookLoadOutput {
+          code: args.code.replace("'value'", "'VALUE'").replace("'dep'", "'DEP'"),
+          map: None,
+          side_effects: None,
+        }))
+      }
+    }
+
+    let fs = memory_fs(&[
+      ("main.js", "import './dep.js';\nconsole.log('value');\n"),
+      ("dep.js", "console.log('dep');\n"),
+    ]);
+    let transformed = Arc::new(Mutex::new(vec![]));
+    // Each run creates a new bundler with the cache of the previous run, which goes through JSON in between.
+    let run = |cache: Option<String>| {
+      let mut builder = memory_bundler(&fs, BundlerOptions::default())
+        .with_plugins(vec![Box::new(Uppercase(Arc::clone(&transformed)))]);
+      if let Some(cache) = cache {
+        builder = builder.with_cache(serde_json::from_str(&cache).unwrap());
+      }
+      let mut bundler = builder.build();
+      async move {
+        let output = bundler.generate().await.unwrap();
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        let Some(Output::Chunk(chunk)) = output.assets.first() else {
+          panic!("The entry chunk should be emitted");
+        };
+        let cache = bundler.cache().map(|cache| serde_json::to_string(&cache).unwrap());
+        (chunk.code.clone(), cache)
+      }
+    };
+    let take_transformed = || {
+      let mut ids = std::mem::take(&mut *transformed.lock().unwrap());
+      ids.sort();
+      ids
+    };
+
+    // There is no cache unless the bundler is built with one.
+    let (_, cache) = run(None).await;
+    assert!(cache.is_none());
+    take_transformed();
+
+    let (code, cache) = run(Some(serde_json::to_string(&BundleCache::default()).unwrap())).await;
+    assert_eq!(take_transformed(), ["/project/dep.js", "/project/main.js"]);
+
+    // Modules whose loaded code is unchanged aren't transformed again, and the output is the same.
+    let (cached_code, cache) = run(cache).await;
+    assert!(take_transformed().is_empty());
+    assert_eq!(cached_code, code);
+    assert!(cached_code.contains("'VALUE'") && cached_code.contains("'DEP'"), "{cached_code}");
+
+    fs.write(Path::new("/project/dep.js"), b"console.log('dep', 'value');\n").unwrap();
+    let (code, _) = run(cache).await;
+    assert_eq!(take_transformed(), ["/project/dep.js"]);
+    assert!(code.contains("console.log('DEP', 'VALUE')"), "{code}");
+  }
+}
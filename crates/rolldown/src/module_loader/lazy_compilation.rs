@@ -0,0 +1,39 @@
+use std::sync::{Arc, Mutex};
+
+use rustc_hash::FxHashSet;
+use tokio::sync::Notify;
+
+pub type SharedLazyCompilation = Arc<LazyCompilation>;
+
+/// Dynamically imported modules requested by the app with `lazyCompilation`. Other dynamically imported modules are
+/// replaced with proxy modules.
+#[derive(Default)]
+pub struct LazyCompilation {
+  /// Ids relative to `cwd`, as proxy modules pass to `__rolldown_lazy__.load(id)`.
+  requested: Mutex<FxHashSet<String>>,
+  notify: Notify,
+}
+
+impl LazyCompilation {
+  /// Bundle the module as usual since the next build. In watch mode, this triggers a rebuild if the module hasn't been
+  /// requested before. Otherwise, the dev server should build again by itself.
+  pub fn request(&self, id: &str) {
+    let is_new = self
+      .requested
+      .lock()
+      .expect("Lazy compilation should not be poisoned")
+      .insert(id.to_string());
+    if is_new {
+      self.notify.notify_one();
+    }
+  }
+
+  pub fn is_requested(&self, id: &str) -> bool {
+    self.requested.lock().expect("Lazy compilation should not be poisoned").contains(id)
+  }
+
+  /// Wait until a new module is requested.
+  pub(crate) async fn requested(&self) {
+    self.notify.notified().await;
+  }
+}
@@ -1,6 +1,11 @@
+pub mod build_state;
+pub mod bundle_cache;
+pub mod lazy_compilation;
+pub mod module_cache;
 #[allow(clippy::module_inception)]
 pub mod module_loader;
 mod normal_module_task;
+pub mod persistent_cache;
 mod runtime_normal_module_task;
 pub mod task_context;
 mod task_result;
@@ -0,0 +1,40 @@
+use std::sync::{Arc, Mutex};
+
+use rolldown_common::{side_effects::HookSideEffects, ModulePath};
+use rolldown_sourcemap::SourceMap;
+use rustc_hash::FxHashMap;
+
+pub type SharedModuleCache = Arc<ModuleCache>;
+
+/// A module after the `load` and `transform` hooks, before it's parsed.
+#[derive(Clone)]
+pub struct LoadedModule {
+  pub source: Arc<str>,
+  pub sourcemap_chain: Vec<SourceMap>,
+  pub side_effects: Option<HookSideEffects>,
+  /// Emitted assets with their filenames and contents
+  pub assets: Vec<(String, Vec<u8>)>,
+  /// The filename exported by the asset module as `export default "..."`
+  pub asset_filename: Option<String>,
+}
+
+/// Loaded modules kept between builds in watch mode, so rebuilds only load and transform changed modules again.
+/// Parsing, linking and generating still run on the whole module graph.
+#[derive(Default)]
+pub struct ModuleCache {
+  modules: Mutex<FxHashMap<ModulePath, LoadedModule>>,
+}
+
+impl ModuleCache {
+  pub fn get(&self, id: &ModulePath) -> Option<LoadedModule> {
+    self.modules.lock().expect("Module cache should not be poisoned").get(id).cloned()
+  }
+
+  pub fn insert(&self, id: ModulePath, module: LoadedModule) {
+    self.modules.lock().expect("Module cache should not be poisoned").insert(id, module);
+  }
+
+  pub fn invalidate(&self, id: &str) {
+    self.modules.lock().expect("Module cache should not be poisoned").remove(&ModulePath::new(id));
+  }
+}
@@ -1,16 +1,20 @@
 use oxc_index::IndexVec;
 use rolldown_common::{
-  EntryPoint, EntryPointKind, ExternalModule, ExternalModuleVec, ImportKind, ImportRecordId,
-  ImporterRecord, ModuleId, ModuleTable, NormalModule, NormalModuleId, ResolvedRequestInfo,
+  AssetModule, AssetModuleVec, EntryPoint, EntryPointKind, ExternalModule, ExternalModuleVec,
+  ImportKind, ImportRecordId, ImporterRecord, ModuleId, ModulePath, ModuleTable, NormalModule,
+  NormalModuleId, ResolvedRequestInfo,
 };
 use rolldown_error::BuildError;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_oxc_utils::OxcAst;
 use rolldown_plugin::SharedPluginDriver;
-use rolldown_utils::rustc_hash::FxHashSetExt;
+use rolldown_utils::{rustc_hash::FxHashSetExt, xxhash::xxhash_base64_url};
 use rustc_hash::{FxHashMap, FxHashSet};
-use std::sync::Arc;
+use std::{path::PathBuf, sync::Arc};
+use sugar_path::SugarPath;
+use tokio::sync::Semaphore;
 
+use super::build_state::BuildState;
 use super::normal_module_task::NormalModuleTask;
 use super::runtime_normal_module_task::RuntimeNormalModuleTask;
 use super::task_result::NormalModuleTaskResult;
@@ -19,6 +23,8 @@ use crate::module_loader::runtime_normal_module_task::RuntimeNormalModuleTaskRes
 use crate::module_loader::task_context::TaskContext;
 use crate::runtime::RuntimeModuleBrief;
 use crate::types::symbols::Symbols;
+use crate::utils::css_urls::{css_asset_placeholder, replace_css_asset_placeholders};
+use crate::utils::fs_glob::glob_files;
 
 use crate::{SharedOptions, SharedResolver};
 
@@ -46,11 +52,12 @@ pub struct ModuleLoader {
   input_options: SharedOptions,
   shared_context: Arc<TaskContext>,
   rx: tokio::sync::mpsc::Receiver<Msg>,
-  visited: FxHashMap<Arc<str>, ModuleId>,
+  visited: FxHashMap<ModulePath, ModuleId>,
   runtime_id: NormalModuleId,
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
   external_modules: ExternalModuleVec,
+  asset_modules: AssetModuleVec,
   symbols: Symbols,
 }
 
@@ -70,9 +77,12 @@ impl ModuleLoader {
   pub fn new(
     input_options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
+    build_state: BuildState,
   ) -> Self {
+    ModulePath::release_unused();
+
     // 1024 should be enough for most cases
     // over 1024 pending tasks are insane
     let (tx, rx) = tokio::sync::mpsc::channel::<Msg>(1024);
@@ -80,11 +90,15 @@ impl ModuleLoader {
     let tx_to_runtime_module = tx.clone();
 
     let common_data = Arc::new(TaskContext {
+      dynamic_require_targets: expand_dynamic_require_targets(&input_options, &fs),
+      eval_warning_exclude: compile_eval_warning_exclude(&input_options),
       input_options: Arc::clone(&input_options),
       tx,
       resolver,
       fs,
       plugin_driver,
+      build_state,
+      file_ops: input_options.max_parallel_file_ops.map(|ops| Semaphore::new(ops.max(1))),
     });
 
     let mut intermediate_normal_modules = IntermediateNormalModules::new();
@@ -115,6 +129,7 @@ impl ModuleLoader {
       remaining: 1,
       intermediate_normal_modules,
       external_modules: IndexVec::new(),
+      asset_modules: IndexVec::new(),
       symbols,
     }
   }
@@ -124,7 +139,7 @@ impl ModuleLoader {
     info: ResolvedRequestInfo,
     is_user_defined_entry: bool,
   ) -> ModuleId {
-    match self.visited.entry(Arc::<str>::clone(&info.path.path)) {
+    match self.visited.entry(info.path.path.clone()) {
       std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
       std::collections::hash_map::Entry::Vacant(not_visited) => {
         if info.is_external {
@@ -169,7 +184,7 @@ impl ModuleLoader {
     mut self,
     user_defined_entries: Vec<(Option<String>, ResolvedRequestInfo)>,
   ) -> anyhow::Result<ModuleLoaderOutput> {
-    if self.input_options.input.is_empty() {
+    if self.input_options.input.is_empty() && self.input_options.stdin.is_none() {
       return Err(anyhow::format_err!("You must supply options.input to rolldown"));
     }
 
@@ -208,6 +223,11 @@ impl ModuleLoader {
       let Some(msg) = self.rx.recv().await else {
         break;
       };
+      // Once the build is cancelled, wait for running tasks without spawning new ones.
+      if self.shared_context.build_state.cancellation_token.is_cancelled() {
+        self.remaining -= 1;
+        continue;
+      }
       match msg {
         Msg::NormalModuleDone(task_result) => {
           let NormalModuleTaskResult {
@@ -218,14 +238,32 @@ impl ModuleLoader {
             raw_import_records,
             warnings,
             ast,
+            assets,
           } = task_result;
           all_warnings.extend(warnings);
 
+          for (filename, source) in assets {
+            let id = self.asset_modules.len_idx();
+            self.asset_modules.push(AssetModule {
+              id,
+              module_id,
+              resource_id: module.resource_id.clone(),
+              filename,
+              source,
+            });
+          }
+
           let import_records = raw_import_records
             .into_iter()
             .zip(resolved_deps)
             .map(|(raw_rec, info)| {
               let id = self.try_spawn_new_task(info, false);
+              if let ModuleId::External(id) = id {
+                let external_module = &mut self.external_modules[id];
+                if external_module.attributes.is_empty() && !raw_rec.attributes.is_empty() {
+                  external_module.attributes.clone_from(&raw_rec.attributes);
+                }
+              }
               // Dynamic imported module will be considered as an entry
               if let ModuleId::Normal(id) = id {
                 self.intermediate_normal_modules.importers[id].push(ImporterRecord {
@@ -267,8 +305,9 @@ impl ModuleLoader {
       }
       self.remaining -= 1;
     }
+    self.shared_context.build_state.cancellation_token.check()?;
 
-    let modules: IndexVec<NormalModuleId, NormalModule> = self
+    let mut modules: IndexVec<NormalModuleId, NormalModule> = self
       .intermediate_normal_modules
       .modules
       .into_iter()
@@ -288,6 +327,8 @@ impl ModuleLoader {
       })
       .collect();
 
+    dedupe_asset_modules(&mut self.asset_modules, &mut modules);
+
     let ast_table: IndexVec<NormalModuleId, OxcAst> =
       self.intermediate_normal_modules.ast_table.into_iter().flatten().collect();
 
@@ -304,6 +345,7 @@ impl ModuleLoader {
       module_table: ModuleTable {
         normal_modules: modules,
         external_modules: self.external_modules,
+        asset_modules: self.asset_modules,
       },
       symbols: self.symbols,
       ast_table,
@@ -314,3 +356,79 @@ impl ModuleLoader {
     })
   }
 }
+
+/// Emit assets with the same content only once, even if they are imported by different names. The smallest filename
+/// is kept, so the result doesn't depend on the order modules are loaded in. References to other filenames, which
+/// are exported by asset modules, used by `new URL(...)` or placed in stylesheets, are redirected to it.
+fn dedupe_asset_modules(
+  asset_modules: &mut AssetModuleVec,
+  modules: &mut IndexVec<NormalModuleId, NormalModule>,
+) {
+  // Assets are grouped by the hash of their contents, which are compared as well in case the hashes collide.
+  let mut filenames_by_hash: FxHashMap<String, Vec<(&[u8], &str)>> = FxHashMap::default();
+  for asset_module in asset_modules.iter() {
+    let same_hash = filenames_by_hash.entry(xxhash_base64_url(&asset_module.source)).or_default();
+    match same_hash.iter_mut().find(|(source, _)| *source == asset_module.source.as_slice()) {
+      Some((_, filename)) => *filename = (*filename).min(asset_module.filename.as_str()),
+      None => same_hash.push((&asset_module.source, &asset_module.filename)),
+    }
+  }
+  let redirects = asset_modules
+    .iter()
+    .filter_map(|asset_module| {
+      let (_, filename) = filenames_by_hash[&xxhash_base64_url(&asset_module.source)]
+        .iter()
+        .find(|(source, _)| *source == asset_module.source.as_slice())?;
+      (*filename != asset_module.filename)
+        .then(|| (asset_module.filename.clone(), (*filename).to_string()))
+    })
+    .collect::<FxHashMap<_, _>>();
+  if redirects.is_empty() {
+    return;
+  }
+
+  for asset_module in asset_modules.iter_mut() {
+    if let Some(filename) = redirects.get(&asset_module.filename) {
+      asset_module.filename.clone_from(filename);
+    }
+  }
+  for module in modules.iter_mut() {
+    for filename in module.asset_urls.values_mut() {
+      if let Some(redirected) = redirects.get(filename) {
+        filename.clone_from(redirected);
+      }
+    }
+    if let Some(css) = &mut module.css {
+      *css = replace_css_asset_placeholders(css, |filename| {
+        css_asset_placeholder(redirects.get(filename).map_or(filename, String::as_str))
+      });
+    }
+  }
+}
+
+/// Invalid patterns are reported by validating options before the build starts, so they're skipped here.
+fn compile_eval_warning_exclude(input_options: &SharedOptions) -> Vec<glob::Pattern> {
+  input_options
+    .eval_warning_exclude
+    .iter()
+    .filter_map(|pattern| {
+      glob::Pattern::new(&input_options.cwd.join(pattern).normalize().to_string_lossy()).ok()
+    })
+    .collect()
+}
+
+/// Invalid patterns are reported by validating options before the build starts, so they're skipped here.
+fn expand_dynamic_require_targets(
+  input_options: &SharedOptions,
+  fs: &SharedFileSystem,
+) -> Vec<PathBuf> {
+  let mut targets = input_options
+    .dynamic_require_targets
+    .iter()
+    .filter_map(|pattern| glob_files(fs, &input_options.cwd.join(pattern).normalize()).ok())
+    .flatten()
+    .collect::<Vec<_>>();
+  targets.sort();
+  targets.dedup();
+  targets
+}
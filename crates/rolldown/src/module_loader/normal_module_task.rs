@@ -1,28 +1,66 @@
-use std::sync::Arc;
+use std::{path::Path, sync::Arc, time::Instant};
 
 use anyhow::Result;
 use futures::future::join_all;
+use oxc::{
+  ast::ast::{ExportDefaultDeclarationKind, Program, Statement},
+  span::Span,
+};
 use oxc_index::IndexVec;
 use rolldown_common::{
   side_effects::{DeterminedSideEffects, HookSideEffects},
-  AstScopes, ImportRecordId, ModuleType, NormalModule, NormalModuleId, PackageJson,
-  RawImportRecord, ResolvedPath, ResolvedRequestInfo, ResourceId, SymbolRef,
+  AstScopes, ImportKind, ImportRecordId, ImportRecordMeta, LegalComments, Loader, ModuleType,
+  NormalModule, NormalModuleId, NormalizedStdin, PackageJson, Platform, RawImportRecord,
+  ResolvedPath, ResolvedRequestInfo, ResourceId, SymbolRef, UnresolvedRequire,
 };
-use rolldown_error::BuildError;
+use rolldown_error::{BuildError, EventKind};
+use rolldown_fs::FileSystem;
+use rolldown_loader_utils::{text_to_esm, wasm_to_esm};
 use rolldown_oxc_utils::OxcAst;
-use rolldown_plugin::{HookResolveIdExtraOptions, SharedPluginDriver};
+use rolldown_plugin::{
+  HookResolveIdExtraOptions, HookShouldTransformCachedModuleArgs, SharedPluginDriver,
+};
 use rolldown_resolver::ResolveError;
-use rolldown_utils::path_ext::PathExt;
+use rolldown_sourcemap::{collapse_sourcemaps, SourceMap};
+use rolldown_utils::{
+  node_builtins::{is_node_builtin, node_builtin_specifier},
+  path_ext::PathExt,
+};
+use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
-use super::{task_context::TaskContext, Msg};
+use super::{
+  bundle_cache::{serialize_side_effects, serialize_sourcemaps, CachedModule, CachedTransform},
+  module_cache::LoadedModule,
+  persistent_cache::{CachedResolution, PendingCacheEntry},
+  task_context::TaskContext,
+  Msg,
+};
 use crate::{
   ast_scanner::{AstScanner, ScanResult},
   module_loader::NormalModuleTaskResult,
   types::ast_symbols::AstSymbols,
   utils::{
-    load_source::load_source, make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
-    parse_to_ast::parse_to_ast, resolve_id::resolve_id, transform_source::transform_source,
+    css_modules::{css_module_to_esm, is_css_module, scope_css_module},
+    css_urls::{
+      css_asset_placeholder, extract_css_imports, find_css_urls, is_local_css_url, CssUrl,
+    },
+    data_url::to_data_url,
+    define::replace_defines,
+    drop_ast_nodes::drop_ast_nodes,
+    extract_legal_comments::extract_legal_comments,
+    html::{find_html_references, html_to_esm},
+    import_glob::{expand_import_globs, find_import_globs},
+    input_sourcemap::load_input_sourcemap,
+    lazy_compilation::{lazy_proxy_id, lazy_proxy_source, lazy_proxy_target},
+    load_source::load_source,
+    make_ast_symbol_and_scope::make_ast_scopes_and_symbols,
+    module_query::split_query,
+    parse_to_ast::{determine_loader, parse_to_ast},
+    render_asset_filename::render_asset_filename,
+    resolve_id::resolve_id,
+    strip_typescript::strip_typescript,
+    transform_source::transform_source,
     tweak_ast_for_scanning::tweak_ast_for_scanning,
   },
   SharedOptions, SharedResolver,
@@ -36,6 +74,10 @@ pub struct NormalModuleTask {
   errors: Vec<BuildError>,
   is_user_defined_entry: bool,
   side_effects: Option<HookSideEffects>,
+  /// Resolutions of imports found with the result of `transform` in the persistent cache
+  cached_resolutions: Vec<CachedResolution>,
+  /// The result of `transform` to write to the persistent cache, along with resolutions of imports
+  pending_cache_entry: Option<PendingCacheEntry>,
 }
 
 impl NormalModuleTask {
@@ -57,6 +99,8 @@ impl NormalModuleTask {
       is_user_defined_entry,
       package_json,
       side_effects,
+      cached_resolutions: vec![],
+      pending_cache_entry: None,
     }
   }
 
@@ -76,42 +120,215 @@ impl NormalModuleTask {
 
   #[allow(clippy::too_many_lines)]
   async fn run_inner(&mut self) -> Result<()> {
-    let mut hook_side_effects = self.side_effects.take();
-    let mut sourcemap_chain = vec![];
+    let ctx = Arc::clone(&self.ctx);
+    let _permit = match &ctx.file_ops {
+      Some(file_ops) => Some(file_ops.acquire().await.expect("Semaphore should not be closed")),
+      None => None,
+    };
     let mut warnings = vec![];
 
-    // Run plugin load to get content first, if it is None using read fs as fallback.
-    let source = load_source(
-      &self.ctx.plugin_driver,
-      &self.resolved_path,
-      &self.ctx.fs,
-      &mut sourcemap_chain,
-      &mut hook_side_effects,
-    )
-    .await?;
-
-    // Run plugin transform.
-    let source: Arc<str> = transform_source(
-      &self.ctx.plugin_driver,
-      &self.resolved_path,
+    let loader = if lazy_proxy_target(&self.resolved_path.path).is_some() {
+      Loader::Js
+    } else if let Some(stdin) = self.stdin() {
+      stdin.loader
+    } else {
+      determine_loader(&self.ctx.input_options, &self.resolved_path.path)
+    };
+
+    // Modules unchanged since the last build in watch mode skip the `load` and `transform` hooks.
+    let module_cache = self.ctx.build_state.module_cache.clone();
+    let loaded = match module_cache.as_ref().and_then(|cache| cache.get(&self.resolved_path.path)) {
+      Some(loaded) => loaded,
+      None => {
+        let start = self.profile_start();
+        let loaded = self.load_and_transform(loader).await?;
+        self.record_profile("load", start);
+        if let Some(cache) = &module_cache {
+          cache.insert(self.resolved_path.path.clone(), loaded.clone());
+        }
+        loaded
+      }
+    };
+    let LoadedModule {
       source,
-      &mut sourcemap_chain,
-      &mut hook_side_effects,
-    )
-    .await?
-    .into();
-
-    let mut ast = parse_to_ast(
-      &self.ctx.input_options,
-      self.resolved_path.path.as_path(),
-      Arc::clone(&source),
-    )?;
+      mut sourcemap_chain,
+      side_effects: hook_side_effects,
+      mut assets,
+      asset_filename,
+    } = loaded;
+
+    let resource_id = ResourceId::new(self.resolved_path.path.clone());
+    let cwd = &self.ctx.input_options.cwd;
+    // Ids of proxy modules of `lazyCompilation` contain absolute paths of their targets, which are shown relative to
+    // `cwd` as the targets are.
+    let stable_resource_id = match lazy_proxy_target(&resource_id) {
+      Some(target) => {
+        let stable_target = ResourceId::new(target.to_string()).stabilize(cwd);
+        lazy_proxy_id(&stable_target).replace('\0', "\\0")
+      }
+      None => resource_id.stabilize(cwd),
+    };
+    let debug_resource_id = if lazy_proxy_target(&resource_id).is_some() {
+      stable_resource_id.clone()
+    } else {
+      self.resolved_path.debug_display(cwd)
+    };
+
+    // Stylesheets are extracted into `.css` files per chunk, so the module is empty in JavaScript.
+    // `@import` rules become imports of the module, and CSS modules export their scoped class names.
+    let (source, css) = if matches!(loader, Loader::Css) {
+      let (css, css_imports) = extract_css_imports(&source);
+      let css = self.rewrite_css_urls(&css, &mut assets).await?;
+      let mut js_source = css_imports
+        .iter()
+        .map(|request| {
+          if request.starts_with('.') {
+            format!("import \"{request}\";\n")
+          } else {
+            // Requests in `@import` are always relative to the stylesheet
+            format!("import \"./{request}\";\n")
+          }
+        })
+        .collect::<String>();
+      let file = split_query(&self.resolved_path.path).0;
+      let css = if is_css_module(file) {
+        let (css, classes) = scope_css_module(
+          &css,
+          &self.ctx.input_options.css_modules_scoped_name,
+          file,
+          &stable_resource_id,
+        );
+        js_source.push_str(&css_module_to_esm(
+          &classes,
+          self.ctx.input_options.css_modules_locals_convention,
+        ));
+        css
+      } else {
+        css
+      };
+      (js_source.into(), Some(css))
+    } else {
+      (source, None)
+    };
+
+    // HTML documents import the module scripts and stylesheets they reference, and are emitted with their chunks.
+    let (source, html) = if matches!(loader, Loader::Html) {
+      (html_to_esm(&find_html_references(&source)).into(), Some(source.to_string()))
+    } else {
+      (source, None)
+    };
+
+    let parse_start = self.profile_start();
+    let mut ast = parse_to_ast(loader, Arc::clone(&source))?;
+
+    // TypeScript modules are compiled into JavaScript, then parsed again. Comments aren't kept in the stripped
+    // code, so legal comments are extracted beforehand.
+    let mut typescript_legal_comments = None;
+    let mut const_enum_members = FxHashMap::default();
+    let source = if matches!(loader, Loader::Ts) {
+      if !matches!(self.ctx.input_options.legal_comments, LegalComments::None) {
+        typescript_legal_comments = Some(extract_legal_comments(&ast));
+      }
+      let stripped = match strip_typescript(
+        &mut ast,
+        &self.resolved_path.path,
+        self.ctx.input_options.sourcemap.is_some(),
+      ) {
+        Ok(stripped) => stripped,
+        Err(errors) => {
+          self.errors.extend(errors);
+          return Ok(());
+        }
+      };
+      sourcemap_chain.extend(stripped.map);
+      const_enum_members = stripped.const_enums;
+      let source: Arc<str> = stripped.code.into();
+      ast = parse_to_ast(Loader::Js, Arc::clone(&source))?;
+      source
+    } else {
+      source
+    };
+
+    // `import.meta.glob(...)` is expanded to imports of matched modules, then the module is parsed again.
+    let import_globs = find_import_globs(ast.program());
+    let source = if import_globs.is_empty() {
+      source
+    } else {
+      let source: Arc<str> = expand_import_globs(
+        &self.ctx.fs,
+        ast.source(),
+        &import_globs,
+        Path::new(split_query(&self.resolved_path.path).0),
+        &self.ctx.input_options.cwd,
+      )?
+      .into();
+      ast = parse_to_ast(Loader::Js, Arc::clone(&source))?;
+      source
+    };
+
+    replace_defines(&mut ast, &self.ctx.input_options.define);
+    let legal_comments = if matches!(self.ctx.input_options.legal_comments, LegalComments::None) {
+      vec![]
+    } else {
+      typescript_legal_comments.unwrap_or_else(|| extract_legal_comments(&ast))
+    };
+    // The filename exported by an asset module might be redirected to another file with the same content later, unless
+    // plugins have changed it.
+    let exported_asset_filename = asset_filename.and_then(|filename| {
+      Some((find_exported_string_literal(ast.program(), &filename)?, filename))
+    });
     tweak_ast_for_scanning(&mut ast);
 
-    let (scope, scan_result, ast_symbol, namespace_object_ref) = self.scan(&mut ast, &source);
+    let (scope, mut scan_result, ast_symbol, namespace_object_ref) = self.scan(&mut ast, &source);
+    self.record_profile("parse", parse_start);
+    // `const enum`s are compiled into variables of the same names.
+    let const_enums = const_enum_members
+      .into_iter()
+      .filter_map(|(name, members)| Some((scope.get_root_binding(&name)?, members)))
+      .collect();
+
+    let resolve_start = self.profile_start();
+    let (mut resolved_deps, new_resolutions) =
+      self.resolve_dependencies(&mut scan_result.import_records, &mut warnings).await?;
+    self.record_profile("resolve", resolve_start);
+    if let (Some(cache), Some(entry)) =
+      (&self.ctx.build_state.persistent_cache, self.pending_cache_entry.take())
+    {
+      cache.insert(&self.resolved_path.path, entry, new_resolutions);
+    }
+
+    // Modules imported by `import()` are replaced with proxy modules until they are requested by the app.
+    if let Some(lazy_compilation) = &self.ctx.build_state.lazy_compilation {
+      for (record, info) in scan_result.import_records.iter().zip(resolved_deps.iter_mut()) {
+        if !matches!(record.kind, ImportKind::DynamicImport)
+          || info.is_external
+          || info.path.ignored
+        {
+          continue;
+        }
+        let stable_id =
+          ResourceId::new(info.path.path.clone()).stabilize(&self.ctx.input_options.cwd);
+        if !lazy_compilation.is_requested(&stable_id) {
+          *info = ResolvedRequestInfo {
+            path: lazy_proxy_id(&info.path.path).into(),
+            module_type: ModuleType::Unknown,
+            is_external: false,
+            package_json: None,
+            side_effects: None,
+          };
+        }
+      }
+    }
 
-    let resolved_deps =
-      self.resolve_dependencies(&scan_result.import_records, &mut warnings).await?;
+    if matches!(self.unresolved_require(), UnresolvedRequire::Error) {
+      for span in &scan_result.non_literal_requires {
+        self.errors.push(BuildError::unsupported_dynamic_require(
+          self.resolved_path.path.to_string(),
+          Arc::clone(&source),
+          *span,
+        ));
+      }
+    }
 
     let ScanResult {
       named_imports,
@@ -124,23 +341,61 @@ impl NormalModuleTask {
       exports_kind,
       repr_name,
       warnings: scan_warnings,
+      errors: scan_errors,
+      node_globals,
+      dynamic_requires,
+      non_literal_requires: _,
+      new_url_references,
+      top_level_this_spans,
+      top_level_await_span,
+      import_meta_span,
     } = scan_result;
-    warnings.extend(scan_warnings);
+    let is_eval_warning_excluded = self
+      .ctx
+      .eval_warning_exclude
+      .iter()
+      .any(|pattern| pattern.matches_path(Path::new(split_query(&self.resolved_path.path).0)));
+    warnings.extend(
+      scan_warnings
+        .into_iter()
+        .filter(|warning| !(is_eval_warning_excluded && warning.kind() == EventKind::Eval)),
+    );
+    self.errors.extend(scan_errors);
+    // Errors are sent instead of the module by `run`, since the loader expects one message per task.
+    if !self.errors.is_empty() {
+      return Ok(());
+    }
+
+    // Assets referenced by `new URL('./foo.png', import.meta.url)` are always emitted as files.
+    let mut asset_urls = FxHashMap::default();
+    if let Some((span, filename)) = exported_asset_filename {
+      asset_urls.insert(span, filename);
+    }
+    let importer_dir = Path::new(split_query(&self.resolved_path.path).0)
+      .parent()
+      .expect("Module should be located in a directory");
+    for (span, request) in new_url_references {
+      let path = importer_dir.join(request.as_str()).normalize();
+      // Leave the expression as it is if the file doesn't exist. It might be served by other means.
+      let Ok(content) = self.ctx.fs.read(&path) else {
+        continue;
+      };
+      let filename = render_asset_filename(&self.ctx.input_options, &path, &content);
+      asset_urls.insert(span, filename.clone());
+      assets.push((filename, content));
+    }
 
     let mut imported_ids = vec![];
     let mut dynamically_imported_ids = vec![];
 
     for (record, info) in import_records.iter().zip(&resolved_deps) {
       if record.kind.is_static() {
-        imported_ids.push(Arc::clone(&info.path.path).into());
+        imported_ids.push(info.path.path.clone().into());
       } else {
-        dynamically_imported_ids.push(Arc::clone(&info.path.path).into());
+        dynamically_imported_ids.push(info.path.path.clone().into());
       }
     }
 
-    let resource_id = ResourceId::new(Arc::clone(&self.resolved_path.path));
-    let stable_resource_id = resource_id.stabilize(&self.ctx.input_options.cwd);
-
     // The side effects priority is:
     // 1. Hook side effects
     // 2. Package.json side effects
@@ -154,7 +409,9 @@ impl NormalModuleTask {
           p.check_side_effects_for(&stable_resource_id).map(DeterminedSideEffects::UserDefined)
         })
         .unwrap_or_else(|| {
-          let analyzed_side_effects = stmt_infos.iter().any(|stmt_info| stmt_info.side_effect);
+          // Importing a stylesheet is a side effect by itself.
+          let analyzed_side_effects =
+            css.is_some() || stmt_infos.iter().any(|stmt_info| stmt_info.side_effect);
           DeterminedSideEffects::Analyzed(analyzed_side_effects)
         })
     };
@@ -185,8 +442,18 @@ impl NormalModuleTask {
       exports_kind,
       namespace_object_ref,
       module_type: self.module_type,
-      debug_resource_id: self.resolved_path.debug_display(&self.ctx.input_options.cwd),
+      debug_resource_id,
       sourcemap_chain,
+      legal_comments,
+      node_globals,
+      dynamic_requires,
+      const_enums,
+      top_level_this_spans: top_level_this_spans.into_iter().collect(),
+      top_level_await_span,
+      import_meta_span,
+      asset_urls,
+      css,
+      html,
       exec_order: u32::MAX,
       is_user_defined_entry: self.is_user_defined_entry,
       import_records: IndexVec::default(),
@@ -196,6 +463,7 @@ impl NormalModuleTask {
       imported_ids,
       dynamically_imported_ids,
       side_effects,
+      package_json: self.package_json.clone(),
     };
 
     self.ctx.plugin_driver.module_parsed(Arc::new(module.to_module_info())).await?;
@@ -211,19 +479,316 @@ impl NormalModuleTask {
         module,
         raw_import_records: import_records,
         ast,
+        assets,
       }))
       .await
       .expect("Send should not fail");
     Ok(())
   }
 
+  /// The start of work on the module measured with `profile`, like `Bundler::stage_start`.
+  fn profile_start(&self) -> Option<Instant> {
+    self.ctx.build_state.profiler.as_ref().map(|_| Instant::now())
+  }
+
+  fn record_profile(&self, name: &'static str, start: Option<Instant>) {
+    if let (Some(profiler), Some(start)) = (&self.ctx.build_state.profiler, start) {
+      profiler.record("module", name, start);
+    }
+  }
+
+  /// The options of `stdin`, if the module is the entry of it.
+  fn stdin(&self) -> Option<&NormalizedStdin> {
+    self.ctx.input_options.stdin.as_ref().filter(|stdin| stdin.id == *self.resolved_path.path)
+  }
+
+  /// How to handle requires of the module that can't be resolved or whose argument isn't a string literal. Unless
+  /// `unresolvedRequire` is set, they're kept in dependencies, which often require optional packages that may be
+  /// missing, and are errors elsewhere.
+  fn unresolved_require(&self) -> UnresolvedRequire {
+    self.ctx.input_options.unresolved_require.unwrap_or_else(|| {
+      let is_dependency = self
+        .resolved_path
+        .path
+        .as_path()
+        .components()
+        .any(|component| component.as_os_str() == "node_modules");
+      if is_dependency {
+        UnresolvedRequire::Keep
+      } else {
+        UnresolvedRequire::Error
+      }
+    })
+  }
+
+  /// Asset modules are emitted as they are. Importers get the public URL of the asset, which replaces `source`.
+  /// Small assets are inlined as `data:` URLs instead. `?url` and `?inline` force either way. Returns the filename and
+  /// the content of the asset to emit, if any.
+  async fn asset_to_esm(
+    &self,
+    loader: Loader,
+    content: Vec<u8>,
+    source: &mut String,
+  ) -> Result<Option<(String, Vec<u8>)>> {
+    let (file, query) = split_query(&self.resolved_path.path);
+    let path = Path::new(file);
+    // WebAssembly modules are instantiated by a generated module. The `.wasm` file is emitted through
+    // `new URL(..., import.meta.url)` in it.
+    if matches!(loader, Loader::Wasm) {
+      let file_name = path.file_name().map(|name| name.to_string_lossy());
+      *source = wasm_to_esm(
+        file_name.as_deref().unwrap_or(file),
+        &content,
+        matches!(self.ctx.input_options.platform, Platform::Node),
+      )?;
+      return Ok(None);
+    }
+    let should_inline = match query {
+      Some("inline") => true,
+      Some("url") => false,
+      _ => self.ctx.input_options.assets_inline_limit.should_inline(file, &content).await?,
+    };
+    if should_inline {
+      *source = text_to_esm(&to_data_url(path, &content))?;
+      Ok(None)
+    } else {
+      let filename = render_asset_filename(&self.ctx.input_options, path, &content);
+      *source = text_to_esm(&filename)?;
+      Ok(Some((filename, content)))
+    }
+  }
+
+  /// Run the `load` and `transform` hooks, and turn assets into modules exporting their URLs.
+  async fn load_and_transform(&mut self, loader: Loader) -> Result<LoadedModule> {
+    // Proxy modules of `lazyCompilation` don't go through plugins.
+    if let Some(target) = lazy_proxy_target(&self.resolved_path.path) {
+      let stable_target_id = ResourceId::new(target).stabilize(&self.ctx.input_options.cwd);
+      return Ok(LoadedModule {
+        source: lazy_proxy_source(&stable_target_id).into(),
+        sourcemap_chain: vec![],
+        side_effects: None,
+        assets: vec![],
+        asset_filename: None,
+      });
+    }
+
+    let mut hook_side_effects = self.side_effects.take();
+    let mut sourcemap_chain = vec![];
+    let mut asset_source = None;
+
+    // The code of `stdin` is given, so the `load` hook is skipped, but the `transform` hook still runs.
+    // Run plugin load to get content first, if it is None using read fs as fallback.
+    let mut source = match self.stdin() {
+      Some(stdin) => stdin.code.clone(),
+      None => {
+        load_source(
+          &self.ctx.plugin_driver,
+          &self.resolved_path,
+          &self.ctx.fs,
+          loader,
+          &mut sourcemap_chain,
+          &mut hook_side_effects,
+          &mut asset_source,
+        )
+        .await?
+      }
+    };
+
+    // Chain the sourcemap generated by tools run before bundling, unless the load hook has returned one.
+    if self.ctx.input_options.sourcemap.is_some()
+      && sourcemap_chain.is_empty()
+      && matches!(loader, Loader::Js)
+    {
+      let file = split_query(&self.resolved_path.path).0;
+      if let Some(map) = load_input_sourcemap(&source, Path::new(file), &self.ctx.fs) {
+        sourcemap_chain.push(map);
+      }
+    }
+
+    let asset = match asset_source {
+      Some(content) => self.asset_to_esm(loader, content, &mut source).await?,
+      None => None,
+    };
+    let asset_filename = asset.as_ref().map(|(filename, _)| filename.clone());
+    let assets = asset.into_iter().collect::<Vec<_>>();
+
+    // Run plugin transform, unless the result is found in caches.
+    let source: Arc<str> = match self.find_cached_transform(&source).await? {
+      Some(cached) => {
+        sourcemap_chain.extend(cached.sourcemap_chain);
+        if cached.side_effects.is_some() {
+          hook_side_effects = cached.side_effects;
+        }
+        cached.code.into()
+      }
+      None => {
+        let build_state = &self.ctx.build_state;
+        let original_source = (build_state.persistent_cache.is_some()
+          || build_state.transform_cache.is_some())
+        .then(|| source.clone());
+        let sourcemap_count = sourcemap_chain.len();
+        let source = transform_source(
+          &self.ctx.plugin_driver,
+          &self.resolved_path,
+          source,
+          &mut sourcemap_chain,
+          &mut hook_side_effects,
+        )
+        .await?;
+        if let Some(original_source) = original_source {
+          self.store_transform(
+            original_source,
+            &source,
+            &sourcemap_chain[sourcemap_count..],
+            hook_side_effects.as_ref(),
+          );
+        }
+        source.into()
+      }
+    };
+
+    // Maps of the `load` and `transform` hooks are only kept if chunks are rendered with sourcemaps. They are
+    // collapsed into one here, so renders, including rebuilds reusing the module in watch mode, only collapse it with
+    // the map of the rendered module.
+    let sourcemap_chain = match self.ctx.input_options.sourcemap {
+      None => vec![],
+      // Keep the chain as is if it couldn't be collapsed, the render collapses it again anyway.
+      Some(_) if sourcemap_chain.len() > 1 => collapse_sourcemaps(sourcemap_chain.iter().collect())
+        .map_or(sourcemap_chain, |sourcemap| vec![sourcemap]),
+      Some(_) => sourcemap_chain,
+    };
+
+    Ok(LoadedModule {
+      source,
+      sourcemap_chain,
+      side_effects: hook_side_effects,
+      assets,
+      asset_filename,
+    })
+  }
+
+  /// Look up the result of the `transform` hook for the loaded code in the cache passed to the bundler, then in the
+  /// persistent cache. Plugins could still ask for transforming with `shouldTransformCachedModule`. Files added by
+  /// `this.addWatchFile` while transforming are watched again if the result is used.
+  async fn find_cached_transform(
+    &mut self,
+    source: &str,
+  ) -> anyhow::Result<Option<CachedTransform>> {
+    let id = &self.resolved_path.path;
+    let build_state = &self.ctx.build_state;
+    let cached = build_state
+      .transform_cache
+      .as_ref()
+      .and_then(|cache| cache.get(id, source))
+      .map(|cached| (cached, vec![]))
+      .or_else(|| {
+        let hit = build_state.persistent_cache.as_ref()?.get(id, source)?;
+        Some((hit.transform, hit.resolutions))
+      });
+    let Some((cached, resolutions)) = cached else {
+      return Ok(None);
+    };
+    let args = HookShouldTransformCachedModuleArgs { id, code: &cached.code };
+    if self.ctx.plugin_driver.should_transform_cached_module(&args).await? {
+      return Ok(None);
+    }
+    for file in &cached.transform_dependencies {
+      self.ctx.plugin_driver.add_module_watch_file(id, file);
+    }
+    self.cached_resolutions = resolutions;
+    Ok(Some(cached))
+  }
+
+  fn store_transform(
+    &mut self,
+    original_source: String,
+    code: &str,
+    sourcemap_chain: &[SourceMap],
+    side_effects: Option<&HookSideEffects>,
+  ) {
+    let id = &self.resolved_path.path;
+    let build_state = &self.ctx.build_state;
+    let transform_dependencies = self.ctx.plugin_driver.watch_files_of(id);
+    if let Some(cache) = &build_state.persistent_cache {
+      self.pending_cache_entry = cache.prepare(
+        &original_source,
+        code,
+        sourcemap_chain,
+        side_effects,
+        &transform_dependencies,
+      );
+    }
+    if let Some(cache) = &build_state.transform_cache {
+      let Some(sourcemaps) = serialize_sourcemaps(sourcemap_chain) else {
+        return;
+      };
+      cache.insert(CachedModule {
+        id: id.to_string(),
+        original_code: original_source,
+        code: code.to_string(),
+        sourcemaps,
+        side_effects: serialize_side_effects(side_effects),
+        transform_dependencies,
+      });
+    }
+  }
+
+  /// Resolve `url(...)` in the stylesheet relative to the module. Referenced files are inlined or emitted according to
+  /// `assetsInlineLimit`. URLs of emitted files are replaced with placeholders, which are rewritten relative to the
+  /// `.css` file while rendering chunks.
+  async fn rewrite_css_urls(
+    &self,
+    css: &str,
+    assets: &mut Vec<(String, Vec<u8>)>,
+  ) -> anyhow::Result<String> {
+    let importer_dir = Path::new(split_query(&self.resolved_path.path).0)
+      .parent()
+      .expect("Module should be located in a directory");
+    let mut output = String::with_capacity(css.len());
+    let mut last = 0;
+    for CssUrl { span, url } in find_css_urls(css) {
+      if !is_local_css_url(&url) {
+        continue;
+      }
+      // Keep the query and the fragment, such as `font.svg#iefix`.
+      let (file, suffix) = url.split_at(url.find(['?', '#']).unwrap_or(url.len()));
+      let path = importer_dir.join(file).normalize();
+      // Leave the URL as it is if the file doesn't exist. It might be served by other means.
+      let Ok(content) = self.ctx.fs.read(&path) else {
+        continue;
+      };
+      let should_inline = suffix.is_empty()
+        && self
+          .ctx
+          .input_options
+          .assets_inline_limit
+          .should_inline(&path.to_string_lossy(), &content)
+          .await?;
+      let new_url = if should_inline {
+        to_data_url(&path, &content)
+      } else {
+        let filename = render_asset_filename(&self.ctx.input_options, &path, &content);
+        let placeholder = css_asset_placeholder(&filename);
+        assets.push((filename, content));
+        format!("{placeholder}{suffix}")
+      };
+      output.push_str(&css[last..span.start]);
+      output.push_str(&format!("url(\"{new_url}\")"));
+      last = span.end;
+    }
+    output.push_str(&css[last..]);
+    Ok(output)
+  }
+
   fn scan(
     &self,
     ast: &mut OxcAst,
     source: &Arc<str>,
   ) -> (AstScopes, ScanResult, AstSymbols, SymbolRef) {
     let (ast_scopes, mut ast_symbols) = make_ast_scopes_and_symbols(ast);
-    let file_path: ResourceId = Arc::<str>::clone(&self.resolved_path.path).into();
+    // Nodes are dropped once references are resolved, so calls of a local `console` are kept.
+    drop_ast_nodes(ast, &self.ctx.input_options.drop, &ast_scopes);
+    let file_path: ResourceId = self.resolved_path.path.clone().into();
     let repr_name = file_path.as_path().representative_file_name();
 
     let scanner = AstScanner::new(
@@ -235,6 +800,8 @@ impl NormalModuleTask {
       source,
       &file_path,
       &ast.trivias,
+      &self.ctx.input_options.pure,
+      &self.ctx.dynamic_require_targets,
     );
     let namespace_object_ref = scanner.namespace_object_ref;
     let scan_result = scanner.scan(ast.program());
@@ -242,6 +809,9 @@ impl NormalModuleTask {
     (ast_scopes, scan_result, ast_symbols, namespace_object_ref)
   }
 
+  /// Resolve the specifier with `resolveId` hooks and the resolver, unless `cached` is found in the persistent cache.
+  /// `external` is checked either way. New resolutions are returned as well with `cacheDir`, before `external` is
+  /// checked, so they could be kept in the persistent cache.
   #[allow(clippy::option_if_let_else)]
   pub(crate) async fn resolve_id(
     input_options: &SharedOptions,
@@ -250,22 +820,37 @@ impl NormalModuleTask {
     importer: &str,
     specifier: &str,
     options: HookResolveIdExtraOptions,
-  ) -> anyhow::Result<Result<ResolvedRequestInfo, ResolveError>> {
+    cached: Option<ResolvedRequestInfo>,
+  ) -> anyhow::Result<(Result<ResolvedRequestInfo, ResolveError>, Option<CachedResolution>)> {
     // Check external with unresolved path
     if let Some(is_external) = input_options.external.as_ref() {
       if is_external(specifier, Some(importer), false).await? {
-        return Ok(Ok(ResolvedRequestInfo {
+        let mut resolved_id = ResolvedRequestInfo {
           path: specifier.to_string().into(),
           module_type: ModuleType::Unknown,
           is_external: true,
           package_json: None,
           side_effects: None,
-        }));
+        };
+        Self::normalize_node_builtin(input_options, &mut resolved_id);
+        return Ok((Ok(resolved_id), None));
       }
     }
 
-    let resolved_id =
-      resolve_id(resolver, plugin_driver, specifier, Some(importer), options).await?;
+    let kind = options.kind;
+    let (resolved_id, new_resolution) = match cached {
+      Some(cached) => (Ok(cached), None),
+      None => {
+        let resolved_id =
+          resolve_id(resolver, plugin_driver, specifier, Some(importer), options).await?;
+        let new_resolution = resolved_id
+          .as_ref()
+          .ok()
+          .filter(|_| input_options.cache_dir.is_some())
+          .map(|info| CachedResolution::new(specifier, kind, info));
+        (resolved_id, new_resolution)
+      }
+    };
 
     match resolved_id {
       Ok(mut resolved_id) => {
@@ -275,25 +860,49 @@ impl NormalModuleTask {
             resolved_id.is_external = is_external(specifier, Some(importer), true).await?;
           }
         }
-        Ok(Ok(resolved_id))
+        Self::normalize_node_builtin(input_options, &mut resolved_id);
+        Ok((Ok(resolved_id), new_resolution))
       }
-      Err(e) => Ok(Err(e)),
+      Err(e) => Ok((Err(e), None)),
+    }
+  }
+
+  /// `fs` and `node:fs` are the same builtin module on Node.js, so they're imported by the same specifier if
+  /// `nodeProtocol` is set.
+  fn normalize_node_builtin(input_options: &SharedOptions, resolved_id: &mut ResolvedRequestInfo) {
+    let Some(with_prefix) = input_options.node_protocol else {
+      return;
+    };
+    if resolved_id.is_external
+      && matches!(input_options.platform, Platform::Node)
+      && is_node_builtin(&resolved_id.path.path)
+    {
+      resolved_id.path = node_builtin_specifier(&resolved_id.path.path, with_prefix).into();
     }
   }
 
+  /// Resolve imports of the module, returning new resolutions to keep in the persistent cache as well.
   async fn resolve_dependencies(
     &mut self,
-    dependencies: &IndexVec<ImportRecordId, RawImportRecord>,
+    dependencies: &mut IndexVec<ImportRecordId, RawImportRecord>,
     warnings: &mut Vec<BuildError>,
-  ) -> Result<IndexVec<ImportRecordId, ResolvedRequestInfo>> {
+  ) -> Result<(IndexVec<ImportRecordId, ResolvedRequestInfo>, Vec<CachedResolution>)> {
     let jobs = dependencies.iter_enumerated().map(|(idx, item)| {
       let specifier = item.module_request.clone();
+      let cached = self.ctx.build_state.persistent_cache.as_ref().and_then(|cache| {
+        let resolution = self
+          .cached_resolutions
+          .iter()
+          .find(|resolution| resolution.matches(&specifier, item.kind))?;
+        cache.restore_resolution(resolution)
+      });
       let input_options = Arc::clone(&self.ctx.input_options);
       // FIXME(hyf0): should not use `Arc<Resolver>` here
       let resolver = Arc::clone(&self.ctx.resolver);
       let plugin_driver = Arc::clone(&self.ctx.plugin_driver);
       let importer = self.resolved_path.clone();
       let kind = item.kind;
+      let attributes = item.attributes.clone();
       async move {
         Self::resolve_id(
           &input_options,
@@ -301,10 +910,11 @@ impl NormalModuleTask {
           &plugin_driver,
           &importer.path,
           &specifier,
-          HookResolveIdExtraOptions { is_entry: false, kind },
+          HookResolveIdExtraOptions { is_entry: false, kind, attributes },
+          cached,
         )
         .await
-        .map(|id| (specifier, idx, id))
+        .map(|(id, new_resolution)| (specifier, idx, id, new_resolution))
       }
     });
 
@@ -312,8 +922,11 @@ impl NormalModuleTask {
 
     let mut ret = IndexVec::with_capacity(dependencies.len());
     let mut build_errors = vec![];
+    let mut unresolved_ids = vec![];
+    let mut new_resolutions = vec![];
     for resolved_id in resolved_ids {
-      let (specifier, idx, resolved_id) = resolved_id?;
+      let (specifier, idx, resolved_id, new_resolution) = resolved_id?;
+      new_resolutions.extend(new_resolution);
 
       match resolved_id {
         Ok(info) => {
@@ -321,14 +934,36 @@ impl NormalModuleTask {
         }
         Err(e) => match &e {
           ResolveError::NotFound(..) => {
-            warnings.push(
-              BuildError::unresolved_import_treated_as_external(
-                specifier.to_string(),
-                self.resolved_path.path.to_string(),
-                Some(e),
-              )
-              .with_severity_warning(),
-            );
+            let is_require = matches!(dependencies[idx].kind, ImportKind::Require);
+            // Like esbuild, builtin modules of Node.js are errors on other platforms, unless they're stubbed.
+            let is_unavailable_builtin = !matches!(self.ctx.input_options.platform, Platform::Node)
+              && is_node_builtin(&specifier);
+            match self.unresolved_require() {
+              UnresolvedRequire::Stub if is_require => {}
+              _ if is_unavailable_builtin => {
+                self.errors.push(BuildError::unresolved_node_builtin(
+                  specifier.to_string(),
+                  self.resolved_path.path.as_path(),
+                ));
+              }
+              UnresolvedRequire::Error if is_require => {
+                self.errors.push(BuildError::unresolved_import(
+                  specifier.to_string(),
+                  self.resolved_path.path.as_path(),
+                ));
+              }
+              _ => {
+                warnings.push(
+                  BuildError::unresolved_import_treated_as_external(
+                    specifier.to_string(),
+                    self.resolved_path.path.to_string(),
+                    Some(e),
+                  )
+                  .with_severity_warning(),
+                );
+              }
+            }
+            unresolved_ids.push(idx);
             ret.push(ResolvedRequestInfo {
               path: specifier.to_string().into(),
               module_type: ModuleType::Unknown,
@@ -344,14 +979,199 @@ impl NormalModuleTask {
       }
     }
 
-    if build_errors.is_empty() {
-      Ok(ret)
-    } else {
+    if !build_errors.is_empty() {
       let resolved_err = anyhow::format_err!(
         "Unexpectedly failed to resolve dependencies of {importer}. Got errors {build_errors:#?}",
         importer = self.resolved_path.path,
       );
-      Err(resolved_err)
+      return Err(resolved_err);
+    }
+
+    for idx in unresolved_ids {
+      dependencies[idx].meta.insert(ImportRecordMeta::IS_UNRESOLVED);
     }
+    Ok((ret, new_resolutions))
+  }
+}
+
+/// Find `export default "..."` exporting the given string, and return the span of the string literal.
+fn find_exported_string_literal(program: &Program, value: &str) -> Option<Span> {
+  program.body.iter().find_map(|stmt| match stmt {
+    Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+      ExportDefaultDeclarationKind::StringLiteral(literal) if literal.value == value => {
+        Some(literal.span)
+      }
+      _ => None,
+    },
+    _ => None,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_max_parallel_file_ops() {
+    use std::{
+      borrow::Cow,
+      sync::atomic::{AtomicUsize, Ordering},
+      time::Duration,
+    };
+
+    use rolldown_common::BundlerOptions;
+    use rolldown_plugin::{HookLoadArgs, HookLoadReturn, Plugin, SharedPluginContext};
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    /// Takes a while to load each module, and records the most modules loaded at the same time.
+    #[derive(Debug, Default)]
+    struct SlowLoad {
+      loading: AtomicUsize,
+      max_loading: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Plugin for SlowLoad {
+      fn name(&self) -> Cow<'static, str> {
+        "slow-load".into()
+      }
+
+      async fn load(&self, _ctx: &SharedPluginContext, _args: &HookLoadArgs) -> HookLoadReturn {
+        let loading = self.loading.fetch_add(1, Ordering::SeqCst) + 1;
+        self.max_loading.fetch_max(loading, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.loading.fetch_sub(1, Ordering::SeqCst);
+        Ok(None)
+      }
+    }
+
+    let modules = (0..8).map(|i| format!("dep_{i}.js")).collect::<Vec<_>>();
+    let main = modules.iter().map(|module| format!("import './{module}';")).collect::<Vec<_>>();
+    let main = main.join("\n");
+    let deps =
+      modules.iter().map(|module| format!("console.log('{module}');\n")).collect::<Vec<_>>();
+    let mut files = vec![("main.js", main.as_str())];
+    files.extend(modules.iter().map(String::as_str).zip(deps.iter().map(String::as_str)));
+    let max_loading = |max_parallel_file_ops: Option<usize>| {
+      let plugin = SlowLoad::default();
+      let max_loading = Arc::clone(&plugin.max_loading);
+      let builder = memory_bundler(
+        &memory_fs(&files),
+        BundlerOptions { max_parallel_file_ops, ..Default::default() },
+      );
+      let mut bundler = builder.with_plugins(vec![Box::new(plugin)]).build();
+      async move {
+        let output = bundler.generate().await.unwrap();
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        max_loading.load(Ordering::SeqCst)
+      }
+    };
+
+    assert!(max_loading(None).await > 2);
+    assert_eq!(max_loading(Some(2)).await, 2);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_module_sourcemap_chain() {
+    use std::{
+      borrow::Cow,
+      sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+      },
+    };
+
+    use rolldown_common::{BundlerOptions, SourceMapType};
+    use rolldown_plugin::{
+      HookLoadArgs, HookLoadOutput, HookLoadReturn, HookTransformArgs, HookTransformReturn, Plugin,
+      SharedPluginContext, TransformPluginContext,
+    };
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    /// Loads `main.js` from a template, and prepends a line to it when transforming. Both hooks return maps.
+    #[derive(Debug, Default)]
+    struct Template {
+      transformed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait::async_trait]
+    impl Plugin for Template {
+      fn name(&self) -> Cow<'static, str> {
+        "template".into()
+      }
+
+      async fn load(&self, _ctx: &SharedPluginContext, args: &HookLoadArgs) -> HookLoadReturn {
+        Ok((args.id == "/project/main.js").then(|| HookLoadOutput {
+        code: "console.log('main');\n".to_string(),
+        map: Some(
+          SourceMap::from_json_string(
+            r#"{"version":3,"sources":["/project/template.tpl"],"sourcesContent":["log main"],"names":[],"mappings":"AAAA"}"#,
+          )
+          .unwrap(),
+        ),
+        side_effects: None,
+      }))
+      }
+
+      async fn transform(
+        &self,
+        _ctx: &TransformPluginContext<'_>,
+        args: &HookTransformArgs,
+      ) -> HookTransformReturn {
+        self.transformed.fetch_add(1, Ordering::SeqCst);
+        Ok(Some(HookLoadOutput {
+          code: format!("console.log('banner');\n{}", args.code),
+          map: Some(
+            SourceMap::from_json_string(
+              r#"{"version":3,"sources":["main.js"],"names":[],"mappings":";AAAA"}"#,
+            )
+            .unwrap(),
+          ),
+          side_effects: None,
+        }))
+      }
+    }
+
+    let transformed = Arc::new(AtomicUsize::new(0));
+    let cache = Arc::new(Mutex::new(None));
+    // Returns the emitted `.map` file, and keeps the transform cache for the next run.
+    let run = |sourcemap: Option<SourceMapType>| {
+      let plugin = Template { transformed: Arc::clone(&transformed) };
+      let builder = memory_bundler(
+        &memory_fs(&[("main.js", "")]),
+        BundlerOptions { sourcemap, ..Default::default() },
+      );
+      let mut bundler = builder
+        .with_plugins(vec![Box::new(plugin)])
+        .with_cache(cache.lock().unwrap().take().unwrap_or_default())
+        .build();
+      let cache = Arc::clone(&cache);
+      async move {
+        let output = bundler.generate().await.unwrap();
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        *cache.lock().unwrap() = bundler.cache();
+        output
+          .assets
+          .iter()
+          .find(|asset| asset.filename() == "main.js.map")
+          .map(|map| String::from_utf8(map.content_as_bytes().to_vec()).unwrap())
+      }
+    };
+
+    // Without sourcemaps, the hook maps are dropped, but still stored in the transform cache.
+    assert_eq!(run(None).await, None);
+    assert_eq!(transformed.swap(0, Ordering::SeqCst), 1);
+
+    // A bundler rendering sourcemaps reuses the cached transform map, and maps the code back through both hooks.
+    let cached_map = run(Some(SourceMapType::File)).await.unwrap();
+    assert_eq!(transformed.swap(0, Ordering::SeqCst), 0);
+    let map: serde_json::Value = serde_json::from_str(&cached_map).unwrap();
+    assert_eq!(map["sources"], serde_json::json!(["../template.tpl"]));
+
+    *cache.lock().unwrap() = None;
+    assert_eq!(run(Some(SourceMapType::File)).await.unwrap(), cached_map);
+    assert_eq!(transformed.load(Ordering::SeqCst), 1);
   }
 }
@@ -0,0 +1,337 @@
+use std::{path::PathBuf, sync::Arc};
+
+use rolldown_common::{
+  side_effects::HookSideEffects, ImportKind, ModuleType, PackageJson, Platform, ResolvedPath,
+  ResolvedRequestInfo,
+};
+use rolldown_fs::{FileSystem, SharedFileSystem};
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::xxhash::xxhash_base64_url;
+use serde::{Deserialize, Serialize};
+
+use crate::utils::module_query::split_query;
+
+use super::bundle_cache::{
+  deserialize_side_effects, serialize_side_effects, serialize_sourcemaps, CachedTransform,
+};
+
+pub type SharedPersistentCache = Arc<PersistentCache>;
+
+/// Bumped whenever the format of entries changes.
+const CACHE_FORMAT_VERSION: u32 = 2;
+
+/// Results of the `transform` hook kept on disk across builds with `cacheDir`, along with resolutions of the imports of
+/// the transformed code. Each module has its own file, named by the hash of the module id and the hash of things
+/// affecting all modules, such as the version, the plugins and their options, and the resolve options. An entry is used
+/// only if the code before `transform` and the files added by `this.addWatchFile` in `transform` hooks are the same as
+/// when it was written.
+pub struct PersistentCache {
+  dir: PathBuf,
+  options_hash: String,
+  fs: SharedFileSystem,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+  /// Hash of the code before `transform`
+  original_hash: String,
+  code: String,
+  /// Sourcemaps in JSON
+  sourcemaps: Vec<String>,
+  side_effects: Option<String>,
+  /// Files added by `this.addWatchFile` in `transform` hooks, and hashes of their content, or `None` if they didn't
+  /// exist
+  transform_dependencies: Vec<(String, Option<String>)>,
+  resolutions: Vec<CachedResolution>,
+}
+
+/// What the resolver or `resolveId` hooks returned for an import of the module. `external` options are checked against
+/// it again in every build, since they may be functions.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedResolution {
+  specifier: String,
+  kind: String,
+  id: String,
+  ignored: bool,
+  external: bool,
+  module_type: String,
+  side_effects: Option<String>,
+  /// Path to `package.json` of the package containing the module, which is read again on use
+  package_json: Option<PathBuf>,
+}
+
+impl CachedResolution {
+  pub fn new(specifier: &str, kind: ImportKind, info: &ResolvedRequestInfo) -> Self {
+    Self {
+      specifier: specifier.to_string(),
+      kind: format!("{kind:?}"),
+      id: info.path.path.to_string(),
+      ignored: info.path.ignored,
+      external: info.is_external,
+      module_type: format!("{:?}", info.module_type),
+      side_effects: serialize_side_effects(info.side_effects.as_ref()),
+      package_json: info.package_json.as_ref().map(|package_json| package_json.path.clone()),
+    }
+  }
+
+  pub fn matches(&self, specifier: &str, kind: ImportKind) -> bool {
+    self.specifier == specifier && self.kind == format!("{kind:?}")
+  }
+}
+
+/// An entry waiting for resolutions of the imports of the transformed code before it's written.
+pub struct PendingCacheEntry(CacheEntry);
+
+/// The result of the `transform` hook found in the persistent cache.
+pub struct PersistentCacheHit {
+  pub transform: CachedTransform,
+  pub resolutions: Vec<CachedResolution>,
+}
+
+impl PersistentCache {
+  pub fn new(
+    dir: PathBuf,
+    plugin_keys: &[String],
+    resolve_options: &rolldown_resolver::ResolveOptions,
+    platform: Platform,
+    sourcemap: bool,
+    fs: SharedFileSystem,
+  ) -> Self {
+    let options_key = format!(
+      "{CACHE_FORMAT_VERSION}\0{}\0{}\0{resolve_options:?}\0{platform:?}\0{sourcemap}",
+      env!("CARGO_PKG_VERSION"),
+      plugin_keys.join("\0")
+    );
+    Self { dir, options_hash: xxhash_base64_url(options_key.as_bytes()), fs }
+  }
+
+  pub fn get(&self, id: &str, original_code: &str) -> Option<PersistentCacheHit> {
+    let content = self.fs.read(&self.entry_path(id)).ok()?;
+    let entry: CacheEntry = serde_json::from_slice(&content).ok()?;
+    if entry.original_hash != xxhash_base64_url(original_code.as_bytes()) {
+      return None;
+    }
+    let is_dependency_changed =
+      entry.transform_dependencies.iter().any(|(file, hash)| self.hash_file(file) != *hash);
+    if is_dependency_changed {
+      return None;
+    }
+    let mut transform = CachedTransform::from_serialized(
+      entry.code,
+      &entry.sourcemaps,
+      entry.side_effects.as_deref(),
+    )?;
+    transform.transform_dependencies =
+      entry.transform_dependencies.into_iter().map(|(file, _)| file).collect();
+    Some(PersistentCacheHit { transform, resolutions: entry.resolutions })
+  }
+
+  /// Make an entry of the result of `transform` hooks, which is written once imports of the code are resolved.
+  pub fn prepare(
+    &self,
+    original_code: &str,
+    code: &str,
+    sourcemap_chain: &[SourceMap],
+    side_effects: Option<&HookSideEffects>,
+    transform_dependencies: &[String],
+  ) -> Option<PendingCacheEntry> {
+    Some(PendingCacheEntry(CacheEntry {
+      original_hash: xxhash_base64_url(original_code.as_bytes()),
+      code: code.to_string(),
+      sourcemaps: serialize_sourcemaps(sourcemap_chain)?,
+      side_effects: serialize_side_effects(side_effects),
+      transform_dependencies: transform_dependencies
+        .iter()
+        .map(|file| (file.clone(), self.hash_file(file)))
+        .collect(),
+      resolutions: vec![],
+    }))
+  }
+
+  pub fn insert(&self, id: &str, entry: PendingCacheEntry, resolutions: Vec<CachedResolution>) {
+    let entry = CacheEntry { resolutions, ..entry.0 };
+    let Ok(content) = serde_json::to_vec(&entry) else {
+      return;
+    };
+    if self.fs.create_dir_all(&self.dir).is_ok() {
+      let _ = self.fs.write(&self.entry_path(id), &content);
+    }
+  }
+
+  /// Turn the cached resolution back into the result of resolving, unless the resolved file is gone or its
+  /// `package.json` couldn't be read.
+  pub fn restore_resolution(&self, resolution: &CachedResolution) -> Option<ResolvedRequestInfo> {
+    // Ids of external and virtual modules aren't files.
+    let is_file = !resolution.external && !resolution.ignored && !resolution.id.starts_with('\0');
+    if is_file && !self.fs.is_file(split_query_path(&resolution.id).as_ref()) {
+      return None;
+    }
+    let package_json = match &resolution.package_json {
+      Some(path) => {
+        let raw: serde_json::Value = serde_json::from_slice(&self.fs.read(path).ok()?).ok()?;
+        Some(Arc::new(PackageJson::new(raw, path.clone())))
+      }
+      None => None,
+    };
+    let module_type = match resolution.module_type.as_str() {
+      "CJS" => ModuleType::CJS,
+      "CjsPackageJson" => ModuleType::CjsPackageJson,
+      "EsmMjs" => ModuleType::EsmMjs,
+      "EsmPackageJson" => ModuleType::EsmPackageJson,
+      _ => ModuleType::Unknown,
+    };
+    Some(ResolvedRequestInfo {
+      path: ResolvedPath { path: resolution.id.clone().into(), ignored: resolution.ignored },
+      module_type,
+      is_external: resolution.external,
+      package_json,
+      side_effects: deserialize_side_effects(resolution.side_effects.as_deref()),
+    })
+  }
+
+  fn hash_file(&self, file: &str) -> Option<String> {
+    self.fs.read(split_query_path(file).as_ref()).ok().map(|content| xxhash_base64_url(&content))
+  }
+
+  fn entry_path(&self, id: &str) -> PathBuf {
+    let id_hash = xxhash_base64_url(id.as_bytes());
+    self.dir.join(format!("{}-{id_hash}.json", self.options_hash))
+  }
+}
+
+fn split_query_path(id: &str) -> PathBuf {
+  PathBuf::from(split_query(id).0)
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  #[allow(clippy::too_many_lines)]
+  async fn test_persistent_cache() {
+    use std::{
+      borrow::Cow,
+      path::Path,
+      sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use rolldown_common::BundlerOptions;
+    use rolldown_fs::MemoryFileSystem;
+    use rolldown_plugin::{
+      HookLoadOutput, HookResolveIdArgs, HookResolveIdReturn, HookTransformArgs,
+      HookTransformReturn, Plugin, SharedPluginContext, TransformPluginContext,
+    };
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    /// Prepends a log of its option and the content of `banner.js`, which is watched with `addWatchFile`.
+    struct Banner {
+      version: &'static str,
+      fs: MemoryFileSystem,
+      transforms: Arc<AtomicUsize>,
+      resolves: Arc<AtomicUsize>,
+    }
+
+    // Only the option is part of the cache key.
+    impl std::fmt::Debug for Banner {
+      fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Banner").field("version", &self.version).finish_non_exhaustive()
+      }
+    }
+
+    #[async_trait::async_trait]
+    impl Plugin for Banner {
+      fn name(&self) -> Cow<'static, str> {
+        "banner".into()
+      }
+
+      async fn resolve_id(
+        &self,
+        _ctx: &SharedPluginContext,
+        _args: &HookResolveIdArgs,
+      ) -> HookResolveIdReturn {
+        self.resolves.fetch_add(1, Ordering::SeqCst);
+        Ok(None)
+      }
+
+      async fn transform(
+        &self,
+        ctx: &TransformPluginContext<'_>,
+        args: &HookTransformArgs,
+      ) -> HookTransformReturn {
+        if !args.id.ends_with("main.js") {
+          return Ok(None);
+        }
+        self.transforms.fetch_add(1, Ordering::SeqCst);
+        ctx.add_watch_file("/project/banner.js");
+        let banner = String::from_utf8(self.fs.read(Path::new("/project/banner.js"))?)?;
+        Ok(Some(HookLoadOutput {
+          code: format!("console.log('{}');\n{banner}{}", self.version, args.code),
+          map: None,
+          side_effects: None,
+        }))
+      }
+    }
+
+    let fs = memory_fs(&[
+      ("main.js", "import './dep.js';\n"),
+      ("dep.js", "console.log('dep');\n"),
+      ("banner.js", "console.log('banner');\n"),
+    ]);
+    let transforms = Arc::new(AtomicUsize::default());
+    let resolves = Arc::new(AtomicUsize::default());
+    let build = |version: &'static str| {
+      let plugin = Banner {
+        version,
+        fs: fs.clone(),
+        transforms: Arc::clone(&transforms),
+        resolves: Arc::clone(&resolves),
+      };
+      let mut bundler = memory_bundler(
+        &fs,
+        BundlerOptions { cache_dir: Some(".cache".to_string()), ..Default::default() },
+      )
+      .with_plugins(vec![Box::new(plugin)])
+      .build();
+      async move {
+        let output = bundler.write().await.unwrap();
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        let watch_files = bundler.plugin_driver.watch_files();
+        assert!(watch_files.contains(&"/project/banner.js".to_string()), "{watch_files:?}");
+      }
+    };
+    let output =
+      || String::from_utf8(fs.read(Path::new("/project/dist/main.js")).unwrap()).unwrap();
+
+    // Miss: `main.js` is transformed, and imports are resolved.
+    build("v1").await;
+    assert_eq!(transforms.load(Ordering::SeqCst), 1);
+    let resolves_of_first_build = resolves.load(Ordering::SeqCst);
+    let first_output = output();
+    assert!(first_output.contains("console.log('v1')"), "{first_output}");
+
+    // Hit: nothing is transformed, `./dep.js` isn't resolved again, and `banner.js` is still watched.
+    build("v1").await;
+    assert_eq!(transforms.load(Ordering::SeqCst), 1);
+    assert_eq!(resolves.load(Ordering::SeqCst), resolves_of_first_build + 1);
+    assert_eq!(output(), first_output);
+
+    // Options of plugins are part of the cache key.
+    build("v2").await;
+    assert_eq!(transforms.load(Ordering::SeqCst), 2);
+    assert!(output().contains("console.log('v2')"));
+
+    // Changes of files added by `addWatchFile` invalidate the entry.
+    fs.write(Path::new("/project/banner.js"), b"console.log('new banner');\n").unwrap();
+    build("v2").await;
+    assert_eq!(transforms.load(Ordering::SeqCst), 3);
+    assert!(output().contains("new banner"));
+
+    // So do changes of the module.
+    fs.write(Path::new("/project/main.js"), b"import './dep.js';\nconsole.log('main');\n").unwrap();
+    build("v2").await;
+    assert_eq!(transforms.load(Ordering::SeqCst), 4);
+    assert!(output().contains("console.log('main')"));
+  }
+}
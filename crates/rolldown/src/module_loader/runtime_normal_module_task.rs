@@ -3,11 +3,12 @@ use std::sync::Arc;
 use oxc::span::SourceType;
 use oxc_index::IndexVec;
 use rolldown_common::{
-  side_effects::DeterminedSideEffects, AstScopes, ExportsKind, ModuleType, NormalModule,
-  NormalModuleId, ResourceId, SymbolRef,
+  side_effects::DeterminedSideEffects, AstScopes, ExportsKind, ModuleType, NodeGlobals,
+  NormalModule, NormalModuleId, ResourceId, SymbolRef,
 };
 use rolldown_error::BuildError;
 use rolldown_oxc_utils::{OxcAst, OxcCompiler};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::Msg;
 use crate::{
@@ -55,6 +56,14 @@ impl RuntimeNormalModuleTask {
       import_records: _,
       exports_kind: _,
       warnings: _,
+      errors: _,
+      node_globals: _,
+      dynamic_requires: _,
+      non_literal_requires: _,
+      new_url_references: _,
+      top_level_this_spans: _,
+      top_level_await_span: _,
+      import_meta_span: _,
     } = scan_result;
 
     let module = NormalModule {
@@ -79,11 +88,22 @@ impl RuntimeNormalModuleTask {
       import_records: IndexVec::default(),
       is_included: false,
       sourcemap_chain: vec![],
+      legal_comments: vec![],
+      node_globals: NodeGlobals::empty(),
+      dynamic_requires: FxHashMap::default(),
+      const_enums: FxHashMap::default(),
+      top_level_this_spans: FxHashSet::default(),
+      top_level_await_span: None,
+      import_meta_span: None,
+      asset_urls: FxHashMap::default(),
+      css: None,
+      html: None,
       // The internal runtime module `importers/imported` should be skip.
       importers: vec![],
       dynamic_importers: vec![],
       imported_ids: vec![],
       dynamically_imported_ids: vec![],
+      package_json: None,
       side_effects: DeterminedSideEffects::Analyzed(false),
     };
 
@@ -127,6 +147,8 @@ impl RuntimeNormalModuleTask {
       source,
       &facade_path,
       &ast.trivias,
+      &[],
+      &[],
     );
     let namespace_symbol = scanner.namespace_object_ref;
     let scan_result = scanner.scan(ast.program());
@@ -1,15 +1,25 @@
-use rolldown_fs::OsFileSystem;
+use std::path::PathBuf;
+
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
+use tokio::sync::Semaphore;
 
 use crate::{SharedOptions, SharedResolver};
 
-use super::Msg;
+use super::{build_state::BuildState, Msg};
 
 /// Used to store common data shared between all tasks.
 pub struct TaskContext {
   pub input_options: SharedOptions,
   pub tx: tokio::sync::mpsc::Sender<Msg>,
   pub resolver: SharedResolver,
-  pub fs: OsFileSystem,
+  pub fs: SharedFileSystem,
   pub plugin_driver: SharedPluginDriver,
+  /// Absolute paths of modules matched by `dynamicRequireTargets`.
+  pub dynamic_require_targets: Vec<PathBuf>,
+  /// Modules matched by `evalWarning.exclude`, whose uses of `eval` aren't reported.
+  pub eval_warning_exclude: Vec<glob::Pattern>,
+  pub build_state: BuildState,
+  /// Limits the number of tasks loading modules at the same time with `maxParallelFileOps`.
+  pub file_ops: Option<Semaphore>,
 }
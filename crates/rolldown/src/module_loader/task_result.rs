@@ -15,4 +15,6 @@ pub struct NormalModuleTaskResult {
   pub warnings: Vec<BuildError>,
   pub module: NormalModule,
   pub ast: OxcAst,
+  /// Filenames and contents of assets emitted by the module, including the module itself if it's an asset module.
+  pub assets: Vec<(String, Vec<u8>)>,
 }
@@ -39,7 +39,8 @@ var __copyProps = (to, from, except, desc) => {
 // from the imported module to the current module's ESM export object. If the
 // current module is an entry point and the target format is CommonJS, we
 // also copy the properties to "module.exports" in addition to our module's
-// internal ESM export object.
+// internal ESM export object. Names that are already defined, by explicit
+// exports or by earlier "export * from" statements, are kept as they are.
 var __reExport = (target, mod, secondTarget) => (
     __copyProps(target, mod, 'default'),
     secondTarget && __copyProps(secondTarget, mod, 'default')
@@ -66,3 +67,91 @@ var __toESM = (mod, isNodeMode, target) => (
 // object with the addition of a non-enumerable "__esModule" property set
 // to "true", which overwrites any existing export named "__esModule".
 var __toCommonJS = mod => __copyProps(__defProp({}, '__esModule', { value: true }), mod)
+
+// This is used to implement "require()" calls with runtime-computed paths. The
+// map contains all modules that could be required, which are specified by the
+// "dynamicRequireTargets" option.
+var __dynamicRequire = (map, id) => {
+    if (__hasOwnProp.call(map, id)) return map[id]()
+    throw Error('Could not dynamically require "' + id + '"')
+}
+
+// This is the HMR runtime, which is shared by chunks and updates through a global variable. `import.meta.hot` is
+// rewritten to `__rolldown_hmr__.hot(id)`, and every module registers its exports, so updates could import them.
+var __rolldown_hmr__ = globalThis.__rolldown_hmr__ || (globalThis.__rolldown_hmr__ = (() => {
+    // Functions returning exports of modules, which are registered by chunks and replaced by updates
+    var modules = new Map()
+    // `import.meta.hot` of modules
+    var contexts = new Map()
+    var createContext = (id, data) => {
+        var context = {
+            data: data || {},
+            acceptCallbacks: [],
+            disposeCallbacks: [],
+            accept(callback) {
+                context.acceptCallbacks.push(callback || (() => {}))
+            },
+            dispose(callback) {
+                context.disposeCallbacks.push(callback)
+            },
+        }
+        contexts.set(id, context)
+        return context
+    }
+    var hmr = {
+        hot: (id) => contexts.get(id) || createContext(id),
+        register(id, getExports) {
+            modules.set(id, getExports)
+        },
+        load(id) {
+            if (!modules.has(id)) throw Error('Module "' + id + '" is not loaded')
+            return modules.get(id)()
+        },
+        // `import()` in updates
+        import: (id) => Promise.resolve().then(() => hmr.load(id)),
+        reExport: (target, mod) => __reExport(target, mod),
+        reload() {
+            if (globalThis.location) globalThis.location.reload()
+        },
+        // Apply updates emitted by rebuilds, which are pairs of module ids and functions evaluating the new code of the
+        // modules. If any of the modules doesn't accept updates or couldn't be updated, the page is reloaded instead.
+        applyUpdates(updates) {
+            var accepted = updates.every(([id, factory]) => {
+                var context = contexts.get(id)
+                return factory && context && context.acceptCallbacks.length > 0
+            })
+            if (!accepted) {
+                hmr.reload()
+                return false
+            }
+            // Updated modules could import modules that aren't loaded yet, which is recovered by reloading the page.
+            try {
+                updates.forEach(([id, factory]) => {
+                    var context = contexts.get(id)
+                    var data = {}
+                    context.disposeCallbacks.forEach((callback) => callback(data))
+                    createContext(id, data)
+                    var exports = factory()
+                    modules.set(id, () => exports)
+                    context.acceptCallbacks.forEach((callback) => callback(exports))
+                })
+            } catch (error) {
+                hmr.reload()
+                throw error
+            }
+            return true
+        },
+    }
+    return hmr
+})())
+
+// This is the client of `lazyCompilation`, unless the dev server has defined `globalThis.__rolldown_lazy__` already.
+// Proxy modules call `load(id)` with the id relative to `cwd`. It imports `<endpoint><id>` from the dev server, which
+// should pass the id to `requestLazyCompilation(id)`, wait for the rebuild, and respond with a module re-exporting the
+// chunk of the module.
+var __rolldown_lazy__ = globalThis.__rolldown_lazy__ || (globalThis.__rolldown_lazy__ = {
+    endpoint: '/@rolldown/lazy/',
+    load(id) {
+        return import(globalThis.__rolldown_lazy__.endpoint + encodeURIComponent(id))
+    },
+})
@@ -39,3 +39,73 @@ var __toESM = (mod, isNodeMode, target) => (
     mod)
 )
 var __toCommonJS = mod => __copyProps(__defProp({}, '__esModule', { value: true }), mod)
+var __dynamicRequire = (map, id) => {
+  if (__hasOwnProp.call(map, id)) return map[id]()
+  throw Error('Could not dynamically require "' + id + '"')
+}
+var __rolldown_hmr__ = globalThis.__rolldown_hmr__ || (globalThis.__rolldown_hmr__ = (() => {
+  var modules = new Map()
+  var contexts = new Map()
+  var createContext = (id, data) => {
+    var context = {
+      data: data || {},
+      acceptCallbacks: [],
+      disposeCallbacks: [],
+      accept(callback) {
+        context.acceptCallbacks.push(callback || (() => {}))
+      },
+      dispose(callback) {
+        context.disposeCallbacks.push(callback)
+      },
+    }
+    contexts.set(id, context)
+    return context
+  }
+  var hmr = {
+    hot: (id) => contexts.get(id) || createContext(id),
+    register(id, getExports) {
+      modules.set(id, getExports)
+    },
+    load(id) {
+      if (!modules.has(id)) throw Error('Module "' + id + '" is not loaded')
+      return modules.get(id)()
+    },
+    import: (id) => Promise.resolve().then(() => hmr.load(id)),
+    reExport: (target, mod) => __reExport(target, mod),
+    reload() {
+      if (globalThis.location) globalThis.location.reload()
+    },
+    applyUpdates(updates) {
+      var accepted = updates.every(([id, factory]) => {
+        var context = contexts.get(id)
+        return factory && context && context.acceptCallbacks.length > 0
+      })
+      if (!accepted) {
+        hmr.reload()
+        return false
+      }
+      try {
+        updates.forEach(([id, factory]) => {
+          var context = contexts.get(id)
+          var data = {}
+          context.disposeCallbacks.forEach((callback) => callback(data))
+          createContext(id, data)
+          var exports = factory()
+          modules.set(id, () => exports)
+          context.acceptCallbacks.forEach((callback) => callback(exports))
+        })
+      } catch (error) {
+        hmr.reload()
+        throw error
+      }
+      return true
+    },
+  }
+  return hmr
+})())
+var __rolldown_lazy__ = globalThis.__rolldown_lazy__ || (globalThis.__rolldown_lazy__ = {
+  endpoint: '/@rolldown/lazy/',
+  load(id) {
+    return import(globalThis.__rolldown_lazy__.endpoint + encodeURIComponent(id))
+  },
+})
@@ -0,0 +1,76 @@
+use std::hash::Hash;
+
+use rolldown_common::{Chunk, OutputFormat};
+use rolldown_error::BuildError;
+
+use super::GenerateStage;
+use crate::{
+  chunk_graph::ChunkGraph, stages::link_stage::checks::report_check,
+  utils::import_chains::shortest_import_chains,
+};
+
+impl<'a> GenerateStage<'a> {
+  /// Check for chunks of the `cjs` format importing each other in a cycle, which `checks.circularChunks` is about.
+  /// Modules reachable from the same entries share a chunk, so chunks only import chunks of modules reachable from
+  /// more entries, and cycles don't form for now. The check guards chunking that doesn't follow it.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn check_circular_chunks(&mut self, chunk_graph: &ChunkGraph) {
+    if !matches!(self.options.format, OutputFormat::Cjs) {
+      return;
+    }
+    let chunks = &chunk_graph.chunks;
+    let cycles =
+      import_cycles(chunks.indices(), |importer| chunks[importer].cross_chunk_imports.clone());
+    for cycle in cycles {
+      let names = cycle.iter().map(|id| self.chunk_name(&chunks[*id])).collect();
+      report_check(
+        self.options.checks_circular_chunks,
+        BuildError::circular_chunk(names),
+        &mut self.link_output.warnings,
+        &mut self.link_output.errors,
+      );
+    }
+  }
+
+  /// The name of the chunk, or its representative module if it has no name.
+  pub(super) fn chunk_name(&self, chunk: &Chunk) -> String {
+    chunk.name.clone().unwrap_or_else(|| {
+      self.link_output.module_table.normal_modules[chunk.representative_module()]
+        .stable_resource_id
+        .clone()
+    })
+  }
+}
+
+/// Cycles of imports between `ids`, following ids returned by `imports`. Each cycle is found once, starting from the
+/// smallest id in it, and ends before coming back to it.
+fn import_cycles<Id: Copy + Eq + Hash + Ord>(
+  ids: impl Iterator<Item = Id>,
+  imports: impl Fn(Id) -> Vec<Id>,
+) -> Vec<Vec<Id>> {
+  ids
+    .filter_map(|id| {
+      // Only ids after `id` are followed, so cycles through earlier ids are found from them.
+      let imports = |importer: Id| -> Vec<Id> {
+        imports(importer).into_iter().filter(|imported| *imported >= id).collect()
+      };
+      let cycle =
+        shortest_import_chains(&imports(id), id, imports).into_iter().min_by_key(Vec::len)?;
+      Some(std::iter::once(id).chain(cycle).collect())
+    })
+    .collect()
+}
+
+#[test]
+fn test_import_cycles() {
+  let graph: rustc_hash::FxHashMap<u32, Vec<u32>> = rustc_hash::FxHashMap::from_iter([
+    (0, vec![1]),
+    (1, vec![2]),
+    (2, vec![0, 3]),
+    (3, vec![3]),
+    (4, vec![0]),
+  ]);
+  let imports = |id| graph.get(&id).cloned().unwrap_or_default();
+  assert_eq!(import_cycles(0..5, imports), vec![vec![0, 1, 2, 0], vec![3, 3]]);
+  assert!(import_cycles(0..2, |_| vec![]).is_empty());
+}
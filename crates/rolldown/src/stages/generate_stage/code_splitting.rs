@@ -6,7 +6,7 @@ use rolldown_common::{Chunk, ChunkId, ChunkKind, ImportKind, ModuleId, NormalMod
 use rolldown_utils::{rustc_hash::FxHashMapExt, BitSet};
 use rustc_hash::FxHashMap;
 
-use crate::{chunk_graph::ChunkGraph, type_alias::IndexChunks, utils::is_in_rust_test_mode};
+use crate::{chunk_graph::ChunkGraph, type_alias::IndexChunks};
 
 use super::GenerateStage;
 
@@ -64,9 +64,13 @@ impl<'a> GenerateStage<'a> {
   pub fn generate_chunks(&self) -> ChunkGraph {
     let entries_len: u32 =
       self.link_output.entries.len().try_into().expect("Too many entries, u32 overflowed.");
-    // If we are in test environment, to make the runtime module always fall into a standalone chunk,
-    // we create a facade entry point for it.
-    let entries_len = if is_in_rust_test_mode() { entries_len + 1 } else { entries_len };
+    // If we are in test environment or `runtime_chunk` is enabled, to make the runtime module always fall into a
+    // standalone chunk, we create a facade entry point for it.
+    let entries_len = if self.options.test_mode() || self.options.runtime_chunk() {
+      entries_len + 1
+    } else {
+      entries_len
+    };
 
     let mut module_to_bits = oxc_index::index_vec![BitSet::new(entries_len); self.link_output.module_table.normal_modules.len()];
     let mut bits_to_chunk = FxHashMap::with_capacity(self.link_output.entries.len());
@@ -97,7 +101,7 @@ impl<'a> GenerateStage<'a> {
       }
     }
 
-    if is_in_rust_test_mode() {
+    if self.options.test_mode() {
       self.determine_reachable_modules_for_entry(
         self.link_output.runtime.id(),
         entries_len - 1,
@@ -114,6 +118,14 @@ impl<'a> GenerateStage<'a> {
       );
     });
 
+    if self.options.runtime_chunk() && !self.options.test_mode() {
+      // Unlike in test environment, the runtime chunk is only emitted if some chunks use the runtime.
+      let runtime_bits = &mut module_to_bits[self.link_output.runtime.id()];
+      if !runtime_bits.is_empty() {
+        runtime_bits.set_bit(entries_len - 1);
+      }
+    }
+
     let mut module_to_chunk: IndexVec<NormalModuleId, Option<ChunkId>> = oxc_index::index_vec![
       None;
       self.link_output.module_table.normal_modules.len()
@@ -121,6 +133,8 @@ impl<'a> GenerateStage<'a> {
 
     // 1. Assign modules to corresponding chunks
     // 2. Create shared chunks to store modules that belong to multiple chunks.
+    // The runtime module is no exception. It's reachable from every entry whose chunks use a helper, so helpers are
+    // emitted once and other chunks import them from the chunk of the runtime module.
     for normal_module in &self.link_output.module_table.normal_modules {
       if !normal_module.is_included {
         continue;
@@ -5,12 +5,14 @@ use super::GenerateStage;
 use crate::chunk_graph::ChunkGraph;
 use indexmap::IndexSet;
 use itertools::{multizip, Itertools};
+use oxc::span::SPAN;
 use oxc_index::{index_vec, IndexVec};
 use rolldown_common::{
   ChunkId, ChunkKind, CrossChunkImportItem, ExportsKind, ExternalModuleId, ImportKind, ModuleId,
-  NamedImport, OutputFormat, SymbolRef, WrapKind,
+  NamedImport, OutputFormat, Specifier, SymbolRef, WrapKind,
 };
 use rolldown_rstr::{Rstr, ToRstr};
+use rolldown_utils::ecma_script::legitimize_identifier_name;
 use rolldown_utils::rayon::IntoParallelIterator;
 use rolldown_utils::rayon::{ParallelBridge, ParallelIterator};
 use rolldown_utils::rustc_hash::FxHashMapExt;
@@ -129,6 +131,45 @@ impl<'a> GenerateStage<'a> {
           cross_chunk_dynamic_imports.into_iter().collect::<Vec<_>>();
       },
     );
+
+    if matches!(self.options.format, OutputFormat::Cjs) {
+      self.collect_live_imports_from_other_chunks(chunk_graph);
+    }
+  }
+
+  /// Destructuring `require(...)` of other chunks copies values of symbols in CommonJS output. Symbols reassigned after
+  /// their declarations are read from the `exports` of the chunk on each access instead, so importers see the latest
+  /// values like they do in ES modules.
+  fn collect_live_imports_from_other_chunks(&mut self, chunk_graph: &mut ChunkGraph) {
+    let modules = &self.link_output.module_table.normal_modules;
+    let symbols = &mut self.link_output.symbols;
+    for chunk_id in chunk_graph.chunks.indices() {
+      let chunk = &chunk_graph.chunks[chunk_id];
+      let mut live_imports = FxHashMap::default();
+      for (importee_chunk_id, items) in &chunk.imports_from_other_chunks {
+        let mut namespace_ref = None;
+        for item in items {
+          let canonical_ref = symbols.par_canonical_ref_for(item.import_ref);
+          let Some(Specifier::Literal(export_alias)) = &item.export_alias else {
+            continue;
+          };
+          if !modules[canonical_ref.owner].scope.is_reassigned(canonical_ref.symbol) {
+            continue;
+          }
+          let namespace_ref = *namespace_ref.get_or_insert_with(|| {
+            let importee_chunk = &chunk_graph.chunks[*importee_chunk_id];
+            let importee_name = importee_chunk
+              .name
+              .as_deref()
+              .unwrap_or(&modules[importee_chunk.representative_module()].repr_name);
+            let name = format!("require_{}", legitimize_identifier_name(importee_name));
+            symbols.create_symbol(chunk.representative_module(), name.into())
+          });
+          live_imports.insert(canonical_ref, (namespace_ref, export_alias.clone()));
+        }
+      }
+      chunk_graph.chunks[chunk_id].live_imports_from_other_chunks = live_imports;
+    }
   }
 
   /// - Assign each symbol to the chunk it belongs to
@@ -196,6 +237,23 @@ impl<'a> GenerateStage<'a> {
             }
           });
 
+          // `export * from 'ext'` copies the namespace of the external module to the namespace of the importer at
+          // runtime, so the namespace is imported like `import * as ns from 'ext'`. ES modules re-export it statically
+          // instead, unless the namespace of the importer is used.
+          let copies_external_star_exports =
+            !matches!(self.options.format, OutputFormat::Esm) || module.stmt_infos[0].is_included;
+          module.star_exports.iter().filter(|_| copies_external_star_exports).for_each(|rec_id| {
+            let rec = &module.import_records[*rec_id];
+            if let ModuleId::External(importee_id) = rec.resolved_module {
+              imports_from_external_modules.entry(importee_id).or_default().push(NamedImport {
+                imported: Specifier::Star,
+                span_imported: SPAN,
+                imported_as: rec.namespace_ref,
+                record_id: *rec_id,
+              });
+            }
+          });
+
           module.stmt_infos.iter().for_each(|stmt_info| {
             if !stmt_info.is_included {
               return;
@@ -248,6 +306,7 @@ impl<'a> GenerateStage<'a> {
 
           if matches!(self.options.format, OutputFormat::Cjs)
             && matches!(entry.exports_kind, ExportsKind::Esm)
+            && (!entry_meta.is_canonical_exports_empty() || !entry.star_exports.is_empty())
           {
             depended_symbols.insert(self.link_output.runtime.resolve_symbol("__toCommonJS"));
             depended_symbols.insert(entry.namespace_object_ref);
@@ -323,6 +382,16 @@ impl<'a> GenerateStage<'a> {
       FxHashMap::with_capacity(index_chunk_exported_symbols.iter().map(FxHashSet::len).sum());
 
     for (chunk_id, chunk) in chunk_graph.chunks.iter_mut_enumerated() {
+      if self.options.minify_internal_exports() && matches!(chunk.kind, ChunkKind::Common) {
+        // Exports of a shared chunk are only imported by other chunks, so aliases only need to be unique in the chunk
+        let mut chunk_exports =
+          index_chunk_exported_symbols[chunk_id].iter().copied().collect::<Vec<_>>();
+        chunk_exports.sort_unstable_by_key(|symbol_ref| (symbol_ref.owner, symbol_ref.symbol));
+        for (index, chunk_export) in chunk_exports.into_iter().enumerate() {
+          chunk.exports_to_other_chunks.insert(chunk_export, minified_export_name(index));
+        }
+        continue;
+      }
       for chunk_export in index_chunk_exported_symbols[chunk_id].iter().copied() {
         let original_name: rolldown_rstr::Rstr =
           self.link_output.symbols.get_original_name(chunk_export).to_rstr();
@@ -351,3 +420,26 @@ impl<'a> GenerateStage<'a> {
     }
   }
 }
+
+/// Short alias of the export at `index` of a chunk: `a`, `b`, ..., `$`, `aa`, `ba`, and so on.
+fn minified_export_name(mut index: usize) -> Rstr {
+  const CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$";
+  let mut name = String::new();
+  loop {
+    name.push(CHARS[index % CHARS.len()] as char);
+    index /= CHARS.len();
+    if index == 0 {
+      break;
+    }
+    index -= 1;
+  }
+  name.into()
+}
+
+#[test]
+fn test_minified_export_name() {
+  assert_eq!(minified_export_name(0).as_str(), "a");
+  assert_eq!(minified_export_name(53).as_str(), "$");
+  assert_eq!(minified_export_name(54).as_str(), "aa");
+  assert_eq!(minified_export_name(55).as_str(), "ba");
+}
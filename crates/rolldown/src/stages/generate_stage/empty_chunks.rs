@@ -0,0 +1,47 @@
+use rolldown_common::{ChunkId, ChunkKind};
+use rolldown_error::BuildError;
+use rustc_hash::FxHashSet;
+
+use super::GenerateStage;
+use crate::chunk_graph::ChunkGraph;
+
+impl<'a> GenerateStage<'a> {
+  /// Find shared chunks that have no code to render, which happens when every statement of their modules is tree-shaken
+  /// but the modules are still included for their side effects. Other chunks stop
+  /// importing them, and they are not emitted. Entry chunks are always emitted, since they're requested by users or
+  /// imported dynamically.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub fn remove_empty_chunks(&mut self, chunk_graph: &mut ChunkGraph) -> FxHashSet<ChunkId> {
+    let runtime_id = self.link_output.runtime.id();
+    let modules = &self.link_output.module_table.normal_modules;
+    let empty_chunk_ids = chunk_graph
+      .chunks
+      .iter_enumerated()
+      .filter(|(_, chunk)| {
+        matches!(chunk.kind, ChunkKind::Common)
+          // The standalone runtime chunk of tests is emitted as is
+          && chunk.modules.iter().any(|id| *id != runtime_id)
+          && chunk.exports_to_other_chunks.is_empty()
+          && chunk.imports_from_external_modules.is_empty()
+          && chunk.modules.iter().all(|id| {
+            let module = &modules[*id];
+            module.css.is_none() && module.stmt_infos.iter().all(|stmt_info| !stmt_info.is_included)
+          })
+      })
+      .map(|(id, _)| id)
+      .collect::<FxHashSet<_>>();
+    if empty_chunk_ids.is_empty() {
+      return empty_chunk_ids;
+    }
+
+    for chunk_id in chunk_graph.sorted_chunk_ids.iter().filter(|id| empty_chunk_ids.contains(id)) {
+      let name = self.chunk_name(&chunk_graph.chunks[*chunk_id]);
+      self.link_output.warnings.push(BuildError::generated_empty_chunk(name));
+    }
+    for chunk in &mut chunk_graph.chunks {
+      chunk.cross_chunk_imports.retain(|id| !empty_chunk_ids.contains(id));
+      chunk.imports_from_other_chunks.retain(|(id, _)| !empty_chunk_ids.contains(id));
+    }
+    empty_chunk_ids
+  }
+}
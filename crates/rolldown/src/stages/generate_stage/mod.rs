@@ -1,19 +1,26 @@
+use std::path::Path;
+
 use anyhow::Result;
 use oxc::ast::VisitMut;
+use oxc_index::IndexVec;
 use rolldown_oxc_utils::AstSnippet;
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use futures::future::try_join_all;
 use rolldown_common::{
-  Chunk, ChunkKind, FileNameRenderOptions, NormalModuleId, Output, OutputAsset, OutputChunk,
-  PreliminaryFilename, SourceMapType,
+  Chunk, ChunkKind, FileNameRenderOptions, LegalComments, NormalModuleId, Output, OutputAsset,
+  OutputChunk, PreliminaryFilename, SourceMapType,
 };
 use rolldown_error::BuildError;
+use rolldown_fs::SharedFileSystem;
 use rolldown_plugin::SharedPluginDriver;
 use rolldown_utils::{
+  base64::to_standard_base64,
   path_buf_ext::PathBufExt,
   path_ext::PathExt,
-  rayon::{ParallelBridge, ParallelIterator},
+  rayon::{
+    IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator, ThreadPool,
+  },
 };
 use sugar_path::SugarPath;
 
@@ -25,38 +32,72 @@ use crate::{
   },
   stages::link_stage::LinkStageOutput,
   type_alias::IndexNormalModules,
+  types::cancellation_token::CancellationToken,
   utils::{
     augment_chunk_hash::augment_chunk_hash,
     chunk::{
       deconflict_chunk_symbols::deconflict_chunk_symbols,
       finalize_chunks::finalize_chunks,
-      render_chunk::{render_chunk, ChunkRenderReturn},
+      render_chunk::{render_chunk, render_chunk_modules, ChunkRenderReturn},
     },
+    dts::{bundle_declarations, declaration_filename},
     extract_hash_pattern::extract_hash_pattern,
+    filename_conflict::{filename_conflict_key, make_unique_filename},
     finalize_normal_module,
+    hash::hash_content,
     hash_placeholder::HashPlaceholderGenerator,
-    is_in_rust_test_mode,
+    html::{find_html_references, rewrite_html},
+    metafile::generate_metafile,
+    minify_js::minify_js,
     render_chunks::render_chunks,
+    size_report::ChunkSize,
+    sourcemap_debug_id::{generate_debug_id, inject_debug_id},
+    validate_chunk::validate_chunk,
+    worker::embed_inline_workers,
+    write_output::write_output_file,
   },
   BundleOutput, SharedOptions,
 };
 
+mod circular_chunks;
 mod code_splitting;
 mod compute_cross_chunk_links;
+mod empty_chunks;
 
 pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
   options: &'a SharedOptions,
   plugin_driver: &'a SharedPluginDriver,
+  cancellation_token: &'a CancellationToken,
+  thread_pool: &'a ThreadPool,
+  /// Where modules are read from, such as declaration files of entries with `dts`.
+  fs: &'a SharedFileSystem,
+  output_fs: &'a SharedFileSystem,
+  is_write: bool,
 }
 
 impl<'a> GenerateStage<'a> {
+  #[allow(clippy::too_many_arguments)]
   pub fn new(
     link_output: &'a mut LinkStageOutput,
     options: &'a SharedOptions,
     plugin_driver: &'a SharedPluginDriver,
+    cancellation_token: &'a CancellationToken,
+    thread_pool: &'a ThreadPool,
+    fs: &'a SharedFileSystem,
+    output_fs: &'a SharedFileSystem,
+    is_write: bool,
   ) -> Self {
-    Self { link_output, options, plugin_driver }
+    Self {
+      link_output,
+      options,
+      plugin_driver,
+      cancellation_token,
+      thread_pool,
+      fs,
+      output_fs,
+      is_write,
+    }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -65,70 +106,154 @@ impl<'a> GenerateStage<'a> {
 
     self.generate_chunk_preliminary_filenames(&mut chunk_graph);
 
-    self.compute_cross_chunk_links(&mut chunk_graph);
+    let thread_pool = self.thread_pool;
+    thread_pool.install(|| {
+      self.compute_cross_chunk_links(&mut chunk_graph);
 
-    chunk_graph.chunks.iter_mut().par_bridge().for_each(|chunk| {
-      deconflict_chunk_symbols(chunk, self.link_output);
-    });
+      let canonical_names = chunk_graph
+        .chunks
+        .raw
+        .par_iter()
+        .map(|chunk| deconflict_chunk_symbols(chunk, self.link_output, self.options))
+        .collect::<Vec<_>>();
+      for (chunk, canonical_names) in chunk_graph.chunks.iter_mut().zip(canonical_names) {
+        chunk.canonical_names = canonical_names;
+      }
 
-    let ast_table_iter = self.link_output.ast_table.iter_mut_enumerated();
-    ast_table_iter
-      .par_bridge()
-      .filter(|(id, _)| self.link_output.module_table.normal_modules[*id].is_included)
-      .for_each(|(id, ast)| {
-        let module = &self.link_output.module_table.normal_modules[id];
-        let chunk_id = chunk_graph.module_to_chunk[module.id].unwrap();
-        let chunk = &chunk_graph.chunks[chunk_id];
-        let linking_info = &self.link_output.metas[module.id];
-        if self.options.format.requires_scope_hoisting() {
-          finalize_normal_module(
-            module,
-            ScopeHoistingFinalizerContext {
-              canonical_names: &chunk.canonical_names,
-              id: module.id,
-              symbols: &self.link_output.symbols,
-              linking_info,
+      let ast_table_iter = self.link_output.ast_table.iter_mut_enumerated();
+      ast_table_iter
+        .par_bridge()
+        .filter(|(id, _)| {
+          // Skip the rest of modules once the build is cancelled.
+          !self.cancellation_token.is_cancelled()
+            && self.link_output.module_table.normal_modules[*id].is_included
+        })
+        .for_each(|(id, ast)| {
+          let module = &self.link_output.module_table.normal_modules[id];
+          let chunk_id = chunk_graph.module_to_chunk[module.id].unwrap();
+          let chunk = &chunk_graph.chunks[chunk_id];
+          let linking_info = &self.link_output.metas[module.id];
+          if self.options.format.requires_scope_hoisting() {
+            finalize_normal_module(
               module,
-              modules: &self.link_output.module_table.normal_modules,
-              external_modules: &self.link_output.module_table.external_modules,
-              linking_infos: &self.link_output.metas,
-              runtime: &self.link_output.runtime,
-              chunk_graph: &chunk_graph,
-              options: self.options,
-            },
-            ast,
-          );
-        } else {
-          ast.with_mut(|fields| {
-            let (oxc_program, alloc) = (fields.program, fields.allocator);
-            let mut finalizer = IsolatingModuleFinalizer {
-              alloc,
-              scope: &module.scope,
-              ctx: &IsolatingModuleFinalizerContext {
+              ScopeHoistingFinalizerContext {
+                canonical_names: &chunk.canonical_names,
+                live_imports_from_other_chunks: &chunk.live_imports_from_other_chunks,
+                id: module.id,
+                symbols: &self.link_output.symbols,
+                linking_info,
                 module,
                 modules: &self.link_output.module_table.normal_modules,
+                external_modules: &self.link_output.module_table.external_modules,
+                linking_infos: &self.link_output.metas,
+                runtime: &self.link_output.runtime,
+                chunk_graph: &chunk_graph,
+                options: self.options,
               },
-              snippet: AstSnippet::new(alloc),
-            };
-            finalizer.visit_program(oxc_program);
-          });
-        }
-      });
+              ast,
+            );
+          } else {
+            ast.with_mut(|fields| {
+              let (oxc_program, alloc) = (fields.program, fields.allocator);
+              let mut finalizer = IsolatingModuleFinalizer {
+                alloc,
+                scope: &module.scope,
+                ctx: &IsolatingModuleFinalizerContext {
+                  module,
+                  modules: &self.link_output.module_table.normal_modules,
+                },
+                snippet: AstSnippet::new(alloc),
+              };
+              finalizer.visit_program(oxc_program);
+            });
+          }
+        });
+    });
+
+    let empty_chunk_ids = self.remove_empty_chunks(&mut chunk_graph);
+
+    self.check_circular_chunks(&chunk_graph);
+
+    self.cancellation_token.check()?;
 
+    // ASTs, along with the source code they own, are only needed for printing modules. Each chunk takes the ASTs of
+    // its modules, and every AST is released right after its module is printed, so the ASTs and the printed code of
+    // all modules never coexist. ASTs of modules that aren't in any chunk are released right away.
+    let mut ast_table = std::mem::take(&mut self.link_output.ast_table)
+      .into_iter()
+      .map(Some)
+      .collect::<IndexVec<NormalModuleId, _>>();
+    let chunk_asts = chunk_graph
+      .chunks
+      .iter()
+      .map(|chunk| {
+        chunk
+          .modules
+          .iter()
+          .map(|id| (*id, ast_table[*id].take().expect("A module should belong to one chunk")))
+          .collect::<Vec<_>>()
+      })
+      .collect::<Vec<_>>();
+    drop(ast_table);
+
+    let modules = &self.link_output.module_table.normal_modules;
+    let chunk_modules = self.thread_pool.install(|| {
+      chunk_asts
+        .into_par_iter()
+        .map(|asts| render_chunk_modules(asts, modules, self.options))
+        .collect::<Vec<_>>()
+    });
+
+    let link_output = &*self.link_output;
     let chunks = try_join_all(
       chunk_graph
         .chunks
         .iter()
-        .map(|c| async { render_chunk(c, self.options, self.link_output, &chunk_graph).await }),
+        .zip(chunk_modules)
+        .map(|(c, modules)| render_chunk(c, modules, self.options, link_output, &chunk_graph)),
     )
     .await?;
 
-    let chunks = render_chunks(self.plugin_driver, chunks).await?;
+    self.cancellation_token.check()?;
+
+    let mut chunks = render_chunks(self.plugin_driver, chunks).await?;
+
+    // Minify before hashing, so filenames reflect the emitted code
+    if self.options.minify() {
+      self.thread_pool.install(|| {
+        chunks.iter_mut().par_bridge().try_for_each(|chunk| -> anyhow::Result<()> {
+          let (code, map) = minify_js(&chunk.code, chunk.map.take(), &self.options.format)?;
+          chunk.code = code;
+          chunk.map = map;
+          Ok(())
+        })
+      })?;
+    }
+
+    // Embed the code of workers imported with `?worker&inline`
+    embed_inline_workers(&mut chunks);
 
     let chunks = augment_chunk_hash(self.plugin_driver, chunks).await?;
 
-    let chunks = finalize_chunks(&mut chunk_graph, chunks);
+    let mut chunks =
+      self.thread_pool.install(|| finalize_chunks(&mut chunk_graph, chunks, self.options));
+
+    // Empty chunks are rendered along with others, since chunks are finalized by their ids
+    if !empty_chunk_ids.is_empty() {
+      chunks = chunks
+        .into_iter()
+        .zip(chunk_graph.chunks.indices())
+        .filter(|(_, chunk_id)| !empty_chunk_ids.contains(chunk_id))
+        .map(|(chunk, _)| chunk)
+        .collect();
+    }
 
+    // With `streamWrites`, each chunk is written and dropped once it's finalized, instead of keeping all of them in
+    // memory until the end.
+    let stream_dir = (self.is_write && self.options.stream_writes())
+      .then(|| self.options.cwd.join(&self.options.dir));
+    let mut streamed_chunk_bytes = FxHashMap::default();
+    let mut streamed_chunk_sizes = vec![];
     let mut assets = vec![];
     for ChunkRenderReturn {
       mut map,
@@ -136,27 +261,102 @@ impl<'a> GenerateStage<'a> {
       mut code,
       file_dir,
       preliminary_filename,
+      legal_comments,
+      css,
+      html,
+      name,
+      hash,
       ..
     } in chunks
     {
+      if self.options.validate() {
+        let module_ids = rendered_chunk
+          .module_ids
+          .iter()
+          .map(|id| id.stabilize(&self.options.cwd))
+          .collect::<Vec<_>>();
+        if let Some(error) =
+          validate_chunk(&rendered_chunk.filename, &code, &self.options.format, &module_ids)
+        {
+          self.link_output.errors.push(error);
+          continue;
+        }
+      }
+
+      if !legal_comments.is_empty() {
+        let legal_filename = format!("{}.LEGAL.txt", rendered_chunk.filename.as_str());
+        if matches!(self.options.legal_comments, LegalComments::Linked) {
+          let legal_file_basename = std::path::Path::new(&legal_filename)
+            .file_name()
+            .map_or_else(|| legal_filename.clone(), |name| name.to_string_lossy().into_owned());
+          code.push_str(&format!(
+            "\n/*! For license information please see {legal_file_basename} */"
+          ));
+        }
+        let mut source = legal_comments.join("\n\n");
+        source.push('\n');
+        assets.push(Output::Asset(Box::new(OutputAsset {
+          filename: legal_filename,
+          source: source.into(),
+        })));
+      }
+
+      let mut sourcemap_filename = None;
       if let Some(map) = map.as_mut() {
         map.set_file(&rendered_chunk.filename);
 
-        let map_filename = format!("{}.map", rendered_chunk.filename.as_str());
-        let map_path = file_dir.join(&map_filename);
-
-        if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
-          let mut x_google_ignore_list = vec![];
-          for (index, source) in map.get_sources().enumerate() {
-            if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
-              #[allow(clippy::cast_possible_truncation)]
-              x_google_ignore_list.push(index as u32);
+        let map_filename = match &self.options.sourcemap_filenames {
+          Some(template) => {
+            let mut map_filename =
+              template.render(&FileNameRenderOptions { name: Some(&name), ..Default::default() });
+            if let Some(chunk_hash) = &hash {
+              map_filename = map_filename.replace("[chunkhash]", &chunk_hash[..8]);
             }
+            if let Some(hash_pattern) = extract_hash_pattern(&map_filename) {
+              let source = match map.to_json_string().map_err(BuildError::sourcemap_error) {
+                Ok(source) => source,
+                Err(e) => {
+                  self.link_output.errors.push(e);
+                  continue;
+                }
+              };
+              let mut hash = hash_content(source.as_bytes(), self.options);
+              hash.truncate(hash_pattern.len.unwrap_or(8));
+              map_filename = map_filename.replace(&hash_pattern.pattern, &hash);
+            }
+            map_filename
           }
-          if !x_google_ignore_list.is_empty() {
-            map.set_x_google_ignore_list(x_google_ignore_list);
+          None => format!("{}.map", rendered_chunk.filename.as_str()),
+        };
+        let map_path = self.options.cwd.join(&self.options.dir).join(&map_filename);
+        let map_dir = map_path.parent().expect("map file should have a parent");
+        // Sources are relative to the chunk. Make them relative to the `.map` file, if it's placed elsewhere.
+        if map_dir != file_dir {
+          let sources = map
+            .get_sources()
+            .map(|source| file_dir.join(source).normalize().relative(map_dir))
+            .collect::<Vec<_>>();
+          let sources = sources.iter().map(|x| x.to_string_lossy()).collect::<Vec<_>>();
+          map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
+        }
+
+        // Sources in `node_modules` are ignored by default, so devtools collapse frames of third-party code.
+        let mut x_google_ignore_list = vec![];
+        for (index, source) in map.get_sources().enumerate() {
+          let is_ignored = match &self.options.sourcemap_ignore_list {
+            Some(source_map_ignore_list) => {
+              source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await?
+            }
+            None => source.contains("node_modules"),
+          };
+          if is_ignored {
+            #[allow(clippy::cast_possible_truncation)]
+            x_google_ignore_list.push(index as u32);
           }
         }
+        if !x_google_ignore_list.is_empty() {
+          map.set_x_google_ignore_list(x_google_ignore_list);
+        }
 
         if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
           let mut sources = Vec::with_capacity(map.get_sources().count());
@@ -172,36 +372,99 @@ impl<'a> GenerateStage<'a> {
         let sources = map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
         map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
 
-        match self.options.sourcemap {
-          SourceMapType::File => {
-            let source = match map.to_json_string().map_err(BuildError::sourcemap_error) {
-              Ok(source) => source,
-              Err(e) => {
-                self.link_output.errors.push(e);
-                continue;
+        let sourcemap_type =
+          self.options.sourcemap.as_ref().expect("Sourcemaps are only generated if enabled");
+        let mut source = match map.to_json_string().map_err(BuildError::sourcemap_error) {
+          Ok(source) => source,
+          Err(e) => {
+            self.link_output.errors.push(e);
+            continue;
+          }
+        };
+        // The debug ID is placed in both the chunk and the sourcemap, so they can be matched without URLs.
+        if self.options.sourcemap_debug_ids() {
+          let debug_id = generate_debug_id(&code);
+          source = inject_debug_id(&source, &debug_id);
+          code.push_str(&format!("\n//# debugId={debug_id}"));
+        }
+        match sourcemap_type {
+          SourceMapType::File | SourceMapType::Hidden | SourceMapType::Cheap => {
+            match &stream_dir {
+              Some(dir) => {
+                write_output_file(self.output_fs, dir, &map_filename, source.as_bytes())?;
               }
-            };
-            assets.push(Output::Asset(Box::new(OutputAsset {
-              filename: map_filename.clone(),
-              source,
-            })));
-            code.push_str(&format!("\n//# sourceMappingURL={map_filename}"));
+              None => assets.push(Output::Asset(Box::new(OutputAsset {
+                filename: map_filename.clone(),
+                source: source.into(),
+              }))),
+            }
+            // Hidden sourcemaps are emitted for error reporting tools, but not referenced by the chunk.
+            if matches!(sourcemap_type, SourceMapType::File | SourceMapType::Cheap) {
+              let map_url = map_path.relative(&file_dir).as_path().expect_to_slash();
+              code.push_str(&format!("\n//# sourceMappingURL={map_url}"));
+            }
           }
           SourceMapType::Inline => {
-            let data_url = match map.to_data_url().map_err(BuildError::sourcemap_error) {
-              Ok(data_url) => data_url,
-              Err(e) => {
-                self.link_output.errors.push(e);
-                continue;
-              }
-            };
+            let data_url =
+              format!("data:application/json;charset=utf-8;base64,{}", to_standard_base64(&source));
             code.push_str(&format!("\n//# sourceMappingURL={data_url}"));
           }
-          SourceMapType::Hidden => {}
         }
+        sourcemap_filename = Some(map_filename);
+      }
+      let sourcemap_filename = sourcemap_filename
+        .filter(|_| !matches!(self.options.sourcemap, Some(SourceMapType::Inline)));
+      let css_filename = css.map(|(css_filename, css_source)| {
+        assets.push(Output::Asset(Box::new(OutputAsset {
+          filename: css_filename.clone(),
+          source: css_source.into(),
+        })));
+        css_filename
+      });
+      // HTML entries are emitted with the references replaced by the chunk and its stylesheet. Unlike chunks, the
+      // filename has no hash, so that the page keeps its URL.
+      if let Some(html) = html {
+        let html_filename = format!("{name}.html");
+        let html_dir = Path::new(&html_filename).parent().unwrap_or(Path::new(""));
+        let url_of = |filename: &str| filename.relative(html_dir).as_path().expect_to_slash();
+        let source = rewrite_html(
+          &html,
+          &find_html_references(&html),
+          &url_of(rendered_chunk.filename.as_str()),
+          css_filename.as_deref().map(url_of).as_deref(),
+        );
+        assets.push(Output::Asset(Box::new(OutputAsset {
+          filename: html_filename,
+          source: source.into(),
+        })));
+      }
+      if self.options.dts() && rendered_chunk.is_entry {
+        let declarations = rendered_chunk
+          .facade_module_id
+          .as_ref()
+          .map(|id| bundle_declarations(self.fs, Path::new(id.as_str())))
+          .transpose()?
+          .flatten();
+        if let Some(source) = declarations {
+          assets.push(Output::Asset(Box::new(OutputAsset {
+            filename: declaration_filename(rendered_chunk.filename.as_str()),
+            source: source.into(),
+          })));
+        }
+      }
+      if let Some(dir) = &stream_dir {
+        write_output_file(self.output_fs, dir, rendered_chunk.filename.as_str(), code.as_bytes())?;
+        streamed_chunk_bytes.insert(rendered_chunk.filename.to_string(), code.len());
+        if self.options.report_compressed_size() || self.options.max_chunk_size.is_some() {
+          streamed_chunk_sizes.push(ChunkSize::new(
+            rendered_chunk.filename.as_str(),
+            code.as_bytes(),
+            self.options.report_compressed_size(),
+          ));
+        }
+        code = String::new();
+        map = None;
       }
-      let sourcemap_filename =
-        map.as_ref().map(|_| format!("{}.map", rendered_chunk.filename.as_str()));
       assets.push(Output::Chunk(Box::new(OutputChunk {
         filename: rendered_chunk.filename,
         code,
@@ -215,10 +478,35 @@ impl<'a> GenerateStage<'a> {
         dynamic_imports: rendered_chunk.dynamic_imports,
         map,
         sourcemap_filename,
+        css_filename,
         preliminary_filename: preliminary_filename.to_string(),
       })));
     }
 
+    // Emit assets imported by included modules. Assets with the same content share the same file.
+    let mut emitted_asset_filenames = FxHashSet::default();
+    for asset_module in &self.link_output.module_table.asset_modules {
+      let module = &self.link_output.module_table.normal_modules[asset_module.module_id];
+      if module.is_included && emitted_asset_filenames.insert(asset_module.filename.as_str()) {
+        assets.push(Output::Asset(Box::new(OutputAsset {
+          filename: asset_module.filename.clone(),
+          source: asset_module.source.clone().into(),
+        })));
+      }
+    }
+
+    // Files of assets, such as stylesheets and sourcemaps, are named by their own templates, which might render the
+    // same filename for different files
+    let mut used_filenames = FxHashSet::default();
+    for output in &assets {
+      if !used_filenames.insert(filename_conflict_key(output.filename())) {
+        self
+          .link_output
+          .warnings
+          .push(BuildError::file_name_conflict(output.filename().to_string()));
+      }
+    }
+
     // Make sure order of assets are deterministic
     assets.sort_by_cached_key(|item| match item {
       // TODO: use `preliminary_filename` instead
@@ -226,10 +514,21 @@ impl<'a> GenerateStage<'a> {
       Output::Chunk(chunk) => chunk.preliminary_filename.to_string(),
     });
 
+    if let Some(metafile) = &self.options.metafile {
+      let source =
+        generate_metafile(&assets, self.link_output, self.options, &streamed_chunk_bytes);
+      assets.push(Output::Asset(Box::new(OutputAsset {
+        filename: metafile.clone(),
+        source: source.into(),
+      })));
+    }
+
     Ok(BundleOutput {
       assets,
       warnings: std::mem::take(&mut self.link_output.warnings),
       errors: std::mem::take(&mut self.link_output.errors),
+      hmr_update: None,
+      streamed_chunk_sizes,
     })
   }
 
@@ -242,10 +541,6 @@ impl<'a> GenerateStage<'a> {
       runtime_id: NormalModuleId,
       normal_modules: &IndexNormalModules,
     ) -> String {
-      if is_in_rust_test_mode() && chunk.modules.first().copied() == Some(runtime_id) {
-        return "$runtime$".to_string();
-      }
-
       // User-defined entry point should always have a name that given by the user
       match chunk.kind {
         ChunkKind::EntryPoint { module: entry_module_id, is_user_defined, .. } => {
@@ -279,6 +574,9 @@ impl<'a> GenerateStage<'a> {
 
     let mut hash_placeholder_generator = HashPlaceholderGenerator::default();
     let mut used_names = FxHashSet::default();
+    // Different names might still be rendered to the same file, if the template has no `[name]` or names only differ
+    // in case.
+    let mut used_filenames = FxHashSet::default();
 
     // First ensure names of user-defined entry chunks aren't shadowed by other chunks
 
@@ -295,11 +593,23 @@ impl<'a> GenerateStage<'a> {
         return;
       }
       let runtime_id = self.link_output.runtime.id();
-
-      let filename_template = chunk.filename_template(self.options);
-
-      let mut chunk_name =
-        ensure_chunk_name(chunk, runtime_id, &self.link_output.module_table.normal_modules);
+      let is_runtime_chunk =
+        self.options.runtime_chunk() && chunk.modules.first().copied() == Some(runtime_id);
+
+      // The runtime chunk is named like entry chunks, so that its filename is as stable as theirs.
+      let filename_template = if is_runtime_chunk {
+        &self.options.entry_filenames
+      } else {
+        chunk.filename_template(self.options)
+      };
+
+      let mut chunk_name = if is_runtime_chunk {
+        "rolldown-runtime".to_string()
+      } else if self.options.test_mode() && chunk.modules.first().copied() == Some(runtime_id) {
+        "$runtime$".to_string()
+      } else {
+        ensure_chunk_name(chunk, runtime_id, &self.link_output.module_table.normal_modules)
+      };
       let mut next_count = 1;
       while used_names.contains(&chunk_name) {
         chunk_name = format!("{chunk_name}~{next_count}");
@@ -312,13 +622,20 @@ impl<'a> GenerateStage<'a> {
       let hash_placeholder =
         extracted_hash_pattern.map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
 
-      let preliminary = filename_template.render(&FileNameRenderOptions {
-        name: Some(&chunk_name),
-        hash: hash_placeholder.as_deref(),
-      });
-
-      chunk.absolute_preliminary_filename =
-        Some(preliminary.absolutize_with(&self.options.dir).expect_into_string());
+      let preliminary = make_unique_filename(
+        filename_template.render(&FileNameRenderOptions {
+          name: Some(&chunk_name),
+          hash: hash_placeholder.as_deref(),
+          ..Default::default()
+        }),
+        &mut used_filenames,
+      );
+
+      // `dir` is resolved against `cwd` rather than the working directory of the process, so paths relative to chunks,
+      // such as those of absolute externals, don't depend on where the bundler runs.
+      chunk.absolute_preliminary_filename = Some(
+        preliminary.absolutize_with(self.options.cwd.join(&self.options.dir)).expect_into_string(),
+      );
       chunk.preliminary_filename = Some(PreliminaryFilename::new(preliminary, hash_placeholder));
     });
   }
@@ -269,13 +269,21 @@ impl<'a> BindImportsAndExportsContext<'a> {
             Some(NamespaceAlias { property_name: alias, namespace_ref });
         }
         MatchImportKind::NoMatch => {
-          let importee = &self.normal_modules[rec.resolved_module.as_normal().unwrap()];
+          let importee_id = rec.resolved_module.as_normal().unwrap();
+          let importee = &self.normal_modules[importee_id];
+          let mut available_exports = self.metas[importee_id]
+            .resolved_exports
+            .keys()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>();
+          available_exports.sort_unstable();
           self.errors.push(BuildError::missing_export(
             module.stable_resource_id.clone(),
             importee.stable_resource_id.clone(),
             Arc::clone(&module.source),
             named_import.imported.to_string(),
             named_import.span_imported,
+            available_exports,
           ));
         }
       }
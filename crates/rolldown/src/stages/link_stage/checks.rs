@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use rolldown_common::{CheckLevel, OutputFormat};
+use rolldown_error::BuildError;
+
+use super::LinkStage;
+
+/// Report `error` of a check as an error or a warning, depending on the level of the check in `checks`.
+pub fn report_check(
+  level: CheckLevel,
+  error: BuildError,
+  warnings: &mut Vec<BuildError>,
+  errors: &mut Vec<BuildError>,
+) {
+  match level {
+    CheckLevel::Error => errors.push(error),
+    CheckLevel::Warn => warnings.push(error.with_severity_warning()),
+  }
+}
+
+impl<'a> LinkStage<'a> {
+  /// Check included modules for constructs that the output format can't express. Cycles of chunks are checked once
+  /// chunks are generated.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn check_unsupported_features(&mut self) {
+    let format = &self.input_options.format;
+    for id in &self.sorted_modules {
+      let module = &self.module_table.normal_modules[*id];
+      if !module.is_included {
+        continue;
+      }
+      if let Some(span) =
+        module.top_level_await_span.filter(|_| !matches!(format, OutputFormat::Esm))
+      {
+        let error = BuildError::invalid_tla_format(
+          module.resource_id.to_string(),
+          Arc::clone(&module.source),
+          span,
+          format.as_str(),
+        );
+        report_check(
+          self.input_options.checks_top_level_await,
+          error,
+          &mut self.warnings,
+          &mut self.errors,
+        );
+      }
+      if let Some(span) = module.import_meta_span.filter(|_| matches!(format, OutputFormat::Cjs)) {
+        let error = BuildError::unsupported_import_meta(
+          module.resource_id.to_string(),
+          Arc::clone(&module.source),
+          span,
+        );
+        report_check(
+          self.input_options.checks_import_meta,
+          error,
+          &mut self.warnings,
+          &mut self.errors,
+        );
+      }
+    }
+  }
+}
@@ -0,0 +1,45 @@
+use rolldown_common::{NormalModuleId, StmtInfoId, SymbolRef};
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Accesses of members of imported `const enum`s, such as `Direction.Up`, are replaced with their values by the
+  /// finalizer. Statements that only access known members don't reference the enums anymore, so enums that are only
+  /// used this way could be tree shaken.
+  pub(super) fn inline_const_enums(&mut self) {
+    if !self.input_options.inline_const_enums() {
+      return;
+    }
+
+    let mut inlined: Vec<(StmtInfoId, Vec<SymbolRef>)> = vec![];
+    for module_id in (0..self.module_table.normal_modules.len()).map(NormalModuleId::from) {
+      let module = &self.module_table.normal_modules[module_id];
+      for (stmt_info_id, stmt_info) in module.stmt_infos.iter_enumerated() {
+        let symbols = stmt_info
+          .member_only_references
+          .iter()
+          .filter(|(symbol_ref, members)| {
+            let canonical_ref = self.symbols.canonical_ref_for(**symbol_ref);
+            self.module_table.normal_modules[canonical_ref.owner]
+              .const_enums
+              .get(&canonical_ref.symbol)
+              .is_some_and(|enum_members| {
+                members.iter().all(|member| enum_members.contains_key(member))
+              })
+          })
+          .map(|(symbol_ref, _)| *symbol_ref)
+          .collect::<Vec<_>>();
+        if !symbols.is_empty() {
+          inlined.push((stmt_info_id, symbols));
+        }
+      }
+
+      let module = &mut self.module_table.normal_modules[module_id];
+      for (stmt_info_id, symbols) in inlined.drain(..) {
+        module.stmt_infos[stmt_info_id]
+          .referenced_symbols
+          .retain(|symbol_ref| !symbols.contains(symbol_ref));
+      }
+    }
+  }
+}
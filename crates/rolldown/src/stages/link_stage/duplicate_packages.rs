@@ -0,0 +1,72 @@
+use std::path::Path;
+
+use rolldown_common::NormalModuleId;
+use rolldown_error::{BuildError, DuplicatePackage, PackageCopy};
+use rolldown_utils::indexmap::FxIndexMap;
+use sugar_path::SugarPath;
+
+use crate::utils::import_chains::shortest_import_chains;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Find packages bundled from more than one directory of `node_modules`, which usually means the package manager
+  /// installed different versions of them for different dependents. Only included modules are counted.
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub(super) fn detect_duplicate_packages(&mut self) {
+    let modules = &self.module_table.normal_modules;
+    // Name of the package => directory of each copy => the first executed module of the copy
+    let mut copies_by_name: FxIndexMap<&str, FxIndexMap<&Path, NormalModuleId>> =
+      FxIndexMap::default();
+    for id in &self.sorted_modules {
+      let module = &modules[*id];
+      let Some(package_json) = module.package_json.as_deref().filter(|_| module.is_included) else {
+        continue;
+      };
+      let Some(name) = package_json.name() else { continue };
+      let Some(dir) = package_json.path.parent() else { continue };
+      if !dir.components().any(|component| component.as_os_str() == "node_modules") {
+        continue;
+      }
+      copies_by_name.entry(name).or_default().entry(dir).or_insert(*id);
+    }
+
+    let entries = self.entries.iter().map(|entry| entry.id).collect::<Vec<_>>();
+    let duplicate_packages = copies_by_name
+      .into_iter()
+      .filter(|(_, copies)| copies.len() > 1)
+      .map(|(name, copies)| DuplicatePackage {
+        name: name.to_string(),
+        copies: copies
+          .into_iter()
+          .map(|(dir, first_module)| PackageCopy {
+            version: modules[first_module]
+              .package_json
+              .as_deref()
+              .and_then(|package_json| package_json.version())
+              .map(ToString::to_string),
+            path: dir.relative(&self.input_options.cwd).to_slash_lossy().into_owned(),
+            import_chains: shortest_import_chains(&entries, first_module, |id| {
+              modules[id]
+                .import_records
+                .iter()
+                .filter_map(|record| record.resolved_module.as_normal())
+                .filter(|imported| modules[*imported].is_included)
+                .collect()
+            })
+            .into_iter()
+            .map(|chain| {
+              chain.into_iter().map(|id| modules[id].stable_resource_id.clone()).collect()
+            })
+            .collect(),
+          })
+          .collect(),
+      })
+      .collect::<Vec<_>>();
+
+    if !duplicate_packages.is_empty() {
+      self.warnings.push(BuildError::duplicate_packages(duplicate_packages.clone()));
+    }
+    self.duplicate_packages = duplicate_packages;
+  }
+}
@@ -0,0 +1,87 @@
+use rolldown_common::{ExportsKind, ModuleId, NormalModuleId};
+use rolldown_error::BuildError;
+use rustc_hash::FxHashSet;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Warn about exports of entries that silently differ from what `export *` statements in them suggest:
+  /// - An export of the entry shadows the export of the same name from a module it re-exports with `export *`.
+  /// - Two modules re-exported with `export *` export different bindings of the same name, so neither is exported.
+  pub(super) fn warn_conflicting_entry_exports(&mut self) {
+    let modules = &self.module_table.normal_modules;
+    for entry in self.entries.iter().filter(|entry| entry.kind.is_user_defined()) {
+      let entry_module = &modules[entry.id];
+      if entry_module.star_exports.is_empty() {
+        continue;
+      }
+
+      // Modules re-exported by the entry with `export *`, directly or through other modules.
+      let mut star_exported_ids = vec![];
+      let mut visited = FxHashSet::from_iter([entry.id]);
+      let mut stack = vec![entry.id];
+      while let Some(id) = stack.pop() {
+        for dep_id in modules[id].star_export_module_ids().filter_map(ModuleId::as_normal) {
+          if visited.insert(dep_id)
+            && !matches!(modules[dep_id].exports_kind, ExportsKind::CommonJs)
+          {
+            star_exported_ids.push(dep_id);
+            stack.push(dep_id);
+          }
+        }
+      }
+
+      let mut shadowed = star_exported_ids
+        .iter()
+        .flat_map(|dep_id| {
+          modules[*dep_id]
+            .named_exports
+            .keys()
+            .filter(|name| {
+              name.as_str() != "default" && entry_module.named_exports.contains_key(*name)
+            })
+            .map(move |name| (name.as_str(), *dep_id))
+        })
+        .collect::<Vec<(&str, NormalModuleId)>>();
+      shadowed.sort_unstable_by_key(|(name, dep_id)| {
+        (*name, modules[*dep_id].stable_resource_id.as_str())
+      });
+      for (name, dep_id) in shadowed {
+        self.warnings.push(BuildError::shadowed_export(
+          entry_module.resource_id.to_string(),
+          name.to_string(),
+          modules[dep_id].resource_id.to_string(),
+        ));
+      }
+
+      let meta = &self.metas[entry.id];
+      let mut conflicts = meta
+        .resolved_exports
+        .iter()
+        .filter(|(name, _)| !meta.sorted_and_non_ambiguous_resolved_exports.contains(name))
+        .collect::<Vec<_>>();
+      conflicts.sort_unstable_by_key(|(name, _)| name.as_str());
+      for (name, resolved_export) in conflicts {
+        let mut sources = vec![];
+        for symbol_ref in resolved_export
+          .potentially_ambiguous_symbol_refs
+          .iter()
+          .flatten()
+          .chain([&resolved_export.symbol_ref])
+        {
+          let owner = self.symbols.par_canonical_ref_for(*symbol_ref).owner;
+          let source = modules[owner].resource_id.to_string();
+          if !sources.contains(&source) {
+            sources.push(source);
+          }
+        }
+        sources.sort_unstable();
+        self.warnings.push(BuildError::namespace_conflict(
+          entry_module.resource_id.to_string(),
+          name.to_string(),
+          sources,
+        ));
+      }
+    }
+  }
+}
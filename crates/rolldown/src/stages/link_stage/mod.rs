@@ -2,23 +2,24 @@ use std::{ptr::addr_of, sync::Mutex};
 
 use oxc_index::IndexVec;
 use rolldown_common::{
-  EntryPoint, ExportsKind, ImportKind, ModuleId, ModuleTable, NormalModule, NormalModuleId,
-  NormalizedBundlerOptions, OutputFormat, StmtInfo, WrapKind,
+  EntryPoint, ExportsKind, ImportKind, ImportRecordMeta, ModuleId, ModuleTable, NormalModule,
+  NormalModuleId, NormalizedBundlerOptions, OutputFormat, StmtInfo, WrapKind,
 };
-use rolldown_error::BuildError;
+use rolldown_error::{BuildError, DuplicatePackage};
 use rolldown_oxc_utils::OxcAst;
 use rolldown_utils::{
   ecma_script::legitimize_identifier_name,
   rayon::{ParallelBridge, ParallelIterator},
 };
-use rustc_hash::FxHashSet;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
   runtime::RuntimeModuleBrief,
   types::{
-    linking_metadata::{LinkingMetadata, LinkingMetadataVec},
+    linking_metadata::{LinkingMetadata, LinkingMetadataVec, WrapReason},
     symbols::Symbols,
   },
+  utils::lazy_compilation::lazy_proxy_target,
   SharedOptions,
 };
 
@@ -27,8 +28,13 @@ use self::wrapping::create_wrapper;
 use super::scan_stage::ScanStageOutput;
 
 mod bind_imports_and_exports;
+pub(crate) mod checks;
+mod const_enums;
+mod duplicate_packages;
+mod entry_exports;
 mod sort_modules;
 mod tree_shaking;
+mod unused_externals;
 mod wrapping;
 
 #[derive(Debug)]
@@ -42,6 +48,8 @@ pub struct LinkStageOutput {
   pub runtime: RuntimeModuleBrief,
   pub warnings: Vec<BuildError>,
   pub errors: Vec<BuildError>,
+  /// Packages bundled from more than one directory of `node_modules`.
+  pub duplicate_packages: Vec<DuplicatePackage>,
 }
 
 #[derive(Debug)]
@@ -55,6 +63,7 @@ pub struct LinkStage<'a> {
   pub warnings: Vec<BuildError>,
   pub errors: Vec<BuildError>,
   pub ast_table: IndexVec<NormalModuleId, OxcAst>,
+  pub duplicate_packages: Vec<DuplicatePackage>,
   pub input_options: &'a SharedOptions,
 }
 
@@ -75,6 +84,7 @@ impl<'a> LinkStage<'a> {
       warnings: scan_stage_output.warnings,
       errors: scan_stage_output.errors,
       ast_table: scan_stage_output.ast_table,
+      duplicate_packages: vec![],
       input_options,
     }
   }
@@ -96,6 +106,7 @@ impl<'a> LinkStage<'a> {
           stmt_idx: None,
           declared_symbols: vec![*symbol_ref],
           referenced_symbols: vec![],
+          member_only_references: FxHashMap::default(),
           side_effect: false,
           is_included: false,
           import_records: Vec::new(),
@@ -118,12 +129,19 @@ impl<'a> LinkStage<'a> {
           referenced_symbols.extend(meta.canonical_exports().map(|(_, export)| export.symbol_ref));
           referenced_symbols.push(self.runtime.resolve_symbol("__export"));
         }
+        let has_external_star_exports = module.star_exports.iter().any(|rec_id| {
+          matches!(module.import_records[*rec_id].resolved_module, ModuleId::External(_))
+        });
+        if has_external_star_exports && matches!(self.input_options.format, OutputFormat::Esm) {
+          referenced_symbols.push(self.runtime.resolve_symbol("__reExport"));
+        }
         // Create a StmtInfo to represent the statement that declares and constructs the Module Namespace Object.
         // Corresponding AST for this statement will be created by the finalizer.
         let namespace_stmt_info = StmtInfo {
           stmt_idx: None,
           declared_symbols: vec![module.namespace_object_ref],
           referenced_symbols,
+          member_only_references: FxHashMap::default(),
           side_effect: false,
           is_included: false,
           import_records: Vec::new(),
@@ -131,20 +149,62 @@ impl<'a> LinkStage<'a> {
         };
         module.stmt_infos.replace_namespace_stmt_info(namespace_stmt_info);
       }
+
+      // With HMR, every module registers its exports by `__rolldown_hmr__.register(id, () => exports)`, so updates
+      // of other modules could import them. The statement is created by the finalizer.
+      if self.input_options.hmr() && module.id != self.runtime.id() {
+        let mut referenced_symbols = vec![self.runtime.resolve_symbol("__rolldown_hmr__")];
+        if matches!(module.exports_kind, ExportsKind::Esm) {
+          referenced_symbols.push(module.namespace_object_ref);
+        }
+        module.stmt_infos.add_stmt_info(StmtInfo {
+          stmt_idx: None,
+          declared_symbols: vec![],
+          referenced_symbols,
+          member_only_references: FxHashMap::default(),
+          side_effect: true,
+          is_included: false,
+          import_records: Vec::new(),
+          debug_label: None,
+        });
+      }
+
+      // Proxy modules of `lazyCompilation` call `globalThis.__rolldown_lazy__.load(id)`, which the runtime defines
+      // unless the dev server has defined it already.
+      if lazy_proxy_target(&module.resource_id).is_some() {
+        module.stmt_infos.add_stmt_info(StmtInfo {
+          stmt_idx: None,
+          declared_symbols: vec![],
+          referenced_symbols: vec![self.runtime.resolve_symbol("__rolldown_lazy__")],
+          member_only_references: FxHashMap::default(),
+          side_effect: true,
+          is_included: false,
+          import_records: Vec::new(),
+          debug_label: None,
+        });
+      }
     });
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn link(mut self) -> LinkStageOutput {
-    self.sort_modules();
+    // Modules that failed to build are missing from the module table, so there's nothing to link.
+    if self.errors.is_empty() {
+      self.sort_modules();
 
-    self.determine_module_exports_kind();
-    self.wrap_modules();
-    self.bind_imports_and_exports();
+      self.determine_module_exports_kind();
+      self.wrap_modules();
+      self.bind_imports_and_exports();
+      self.inline_const_enums();
+      self.warn_conflicting_entry_exports();
+      self.warn_unused_externals();
 
-    self.create_exports_for_modules();
-    self.reference_needed_symbols();
-    self.include_statements();
+      self.create_exports_for_modules();
+      self.reference_needed_symbols();
+      self.include_statements();
+      self.check_unsupported_features();
+      self.detect_duplicate_packages();
+    }
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
 
     LinkStageOutput {
@@ -157,6 +217,7 @@ impl<'a> LinkStage<'a> {
       warnings: self.warnings,
       errors: self.errors,
       ast_table: self.ast_table,
+      duplicate_packages: self.duplicate_packages,
     }
   }
 
@@ -165,6 +226,18 @@ impl<'a> LinkStage<'a> {
     // Maximize the compatibility with commonjs
     let compat_mode = true;
     let entry_ids_set = self.entries.iter().map(|e| e.id).collect::<FxHashSet<_>>();
+    // Without `strictRequires`, ES modules that are only required at the top level don't need to be evaluated lazily.
+    let conditionally_required_ids = self
+      .module_table
+      .normal_modules
+      .iter()
+      .flat_map(|m| m.import_records.iter())
+      .filter(|rec| {
+        matches!(rec.kind, ImportKind::Require)
+          && !rec.meta.contains(ImportRecordMeta::IS_TOP_LEVEL)
+      })
+      .filter_map(|rec| rec.resolved_module.as_normal())
+      .collect::<FxHashSet<_>>();
     self.module_table.normal_modules.iter().for_each(|importer| {
       importer.import_records.iter().for_each(|rec| {
         let ModuleId::Normal(importee_id) = rec.resolved_module else {
@@ -177,8 +250,12 @@ impl<'a> LinkStage<'a> {
             if matches!(importee.exports_kind, ExportsKind::None) {
               if compat_mode {
                 // See https://github.com/evanw/esbuild/issues/447
-                if rec.contains_import_default || rec.contains_import_star {
-                  self.metas[importee.id].wrap_kind = WrapKind::Cjs;
+                if rec.meta.intersects(
+                  ImportRecordMeta::CONTAINS_IMPORT_DEFAULT
+                    | ImportRecordMeta::CONTAINS_IMPORT_STAR,
+                ) {
+                  self.metas[importee.id]
+                    .wrap(WrapKind::Cjs, WrapReason::ImportedAsCommonJs { importer: importer.id });
                   // SAFETY: If `importee` and `importer` are different, so this is safe. If they are the same, then behaviors are still expected.
                   unsafe {
                     let importee_mut = addr_of!(*importee).cast_mut();
@@ -186,7 +263,8 @@ impl<'a> LinkStage<'a> {
                   }
                 }
               } else {
-                self.metas[importee.id].wrap_kind = WrapKind::Esm;
+                self.metas[importee.id]
+                  .wrap(WrapKind::Esm, WrapReason::ImportedAsCommonJs { importer: importer.id });
                 unsafe {
                   let importee_mut = addr_of!(*importee).cast_mut();
                   (*importee_mut).exports_kind = ExportsKind::Esm;
@@ -196,14 +274,21 @@ impl<'a> LinkStage<'a> {
           }
           ImportKind::Require => match importee.exports_kind {
             ExportsKind::Esm => {
-              self.metas[importee.id].wrap_kind = WrapKind::Esm;
+              if self.input_options.strict_requires()
+                || conditionally_required_ids.contains(&importee.id)
+              {
+                self.metas[importee.id]
+                  .wrap(WrapKind::Esm, WrapReason::Required { importer: importer.id });
+              }
             }
             ExportsKind::CommonJs => {
-              self.metas[importee.id].wrap_kind = WrapKind::Cjs;
+              self.metas[importee.id]
+                .wrap(WrapKind::Cjs, WrapReason::Required { importer: importer.id });
             }
             ExportsKind::None => {
               if compat_mode {
-                self.metas[importee.id].wrap_kind = WrapKind::Cjs;
+                self.metas[importee.id]
+                  .wrap(WrapKind::Cjs, WrapReason::Required { importer: importer.id });
                 // SAFETY: If `importee` and `importer` are different, so this is safe. If they are the same, then behaviors are still expected.
                 // A module with `ExportsKind::None` that `require` self should be turned into `ExportsKind::CommonJs`.
                 unsafe {
@@ -211,7 +296,8 @@ impl<'a> LinkStage<'a> {
                   (*importee_mut).exports_kind = ExportsKind::CommonJs;
                 }
               } else {
-                self.metas[importee.id].wrap_kind = WrapKind::Esm;
+                self.metas[importee.id]
+                  .wrap(WrapKind::Esm, WrapReason::Required { importer: importer.id });
                 unsafe {
                   let importee_mut = addr_of!(*importee).cast_mut();
                   (*importee_mut).exports_kind = ExportsKind::Esm;
@@ -227,7 +313,7 @@ impl<'a> LinkStage<'a> {
       if matches!(importer.exports_kind, ExportsKind::CommonJs)
         && (!is_entry || matches!(self.input_options.format, OutputFormat::Esm))
       {
-        self.metas[importer.id].wrap_kind = WrapKind::Cjs;
+        self.metas[importer.id].wrap(WrapKind::Cjs, WrapReason::CommonJs);
       }
     });
   }
@@ -243,15 +329,27 @@ impl<'a> LinkStage<'a> {
       let stmt_infos = unsafe { &mut *(addr_of!(importer.stmt_infos).cast_mut()) };
 
       stmt_infos.iter_mut().for_each(|stmt_info| {
+        let has_dynamic_require = stmt_info
+          .import_records
+          .iter()
+          .any(|rec_id| importer.dynamic_requires.values().any(|ids| ids.contains(rec_id)));
+        if has_dynamic_require {
+          // something like `__dynamicRequire({ './foo.js': () => require_foo() }, path)`
+          stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__dynamicRequire"));
+        }
         stmt_info.import_records.iter().for_each(|rec_id| {
           let rec = &importer.import_records[*rec_id];
           match rec.resolved_module {
             ModuleId::External(importee_id) => {
-              // Make sure symbols from external modules are included and de_conflicted
-              stmt_info.side_effect = true;
+              // Make sure symbols from external modules are included and de_conflicted. `require(...)` calls
+              // declare no symbols, so they're kept or tree shaken like any other call.
+              if !matches!(rec.kind, ImportKind::Require) {
+                stmt_info.side_effect = true;
+              }
               match rec.kind {
                 ImportKind::Import => {
-                  if matches!(self.input_options.format, OutputFormat::Cjs) && !rec.is_plain_import
+                  if matches!(self.input_options.format, OutputFormat::Cjs)
+                    && !rec.meta.contains(ImportRecordMeta::IS_PLAIN_IMPORT)
                   {
                     stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__toESM"));
                   }
@@ -261,8 +359,12 @@ impl<'a> LinkStage<'a> {
                     symbols.lock().unwrap().get_mut(rec.namespace_ref).name =
                       format!("import_{}", legitimize_identifier_name(&importee.name)).into();
                     stmt_info.declared_symbols.push(rec.namespace_ref);
-                    stmt_info.referenced_symbols.push(importer.namespace_object_ref);
-                    stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__reExport"));
+                    // ES modules re-export names of external modules statically, so they're only copied to the
+                    // namespace object if it's used. See `create_exports_for_modules`.
+                    if !matches!(self.input_options.format, OutputFormat::Esm) {
+                      stmt_info.referenced_symbols.push(importer.namespace_object_ref);
+                      stmt_info.referenced_symbols.push(self.runtime.resolve_symbol("__reExport"));
+                    }
                   }
                 }
                 _ => {}
@@ -321,7 +423,16 @@ impl<'a> LinkStage<'a> {
                   }
                 }
                 ImportKind::Require => match importee_linking_info.wrap_kind {
-                  WrapKind::None => {}
+                  WrapKind::None => {
+                    // The required ES module is hoisted. Something like `__toCommonJS(foo_exports)`
+                    let importee = &self.module_table.normal_modules[importee_id];
+                    if matches!(importee.exports_kind, ExportsKind::Esm) {
+                      stmt_info
+                        .referenced_symbols
+                        .push(self.runtime.resolve_symbol("__toCommonJS"));
+                      stmt_info.referenced_symbols.push(importee.namespace_object_ref);
+                    }
+                  }
                   WrapKind::Cjs => {
                     // something like `require_foo()`
                     // Reference to `require_foo`
@@ -364,9 +475,11 @@ pub fn init_entry_point_stmt_info(
   // Entry chunk need to generate exports, so we need reference to all exports to make sure they are included in tree-shaking.
   referenced_symbols.extend(meta.canonical_exports().map(|(_, export)| export.symbol_ref));
 
-  if matches!(module.exports_kind, ExportsKind::Esm) && matches!(options.format, OutputFormat::Cjs)
+  if matches!(module.exports_kind, ExportsKind::Esm)
+    && matches!(options.format, OutputFormat::Cjs)
+    && (!meta.is_canonical_exports_empty() || !module.star_exports.is_empty())
   {
-    // We will generate `module.exports = __toCommonJS(exports);` for esm modules that are entry points
+    // We will generate `module.exports = __toCommonJS(exports);` for esm modules that are entry points with exports
     // Include the namespace statement
     referenced_symbols.push(module.namespace_object_ref);
     referenced_symbols.push(runtime.resolve_symbol("__toCommonJS"));
@@ -1,8 +1,9 @@
-use std::iter;
+use std::{iter, path::Path};
 
 use rolldown_common::ModuleId;
 use rolldown_error::BuildError;
 use rustc_hash::{FxHashMap, FxHashSet};
+use sugar_path::SugarPath;
 
 use super::LinkStage;
 
@@ -112,15 +113,7 @@ impl<'a> LinkStage<'a> {
     }
 
     if !circular_dependencies.is_empty() {
-      let cycles = circular_dependencies.into_iter().collect::<Vec<_>>();
-      for cycle in cycles {
-        let paths = cycle
-          .iter()
-          .filter_map(|id| id.as_normal())
-          .map(|id| self.module_table.normal_modules[id].resource_id.to_string())
-          .collect::<Vec<_>>();
-        self.warnings.push(BuildError::circular_dependency(paths).with_severity_warning());
-      }
+      self.report_circular_dependencies(circular_dependencies);
     }
 
     self.sorted_modules = sorted_modules;
@@ -130,4 +123,39 @@ impl<'a> LinkStage<'a> {
       "runtime module should always be the first module in the sorted modules"
     );
   }
+
+  /// Cycles with a module matching `circularDependency.exclude` are known to be safe and not reported.
+  fn report_circular_dependencies(&mut self, circular_dependencies: FxHashSet<Box<[ModuleId]>>) {
+    // Invalid patterns are reported by validating options before the build starts, so they're skipped here.
+    let cwd = &self.input_options.cwd;
+    let exclude = self
+      .input_options
+      .circular_dependency_exclude
+      .iter()
+      .filter_map(|pattern| {
+        glob::Pattern::new(&cwd.join(pattern).normalize().to_string_lossy()).ok()
+      })
+      .collect::<Vec<_>>();
+
+    for cycle in circular_dependencies {
+      let modules = cycle
+        .iter()
+        .filter_map(|id| id.as_normal())
+        .map(|id| &self.module_table.normal_modules[id])
+        .collect::<Vec<_>>();
+      let is_excluded = modules.iter().any(|module| {
+        exclude.iter().any(|pattern| pattern.matches_path(Path::new(module.resource_id.as_str())))
+      });
+      if is_excluded {
+        continue;
+      }
+      let paths = modules.iter().map(|module| module.resource_id.to_string()).collect();
+      let error = BuildError::circular_dependency(paths);
+      if self.input_options.circular_dependency_error() {
+        self.errors.push(error);
+      } else {
+        self.warnings.push(error.with_severity_warning());
+      }
+    }
+  }
 }
@@ -0,0 +1,48 @@
+use rolldown_common::{ModuleId, Specifier};
+use rolldown_error::BuildError;
+use rustc_hash::FxHashSet;
+
+use super::LinkStage;
+
+impl<'a> LinkStage<'a> {
+  /// Warn about ids listed in `external` that no import matches, which are usually left over from removed dependencies.
+  pub(super) fn warn_unused_externals(&mut self) {
+    let Some(external) = &self.input_options.external else {
+      return;
+    };
+    if external.ids().is_empty() {
+      return;
+    }
+
+    // Modules could be required by functions that `createRequire` returns, which aren't tracked as imports.
+    let uses_create_require = self.module_table.normal_modules.iter().any(|module| {
+      module.named_imports.values().any(|import| {
+        matches!(&import.imported, Specifier::Literal(name) if name.as_str() == "createRequire")
+          && matches!(
+            module.import_records[import.record_id].module_request.as_str(),
+            "module" | "node:module"
+          )
+      })
+    });
+    if uses_create_require {
+      return;
+    }
+
+    // `external` is matched against both the specifier of the import and the resolved id.
+    let mut matched = FxHashSet::default();
+    for module in &self.module_table.normal_modules {
+      for record in &module.import_records {
+        if let ModuleId::External(id) = record.resolved_module {
+          matched.insert(record.module_request.as_str());
+          matched.insert(self.module_table.external_modules[id].name.as_str());
+        }
+      }
+    }
+
+    for id in external.ids() {
+      if !matched.contains(id.as_str()) {
+        self.warnings.push(BuildError::unused_external(id.clone()));
+      }
+    }
+  }
+}
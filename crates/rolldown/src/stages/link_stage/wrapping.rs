@@ -2,11 +2,12 @@ use oxc_index::IndexVec;
 use rolldown_common::{
   ExportsKind, ModuleId, NormalModule, NormalModuleId, NormalModuleVec, StmtInfo, WrapKind,
 };
+use rustc_hash::FxHashMap;
 
 use crate::{
   runtime::RuntimeModuleBrief,
   types::{
-    linking_metadata::{LinkingMetadata, LinkingMetadataVec},
+    linking_metadata::{LinkingMetadata, LinkingMetadataVec, WrapReason},
     symbols::Symbols,
   },
 };
@@ -19,7 +20,8 @@ struct Context<'a> {
   pub modules: &'a NormalModuleVec,
 }
 
-fn wrap_module_recursively(ctx: &mut Context, target: NormalModuleId) {
+/// `reason` is why `target` is wrapped, if it isn't wrapped yet.
+fn wrap_module_recursively(ctx: &mut Context, target: NormalModuleId, reason: WrapReason) {
   let is_visited = &mut ctx.visited_modules[target];
   if *is_visited {
     return;
@@ -29,15 +31,16 @@ fn wrap_module_recursively(ctx: &mut Context, target: NormalModuleId) {
   let module = &ctx.modules[target];
 
   if matches!(ctx.linking_infos[target].wrap_kind, WrapKind::None) {
-    ctx.linking_infos[target].wrap_kind = match module.exports_kind {
+    let wrap_kind = match module.exports_kind {
       ExportsKind::Esm | ExportsKind::None => WrapKind::Esm,
       ExportsKind::CommonJs => WrapKind::Cjs,
-    }
+    };
+    ctx.linking_infos[target].wrap(wrap_kind, reason);
   }
 
   module.import_records.iter().filter_map(|rec| rec.resolved_module.as_normal()).for_each(
     |importee| {
-      wrap_module_recursively(ctx, importee);
+      wrap_module_recursively(ctx, importee, WrapReason::ImportedByWrapped { importer: target });
     },
   );
 }
@@ -93,6 +96,8 @@ impl LinkStage<'_> {
 
       match linking_info.wrap_kind {
         WrapKind::Cjs | WrapKind::Esm => {
+          // The module is wrapped already, so the reason is never used.
+          let reason = linking_info.wrap_reason.unwrap_or(WrapReason::CommonJs);
           wrap_module_recursively(
             &mut Context {
               visited_modules: &mut visited_modules_for_wrapping,
@@ -100,6 +105,7 @@ impl LinkStage<'_> {
               modules: &self.module_table.normal_modules,
             },
             module_id,
+            reason,
           );
         }
         WrapKind::None => {}
@@ -127,6 +133,7 @@ impl LinkStage<'_> {
               modules: &self.module_table.normal_modules,
             },
             importee.id,
+            WrapReason::ImportedAsCommonJs { importer: module_id },
           );
         }
       });
@@ -156,6 +163,7 @@ pub fn create_wrapper(
         stmt_idx: None,
         declared_symbols: vec![wrapper_ref],
         referenced_symbols: vec![runtime.resolve_symbol("__commonJSMin")],
+        member_only_references: FxHashMap::default(),
         side_effect: false,
         is_included: false,
         import_records: Vec::new(),
@@ -181,6 +189,7 @@ pub fn create_wrapper(
         stmt_idx: None,
         declared_symbols: vec![wrapper_ref],
         referenced_symbols: vec![runtime.resolve_symbol("__esmMin")],
+        member_only_references: FxHashMap::default(),
         side_effect: false,
         is_included: false,
         import_records: Vec::new(),
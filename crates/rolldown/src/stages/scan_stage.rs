@@ -3,26 +3,32 @@ use std::sync::Arc;
 use anyhow::Result;
 use futures::future::join_all;
 use oxc_index::IndexVec;
-use rolldown_common::{EntryPoint, ImportKind, ModuleTable, NormalModuleId, ResolvedRequestInfo};
+use rolldown_common::{
+  EntryPoint, ImportKind, ModuleTable, ModuleType, NormalModuleId, ResolvedRequestInfo,
+};
 use rolldown_error::BuildError;
-use rolldown_fs::OsFileSystem;
+use rolldown_fs::SharedFileSystem;
 use rolldown_oxc_utils::OxcAst;
 use rolldown_plugin::{HookResolveIdExtraOptions, SharedPluginDriver};
 use rolldown_resolver::ResolveError;
 
 use crate::{
-  module_loader::{module_loader::ModuleLoaderOutput, ModuleLoader},
+  module_loader::{build_state::BuildState, module_loader::ModuleLoaderOutput, ModuleLoader},
   runtime::RuntimeModuleBrief,
   types::symbols::Symbols,
-  utils::resolve_id::resolve_id,
+  utils::{
+    input_glob::{expand_input_glob, is_glob_entry},
+    resolve_id::resolve_id,
+  },
   SharedOptions, SharedResolver,
 };
 
 pub struct ScanStage {
   input_options: SharedOptions,
   plugin_driver: SharedPluginDriver,
-  fs: OsFileSystem,
+  fs: SharedFileSystem,
   resolver: SharedResolver,
+  build_state: BuildState,
   pub errors: Vec<BuildError>,
 }
 
@@ -41,21 +47,26 @@ impl ScanStage {
   pub fn new(
     input_options: SharedOptions,
     plugin_driver: SharedPluginDriver,
-    fs: OsFileSystem,
+    fs: SharedFileSystem,
     resolver: SharedResolver,
+    build_state: BuildState,
   ) -> Self {
-    Self { input_options, plugin_driver, fs, resolver, errors: vec![] }
+    Self { input_options, plugin_driver, fs, resolver, build_state, errors: vec![] }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn scan(&mut self) -> anyhow::Result<ScanStageOutput> {
-    assert!(!self.input_options.input.is_empty(), "You must supply options.input to rolldown");
+    assert!(
+      !self.input_options.input.is_empty() || self.input_options.stdin.is_some(),
+      "You must supply options.input to rolldown"
+    );
 
     let module_loader = ModuleLoader::new(
       Arc::clone(&self.input_options),
       Arc::clone(&self.plugin_driver),
       self.fs.clone(),
       Arc::clone(&self.resolver),
+      self.build_state.clone(),
     );
 
     let user_entries = self.resolve_user_defined_entries().await?;
@@ -91,7 +102,21 @@ impl ScanStage {
     let resolver = &self.resolver;
     let plugin_driver = &self.plugin_driver;
 
-    let resolved_ids = join_all(self.input_options.input.iter().map(|input_item| async move {
+    // Glob patterns are expanded on every build, so files added since the last build become entries as well.
+    let mut input = Vec::with_capacity(self.input_options.input.len());
+    for input_item in &self.input_options.input {
+      if is_glob_entry(&input_item.import) {
+        let expanded = expand_input_glob(&self.fs, input_item, &self.input_options.cwd);
+        if expanded.is_empty() {
+          self.errors.push(BuildError::unresolved_entry(&input_item.import, None));
+        }
+        input.extend(expanded);
+      } else {
+        input.push(input_item.clone());
+      }
+    }
+
+    let resolved_ids = join_all(input.iter().map(|input_item| async move {
       struct Args<'a> {
         specifier: &'a str,
       }
@@ -101,7 +126,7 @@ impl ScanStage {
         plugin_driver,
         args.specifier,
         None,
-        HookResolveIdExtraOptions { is_entry: true, kind: ImportKind::Import },
+        HookResolveIdExtraOptions { is_entry: true, kind: ImportKind::Import, attributes: vec![] },
       )
       .await;
 
@@ -109,7 +134,22 @@ impl ScanStage {
     }))
     .await;
 
-    let mut ret = Vec::with_capacity(self.input_options.input.len());
+    let mut ret = Vec::with_capacity(input.len() + 1);
+
+    // The entry of `stdin` is virtual, so it isn't resolved. Its id is under the resolve directory, so relative
+    // imports of it are resolved from there.
+    if let Some(stdin) = &self.input_options.stdin {
+      ret.push((
+        Some("stdin".to_string()),
+        ResolvedRequestInfo {
+          path: stdin.id.clone().into(),
+          module_type: ModuleType::Unknown,
+          is_external: false,
+          package_json: None,
+          side_effects: None,
+        },
+      ));
+    }
 
     for resolve_id in resolved_ids {
       let (args, resolve_id) = resolve_id?;
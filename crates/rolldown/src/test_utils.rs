@@ -0,0 +1,25 @@
+use std::path::Path;
+
+use rolldown_common::InputItem;
+use rolldown_fs::MemoryFileSystem;
+
+use crate::{BundlerBuilder, BundlerOptions};
+
+/// A file system in memory with `files`, whose paths are relative to `/project` unless they're absolute.
+pub fn memory_fs(files: &[(&str, &str)]) -> MemoryFileSystem {
+  let mut fs = MemoryFileSystem::default();
+  for (path, content) in files {
+    fs.add_file(&Path::new("/project").join(path), content);
+  }
+  fs
+}
+
+/// A builder of the bundler reading files from `fs`. `cwd` is `/project` unless it's given, and `main.js` is the entry
+/// unless `input` is given.
+pub fn memory_bundler(fs: &MemoryFileSystem, mut options: BundlerOptions) -> BundlerBuilder {
+  options.cwd.get_or_insert_with(|| "/project".into());
+  options.input.get_or_insert_with(|| {
+    vec![InputItem { name: Some("main".to_string()), import: "./main.js".to_string() }]
+  });
+  BundlerBuilder::default().with_options(options).with_file_system(fs.clone())
+}
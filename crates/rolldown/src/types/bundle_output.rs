@@ -1,9 +1,15 @@
 use rolldown_common::Output;
 use rolldown_error::BuildError;
 
+use crate::utils::{hmr::HmrUpdate, size_report::ChunkSize};
+
 #[derive(Default)]
 pub struct BundleOutput {
   pub warnings: Vec<BuildError>,
   pub errors: Vec<BuildError>,
   pub assets: Vec<Output>,
+  /// The update chunk of modules changed since the last build in watch mode with `hmr`, which is in `assets` as well.
+  pub hmr_update: Option<HmrUpdate>,
+  /// Sizes of chunks measured as they're written with `streamWrites`, since their code is dropped once written.
+  pub(crate) streamed_chunk_sizes: Vec<ChunkSize>,
 }
@@ -0,0 +1,46 @@
+use std::{
+  fmt::Display,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
+
+/// Cancels the build in progress. Builds check it between stages and while processing modules in parallel, and fail
+/// with `BuildCancelled` once it's cancelled. Each build starts uncancelled.
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+  pub fn cancel(&self) {
+    self.0.store(true, Ordering::Relaxed);
+  }
+
+  pub fn is_cancelled(&self) -> bool {
+    self.0.load(Ordering::Relaxed)
+  }
+
+  pub(crate) fn reset(&self) {
+    self.0.store(false, Ordering::Relaxed);
+  }
+
+  pub(crate) fn check(&self) -> anyhow::Result<()> {
+    if self.is_cancelled() {
+      Err(BuildCancelled.into())
+    } else {
+      Ok(())
+    }
+  }
+}
+
+/// The error of cancelled builds. Check it with `err.is::<BuildCancelled>()`.
+#[derive(Debug)]
+pub struct BuildCancelled;
+
+impl Display for BuildCancelled {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "The build was cancelled")
+  }
+}
+
+impl std::error::Error for BuildCancelled {}
@@ -3,6 +3,21 @@ use rolldown_common::{NormalModuleId, ResolvedExport, StmtInfoId, SymbolRef, Wra
 use rolldown_rstr::Rstr;
 use rustc_hash::{FxHashMap, FxHashSet};
 
+/// Why a module is wrapped, which is reported in the metafile, so users could restructure the code to avoid wrappers.
+#[derive(Debug, Clone, Copy)]
+pub enum WrapReason {
+  /// The module is CommonJS and isn't an entry of `cjs` output.
+  CommonJs,
+  /// The module is `require`d by the importer.
+  Required { importer: NormalModuleId },
+  /// The module is imported by the importer, and is CommonJS or has no exports but its default export or namespace
+  /// is imported, so it's treated as CommonJS.
+  ImportedAsCommonJs { importer: NormalModuleId },
+  /// The module is imported by the importer, which is wrapped. Importees of wrapped modules are wrapped too, so they
+  /// are evaluated when the wrapper of the importer is called.
+  ImportedByWrapped { importer: NormalModuleId },
+}
+
 /// Module metadata about linking
 #[derive(Debug, Default)]
 pub struct LinkingMetadata {
@@ -32,6 +47,8 @@ pub struct LinkingMetadata {
   pub wrapper_ref: Option<SymbolRef>,
   pub wrapper_stmt_info: Option<StmtInfoId>,
   pub wrap_kind: WrapKind,
+  /// The first reason the module is wrapped for. `None` if it isn't wrapped.
+  pub wrap_reason: Option<WrapReason>,
   // Store the export info for each module, including export named declaration and export star declaration.
   pub resolved_exports: FxHashMap<Rstr, ResolvedExport>,
   pub re_export_all_names: FxHashSet<Rstr>,
@@ -50,6 +67,11 @@ pub struct LinkingMetadata {
 }
 
 impl LinkingMetadata {
+  pub fn wrap(&mut self, wrap_kind: WrapKind, reason: WrapReason) {
+    self.wrap_kind = wrap_kind;
+    self.wrap_reason.get_or_insert(reason);
+  }
+
   pub fn canonical_exports(&self) -> impl Iterator<Item = (&Rstr, &ResolvedExport)> {
     self
       .sorted_and_non_ambiguous_resolved_exports
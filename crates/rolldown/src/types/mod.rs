@@ -5,6 +5,7 @@
 pub mod ast_symbols;
 pub mod bundle_output;
 pub mod bundler_fs;
+pub mod cancellation_token;
 pub mod linking_metadata;
 pub mod module_render_output;
 pub mod namespace_alias;
@@ -8,4 +8,5 @@ pub struct ModuleRenderOutput<'a> {
   pub rendered_content: String,
   pub sourcemap: Option<SourceMap>,
   pub lines_count: u32,
+  pub legal_comments: &'a [String],
 }
@@ -17,6 +17,11 @@ pub async fn augment_chunk_hash<'a>(
         augment_chunk_hash,
         file_dir: chunk.file_dir,
         preliminary_filename: chunk.preliminary_filename,
+        name: chunk.name,
+        legal_comments: chunk.legal_comments,
+        css: chunk.css,
+        html: chunk.html,
+        hash: chunk.hash,
       }
     })
   }))
@@ -1,29 +1,52 @@
-use std::borrow::Cow;
-
+use super::render_chunk_imports::node_esm_shims;
 use crate::{stages::link_stage::LinkStageOutput, utils::renamer::Renamer};
-use rolldown_common::Chunk;
-use rolldown_rstr::ToRstr;
+use rolldown_common::{Chunk, NormalizedBundlerOptions, SymbolRef};
+use rolldown_rstr::{Rstr, ToRstr};
+use rustc_hash::FxHashMap;
 
+/// Returns canonical names of symbols in the chunk. It only reads the chunk and the link output, so chunks can be
+/// deconflicted in parallel. Names only depend on the order of modules in the chunk, which keeps them deterministic.
 #[tracing::instrument(level = "trace", skip_all)]
-pub fn deconflict_chunk_symbols(chunk: &mut Chunk, link_output: &LinkStageOutput) {
+pub fn deconflict_chunk_symbols(
+  chunk: &Chunk,
+  link_output: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+) -> FxHashMap<SymbolRef, Rstr> {
   let mut renamer =
-    Renamer::new(&link_output.symbols, link_output.module_table.normal_modules.len());
+    Renamer::new(&link_output.symbols, link_output.module_table.normal_modules.len(), options);
 
   chunk
     .modules
     .iter()
     .copied()
     .map(|id| &link_output.module_table.normal_modules[id])
-    .flat_map(|m| m.scope.root_unresolved_references().keys().map(Cow::Borrowed))
+    .flat_map(|m| m.scope.root_unresolved_references().keys())
     .for_each(|name| {
       // global names should be reserved
-      renamer.reserve(Cow::Owned(name.to_rstr()));
+      renamer.reserve_global(name.to_rstr());
     });
 
+  // Names declared at the top of the chunk by shims of Node.js globals.
+  node_esm_shims(chunk, link_output, options).declared_names().for_each(|name| {
+    renamer.reserve_global(Rstr::new(name));
+  });
+
   chunk.imports_from_other_chunks.iter().flat_map(|(_, items)| items.iter()).for_each(|item| {
     renamer.add_top_level_symbol(item.import_ref);
   });
 
+  let mut namespace_refs = chunk
+    .live_imports_from_other_chunks
+    .values()
+    .map(|(namespace_ref, _)| *namespace_ref)
+    .collect::<Vec<_>>();
+  // Keep names deterministic
+  namespace_refs.sort_unstable_by_key(|namespace_ref| namespace_ref.symbol);
+  namespace_refs.dedup();
+  namespace_refs.into_iter().for_each(|namespace_ref| {
+    renamer.add_top_level_symbol(namespace_ref);
+  });
+
   chunk
     .modules
     .iter()
@@ -45,5 +68,75 @@ pub fn deconflict_chunk_symbols(chunk: &mut Chunk, link_output: &LinkStageOutput
   // rename non-top-level names
   renamer.rename_non_top_level_symbol(&chunk.modules, &link_output.module_table.normal_modules);
 
-  chunk.canonical_names = renamer.into_canonical_names();
+  renamer.into_canonical_names()
+}
+
+#[cfg(test)]
+mod tests {
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_deconflict_chunks_deterministically() {
+    use rolldown_common::{BundlerOptions, InputItem, Output};
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    // Each entry is its own chunk, and imports modules declaring the same names.
+    let mut files = vec![];
+    let mut input = vec![];
+    for entry in 0..8 {
+      let imports = (0..4)
+        .map(|dep| format!("import {{ value as v{dep} }} from './dep_{entry}_{dep}.js';"))
+        .collect::<Vec<_>>()
+        .join("\n");
+      let uses = (0..4).map(|dep| format!("v{dep}")).collect::<Vec<_>>().join(", ");
+      files.push((
+        format!("entry_{entry}.js"),
+        format!("{imports}\nconst value = 'entry';\nconsole.log(value, {uses});\n"),
+      ));
+      for dep in 0..4 {
+        files.push((
+          format!("dep_{entry}_{dep}.js"),
+          format!("const value = '{entry}_{dep}';\nexport {{ value }};\n"),
+        ));
+      }
+      input.push(InputItem {
+        name: Some(format!("entry_{entry}")),
+        import: format!("./entry_{entry}.js"),
+      });
+    }
+    let fs = memory_fs(
+      &files.iter().map(|(path, code)| (path.as_str(), code.as_str())).collect::<Vec<_>>(),
+    );
+    let generate = |threads: usize| {
+      let mut bundler = memory_bundler(
+        &fs,
+        BundlerOptions { input: Some(input.clone()), threads: Some(threads), ..Default::default() },
+      )
+      .build();
+      async move {
+        let output = bundler.generate().await.unwrap();
+        assert!(output.errors.is_empty(), "{:?}", output.errors);
+        output
+          .assets
+          .iter()
+          .filter_map(|asset| match asset {
+            Output::Chunk(chunk) => Some((chunk.filename.to_string(), chunk.code.clone())),
+            Output::Asset(_) => None,
+          })
+          .collect::<Vec<_>>()
+      }
+    };
+
+    // Chunks are deconflicted in parallel, and the names are the same regardless of the number of threads.
+    let chunks = generate(1).await;
+    assert_eq!(chunks.len(), 8);
+    for (_, code) in &chunks {
+      for name in ["value", "value$1", "value$2", "value$3", "value$4"] {
+        assert!(code.contains(&format!("const {name} = ")), "{code}");
+      }
+    }
+    for _ in 0..4 {
+      assert_eq!(generate(4).await, chunks);
+    }
+  }
 }
@@ -0,0 +1,123 @@
+use std::fmt::Write;
+
+use oxc::{
+  allocator::Allocator,
+  ast::{ast::RegExpLiteral, Visit},
+  parser::Parser,
+  sourcemap::SourceMapBuilder,
+  span::{SourceType, Span},
+};
+use rolldown_sourcemap::SourceMap;
+
+/// Escape non-ASCII characters to `\uXXXX` for `charset: "ascii"`.
+///
+/// Characters outside the BMP are escaped as `\u{XXXXX}`, which is valid in identifiers, string literals, template
+/// literals and comments. Regular expressions without the `u` flag don't support it, so characters in regular
+/// expression literals are escaped as surrogate pairs instead. Columns of the sourcemap are shifted accordingly.
+#[allow(clippy::cast_possible_truncation)]
+pub fn escape_non_ascii(
+  code: String,
+  map: Option<SourceMap>,
+  source_type: SourceType,
+) -> (String, Option<SourceMap>) {
+  if code.is_ascii() {
+    return (code, map);
+  }
+
+  let regexp_spans = if code.chars().any(|ch| ch.len_utf16() == 2) {
+    collect_regexp_spans(&code, source_type)
+  } else {
+    vec![]
+  };
+
+  let mut escaped = String::with_capacity(code.len() + code.len() / 4);
+  // For each line, the UTF-16 column of each escaped character along with the columns it adds.
+  let mut escapes_per_line: Vec<Vec<(u32, u32)>> = vec![vec![]];
+  let mut column = 0;
+  for (offset, ch) in code.char_indices() {
+    if ch == '\n' {
+      escapes_per_line.push(vec![]);
+      column = 0;
+      escaped.push(ch);
+    } else if ch.is_ascii() {
+      column += 1;
+      escaped.push(ch);
+    } else {
+      let escapes = escapes_per_line.last_mut().expect("should have a line");
+      let in_regexp = regexp_spans
+        .get(regexp_spans.partition_point(|span| span.end as usize <= offset))
+        .is_some_and(|span| span.start as usize <= offset);
+      let start = escaped.len();
+      if ch.len_utf16() == 1 || in_regexp {
+        for unit in ch.encode_utf16(&mut [0; 2]) {
+          write!(escaped, "\\u{unit:04X}").expect("writing to a string should not fail");
+        }
+      } else {
+        write!(escaped, "\\u{{{:X}}}", ch as u32).expect("writing to a string should not fail");
+      }
+      let units = ch.len_utf16() as u32;
+      escapes.push((column, (escaped.len() - start) as u32 - units));
+      column += units;
+    }
+  }
+
+  let map = map.map(|map| {
+    let mut sourcemap_builder = SourceMapBuilder::default();
+    for token in map.get_source_view_tokens() {
+      let dst_line = token.get_dst_line();
+      let dst_col = token.get_dst_col();
+      let shift = escapes_per_line.get(dst_line as usize).map_or(0, |escapes| {
+        escapes.iter().take_while(|(column, _)| *column < dst_col).map(|(_, extra)| extra).sum()
+      });
+
+      let name_id = token.get_name().map(|name| sourcemap_builder.add_name(name));
+      let source_id = token.get_source_and_content().map(|(source, source_content)| {
+        sourcemap_builder.add_source_and_content(source, source_content)
+      });
+      sourcemap_builder.add_token(
+        dst_line,
+        dst_col + shift,
+        token.get_src_line(),
+        token.get_src_col(),
+        source_id,
+        name_id,
+      );
+    }
+    sourcemap_builder.into_sourcemap()
+  });
+
+  (escaped, map)
+}
+
+/// Spans of regular expression literals, sorted by their start. Code that can't be parsed is treated as having none.
+fn collect_regexp_spans(code: &str, source_type: SourceType) -> Vec<Span> {
+  #[derive(Default)]
+  struct RegExpCollector {
+    spans: Vec<Span>,
+  }
+
+  impl<'a> Visit<'a> for RegExpCollector {
+    fn visit_reg_expr_literal(&mut self, lit: &RegExpLiteral<'a>) {
+      self.spans.push(lit.span);
+    }
+  }
+
+  let allocator = Allocator::default();
+  let ret = Parser::new(&allocator, code, source_type).parse();
+  let mut collector = RegExpCollector::default();
+  if ret.errors.is_empty() {
+    collector.visit_program(&ret.program);
+  }
+  collector.spans.sort_unstable_by_key(|span| span.start);
+  collector.spans
+}
+
+#[test]
+fn test_escape_non_ascii() {
+  let escape = |code: &str| escape_non_ascii(code.to_string(), None, SourceType::default()).0;
+  assert_eq!(escape("const a = 1;"), "const a = 1;");
+  assert_eq!(escape("'é'"), "'\\u00E9'");
+  assert_eq!(escape("'😈'"), "'\\u{1F608}'");
+  assert_eq!(escape("const 𐀀 = '😈';"), "const \\u{10000} = '\\u{1F608}';");
+  assert_eq!(escape("/😈/.test('😈')"), "/\\uD83D\\uDE08/.test('\\u{1F608}')");
+}
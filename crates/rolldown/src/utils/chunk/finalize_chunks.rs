@@ -2,18 +2,18 @@ use std::hash::Hash;
 
 use itertools::Itertools;
 use oxc_index::IndexVec;
-use rolldown_common::{ChunkId, ResourceId};
-use rolldown_utils::{
-  base64::to_url_safe_base64,
-  rayon::{IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator},
-  xxhash::xxhash_base64_url,
+use rolldown_common::{ChunkId, NormalizedBundlerOptions, ResourceId};
+use rolldown_utils::rayon::{
+  IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator,
 };
 use rustc_hash::FxHashMap;
-use xxhash_rust::xxh3::Xxh3;
 
 use crate::{
   chunk_graph::ChunkGraph,
-  utils::hash_placeholder::{extract_hash_placeholders, replace_facade_hash_replacement},
+  utils::{
+    hash::{encode_hash, hash_content, ContentHasher},
+    hash_placeholder::{extract_hash_placeholders, replace_facade_hash_replacement},
+  },
 };
 
 use super::render_chunk::ChunkRenderReturn;
@@ -22,6 +22,7 @@ use super::render_chunk::ChunkRenderReturn;
 pub fn finalize_chunks(
   chunk_graph: &mut ChunkGraph,
   mut chunks: Vec<ChunkRenderReturn>,
+  options: &NormalizedBundlerOptions,
 ) -> Vec<ChunkRenderReturn> {
   let chunk_id_by_placeholder = chunk_graph
     .chunks
@@ -54,13 +55,13 @@ pub fn finalize_chunks(
       if let Some(augment_chunk_hash) = &chunk.augment_chunk_hash {
         content.extend(augment_chunk_hash.as_bytes());
       }
-      xxhash_base64_url(&content)
+      hash_content(&content, options)
     })
     .collect::<Vec<_>>()
     .into();
 
-  let mut index_chunk_hashers: IndexVec<ChunkId, Xxh3> =
-    oxc_index::index_vec![Xxh3::default(); chunks.len()];
+  let mut index_chunk_hashers: IndexVec<ChunkId, ContentHasher> =
+    oxc_index::index_vec![ContentHasher::new(options.hash_algorithm); chunks.len()];
 
   let index_final_hashes: IndexVec<ChunkId, String> = index_chunk_hashers
     .iter_mut_enumerated()
@@ -80,8 +81,7 @@ pub fn finalize_chunks(
       dependencies.iter().copied().for_each(|dep_id| {
         index_standalone_content_hashes[dep_id].hash(state);
       });
-      let digested = state.digest128();
-      to_url_safe_base64(digested.to_le_bytes())
+      encode_hash(state.digest(), options.hash_characters)
     })
     .collect::<Vec<_>>()
     .into();
@@ -100,8 +100,14 @@ pub fn finalize_chunks(
     })
     .collect::<FxHashMap<_, _>>();
 
-  chunk_graph.chunks.iter_mut().zip(chunks.iter_mut()).par_bridge().for_each(
-    |(chunk, chunk_render_return)| {
+  chunk_graph
+    .chunks
+    .iter_mut()
+    .zip(chunks.iter_mut())
+    .zip(&index_final_hashes)
+    .par_bridge()
+    .for_each(|((chunk, chunk_render_return), hash)| {
+      chunk_render_return.hash = Some(hash.clone());
       let preliminary_filename_raw =
         chunk.preliminary_filename.as_deref().expect("should have file name").to_string();
       let filename: ResourceId =
@@ -113,8 +119,7 @@ pub fn finalize_chunks(
         std::mem::take(&mut chunk_render_return.code),
         &final_hashes_by_placeholder,
       );
-    },
-  );
+    });
 
   // Replace hash placeholder in `imports`
   chunk_graph.chunks.iter().zip(chunks.iter_mut()).par_bridge().for_each(
@@ -8,6 +8,7 @@ use crate::{chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput, Shared
 use self::render_chunk_exports::get_chunk_export_names;
 
 pub mod deconflict_chunk_symbols;
+pub mod escape_non_ascii;
 pub mod finalize_chunks;
 pub mod render_chunk;
 pub mod render_chunk_exports;
@@ -1,17 +1,30 @@
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::{
-  chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput,
+  chunk_graph::ChunkGraph,
+  stages::link_stage::LinkStageOutput,
+  type_alias::IndexNormalModules,
   types::module_render_output::ModuleRenderOutput,
-  utils::render_normal_module::render_normal_module, SharedOptions,
+  utils::{
+    css_urls::replace_css_asset_placeholders, minify_css::minify_css,
+    render_asset_filename::render_asset_filename, render_normal_module::render_normal_module,
+  },
+  SharedOptions,
 };
 
 use anyhow::Result;
+use oxc::span::SourceType;
 use rolldown_common::{
-  Chunk, ChunkKind, ExportsKind, OutputFormat, RenderedChunk, ResourceId, WrapKind,
+  Charset, Chunk, ChunkKind, ExportsKind, LegalComments, NormalModuleId, OutputFormat,
+  RenderedChunk, ResourceId, WrapKind,
 };
+use rolldown_oxc_utils::OxcAst;
 use rolldown_sourcemap::{ConcatSource, RawSource, SourceMap, SourceMapSource};
-use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+use rolldown_utils::{
+  indexmap::FxIndexSet,
+  path_ext::PathExt,
+  rayon::{IntoParallelIterator, ParallelIterator},
+};
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
@@ -22,105 +35,142 @@ pub struct ChunkRenderReturn {
   pub augment_chunk_hash: Option<String>,
   pub file_dir: PathBuf,
   pub preliminary_filename: ResourceId,
+  /// Name of the chunk, which is used for `[name]` in filenames of files emitted along with the chunk.
+  pub name: String,
+  /// Legal comments that need to be written to a separate file. See `LegalComments::is_extracted_to_file`.
+  pub legal_comments: Vec<String>,
+  /// Filename and content of the stylesheet extracted from modules in this chunk.
+  pub css: Option<(String, String)>,
+  /// The document of the HTML entry of this chunk, which is emitted with references to the chunk and its stylesheet.
+  pub html: Option<String>,
+  /// The hash of the chunk used in its filename, which is known once chunks are finalized.
+  pub hash: Option<String>,
 }
 
 use super::{
-  generate_rendered_chunk, render_chunk_exports::render_chunk_exports,
+  escape_non_ascii::escape_non_ascii,
+  generate_rendered_chunk,
+  render_chunk_exports::{render_chunk_exports, render_facade_reexports},
   render_chunk_imports::render_chunk_imports,
 };
 
+/// Code of the modules in a chunk, which is printed from their ASTs before chunks are rendered, so the ASTs could be
+/// released before rendering chunks.
+pub struct ChunkModulesRenderOutput<'a> {
+  pub rendered_modules: Vec<ModuleRenderOutput<'a>>,
+  /// Whether all modules are in strict mode, so CommonJS chunks start with `"use strict"`.
+  pub are_all_strict: bool,
+}
+
+/// Prints the modules of a chunk, releasing the AST of each module once it's printed.
+pub fn render_chunk_modules<'a>(
+  asts: Vec<(NormalModuleId, OxcAst)>,
+  modules: &'a IndexNormalModules,
+  options: &SharedOptions,
+) -> ChunkModulesRenderOutput<'a> {
+  let are_all_strict = asts.iter().all(|(id, ast)| {
+    matches!(modules[*id].exports_kind, ExportsKind::Esm) || ast.contains_use_strict
+  });
+  let rendered_modules = asts
+    .into_par_iter()
+    .filter_map(|(id, ast)| {
+      let m = &modules[id];
+      render_normal_module(m, &ast, m.resource_id.as_ref(), options)
+    })
+    .collect::<Vec<_>>();
+  ChunkModulesRenderOutput { rendered_modules, are_all_strict }
+}
+
 #[allow(clippy::unnecessary_wraps, clippy::cast_possible_truncation)]
 #[tracing::instrument(level = "trace", skip_all)]
 pub async fn render_chunk(
   this: &Chunk,
+  modules: ChunkModulesRenderOutput<'_>,
   options: &SharedOptions,
   graph: &LinkStageOutput,
   chunk_graph: &ChunkGraph,
 ) -> Result<ChunkRenderReturn> {
   let mut rendered_modules = FxHashMap::default();
+  let facade_reexports = render_facade_reexports(this, graph, chunk_graph, options);
   let mut concat_source = ConcatSource::default();
 
   let rendered_chunk = match options.format {
     OutputFormat::Esm | OutputFormat::Cjs => {
-      concat_source.add_source(Box::new(RawSource::new(render_chunk_imports(
-        this,
-        graph,
-        chunk_graph,
-        options,
-      ))));
-
-      this
-        .modules
-        .par_iter()
-        .copied()
-        .map(|id| &graph.module_table.normal_modules[id])
-        .filter_map(|m| {
-          render_normal_module(m, &graph.ast_table[m.id], m.resource_id.as_ref(), options)
-        })
-        .collect::<Vec<_>>()
-        .into_iter()
-        .for_each(|module_render_output| {
-          let ModuleRenderOutput {
-            module_path,
-            module_pretty_path,
-            rendered_module,
+      if let Some(facade_reexports) = &facade_reexports {
+        concat_source.add_source(Box::new(RawSource::new(facade_reexports.as_str())));
+      } else {
+        concat_source.add_source(Box::new(RawSource::new(render_chunk_imports(
+          this,
+          graph,
+          chunk_graph,
+          options,
+        ))));
+      }
+
+      modules.rendered_modules.into_iter().for_each(|module_render_output| {
+        let ModuleRenderOutput {
+          module_path,
+          module_pretty_path,
+          rendered_module,
+          rendered_content,
+          sourcemap,
+          lines_count,
+          legal_comments,
+        } = module_render_output;
+        concat_source.add_source(Box::new(RawSource::new(format!("// {module_pretty_path}",))));
+        if matches!(options.legal_comments, LegalComments::Inline) {
+          for comment in legal_comments {
+            concat_source.add_source(Box::new(RawSource::new(comment.as_str())));
+          }
+        }
+        if let Some(sourcemap) = sourcemap {
+          concat_source.add_source(Box::new(SourceMapSource::new(
             rendered_content,
             sourcemap,
             lines_count,
-          } = module_render_output;
-          concat_source.add_source(Box::new(RawSource::new(format!("// {module_pretty_path}",))));
-          if let Some(sourcemap) = sourcemap {
-            concat_source.add_source(Box::new(SourceMapSource::new(
-              rendered_content,
-              sourcemap,
-              lines_count,
-            )));
-          } else {
-            concat_source.add_source(Box::new(RawSource::new(rendered_content)));
-          }
-          // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
-          if !module_path.starts_with('\0') {
-            rendered_modules.insert(module_path, rendered_module);
-          }
-        });
+          )));
+        } else {
+          concat_source.add_source(Box::new(RawSource::new(rendered_content)));
+        }
+        // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
+        if !module_path.starts_with('\0') {
+          rendered_modules.insert(module_path, rendered_module);
+        }
+      });
 
       generate_rendered_chunk(this, graph, options, rendered_modules, chunk_graph)
     }
     OutputFormat::App => {
-      this
-        .modules
-        .par_iter()
-        .copied()
-        .map(|id| &graph.module_table.normal_modules[id])
-        .filter_map(|m| {
-          render_normal_module(m, &graph.ast_table[m.id], m.resource_id.as_ref(), options)
-        })
-        .collect::<Vec<_>>()
-        .into_iter()
-        .for_each(|module_render_output| {
-          let ModuleRenderOutput {
-            module_path,
-            module_pretty_path,
-            rendered_module,
+      modules.rendered_modules.into_iter().for_each(|module_render_output| {
+        let ModuleRenderOutput {
+          module_path,
+          module_pretty_path,
+          rendered_module,
+          rendered_content,
+          sourcemap,
+          lines_count,
+          legal_comments,
+        } = module_render_output;
+        concat_source.add_source(Box::new(RawSource::new(format!("// {module_pretty_path}",))));
+        if matches!(options.legal_comments, LegalComments::Inline) {
+          for comment in legal_comments {
+            concat_source.add_source(Box::new(RawSource::new(comment.as_str())));
+          }
+        }
+        if let Some(sourcemap) = sourcemap {
+          concat_source.add_source(Box::new(SourceMapSource::new(
             rendered_content,
             sourcemap,
             lines_count,
-          } = module_render_output;
-          concat_source.add_source(Box::new(RawSource::new(format!("// {module_pretty_path}",))));
-          if let Some(sourcemap) = sourcemap {
-            concat_source.add_source(Box::new(SourceMapSource::new(
-              rendered_content,
-              sourcemap,
-              lines_count,
-            )));
-          } else {
-            concat_source.add_source(Box::new(RawSource::new(rendered_content)));
-          }
-          // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
-          if !module_path.starts_with('\0') {
-            rendered_modules.insert(module_path, rendered_module);
-          }
-        });
+          )));
+        } else {
+          concat_source.add_source(Box::new(RawSource::new(rendered_content)));
+        }
+        // FIXME: NAPI-RS used CStr under the hood, so it can't handle null byte in the string.
+        if !module_path.starts_with('\0') {
+          rendered_modules.insert(module_path, rendered_module);
+        }
+      });
       generate_rendered_chunk(this, graph, options, rendered_modules, chunk_graph)
     }
   };
@@ -135,19 +185,8 @@ pub async fn render_chunk(
   }
 
   // Add `use strict` directive if needed. This must come before the banner, because users might use banner to add hashbang.
-  if matches!(options.format, OutputFormat::Cjs) {
-    let are_modules_all_strict = this.modules.iter().all(|id| {
-      let is_esm = matches!(graph.module_table.normal_modules[*id].exports_kind, ExportsKind::Esm);
-      if is_esm {
-        true
-      } else {
-        graph.ast_table[*id].contains_use_strict
-      }
-    });
-
-    if are_modules_all_strict {
-      concat_source.add_prepend_source(Box::new(RawSource::new("\"use strict\";\n".to_string())));
-    }
+  if matches!(options.format, OutputFormat::Cjs) && modules.are_all_strict {
+    concat_source.add_prepend_source(Box::new(RawSource::new("\"use strict\";\n")));
   }
 
   if let ChunkKind::EntryPoint { module: entry_id, .. } = this.kind {
@@ -178,15 +217,59 @@ pub async fn render_chunk(
   }
 
   match options.format {
-    OutputFormat::Esm | OutputFormat::Cjs => {
+    OutputFormat::Esm | OutputFormat::Cjs if facade_reexports.is_none() => {
       if let Some(exports) = render_chunk_exports(this, &graph.runtime, graph, options) {
         concat_source.add_source(Box::new(RawSource::new(exports)));
       }
     }
 
-    OutputFormat::App => {}
+    OutputFormat::Esm | OutputFormat::Cjs | OutputFormat::App => {}
   }
 
+  // Deduplicated legal comments of all modules in this chunk
+  let legal_comments = match options.legal_comments {
+    LegalComments::Eof | LegalComments::Linked | LegalComments::External => this
+      .modules
+      .iter()
+      .flat_map(|id| graph.module_table.normal_modules[*id].legal_comments.iter())
+      .map(String::as_str)
+      .collect::<FxIndexSet<_>>(),
+    LegalComments::Inline | LegalComments::None => FxIndexSet::default(),
+  };
+
+  if matches!(options.legal_comments, LegalComments::Eof) {
+    for comment in &legal_comments {
+      concat_source.add_source(Box::new(RawSource::new(*comment)));
+    }
+  }
+
+  // Stylesheets of modules in this chunk are concatenated in execution order
+  let css_modules = this
+    .modules
+    .iter()
+    .map(|id| &graph.module_table.normal_modules[*id])
+    .filter(|m| m.is_included && m.css.is_some())
+    .collect::<Vec<_>>();
+  let css = css_modules.first().map(|first_css_module| {
+    let mut content =
+      css_modules.iter().filter_map(|m| m.css.as_deref()).collect::<Vec<_>>().join("\n");
+    // Minify before hashing, so the filename reflects the emitted content
+    if options.css_minify() {
+      content = minify_css(&content);
+    }
+    let name = this.name.clone().unwrap_or_else(|| {
+      first_css_module.resource_id.as_path().representative_file_name().into_owned()
+    });
+    let filename =
+      render_asset_filename(options, Path::new(&format!("{name}.css")), content.as_bytes());
+    // Emitted assets are referenced relative to the `.css` file
+    let css_dir = Path::new(&filename).parent().unwrap_or(Path::new(""));
+    let content = replace_css_asset_placeholders(&content, |asset_filename| {
+      asset_filename.relative(css_dir).as_path().expect_to_slash()
+    });
+    (filename, content)
+  });
+
   // add footer
   if let Some(footer) = options.footer.as_ref() {
     if let Some(footer_txt) = footer.call(&rendered_chunk).await? {
@@ -196,7 +279,15 @@ pub async fn render_chunk(
     }
   }
 
-  let (content, mut map) = concat_source.content_and_sourcemap();
+  let (mut content, mut map) = concat_source.content_and_sourcemap();
+
+  if matches!(options.charset, Charset::Ascii) {
+    (content, map) = escape_non_ascii(
+      content,
+      map,
+      SourceType::default().with_module(matches!(options.format, OutputFormat::Esm)),
+    );
+  }
 
   // Here file path is generated by chunk file name template, it maybe including path segments.
   // So here need to read it's parent directory as file_dir.
@@ -228,5 +319,25 @@ pub async fn render_chunk(
       .as_deref()
       .expect("should have preliminary filename")
       .clone(),
+    name: this.name.clone().unwrap_or_else(|| {
+      graph.module_table.normal_modules[this.representative_module()]
+        .resource_id
+        .as_path()
+        .representative_file_name()
+        .into_owned()
+    }),
+    legal_comments: if options.legal_comments.is_extracted_to_file() {
+      legal_comments.into_iter().map(ToString::to_string).collect()
+    } else {
+      vec![]
+    },
+    css,
+    html: match this.kind {
+      ChunkKind::EntryPoint { module, is_user_defined: true, .. } => {
+        graph.module_table.normal_modules[module].html.clone()
+      }
+      _ => None,
+    },
+    hash: None,
   })
 }
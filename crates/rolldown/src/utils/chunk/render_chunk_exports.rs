@@ -1,8 +1,15 @@
-use rolldown_common::{Chunk, ChunkKind, OutputFormat, SymbolRef, WrapKind};
+use rolldown_common::{
+  Chunk, ChunkKind, ExportsKind, ExternalModuleId, NormalModuleId, OutputFormat, Specifier,
+  SymbolRef, WrapKind,
+};
 use rolldown_rstr::Rstr;
 use rolldown_utils::ecma_script::is_validate_identifier_name;
+use rustc_hash::{FxHashMap, FxHashSet};
 
-use crate::{runtime::RuntimeModuleBrief, stages::link_stage::LinkStageOutput, SharedOptions};
+use crate::{
+  chunk_graph::ChunkGraph, runtime::RuntimeModuleBrief, stages::link_stage::LinkStageOutput,
+  SharedOptions,
+};
 
 pub fn render_chunk_exports(
   this: &Chunk,
@@ -11,14 +18,38 @@ pub fn render_chunk_exports(
   output_options: &SharedOptions,
 ) -> Option<String> {
   let export_items = get_export_items(this, graph);
+  // Names exported by external modules can't be known while bundling, so ES modules re-export them statically.
+  let external_star_exports = match (&output_options.format, &this.kind) {
+    (OutputFormat::Esm, ChunkKind::EntryPoint { module, .. }) => {
+      get_external_star_exports(*module, graph)
+    }
+    _ => vec![],
+  };
+  // Bindings of `export *` are copied into the namespace object at runtime, which becomes `module.exports`.
+  let has_cjs_star_exports = match (&output_options.format, &this.kind) {
+    (OutputFormat::Cjs, ChunkKind::EntryPoint { module, .. }) => {
+      !graph.module_table.normal_modules[*module].star_exports.is_empty()
+    }
+    _ => false,
+  };
 
-  if export_items.is_empty() {
+  if export_items.is_empty() && external_star_exports.is_empty() && !has_cjs_star_exports {
     return None;
   }
 
   match output_options.format {
     OutputFormat::Esm => {
       let mut s = String::new();
+      for external_id in external_star_exports {
+        let external = &graph.module_table.external_modules[external_id];
+        s.push_str(&format!(
+          "export * from \"{}\";\n",
+          this.external_import_path_for(&external.name)
+        ));
+      }
+      if export_items.is_empty() {
+        return Some(s);
+      }
       let rendered_items = export_items
         .into_iter()
         .map(|(exported_name, export_ref)| {
@@ -70,6 +101,14 @@ pub fn render_chunk_exports(
               let canonical_ns_name = &this.canonical_names[&ns_alias.namespace_ref];
               let property_name = &ns_alias.property_name;
               s.push_str(&format!("{assignee_name} = {canonical_ns_name}.{property_name};;\n"));
+            } else if graph.module_table.normal_modules[canonical_ref.owner]
+              .scope
+              .is_reassigned(canonical_ref.symbol)
+            {
+              // Keep the binding live for other chunks
+              s.push_str(&format!(
+                "Object.defineProperty(exports, '{exported_name}', {{ enumerable: true, get: () => {canonical_name} }});\n"
+              ));
             } else {
               s.push_str(&format!("{assignee_name} = {canonical_name};\n"));
             }
@@ -83,6 +122,105 @@ pub fn render_chunk_exports(
   }
 }
 
+/// Facades of ES module entries re-export bindings of shared chunks with `export { ... } from`, instead of importing and
+/// exporting them. Returns `None` if the chunk isn't a facade or uses the imported bindings itself, such as calling the
+/// wrapper of the entry module, then imports and exports are rendered as usual.
+pub fn render_facade_reexports(
+  this: &Chunk,
+  graph: &LinkStageOutput,
+  chunk_graph: &ChunkGraph,
+  output_options: &SharedOptions,
+) -> Option<String> {
+  let ChunkKind::EntryPoint { module, .. } = this.kind else {
+    return None;
+  };
+  if !this.is_facade()
+    || !matches!(output_options.format, OutputFormat::Esm)
+    || !matches!(graph.metas[module].wrap_kind, WrapKind::None)
+    || !this.imports_from_external_modules.is_empty()
+  {
+    return None;
+  }
+
+  let export_items = get_export_items(this, graph);
+  if export_items.is_empty() {
+    return None;
+  }
+  let mut exported_names_by_ref: FxHashMap<SymbolRef, Vec<Rstr>> = FxHashMap::default();
+  for (exported_name, export_ref) in export_items {
+    let canonical_ref = graph.symbols.par_canonical_ref_for(export_ref);
+    if graph.symbols.get(canonical_ref).namespace_alias.is_some() {
+      return None;
+    }
+    exported_names_by_ref.entry(canonical_ref).or_default().push(exported_name);
+  }
+
+  let mut s = String::new();
+  for external_id in get_external_star_exports(module, graph) {
+    let external = &graph.module_table.external_modules[external_id];
+    s.push_str(&format!("export * from \"{}\";\n", this.external_import_path_for(&external.name)));
+  }
+  let mut reexported_refs = FxHashSet::default();
+  for (importee_id, items) in &this.imports_from_other_chunks {
+    let mut specifiers = vec![];
+    for item in items {
+      let canonical_ref = graph.symbols.par_canonical_ref_for(item.import_ref);
+      let Some(Specifier::Literal(export_alias)) = &item.export_alias else {
+        return None;
+      };
+      // Bindings that aren't exported are used by the facade itself.
+      let exported_names = exported_names_by_ref.get(&canonical_ref)?;
+      reexported_refs.insert(canonical_ref);
+      specifiers.extend(exported_names.iter().map(|exported_name| {
+        if export_alias == exported_name {
+          exported_name.to_string()
+        } else if is_validate_identifier_name(exported_name) {
+          format!("{export_alias} as {exported_name}")
+        } else {
+          format!("{export_alias} as '{exported_name}'")
+        }
+      }));
+    }
+    let import_path = this.import_path_for(&chunk_graph.chunks[*importee_id]);
+    if specifiers.is_empty() {
+      s.push_str(&format!("import \"{import_path}\";\n"));
+    } else {
+      specifiers.sort();
+      s.push_str(&format!("export {{ {} }} from \"{import_path}\";\n", specifiers.join(", ")));
+    }
+  }
+  if reexported_refs.len() != exported_names_by_ref.len() {
+    return None;
+  }
+  Some(s)
+}
+
+/// External modules re-exported by the entry with `export *`, directly or through other ES modules. Like other exports of
+/// `export *`, explicit exports of the entry take precedence over them.
+fn get_external_star_exports(
+  entry_id: NormalModuleId,
+  graph: &LinkStageOutput,
+) -> Vec<ExternalModuleId> {
+  let modules = &graph.module_table.normal_modules;
+  let mut external_ids = vec![];
+  let mut visited = FxHashSet::default();
+  let mut stack = vec![entry_id];
+  while let Some(id) = stack.pop() {
+    if !visited.insert(id) || matches!(modules[id].exports_kind, ExportsKind::CommonJs) {
+      continue;
+    }
+    let importee_ids = modules[id].star_export_module_ids().collect::<Vec<_>>();
+    for importee_id in importee_ids.iter().filter_map(|id| id.as_external()) {
+      if !external_ids.contains(&importee_id) {
+        external_ids.push(importee_id);
+      }
+    }
+    // Push in reverse, so the first `export *` is visited first.
+    stack.extend(importee_ids.iter().rev().filter_map(|id| id.as_normal()));
+  }
+  external_ids
+}
+
 fn get_export_items(this: &Chunk, graph: &LinkStageOutput) -> Vec<(Rstr, SymbolRef)> {
   match this.kind {
     ChunkKind::EntryPoint { module, .. } => {
@@ -1,4 +1,7 @@
-use rolldown_common::{Chunk, OutputFormat, Specifier};
+use rolldown_common::{
+  Chunk, ImportAttributes, ImportKind, ModuleId, NodeGlobals, NormalizedBundlerOptions,
+  OutputFormat, Platform, Specifier,
+};
 
 use crate::{chunk_graph::ChunkGraph, stages::link_stage::LinkStageOutput, SharedOptions};
 
@@ -12,6 +15,23 @@ pub fn render_chunk_imports(
 ) -> String {
   let mut s = String::new();
 
+  // `require`, `__filename` and `__dirname` are not available in ESM on Node.js. Create them from `import.meta.url`,
+  // so `require(...)` calls to externalized CommonJS packages keep working. For other platforms, `__filename` and
+  // `__dirname` are replaced with constants while finalizing modules.
+  let shims = node_esm_shims(chunk, graph, options);
+  if shims.require {
+    s.push_str("import { createRequire as __rolldown_createRequire } from \"node:module\";\n");
+    s.push_str("var require = __rolldown_createRequire(import.meta.url);\n");
+  }
+  if shims.filename {
+    s.push_str("import { fileURLToPath as __rolldown_fileURLToPath } from \"node:url\";\n");
+    s.push_str("var __filename = __rolldown_fileURLToPath(import.meta.url);\n");
+  }
+  if shims.dirname {
+    s.push_str("import { dirname as __rolldown_dirname } from \"node:path\";\n");
+    s.push_str("var __dirname = __rolldown_dirname(__filename);\n");
+  }
+
   let render_import_specifier = |imported: &str, alias: &str| match options.format {
     OutputFormat::Esm => {
       if imported == alias {
@@ -53,9 +73,9 @@ pub fn render_chunk_imports(
   };
 
   let render_plain_import =
-    |importee_module_specifier: &str, output: &mut String| match options.format {
+    |importee_module_specifier: &str, attributes: &str, output: &mut String| match options.format {
       OutputFormat::Esm => {
-        output.push_str(&format!("import \"{importee_module_specifier}\";\n"));
+        output.push_str(&format!("import \"{importee_module_specifier}\"{attributes};\n"));
       }
       OutputFormat::Cjs => {
         output.push_str(&format!("require(\"{importee_module_specifier}\");\n"));
@@ -69,23 +89,38 @@ pub fn render_chunk_imports(
 
   chunk.imports_from_other_chunks.iter().for_each(|(exporter_id, items)| {
     let importee_chunk = &chunk_graph.chunks[*exporter_id];
+    // Live imports are read from the `exports` of the chunk, instead of being destructured.
+    let mut namespace_ref = None;
     let mut import_items = items
       .iter()
-      .map(|item| {
+      .filter_map(|item| {
         let canonical_ref = graph.symbols.par_canonical_ref_for(item.import_ref);
+        if let Some((live_namespace_ref, _)) =
+          chunk.live_imports_from_other_chunks.get(&canonical_ref)
+        {
+          namespace_ref = Some(*live_namespace_ref);
+          return None;
+        }
         let local_binding = &chunk.canonical_names[&canonical_ref];
         let Specifier::Literal(export_alias) = item.export_alias.as_ref().unwrap() else {
           panic!("should not be star import from other chunks")
         };
-        render_import_specifier(export_alias, local_binding)
+        Some(render_import_specifier(export_alias, local_binding))
       })
       .collect::<Vec<_>>();
 
     let import_path = chunk.import_path_for(importee_chunk);
 
-    if import_items.is_empty() {
+    if let Some(namespace_ref) = namespace_ref {
+      let namespace_name = &chunk.canonical_names[&namespace_ref];
+      s.push_str(&format!("const {namespace_name} = require(\"{import_path}\");\n"));
+      if !import_items.is_empty() {
+        import_items.sort();
+        s.push_str(&format!("const {{ {} }} = {namespace_name};\n", import_items.join(", ")));
+      }
+    } else if import_items.is_empty() {
       // TODO: filename relative to importee
-      render_plain_import(&import_path, &mut s);
+      render_plain_import(&import_path, "", &mut s);
     } else {
       import_items.sort();
       render_import_stmt(&import_items, &import_path, &mut s);
@@ -101,6 +136,8 @@ pub fn render_chunk_imports(
 
   imports_from_external_modules.iter().for_each(|(importee_id, named_imports)| {
     let importee = &graph.module_table.external_modules[*importee_id];
+    let importee_name = chunk.external_import_path_for(&importee.name);
+    let attributes = render_import_attributes(&importee.attributes);
     let mut is_importee_imported = false;
     let mut import_items = named_imports
       .iter()
@@ -110,10 +147,11 @@ pub fn render_chunk_imports(
         match &item.imported {
           Specifier::Star => {
             is_importee_imported = true;
-            let importee_name = &importee.name;
             match options.format {
               OutputFormat::Esm => {
-                s.push_str(&format!("import * as {alias} from \"{importee_name}\";\n",));
+                s.push_str(
+                  &format!("import * as {alias} from \"{importee_name}\"{attributes};\n",),
+                );
               }
               OutputFormat::Cjs => {
                 let to_esm_fn_name = &chunk.canonical_names
@@ -136,9 +174,9 @@ pub fn render_chunk_imports(
       match options.format {
         OutputFormat::Esm => {
           s.push_str(&format!(
-            "import {{ {} }} from \"{importee_module_specifier}\";\n",
+            "import {{ {} }} from \"{importee_module_specifier}\"{attributes};\n",
             import_items.join(", "),
-            importee_module_specifier = &importee.name
+            importee_module_specifier = importee_name
           ));
         }
         OutputFormat::Cjs => {
@@ -147,7 +185,7 @@ pub fn render_chunk_imports(
           s.push_str(&format!(
             "const {{ {} }} = {to_esm_fn_name}(require(\"{importee_module_specifier}\"));\n",
             import_items.join(", "),
-            importee_module_specifier = &importee.name
+            importee_module_specifier = importee_name
           ));
         }
         OutputFormat::App => {
@@ -156,8 +194,80 @@ pub fn render_chunk_imports(
       }
     } else if !is_importee_imported {
       // Ensure the side effect
-      render_plain_import(&importee.name, &mut s);
+      render_plain_import(&importee_name, &attributes, &mut s);
     }
   });
   s
 }
+
+/// Shims of Node.js globals declared at the top of ESM chunks for Node.js.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct NodeEsmShims {
+  pub require: bool,
+  pub filename: bool,
+  pub dirname: bool,
+}
+
+impl NodeEsmShims {
+  /// Names declared by the shims, which are reserved while deconflicting symbols of the chunk.
+  pub fn declared_names(self) -> impl Iterator<Item = &'static str> {
+    [
+      (self.require, ["__rolldown_createRequire", "require"]),
+      (self.filename, ["__rolldown_fileURLToPath", "__filename"]),
+      (self.dirname, ["__rolldown_dirname", "__dirname"]),
+    ]
+    .into_iter()
+    .filter(|(declared, _)| *declared)
+    .flat_map(|(_, names)| names)
+  }
+}
+
+pub fn node_esm_shims(
+  chunk: &Chunk,
+  graph: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+) -> NodeEsmShims {
+  let mut shims = NodeEsmShims::default();
+  if !matches!(options.format, OutputFormat::Esm) || !matches!(options.platform, Platform::Node) {
+    return shims;
+  }
+  let modules = chunk
+    .modules
+    .iter()
+    .map(|id| &graph.module_table.normal_modules[*id])
+    .filter(|module| module.is_included);
+  for module in modules {
+    // Only `require(...)` calls that survived tree shaking need `require`.
+    let requires_external_module = module
+      .stmt_infos
+      .iter()
+      .filter(|stmt_info| stmt_info.is_included)
+      .flat_map(|stmt_info| &stmt_info.import_records)
+      .map(|rec_id| &module.import_records[*rec_id])
+      .any(|rec| {
+        matches!(rec.kind, ImportKind::Require)
+          && matches!(rec.resolved_module, ModuleId::External(_))
+      });
+    shims.require |= requires_external_module;
+    // `__dirname` is derived from `__filename`.
+    shims.filename |= !module.node_globals.is_empty();
+    shims.dirname |= module.node_globals.contains(NodeGlobals::DIRNAME);
+  }
+  shims
+}
+
+/// Render import attributes as ` with { type: "json" }`. Returns an empty string if there is no attribute.
+fn render_import_attributes(attributes: &ImportAttributes) -> String {
+  if attributes.is_empty() {
+    return String::new();
+  }
+  let entries = attributes
+    .iter()
+    .map(|(key, value)| format!("{}: {}", quote_str(key), quote_str(value)))
+    .collect::<Vec<_>>();
+  format!(" with {{ {} }}", entries.join(", "))
+}
+
+fn quote_str(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
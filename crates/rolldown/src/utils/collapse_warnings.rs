@@ -0,0 +1,48 @@
+use rolldown_error::BuildError;
+use rustc_hash::FxHashMap;
+
+/// Number of warnings of each kind reported before the rest are collapsed
+const MAX_SIMILAR_WARNINGS: usize = 5;
+
+/// Keep the first few warnings of each kind, and replace the rest with a warning counting them. Warnings keep their
+/// order, and each summary follows the last kept warning of its kind.
+pub fn collapse_similar_warnings(warnings: Vec<BuildError>) -> Vec<BuildError> {
+  let mut counts = FxHashMap::default();
+  for warning in &warnings {
+    *counts.entry(warning.kind()).or_insert(0usize) += 1;
+  }
+
+  let mut seen = FxHashMap::default();
+  let mut collapsed = Vec::with_capacity(warnings.len());
+  for warning in warnings {
+    let kind = warning.kind();
+    let index = seen.entry(kind).or_insert(0usize);
+    *index += 1;
+    if *index > MAX_SIMILAR_WARNINGS {
+      continue;
+    }
+    let count = counts[&kind];
+    collapsed.push(warning);
+    if *index == MAX_SIMILAR_WARNINGS && count > MAX_SIMILAR_WARNINGS {
+      collapsed.push(BuildError::similar_warnings(kind, count - MAX_SIMILAR_WARNINGS));
+    }
+  }
+  collapsed
+}
+
+#[test]
+fn test_collapse_similar_warnings() {
+  let cycle =
+    |name: &str| BuildError::circular_dependency(vec![name.to_string(), name.to_string()]);
+  let mut warnings = (0..8).map(|i| cycle(&format!("{i}.js"))).collect::<Vec<_>>();
+  warnings.insert(1, BuildError::unresolved_import_treated_as_external("foo", "main.js", None));
+
+  let collapsed = collapse_similar_warnings(warnings);
+  let messages = collapsed.iter().map(ToString::to_string).collect::<Vec<_>>();
+  assert_eq!(collapsed.len(), 7);
+  assert_eq!(collapsed[1].kind(), rolldown_error::EventKind::UnresolvedImport);
+  assert_eq!(
+    messages[6],
+    "3 similar CIRCULAR_DEPENDENCY warnings were omitted. Set `collapseWarnings: false` to report all of them."
+  );
+}
@@ -0,0 +1,300 @@
+use rolldown_common::LocalsConvention;
+use rolldown_utils::{
+  ecma_script::is_validate_identifier_name, indexmap::FxIndexMap, xxhash::xxhash_base64_url,
+};
+
+use super::extract_hash_pattern::extract_hash_pattern;
+
+/// Stylesheets named `*.module.css` are compiled as CSS modules.
+pub fn is_css_module(path: &str) -> bool {
+  path.ends_with(".module.css")
+}
+
+/// Scope class names of a CSS module with the `scoped_name` pattern. Classes inside `:global(...)` are kept as they are.
+/// Returns the transformed stylesheet and the original class names mapped to the scoped ones, in order of appearance.
+pub fn scope_css_module(
+  css: &str,
+  scoped_name: &str,
+  path: &str,
+  stable_id: &str,
+) -> (String, FxIndexMap<String, String>) {
+  let file_name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+  let name = file_name
+    .strip_suffix(".module.css")
+    .unwrap_or(file_name)
+    .chars()
+    .map(|c| if c.is_ascii_alphanumeric() || c == '-' || c == '_' { c } else { '_' })
+    .collect::<String>();
+  let mut scoper = CssModuleScoper {
+    scoped_name,
+    name: &name,
+    hash: xxhash_base64_url(stable_id.as_bytes()),
+    classes: FxIndexMap::default(),
+  };
+  let css = scoper.scope_stylesheet(css);
+  (css, scoper.classes)
+}
+
+/// Generate the JavaScript of a CSS module, which exports class names mapped to the scoped ones.
+pub fn css_module_to_esm(
+  classes: &FxIndexMap<String, String>,
+  locals_convention: LocalsConvention,
+) -> String {
+  let mut exports = FxIndexMap::default();
+  for (local, scoped) in classes {
+    let (keep_original, separators): (bool, &[char]) = match locals_convention {
+      LocalsConvention::AsIs => (true, &[]),
+      LocalsConvention::CamelCase => (true, &['-', '_']),
+      LocalsConvention::CamelCaseOnly => (false, &['-', '_']),
+      LocalsConvention::Dashes => (true, &['-']),
+      LocalsConvention::DashesOnly => (false, &['-']),
+    };
+    if keep_original {
+      exports.insert(local.clone(), scoped);
+    }
+    if !separators.is_empty() {
+      exports.insert(camel_case(local, separators), scoped);
+    }
+  }
+
+  let mut source = String::new();
+  let mut exported_items_for_default_export = Vec::with_capacity(exports.len());
+  for (idx, (name, scoped)) in exports.iter().enumerate() {
+    let scoped = quote_str(scoped);
+    if is_validate_identifier_name(name) && name != "default" {
+      source.push_str(&format!("export const {name} = {scoped};\n"));
+      exported_items_for_default_export.push(name.to_string());
+    } else {
+      let valid_id = format!("key_{idx}");
+      let name = quote_str(name);
+      source.push_str(&format!("const {valid_id} = {scoped};\n"));
+      source.push_str(&format!("export {{ {valid_id} as {name} }};\n"));
+      exported_items_for_default_export.push(format!("{name}: {valid_id}"));
+    }
+  }
+  source
+    .push_str(&format!("export default {{ {} }};", exported_items_for_default_export.join(", ")));
+  source
+}
+
+struct CssModuleScoper<'a> {
+  scoped_name: &'a str,
+  /// Name of the file without `.module.css`
+  name: &'a str,
+  /// Hash of the stable id of the file, so scoped names are stable across builds and machines
+  hash: String,
+  classes: FxIndexMap<String, String>,
+}
+
+impl<'a> CssModuleScoper<'a> {
+  /// Scope selectors of style rules, including the ones nested in conditional at-rules such as `@media`.
+  /// Declarations and other at-rules, such as `@font-face` and `@keyframes`, are kept as they are.
+  fn scope_stylesheet(&mut self, css: &str) -> String {
+    let bytes = css.as_bytes();
+    let mut output = String::with_capacity(css.len());
+    // Whether each open block contains rules rather than declarations
+    let mut block_stack: Vec<bool> = vec![];
+    let mut prelude_start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+      match bytes[i] {
+        b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+        b'"' | b'\'' => i = skip_string(bytes, i),
+        b'{' => {
+          let prelude = &css[prelude_start..i];
+          let is_in_rule_list = block_stack.last().copied().unwrap_or(true);
+          let is_rule_list = if !is_in_rule_list {
+            output.push_str(prelude);
+            false
+          } else if let Some(at_rule) = at_rule_name(prelude) {
+            output.push_str(prelude);
+            matches!(at_rule, "media" | "supports" | "layer" | "container" | "document" | "scope")
+          } else {
+            let selector = self.scope_selector(prelude);
+            output.push_str(&selector);
+            false
+          };
+          output.push('{');
+          block_stack.push(is_rule_list);
+          i += 1;
+          prelude_start = i;
+        }
+        b'}' | b';' => {
+          if bytes[i] == b'}' {
+            block_stack.pop();
+          }
+          output.push_str(&css[prelude_start..=i]);
+          i += 1;
+          prelude_start = i;
+        }
+        _ => i += 1,
+      }
+    }
+    output.push_str(&css[prelude_start..]);
+    output
+  }
+
+  fn scope_selector(&mut self, selector: &str) -> String {
+    let bytes = selector.as_bytes();
+    let mut output = String::with_capacity(selector.len());
+    let mut last = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+      match bytes[i] {
+        b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+        b'"' | b'\'' => i = skip_string(bytes, i),
+        b'[' => {
+          i = bytes[i..].iter().position(|b| *b == b']').map_or(bytes.len(), |pos| i + pos + 1);
+        }
+        b':' if selector[i..].starts_with(":global(") || selector[i..].starts_with(":local(") => {
+          let is_global = selector[i..].starts_with(":global(");
+          let content_start = i + if is_global { ":global(".len() } else { ":local(".len() };
+          let content_end = find_closing_paren(bytes, content_start);
+          output.push_str(&selector[last..i]);
+          let content = &selector[content_start..content_end];
+          if is_global {
+            output.push_str(content);
+          } else {
+            let scoped = self.scope_selector(content);
+            output.push_str(&scoped);
+          }
+          i = (content_end + 1).min(bytes.len());
+          last = i;
+        }
+        b'.' => {
+          let start = i + 1;
+          let end = ident_end(bytes, start);
+          if end > start && !bytes[start].is_ascii_digit() {
+            output.push_str(&selector[last..start]);
+            let scoped = self.scoped_name_of(&selector[start..end]);
+            output.push_str(&scoped);
+            last = end;
+          }
+          i = end.max(start);
+        }
+        _ => i += 1,
+      }
+    }
+    output.push_str(&selector[last..]);
+    output
+  }
+
+  fn scoped_name_of(&mut self, local: &str) -> String {
+    if let Some(scoped) = self.classes.get(local) {
+      return scoped.clone();
+    }
+    let mut scoped = self.scoped_name.replace("[name]", self.name).replace("[local]", local);
+    if let Some(hash_pattern) = extract_hash_pattern(&scoped) {
+      let mut hash = self.hash.clone();
+      hash.truncate(hash_pattern.len.unwrap_or(8));
+      scoped = scoped.replace(&hash_pattern.pattern, &hash);
+    }
+    self.classes.insert(local.to_string(), scoped.clone());
+    scoped
+  }
+}
+
+/// Name of the at-rule, such as `media` for `@media (min-width: 100px)`.
+fn at_rule_name(prelude: &str) -> Option<&str> {
+  let rest = prelude.trim_start().strip_prefix('@')?;
+  let end = rest.find(|c: char| !(c.is_ascii_alphanumeric() || c == '-')).unwrap_or(rest.len());
+  Some(&rest[..end])
+}
+
+/// Returns the index after the end of the comment starting at `start`.
+fn skip_comment(bytes: &[u8], start: usize) -> usize {
+  bytes[start + 2..].windows(2).position(|w| w == b"*/").map_or(bytes.len(), |pos| start + pos + 4)
+}
+
+/// Returns the index after the end of the string starting at `start`.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      b'\n' => return i,
+      b if b == quote => return i + 1,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+/// Returns the index of the `)` that closes the parenthesis opened right before `start`.
+fn find_closing_paren(bytes: &[u8], start: usize) -> usize {
+  let mut depth = 0;
+  for (i, b) in bytes.iter().enumerate().skip(start) {
+    match b {
+      b'(' => depth += 1,
+      b')' if depth == 0 => return i,
+      b')' => depth -= 1,
+      _ => {}
+    }
+  }
+  bytes.len()
+}
+
+fn ident_end(bytes: &[u8], start: usize) -> usize {
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      // Bytes of non-ASCII characters are all greater than 0x7f
+      b if b.is_ascii_alphanumeric() || b == b'-' || b == b'_' || b > 0x7f => i += 1,
+      _ => break,
+    }
+  }
+  i.min(bytes.len())
+}
+
+fn camel_case(name: &str, separators: &[char]) -> String {
+  let mut result = String::with_capacity(name.len());
+  let mut is_upper_next = false;
+  for c in name.chars() {
+    if separators.contains(&c) {
+      is_upper_next = !result.is_empty();
+    } else if is_upper_next {
+      result.extend(c.to_uppercase());
+      is_upper_next = false;
+    } else {
+      result.push(c);
+    }
+  }
+  result
+}
+
+fn quote_str(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[test]
+fn test_scope_css_module() {
+  let (css, classes) = scope_css_module(
+    ".foo, .bar:hover > a[href$='.pdf'] { margin: .5em; }\n@media (width > 1px) { .foo { color: red; } }\n:global(.baz) .qux-quux {}",
+    "[name]_[local]",
+    "/src/button.module.css",
+    "src/button.module.css",
+  );
+  assert_eq!(
+    css,
+    ".button_foo, .button_bar:hover > a[href$='.pdf'] { margin: .5em; }\n@media (width > 1px) { .button_foo { color: red; } }\n.baz .button_qux-quux {}"
+  );
+  assert_eq!(
+    classes.keys().map(String::as_str).collect::<Vec<_>>(),
+    vec!["foo", "bar", "qux-quux"]
+  );
+}
+
+#[test]
+fn test_css_module_to_esm() {
+  let classes = [("foo-bar".to_string(), "a_foo-bar".to_string())].into_iter().collect();
+  assert_eq!(
+    css_module_to_esm(&classes, LocalsConvention::CamelCaseOnly),
+    "export const fooBar = \"a_foo-bar\";\nexport default { fooBar };"
+  );
+  assert_eq!(
+    css_module_to_esm(&classes, LocalsConvention::AsIs),
+    "const key_0 = \"a_foo-bar\";\nexport { key_0 as \"foo-bar\" };\nexport default { \"foo-bar\": key_0 };"
+  );
+}
@@ -0,0 +1,191 @@
+use std::ops::Range;
+
+/// Take out `@import` rules at the top of the stylesheet. They are turned into imports of the module, so imported
+/// stylesheets are bundled into the same `.css` file and placed before the importer.
+/// Rules with media queries or remote URLs are kept as they are.
+pub fn extract_css_imports(css: &str) -> (String, Vec<String>) {
+  let mut output = String::with_capacity(css.len());
+  let mut requests = vec![];
+  let mut rest = css;
+  loop {
+    let trimmed = skip_whitespace_and_comments(rest);
+    output.push_str(&rest[..rest.len() - trimmed.len()]);
+    rest = trimmed;
+    let is_at_rule_before_rules = ["@import", "@charset"]
+      .iter()
+      .any(|at_rule| rest.get(..at_rule.len()).is_some_and(|s| s.eq_ignore_ascii_case(at_rule)));
+    if !is_at_rule_before_rules {
+      break;
+    }
+    let rule_end = find_rule_end(rest);
+    let rule = &rest[..rule_end];
+    match parse_import_request(rule) {
+      Some(request) if is_local_css_url(request) => requests.push(request.to_string()),
+      _ => output.push_str(rule),
+    }
+    rest = &rest[rule_end..];
+  }
+  output.push_str(rest);
+  (output, requests)
+}
+
+/// A `url(...)` in the stylesheet.
+pub struct CssUrl {
+  /// Range of the whole `url(...)`
+  pub span: Range<usize>,
+  pub url: String,
+}
+
+/// Find all `url(...)` in the stylesheet, except the ones in comments and strings.
+pub fn find_css_urls(css: &str) -> Vec<CssUrl> {
+  let bytes = css.as_bytes();
+  let mut urls = vec![];
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => i = skip_comment(bytes, i),
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      b'u' | b'U'
+        if css.get(i..i + 4).is_some_and(|s| s.eq_ignore_ascii_case("url("))
+          && (i == 0 || !is_ident_byte(bytes[i - 1])) =>
+      {
+        let content_start = i + 4;
+        let Some(close) = css[content_start..].find(')').map(|pos| content_start + pos) else {
+          break;
+        };
+        let content = css[content_start..close].trim();
+        let url = match content.as_bytes().first() {
+          Some(b'"' | b'\'') => {
+            let end = skip_string(content.as_bytes(), 0);
+            content[1..end.saturating_sub(1).max(1)].to_string()
+          }
+          _ => content.to_string(),
+        };
+        // Including the closing parenthesis
+        let end = close + 1;
+        urls.push(CssUrl { span: i..end, url });
+        i = end;
+      }
+      _ => i += 1,
+    }
+  }
+  urls
+}
+
+/// Whether the URL refers to a file relative to the stylesheet, rather than a remote file, a data URL or a fragment.
+pub fn is_local_css_url(url: &str) -> bool {
+  !(url.is_empty() || url.starts_with('/') || url.starts_with('#') || url.contains(':'))
+}
+
+/// A placeholder of the URL of an emitted asset in a stylesheet. It's replaced with the path relative to the emitted
+/// `.css` file, once the filename of the `.css` file is determined.
+pub fn css_asset_placeholder(asset_filename: &str) -> String {
+  format!("\0asset:{asset_filename}\0")
+}
+
+pub fn replace_css_asset_placeholders(
+  css: &str,
+  mut replacer: impl FnMut(&str) -> String,
+) -> String {
+  let mut output = String::with_capacity(css.len());
+  let mut rest = css;
+  while let Some(start) = rest.find("\0asset:") {
+    let Some(len) = rest[start + 7..].find('\0') else {
+      break;
+    };
+    output.push_str(&rest[..start]);
+    output.push_str(&replacer(&rest[start + 7..start + 7 + len]));
+    rest = &rest[start + 7 + len + 1..];
+  }
+  output.push_str(rest);
+  output
+}
+
+/// `@import "foo.css";` or `@import url(foo.css);` to `foo.css`. Returns `None` if the rule has media queries.
+fn parse_import_request(rule: &str) -> Option<&str> {
+  if !rule.get(..7)?.eq_ignore_ascii_case("@import") {
+    return None;
+  }
+  let rest = rule[7..].trim();
+  let rest = rest.strip_suffix(';').unwrap_or(rest).trim();
+  let rest = if rest.len() >= 4 && rest[..4].eq_ignore_ascii_case("url(") {
+    rest[4..].strip_suffix(')')?.trim()
+  } else {
+    rest
+  };
+  let quote = rest.chars().next()?;
+  if quote == '"' || quote == '\'' {
+    let content = rest[1..].strip_suffix(quote)?;
+    (!content.contains(quote)).then_some(content)
+  } else {
+    (!rest.contains(|c: char| c.is_whitespace() || c == '"' || c == '\'')).then_some(rest)
+  }
+}
+
+fn skip_whitespace_and_comments(mut css: &str) -> &str {
+  loop {
+    let trimmed = css.trim_start();
+    match trimmed.strip_prefix("/*") {
+      Some(comment) => css = comment.find("*/").map_or("", |end| &comment[end + 2..]),
+      None => return trimmed,
+    }
+  }
+}
+
+/// Returns the index after the `;` that ends the at-rule.
+fn find_rule_end(css: &str) -> usize {
+  let bytes = css.as_bytes();
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => i = skip_string(bytes, i),
+      b';' => return i + 1,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+/// Returns the index after the end of the comment starting at `start`.
+fn skip_comment(bytes: &[u8], start: usize) -> usize {
+  bytes[start + 2..].windows(2).position(|w| w == b"*/").map_or(bytes.len(), |pos| start + pos + 4)
+}
+
+/// Returns the index after the end of the string starting at `start`.
+fn skip_string(bytes: &[u8], start: usize) -> usize {
+  let quote = bytes[start];
+  let mut i = start + 1;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'\\' => i += 2,
+      b'\n' => return i,
+      b if b == quote => return i + 1,
+      _ => i += 1,
+    }
+  }
+  bytes.len()
+}
+
+fn is_ident_byte(b: u8) -> bool {
+  b.is_ascii_alphanumeric() || b == b'-' || b == b'_'
+}
+
+#[test]
+fn test_extract_css_imports() {
+  let (css, requests) = extract_css_imports(
+    "@charset \"utf-8\";\n@import './a.css';\n@import url(b.css);\n@import 'c.css' screen;\n@import 'https://example.com/d.css';\n.a { color: red; }",
+  );
+  assert_eq!(requests, vec!["./a.css", "b.css"]);
+  assert_eq!(
+    css,
+    "@charset \"utf-8\";\n\n\n@import 'c.css' screen;\n@import 'https://example.com/d.css';\n.a { color: red; }"
+  );
+}
+
+#[test]
+fn test_find_css_urls() {
+  let css = ".a { background: url(./a.png), URL( \"b.png\" ); } /* url(c.png) */ .b { content: 'url(d.png)'; }";
+  let urls = find_css_urls(css);
+  assert_eq!(urls.iter().map(|u| u.url.as_str()).collect::<Vec<_>>(), vec!["./a.png", "b.png"]);
+  assert_eq!(&css[urls[1].span.clone()], "URL( \"b.png\" )");
+}
@@ -0,0 +1,37 @@
+use std::path::Path;
+
+use rolldown_utils::base64::to_standard_base64;
+
+/// Guess the MIME type based on the file extension. Unknown extensions fallback to `application/octet-stream`.
+pub fn guess_mime(path: &Path) -> &'static str {
+  match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+    Some("png") => "image/png",
+    Some("jpg" | "jpeg") => "image/jpeg",
+    Some("gif") => "image/gif",
+    Some("webp") => "image/webp",
+    Some("avif") => "image/avif",
+    Some("ico") => "image/x-icon",
+    Some("svg") => "image/svg+xml",
+    Some("woff") => "font/woff",
+    Some("woff2") => "font/woff2",
+    Some("ttf") => "font/ttf",
+    Some("otf") => "font/otf",
+    Some("eot") => "application/vnd.ms-fontobject",
+    Some("mp3") => "audio/mpeg",
+    Some("wav") => "audio/wav",
+    Some("mp4") => "video/mp4",
+    Some("webm") => "video/webm",
+    Some("txt") => "text/plain",
+    Some("json") => "application/json",
+    Some("js" | "mjs" | "cjs") => "text/javascript",
+    Some("css") => "text/css",
+    Some("html") => "text/html",
+    Some("wasm") => "application/wasm",
+    _ => "application/octet-stream",
+  }
+}
+
+/// Encode the content as a base64 `data:` URL, such as `data:image/png;base64,...`.
+pub fn to_data_url(path: &Path, content: &[u8]) -> String {
+  format!("data:{};base64,{}", guess_mime(path), to_standard_base64(content))
+}
@@ -0,0 +1,127 @@
+use oxc::{
+  allocator::Allocator,
+  ast::{
+    ast::{self, Expression},
+    visit::walk_mut,
+    Visit, VisitMut,
+  },
+  span::{GetSpan, Span},
+  syntax::operator::UnaryOperator,
+};
+use rolldown_common::{DefineReplacement, DefineValue};
+use rolldown_oxc_utils::{AstSnippet, IntoIn, OxcAst, TakeIn, WithFieldsMut};
+use rustc_hash::FxHashSet;
+
+/// Replace expressions specified by `define` and `env`, such as `process.env.NODE_ENV`, with their values. It runs
+/// before scanning, so branches on the replaced values could be tree shaken. Expressions whose root is declared in
+/// the module, such as a local `process`, are left as is.
+pub fn replace_defines(ast: &mut OxcAst, define: &[DefineReplacement]) {
+  if define.is_empty() {
+    return;
+  }
+  ast.with_mut(|WithFieldsMut { program, allocator, .. }| {
+    let mut collector = DeclaredRootsCollector {
+      roots: define.iter().map(|replacement| replacement.key[0].as_str()).collect(),
+      declared: FxHashSet::default(),
+    };
+    collector.visit_program(program);
+    let define = define
+      .iter()
+      .filter(|replacement| !collector.declared.contains(replacement.key[0].as_str()))
+      .collect::<Vec<_>>();
+    if define.is_empty() {
+      return;
+    }
+    let mut replacer =
+      DefineReplacer { alloc: allocator, snippet: AstSnippet::new(allocator), define };
+    replacer.visit_program(program);
+  });
+}
+
+/// Find roots of keys that are declared in the module, which shadow the globals.
+struct DeclaredRootsCollector<'me> {
+  roots: FxHashSet<&'me str>,
+  declared: FxHashSet<&'me str>,
+}
+
+impl<'me, 'ast> Visit<'ast> for DeclaredRootsCollector<'me> {
+  fn visit_binding_identifier(&mut self, ident: &ast::BindingIdentifier<'ast>) {
+    if let Some(root) = self.roots.get(ident.name.as_str()) {
+      self.declared.insert(*root);
+    }
+  }
+}
+
+struct DefineReplacer<'me, 'ast> {
+  alloc: &'ast Allocator,
+  snippet: AstSnippet<'ast>,
+  define: Vec<&'me DefineReplacement>,
+}
+
+impl<'me, 'ast> DefineReplacer<'me, 'ast> {
+  /// Check if the expression is the chain of property accesses in the key, such as `process.env.NODE_ENV`.
+  fn matches(expr: &Expression<'ast>, key: &[String]) -> bool {
+    let Some((last, rest)) = key.split_last() else {
+      return false;
+    };
+    match expr {
+      Expression::StaticMemberExpression(member_expr) if !rest.is_empty() => {
+        member_expr.property.name == last.as_str() && Self::matches(&member_expr.object, rest)
+      }
+      Expression::Identifier(ident) if rest.is_empty() => ident.name == last.as_str(),
+      Expression::MetaProperty(meta) if rest.is_empty() => {
+        last == "import.meta" && meta.meta.name == "import" && meta.property.name == "meta"
+      }
+      _ => false,
+    }
+  }
+
+  fn value_expr(&self, value: &DefineValue, span: Span) -> Expression<'ast> {
+    match value {
+      DefineValue::String(value) => {
+        Expression::StringLiteral(self.snippet.string_literal(value, span).into_in(self.alloc))
+      }
+      DefineValue::Number(value) if value.is_sign_negative() => Expression::UnaryExpression(
+        ast::UnaryExpression {
+          span,
+          operator: UnaryOperator::UnaryNegation,
+          argument: self.snippet.number_expr(-value),
+        }
+        .into_in(self.alloc),
+      ),
+      DefineValue::Number(value) => self.snippet.number_expr(*value),
+      DefineValue::Bool(value) => {
+        Expression::BooleanLiteral(ast::BooleanLiteral { span, value: *value }.into_in(self.alloc))
+      }
+      DefineValue::Null => Expression::NullLiteral(ast::NullLiteral { span }.into_in(self.alloc)),
+      DefineValue::Undefined => self.snippet.void_zero(),
+      DefineValue::Path(path) => {
+        let mut expr = self.snippet.id_ref_expr(&path[0], span);
+        for property in &path[1..] {
+          expr = Expression::StaticMemberExpression(
+            ast::StaticMemberExpression {
+              span,
+              object: expr,
+              property: self.snippet.id_name(property, span),
+              ..TakeIn::dummy(self.alloc)
+            }
+            .into_in(self.alloc),
+          );
+        }
+        expr
+      }
+    }
+  }
+}
+
+impl<'me, 'ast> VisitMut<'ast> for DefineReplacer<'me, 'ast> {
+  fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
+    if let Some(replacement) =
+      self.define.iter().find(|replacement| Self::matches(expr, &replacement.key))
+    {
+      *expr = self.value_expr(&replacement.value, expr.span());
+      return;
+    }
+    walk_mut::walk_expression_mut(self, expr);
+  }
+}
@@ -0,0 +1,100 @@
+use oxc::{
+  allocator::{self, Allocator},
+  ast::{
+    ast::{self, Expression, Statement},
+    visit::walk_mut,
+    VisitMut,
+  },
+  span::GetSpan,
+};
+use rolldown_common::{AstScopes, DropKind};
+use rolldown_oxc_utils::{AstSnippet, IntoIn, OxcAst, WithFieldsMut};
+
+/// Remove nodes specified by the `drop` option, such as `console.log(...)` and `debugger`. References must be resolved
+/// in `scope`, so calls of a local `console` are left as is.
+pub fn drop_ast_nodes(ast: &mut OxcAst, drop: &[DropKind], scope: &AstScopes) {
+  if drop.is_empty() {
+    return;
+  }
+  ast.with_mut(|WithFieldsMut { program, allocator, .. }| {
+    let mut dropper = AstNodeDropper {
+      alloc: allocator,
+      snippet: AstSnippet::new(allocator),
+      scope,
+      drop_console: drop.contains(&DropKind::Console),
+      drop_debugger: drop.contains(&DropKind::Debugger),
+    };
+    dropper.visit_program(program);
+  });
+}
+
+struct AstNodeDropper<'me, 'ast> {
+  alloc: &'ast Allocator,
+  snippet: AstSnippet<'ast>,
+  scope: &'me AstScopes,
+  drop_console: bool,
+  drop_debugger: bool,
+}
+
+impl<'me, 'ast> AstNodeDropper<'me, 'ast> {
+  /// Check if the expression is `console.xxx(...)`, `console.xxx.yyy(...)` or `console["xxx"](...)`, where `console`
+  /// is the global one.
+  fn is_console_call(&self, expr: &Expression<'ast>) -> bool {
+    if !self.drop_console {
+      return false;
+    }
+    let Expression::CallExpression(call_expr) = expr else {
+      return false;
+    };
+    let mut object = match &call_expr.callee {
+      Expression::StaticMemberExpression(member_expr) => &member_expr.object,
+      Expression::ComputedMemberExpression(member_expr) => &member_expr.object,
+      _ => return false,
+    };
+    loop {
+      match object {
+        Expression::StaticMemberExpression(member_expr) => object = &member_expr.object,
+        Expression::ComputedMemberExpression(member_expr) => object = &member_expr.object,
+        Expression::Identifier(ident) => {
+          return ident.name == "console"
+            && ident.reference_id.get().is_some_and(|id| self.scope.is_unresolved(id));
+        }
+        _ => return false,
+      }
+    }
+  }
+
+  fn should_drop_stmt(&self, stmt: &Statement<'ast>) -> bool {
+    match stmt {
+      Statement::DebuggerStatement(_) => self.drop_debugger,
+      Statement::ExpressionStatement(expr_stmt) => self.is_console_call(&expr_stmt.expression),
+      _ => false,
+    }
+  }
+}
+
+impl<'me, 'ast> VisitMut<'ast> for AstNodeDropper<'me, 'ast> {
+  fn visit_statements(&mut self, stmts: &mut allocator::Vec<'ast, Statement<'ast>>) {
+    stmts.retain(|stmt| !self.should_drop_stmt(stmt));
+    walk_mut::walk_statements_mut(self, stmts);
+  }
+
+  fn visit_statement(&mut self, stmt: &mut Statement<'ast>) {
+    // Statements that are not in a statement list, such as `if (a) debugger;`, could only be replaced.
+    if self.should_drop_stmt(stmt) {
+      *stmt =
+        Statement::EmptyStatement(ast::EmptyStatement { span: stmt.span() }.into_in(self.alloc));
+      return;
+    }
+    walk_mut::walk_statement_mut(self, stmt);
+  }
+
+  fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
+    // `a = console.log(...)` => `a = void 0`
+    if self.is_console_call(expr) {
+      *expr = self.snippet.void_zero();
+      return;
+    }
+    walk_mut::walk_expression_mut(self, expr);
+  }
+}
@@ -0,0 +1,339 @@
+//! Experimental bundling of TypeScript declaration files for `output.dts`.
+//!
+//! Declarations are expected to be written in the style of `isolatedDeclarations`, so that every declaration file
+//! can be understood on its own. The declaration file of an entry is rolled up with the declaration files it imports
+//! through relative specifiers, while imports of other packages are kept as is.
+
+use std::{
+  collections::{hash_map::Entry, BTreeMap},
+  path::{Path, PathBuf},
+};
+
+use oxc::{
+  allocator::Allocator,
+  ast::ast::{
+    Declaration, ImportDeclarationSpecifier, ModuleDeclaration, TSModuleDeclarationKind,
+    TSModuleDeclarationName,
+  },
+  parser::Parser,
+  span::{GetSpan, SourceType, Span},
+};
+use rolldown_fs::FileSystem;
+use rolldown_oxc_utils::BindingPatternExt;
+use rustc_hash::{FxHashMap, FxHashSet};
+use sugar_path::SugarPath;
+
+/// The declaration file emitted along with a chunk named `filename`.
+pub fn declaration_filename(filename: &str) -> String {
+  for (ext, dts_ext) in [(".mjs", ".d.mts"), (".cjs", ".d.cts"), (".js", ".d.ts")] {
+    if let Some(stem) = filename.strip_suffix(ext) {
+      return format!("{stem}{dts_ext}");
+    }
+  }
+  format!("{filename}.d.ts")
+}
+
+/// Bundle the declaration file next to the entry module `entry` into a single declaration file. Returns `None` if the
+/// entry has no declaration file.
+///
+/// Declarations are concatenated without renaming, so top-level names must be unique across the declaration files
+/// rolled up for an entry. A name declared by more than one of them is an error.
+pub fn bundle_declarations(fs: &dyn FileSystem, entry: &Path) -> anyhow::Result<Option<String>> {
+  let Some(entry) = declaration_file_of(fs, entry) else {
+    return Ok(None);
+  };
+  let mut bundler = DeclarationBundler {
+    fs,
+    external_statements: vec![],
+    declarations: vec![],
+    visited: FxHashSet::default(),
+    exports: BTreeMap::default(),
+    declared_in: FxHashMap::default(),
+  };
+  let exports = bundler.visit(&entry, true)?;
+
+  let mut code = String::new();
+  for stmt in &bundler.external_statements {
+    code.push_str(stmt);
+    code.push('\n');
+  }
+  for declaration in &bundler.declarations {
+    code.push_str(declaration);
+    code.push('\n');
+  }
+  let specifiers = exports
+    .iter()
+    .map(
+      |(exported, local)| {
+        if exported == local {
+          exported.clone()
+        } else {
+          format!("{local} as {exported}")
+        }
+      },
+    )
+    .collect::<Vec<_>>();
+  if specifiers.is_empty() {
+    code.push_str("export {};\n");
+  } else {
+    code.push_str(&format!("export {{ {} }};\n", specifiers.join(", ")));
+  }
+  Ok(Some(code))
+}
+
+/// Exported name to the top-level name that is exported in the bundled declaration file.
+type DeclarationExports = BTreeMap<String, String>;
+
+struct DeclarationBundler<'a> {
+  fs: &'a dyn FileSystem,
+  /// Imports and re-exports of other packages, which are kept as is.
+  external_statements: Vec<String>,
+  declarations: Vec<String>,
+  visited: FxHashSet<PathBuf>,
+  exports: BTreeMap<PathBuf, DeclarationExports>,
+  /// Top-level names of the declarations, and the declaration file declaring them.
+  declared_in: FxHashMap<String, PathBuf>,
+}
+
+impl DeclarationBundler<'_> {
+  fn visit(&mut self, path: &Path, is_entry: bool) -> anyhow::Result<DeclarationExports> {
+    if !self.visited.insert(path.to_path_buf()) {
+      // Declaration files may import each other. Exports of a file still being visited are not known yet.
+      return Ok(self.exports.get(path).cloned().unwrap_or_default());
+    }
+    let source = self
+      .fs
+      .read_to_string(path)
+      .map_err(|err| anyhow::format_err!("Failed to read {}: {err}", path.display()))?;
+    let allocator = Allocator::default();
+    let source_type = SourceType::default()
+      .with_module(true)
+      .with_typescript(true)
+      .with_typescript_definition(true);
+    let ret = Parser::new(&allocator, &source, source_type).parse();
+    if let Some(error) = ret.errors.first() {
+      anyhow::bail!("Failed to parse {}: {error}", path.display());
+    }
+
+    let text_of = |span: Span| source[span.start as usize..span.end as usize].to_string();
+    let unsupported = |what: &str| {
+      anyhow::format_err!("{what} in {} is not supported by `output.dts` yet", path.display())
+    };
+    let mut exports = DeclarationExports::default();
+    for stmt in &ret.program.body {
+      let Some(decl) = stmt.as_module_declaration() else {
+        if let Some(declaration) = stmt.as_declaration() {
+          self.declare(declared_names(declaration), path)?;
+        }
+        self.declarations.push(text_of(stmt.span()));
+        continue;
+      };
+      match decl {
+        ModuleDeclaration::ImportDeclaration(decl) => {
+          let Some(dependency) = resolve(self.fs, path, &decl.source.value)? else {
+            self.push_external(text_of(decl.span));
+            continue;
+          };
+          let dependency_exports = self.visit(&dependency, false)?;
+          for spec in decl.specifiers.iter().flatten() {
+            let ImportDeclarationSpecifier::ImportSpecifier(spec) = spec else {
+              return Err(unsupported("Default or namespace import of a declaration file"));
+            };
+            // Declarations are not renamed, so the imported binding must keep its name.
+            if dependency_exports.get(spec.imported.name().as_str())
+              != Some(&spec.local.name.to_string())
+            {
+              return Err(unsupported("Renamed import of a declaration file"));
+            }
+          }
+        }
+        ModuleDeclaration::ExportAllDeclaration(decl) => {
+          let Some(dependency) = resolve(self.fs, path, &decl.source.value)? else {
+            self.push_external(text_of(decl.span));
+            continue;
+          };
+          if decl.exported.is_some() {
+            return Err(unsupported("`export * as` of a declaration file"));
+          }
+          for (exported, local) in self.visit(&dependency, false)? {
+            if exported != "default" {
+              exports.entry(exported).or_insert(local);
+            }
+          }
+        }
+        ModuleDeclaration::ExportNamedDeclaration(decl) => {
+          if let Some(source) = &decl.source {
+            let Some(dependency) = resolve(self.fs, path, &source.value)? else {
+              self.push_external(text_of(decl.span));
+              continue;
+            };
+            let dependency_exports = self.visit(&dependency, false)?;
+            for spec in &decl.specifiers {
+              let local = dependency_exports.get(spec.local.name().as_str()).ok_or_else(|| {
+                unsupported(&format!("Re-export of missing `{}`", spec.local.name()))
+              })?;
+              exports.insert(spec.exported.name().to_string(), local.clone());
+            }
+            continue;
+          }
+          for spec in &decl.specifiers {
+            exports.insert(spec.exported.name().to_string(), spec.local.name().to_string());
+          }
+          if let Some(declaration) = &decl.declaration {
+            let names = declared_names(declaration);
+            for name in &names {
+              exports.insert(name.clone(), name.clone());
+            }
+            self.declare(names, path)?;
+            self.declarations.push(text_of(declaration.span()));
+          }
+        }
+        // `export default` and `export =` are only kept for the entry, where they are exported as is.
+        _ if is_entry => self.declarations.push(text_of(decl.span())),
+        _ => return Err(unsupported("Default export of a non-entry declaration file")),
+      }
+    }
+    self.exports.insert(path.to_path_buf(), exports.clone());
+    Ok(exports)
+  }
+
+  /// Record `names` declared at the top level of the declaration file at `path`. A file may declare a name more than
+  /// once, such as overloads of a function, but names declared by other files would be merged with or clash with them.
+  fn declare(&mut self, names: Vec<String>, path: &Path) -> anyhow::Result<()> {
+    for name in names {
+      match self.declared_in.entry(name) {
+        Entry::Occupied(entry) if entry.get() != path => anyhow::bail!(
+          "`{}` is declared in both {} and {}, which can't be bundled by `output.dts` yet",
+          entry.key(),
+          entry.get().display(),
+          path.display()
+        ),
+        Entry::Occupied(_) => {}
+        Entry::Vacant(entry) => {
+          entry.insert(path.to_path_buf());
+        }
+      }
+    }
+    Ok(())
+  }
+
+  fn push_external(&mut self, stmt: String) {
+    if !self.external_statements.contains(&stmt) {
+      self.external_statements.push(stmt);
+    }
+  }
+}
+
+/// Resolve a relative specifier to the declaration file it refers to. Other specifiers refer to other packages.
+fn resolve(
+  fs: &dyn FileSystem,
+  importer: &Path,
+  specifier: &str,
+) -> anyhow::Result<Option<PathBuf>> {
+  if !specifier.starts_with("./") && !specifier.starts_with("../") {
+    return Ok(None);
+  }
+  let dir = importer.parent().unwrap_or(Path::new(""));
+  let path = dir.join(specifier).normalize();
+  declaration_file_of(fs, &path).map(Some).ok_or_else(|| {
+    anyhow::format_err!(
+      "Could not find the declaration file of \"{specifier}\" imported by {}",
+      importer.display()
+    )
+  })
+}
+
+/// The declaration file written for the module at `path`.
+fn declaration_file_of(fs: &dyn FileSystem, path: &Path) -> Option<PathBuf> {
+  let path = path.to_string_lossy();
+  let candidates =
+    if path.ends_with(".d.ts") || path.ends_with(".d.mts") || path.ends_with(".d.cts") {
+      vec![path.to_string()]
+    } else if let Some((stem, ext)) = path.rsplit_once('.').filter(|(_, ext)| !ext.contains('/')) {
+      match ext {
+        "mjs" | "mts" => vec![format!("{stem}.d.mts")],
+        "cjs" | "cts" => vec![format!("{stem}.d.cts")],
+        "js" | "jsx" | "ts" | "tsx" => vec![format!("{stem}.d.ts")],
+        _ => vec![format!("{path}.d.ts"), format!("{path}/index.d.ts")],
+      }
+    } else {
+      vec![format!("{path}.d.ts"), format!("{path}/index.d.ts")]
+    };
+  candidates.into_iter().map(PathBuf::from).find(|candidate| fs.is_file(candidate))
+}
+
+/// Top-level names bound by `declaration`. `declare global` doesn't bind any.
+fn declared_names(declaration: &Declaration) -> Vec<String> {
+  match declaration {
+    Declaration::VariableDeclaration(var_decl) => var_decl
+      .declarations
+      .iter()
+      .flat_map(|decl| decl.id.binding_identifiers())
+      .map(|id| id.name.to_string())
+      .collect(),
+    Declaration::FunctionDeclaration(fn_decl) => {
+      fn_decl.id.iter().map(|id| id.name.to_string()).collect()
+    }
+    Declaration::ClassDeclaration(cls_decl) => {
+      cls_decl.id.iter().map(|id| id.name.to_string()).collect()
+    }
+    Declaration::TSTypeAliasDeclaration(decl) => vec![decl.id.name.to_string()],
+    Declaration::TSInterfaceDeclaration(decl) => vec![decl.id.name.to_string()],
+    Declaration::TSEnumDeclaration(decl) => vec![decl.id.name.to_string()],
+    Declaration::TSModuleDeclaration(decl) if decl.kind == TSModuleDeclarationKind::Global => {
+      vec![]
+    }
+    Declaration::TSModuleDeclaration(decl) => match &decl.id {
+      TSModuleDeclarationName::Identifier(id) => vec![id.name.to_string()],
+      TSModuleDeclarationName::StringLiteral(_) => vec![],
+    },
+    _ => vec![],
+  }
+}
+
+#[test]
+fn test_declaration_filename() {
+  assert_eq!(declaration_filename("main.js"), "main.d.ts");
+  assert_eq!(declaration_filename("main.mjs"), "main.d.mts");
+  assert_eq!(declaration_filename("nested/main.cjs"), "nested/main.d.cts");
+  assert_eq!(declaration_filename("main"), "main.d.ts");
+}
+
+#[test]
+fn test_bundle_declarations_through_file_system() {
+  let mut fs = rolldown_fs::MemoryFileSystem::default();
+  fs.add_file(
+    Path::new("/project/main.d.ts"),
+    "import type { Dep } from './dep';\nexport declare const main: Dep;\n",
+  );
+  fs.add_file(Path::new("/project/dep.d.ts"), "export type Dep = string;\n");
+  let code = bundle_declarations(&fs, Path::new("/project/main.ts")).unwrap().unwrap();
+  assert!(code.contains("type Dep = string;"), "{code}");
+  assert!(code.contains("declare const main: Dep;"), "{code}");
+  assert!(code.ends_with("export { main };\n"), "{code}");
+  assert!(bundle_declarations(&fs, Path::new("/project/missing.ts")).unwrap().is_none());
+}
+
+#[test]
+fn test_bundle_declarations_with_duplicate_names() {
+  let mut fs = rolldown_fs::MemoryFileSystem::default();
+  // Overloads in a file and `declare global` in several files are fine.
+  fs.add_file(
+    Path::new("/project/main.d.ts"),
+    "export * from './a';\nexport * from './b';\nexport declare function f(a: string): void;\nexport declare function f(a: number): void;\n",
+  );
+  fs.add_file(Path::new("/project/a.d.ts"), "declare global {}\nexport type A = string;\n");
+  fs.add_file(Path::new("/project/b.d.ts"), "declare global {}\nexport type B = number;\n");
+  let code = bundle_declarations(&fs, Path::new("/project/main.ts")).unwrap().unwrap();
+  assert!(code.ends_with("export { A, B, f };\n"), "{code}");
+
+  // Names that aren't exported clash too, including ones of files reached through `export *` chains.
+  fs.add_file(Path::new("/project/main.d.ts"), "export * from './a';\ninterface Options {}\n");
+  fs.add_file(Path::new("/project/a.d.ts"), "export * from './b';\n");
+  fs.add_file(Path::new("/project/b.d.ts"), "interface Options {}\nexport type B = Options;\n");
+  let err = bundle_declarations(&fs, Path::new("/project/main.ts")).unwrap_err();
+  assert_eq!(
+    err.to_string(),
+    "`Options` is declared in both /project/b.d.ts and /project/main.d.ts, which can't be bundled by `output.dts` yet"
+  );
+}
@@ -0,0 +1,22 @@
+use oxc::ast::CommentKind;
+use rolldown_oxc_utils::OxcAst;
+
+/// Collect comments that are considered as legal comments, such as `/*! ... */` or `// @license ...`.
+///
+/// The codegen doesn't preserve comments, so these comments need to be re-inserted while rendering chunks.
+pub fn extract_legal_comments(ast: &OxcAst) -> Vec<String> {
+  let source = ast.source();
+  ast
+    .trivias
+    .comments_range(..)
+    .filter_map(|(start, comment)| {
+      let text = &source[*start as usize..comment.end as usize];
+      let is_legal =
+        text.starts_with('!') || text.contains("@license") || text.contains("@preserve");
+      is_legal.then(|| match comment.kind {
+        CommentKind::SingleLine => format!("//{text}"),
+        CommentKind::MultiLine => format!("/*{text}*/"),
+      })
+    })
+    .collect()
+}
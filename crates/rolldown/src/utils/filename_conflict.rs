@@ -0,0 +1,63 @@
+use rustc_hash::FxHashSet;
+
+/// The key that output filenames conflict on. Separators and `.` segments are normalized and the case is ignored,
+/// since `a/b.js`, `./a\b.js` and `A/B.js` are the same file on Windows and macOS.
+pub fn filename_conflict_key(filename: &str) -> String {
+  let mut segments: Vec<&str> = vec![];
+  for segment in filename.split(['/', '\\']) {
+    match segment {
+      "" | "." => {}
+      ".." if segments.last().is_some_and(|last| *last != "..") => {
+        segments.pop();
+      }
+      _ => segments.push(segment),
+    }
+  }
+  segments.join("/").to_lowercase()
+}
+
+/// Make `filename` not conflict with the `used` filenames by appending a number to its name, e.g. `chunk.js`,
+/// `chunk2.js`, `chunk3.js`. The returned filename is marked as used.
+pub fn make_unique_filename(filename: String, used: &mut FxHashSet<String>) -> String {
+  if used.insert(filename_conflict_key(&filename)) {
+    return filename;
+  }
+  let basename_start = filename.rfind(['/', '\\']).map_or(0, |index| index + 1);
+  // The extension starts from the first `.` of the basename, so `main.d.ts` becomes `main2.d.ts`.
+  let extension_start = filename
+    .get(basename_start + 1..)
+    .and_then(|rest| rest.find('.'))
+    .map_or(filename.len(), |index| basename_start + 1 + index);
+  let (stem, extension) = filename.split_at(extension_start);
+  // Candidates have distinct keys, so at most `used.len()` of them are taken.
+  let max_count = used.len() + 2;
+  (2..=max_count)
+    .map(|count| format!("{stem}{count}{extension}"))
+    .find(|candidate| used.insert(filename_conflict_key(candidate)))
+    .expect("One of the candidates should be unused")
+}
+
+#[test]
+fn test_filename_conflict_key() {
+  assert_eq!(filename_conflict_key("assets/main.js"), "assets/main.js");
+  assert_eq!(filename_conflict_key("./assets\\main.js"), "assets/main.js");
+  assert_eq!(filename_conflict_key("assets/Main.js"), "assets/main.js");
+  assert_eq!(filename_conflict_key("assets\\nested\\..\\main.js"), "assets/main.js");
+  assert_eq!(filename_conflict_key("../main.js"), "../main.js");
+}
+
+#[test]
+fn test_make_unique_filename() {
+  let mut used = FxHashSet::default();
+  assert_eq!(make_unique_filename("chunks/chunk.js".to_string(), &mut used), "chunks/chunk.js");
+  assert_eq!(make_unique_filename("chunks\\chunk.js".to_string(), &mut used), "chunks\\chunk2.js");
+  assert_eq!(
+    make_unique_filename("./chunks/chunk.js".to_string(), &mut used),
+    "./chunks/chunk3.js"
+  );
+  assert_eq!(make_unique_filename("chunks/Chunk.js".to_string(), &mut used), "chunks/Chunk4.js");
+  assert_eq!(make_unique_filename("types/main.d.ts".to_string(), &mut used), "types/main.d.ts");
+  assert_eq!(make_unique_filename("types/main.d.ts".to_string(), &mut used), "types/main2.d.ts");
+  assert_eq!(make_unique_filename("chunks/.hidden".to_string(), &mut used), "chunks/.hidden");
+  assert_eq!(make_unique_filename("chunks/.hidden".to_string(), &mut used), "chunks/.hidden2");
+}
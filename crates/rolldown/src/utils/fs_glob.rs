@@ -0,0 +1,77 @@
+use std::path::{Component, Path, PathBuf};
+
+use glob::{MatchOptions, Pattern, PatternError};
+use rolldown_fs::FileSystem;
+
+/// Files matching the absolute glob `pattern`, found by walking directories through `fs` instead of the disk, so
+/// in-memory and host-provided file systems work too. Paths are sorted so the order is stable.
+///
+/// Directories are only walked as deep as the pattern could match, so patterns without `**` don't read whole trees.
+pub fn glob_files(fs: &impl FileSystem, pattern: &Path) -> Result<Vec<PathBuf>, PatternError> {
+  let matcher = Pattern::new(&pattern.to_string_lossy())?;
+  let base = glob_base(pattern);
+  let rest = pattern.strip_prefix(&base).unwrap_or(pattern);
+  let max_depth = if rest.components().any(|component| component.as_os_str() == "**") {
+    usize::MAX
+  } else {
+    rest.components().count()
+  };
+  // Same as `glob::glob`, which matches each component separately.
+  let options = MatchOptions { require_literal_separator: true, ..MatchOptions::new() };
+
+  let mut files = vec![];
+  if max_depth == 0 {
+    if fs.is_file(&base) {
+      files.push(base);
+    }
+    return Ok(files);
+  }
+  let mut dirs = vec![(base, 1)];
+  while let Some((dir, depth)) = dirs.pop() {
+    // Missing or unreadable directories have nothing to match.
+    let Ok(entries) = fs.read_dir(&dir) else {
+      continue;
+    };
+    for entry in entries {
+      if fs.is_dir(&entry) {
+        if depth < max_depth {
+          dirs.push((entry, depth + 1));
+        }
+      } else if fs.is_file(&entry) && matcher.matches_path_with(&entry, options) {
+        files.push(entry);
+      }
+    }
+  }
+  files.sort();
+  Ok(files)
+}
+
+/// The leading components of the pattern without wildcards, which all matched files are under.
+pub fn glob_base(pattern: &Path) -> PathBuf {
+  pattern
+    .components()
+    .take_while(|component| match component {
+      Component::Normal(name) => !name.to_string_lossy().contains(['*', '?', '[']),
+      _ => true,
+    })
+    .collect()
+}
+
+#[test]
+fn test_glob_files() {
+  let mut fs = rolldown_fs::MemoryFileSystem::default();
+  for path in
+    ["/root/locale/de.js", "/root/locale/fr.js", "/root/locale/nested/it.js", "/root/main.js"]
+  {
+    fs.add_file(Path::new(path), "");
+  }
+  let glob = |pattern: &str| glob_files(&fs, Path::new(pattern)).unwrap();
+  assert_eq!(
+    glob("/root/locale/*.js"),
+    ["/root/locale/de.js", "/root/locale/fr.js"].map(PathBuf::from)
+  );
+  assert_eq!(glob("/root/locale/**/*.js").len(), 3);
+  assert_eq!(glob("/root/main.js"), [PathBuf::from("/root/main.js")]);
+  assert!(glob("/root/missing/*.js").is_empty());
+  assert!(glob_files(&fs, Path::new("/root/[.js")).is_err());
+}
@@ -0,0 +1,93 @@
+use std::{fmt::Write, hash::Hasher};
+
+use rolldown_common::{HashAlgorithm, HashCharacters, NormalizedBundlerOptions};
+use rolldown_utils::base64::to_url_safe_base64;
+use xxhash_rust::xxh3::Xxh3;
+
+/// Hasher of `[hash]` in filenames with `hashAlgorithm`. It implements `Hasher`, so values implementing `Hash` could
+/// be fed to it as well as bytes.
+#[derive(Clone)]
+pub enum ContentHasher {
+  Xxhash3(Box<Xxh3>),
+  Blake3(Box<blake3::Hasher>),
+}
+
+impl ContentHasher {
+  pub fn new(algorithm: HashAlgorithm) -> Self {
+    match algorithm {
+      HashAlgorithm::Xxhash3 => Self::Xxhash3(Box::default()),
+      HashAlgorithm::Blake3 => Self::Blake3(Box::default()),
+    }
+  }
+
+  /// The first 128 bits of the hash.
+  pub fn digest(&self) -> [u8; 16] {
+    match self {
+      Self::Xxhash3(hasher) => hasher.digest128().to_le_bytes(),
+      Self::Blake3(hasher) => {
+        let mut digest = [0; 16];
+        digest.copy_from_slice(&hasher.finalize().as_bytes()[..16]);
+        digest
+      }
+    }
+  }
+}
+
+impl Hasher for ContentHasher {
+  fn write(&mut self, bytes: &[u8]) {
+    match self {
+      Self::Xxhash3(hasher) => hasher.update(bytes),
+      Self::Blake3(hasher) => {
+        hasher.update(bytes);
+      }
+    }
+  }
+
+  fn finish(&self) -> u64 {
+    let digest = self.digest();
+    u64::from_le_bytes(digest[..8].try_into().expect("Digest should have 16 bytes"))
+  }
+}
+
+/// Returns the hash of the content for `[hash]` in filenames, according to `hashAlgorithm`, `hashCharacters` and
+/// `hashSalt`.
+pub fn hash_content(content: &[u8], options: &NormalizedBundlerOptions) -> String {
+  let mut hasher = ContentHasher::new(options.hash_algorithm);
+  if let Some(salt) = &options.hash_salt {
+    hasher.write(salt.as_bytes());
+  }
+  hasher.write(content);
+  encode_hash(hasher.digest(), options.hash_characters)
+}
+
+/// Base64 hashes have 22 characters, base36 ones have 25 and hex ones have 32.
+pub fn encode_hash(digest: [u8; 16], characters: HashCharacters) -> String {
+  match characters {
+    HashCharacters::Base64 => to_url_safe_base64(digest),
+    HashCharacters::Base36 => {
+      let mut value = u128::from_le_bytes(digest);
+      let mut encoded = Vec::with_capacity(25);
+      for _ in 0..25 {
+        #[allow(clippy::cast_possible_truncation)]
+        let digit = (value % 36) as u32;
+        encoded.push(char::from_digit(digit, 36).expect("Digit should be less than 36"));
+        value /= 36;
+      }
+      encoded.into_iter().collect()
+    }
+    HashCharacters::Hex => digest.iter().fold(String::with_capacity(32), |mut hex, byte| {
+      write!(hex, "{byte:02x}").expect("writing to a string should not fail");
+      hex
+    }),
+  }
+}
+
+#[test]
+fn test_encode_hash() {
+  let digest = [0xab; 16];
+  assert_eq!(encode_hash(digest, HashCharacters::Base64), "q6urq6urq6urq6urq6urqw");
+  assert_eq!(encode_hash(digest, HashCharacters::Hex), "abababababababababababababababab");
+  let base36 = encode_hash(digest, HashCharacters::Base36);
+  assert_eq!(base36.len(), 25);
+  assert!(base36.chars().all(|c| c.is_ascii_digit() || c.is_ascii_lowercase()));
+}
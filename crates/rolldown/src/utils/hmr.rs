@@ -0,0 +1,380 @@
+use std::sync::Arc;
+
+use oxc::{
+  ast::{
+    ast::{
+      CallExpression, ExportDefaultDeclarationKind, Expression, IdentifierReference,
+      ImportDeclarationSpecifier, ImportExpression, MetaProperty, ObjectProperty, Program,
+      Statement,
+    },
+    syntax_directed_operations::BoundNames,
+    visit::walk,
+    Visit,
+  },
+  semantic::{SymbolId, SymbolTable},
+  span::{GetSpan, SourceType, Span},
+};
+use oxc_index::IndexVec;
+use rolldown_common::{ExportsKind, ModuleId, NormalModule, NormalModuleId};
+use rolldown_oxc_utils::{OxcAst, OxcCompiler};
+use rolldown_utils::{ecma_script::is_validate_identifier_name, xxhash::xxhash_base64_url};
+use rustc_hash::FxHashMap;
+
+use crate::type_alias::IndexNormalModules;
+
+/// The object of the HMR runtime, which `import.meta.hot` is rewritten to call `hot(id)` on. It's defined by the
+/// runtime module and shared by chunks through `globalThis`.
+pub const HMR_RUNTIME_NAME: &str = "__rolldown_hmr__";
+
+/// An update chunk for modules changed since the last build. Run it in the browser to apply the update with
+/// `__rolldown_hmr__.applyUpdates`, which reloads the page if a module doesn't accept updates.
+#[derive(Debug, Clone)]
+pub struct HmrUpdate {
+  /// The filename of the update chunk, which is written along with other chunks.
+  pub filename: String,
+  pub code: String,
+  /// Stable ids of updated modules, as `import.meta.hot` refers to.
+  pub module_ids: Vec<String>,
+}
+
+/// Render modules with `changed_ids` in an update chunk. Each module is rendered as a factory returning its exports,
+/// which imports other modules from the HMR runtime, so the update doesn't depend on how modules are bundled in chunks.
+/// It's rendered from sources of `ast_table`, which the scan stage parsed.
+pub fn render_hmr_update(
+  changed_ids: &[String],
+  modules: &IndexNormalModules,
+  ast_table: &IndexVec<NormalModuleId, OxcAst>,
+) -> Option<HmrUpdate> {
+  let mut updated = modules
+    .iter()
+    .filter(|module| changed_ids.iter().any(|id| id.as_str() == module.resource_id.as_str()))
+    .collect::<Vec<_>>();
+  if updated.is_empty() {
+    return None;
+  }
+  // Modules are updated in the order they are executed, so imported modules are updated first.
+  updated.sort_by_key(|module| module.exec_order);
+
+  let entries = updated
+    .iter()
+    .map(|module| {
+      let factory = render_module_factory(module, modules, ast_table[module.id].source())
+        .unwrap_or_else(|| "null".to_string());
+      format!("[{}, {factory}]", quote(&module.stable_resource_id))
+    })
+    .collect::<Vec<_>>();
+  let code = format!("{HMR_RUNTIME_NAME}.applyUpdates([\n{}\n]);\n", entries.join(",\n"));
+  Some(HmrUpdate {
+    filename: format!("hmr-update-{}.js", &xxhash_base64_url(code.as_bytes())[..8]),
+    code,
+    module_ids: updated.iter().map(|module| module.stable_resource_id.clone()).collect(),
+  })
+}
+
+/// `function () { ...; return exports; }` of the module, or `None` if the module can't be updated without reloading,
+/// such as the ones importing external modules or using top-level await.
+fn render_module_factory(
+  module: &NormalModule,
+  modules: &IndexNormalModules,
+  source: &Arc<str>,
+) -> Option<String> {
+  if module.top_level_await_span.is_some() {
+    return None;
+  }
+  let ast = OxcCompiler::parse(Arc::clone(source), SourceType::default().with_module(true)).ok()?;
+  let (symbol_table, _) = ast.make_symbol_table_and_scope_tree();
+  let mut renderer = UpdateRenderer {
+    module,
+    modules,
+    symbol_table: &symbol_table,
+    import_bindings: FxHashMap::default(),
+    import_names: FxHashMap::default(),
+    exports: vec![],
+    local_exports: vec![],
+    edits: vec![],
+    import_count: 0,
+    handled_imports: 0,
+    is_supported: true,
+  };
+  renderer.render_program(ast.program());
+  // Imports the renderer doesn't know how to load, such as the ones of workers, need a reload.
+  if !renderer.is_supported || renderer.handled_imports != module.imports.len() {
+    return None;
+  }
+
+  let mut exports = std::mem::take(&mut renderer.exports);
+  for (exported, local) in std::mem::take(&mut renderer.local_exports) {
+    let value = renderer.import_names.get(&local).cloned().unwrap_or(local);
+    exports.push((exported, value));
+  }
+  let body = apply_edits(source, renderer.edits);
+  Some(match module.exports_kind {
+    ExportsKind::CommonJs => format!(
+      "function () {{\nvar module = {{ exports: {{}} }}, exports = module.exports;\n{body}\nreturn module.exports;\n}}"
+    ),
+    ExportsKind::Esm | ExportsKind::None => {
+      let getters = exports
+        .iter()
+        .map(|(exported, value)| format!("get {}() {{ return {value}; }}", quote(exported)))
+        .collect::<Vec<_>>();
+      format!(
+        "function () {{\n\"use strict\";\nvar __rolldown_exports__ = {{ {} }};\n{body}\nreturn __rolldown_exports__;\n}}",
+        getters.join(", ")
+      )
+    }
+  })
+}
+
+struct UpdateRenderer<'a> {
+  module: &'a NormalModule,
+  modules: &'a IndexNormalModules,
+  symbol_table: &'a SymbolTable,
+  /// What references of imported bindings are replaced with, such as `__rolldown_import_0__.foo`.
+  import_bindings: FxHashMap<SymbolId, String>,
+  /// The same as `import_bindings`, by names of the bindings, for `export { foo }` of imported `foo`.
+  import_names: FxHashMap<String, String>,
+  /// Exported names and the expressions of their values.
+  exports: Vec<(String, String)>,
+  /// Exported names of `export { foo as bar }` and their local names, which could be imported bindings.
+  local_exports: Vec<(String, String)>,
+  edits: Vec<(Span, String)>,
+  import_count: usize,
+  handled_imports: usize,
+  is_supported: bool,
+}
+
+impl<'a> UpdateRenderer<'a> {
+  fn render_program(&mut self, program: &Program<'a>) {
+    // Imports are hoisted, so bindings of them are known before rewriting references in the module.
+    for stmt in &program.body {
+      let Statement::ImportDeclaration(decl) = stmt else {
+        continue;
+      };
+      let Some(load) = self.load_expr(decl.span) else {
+        continue;
+      };
+      let specifiers = decl.specifiers.as_ref().filter(|specifiers| !specifiers.is_empty());
+      let Some(specifiers) = specifiers else {
+        self.edits.push((decl.span, format!("{load};")));
+        continue;
+      };
+      let import_var = self.declare_import(decl.span, &load);
+      for specifier in specifiers {
+        let (local, value) = match specifier {
+          ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+            (&specifier.local, property_access(&import_var, specifier.imported.name()))
+          }
+          ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+            (&specifier.local, property_access(&import_var, "default"))
+          }
+          ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+            (&specifier.local, import_var.clone())
+          }
+        };
+        if let Some(symbol_id) = local.symbol_id.get() {
+          self.import_bindings.insert(symbol_id, value.clone());
+        }
+        self.import_names.insert(local.name.to_string(), value);
+      }
+    }
+
+    for stmt in &program.body {
+      match stmt {
+        Statement::ImportDeclaration(_) => {}
+        Statement::ExportNamedDeclaration(decl) => {
+          if let Some(declaration) = &decl.declaration {
+            self.edits.push((Span::new(decl.span.start, declaration.span().start), String::new()));
+            declaration.bound_names(&mut |id| {
+              self.exports.push((id.name.to_string(), id.name.to_string()));
+            });
+            self.visit_declaration(declaration);
+          } else if decl.source.is_some() {
+            let Some(load) = self.load_expr(decl.span) else {
+              continue;
+            };
+            let import_var = self.declare_import(decl.span, &load);
+            for specifier in &decl.specifiers {
+              self.exports.push((
+                specifier.exported.name().to_string(),
+                property_access(&import_var, specifier.local.name()),
+              ));
+            }
+          } else {
+            self.edits.push((decl.span, String::new()));
+            for specifier in &decl.specifiers {
+              self
+                .local_exports
+                .push((specifier.exported.name().to_string(), specifier.local.name().to_string()));
+            }
+          }
+        }
+        Statement::ExportDefaultDeclaration(decl) => {
+          let declaration_span = decl.declaration.span();
+          let named = match &decl.declaration {
+            ExportDefaultDeclarationKind::FunctionDeclaration(func) => func.id.as_ref(),
+            ExportDefaultDeclarationKind::ClassDeclaration(class) => class.id.as_ref(),
+            _ => None,
+          };
+          if let Some(id) = named {
+            self.edits.push((Span::new(decl.span.start, declaration_span.start), String::new()));
+            self.exports.push(("default".to_string(), id.name.to_string()));
+          } else {
+            self.edits.push((
+              Span::new(decl.span.start, declaration_span.start),
+              "var __rolldown_default__ = ".to_string(),
+            ));
+            self.edits.push((Span::new(declaration_span.end, decl.span.end), ";".to_string()));
+            self.exports.push(("default".to_string(), "__rolldown_default__".to_string()));
+          }
+          self.visit_export_default_declaration(decl);
+        }
+        Statement::ExportAllDeclaration(decl) => {
+          let Some(load) = self.load_expr(decl.span) else {
+            continue;
+          };
+          if let Some(exported) = &decl.exported {
+            let import_var = self.declare_import(decl.span, &load);
+            self.exports.push((exported.name().to_string(), import_var));
+          } else {
+            self.edits.push((
+              decl.span,
+              format!("{HMR_RUNTIME_NAME}.reExport(__rolldown_exports__, {load});"),
+            ));
+          }
+        }
+        _ => self.visit_statement(stmt),
+      }
+    }
+  }
+
+  /// `__rolldown_hmr__.load("<stable id>")` of the module imported at `span`.
+  fn load_expr(&mut self, span: Span) -> Option<String> {
+    let rec_id = self.module.imports.get(&span)?;
+    self.handled_imports += 1;
+    match self.module.import_records[*rec_id].resolved_module {
+      ModuleId::Normal(importee) => Some(format!(
+        "{HMR_RUNTIME_NAME}.load({})",
+        quote(&self.modules[importee].stable_resource_id)
+      )),
+      ModuleId::External(_) => {
+        self.is_supported = false;
+        None
+      }
+    }
+  }
+
+  /// Replace the statement at `span` with `var __rolldown_import_0__ = <load>`, and return the name of the variable.
+  fn declare_import(&mut self, span: Span, load: &str) -> String {
+    let import_var = format!("__rolldown_import_{}__", self.import_count);
+    self.import_count += 1;
+    self.edits.push((span, format!("var {import_var} = {load};")));
+    import_var
+  }
+
+  fn import_binding(&self, ident: &IdentifierReference) -> Option<&String> {
+    let reference_id = ident.reference_id.get()?;
+    let symbol_id = self.symbol_table.get_reference(reference_id).symbol_id()?;
+    self.import_bindings.get(&symbol_id)
+  }
+}
+
+impl<'a> Visit<'a> for UpdateRenderer<'a> {
+  fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+    if let Some(value) = self.import_binding(ident) {
+      self.edits.push((ident.span, value.clone()));
+    }
+  }
+
+  fn visit_object_property(&mut self, prop: &ObjectProperty<'a>) {
+    if prop.shorthand {
+      if let Expression::Identifier(ident) = &prop.value {
+        if let Some(value) = self.import_binding(ident) {
+          self.edits.push((prop.span, format!("{}: {value}", ident.name)));
+          return;
+        }
+      }
+    }
+    walk::walk_object_property(self, prop);
+  }
+
+  fn visit_expression(&mut self, expr: &Expression<'a>) {
+    if let Expression::StaticMemberExpression(member_expr) = expr {
+      let is_import_meta = matches!(&member_expr.object, Expression::MetaProperty(meta)
+        if meta.meta.name == "import" && meta.property.name == "meta");
+      if is_import_meta && member_expr.property.name == "hot" {
+        self.edits.push((
+          member_expr.span,
+          format!("{HMR_RUNTIME_NAME}.hot({})", quote(&self.module.stable_resource_id)),
+        ));
+        return;
+      }
+    }
+    walk::walk_expression(self, expr);
+  }
+
+  fn visit_meta_property(&mut self, _meta: &MetaProperty<'a>) {
+    // The update is evaluated as a script, which has no `import.meta`.
+    self.is_supported = false;
+  }
+
+  fn visit_call_expression(&mut self, expr: &CallExpression<'a>) {
+    if self.module.dynamic_requires.contains_key(&expr.span) {
+      self.is_supported = false;
+    }
+    if self.module.imports.contains_key(&expr.span) {
+      if let Some(load) = self.load_expr(expr.span) {
+        self.edits.push((expr.span, load));
+      }
+      return;
+    }
+    walk::walk_call_expression(self, expr);
+  }
+
+  fn visit_import_expression(&mut self, expr: &ImportExpression<'a>) {
+    if self.module.imports.contains_key(&expr.span) {
+      if let Some(load) = self.load_expr(expr.span) {
+        let import = load.replacen(".load(", ".import(", 1);
+        self.edits.push((expr.span, import));
+      }
+      return;
+    }
+    walk::walk_import_expression(self, expr);
+  }
+}
+
+/// Apply edits, which don't overlap, to `source`.
+fn apply_edits(source: &str, mut edits: Vec<(Span, String)>) -> String {
+  edits.sort_by_key(|(span, _)| (span.start, span.end));
+  let mut code = String::with_capacity(source.len());
+  let mut last_end = 0;
+  for (span, replacement) in edits {
+    let (start, end) = (span.start as usize, span.end as usize);
+    code.push_str(&source[last_end..start]);
+    code.push_str(&replacement);
+    last_end = end;
+  }
+  code.push_str(&source[last_end..]);
+  code
+}
+
+fn property_access(object: &str, name: &str) -> String {
+  if is_validate_identifier_name(name) {
+    format!("{object}.{name}")
+  } else {
+    format!("{object}[{}]", quote(name))
+  }
+}
+
+fn quote(value: &str) -> String {
+  serde_json::to_string(value).expect("Strings should be serializable")
+}
+
+#[test]
+fn test_apply_edits() {
+  let edits = vec![
+    (Span::new(4, 5), "b".to_string()),
+    (Span::new(0, 0), "var ".to_string()),
+    (Span::new(8, 9), "2".to_string()),
+  ];
+  assert_eq!(apply_edits("let a = 1;", edits), "var let b = 2;");
+}
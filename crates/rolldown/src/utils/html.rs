@@ -0,0 +1,204 @@
+use std::ops::Range;
+
+use super::css_urls::is_local_css_url;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HtmlReferenceKind {
+  /// `<script type="module" src="..."></script>`
+  Script,
+  /// `<link rel="stylesheet" href="...">`
+  Stylesheet,
+}
+
+/// A module script or a stylesheet referenced by an HTML document, which is bundled into the chunk of the document.
+#[derive(Debug)]
+pub struct HtmlReference {
+  /// Range of the whole element, including `</script>` of scripts.
+  pub span: Range<usize>,
+  pub kind: HtmlReferenceKind,
+  pub request: String,
+}
+
+/// Find local module scripts and stylesheets in `html`. Inline scripts, classic scripts and remote files are left as is.
+pub fn find_html_references(html: &str) -> Vec<HtmlReference> {
+  let lowercase = html.to_ascii_lowercase();
+  let mut references = vec![];
+  let mut cursor = 0;
+  while let Some(offset) = lowercase[cursor..].find('<') {
+    let start = cursor + offset;
+    let rest = &lowercase[start..];
+    if rest.starts_with("<!--") {
+      cursor = rest.find("-->").map_or(html.len(), |end| start + end + "-->".len());
+      continue;
+    }
+    let Some(tag_name) = ["script", "link"].into_iter().find(|name| {
+      rest[1..].starts_with(name)
+        && rest[1 + name.len()..]
+          .starts_with(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+    }) else {
+      cursor = start + 1;
+      continue;
+    };
+    let Some((attributes, tag_end)) = parse_attributes(html, start + 1 + tag_name.len()) else {
+      break;
+    };
+    let attribute =
+      |name: &str| attributes.iter().find(|(key, _)| key == name).map(|(_, value)| value.as_str());
+    if tag_name == "script" {
+      // The content of scripts is not HTML
+      let end = lowercase[tag_end..]
+        .find("</script")
+        .and_then(|close| {
+          let close = tag_end + close;
+          lowercase[close..].find('>').map(|gt| close + gt + 1)
+        })
+        .unwrap_or(html.len());
+      if let (Some("module"), Some(src)) = (attribute("type"), attribute("src")) {
+        if is_local_css_url(src) {
+          references.push(HtmlReference {
+            span: start..end,
+            kind: HtmlReferenceKind::Script,
+            request: src.to_string(),
+          });
+        }
+      }
+      cursor = end;
+    } else {
+      let is_stylesheet = attribute("rel").is_some_and(|rel| {
+        rel.split_ascii_whitespace().any(|rel| rel.eq_ignore_ascii_case("stylesheet"))
+      });
+      if let (true, Some(href)) = (is_stylesheet, attribute("href")) {
+        if is_local_css_url(href) {
+          references.push(HtmlReference {
+            span: start..tag_end,
+            kind: HtmlReferenceKind::Stylesheet,
+            request: href.to_string(),
+          });
+        }
+      }
+      cursor = tag_end;
+    }
+  }
+  references
+}
+
+/// Parse attributes of the tag starting at `offset`, right after the tag name. Returns the attributes with lowercase
+/// names and the offset after `>`.
+fn parse_attributes(html: &str, mut offset: usize) -> Option<(Vec<(String, String)>, usize)> {
+  let bytes = html.as_bytes();
+  let mut attributes = vec![];
+  loop {
+    while offset < bytes.len() && (bytes[offset].is_ascii_whitespace() || bytes[offset] == b'/') {
+      offset += 1;
+    }
+    if *bytes.get(offset)? == b'>' {
+      return Some((attributes, offset + 1));
+    }
+    let name_start = offset;
+    while offset < bytes.len()
+      && !matches!(bytes[offset], b'=' | b'>' | b'/')
+      && !bytes[offset].is_ascii_whitespace()
+    {
+      offset += 1;
+    }
+    let name = html[name_start..offset].to_ascii_lowercase();
+    let mut value = String::new();
+    if bytes.get(offset) == Some(&b'=') {
+      offset += 1;
+      let quote = *bytes.get(offset)?;
+      if quote == b'"' || quote == b'\'' {
+        let value_start = offset + 1;
+        let value_end = value_start + html[value_start..].find(char::from(quote))?;
+        value = html[value_start..value_end].to_string();
+        offset = value_end + 1;
+      } else {
+        let value_start = offset;
+        while offset < bytes.len() && bytes[offset] != b'>' && !bytes[offset].is_ascii_whitespace()
+        {
+          offset += 1;
+        }
+        value = html[value_start..offset].to_string();
+      }
+    }
+    attributes.push((name, value));
+  }
+}
+
+/// Module source of an HTML entry, which imports the scripts and stylesheets referenced by the document.
+pub fn html_to_esm(references: &[HtmlReference]) -> String {
+  references
+    .iter()
+    .map(|reference| {
+      if reference.request.starts_with('.') {
+        format!("import \"{}\";\n", reference.request)
+      } else {
+        // Like `src` of scripts, requests are always relative to the document
+        format!("import \"./{}\";\n", reference.request)
+      }
+    })
+    .collect()
+}
+
+/// Replace the references of an HTML entry with the stylesheet and the script of its chunk. They are put where the
+/// first reference was. Lines that only contained the other references are removed.
+pub fn rewrite_html(
+  html: &str,
+  references: &[HtmlReference],
+  script_url: &str,
+  stylesheet_url: Option<&str>,
+) -> String {
+  let mut output = String::with_capacity(html.len());
+  let mut last = 0;
+  for (index, reference) in references.iter().enumerate() {
+    output.push_str(&html[last..reference.span.start]);
+    if index == 0 {
+      if let Some(stylesheet_url) = stylesheet_url {
+        output.push_str(&format!("<link rel=\"stylesheet\" href=\"{stylesheet_url}\">"));
+      }
+      output.push_str(&format!("<script type=\"module\" src=\"{script_url}\"></script>"));
+    } else {
+      let line_start = output.rfind('\n');
+      let is_alone_on_line = line_start.is_some_and(|line_start| {
+        output[line_start + 1..].trim_start().is_empty()
+          && html[reference.span.end..].trim_start_matches([' ', '\t']).starts_with(['\n', '\r'])
+      });
+      if let (true, Some(line_start)) = (is_alone_on_line, line_start) {
+        // Keep the line break after the reference, so the line of the reference goes away as a whole
+        output.truncate(output[..line_start].strip_suffix('\r').map_or(line_start, str::len));
+      }
+    }
+    last = reference.span.end;
+  }
+  output.push_str(&html[last..]);
+  output
+}
+
+#[test]
+fn test_find_html_references() {
+  let html = r#"<link rel="icon" href="favicon.ico">
+<LINK rel=stylesheet href="./style.css">
+<!-- <script type="module" src="./commented.js"></script> -->
+<script>const s = '<script type="module" src="./inline.js">'</script>
+<script type="module" src="main.js" async></script>
+<script type="module" src="https://example.com/remote.js"></script>"#;
+  let references = find_html_references(html);
+  assert_eq!(
+    references.iter().map(|r| (r.kind, r.request.as_str())).collect::<Vec<_>>(),
+    vec![(HtmlReferenceKind::Stylesheet, "./style.css"), (HtmlReferenceKind::Script, "main.js")]
+  );
+  assert_eq!(
+    &html[references[1].span.clone()],
+    r#"<script type="module" src="main.js" async></script>"#
+  );
+  assert_eq!(html_to_esm(&references), "import \"./style.css\";\nimport \"./main.js\";\n");
+}
+
+#[test]
+fn test_rewrite_html() {
+  let html = "<head>\n  <link rel=\"stylesheet\" href=\"./style.css\">\n  <script type=\"module\" src=\"./main.js\"></script>\n</head>";
+  let references = find_html_references(html);
+  assert_eq!(
+    rewrite_html(html, &references, "main.mjs", Some("main.css")),
+    "<head>\n  <link rel=\"stylesheet\" href=\"main.css\"><script type=\"module\" src=\"main.mjs\"></script>\n</head>"
+  );
+}
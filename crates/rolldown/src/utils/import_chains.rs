@@ -0,0 +1,50 @@
+use std::{collections::VecDeque, hash::Hash};
+
+use rustc_hash::FxHashMap;
+
+/// The shortest chain of imports from each entry to `target`, following modules returned by `imports`. Entries that
+/// don't reach `target` have no chain.
+pub fn shortest_import_chains<Id: Copy + Eq + Hash>(
+  entries: &[Id],
+  target: Id,
+  imports: impl Fn(Id) -> Vec<Id>,
+) -> Vec<Vec<Id>> {
+  let mut chains: Vec<Vec<Id>> = vec![];
+  for entry in entries {
+    if chains.iter().any(|chain| chain[0] == *entry) {
+      continue;
+    }
+    // Breadth-first search, recording the importer that first reached each module.
+    let mut importer_of = FxHashMap::from_iter([(*entry, None)]);
+    let mut queue = VecDeque::from([*entry]);
+    while let Some(id) = queue.pop_front() {
+      if id == target {
+        let mut chain = vec![id];
+        while let Some(Some(importer)) = importer_of.get(chain.last().expect("Chain is not empty"))
+        {
+          chain.push(*importer);
+        }
+        chain.reverse();
+        chains.push(chain);
+        break;
+      }
+      for imported in imports(id) {
+        importer_of.entry(imported).or_insert_with(|| {
+          queue.push_back(imported);
+          Some(id)
+        });
+      }
+    }
+  }
+  chains
+}
+
+#[test]
+fn test_shortest_import_chains() {
+  let graph: FxHashMap<u32, Vec<u32>> =
+    FxHashMap::from_iter([(0, vec![1, 2]), (1, vec![3]), (2, vec![1]), (3, vec![1]), (4, vec![2])]);
+  let imports = |id| graph.get(&id).cloned().unwrap_or_default();
+  assert_eq!(shortest_import_chains(&[0, 4, 5], 3, imports), vec![vec![0, 1, 3], vec![4, 2, 1, 3]]);
+  assert_eq!(shortest_import_chains(&[0], 0, imports), vec![vec![0]]);
+  assert!(shortest_import_chains(&[3], 2, imports).is_empty());
+}
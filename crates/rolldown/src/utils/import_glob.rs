@@ -0,0 +1,237 @@
+use std::path::Path;
+
+use oxc::{
+  ast::{
+    ast::{
+      Argument, ArrayExpressionElement, CallExpression, Expression, ObjectPropertyKind, Program,
+      PropertyKey,
+    },
+    visit::walk,
+    Visit,
+  },
+  span::Span,
+};
+use rolldown_fs::FileSystem;
+use rolldown_utils::{ecma_script::is_validate_identifier_name, path_ext::PathExt};
+use sugar_path::SugarPath;
+
+use super::fs_glob::glob_files;
+
+/// A `import.meta.glob(...)` call with literal arguments.
+#[derive(Debug, Default)]
+pub struct ImportGlob {
+  /// Span of the whole call
+  pub span: Span,
+  /// Glob patterns. Patterns starting with `!` exclude matched files.
+  pub patterns: Vec<String>,
+  /// Import matched modules statically instead of with `import()`.
+  pub eager: bool,
+  /// Take the named export of matched modules instead of the namespace.
+  pub import: Option<String>,
+  /// Query appended to the import requests, such as `raw` for `?raw`.
+  pub query: Option<String>,
+}
+
+/// Find `import.meta.glob(...)` calls in the module. Calls with non-literal arguments are ignored.
+pub fn find_import_globs(program: &Program) -> Vec<ImportGlob> {
+  let mut finder = ImportGlobFinder { globs: vec![] };
+  finder.visit_program(program);
+  finder.globs
+}
+
+/// Replace `import.meta.glob(...)` calls with objects mapping paths of matched files to their modules.
+/// - `import.meta.glob('./dir/*.js')` => `{ "./dir/a.js": () => import("./dir/a.js") }`
+/// - `import.meta.glob('./dir/*.js', { eager: true })` => `{ "./dir/a.js": __glob_0_0 }` with
+///   `import * as __glob_0_0 from "./dir/a.js"` appended to the module.
+///
+/// Relative patterns are matched against the directory of the importer, and absolute ones against `cwd`.
+/// Static imports are appended to the end of the module, so positions of the original code are not changed.
+pub fn expand_import_globs(
+  fs: &impl FileSystem,
+  source: &str,
+  globs: &[ImportGlob],
+  importer: &Path,
+  cwd: &Path,
+) -> anyhow::Result<String> {
+  let mut output = String::with_capacity(source.len());
+  let mut static_imports = String::new();
+  let mut last = 0;
+  for (glob_idx, glob) in globs.iter().enumerate() {
+    let requests = match_import_glob(fs, glob, importer, cwd)?;
+    let entries = requests
+      .iter()
+      .enumerate()
+      .map(|(idx, request)| {
+        let key = quote_str(request);
+        let specifier = match &glob.query {
+          Some(query) => quote_str(&format!("{request}?{query}")),
+          None => key.clone(),
+        };
+        match (glob.eager, &glob.import) {
+          (true, import) => {
+            let binding = format!("__glob_{glob_idx}_{idx}");
+            static_imports.push_str(&match import {
+              Some(name) if is_validate_identifier_name(name) => {
+                format!("import {{ {name} as {binding} }} from {specifier};\n")
+              }
+              Some(name) => {
+                format!("import {{ {} as {binding} }} from {specifier};\n", quote_str(name))
+              }
+              None => format!("import * as {binding} from {specifier};\n"),
+            });
+            format!("{key}: {binding}")
+          }
+          (false, Some(name)) => {
+            format!("{key}: () => import({specifier}).then((m) => m[{}])", quote_str(name))
+          }
+          (false, None) => format!("{key}: () => import({specifier})"),
+        }
+      })
+      .collect::<Vec<_>>();
+    output.push_str(&source[last..glob.span.start as usize]);
+    output.push_str(&format!("({{ {} }})", entries.join(", ")));
+    last = glob.span.end as usize;
+  }
+  output.push_str(&source[last..]);
+  if !static_imports.is_empty() {
+    output.push('\n');
+    output.push_str(&static_imports);
+  }
+  Ok(output)
+}
+
+/// Returns requests of matched files, which are relative to the importer for relative patterns, and relative to `cwd`
+/// with a leading `/` for absolute ones. The importer itself is excluded.
+fn match_import_glob(
+  fs: &impl FileSystem,
+  glob: &ImportGlob,
+  importer: &Path,
+  cwd: &Path,
+) -> anyhow::Result<Vec<String>> {
+  let importer_dir = importer.parent().expect("Module should be located in a directory");
+  let base_of = |pattern: &str| if pattern.starts_with('/') { cwd } else { importer_dir };
+  let mut excludes = vec![];
+  for pattern in glob.patterns.iter().filter_map(|pattern| pattern.strip_prefix('!')) {
+    let pattern = base_of(pattern).join(pattern.trim_start_matches('/')).normalize();
+    excludes.push(glob::Pattern::new(&pattern.to_string_lossy())?);
+  }
+
+  let mut requests = vec![];
+  for pattern in glob.patterns.iter().filter(|pattern| !pattern.starts_with('!')) {
+    let is_absolute = pattern.starts_with('/');
+    let base = base_of(pattern);
+    let full_pattern = base.join(pattern.trim_start_matches('/')).normalize();
+    for path in glob_files(fs, &full_pattern)? {
+      if path == importer || excludes.iter().any(|exclude| exclude.matches_path(&path)) {
+        continue;
+      }
+      let relative = path.relative(base).expect_to_slash();
+      let request = if is_absolute {
+        format!("/{relative}")
+      } else if relative.starts_with("../") {
+        relative
+      } else {
+        format!("./{relative}")
+      };
+      requests.push(request);
+    }
+  }
+  requests.sort();
+  requests.dedup();
+  Ok(requests)
+}
+
+struct ImportGlobFinder {
+  globs: Vec<ImportGlob>,
+}
+
+impl<'ast> Visit<'ast> for ImportGlobFinder {
+  fn visit_call_expression(&mut self, expr: &CallExpression<'ast>) {
+    if let Some(glob) = extract_import_glob(expr) {
+      self.globs.push(glob);
+    }
+    walk::walk_call_expression(self, expr);
+  }
+}
+
+fn extract_import_glob(expr: &CallExpression) -> Option<ImportGlob> {
+  let Expression::StaticMemberExpression(callee) = &expr.callee else {
+    return None;
+  };
+  let is_import_meta = matches!(&callee.object, Expression::MetaProperty(meta)
+    if meta.meta.name == "import" && meta.property.name == "meta");
+  if !is_import_meta || callee.property.name != "glob" {
+    return None;
+  }
+
+  let mut glob = ImportGlob { span: expr.span, ..Default::default() };
+  match expr.arguments.first()? {
+    Argument::StringLiteral(pattern) => glob.patterns.push(pattern.value.to_string()),
+    Argument::ArrayExpression(patterns) => {
+      for pattern in &patterns.elements {
+        let ArrayExpressionElement::StringLiteral(pattern) = pattern else {
+          return None;
+        };
+        glob.patterns.push(pattern.value.to_string());
+      }
+    }
+    _ => return None,
+  }
+  if let Some(options) = expr.arguments.get(1) {
+    let Argument::ObjectExpression(options) = options else {
+      return None;
+    };
+    for prop in &options.properties {
+      let ObjectPropertyKind::ObjectProperty(prop) = prop else {
+        return None;
+      };
+      let key = match &prop.key {
+        PropertyKey::StaticIdentifier(id) => id.name.as_str(),
+        PropertyKey::StringLiteral(key) => key.value.as_str(),
+        _ => return None,
+      };
+      match (key, &prop.value) {
+        ("eager", Expression::BooleanLiteral(value)) => glob.eager = value.value,
+        ("import", Expression::StringLiteral(value)) => glob.import = Some(value.value.to_string()),
+        ("query", Expression::StringLiteral(value)) => {
+          glob.query = Some(value.value.trim_start_matches('?').to_string());
+        }
+        _ => return None,
+      }
+    }
+  }
+  Some(glob)
+}
+
+fn quote_str(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[test]
+fn test_expand_import_globs() {
+  use oxc::span::SourceType;
+  use rolldown_oxc_utils::OxcCompiler;
+
+  let dir = Path::new("/project");
+  let mut fs = rolldown_fs::MemoryFileSystem::default();
+  for file in ["main.js", "modules/a.js", "modules/b.js", "modules/c.txt"] {
+    fs.add_file(&dir.join(file), "");
+  }
+
+  let source = "const lazy = import.meta.glob(['./modules/*.js', '!./modules/b.js']);\nconst eager = import.meta.glob('./modules/*', { eager: true, import: 'default', query: '?raw' });\nconst skipped = import.meta.glob(pattern);";
+  let ast = OxcCompiler::parse(source, SourceType::default().with_module(true)).unwrap();
+  let globs = find_import_globs(ast.program());
+  assert_eq!(globs.len(), 2);
+  assert_eq!(
+    expand_import_globs(&fs, source, &globs, &dir.join("main.js"), dir).unwrap(),
+    [
+      "const lazy = ({ \"./modules/a.js\": () => import(\"./modules/a.js\") });\n",
+      "const eager = ({ \"./modules/a.js\": __glob_1_0, \"./modules/b.js\": __glob_1_1, \"./modules/c.txt\": __glob_1_2 });\n",
+      "const skipped = import.meta.glob(pattern);\n",
+      "import { default as __glob_1_0 } from \"./modules/a.js?raw\";\n",
+      "import { default as __glob_1_1 } from \"./modules/b.js?raw\";\n",
+      "import { default as __glob_1_2 } from \"./modules/c.txt?raw\";\n",
+    ]
+    .concat()
+  );
+}
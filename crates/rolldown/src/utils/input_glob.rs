@@ -0,0 +1,56 @@
+use std::path::Path;
+
+use rolldown_common::InputItem;
+use rolldown_fs::FileSystem;
+
+use super::fs_glob::{glob_base, glob_files};
+
+/// Whether the entry is a glob pattern, such as `src/pages/**/*.ts`, instead of a path or a package.
+pub fn is_glob_entry(import: &str) -> bool {
+  import.contains(['*', '?'])
+}
+
+/// Expand the entry of a glob pattern to entries of matched files, which are sorted so the order of entries is stable.
+/// Each entry is named after its path relative to the part of the pattern before the first wildcard, without the
+/// extension. The name of the item, if any, prefixes the names of matched entries.
+///
+/// Returns an empty `Vec` if nothing matches.
+pub fn expand_input_glob(fs: &impl FileSystem, item: &InputItem, cwd: &Path) -> Vec<InputItem> {
+  let pattern = cwd.join(&item.import);
+  let Ok(paths) = glob_files(fs, &pattern) else {
+    return vec![];
+  };
+  let base = glob_base(&pattern);
+  paths
+    .into_iter()
+    .map(|path| {
+      let name = entry_name(&base, &path);
+      InputItem {
+        name: Some(match &item.name {
+          Some(prefix) => format!("{prefix}/{name}"),
+          None => name,
+        }),
+        import: path.to_string_lossy().into_owned(),
+      }
+    })
+    .collect()
+}
+
+fn entry_name(base: &Path, path: &Path) -> String {
+  let relative = path.strip_prefix(base).unwrap_or(path).with_extension("");
+  relative
+    .components()
+    .map(|component| component.as_os_str().to_string_lossy())
+    .collect::<Vec<_>>()
+    .join("/")
+}
+
+#[test]
+fn test_input_glob_names() {
+  let base = glob_base(Path::new("/root/src/pages/**/*.ts"));
+  assert_eq!(base, Path::new("/root/src/pages"));
+  assert_eq!(entry_name(&base, Path::new("/root/src/pages/about.ts")), "about");
+  assert_eq!(entry_name(&base, Path::new("/root/src/pages/blog/index.ts")), "blog/index");
+  assert!(is_glob_entry("src/pages/*.ts"));
+  assert!(!is_glob_entry("src/pages/[id].ts"));
+}
@@ -0,0 +1,75 @@
+use std::path::Path;
+
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::base64::from_standard_base64;
+use sugar_path::SugarPath;
+
+/// Returns the URL of the `//# sourceMappingURL=` comment on the last line of the code.
+pub fn find_sourcemap_url(code: &str) -> Option<&str> {
+  let code = code.trim_end();
+  let last_line = code[code.rfind('\n').map_or(0, |idx| idx + 1)..].trim();
+  let url = last_line
+    .strip_prefix("//# sourceMappingURL=")
+    .or_else(|| last_line.strip_prefix("//@ sourceMappingURL="))?
+    .trim();
+  (!url.is_empty()).then_some(url)
+}
+
+/// Load the sourcemap referenced by the code, which is generated by tools run before bundling, such as `tsc`.
+/// Inline `data:` URLs are decoded and other URLs are read relative to the file. Sources of the sourcemap are turned
+/// into absolute paths, like sources of other sourcemaps of modules.
+///
+/// Sourcemaps that can't be loaded are ignored, since the code is still bundled correctly without them.
+pub fn load_input_sourcemap(
+  code: &str,
+  file_path: &Path,
+  fs: &dyn rolldown_fs::FileSystem,
+) -> Option<SourceMap> {
+  let url = find_sourcemap_url(code)?;
+  let file_dir = file_path.parent()?;
+  let (json, map_dir) = if let Some(data_url) = url.strip_prefix("data:") {
+    (decode_data_url(data_url)?, file_dir.to_path_buf())
+  } else {
+    if url.contains(':') {
+      // Remote sourcemaps
+      return None;
+    }
+    let map_path = file_dir.join(url.split(['?', '#']).next()?).normalize();
+    let json = fs.read_to_string(&map_path).ok()?;
+    (json, map_path.parent()?.to_path_buf())
+  };
+
+  let mut map = SourceMap::from_json_string(&json).ok()?;
+  let sources = map
+    .get_sources()
+    .map(|source| map_dir.join(source).normalize().to_string_lossy().into_owned())
+    .collect::<Vec<_>>();
+  map.set_sources(sources.iter().map(String::as_str).collect());
+  Some(map)
+}
+
+/// `application/json;base64,eyJ2...` to the JSON string.
+fn decode_data_url(data_url: &str) -> Option<String> {
+  let (header, data) = data_url.split_once(',')?;
+  if header.ends_with(";base64") {
+    String::from_utf8(from_standard_base64(data).ok()?).ok()
+  } else {
+    Some(data.to_string())
+  }
+}
+
+#[test]
+fn test_find_sourcemap_url() {
+  assert_eq!(find_sourcemap_url("foo();\n//# sourceMappingURL=foo.js.map\n"), Some("foo.js.map"));
+  assert_eq!(
+    find_sourcemap_url("//@ sourceMappingURL=data:application/json;base64,e30="),
+    Some("data:application/json;base64,e30=")
+  );
+  assert_eq!(find_sourcemap_url("//# sourceMappingURL=foo.js.map\nfoo();"), None);
+}
+
+#[test]
+fn test_decode_data_url() {
+  assert_eq!(decode_data_url("application/json;charset=utf-8;base64,e30=").as_deref(), Some("{}"));
+  assert_eq!(decode_data_url("application/json,{}").as_deref(), Some("{}"));
+}
@@ -0,0 +1,39 @@
+/// The client of `lazyCompilation` in the browser, which the runtime defines unless the dev server has defined it.
+/// `load(id)` compiles the module and resolves to its namespace.
+pub const LAZY_COMPILATION_RUNTIME_NAME: &str = "__rolldown_lazy__";
+
+const LAZY_PROXY_PREFIX: &str = "\0rolldown-lazy:";
+
+/// The id of the proxy module standing for the dynamically imported module, which is virtual.
+pub fn lazy_proxy_id(target: &str) -> String {
+  format!("{LAZY_PROXY_PREFIX}{target}")
+}
+
+/// The id of the module that the proxy module stands for.
+pub fn lazy_proxy_target(id: &str) -> Option<&str> {
+  id.strip_prefix(LAZY_PROXY_PREFIX)
+}
+
+/// The proxy module exports a promise of the namespace of the module as the default export. `import()` of the proxy
+/// module is rewritten to take the default export, so importers get the namespace as they would without the proxy.
+pub fn lazy_proxy_source(stable_target_id: &str) -> String {
+  format!(
+    "export default globalThis.{LAZY_COMPILATION_RUNTIME_NAME}.load({});\n",
+    quote_str(stable_target_id)
+  )
+}
+
+fn quote_str(value: &str) -> String {
+  format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[test]
+fn test_lazy_proxy() {
+  let id = lazy_proxy_id("/root/src/pages/about.js");
+  assert_eq!(lazy_proxy_target(&id), Some("/root/src/pages/about.js"));
+  assert_eq!(lazy_proxy_target("/root/src/pages/about.js"), None);
+  assert_eq!(
+    lazy_proxy_source("src/pages/about.js"),
+    "export default globalThis.__rolldown_lazy__.load(\"src/pages/about.js\");\n"
+  );
+}
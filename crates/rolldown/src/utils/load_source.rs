@@ -1,15 +1,28 @@
-use rolldown_common::{side_effects::HookSideEffects, ResolvedPath};
+use rolldown_common::{side_effects::HookSideEffects, Loader, ResolvedPath};
+use rolldown_loader_utils::text_to_esm;
 use rolldown_plugin::{HookLoadArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
+use rolldown_utils::base64::to_standard_base64;
 use sugar_path::SugarPath;
 
+use super::{
+  data_url::to_data_url,
+  module_query::split_query,
+  worker::{parse_worker_query, worker_wrapper_source},
+};
+
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_path: &ResolvedPath,
   fs: &dyn rolldown_fs::FileSystem,
+  loader: Loader,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  asset_source: &mut Option<Vec<u8>>,
 ) -> anyhow::Result<String> {
+  // Queries, such as `?raw`, are not part of the path on disk.
+  let (file_path, query) = split_query(&resolved_path.path);
+  let worker_query = query.and_then(parse_worker_query);
   let source =
     if let Some(r) = plugin_driver.load(&HookLoadArgs { id: &resolved_path.path }).await? {
       if let Some(map) = r.map {
@@ -18,11 +31,124 @@ pub async fn load_source(
       if let Some(v) = r.side_effects {
         *side_effects = Some(v);
       }
-      r.code
+      if is_binary_loader(loader) {
+        binary_to_esm(loader, file_path, r.code.as_bytes())?
+      } else {
+        r.code
+      }
     } else if resolved_path.ignored {
       String::new()
+    } else if let Some(worker_query) = worker_query {
+      let file_name = file_path.as_path().file_name().map(|name| name.to_string_lossy());
+      worker_wrapper_source(file_name.as_deref().unwrap_or(file_path), &worker_query)
+    } else if matches!(loader, Loader::Empty) {
+      String::new()
+    } else if is_binary_loader(loader) {
+      binary_to_esm(loader, file_path, &fs.read(file_path.as_path())?)?
+    } else if matches!(loader, Loader::Asset | Loader::Wasm) {
+      // The content of assets and WebAssembly modules is binary. The source is filled by the caller.
+      *asset_source = Some(fs.read(file_path.as_path())?);
+      String::new()
+    } else if matches!(loader, Loader::Text) {
+      // The byte order mark is not part of the text
+      let text = fs.read_to_string(file_path.as_path())?;
+      if text.starts_with('\u{feff}') {
+        text['\u{feff}'.len_utf8()..].to_string()
+      } else {
+        text
+      }
     } else {
-      fs.read_to_string(resolved_path.path.as_path())?
+      fs.read_to_string(file_path.as_path())?
     };
   Ok(source)
 }
+
+/// Loaders that take the raw bytes of the file, which might not be valid utf8.
+fn is_binary_loader(loader: Loader) -> bool {
+  matches!(loader, Loader::Base64 | Loader::DataUrl | Loader::Binary)
+}
+
+/// - `base64` exports the content encoded in base64.
+/// - `dataurl` exports the content as a `data:` URL.
+/// - `binary` exports the content as a `Uint8Array`, which is decoded from base64 at runtime.
+fn binary_to_esm(loader: Loader, file_path: &str, content: &[u8]) -> anyhow::Result<String> {
+  let base64 = to_standard_base64(content);
+  match loader {
+    Loader::Base64 => text_to_esm(&base64),
+    Loader::DataUrl => text_to_esm(&to_data_url(file_path.as_path(), content)),
+    Loader::Binary => {
+      Ok(format!("export default Uint8Array.from(atob(\"{base64}\"), (c) => c.charCodeAt(0));"))
+    }
+    _ => unreachable!("{loader:?} is not a binary loader"),
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_loaders_apply_to_plugin_loaded_contents() {
+    use std::borrow::Cow;
+
+    use rolldown_common::{BundlerOptions, Output};
+    use rolldown_plugin::{HookLoadOutput, HookLoadReturn, Plugin, SharedPluginContext};
+
+    use crate::test_utils::{memory_bundler, memory_fs};
+
+    /// Loads the contents of `shader.frag` and `data.bin` instead of the files on disk.
+    #[derive(Debug)]
+    struct Virtual;
+
+    #[async_trait::async_trait]
+    impl Plugin for Virtual {
+      fn name(&self) -> Cow<'static, str> {
+        "virtual".into()
+      }
+
+      async fn load(&self, _ctx: &SharedPluginContext, args: &HookLoadArgs) -> HookLoadReturn {
+        let code = match args.id {
+          "/project/shader.frag" => "void main() {}",
+          "/project/data.bin" => "\u{1}\u{2}",
+          _ => return Ok(None),
+        };
+        Ok(Some(HookLoadOutput { code: code.to_string(), map: None, side_effects: None }))
+      }
+    }
+
+    let fs = memory_fs(&[
+      (
+        "main.js",
+        "import shader from './shader.frag';\nimport data from './data.bin';\nconsole.log(shader, data);\n",
+      ),
+      ("shader.frag", "on disk"),
+      ("data.bin", "on disk"),
+    ]);
+    let mut bundler = memory_bundler(
+      &fs,
+      BundlerOptions {
+        module_types: Some(
+          [(".frag".to_string(), Loader::Text), (".bin".to_string(), Loader::Binary)].into(),
+        ),
+        ..Default::default()
+      },
+    )
+    .with_plugins(vec![Box::new(Virtual)])
+    .build();
+    let output = bundler.generate().await.unwrap();
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+
+    let Some(Output::Chunk(chunk)) =
+      output.assets.iter().find(|asset| asset.filename() == "main.js")
+    else {
+      panic!("main.js should be emitted as a chunk");
+    };
+    assert!(!chunk.code.contains("on disk"), "{}", chunk.code);
+    assert!(chunk.code.contains("'void main() {}'"), "{}", chunk.code);
+    assert!(
+      chunk.code.contains(&format!("atob('{}')", to_standard_base64([1, 2]))),
+      "{}",
+      chunk.code
+    );
+  }
+}
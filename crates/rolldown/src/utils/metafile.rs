@@ -0,0 +1,218 @@
+use rolldown_common::{
+  ExportsKind, ImportKind, ModuleId, NormalModule, NormalizedBundlerOptions, Output, OutputChunk,
+  ResourceId, WrapKind,
+};
+use rolldown_utils::path_ext::PathExt;
+use rustc_hash::FxHashMap;
+use serde_json::{json, Map, Value};
+use sugar_path::SugarPath;
+
+use crate::{stages::link_stage::LinkStageOutput, types::linking_metadata::WrapReason};
+
+/// Describe inputs and outputs of the build in the format of esbuild's metafile, so tools analyzing esbuild's metafile
+/// work with rolldown too. See https://esbuild.github.io/api/#metafile
+///
+/// Inputs wrapped in `__commonJS`/`__esm` closures have `wrap` with the kind of the wrapper and the reason, which is one
+/// of `commonjs`, `required`, `imported-as-commonjs` and `imported-by-wrapped`, and the `importer` causing it.
+///
+/// Packages bundled from more than one directory of `node_modules` are listed in `duplicatePackages`. Paths of inputs
+/// and outputs, and absolute paths of externals, are relative to `cwd`. `streamed_chunk_bytes` are sizes of chunks with
+/// `streamWrites`, whose code is dropped once written.
+pub fn generate_metafile(
+  outputs: &[Output],
+  link_output: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+  streamed_chunk_bytes: &FxHashMap<String, usize>,
+) -> String {
+  let module_table = &link_output.module_table;
+  let inputs = module_table
+    .normal_modules
+    .iter()
+    .filter(|module| module.id != link_output.runtime.id())
+    .map(|module| (module.stable_resource_id.clone(), input_json(module, link_output, options)))
+    .collect::<Map<_, _>>();
+
+  let modules_by_id = module_table
+    .normal_modules
+    .iter()
+    .map(|module| (&module.resource_id, module))
+    .collect::<FxHashMap<&ResourceId, &NormalModule>>();
+
+  let mut metafile_outputs = Map::new();
+  for output in outputs {
+    let (filename, output) = match output {
+      Output::Asset(asset) => (
+        asset.filename.as_str(),
+        json!({
+          "bytes": asset.source.as_bytes().len(),
+          "inputs": {},
+          "imports": [],
+          "exports": [],
+        }),
+      ),
+      Output::Chunk(chunk) => (
+        chunk.filename.as_str(),
+        chunk_output_json(chunk, &modules_by_id, link_output, options, streamed_chunk_bytes),
+      ),
+    };
+    metafile_outputs.insert(output_path(filename, options), output);
+  }
+
+  let mut metafile = json!({ "inputs": inputs, "outputs": metafile_outputs });
+  // Not part of esbuild's format. Tools reading the format ignore fields they don't know.
+  if !link_output.duplicate_packages.is_empty() {
+    metafile["duplicatePackages"] = link_output
+      .duplicate_packages
+      .iter()
+      .map(|package| {
+        let copies = package
+          .copies
+          .iter()
+          .map(|copy| {
+            json!({ "version": copy.version, "path": copy.path, "importChains": copy.import_chains })
+          })
+          .collect::<Vec<_>>();
+        (package.name.clone(), Value::from(copies))
+      })
+      .collect::<Map<_, _>>()
+      .into();
+  }
+  metafile.to_string()
+}
+
+fn output_path(filename: &str, options: &NormalizedBundlerOptions) -> String {
+  options.cwd.join(&options.dir).join(filename).relative(&options.cwd).expect_to_slash()
+}
+
+/// The path of an external, relative to `cwd` if it's an absolute path. URLs and bare names are kept as is.
+fn external_path(name: &str, options: &NormalizedBundlerOptions) -> String {
+  if name.as_path().is_absolute() && !name.starts_with("//") {
+    name.relative(&options.cwd).expect_to_slash()
+  } else {
+    name.to_string()
+  }
+}
+
+fn input_json(
+  module: &NormalModule,
+  link_output: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+) -> Value {
+  let module_table = &link_output.module_table;
+  let imports = module
+    .import_records
+    .iter()
+    .map(|record| {
+      let (path, external) = match record.resolved_module {
+        ModuleId::Normal(id) => (module_table.normal_modules[id].stable_resource_id.clone(), false),
+        ModuleId::External(id) => {
+          (external_path(&module_table.external_modules[id].name, options), true)
+        }
+      };
+      let mut import = json!({
+        "path": path,
+        "kind": record.kind.to_string(),
+        "original": record.module_request.as_str(),
+      });
+      if external {
+        import["external"] = true.into();
+      }
+      if !record.attributes.is_empty() {
+        import["with"] = record
+          .attributes
+          .iter()
+          .map(|(key, value)| (key.to_string(), Value::from(value.as_str())))
+          .collect::<Map<_, _>>()
+          .into();
+      }
+      import
+    })
+    .collect::<Vec<_>>();
+  let mut input = json!({ "bytes": module.source.len(), "imports": imports });
+  match module.exports_kind {
+    ExportsKind::Esm => input["format"] = "esm".into(),
+    ExportsKind::CommonJs => input["format"] = "cjs".into(),
+    ExportsKind::None => {}
+  }
+  // Not part of esbuild's format.
+  if let Some(reason) = link_output.metas[module.id].wrap_reason {
+    let kind = match link_output.metas[module.id].wrap_kind {
+      WrapKind::Cjs => "cjs",
+      WrapKind::Esm | WrapKind::None => "esm",
+    };
+    let (reason, importer) = match reason {
+      WrapReason::CommonJs => ("commonjs", None),
+      WrapReason::Required { importer } => ("required", Some(importer)),
+      WrapReason::ImportedAsCommonJs { importer } => ("imported-as-commonjs", Some(importer)),
+      WrapReason::ImportedByWrapped { importer } => ("imported-by-wrapped", Some(importer)),
+    };
+    let mut wrap = json!({ "kind": kind, "reason": reason });
+    if let Some(importer) = importer {
+      wrap["importer"] = module_table.normal_modules[importer].stable_resource_id.clone().into();
+    }
+    input["wrap"] = wrap;
+  }
+  input
+}
+
+fn chunk_output_json(
+  chunk: &OutputChunk,
+  modules_by_id: &FxHashMap<&ResourceId, &NormalModule>,
+  link_output: &LinkStageOutput,
+  options: &NormalizedBundlerOptions,
+  streamed_chunk_bytes: &FxHashMap<String, usize>,
+) -> Value {
+  let inputs = chunk
+    .module_ids
+    .iter()
+    .filter_map(|id| {
+      // The runtime isn't listed in inputs either.
+      let module = modules_by_id.get(id).filter(|module| module.id != link_output.runtime.id())?;
+      let bytes_in_output = chunk.modules.get(id).map_or(0, |m| m.rendered_length);
+      Some((module.stable_resource_id.clone(), json!({ "bytesInOutput": bytes_in_output })))
+    })
+    .collect::<Map<_, _>>();
+
+  let mut imports = chunk
+    .imports
+    .iter()
+    .map(|filename| {
+      json!({ "path": output_path(filename, options), "kind": ImportKind::Import.to_string() })
+    })
+    .chain(chunk.dynamic_imports.iter().map(|filename| {
+      json!({ "path": output_path(filename, options), "kind": ImportKind::DynamicImport.to_string() })
+    }))
+    .collect::<Vec<_>>();
+  // Externals stay as imports of the chunks containing modules importing them.
+  for id in &chunk.module_ids {
+    let Some(module) = modules_by_id.get(id) else { continue };
+    for record in &module.import_records {
+      if let ModuleId::External(external_id) = record.resolved_module {
+        let import = json!({
+          "path": external_path(&link_output.module_table.external_modules[external_id].name, options),
+          "kind": record.kind.to_string(),
+          "external": true,
+        });
+        if !imports.contains(&import) {
+          imports.push(import);
+        }
+      }
+    }
+  }
+
+  let mut output = json!({
+    "bytes": streamed_chunk_bytes.get(chunk.filename.as_str()).copied().unwrap_or(chunk.code.len()),
+    "inputs": inputs,
+    "imports": imports,
+    "exports": chunk.exports,
+  });
+  if chunk.is_entry {
+    if let Some(module) = chunk.facade_module_id.as_ref().and_then(|id| modules_by_id.get(id)) {
+      output["entryPoint"] = module.stable_resource_id.clone().into();
+    }
+  }
+  if let Some(css_filename) = &chunk.css_filename {
+    output["cssBundle"] = output_path(css_filename, options).into();
+  }
+  output
+}
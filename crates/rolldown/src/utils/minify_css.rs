@@ -0,0 +1,166 @@
+/// Minify a stylesheet by removing comments and unnecessary whitespace, and merging adjacent rules with the same
+/// selector. Legal comments starting with `/*!` are preserved.
+pub fn minify_css(css: &str) -> String {
+  merge_adjacent_rules(&strip_whitespace_and_comments(css))
+}
+
+fn strip_whitespace_and_comments(css: &str) -> String {
+  let bytes = css.as_bytes();
+  let mut output = String::with_capacity(css.len());
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'/' if bytes.get(i + 1) == Some(&b'*') => {
+        let end =
+          bytes[i + 2..].windows(2).position(|w| w == b"*/").map_or(bytes.len(), |pos| i + pos + 4);
+        if bytes.get(i + 2) == Some(&b'!') {
+          output.push_str(&css[i..end]);
+        }
+        i = end;
+      }
+      b'"' | b'\'' => {
+        let quote = bytes[i];
+        let mut end = i + 1;
+        while end < bytes.len() && bytes[end] != quote {
+          end += if bytes[end] == b'\\' { 2 } else { 1 };
+        }
+        end = (end + 1).min(bytes.len());
+        output.push_str(&css[i..end]);
+        i = end;
+      }
+      b if b.is_ascii_whitespace() => {
+        while i < bytes.len() && bytes[i].is_ascii_whitespace() {
+          i += 1;
+        }
+        // Whitespace is significant between other tokens, such as `.a .b` and `1px solid`.
+        let is_needed = match (output.as_bytes().last(), bytes.get(i)) {
+          (None, _) | (_, None) => false,
+          (Some(prev), Some(next)) => !b"{};,>:".contains(prev) && !b"{};,>!)".contains(next),
+        };
+        if is_needed {
+          output.push(' ');
+        }
+      }
+      b'}' => {
+        if output.ends_with(';') {
+          output.pop();
+        }
+        output.push('}');
+        i += 1;
+      }
+      _ => {
+        let start = i;
+        i += 1;
+        // Non-ASCII characters are copied as a whole
+        while i < bytes.len() && !css.is_char_boundary(i) {
+          i += 1;
+        }
+        output.push_str(&css[start..i]);
+      }
+    }
+  }
+  output
+}
+
+/// Merge adjacent top-level rules with the same selector, such as `.a{color:red}.a{margin:0}` to
+/// `.a{color:red;margin:0}`. Expects a stylesheet without comments and unnecessary whitespace.
+fn merge_adjacent_rules(css: &str) -> String {
+  let bytes = css.as_bytes();
+  let mut output = String::with_capacity(css.len());
+  // Selector and declarations of the last top-level rule, which might be merged with the next one
+  let mut pending: Option<(&str, String)> = None;
+  let mut prelude_start = 0;
+  let mut i = 0;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => {
+        let quote = bytes[i];
+        i += 1;
+        while i < bytes.len() && bytes[i] != quote {
+          i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+        i += 1;
+      }
+      b'{' => {
+        let prelude = &css[prelude_start..i];
+        let block_end = find_block_end(bytes, i);
+        let block = &css[i + 1..block_end.min(bytes.len())];
+        let is_plain_rule = !prelude.starts_with('@') && !block.contains('{');
+        match &mut pending {
+          Some((selector, declarations)) if is_plain_rule && *selector == prelude => {
+            if !declarations.is_empty() && !block.is_empty() {
+              declarations.push(';');
+            }
+            declarations.push_str(block);
+          }
+          _ => {
+            flush_pending_rule(&mut output, pending.take());
+            if is_plain_rule {
+              pending = Some((prelude, block.to_string()));
+            } else {
+              output.push_str(&css[prelude_start..(block_end + 1).min(bytes.len())]);
+            }
+          }
+        }
+        i = block_end + 1;
+        prelude_start = i;
+      }
+      b';' => {
+        flush_pending_rule(&mut output, pending.take());
+        output.push_str(&css[prelude_start..=i]);
+        i += 1;
+        prelude_start = i;
+      }
+      _ => i += 1,
+    }
+  }
+  flush_pending_rule(&mut output, pending);
+  output.push_str(&css[prelude_start.min(bytes.len())..]);
+  output
+}
+
+fn flush_pending_rule(output: &mut String, rule: Option<(&str, String)>) {
+  if let Some((selector, declarations)) = rule {
+    output.push_str(selector);
+    output.push('{');
+    output.push_str(&declarations);
+    output.push('}');
+  }
+}
+
+/// Returns the index of the `}` that closes the block opened at `start`.
+fn find_block_end(bytes: &[u8], start: usize) -> usize {
+  let mut depth = 0;
+  let mut i = start;
+  while i < bytes.len() {
+    match bytes[i] {
+      b'"' | b'\'' => {
+        let quote = bytes[i];
+        i += 1;
+        while i < bytes.len() && bytes[i] != quote {
+          i += if bytes[i] == b'\\' { 2 } else { 1 };
+        }
+      }
+      b'{' => depth += 1,
+      b'}' => {
+        depth -= 1;
+        if depth == 0 {
+          return i;
+        }
+      }
+      _ => {}
+    }
+    i += 1;
+  }
+  bytes.len()
+}
+
+#[test]
+fn test_minify_css() {
+  assert_eq!(
+    minify_css(
+      "/* comment */\n.a .b,\n.c > .d {\n  color: red;\n  content: \"a  b\";\n}\n.a .b,\n.c > .d { margin: 0 auto; }\n@media (width > 1px) {\n  .a { border: 1px solid; }\n}\n/*! legal */"
+    ),
+    ".a .b,.c>.d{color:red;content:\"a  b\";margin:0 auto}@media (width>1px){.a{border:1px solid}}/*! legal */"
+  );
+}
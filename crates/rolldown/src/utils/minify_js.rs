@@ -0,0 +1,45 @@
+use oxc::{
+  codegen::{Codegen, CodegenOptions},
+  span::SourceType,
+};
+use rolldown_common::OutputFormat;
+use rolldown_oxc_utils::OxcCompiler;
+use rolldown_sourcemap::{collapse_sourcemaps, SourceMap};
+
+/// Minify the code of a chunk by printing it again without whitespace and comments. `map` of the chunk is composed with
+/// the sourcemap of the minification, so it still points at the original sources.
+pub fn minify_js(
+  code: &str,
+  map: Option<SourceMap>,
+  format: &OutputFormat,
+) -> anyhow::Result<(String, Option<SourceMap>)> {
+  let source_type = SourceType::default().with_module(matches!(format, OutputFormat::Esm));
+  let ast = OxcCompiler::parse(code, source_type)?;
+  let ret = Codegen::<true>::new(
+    "",
+    code,
+    CodegenOptions { enable_typescript: false, enable_source_map: map.is_some() },
+  )
+  .build(ast.program());
+  let map = map.and_then(|map| collapse_sourcemaps(vec![&map, ret.source_map.as_ref()?]));
+  Ok((ret.source_text, map))
+}
+
+#[test]
+fn test_minify_js() {
+  let (code, map) = minify_js(
+    "// comment\nfunction add(a, b) {\n\treturn a + b;\n}\nexport { add };\n",
+    None,
+    &OutputFormat::Esm,
+  )
+  .unwrap();
+  assert_eq!(code, "function add(a,b){return a+b}export {add};");
+  assert!(map.is_none());
+
+  // Chunks in other formats aren't parsed as modules.
+  let (code, _) =
+    minify_js("var await = 1;\nmodule.exports = await;\n", None, &OutputFormat::Cjs).unwrap();
+  assert_eq!(code, "var await=1;module.exports=await;");
+
+  assert!(minify_js("const a = ;", None, &OutputFormat::Esm).is_err());
+}
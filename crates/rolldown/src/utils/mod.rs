@@ -8,24 +8,48 @@ use super::module_finalizers::scope_hoisting::{
 pub mod augment_chunk_hash;
 pub mod call_expression_ext;
 pub mod chunk;
+pub mod collapse_warnings;
+pub mod css_modules;
+pub mod css_urls;
+pub mod data_url;
+pub mod define;
+pub mod drop_ast_nodes;
+pub mod dts;
 pub mod extract_hash_pattern;
+pub mod extract_legal_comments;
+pub mod filename_conflict;
+pub mod fs_glob;
+pub mod hash;
 pub mod hash_placeholder;
+pub mod hmr;
+pub mod html;
+pub mod import_chains;
+pub mod import_glob;
+pub mod input_glob;
+pub mod input_sourcemap;
+pub mod lazy_compilation;
 pub mod load_source;
 pub mod make_ast_symbol_and_scope;
+pub mod metafile;
+pub mod minify_css;
+pub mod minify_js;
+pub mod module_query;
 pub mod normalize_options;
 pub mod parse_to_ast;
+pub mod profile;
 pub mod renamer;
+pub mod render_asset_filename;
 pub mod render_chunks;
 pub mod render_normal_module;
 pub mod resolve_id;
+pub mod size_report;
+pub mod sourcemap_debug_id;
+pub mod strip_typescript;
 pub mod transform_source;
 pub mod tweak_ast_for_scanning;
-
-pub(crate) fn is_in_rust_test_mode() -> bool {
-  static TEST_MODE: once_cell::sync::Lazy<bool> =
-    once_cell::sync::Lazy::new(|| std::env::var("ROLLDOWN_TEST").is_ok());
-  *TEST_MODE
-}
+pub mod validate_chunk;
+pub mod worker;
+pub mod write_output;
 
 #[tracing::instrument(level = "trace", skip_all)]
 pub fn finalize_normal_module(
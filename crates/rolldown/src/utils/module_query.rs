@@ -0,0 +1,11 @@
+/// Split the query from a module id. For example, `/src/foo.png?raw` is split into `/src/foo.png` and `Some("raw")`.
+pub fn split_query(id: &str) -> (&str, Option<&str>) {
+  id.split_once('?').map_or((id, None), |(path, query)| (path, Some(query)))
+}
+
+#[test]
+fn test_split_query() {
+  assert_eq!(split_query("/src/foo.png"), ("/src/foo.png", None));
+  assert_eq!(split_query("/src/foo.png?raw"), ("/src/foo.png", Some("raw")));
+  assert_eq!(split_query("/src/foo.png?"), ("/src/foo.png", Some("")));
+}
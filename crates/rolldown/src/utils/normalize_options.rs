@@ -1,4 +1,9 @@
-use rolldown_common::{Loader, NormalizedBundlerOptions, Platform, SourceMapType};
+use std::collections::{BTreeMap, HashMap};
+
+use rolldown_common::{
+  BundlerFlags, CheckLevel, DefineReplacement, Loader, NormalizedBundlerOptions, NormalizedStdin,
+  Platform, STDIN_FILE_NAME,
+};
 use rustc_hash::FxHashMap;
 
 #[allow(clippy::struct_field_names)]
@@ -11,58 +16,202 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
   // Take out resolve options
 
   let raw_resolve = std::mem::take(&mut raw_options.resolve).unwrap_or_default();
+  let flags = normalize_flags(&raw_options);
 
-  let mut loaders = FxHashMap::from(
-    [
-      ("json".to_string(), Loader::Json),
-      ("js".to_string(), Loader::Js),
-      ("mjs".to_string(), Loader::Js),
-      ("cjs".to_string(), Loader::Js),
-      ("txt".to_string(), Loader::Text),
-    ]
-    .into_iter()
-    .collect(),
-  );
+  let mut module_types = default_module_types();
 
-  let user_defined_loaders: FxHashMap<String, Loader> = raw_options
+  // `loaders` is a deprecated alias of `moduleTypes`, which wins for extensions listed in both.
+  let user_defined_module_types: FxHashMap<String, Loader> = raw_options
     .loaders
-    .map(|loaders| {
-      loaders
-        .into_iter()
-        .map(|(ext, value)| {
-          let stripped = ext.strip_prefix('.').map(ToString::to_string).unwrap_or(ext);
+    .into_iter()
+    .chain(raw_options.module_types)
+    .flatten()
+    .map(|(ext, value)| {
+      let stripped = ext.strip_prefix('.').map(ToString::to_string).unwrap_or(ext);
 
-          (stripped, value)
-        })
-        .collect()
+      (stripped, value)
     })
-    .unwrap_or_default();
+    .collect();
+
+  module_types.extend(user_defined_module_types);
 
-  loaders.extend(user_defined_loaders);
+  let css_modules = raw_options.css_modules.unwrap_or_default();
+  let circular_dependency = raw_options.circular_dependency.unwrap_or_default();
+  let eval_warning = raw_options.eval_warning.unwrap_or_default();
+  let checks = raw_options.checks.unwrap_or_default();
+  let watch = raw_options.watch.unwrap_or_default();
+
+  let cwd =
+    raw_options.cwd.unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+  let public_dir = raw_options.public_dir.map(|public_dir| cwd.join(public_dir));
+  let cache_dir = raw_options.cache_dir.map(|cache_dir| cwd.join(cache_dir));
+  let profile_trace_file = raw_options.profile_trace_file.map(|file| cwd.join(file));
+  let platform = raw_options.platform.unwrap_or(Platform::Browser);
 
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
+    stdin: raw_options.stdin.map(|stdin| NormalizedStdin {
+      code: stdin.code,
+      id: cwd
+        .join(stdin.resolve_dir.unwrap_or_default())
+        .join(STDIN_FILE_NAME)
+        .to_string_lossy()
+        .into_owned(),
+      loader: stdin.loader.unwrap_or(Loader::Js),
+    }),
+    cwd,
     external: raw_options.external,
     treeshake: raw_options.treeshake.unwrap_or(true),
-    platform: raw_options.platform.unwrap_or(Platform::Browser),
+    flags,
+    platform,
+    node_protocol: raw_options.node_protocol,
     entry_filenames: raw_options.entry_filenames.unwrap_or_else(|| "[name].js".to_string()).into(),
     chunk_filenames: raw_options
       .chunk_filenames
       .unwrap_or_else(|| "[name]-[hash].js".to_string())
       .into(),
+    asset_filenames: raw_options
+      .asset_filenames
+      .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string())
+      .into(),
+    sourcemap_filenames: raw_options.sourcemap_filenames.map(Into::into),
     banner: raw_options.banner,
     footer: raw_options.footer,
     dir: raw_options.dir.unwrap_or_else(|| "dist".to_string()),
     format: raw_options.format.unwrap_or(crate::OutputFormat::Esm),
-    sourcemap: raw_options.sourcemap.unwrap_or(SourceMapType::Hidden),
+    charset: raw_options.charset.unwrap_or_default(),
+    legal_comments: raw_options.legal_comments.unwrap_or_default(),
+    hash_characters: raw_options.hash_characters.unwrap_or_default(),
+    hash_algorithm: raw_options.hash_algorithm.unwrap_or_default(),
+    hash_salt: raw_options.hash_salt,
+    sourcemap: raw_options.sourcemap,
+    reserved_names: raw_options.reserved_names.unwrap_or_default(),
+    metafile: raw_options.metafile,
+    max_chunk_size: raw_options.max_chunk_size,
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
-    loaders,
+    drop: raw_options.drop.unwrap_or_default(),
+    pure: raw_options.pure.unwrap_or_default(),
+    define: normalize_define(raw_options.define, raw_options.env, platform),
+    require_returns_default: raw_options.require_returns_default.unwrap_or_default(),
+    dynamic_require_targets: raw_options.dynamic_require_targets.unwrap_or_default(),
+    unresolved_require: raw_options.unresolved_require,
+    assets_inline_limit: raw_options.assets_inline_limit.unwrap_or_default(),
+    css_modules_scoped_name: css_modules
+      .scoped_name
+      .unwrap_or_else(|| "[name]_[local]_[hash:5]".to_string()),
+    css_modules_locals_convention: css_modules.locals_convention.unwrap_or_default(),
+    circular_dependency_exclude: circular_dependency.exclude.unwrap_or_default(),
+    eval_warning_exclude: eval_warning.exclude.unwrap_or_default(),
+    checks_top_level_await: checks.top_level_await.unwrap_or(CheckLevel::Error),
+    checks_import_meta: checks.import_meta.unwrap_or(CheckLevel::Error),
+    checks_circular_chunks: checks.circular_chunks.unwrap_or(CheckLevel::Warn),
+    module_types,
+    public_dir,
+    watch_include: watch.include.unwrap_or_default(),
+    watch_exclude: watch.exclude.unwrap_or_default(),
+    watch_build_delay: watch.build_delay.unwrap_or(20),
+    watch_poll_interval: watch.poll.map(|poll| poll.interval.unwrap_or(100)),
+    cache_dir,
+    threads: raw_options.threads,
+    max_parallel_file_ops: raw_options.max_parallel_file_ops,
+    profile_trace_file,
+    log_level: raw_options.log_level.unwrap_or_default(),
+    on_log: raw_options.on_log,
   };
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve }
 }
+
+fn default_module_types() -> FxHashMap<String, Loader> {
+  FxHashMap::from(
+    [
+      ("json".to_string(), Loader::Json),
+      ("js".to_string(), Loader::Js),
+      ("mjs".to_string(), Loader::Js),
+      ("cjs".to_string(), Loader::Js),
+      ("ts".to_string(), Loader::Ts),
+      ("mts".to_string(), Loader::Ts),
+      ("cts".to_string(), Loader::Ts),
+      ("txt".to_string(), Loader::Text),
+      ("css".to_string(), Loader::Css),
+      ("wasm".to_string(), Loader::Wasm),
+      ("html".to_string(), Loader::Html),
+      ("png".to_string(), Loader::Asset),
+      ("jpg".to_string(), Loader::Asset),
+      ("jpeg".to_string(), Loader::Asset),
+      ("gif".to_string(), Loader::Asset),
+      ("webp".to_string(), Loader::Asset),
+      ("avif".to_string(), Loader::Asset),
+      ("ico".to_string(), Loader::Asset),
+      ("svg".to_string(), Loader::Asset),
+      ("woff".to_string(), Loader::Asset),
+      ("woff2".to_string(), Loader::Asset),
+      ("ttf".to_string(), Loader::Asset),
+      ("otf".to_string(), Loader::Asset),
+      ("eot".to_string(), Loader::Asset),
+      ("mp3".to_string(), Loader::Asset),
+      ("mp4".to_string(), Loader::Asset),
+      ("webm".to_string(), Loader::Asset),
+      ("wav".to_string(), Loader::Asset),
+    ]
+    .into_iter()
+    .collect(),
+  )
+}
+
+fn normalize_flags(raw_options: &crate::BundlerOptions) -> BundlerFlags {
+  let has_profile_trace_file = raw_options.profile_trace_file.is_some();
+  let circular_dependency_error =
+    raw_options.circular_dependency.as_ref().and_then(|options| options.error);
+  let watch_skip_write = raw_options.watch.as_ref().and_then(|options| options.skip_write);
+  [
+    (BundlerFlags::STRICT_REQUIRES, raw_options.strict_requires.unwrap_or(true)),
+    (BundlerFlags::CIRCULAR_DEPENDENCY_ERROR, circular_dependency_error.unwrap_or(false)),
+    (BundlerFlags::WATCH_SKIP_WRITE, watch_skip_write.unwrap_or(false)),
+    (BundlerFlags::HMR, raw_options.hmr.unwrap_or(false)),
+    (BundlerFlags::LAZY_COMPILATION, raw_options.lazy_compilation.unwrap_or(false)),
+    (BundlerFlags::PROFILE, raw_options.profile.unwrap_or(false) || has_profile_trace_file),
+    (BundlerFlags::COLLAPSE_WARNINGS, raw_options.collapse_warnings.unwrap_or(true)),
+    (BundlerFlags::CSS_MINIFY, raw_options.css_minify.unwrap_or(false)),
+    (BundlerFlags::MINIFY, raw_options.minify.unwrap_or(false)),
+    (BundlerFlags::STREAM_WRITES, raw_options.stream_writes.unwrap_or(false)),
+    (BundlerFlags::SOURCEMAP_DEBUG_IDS, raw_options.sourcemap_debug_ids.unwrap_or(false)),
+    (BundlerFlags::VALIDATE, raw_options.validate.unwrap_or(false)),
+    (BundlerFlags::ES3_SAFE_IDENTIFIERS, raw_options.es3_safe_identifiers.unwrap_or(false)),
+    (BundlerFlags::DTS, raw_options.dts.unwrap_or(false)),
+    (BundlerFlags::RUNTIME_CHUNK, raw_options.runtime_chunk.unwrap_or(false)),
+    (BundlerFlags::MINIFY_INTERNAL_EXPORTS, raw_options.minify_internal_exports.unwrap_or(false)),
+    (BundlerFlags::REPORT_COMPRESSED_SIZE, raw_options.report_compressed_size.unwrap_or(false)),
+    (BundlerFlags::INLINE_CONST_ENUMS, raw_options.inline_const_enums.unwrap_or(true)),
+  ]
+  .into_iter()
+  .filter(|(_, enabled)| *enabled)
+  .fold(BundlerFlags::empty(), |flags, (flag, _)| flags | flag)
+}
+
+/// Merge `env` into `define`, which takes precedence. Invalid entries are reported by `BundlerOptions::validate`, and
+/// skipped here. Like esbuild, `process.env.NODE_ENV` is defined for browsers, where `process` doesn't exist.
+fn normalize_define(
+  define: Option<HashMap<String, String>>,
+  env: Option<HashMap<String, String>>,
+  platform: Platform,
+) -> Vec<DefineReplacement> {
+  let mut merged = BTreeMap::new();
+  if let Some(mut env) = env {
+    env.entry("NODE_ENV".to_string()).or_insert_with(|| "production".to_string());
+    for (key, value) in env {
+      let value = serde_json::to_string(&value).expect("Strings are always serializable");
+      merged.insert(format!("import.meta.env.{key}"), value.clone());
+      merged.insert(format!("process.env.{key}"), value);
+    }
+  }
+  merged.extend(define.unwrap_or_default());
+  if matches!(platform, Platform::Browser) {
+    merged
+      .entry("process.env.NODE_ENV".to_string())
+      .or_insert_with(|| r#""production""#.to_string());
+  }
+  merged.iter().filter_map(|(key, value)| DefineReplacement::parse(key, value)).collect()
+}
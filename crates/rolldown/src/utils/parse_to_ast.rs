@@ -5,6 +5,8 @@ use rolldown_common::{Loader, NormalizedBundlerOptions};
 use rolldown_loader_utils::{json_to_esm, text_to_esm};
 use rolldown_oxc_utils::{OxcAst, OxcCompiler};
 
+use super::{module_query::split_query, worker::parse_worker_query};
+
 fn pure_esm_js_oxc_source_type() -> OxcSourceType {
   let pure_esm_js = OxcSourceType::default().with_module(true);
   debug_assert!(pure_esm_js.is_javascript());
@@ -15,7 +17,6 @@ fn pure_esm_js_oxc_source_type() -> OxcSourceType {
   pure_esm_js
 }
 
-#[allow(dead_code)]
 enum ParseType {
   Js,
   Jsx,
@@ -23,34 +24,59 @@ enum ParseType {
   Tsx,
 }
 
-pub fn parse_to_ast(
-  options: &NormalizedBundlerOptions,
-  resource_id: &Path,
-  source: impl Into<Arc<str>>,
-) -> anyhow::Result<OxcAst> {
-  let source: Arc<str> = source.into();
+/// Determine the loader based on the query or the file extension of the module id.
+/// - `?raw` loads the file as a string.
+/// - `?url` and `?inline` load the file as an asset.
+/// - `?worker` and its variants load a generated module that constructs the worker.
+///
+/// Files with extensions missing in `moduleTypes` are loaded as JavaScript, like files without extensions.
+pub fn determine_loader(options: &NormalizedBundlerOptions, resource_id: &str) -> Loader {
+  let (path, query) = split_query(resource_id);
+  match query {
+    Some("raw") => return Loader::Text,
+    Some("url" | "inline") => return Loader::Asset,
+    Some(query) if parse_worker_query(query).is_some() => return Loader::Js,
+    _ => {}
+  }
+
+  let ext = Path::new(path).extension().and_then(|ext| ext.to_str()).unwrap_or("js");
+  options.module_types.get(ext).copied().unwrap_or(Loader::Js)
+}
 
-  // 1. Determine the loader based on the file extension.
-  let loader = {
-    let ext = resource_id.extension().and_then(|ext| ext.to_str()).unwrap_or("js");
-    let loader = options.loaders.get(ext);
+pub fn parse_to_ast(loader: Loader, source: impl Into<Arc<str>>) -> anyhow::Result<OxcAst> {
+  let source: Arc<str> = source.into();
 
-    // FIXME: Once we support more loaders, we should return error instead of defaulting to JS.
-    loader.copied().unwrap_or(Loader::Js)
-  };
-  // 2. Transform the source to the type that rolldown supported.
+  // 1. Transform the source to the type that rolldown supported.
   let (source, parsed_type) = match loader {
-    Loader::Js => (source, ParseType::Js),
+    // Binary files, assets and WebAssembly modules are already turned into JavaScript while loading.
+    // Stylesheets are taken out of the source before parsing. CSS modules are turned into JavaScript as well.
+    Loader::Js
+    | Loader::Base64
+    | Loader::DataUrl
+    | Loader::Binary
+    | Loader::Empty
+    | Loader::Asset
+    | Loader::Css
+    | Loader::Wasm
+    | Loader::Html => (source, ParseType::Js),
+    Loader::Jsx => (source, ParseType::Jsx),
+    Loader::Ts => (source, ParseType::Ts),
+    Loader::Tsx => (source, ParseType::Tsx),
     Loader::Json => (json_to_esm(&source)?.into(), ParseType::Js),
     Loader::Text => (text_to_esm(&source)?.into(), ParseType::Js),
   };
 
-  // 3. Parse the source to AST and transform non-js AST to valid JS AST.
+  // 2. Parse the source to AST and transform non-js AST to valid JS AST.
   let valid_js_ast = match parsed_type {
     ParseType::Js => OxcCompiler::parse(Arc::clone(&source), pure_esm_js_oxc_source_type())?,
-    ParseType::Jsx => todo!(),
-    ParseType::Ts => todo!(),
-    ParseType::Tsx => todo!(),
+    // Types are stripped from the AST by the module loader, which parses the stripped code again as JavaScript.
+    ParseType::Ts => {
+      OxcCompiler::parse(Arc::clone(&source), pure_esm_js_oxc_source_type().with_typescript(true))?
+    }
+    // Loading modules as JSX is rejected while validating options.
+    ParseType::Jsx | ParseType::Tsx => {
+      unreachable!("JSX modules are rejected by `moduleTypes` validation")
+    }
   };
 
   Ok(valid_js_ast)
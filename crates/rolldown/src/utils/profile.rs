@@ -0,0 +1,113 @@
+use std::time::Duration;
+
+use rolldown_utils::profiler::ProfileEvent;
+use serde_json::json;
+
+struct SummaryRow<'a> {
+  category: &'static str,
+  name: &'a str,
+  count: usize,
+  total: Duration,
+  max: Duration,
+}
+
+/// A table of the total time of each stage, and the times of plugin hooks sorted from the slowest.
+pub fn format_profile_summary(events: &[ProfileEvent]) -> String {
+  let mut rows: Vec<SummaryRow> = vec![];
+  for event in events {
+    match rows.iter_mut().find(|row| row.category == event.category && row.name == event.name) {
+      Some(row) => {
+        row.count += 1;
+        row.total += event.duration;
+        row.max = row.max.max(event.duration);
+      }
+      None => rows.push(SummaryRow {
+        category: event.category,
+        name: &event.name,
+        count: 1,
+        total: event.duration,
+        max: event.duration,
+      }),
+    }
+  }
+  // Stages end in the order they run, so only hooks are sorted.
+  rows.sort_by(|a, b| {
+    let category_order = |category| ["stage", "module", "hook"].iter().position(|c| *c == category);
+    category_order(a.category).cmp(&category_order(b.category)).then_with(|| {
+      if a.category == "hook" {
+        b.total.cmp(&a.total)
+      } else {
+        std::cmp::Ordering::Equal
+      }
+    })
+  });
+
+  let name_width = rows.iter().map(|row| row.name.len()).chain(["Name".len()]).max().unwrap_or(0);
+  let mut summary = format!(
+    "{:<8}  {:<name_width$}  {:>7}  {:>12}  {:>12}\n",
+    "Kind", "Name", "Count", "Total", "Max"
+  );
+  for row in &rows {
+    summary.push_str(&format!(
+      "{:<8}  {:<name_width$}  {:>7}  {:>12}  {:>12}\n",
+      row.category,
+      row.name,
+      row.count,
+      format_duration(row.total),
+      format_duration(row.max)
+    ));
+  }
+  summary
+}
+
+/// Events in the Chrome trace event format, which `chrome://tracing` and Perfetto show as flame graphs.
+pub fn profile_to_chrome_trace(events: &[ProfileEvent]) -> String {
+  let trace_events = events
+    .iter()
+    .map(|event| {
+      json!({
+        "name": event.name,
+        "cat": event.category,
+        "ph": "X",
+        "ts": u64::try_from(event.start.as_micros()).unwrap_or(u64::MAX),
+        "dur": u64::try_from(event.duration.as_micros()).unwrap_or(u64::MAX),
+        "pid": 1,
+        "tid": event.thread,
+      })
+    })
+    .collect::<Vec<_>>();
+  json!({ "traceEvents": trace_events, "displayTimeUnit": "ms" }).to_string()
+}
+
+fn format_duration(duration: Duration) -> String {
+  format!("{:.2}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[test]
+fn test_format_profile_summary() {
+  let event = |category, name: &str, millis| ProfileEvent {
+    category,
+    name: name.to_string(),
+    start: Duration::ZERO,
+    duration: Duration::from_millis(millis),
+    thread: 0,
+  };
+  let events = [
+    event("hook", "a:load", 1),
+    event("stage", "scan", 10),
+    event("hook", "b:transform", 2),
+    event("hook", "a:load", 3),
+    event("stage", "link", 5),
+  ];
+  assert_eq!(
+    format_profile_summary(&events),
+    [
+      "Kind      Name           Count         Total           Max\n",
+      "stage     scan               1       10.00ms       10.00ms\n",
+      "stage     link               1        5.00ms        5.00ms\n",
+      "hook      a:load             2        4.00ms        3.00ms\n",
+      "hook      b:transform        1        2.00ms        2.00ms\n",
+    ]
+    .concat()
+  );
+}
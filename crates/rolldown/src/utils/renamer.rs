@@ -2,8 +2,11 @@ use std::borrow::Cow;
 
 use oxc::semantic::ScopeId;
 use oxc_syntax::keyword::{GLOBAL_OBJECTS, RESERVED_KEYWORDS};
-use rolldown_common::{NormalModule, NormalModuleId, NormalModuleVec, SymbolRef};
+use rolldown_common::{
+  NormalModule, NormalModuleId, NormalModuleVec, NormalizedBundlerOptions, OutputFormat, SymbolRef,
+};
 use rolldown_rstr::{Rstr, ToRstr};
+use rolldown_utils::ecma_script::{ES3_RESERVED_WORDS, STRICT_MODE_RESERVED_WORDS};
 use rolldown_utils::rayon::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -12,25 +15,44 @@ use crate::types::symbols::Symbols;
 #[derive(Debug)]
 pub struct Renamer<'name> {
   used_canonical_names: FxHashSet<Cow<'name, Rstr>>,
+  /// Names that are only reserved because modules of the chunk reference them as globals.
+  global_names: FxHashSet<Rstr>,
   canonical_names: FxHashMap<SymbolRef, Rstr>,
   symbols: &'name Symbols,
 }
 
 impl<'name> Renamer<'name> {
-  pub fn new(symbols: &'name Symbols, _modules_len: usize) -> Self {
+  pub fn new(
+    symbols: &'name Symbols,
+    _modules_len: usize,
+    options: &'name NormalizedBundlerOptions,
+  ) -> Self {
+    let es3_reserved_words: &[&str] =
+      if options.es3_safe_identifiers() { &ES3_RESERVED_WORDS } else { &[] };
+    // Only ES module output is always strict. Other formats keep non-strict code as it was written.
+    let strict_mode_reserved_words: &[&str] =
+      if matches!(options.format, OutputFormat::Esm) { &STRICT_MODE_RESERVED_WORDS } else { &[] };
     Self {
       canonical_names: FxHashMap::default(),
+      global_names: FxHashSet::default(),
       symbols,
       used_canonical_names: RESERVED_KEYWORDS
         .iter()
         .chain(GLOBAL_OBJECTS.iter())
+        .chain(strict_mode_reserved_words)
+        .chain(es3_reserved_words)
         .map(|s| Cow::Owned(Rstr::new(s)))
+        .chain(options.reserved_names.iter().map(|name| Cow::Owned(Rstr::new(name))))
         .collect(),
     }
   }
 
-  pub fn reserve(&mut self, name: Cow<'name, Rstr>) {
-    self.used_canonical_names.insert(name);
+  /// Reserve the name of a global referenced by modules of the chunk. Top-level symbols can't use it, while nested
+  /// scopes only avoid it if their own module references the global.
+  pub fn reserve_global(&mut self, name: Rstr) {
+    if self.used_canonical_names.insert(Cow::Owned(name.clone())) {
+      self.global_names.insert(name);
+    }
   }
 
   pub fn add_top_level_symbol(&mut self, symbol_ref: SymbolRef) {
@@ -67,6 +89,7 @@ impl<'name> Renamer<'name> {
       module: &'name NormalModule,
       scope_id: ScopeId,
       stack: &mut Vec<Cow<FxHashSet<Cow<'name, Rstr>>>>,
+      global_names: &FxHashSet<Rstr>,
       canonical_names: &mut FxHashMap<SymbolRef, Rstr>,
     ) {
       let bindings = module.scope.get_bindings(scope_id);
@@ -80,9 +103,15 @@ impl<'name> Renamer<'name> {
         let mut candidate_name = Cow::Owned(binding_name.to_rstr());
         match canonical_names.entry(binding_ref) {
           std::collections::hash_map::Entry::Vacant(slot) => loop {
-            let is_shadowed = stack
-              .iter()
-              .any(|used_canonical_names| used_canonical_names.contains(&candidate_name));
+            let is_shadowed = stack.iter().enumerate().any(|(depth, used_canonical_names)| {
+              used_canonical_names.contains(&candidate_name)
+                && !(depth == 0
+                  && global_names.contains(candidate_name.as_ref())
+                  && !module
+                    .scope
+                    .root_unresolved_references()
+                    .contains_key(candidate_name.as_str()))
+            });
 
             if is_shadowed {
               candidate_name = Cow::Owned(format!("{binding_name}${count}").into());
@@ -102,7 +131,7 @@ impl<'name> Renamer<'name> {
       stack.push(Cow::Owned(used_canonical_names_for_this_scope));
       let child_scopes = module.scope.get_child_ids(scope_id).cloned().unwrap_or_default();
       child_scopes.into_iter().for_each(|scope_id| {
-        rename_symbols_of_nested_scopes(module, scope_id, stack, canonical_names);
+        rename_symbols_of_nested_scopes(module, scope_id, stack, global_names, canonical_names);
       });
       stack.pop();
     }
@@ -119,6 +148,7 @@ impl<'name> Renamer<'name> {
             module,
             *child_scope_id,
             &mut stack,
+            &self.global_names,
             &mut canonical_names,
           );
           canonical_names
@@ -0,0 +1,29 @@
+use std::path::Path;
+
+use rolldown_common::{FileNameRenderOptions, NormalizedBundlerOptions};
+
+use super::{extract_hash_pattern::extract_hash_pattern, hash::hash_content};
+
+/// Render the filename of an asset. Unlike chunks, the hash of an asset only depends on its content, so it's
+/// calculated right away instead of using placeholders.
+pub fn render_asset_filename(
+  options: &NormalizedBundlerOptions,
+  path: &Path,
+  content: &[u8],
+) -> String {
+  let name = path.file_stem().map(|stem| stem.to_string_lossy());
+  let ext = path.extension().map(|ext| ext.to_string_lossy());
+
+  let mut filename = options.asset_filenames.render(&FileNameRenderOptions {
+    name: name.as_deref(),
+    ext: ext.as_deref(),
+    ..Default::default()
+  });
+
+  if let Some(hash_pattern) = extract_hash_pattern(&filename) {
+    let mut hash = hash_content(content, options);
+    hash.truncate(hash_pattern.len.unwrap_or(8));
+    filename = filename.replace(&hash_pattern.pattern, &hash);
+  }
+  filename
+}
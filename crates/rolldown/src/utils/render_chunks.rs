@@ -15,20 +15,26 @@ pub async fn render_chunks<'a>(
       .await
       .map(|(code, render_chunk_sourcemap_chain)| ChunkRenderReturn {
         code,
-        map: if render_chunk_sourcemap_chain.is_empty() {
-          chunk.map
-        } else {
-          let mut sourcemap_chain = Vec::with_capacity(render_chunk_sourcemap_chain.len() + 1);
-          if let Some(sourcemap) = chunk.map.as_ref() {
-            sourcemap_chain.push(sourcemap);
+        // Compose sourcemaps of rewrites, such as minification, with the sourcemap of the rendered chunk, so it
+        // still points at the original sources. There's nothing to compose with if sourcemaps are disabled.
+        map: match chunk.map {
+          Some(map) if !render_chunk_sourcemap_chain.is_empty() => {
+            let mut sourcemap_chain = Vec::with_capacity(render_chunk_sourcemap_chain.len() + 1);
+            sourcemap_chain.push(&map);
+            sourcemap_chain.extend(render_chunk_sourcemap_chain.iter());
+            collapse_sourcemaps(sourcemap_chain)
           }
-          sourcemap_chain.extend(render_chunk_sourcemap_chain.iter());
-          collapse_sourcemaps(sourcemap_chain)
+          map => map,
         },
         rendered_chunk: chunk.rendered_chunk,
         augment_chunk_hash: None,
         file_dir: chunk.file_dir,
         preliminary_filename: chunk.preliminary_filename,
+        name: chunk.name,
+        legal_comments: chunk.legal_comments,
+        css: chunk.css,
+        html: chunk.html,
+        hash: chunk.hash,
       })
   }))
   .await
@@ -1,6 +1,6 @@
-use rolldown_common::{NormalModule, RenderedModule};
+use rolldown_common::{NormalModule, RenderedModule, SourceMapType};
 use rolldown_oxc_utils::{OxcAst, OxcCompiler};
-use rolldown_sourcemap::{collapse_sourcemaps, lines_count};
+use rolldown_sourcemap::{collapse_sourcemaps, lines_count, to_line_only_sourcemap};
 
 use crate::{types::module_render_output::ModuleRenderOutput, SharedOptions};
 
@@ -13,31 +13,43 @@ pub fn render_normal_module<'a>(
   if ast.is_body_empty() {
     None
   } else {
-    let enable_sourcemap = !options.sourcemap.is_hidden() && !module.is_virtual();
+    let enable_sourcemap = options.sourcemap.is_some() && !module.is_virtual();
 
     // Because oxc codegen sourcemap is last of sourcemap chain,
     // If here no extra sourcemap need remapping, we using it as final module sourcemap.
     // So here make sure using correct `source_name` and `source_content.
     let render_output = OxcCompiler::print(ast, source_name, enable_sourcemap);
 
+    let sourcemap = if options.sourcemap.is_none() {
+      None
+    } else if module.sourcemap_chain.is_empty() {
+      render_output.source_map
+    } else {
+      let mut sourcemap_chain = module.sourcemap_chain.iter().collect::<Vec<_>>();
+      if let Some(sourcemap) = render_output.source_map.as_ref() {
+        sourcemap_chain.push(sourcemap);
+      }
+      collapse_sourcemaps(sourcemap_chain)
+    };
+    // Reduce the sourcemap of each module, so concatenating them into the chunk sourcemap is cheap as well.
+    let sourcemap = if matches!(options.sourcemap, Some(SourceMapType::Cheap)) {
+      sourcemap.as_ref().map(to_line_only_sourcemap)
+    } else {
+      sourcemap
+    };
+
     Some(ModuleRenderOutput {
       module_path: module.resource_id.clone(),
       module_pretty_path: &module.debug_resource_id,
-      rendered_module: RenderedModule { code: None },
+      rendered_module: RenderedModule {
+        code: None,
+        rendered_length: render_output.source_text.len(),
+      },
+      legal_comments: &module.legal_comments,
       // Search lines count from rendered content has a little overhead, so make it at parallel.
       lines_count: lines_count(&render_output.source_text),
       rendered_content: render_output.source_text,
-      sourcemap: if options.sourcemap.is_hidden() {
-        None
-      } else if module.sourcemap_chain.is_empty() {
-        render_output.source_map
-      } else {
-        let mut sourcemap_chain = module.sourcemap_chain.iter().collect::<Vec<_>>();
-        if let Some(sourcemap) = render_output.source_map.as_ref() {
-          sourcemap_chain.push(sourcemap);
-        }
-        collapse_sourcemaps(sourcemap_chain)
-      },
+      sourcemap,
     })
   }
 }
@@ -0,0 +1,85 @@
+use std::io::Write;
+
+use brotli::enc::BrotliEncoderParams;
+use flate2::{write::GzEncoder, Compression};
+use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
+
+pub struct ChunkSize {
+  pub filename: String,
+  pub raw: usize,
+  pub gzip: usize,
+  pub brotli: usize,
+}
+
+impl ChunkSize {
+  /// Sizes of a chunk before and after compression by gzip and brotli at their default levels, which are what
+  /// servers usually serve. Compressing is the expensive part, so it's skipped without `compress`, leaving `gzip` and
+  /// `brotli` as zero.
+  pub fn new(filename: &str, content: &[u8], compress: bool) -> Self {
+    Self {
+      filename: filename.to_string(),
+      raw: content.len(),
+      gzip: if compress { gzip_size(content) } else { 0 },
+      brotli: if compress { brotli_size(content) } else { 0 },
+    }
+  }
+}
+
+/// Chunks are compressed in parallel, so it should run in the thread pool of the bundler.
+pub fn compute_chunk_sizes(chunks: &[(&str, &[u8])], compress: bool) -> Vec<ChunkSize> {
+  chunks.par_iter().map(|(filename, content)| ChunkSize::new(filename, content, compress)).collect()
+}
+
+fn gzip_size(content: &[u8]) -> usize {
+  let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+  encoder.write_all(content).expect("Writing to a `Vec` should not fail");
+  encoder.finish().expect("Writing to a `Vec` should not fail").len()
+}
+
+fn brotli_size(mut content: &[u8]) -> usize {
+  let mut compressed = vec![];
+  brotli::BrotliCompress(&mut content, &mut compressed, &BrotliEncoderParams::default())
+    .expect("Writing to a `Vec` should not fail");
+  compressed.len()
+}
+
+/// Kilobytes with two decimals, rounded down.
+fn format_size(bytes: usize) -> String {
+  format!("{}.{:02} kB", bytes / 1000, bytes % 1000 / 10)
+}
+
+/// A table of the sizes of chunks, in the order of the chunks.
+pub fn format_size_report(sizes: &[ChunkSize]) -> String {
+  let name_width =
+    sizes.iter().map(|size| size.filename.len()).chain(["Chunk".len()]).max().unwrap_or(0);
+  let mut report =
+    format!("{:<name_width$}  {:>12}  {:>12}  {:>12}\n", "Chunk", "Size", "Gzip", "Brotli");
+  for size in sizes {
+    report.push_str(&format!(
+      "{:<name_width$}  {:>12}  {:>12}  {:>12}\n",
+      size.filename,
+      format_size(size.raw),
+      format_size(size.gzip),
+      format_size(size.brotli)
+    ));
+  }
+  report
+}
+
+#[test]
+fn test_chunk_sizes() {
+  let code = "export const value = 'value';\n".repeat(100);
+  let chunks = [("main.js", code.as_bytes())];
+  let sizes = compute_chunk_sizes(&chunks, true);
+  assert_eq!(sizes[0].raw, 3000);
+  assert!(sizes[0].gzip < sizes[0].raw);
+  assert!(sizes[0].brotli < sizes[0].raw);
+  let sizes = compute_chunk_sizes(&chunks, false);
+  assert_eq!((sizes[0].raw, sizes[0].gzip, sizes[0].brotli), (3000, 0, 0));
+
+  let sizes = [ChunkSize { filename: "main.js".to_string(), raw: 3000, gzip: 950, brotli: 1234 }];
+  assert_eq!(
+    format_size_report(&sizes),
+    "Chunk            Size          Gzip        Brotli\nmain.js       3.00 kB       0.95 kB       1.23 kB\n"
+  );
+}
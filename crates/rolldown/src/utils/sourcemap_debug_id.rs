@@ -0,0 +1,36 @@
+use rolldown_common::HashCharacters;
+use xxhash_rust::xxh3::xxh3_128;
+
+use super::hash::encode_hash;
+
+/// Generate a debug ID of the chunk, which is a UUID derived from the code, so it's stable across builds.
+/// See https://github.com/tc39/source-map/blob/main/proposals/debug-id.md
+pub fn generate_debug_id(code: &str) -> String {
+  let mut bytes = xxh3_128(code.as_bytes()).to_be_bytes();
+  // Mark it as a version 4 UUID of the RFC 4122 variant
+  bytes[6] = (bytes[6] & 0x0f) | 0x40;
+  bytes[8] = (bytes[8] & 0x3f) | 0x80;
+  let hex = encode_hash(bytes, HashCharacters::Hex);
+  format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+/// Add the `debugId` field to the JSON of the sourcemap.
+pub fn inject_debug_id(map_json: &str, debug_id: &str) -> String {
+  match map_json.strip_prefix('{') {
+    Some(rest) if rest.trim_start().starts_with('}') => {
+      format!("{{\"debugId\":\"{debug_id}\"{rest}")
+    }
+    Some(rest) => format!("{{\"debugId\":\"{debug_id}\",{rest}"),
+    None => map_json.to_string(),
+  }
+}
+
+#[test]
+fn test_debug_id() {
+  let debug_id = generate_debug_id("console.log(1);");
+  assert_eq!(debug_id.len(), 36);
+  assert_eq!(&debug_id[14..15], "4");
+  assert_eq!(generate_debug_id("console.log(1);"), debug_id);
+  assert_ne!(generate_debug_id("console.log(2);"), debug_id);
+  assert_eq!(inject_debug_id("{\"version\":3}", "id"), "{\"debugId\":\"id\",\"version\":3}");
+}
@@ -0,0 +1,447 @@
+use std::{path::Path, sync::Arc};
+
+use oxc::{
+  allocator::{self, Allocator},
+  ast::{
+    ast::{
+      self, Expression, MethodDefinitionKind, ModifierKind, Statement, TSEnumDeclaration,
+      TSEnumMemberName,
+    },
+    visit::walk_mut,
+    Trivias, VisitMut,
+  },
+  codegen::CodegenReturn,
+  span::{Atom, SourceType, Span, SPAN},
+  syntax::operator::{AssignmentOperator, BinaryOperator, UnaryOperator},
+  transformer::{TransformOptions, Transformer},
+};
+use rolldown_common::{ConstEnumMembers, ConstEnumValue};
+use rolldown_error::BuildError;
+use rolldown_oxc_utils::{AstSnippet, IntoIn, OxcAst, OxcCompiler, TakeIn, WithFieldsMut};
+use rolldown_rstr::{Rstr, ToRstr};
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::ecma_script::is_validate_identifier_name;
+use rustc_hash::FxHashMap;
+
+pub struct StrippedTypeScript {
+  pub code: String,
+  pub map: Option<SourceMap>,
+  /// Members of `const enum`s declared at the top level whose values are known, by the names of the enums.
+  pub const_enums: FxHashMap<Rstr, ConstEnumMembers>,
+}
+
+/// Compile a TypeScript module into JavaScript with oxc's TypeScript transform, so the module could be bundled as
+/// JavaScript. `import x = require()` and `export =` need the module to be compiled to CommonJS and are reported as
+/// errors instead.
+///
+/// Values of `const enum` members are collected for inlining. `const enum`s whose members are all known are compiled
+/// into object literals beforehand, so they could be tree shaken once their members are inlined.
+pub fn strip_typescript(
+  ast: &mut OxcAst,
+  filename: &str,
+  enable_source_map: bool,
+) -> Result<StrippedTypeScript, Vec<BuildError>> {
+  let const_enums = ast.with_mut(|WithFieldsMut { source, allocator, program, .. }| {
+    let errors = find_unsupported_syntax(&program.body, filename, source);
+    if !errors.is_empty() {
+      return Err(errors);
+    }
+
+    let const_enums = compile_const_enums(allocator, &mut program.body);
+    ParameterPropertyLowering { snippet: AstSnippet::new(allocator) }.visit_program(program);
+
+    // Comments are only read for JSX pragmas, which TypeScript modules don't have.
+    let trivias = allocator.alloc(Trivias::default());
+    let source_type = SourceType::default().with_module(true).with_typescript(true);
+    Transformer::new(
+      allocator,
+      Path::new(filename),
+      source_type,
+      source,
+      trivias,
+      TransformOptions::default(),
+    )
+    .build(program)
+    .map_err(|errors| {
+      errors
+        .iter()
+        .map(|error| {
+          let span = error.labels().and_then(|mut labels| labels.next()).map_or(SPAN, |label| {
+            #[allow(clippy::cast_possible_truncation)]
+            Span::new(label.offset() as u32, (label.offset() + label.len()) as u32)
+          });
+          // The transform only reports modules declared inside namespaces, once the syntax above is rejected.
+          BuildError::unsupported_typescript(
+            filename.to_string(),
+            Arc::clone(source),
+            span,
+            "ambient modules inside namespaces",
+          )
+        })
+        .collect::<Vec<_>>()
+    })?;
+    Ok(const_enums)
+  })?;
+
+  let CodegenReturn { source_text, source_map } =
+    OxcCompiler::print(ast, filename, enable_source_map);
+  Ok(StrippedTypeScript { code: source_text, map: source_map, const_enums })
+}
+
+/// `import x = require()` and `export =` only work if the module is compiled to CommonJS.
+fn find_unsupported_syntax(
+  stmts: &[Statement],
+  filename: &str,
+  source: &Arc<str>,
+) -> Vec<BuildError> {
+  stmts
+    .iter()
+    .filter_map(|stmt| match stmt {
+      Statement::TSImportEqualsDeclaration(decl)
+        if decl.import_kind.is_value()
+          && matches!(
+            decl.module_reference,
+            ast::TSModuleReference::ExternalModuleReference(_)
+          ) =>
+      {
+        Some((decl.span, "`import =` declarations"))
+      }
+      Statement::TSExportAssignment(decl) => Some((decl.span, "`export =` assignments")),
+      _ => None,
+    })
+    .map(|(span, syntax)| {
+      BuildError::unsupported_typescript(filename.to_string(), Arc::clone(source), span, syntax)
+    })
+    .collect()
+}
+
+/// `constructor(private foo)` assigns the parameter to `this.foo`. The TypeScript transform only does so for `public`
+/// parameters, so the assignments are added beforehand.
+struct ParameterPropertyLowering<'ast> {
+  snippet: AstSnippet<'ast>,
+}
+
+impl<'ast> VisitMut<'ast> for ParameterPropertyLowering<'ast> {
+  fn visit_method_definition(&mut self, def: &mut ast::MethodDefinition<'ast>) {
+    if matches!(def.kind, MethodDefinitionKind::Constructor) {
+      self.assign_parameter_properties(&mut def.value);
+    }
+    walk_mut::walk_method_definition_mut(self, def);
+  }
+}
+
+impl<'ast> ParameterPropertyLowering<'ast> {
+  fn assign_parameter_properties(&self, func: &mut ast::Function<'ast>) {
+    let alloc = self.snippet.alloc;
+    let mut names = vec![];
+    for param in func.params.items.iter_mut() {
+      if param.accessibility.is_none() && !param.readonly && !param.r#override {
+        continue;
+      }
+      param.accessibility = None;
+      param.readonly = false;
+      param.r#override = false;
+      let name = match &param.pattern.kind {
+        ast::BindingPatternKind::BindingIdentifier(id) => id.name.clone(),
+        ast::BindingPatternKind::AssignmentPattern(pattern) => match &pattern.left.kind {
+          ast::BindingPatternKind::BindingIdentifier(id) => id.name.clone(),
+          _ => continue,
+        },
+        _ => continue,
+      };
+      names.push(name);
+    }
+    let Some(body) = &mut func.body else {
+      return;
+    };
+    if names.is_empty() {
+      return;
+    }
+
+    // Fields can only be assigned after `super()` in derived classes.
+    let insert_at = body
+      .statements
+      .iter()
+      .position(|stmt| {
+        matches!(stmt, Statement::ExpressionStatement(stmt)
+          if matches!(&stmt.expression, Expression::CallExpression(call) if matches!(call.callee, Expression::Super(_))))
+      })
+      .map_or(0, |index| index + 1);
+    for (index, name) in names.into_iter().enumerate() {
+      let left = ast::AssignmentTarget::StaticMemberExpression(
+        ast::StaticMemberExpression {
+          object: Expression::ThisExpression(TakeIn::dummy(alloc)),
+          property: self.snippet.id_name(name.as_str(), SPAN),
+          ..TakeIn::dummy(alloc)
+        }
+        .into_in(alloc),
+      );
+      let expression = Expression::AssignmentExpression(
+        ast::AssignmentExpression {
+          operator: AssignmentOperator::Assign,
+          left,
+          right: self.snippet.id_ref_expr(name.as_str(), SPAN),
+          ..TakeIn::dummy(alloc)
+        }
+        .into_in(alloc),
+      );
+      let stmt = Statement::ExpressionStatement(
+        ast::ExpressionStatement { expression, ..TakeIn::dummy(alloc) }.into_in(alloc),
+      );
+      body.statements.insert(insert_at + index, stmt);
+    }
+  }
+}
+
+/// The value of a member of a `const enum` as an expression, such as `1`, `-1` or `"foo"`.
+pub fn const_enum_value_expr<'ast>(
+  snippet: &AstSnippet<'ast>,
+  value: &ConstEnumValue,
+) -> Expression<'ast> {
+  match value {
+    ConstEnumValue::Number(value) if value.is_nan() => snippet.id_ref_expr("NaN", SPAN),
+    ConstEnumValue::Number(value) if value.is_sign_negative() => ast::Expression::UnaryExpression(
+      ast::UnaryExpression {
+        operator: UnaryOperator::UnaryNegation,
+        argument: const_enum_value_expr(snippet, &ConstEnumValue::Number(-value)),
+        ..TakeIn::dummy(snippet.alloc)
+      }
+      .into_in(snippet.alloc),
+    ),
+    ConstEnumValue::Number(value) if value.is_infinite() => snippet.id_ref_expr("Infinity", SPAN),
+    ConstEnumValue::Number(value) => snippet.number_expr(*value),
+    ConstEnumValue::String(value) => {
+      ast::Expression::StringLiteral(snippet.string_literal(value, SPAN).into_in(snippet.alloc))
+    }
+  }
+}
+
+/// Evaluate members of the enums declared at the top level, and compile `const enum`s whose members are all known into
+/// object literals like TypeScript does:
+///
+/// ```js
+/// var Direction = { Up: 0, 0: "Up", Down: 1, 1: "Down" };
+/// ```
+///
+/// Other enums are left to the TypeScript transform.
+fn compile_const_enums<'ast>(
+  alloc: &'ast Allocator,
+  stmts: &mut allocator::Vec<'ast, Statement<'ast>>,
+) -> FxHashMap<Rstr, ConstEnumMembers> {
+  let snippet = AstSnippet::new(alloc);
+  let mut enums: FxHashMap<Atom<'ast>, ConstEnumMembers> = FxHashMap::default();
+  let mut const_enums = FxHashMap::default();
+  for stmt in stmts.iter_mut() {
+    let (decl, exported) = match stmt {
+      Statement::TSEnumDeclaration(decl) => (decl, false),
+      Statement::ExportNamedDeclaration(export_decl) => match &mut export_decl.declaration {
+        Some(ast::Declaration::TSEnumDeclaration(decl)) => (decl, true),
+        _ => continue,
+      },
+      _ => continue,
+    };
+    if decl.modifiers.is_contains_declare() {
+      continue;
+    }
+
+    let name = decl.id.name.clone();
+    let is_merged = enums.contains_key(&name);
+    let (members, all_known) = evaluate_enum(decl, enums.get(&name).cloned(), &enums);
+    enums.insert(name.clone(), members.clone());
+    if !decl.modifiers.contains(ModifierKind::Const) {
+      continue;
+    }
+    const_enums.insert(name.to_rstr(), members.clone());
+    if !all_known || is_merged {
+      continue;
+    }
+
+    let mut properties = allocator::Vec::new_in(alloc);
+    for member in &decl.members {
+      let key = enum_member_name(&member.id).expect("Members of the enum are all known");
+      let value = &members[&key];
+      properties.push(object_property(&snippet, &key, const_enum_value_expr(&snippet, value)));
+      if matches!(value, ConstEnumValue::Number(_)) {
+        let reverse = Expression::StringLiteral(snippet.string_literal(&key, SPAN).into_in(alloc));
+        properties.push(object_property(&snippet, &value.to_js(), reverse));
+      }
+    }
+    let object = Expression::ObjectExpression(
+      ast::ObjectExpression { properties, ..TakeIn::dummy(alloc) }.into_in(alloc),
+    );
+    let var_decl = snippet.decl_var_decl(&name, object);
+    let span = decl.span;
+    *stmt = if exported {
+      Statement::ExportNamedDeclaration(
+        ast::ExportNamedDeclaration {
+          span,
+          declaration: Some(var_decl),
+          specifiers: allocator::Vec::new_in(alloc),
+          source: None,
+          export_kind: ast::ImportOrExportKind::Value,
+          with_clause: None,
+        }
+        .into_in(alloc),
+      )
+    } else {
+      Statement::from(var_decl)
+    };
+  }
+  const_enums
+}
+
+fn enum_member_name(name: &TSEnumMemberName) -> Option<Rstr> {
+  match name {
+    TSEnumMemberName::StaticIdentifier(id) => Some(id.name.to_rstr()),
+    TSEnumMemberName::StaticStringLiteral(literal) => Some(literal.value.to_rstr()),
+    _ => None,
+  }
+}
+
+/// Values of the members of the enum that are constant expressions, and whether all of them are. Members of a previous
+/// declaration of the same enum are passed as `known`.
+fn evaluate_enum<'ast>(
+  decl: &TSEnumDeclaration<'ast>,
+  known: Option<ConstEnumMembers>,
+  enums: &FxHashMap<Atom<'ast>, ConstEnumMembers>,
+) -> (ConstEnumMembers, bool) {
+  let mut known = known.unwrap_or_default();
+  let mut all_known = true;
+  let mut previous: Option<Option<ConstEnumValue>> = None;
+  for member in &decl.members {
+    let Some(key) = enum_member_name(&member.id) else {
+      all_known = false;
+      previous = Some(None);
+      continue;
+    };
+    let value = match &member.initializer {
+      Some(initializer) => evaluate(initializer, &decl.id.name, &known, enums),
+      None => match &previous {
+        None => Some(ConstEnumValue::Number(0.0)),
+        Some(Some(ConstEnumValue::Number(value))) => Some(ConstEnumValue::Number(value + 1.0)),
+        Some(_) => None,
+      },
+    };
+    match &value {
+      Some(value) => {
+        known.insert(key, value.clone());
+      }
+      None => all_known = false,
+    }
+    previous = Some(value);
+  }
+  (known, all_known)
+}
+
+fn object_property<'ast>(
+  snippet: &AstSnippet<'ast>,
+  key: &str,
+  value: Expression<'ast>,
+) -> ast::ObjectPropertyKind<'ast> {
+  let key = if is_validate_identifier_name(key) {
+    ast::PropertyKey::StaticIdentifier(snippet.id_name(key, SPAN).into_in(snippet.alloc))
+  } else {
+    ast::PropertyKey::StringLiteral(snippet.string_literal(key, SPAN).into_in(snippet.alloc))
+  };
+  ast::ObjectPropertyKind::ObjectProperty(
+    ast::ObjectProperty { key, value, ..TakeIn::dummy(snippet.alloc) }.into_in(snippet.alloc),
+  )
+}
+
+/// Evaluate the initializer of an enum member, if it's a constant expression.
+fn evaluate<'ast>(
+  expr: &Expression<'ast>,
+  enum_name: &Atom<'ast>,
+  members: &ConstEnumMembers,
+  enums: &FxHashMap<Atom<'ast>, ConstEnumMembers>,
+) -> Option<ConstEnumValue> {
+  let member_of = |object: &Expression<'ast>, key: &str| {
+    let Expression::Identifier(object) = object else {
+      return None;
+    };
+    let members = if object.name == *enum_name { members } else { enums.get(&object.name)? };
+    members.get(&Rstr::new(key)).cloned()
+  };
+  match expr {
+    Expression::NumericLiteral(literal) => Some(ConstEnumValue::Number(literal.value)),
+    Expression::StringLiteral(literal) => Some(ConstEnumValue::String(literal.value.to_rstr())),
+    Expression::TemplateLiteral(literal) if literal.expressions.is_empty() => {
+      let cooked = literal.quasis.first()?.value.cooked.as_ref()?;
+      Some(ConstEnumValue::String(cooked.to_rstr()))
+    }
+    Expression::ParenthesizedExpression(expr) => {
+      evaluate(&expr.expression, enum_name, members, enums)
+    }
+    Expression::Identifier(id) => match id.name.as_str() {
+      "Infinity" => Some(ConstEnumValue::Number(f64::INFINITY)),
+      "NaN" => Some(ConstEnumValue::Number(f64::NAN)),
+      name => members.get(&Rstr::new(name)).cloned(),
+    },
+    Expression::StaticMemberExpression(expr) => member_of(&expr.object, &expr.property.name),
+    Expression::ComputedMemberExpression(expr) => match &expr.expression {
+      Expression::StringLiteral(key) => member_of(&expr.object, &key.value),
+      _ => None,
+    },
+    Expression::UnaryExpression(expr) => {
+      let ConstEnumValue::Number(value) = evaluate(&expr.argument, enum_name, members, enums)?
+      else {
+        return None;
+      };
+      let value = match expr.operator {
+        UnaryOperator::UnaryPlus => value,
+        UnaryOperator::UnaryNegation => -value,
+        UnaryOperator::BitwiseNot => f64::from(!to_int32(value)),
+        _ => return None,
+      };
+      Some(ConstEnumValue::Number(value))
+    }
+    Expression::BinaryExpression(expr) => {
+      let left = evaluate(&expr.left, enum_name, members, enums)?;
+      let right = evaluate(&expr.right, enum_name, members, enums)?;
+      match (left, right) {
+        (ConstEnumValue::Number(left), ConstEnumValue::Number(right)) => {
+          evaluate_numeric_binary(expr.operator, left, right).map(ConstEnumValue::Number)
+        }
+        (left, right) if matches!(expr.operator, BinaryOperator::Addition) => {
+          let to_string = |value: ConstEnumValue| match value {
+            ConstEnumValue::String(value) => value.to_string(),
+            ConstEnumValue::Number(_) => value.to_js(),
+          };
+          Some(ConstEnumValue::String(format!("{}{}", to_string(left), to_string(right)).into()))
+        }
+        _ => None,
+      }
+    }
+    _ => None,
+  }
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss, clippy::cast_possible_wrap)]
+fn to_int32(value: f64) -> i32 {
+  if value.is_finite() {
+    (value.trunc() % 4_294_967_296.0) as i64 as u32 as i32
+  } else {
+    0
+  }
+}
+
+#[allow(clippy::cast_sign_loss)]
+fn evaluate_numeric_binary(operator: BinaryOperator, left: f64, right: f64) -> Option<f64> {
+  let shift = || to_int32(right) as u32 & 31;
+  let value = match operator {
+    BinaryOperator::Addition => left + right,
+    BinaryOperator::Subtraction => left - right,
+    BinaryOperator::Multiplication => left * right,
+    BinaryOperator::Division => left / right,
+    BinaryOperator::Remainder => left % right,
+    BinaryOperator::Exponential => left.powf(right),
+    BinaryOperator::ShiftLeft => f64::from(to_int32(left).wrapping_shl(shift())),
+    BinaryOperator::ShiftRight => f64::from(to_int32(left) >> shift()),
+    BinaryOperator::ShiftRightZeroFill => f64::from((to_int32(left) as u32) >> shift()),
+    BinaryOperator::BitwiseOR => f64::from(to_int32(left) | to_int32(right)),
+    BinaryOperator::BitwiseXOR => f64::from(to_int32(left) ^ to_int32(right)),
+    BinaryOperator::BitwiseAnd => f64::from(to_int32(left) & to_int32(right)),
+    _ => return None,
+  };
+  Some(value)
+}
@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use oxc::{
+  allocator::Allocator,
+  parser::Parser,
+  span::{SourceType, Span},
+};
+use rolldown_common::OutputFormat;
+use rolldown_error::BuildError;
+
+/// Parse the rendered code of a chunk for `output.validate`, and report the first syntax error. `module_ids` are the
+/// stable ids of modules in the chunk, used to tell which module the invalid code is rendered from.
+pub fn validate_chunk(
+  filename: &str,
+  code: &str,
+  format: &OutputFormat,
+  module_ids: &[String],
+) -> Option<BuildError> {
+  let allocator = Allocator::default();
+  let source_type = SourceType::default().with_module(matches!(format, OutputFormat::Esm));
+  let ret = Parser::new(&allocator, code, source_type).parse();
+  let error = ret.errors.first()?;
+  let span =
+    error.labels.as_ref().and_then(|labels| labels.first()).map_or(Span::new(0, 0), |label| {
+      #[allow(clippy::cast_possible_truncation)]
+      Span::new(label.offset() as u32, (label.offset() + label.len()) as u32)
+    });
+  Some(BuildError::invalid_output(
+    filename.to_string(),
+    Arc::from(code),
+    span,
+    error.to_string(),
+    module_at_offset(code, span.start as usize, module_ids),
+  ))
+}
+
+/// Modules are rendered after a `// path/to/module.js` comment, so the last of these comments before `offset` tells
+/// the module. Plugins transforming the chunk may have removed them.
+fn module_at_offset(code: &str, offset: usize, module_ids: &[String]) -> Option<String> {
+  code[..offset.min(code.len())]
+    .lines()
+    .rev()
+    .filter_map(|line| line.strip_prefix("// "))
+    .find(|comment| module_ids.iter().any(|id| id == comment))
+    .map(ToString::to_string)
+}
+
+#[test]
+fn test_module_at_offset() {
+  let code = "// a.js\nconst a = 1;\n// not a module\n// b.js\nconst b = ;\n";
+  let module_ids = ["a.js".to_string(), "b.js".to_string()];
+  assert_eq!(
+    module_at_offset(code, code.find("= ;").unwrap(), &module_ids),
+    Some("b.js".to_string())
+  );
+  assert_eq!(
+    module_at_offset(code, code.find("= 1").unwrap(), &module_ids),
+    Some("a.js".to_string())
+  );
+  assert_eq!(module_at_offset(code, 0, &module_ids), None);
+}
@@ -0,0 +1,206 @@
+use rolldown_sourcemap::shift_columns;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use super::chunk::render_chunk::ChunkRenderReturn;
+
+/// Options of importing a module with `?worker`, `?sharedworker`, `?worker&inline` or `?sharedworker&inline`.
+#[derive(Debug, PartialEq, Eq)]
+pub struct WorkerQuery {
+  /// `Worker` or `SharedWorker`
+  pub constructor: &'static str,
+  /// Embed the code of the worker as a blob URL instead of referencing the emitted chunk.
+  pub inline: bool,
+}
+
+pub fn parse_worker_query(query: &str) -> Option<WorkerQuery> {
+  let (kind, inline) = match query.strip_suffix("&inline") {
+    Some(kind) => (kind, true),
+    None => (query, false),
+  };
+  let constructor = match kind {
+    "worker" => "Worker",
+    "sharedworker" => "SharedWorker",
+    _ => return None,
+  };
+  Some(WorkerQuery { constructor, inline })
+}
+
+/// The module imported with `?worker` exports a function constructing the worker. The worker is bundled as a
+/// separate entry, since `new Worker(new URL(...))` in it is handled like other workers.
+pub fn worker_wrapper_source(file_name: &str, query: &WorkerQuery) -> String {
+  let constructor = query.constructor;
+  let url = quote_str(&format!("./{file_name}"));
+  format!(
+    "export default function WorkerWrapper(options) {{\n  return new {constructor}(new URL({url}, import.meta.url), {{ type: \"module\", ...options }});\n}}\n"
+  )
+}
+
+const INLINE_WORKER_PLACEHOLDER_PREFIX: &str = "rolldown-inline-worker:";
+
+/// A string literal standing for the code of the worker chunk, which is embedded by `embed_inline_workers`.
+pub fn inline_worker_placeholder(preliminary_filename: &str) -> String {
+  format!("{INLINE_WORKER_PLACEHOLDER_PREFIX}{preliminary_filename}")
+}
+
+/// Replace placeholders of inline workers in rendered chunks with blob URLs of the code of the worker chunks. It runs
+/// before hashes are computed, so the hash of a chunk changes with the code of the workers it embeds. Workers embedding
+/// other workers are embedded after their own placeholders are replaced. Workers embedded in this way can't import
+/// other chunks.
+pub fn embed_inline_workers(chunks: &mut [ChunkRenderReturn]) {
+  if !chunks.iter().any(|chunk| chunk.code.contains(INLINE_WORKER_PLACEHOLDER_PREFIX)) {
+    return;
+  }
+  let index_by_filename = chunks
+    .iter()
+    .enumerate()
+    .map(|(index, chunk)| (chunk.preliminary_filename.to_string(), index))
+    .collect::<FxHashMap<_, _>>();
+  let mut embedded = vec![false; chunks.len()];
+  for index in 0..chunks.len() {
+    embed_into(index, chunks, &index_by_filename, &mut embedded, &mut FxHashSet::default());
+  }
+}
+
+fn embed_into(
+  index: usize,
+  chunks: &mut [ChunkRenderReturn],
+  index_by_filename: &FxHashMap<String, usize>,
+  embedded: &mut [bool],
+  visiting: &mut FxHashSet<usize>,
+) {
+  if embedded[index] || !visiting.insert(index) {
+    return;
+  }
+  for filename in find_inline_worker_placeholders(&chunks[index].code) {
+    if let Some(&worker_index) = index_by_filename.get(filename.as_str()) {
+      embed_into(worker_index, chunks, index_by_filename, embedded, visiting);
+    }
+  }
+  visiting.remove(&index);
+  embedded[index] = true;
+
+  let code = std::mem::take(&mut chunks[index].code);
+  let (code, shifts) = replace_inline_worker_placeholders(&code, |filename| {
+    // A worker embedding itself, directly or not, is left as a placeholder.
+    index_by_filename
+      .get(filename)
+      .filter(|worker_index| embedded[**worker_index] && **worker_index != index)
+      .map(|worker_index| chunks[*worker_index].code.as_str())
+  });
+  let chunk = &mut chunks[index];
+  chunk.code = code;
+  if let Some(map) = &chunk.map {
+    if !shifts.is_empty() {
+      chunk.map = Some(shift_columns(map, &shifts));
+    }
+  }
+}
+
+fn find_inline_worker_placeholders(code: &str) -> Vec<String> {
+  let mut filenames = vec![];
+  replace_inline_worker_placeholders(code, |filename| {
+    filenames.push(filename.to_string());
+    None
+  });
+  filenames
+}
+
+/// Replace placeholders of inline workers in the code with blob URLs of the code of the worker chunks. Returns the new
+/// code, and how columns after each replaced placeholder moved, as `(line, column, delta)` in UTF-16 code units of the
+/// old code, which is how sourcemaps count columns.
+pub fn replace_inline_worker_placeholders<'a>(
+  code: &str,
+  mut code_of: impl FnMut(&str) -> Option<&'a str>,
+) -> (String, Vec<(u32, u32, i64)>) {
+  let mut output = String::with_capacity(code.len());
+  let mut shifts = vec![];
+  let (mut line, mut column) = (0u32, 0u32);
+  let mut advance = |text: &str| {
+    for c in text.chars() {
+      if c == '\n' {
+        line += 1;
+        column = 0;
+      } else {
+        column += u32::try_from(c.len_utf16()).unwrap_or(1);
+      }
+    }
+    (line, column)
+  };
+  let mut rest = code;
+  while let Some(start) = rest.find(INLINE_WORKER_PLACEHOLDER_PREFIX) {
+    let quote = start.checked_sub(1).and_then(|idx| rest[idx..].chars().next());
+    let Some(quote @ ('"' | '\'')) = quote else {
+      let (skipped, remaining) = rest.split_at(start + INLINE_WORKER_PLACEHOLDER_PREFIX.len());
+      output.push_str(skipped);
+      advance(skipped);
+      rest = remaining;
+      continue;
+    };
+    let filename_start = start + INLINE_WORKER_PLACEHOLDER_PREFIX.len();
+    let Some(len) = rest[filename_start..].find(quote) else {
+      break;
+    };
+    let filename = &rest[filename_start..filename_start + len];
+    let literal = &rest[start - 1..=filename_start + len];
+    output.push_str(&rest[..start - 1]);
+    advance(&rest[..start - 1]);
+    let (line, column) = advance(literal);
+    match code_of(filename) {
+      Some(worker_code) => {
+        let replacement = format!(
+          "URL.createObjectURL(new Blob([{}], {{ type: \"text/javascript\" }}))",
+          quote_str(worker_code)
+        );
+        let utf16_len = |text: &str| i64::try_from(text.encode_utf16().count()).unwrap_or_default();
+        shifts.push((line, column, utf16_len(&replacement) - utf16_len(literal)));
+        output.push_str(&replacement);
+      }
+      None => output.push_str(literal),
+    }
+    rest = &rest[filename_start + len + 1..];
+  }
+  output.push_str(rest);
+  (output, shifts)
+}
+
+fn quote_str(value: &str) -> String {
+  let mut quoted = String::with_capacity(value.len() + 2);
+  quoted.push('"');
+  for c in value.chars() {
+    match c {
+      '\\' => quoted.push_str("\\\\"),
+      '"' => quoted.push_str("\\\""),
+      '\n' => quoted.push_str("\\n"),
+      '\r' => quoted.push_str("\\r"),
+      '\u{2028}' => quoted.push_str("\\u2028"),
+      '\u{2029}' => quoted.push_str("\\u2029"),
+      _ => quoted.push(c),
+    }
+  }
+  quoted.push('"');
+  quoted
+}
+
+#[test]
+fn test_parse_worker_query() {
+  assert_eq!(
+    parse_worker_query("worker"),
+    Some(WorkerQuery { constructor: "Worker", inline: false })
+  );
+  assert_eq!(
+    parse_worker_query("sharedworker&inline"),
+    Some(WorkerQuery { constructor: "SharedWorker", inline: true })
+  );
+  assert_eq!(parse_worker_query("raw"), None);
+}
+
+#[test]
+fn test_replace_inline_worker_placeholders() {
+  let code = "new Worker(\"rolldown-inline-worker:worker-abc.js\");\nnew Worker('rolldown-inline-worker:missing.js');";
+  assert_eq!(
+    replace_inline_worker_placeholders(code, |filename| (filename == "worker-abc.js")
+      .then_some("self.onmessage = () => {\n  postMessage(\"hi\");\n};"))
+    .0,
+    "new Worker(URL.createObjectURL(new Blob([\"self.onmessage = () => {\\n  postMessage(\\\"hi\\\");\\n};\"], { type: \"text/javascript\" })));\nnew Worker('rolldown-inline-worker:missing.js');"
+  );
+}
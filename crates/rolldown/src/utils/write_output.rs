@@ -0,0 +1,23 @@
+use std::path::Path;
+
+use rolldown_fs::{FileSystem, SharedFileSystem};
+
+/// Write a file of the output to `filename` in `dir`, creating its parent directories if they don't exist.
+pub fn write_output_file(
+  fs: &SharedFileSystem,
+  dir: &Path,
+  filename: &str,
+  content: &[u8],
+) -> anyhow::Result<()> {
+  let dest = dir.join(filename);
+  if let Some(p) = dest.parent() {
+    if !fs.exists(p) {
+      fs.create_dir_all(p)
+        .map_err(|err| anyhow::anyhow!("Failed to create directory {p:?}").context(err))?;
+    }
+  };
+  fs.write(dest.as_path(), content).map_err(|err| {
+    anyhow::anyhow!("Failed to write file in {:?}", dir.join(filename)).context(err)
+  })?;
+  Ok(())
+}
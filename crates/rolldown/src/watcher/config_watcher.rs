@@ -0,0 +1,205 @@
+use std::{iter, path::PathBuf, time::Duration};
+
+use anyhow::Result;
+use notify::{EventKind, RecursiveMode, Watcher};
+use rustc_hash::FxHashSet;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver};
+
+use super::WatcherEvent;
+use crate::Bundler;
+
+/// The bundler created from the config file.
+pub struct LoadedConfig {
+  pub bundler: Bundler,
+  /// Files the config depends on, such as modules imported by the config file. The config file itself is always
+  /// watched.
+  pub dependencies: Vec<PathBuf>,
+}
+
+/// Watch with the bundler created by `load_config`, and start over with a new bundler whenever the config file or
+/// any of its dependencies changes. `WatcherEvent::RestartDueToConfigChange` is sent before restarting, and events of
+/// the previous bundler are no longer sent.
+///
+/// If loading the config fails, the error is sent as `WatcherEvent::Error`, and the config is loaded again on the
+/// next change of it.
+///
+/// Must be called within a tokio runtime. Watching stops once the returned receiver is dropped.
+pub fn watch_with_config<F>(
+  config_file: PathBuf,
+  load_config: F,
+) -> Result<UnboundedReceiver<WatcherEvent>>
+where
+  F: Fn() -> Result<LoadedConfig> + Send + 'static,
+{
+  let (tx, rx) = unbounded_channel();
+  let (change_tx, mut change_rx) = unbounded_channel::<()>();
+  let mut config_watcher =
+    notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+      let is_changed = event.is_ok_and(|event| {
+        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_))
+      });
+      if is_changed {
+        let _ = change_tx.send(());
+      }
+    })?;
+
+  tokio::spawn(async move {
+    let mut watched_files = FxHashSet::default();
+    let mut config_files = vec![config_file.clone()];
+    loop {
+      let events = match load_config() {
+        Ok(LoadedConfig { bundler, dependencies }) => {
+          config_files = iter::once(config_file.clone()).chain(dependencies).collect();
+          bundler.watch()
+        }
+        Err(err) => Err(err),
+      };
+      let mut events = match events {
+        Ok(events) => Some(events),
+        Err(err) => {
+          if tx.send(WatcherEvent::Error(err)).is_err() {
+            return;
+          }
+          None
+        }
+      };
+
+      for file in &config_files {
+        if watched_files.insert(file.clone()) {
+          let _ = config_watcher.watch(file, RecursiveMode::NonRecursive);
+        }
+      }
+
+      // Forward events of the bundler until the config changes.
+      loop {
+        tokio::select! {
+          () = tx.closed() => return,
+          changed = change_rx.recv() => {
+            if changed.is_none() {
+              return;
+            }
+            break;
+          }
+          event = next_event(&mut events) => {
+            let Some(event) = event else {
+              return;
+            };
+            if tx.send(event).is_err() {
+              return;
+            }
+          }
+        }
+      }
+      // Editors might write the file more than once on saving.
+      while let Ok(Some(())) =
+        tokio::time::timeout(Duration::from_millis(20), change_rx.recv()).await
+      {}
+
+      // Dropping the receiver stops the watcher of the previous bundler.
+      drop(events);
+      if tx.send(WatcherEvent::RestartDueToConfigChange).is_err() {
+        return;
+      }
+    }
+  });
+
+  Ok(rx)
+}
+
+/// Never resolves if there is no bundler, since loading the config has failed.
+async fn next_event(events: &mut Option<UnboundedReceiver<WatcherEvent>>) -> Option<WatcherEvent> {
+  match events {
+    Some(events) => events.recv().await,
+    None => std::future::pending().await,
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_with_config_restarts_on_config_changes() {
+    use std::{
+      path::Path,
+      sync::atomic::{AtomicUsize, Ordering},
+      sync::Arc,
+    };
+
+    use rolldown_common::{InputItem, WatchOptions};
+
+    use crate::watcher::tests::{create_test_dir, next_output};
+    use crate::{BundleOutput, BundlerBuilder, BundlerOptions};
+
+    /// Keep changing the file until the watcher restarts, since files are watched right after the config is loaded.
+    async fn write_until_restart(
+      rx: &mut UnboundedReceiver<WatcherEvent>,
+      path: &Path,
+      content: &str,
+    ) {
+      loop {
+        std::fs::write(path, content).unwrap();
+        while let Ok(event) = tokio::time::timeout(Duration::from_millis(500), rx.recv()).await {
+          match event {
+            Some(WatcherEvent::RestartDueToConfigChange) => return,
+            // Events of the bundler are forwarded until the restart.
+            Some(WatcherEvent::BundleStart { .. } | WatcherEvent::BundleEnd { .. }) => {}
+            _ => panic!("The watcher should restart"),
+          }
+        }
+      }
+    }
+
+    let entry_filenames = |output: &BundleOutput| {
+      output.assets.iter().map(|asset| asset.filename().to_string()).collect::<Vec<_>>()
+    };
+
+    let dir = create_test_dir("rolldown_test_watch_with_config_restarts_on_config_changes");
+    std::fs::write(dir.join("main.js"), "console.log('main')\n").unwrap();
+    // The config is the name of the entry, and it depends on `shared.txt`.
+    std::fs::write(dir.join("config.txt"), "first").unwrap();
+    std::fs::write(dir.join("shared.txt"), "").unwrap();
+
+    let loads = Arc::new(AtomicUsize::default());
+    let load_config = {
+      let dir = dir.clone();
+      let loads = Arc::clone(&loads);
+      move || {
+        loads.fetch_add(1, Ordering::SeqCst);
+        let name = std::fs::read_to_string(dir.join("config.txt"))?;
+        if name.is_empty() {
+          anyhow::bail!("The name of the entry is empty");
+        }
+        let bundler = BundlerBuilder::default()
+          .with_options(BundlerOptions {
+            input: Some(vec![InputItem { name: Some(name), import: "./main.js".to_string() }]),
+            cwd: Some(dir.clone()),
+            watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+            ..Default::default()
+          })
+          .build();
+        Ok(LoadedConfig { bundler, dependencies: vec![dir.join("shared.txt")] })
+      }
+    };
+    let mut rx = watch_with_config(dir.join("config.txt"), load_config).unwrap();
+    assert_eq!(entry_filenames(&next_output(&mut rx).await), ["first.js"]);
+
+    write_until_restart(&mut rx, &dir.join("config.txt"), "second").await;
+    assert_eq!(entry_filenames(&next_output(&mut rx).await), ["second.js"]);
+
+    // Changes of dependencies of the config restart the watcher as well.
+    let loads_before = loads.load(Ordering::SeqCst);
+    write_until_restart(&mut rx, &dir.join("shared.txt"), "changed").await;
+    assert_eq!(entry_filenames(&next_output(&mut rx).await), ["second.js"]);
+    assert!(loads.load(Ordering::SeqCst) > loads_before);
+
+    // A config failing to load is reported, and loaded again on the next change.
+    write_until_restart(&mut rx, &dir.join("config.txt"), "").await;
+    let Some(WatcherEvent::Error(err)) = rx.recv().await else {
+      panic!("Loading the config should fail");
+    };
+    assert_eq!(err.to_string(), "The name of the entry is empty");
+    write_until_restart(&mut rx, &dir.join("config.txt"), "third").await;
+    assert_eq!(entry_filenames(&next_output(&mut rx).await), ["third.js"]);
+  }
+}
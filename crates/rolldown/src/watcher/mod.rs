@@ -0,0 +1,784 @@
+mod config_watcher;
+mod watch_filter;
+
+use std::{
+  path::{Path, PathBuf},
+  sync::{Arc, Mutex},
+  time::{Duration, Instant},
+};
+
+use anyhow::Result;
+use notify::{EventKind, PollWatcher, RecursiveMode, Watcher};
+use rolldown_plugin::HookWatchChangeArgs;
+use rolldown_utils::indexmap::FxIndexSet;
+use rustc_hash::FxHashSet;
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+pub use self::config_watcher::{watch_with_config, LoadedConfig};
+use self::watch_filter::WatchFilter;
+use crate::{
+  module_loader::module_cache::ModuleCache, types::cancellation_token::BuildCancelled,
+  utils::hmr::HmrUpdate, BundleOutput, Bundler,
+};
+
+pub enum WatcherEvent {
+  /// A build is started, either the initial one or a rebuild triggered by changes. `changed_files` are the files
+  /// changed since the last build, which is empty for the initial build.
+  BundleStart { changed_files: Vec<String> },
+  /// The build is finished. The output is written to disk unless `watch.skipWrite` is enabled.
+  BundleEnd { duration: Duration, output: BundleOutput },
+  /// The build failed. The watcher keeps watching files of the last successful build.
+  Error(anyhow::Error),
+  /// The update chunk of modules changed since the last build, which is sent after `BundleEnd` of rebuilds if `hmr` is
+  /// enabled. It's in the output of `BundleEnd` as well, and written along with other chunks.
+  HmrUpdate(HmrUpdate),
+  /// The config file or its dependencies have changed, so the watcher is restarted with the reloaded config. Only
+  /// sent by `watch_with_config`.
+  RestartDueToConfigChange,
+}
+
+impl Bundler {
+  /// Build and write the output, or only generate it with `watch.skipWrite`, then rebuild whenever files that
+  /// participated in the latest successful build change. Files added by `this.addWatchFile` are watched as well.
+  /// Changes are filtered by `watch.include` and `watch.exclude` before scheduling a rebuild. Changes are detected by
+  /// polling instead of events of the file system with `watch.poll`.
+  ///
+  /// Rebuilds only load and transform modules depending on changed files, and reuse the loaded code of the rest from
+  /// previous builds. Rebuilds aren't incremental beyond that: every module is parsed, scanned and resolved again, and
+  /// the whole module graph is linked and generated again.
+  /// With `lazyCompilation`, modules newly requested by the app trigger a rebuild as well. Changes during a build
+  /// cancel it, and a rebuild starts once changes settle.
+  ///
+  /// Errors and warnings of each build are kept in the output of `BundleEnd`, after going through `onLog` as the ones of
+  /// `write` do.
+  ///
+  /// Must be called within a tokio runtime. Watching stops once the returned receiver is dropped.
+  pub fn watch(mut self) -> Result<UnboundedReceiver<WatcherEvent>> {
+    let module_cache = Arc::new(ModuleCache::default());
+    self.build_state.module_cache = Some(Arc::clone(&module_cache));
+    let (tx, rx) = unbounded_channel();
+    let (change_tx, mut change_rx) = unbounded_channel::<PathBuf>();
+    let filter = Arc::new(WatchFilter::new(
+      &self.options.watch_include,
+      &self.options.watch_exclude,
+      &self.options.cwd,
+    )?);
+    let lazy_compilation = self.lazy_compilation();
+    let watched_files = Arc::new(Mutex::new(FxHashSet::default()));
+    let mut watcher = self.create_file_watcher(Arc::clone(&watched_files), change_tx)?;
+
+    tokio::spawn(async move {
+      let mut watched_dirs = FxHashSet::default();
+      let mut changed_files = vec![];
+      loop {
+        if tx
+          .send(WatcherEvent::BundleStart { changed_files: std::mem::take(&mut changed_files) })
+          .is_err()
+        {
+          break;
+        }
+        let start = Instant::now();
+        let ret =
+          if self.options.watch_skip_write() { self.generate().await } else { self.write().await };
+        // Files of the build are watched before it's reported, and files dropped out of the build are no longer
+        // watched.
+        let files = self
+          .plugin_driver
+          .watch_files()
+          .into_iter()
+          .map(PathBuf::from)
+          .filter(|file| filter.is_watched(file))
+          .collect::<FxHashSet<_>>();
+        watch_dirs_of(watcher.as_mut(), &files, &mut watched_dirs);
+        *watched_files.lock().expect("Watched files should not be poisoned") = files;
+
+        // The build cancelled by changes is followed by a rebuild for the changes.
+        let is_cancelled = matches!(&ret, Err(err) if err.is::<BuildCancelled>());
+        if !is_cancelled {
+          let hmr_update = ret.as_ref().ok().and_then(|output| output.hmr_update.clone());
+          let event = match ret {
+            Ok(output) => WatcherEvent::BundleEnd { duration: start.elapsed(), output },
+            Err(err) => WatcherEvent::Error(err),
+          };
+          if tx.send(event).is_err() {
+            break;
+          }
+          if let Some(hmr_update) = hmr_update {
+            if tx.send(WatcherEvent::HmrUpdate(hmr_update)).is_err() {
+              break;
+            }
+          }
+        }
+
+        let mut changed_paths = FxIndexSet::default();
+        let lazy_requested = async {
+          match &lazy_compilation {
+            Some(lazy_compilation) => lazy_compilation.requested().await,
+            None => std::future::pending().await,
+          }
+        };
+        tokio::select! {
+          () = tx.closed() => break,
+          () = lazy_requested => {}
+          changed = change_rx.recv() => {
+            let Some(changed) = changed else {
+              break;
+            };
+            changed_paths.insert(changed);
+          }
+        }
+        // Every change postpones the rebuild, so a burst of changes, such as switching git branches, triggers a
+        // single rebuild.
+        let build_delay = Duration::from_millis(self.options.watch_build_delay);
+        while let Ok(Some(changed)) = tokio::time::timeout(build_delay, change_rx.recv()).await {
+          changed_paths.insert(changed);
+        }
+
+        self.resolver.clear_cache();
+        changed_files =
+          changed_paths.iter().map(|path| path.to_string_lossy().into_owned()).collect::<Vec<_>>();
+        for file in &changed_files {
+          for module_id in self.plugin_driver.modules_depending_on(file) {
+            module_cache.invalidate(&module_id);
+            self.hmr_changed_modules.push(module_id);
+          }
+        }
+        if changed_files.is_empty() {
+          continue;
+        }
+        let args = HookWatchChangeArgs { changed_files: &changed_files };
+        if let Err(err) = self.plugin_driver.watch_change(&args).await {
+          if tx.send(WatcherEvent::Error(err)).is_err() {
+            break;
+          }
+        }
+      }
+    });
+
+    Ok(rx)
+  }
+
+  /// Send paths of changed files in `watched_files` to `change_tx`, and cancel the build in progress. Other files in
+  /// watched directories are ignored.
+  fn create_file_watcher(
+    &self,
+    watched_files: Arc<Mutex<FxHashSet<PathBuf>>>,
+    change_tx: UnboundedSender<PathBuf>,
+  ) -> Result<Box<dyn Watcher + Send>> {
+    let cancellation_token = self.cancellation_token();
+    let event_handler = move |event: notify::Result<notify::Event>| {
+      let Ok(event) = event else {
+        return;
+      };
+      if matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)) {
+        let watched_files = watched_files.lock().expect("Watched files should not be poisoned");
+        for path in event.paths.into_iter().filter(|path| watched_files.contains(path)) {
+          let _ = change_tx.send(path);
+          // The build in progress is stale, so stop it early instead of waiting for it.
+          cancellation_token.cancel();
+        }
+      }
+    };
+    // `watch.poll` checks watched files periodically, for file systems that don't deliver events, such as network
+    // file systems. Modification times are compared in whole seconds, so contents are compared as well to notice
+    // changes within the same second.
+    Ok(match self.options.watch_poll_interval {
+      Some(interval) => Box::new(PollWatcher::new(
+        event_handler,
+        notify::Config::default()
+          .with_poll_interval(Duration::from_millis(interval))
+          .with_compare_contents(true),
+      )?),
+      None => Box::new(notify::recommended_watcher(event_handler)?),
+    })
+  }
+}
+
+/// Watch directories of `files` instead of the files, so files replaced by atomic saves, or removed and created
+/// again, are still watched. Directories no longer containing any of the files are unwatched, and the ones that don't
+/// exist yet are tried again next time.
+fn watch_dirs_of(
+  watcher: &mut dyn Watcher,
+  files: &FxHashSet<PathBuf>,
+  watched_dirs: &mut FxHashSet<PathBuf>,
+) {
+  // Ids that aren't files, such as the ones of virtual modules, can't be watched.
+  let dirs = files
+    .iter()
+    .filter(|file| file.is_absolute())
+    .filter_map(|file| file.parent().map(Path::to_path_buf))
+    .collect::<FxHashSet<_>>();
+  for dir in watched_dirs.difference(&dirs) {
+    let _ = watcher.unwatch(dir);
+  }
+  watched_dirs.retain(|dir| dirs.contains(dir));
+  for dir in dirs {
+    if !watched_dirs.contains(&dir) && watcher.watch(&dir, RecursiveMode::NonRecursive).is_ok() {
+      watched_dirs.insert(dir);
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+
+  /// An empty directory in the temporary directory for files of a test.
+  pub(super) fn create_test_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(name);
+    let _ = std::fs::remove_dir_all(&dir);
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+  }
+
+  /// Changes might not be noticed right away, such as by polling with `watch.poll`, so keep changing the file until a
+  /// rebuild starts. Returns the changed files of the rebuild.
+  async fn write_until_rebuild(
+    rx: &mut UnboundedReceiver<WatcherEvent>,
+    path: &Path,
+    content: &str,
+  ) -> Vec<String> {
+    loop {
+      std::fs::write(path, content).unwrap();
+      match tokio::time::timeout(Duration::from_millis(500), rx.recv()).await {
+        Ok(Some(WatcherEvent::BundleStart { changed_files })) => break changed_files,
+        Ok(_) => panic!("A rebuild should start"),
+        Err(_) => continue,
+      }
+    }
+  }
+
+  /// The output of the next build that isn't cancelled.
+  pub(super) async fn next_output(rx: &mut UnboundedReceiver<WatcherEvent>) -> BundleOutput {
+    loop {
+      match rx.recv().await {
+        Some(WatcherEvent::BundleEnd { output, .. }) => break output,
+        // Later writes of `write_until_rebuild` could cancel the rebuild and start another one.
+        Some(WatcherEvent::BundleStart { .. }) => continue,
+        _ => panic!("The build should finish"),
+      }
+    }
+  }
+
+  /// The code of the entry chunk.
+  fn entry_code(output: &BundleOutput) -> String {
+    output
+      .assets
+      .iter()
+      .find_map(|asset| match asset {
+        rolldown_common::Output::Chunk(chunk) if chunk.is_entry => Some(chunk.code.clone()),
+        _ => None,
+      })
+      .expect("An entry chunk should be emitted")
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_rebuilds_changed_modules() {
+    use std::{borrow::Cow, sync::Mutex};
+
+    use rolldown_common::{InputItem, WatchOptions};
+    use rolldown_plugin::{
+      HookLoadArgs, HookLoadReturn, HookTransformArgs, HookTransformReturn, Plugin,
+      SharedPluginContext, TransformPluginContext,
+    };
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    /// Ids passed to `load` and `transform`.
+    #[derive(Debug)]
+    struct RecordHooks(Arc<Mutex<Vec<String>>>, Arc<Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl Plugin for RecordHooks {
+      fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("record-hooks")
+      }
+
+      async fn load(&self, _ctx: &SharedPluginContext, args: &HookLoadArgs) -> HookLoadReturn {
+        self.0.lock().unwrap().push(args.id.to_string());
+        Ok(None)
+      }
+
+      async fn transform(
+        &self,
+        _ctx: &TransformPluginContext<'_>,
+        args: &HookTransformArgs,
+      ) -> HookTransformReturn {
+        self.1.lock().unwrap().push(args.id.to_string());
+        Ok(None)
+      }
+    }
+
+    let dir = create_test_dir("rolldown_test_watch_rebuilds_changed_modules");
+    std::fs::write(dir.join("main.js"), "import { value } from './dep.js'\nconsole.log(value)\n")
+      .unwrap();
+    std::fs::write(dir.join("dep.js"), "export const value = 'before'\n").unwrap();
+
+    let loaded = Arc::new(Mutex::new(vec![]));
+    let transformed = Arc::new(Mutex::new(vec![]));
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+        ..Default::default()
+      })
+      .with_plugins(vec![Box::new(RecordHooks(Arc::clone(&loaded), Arc::clone(&transformed)))])
+      .build();
+    let mut rx = bundler.watch().unwrap();
+
+    assert!(
+      matches!(rx.recv().await, Some(WatcherEvent::BundleStart { changed_files }) if changed_files.is_empty())
+    );
+    let Some(WatcherEvent::BundleEnd { output, .. }) = rx.recv().await else {
+      panic!("The initial build should succeed");
+    };
+    assert!(entry_code(&output).contains("'before'"));
+    assert_eq!(loaded.lock().unwrap().len(), 2);
+    assert_eq!(transformed.lock().unwrap().len(), 2);
+    loaded.lock().unwrap().clear();
+    transformed.lock().unwrap().clear();
+
+    let changed_files =
+      write_until_rebuild(&mut rx, &dir.join("dep.js"), "export const value = 'after'\n").await;
+    assert!(changed_files.iter().all(|file| file.ends_with("dep.js")));
+    let output = next_output(&mut rx).await;
+    assert!(entry_code(&output).contains("'after'"));
+    // `main.js` is unchanged, so it's reused from the previous build instead of being loaded and transformed again.
+    for ids in [loaded.lock().unwrap().clone(), transformed.lock().unwrap().clone()] {
+      assert!(!ids.is_empty());
+      assert!(ids.iter().all(|id| id.ends_with("dep.js")));
+    }
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_sends_hmr_updates() {
+    use rolldown_common::{InputItem, WatchOptions};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    let dir = create_test_dir("rolldown_test_watch_sends_hmr_updates");
+    std::fs::write(
+      dir.join("main.js"),
+      "import { value } from './dep.js'\nimport { log } from './log.js'\nlog.push(value)\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("log.js"), "export const log = globalThis.log = []\n").unwrap();
+    let dep = |value: &str| {
+      format!(
+      "import {{ log }} from './log.js'\nexport const value = '{value}'\nimport.meta.hot.accept((mod) => log.push('accepted ' + mod.value))\n"
+    )
+    };
+    std::fs::write(dir.join("dep.js"), dep("before")).unwrap();
+
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        hmr: Some(true),
+        watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+        ..Default::default()
+      })
+      .build();
+    let mut rx = bundler.watch().unwrap();
+
+    assert!(matches!(rx.recv().await, Some(WatcherEvent::BundleStart { .. })));
+    let Some(WatcherEvent::BundleEnd { output, .. }) = rx.recv().await else {
+      panic!("The initial build should succeed");
+    };
+    assert!(output.hmr_update.is_none());
+    let chunk_code = entry_code(&output);
+
+    write_until_rebuild(&mut rx, &dir.join("dep.js"), &dep("after")).await;
+    let update = loop {
+      match rx.recv().await {
+        Some(WatcherEvent::HmrUpdate(update)) => break update,
+        Some(WatcherEvent::BundleStart { .. } | WatcherEvent::BundleEnd { .. }) => continue,
+        _ => panic!("The rebuild should send an HMR update"),
+      }
+    };
+    assert_eq!(update.module_ids, vec!["dep.js".to_string()]);
+    assert!(update.filename.starts_with("hmr-update-"));
+
+    // Modules of the bundle register their exports with the HMR runtime, which the update loads them from.
+    assert!(
+      chunk_code.contains("__rolldown_hmr__.register('log.js', () => log_ns);"),
+      "{chunk_code}"
+    );
+    assert!(chunk_code.contains("__rolldown_hmr__.hot('dep.js').accept("), "{chunk_code}");
+    assert_eq!(
+      update.code,
+      r#"__rolldown_hmr__.applyUpdates([
+["dep.js", function () {
+"use strict";
+var __rolldown_exports__ = { get "value"() { return value; } };
+var __rolldown_import_0__ = __rolldown_hmr__.load("log.js");
+const value = 'after'
+__rolldown_hmr__.hot("dep.js").accept((mod) => __rolldown_import_0__.log.push('accepted ' + mod.value))
+
+return __rolldown_exports__;
+}]
+]);
+"#
+    );
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_recovers_from_errors() {
+    use rolldown_common::{InputItem, WatchOptions};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    let dir = create_test_dir("rolldown_test_watch_recovers_from_errors");
+    std::fs::write(dir.join("main.js"), "import { value } from './dep.js'\nconsole.log(value)\n")
+      .unwrap();
+    std::fs::write(dir.join("dep.js"), "export const value = 'before'\n").unwrap();
+
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+        ..Default::default()
+      })
+      .build();
+    let mut rx = bundler.watch().unwrap();
+    let output = next_output(&mut rx).await;
+    assert!(output.errors.is_empty());
+
+    // A failed build is reported as an error, and files of the last successful build are still watched.
+    write_until_rebuild(&mut rx, &dir.join("dep.js"), "export const value = \n").await;
+    let err = loop {
+      match rx.recv().await {
+        Some(WatcherEvent::Error(err)) => break err,
+        Some(WatcherEvent::BundleStart { .. }) => continue,
+        _ => panic!("The rebuild should fail"),
+      }
+    };
+    assert!(err.to_string().contains("Unexpected token"), "{err}");
+
+    write_until_rebuild(&mut rx, &dir.join("dep.js"), "export const value = 'after'\n").await;
+    let Some(WatcherEvent::BundleEnd { duration, output }) = rx.recv().await else {
+      panic!("The rebuild should finish");
+    };
+    assert!(output.errors.is_empty(), "{:?}", output.errors);
+    assert!(entry_code(&output).contains("'after'"));
+    assert!(duration > Duration::ZERO);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_follows_files_of_the_build() {
+    use rolldown_common::{InputItem, WatchOptions};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    /// The output of the next build without errors, skipping failed ones.
+    async fn next_successful_output(rx: &mut UnboundedReceiver<WatcherEvent>) -> BundleOutput {
+      loop {
+        match rx.recv().await {
+          Some(WatcherEvent::BundleEnd { output, .. }) if output.errors.is_empty() => break output,
+          Some(_) => continue,
+          None => panic!("The watcher should keep running"),
+        }
+      }
+    }
+
+    let dir = create_test_dir("rolldown_test_watch_follows_files_of_the_build");
+    std::fs::write(dir.join("main.js"), "import { value } from './dep.js'\nconsole.log(value)\n")
+      .unwrap();
+    std::fs::write(dir.join("dep.js"), "export const value = 'before'\n").unwrap();
+
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+        ..Default::default()
+      })
+      .build();
+    let mut rx = bundler.watch().unwrap();
+    next_output(&mut rx).await;
+
+    // Editors save files atomically by renaming a temporary file over them.
+    std::fs::write(dir.join("dep.js.tmp"), "export const value = 'renamed'\n").unwrap();
+    std::fs::rename(dir.join("dep.js.tmp"), dir.join("dep.js")).unwrap();
+    assert!(entry_code(&next_successful_output(&mut rx).await).contains("'renamed'"));
+
+    // The file is still watched after it's removed and created again.
+    std::fs::remove_file(dir.join("dep.js")).unwrap();
+    std::fs::write(dir.join("dep.js"), "export const value = 'created'\n").unwrap();
+    assert!(entry_code(&next_successful_output(&mut rx).await).contains("'created'"));
+
+    // Files dropped out of the build don't trigger rebuilds anymore.
+    std::fs::write(dir.join("main.js"), "console.log('alone')\n").unwrap();
+    assert!(entry_code(&next_successful_output(&mut rx).await).contains("'alone'"));
+    std::fs::write(dir.join("dep.js"), "export const value = 'unused'\n").unwrap();
+    let main = dir.join("main.js");
+    let changed_files = write_until_rebuild(&mut rx, &main, "console.log('again')\n").await;
+    assert_eq!(changed_files, [main.to_string_lossy()]);
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_coalesces_changes_within_build_delay() {
+    use std::{borrow::Cow, sync::Mutex};
+
+    use rolldown_common::{InputItem, WatchOptions};
+    use rolldown_plugin::{HookNoopReturn, Plugin, SharedPluginContext};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    /// Changed files passed to `watchChange`.
+    #[derive(Debug)]
+    struct RecordWatchChange(Arc<Mutex<Vec<Vec<String>>>>);
+
+    #[async_trait::async_trait]
+    impl Plugin for RecordWatchChange {
+      fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("record-watch-change")
+      }
+
+      async fn watch_change(
+        &self,
+        _ctx: &SharedPluginContext,
+        args: &HookWatchChangeArgs,
+      ) -> HookNoopReturn {
+        self.0.lock().unwrap().push(args.changed_files.to_vec());
+        Ok(())
+      }
+    }
+
+    let dir = create_test_dir("rolldown_test_watch_coalesces_changes_within_build_delay");
+    std::fs::write(
+    dir.join("main.js"),
+    "import { a } from './a.js'\nimport { b } from './b.js'\nimport { c } from './c.js'\nconsole.log(a, b, c)\n",
+  )
+  .unwrap();
+    for name in ["a", "b", "c"] {
+      std::fs::write(dir.join(format!("{name}.js")), format!("export const {name} = 0\n")).unwrap();
+    }
+
+    let watch_changes = Arc::new(Mutex::new(vec![]));
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions {
+          build_delay: Some(200),
+          skip_write: Some(true),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })
+      .with_plugins(vec![Box::new(RecordWatchChange(Arc::clone(&watch_changes)))])
+      .build();
+    let mut rx = bundler.watch().unwrap();
+    next_output(&mut rx).await;
+    write_until_rebuild(&mut rx, &dir.join("a.js"), "export const a = 1\n").await;
+    next_output(&mut rx).await;
+    watch_changes.lock().unwrap().clear();
+
+    // Each change comes within the delay of the previous one, so they trigger a single rebuild.
+    for name in ["a", "b", "c"] {
+      std::fs::write(dir.join(format!("{name}.js")), format!("export const {name} = 2\n")).unwrap();
+      tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+    // Changed files are in the order they were first changed.
+    let Some(WatcherEvent::BundleStart { changed_files }) = rx.recv().await else {
+      panic!("A rebuild should start");
+    };
+    let expected =
+      ["a.js", "b.js", "c.js"].map(|name| dir.join(name).to_string_lossy().into_owned()).to_vec();
+    assert_eq!(changed_files, expected);
+    let output = next_output(&mut rx).await;
+    let code = entry_code(&output);
+    assert!(
+      ["a", "b", "c"].iter().all(|name| code.contains(&format!("const {name} = 2;"))),
+      "{code}"
+    );
+    assert_eq!(*watch_changes.lock().unwrap(), [expected]);
+    assert!(tokio::time::timeout(Duration::from_millis(500), rx.recv()).await.is_err());
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_skip_write() {
+    use rolldown_common::{InputItem, WatchOptions};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    let dir = create_test_dir("rolldown_test_watch_skip_write");
+    std::fs::write(dir.join("main.js"), "console.log('before')\n").unwrap();
+    let watch = |skip_write: bool| {
+      BundlerBuilder::default()
+        .with_options(BundlerOptions {
+          input: Some(vec![InputItem {
+            name: Some("main".to_string()),
+            import: "./main.js".to_string(),
+          }]),
+          cwd: Some(dir.clone()),
+          watch: Some(WatchOptions { skip_write: Some(skip_write), ..Default::default() }),
+          ..Default::default()
+        })
+        .build()
+        .watch()
+        .unwrap()
+    };
+
+    // Builds and rebuilds are handed to the consumer without being written.
+    let mut rx = watch(true);
+    assert!(entry_code(&next_output(&mut rx).await).contains("console.log('before')"));
+    write_until_rebuild(&mut rx, &dir.join("main.js"), "console.log('after')\n").await;
+    assert!(entry_code(&next_output(&mut rx).await).contains("console.log('after')"));
+    assert!(!dir.join("dist").exists());
+    drop(rx);
+
+    let mut rx = watch(false);
+    let output = next_output(&mut rx).await;
+    let written = std::fs::read_to_string(dir.join("dist/main.js")).unwrap();
+    assert_eq!(written, entry_code(&output));
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_files_added_by_plugins() {
+    use std::{borrow::Cow, sync::Mutex};
+
+    use rolldown_common::{InputItem, WatchOptions};
+    use rolldown_plugin::{
+      HookLoadOutput, HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext,
+    };
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    /// Replaces `TEMPLATE` in `main.js` with the content of `template.txt`, which is added as a watch file.
+    #[derive(Debug)]
+    struct Template(PathBuf, Arc<Mutex<Vec<String>>>);
+
+    #[async_trait::async_trait]
+    impl Plugin for Template {
+      fn name(&self) -> Cow<'static, str> {
+        Cow::Borrowed("template")
+      }
+
+      async fn transform(
+        &self,
+        ctx: &TransformPluginContext<'_>,
+        args: &HookTransformArgs,
+      ) -> HookTransformReturn {
+        self.1.lock().unwrap().push(args.id.to_string());
+        if !args.id.ends_with("main.js") {
+          return Ok(None);
+        }
+        ctx.add_watch_file(&self.0.to_string_lossy());
+        let template = std::fs::read_to_string(&self.0)?;
+        Ok(Some(HookLoadOutput {
+          code: args.code.replace("TEMPLATE", &format!("{template:?}")),
+          map: None,
+          side_effects: None,
+        }))
+      }
+    }
+
+    let dir = create_test_dir("rolldown_test_watch_files_added_by_plugins");
+    std::fs::write(
+      dir.join("main.js"),
+      "import { value } from './dep.js'\nconsole.log(TEMPLATE, value)\n",
+    )
+    .unwrap();
+    std::fs::write(dir.join("dep.js"), "export const value = 'dep'\n").unwrap();
+    std::fs::write(dir.join("template.txt"), "before").unwrap();
+
+    let transformed = Arc::new(Mutex::new(vec![]));
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions { skip_write: Some(true), ..Default::default() }),
+        ..Default::default()
+      })
+      .with_plugins(vec![Box::new(Template(dir.join("template.txt"), Arc::clone(&transformed)))])
+      .build();
+    let mut rx = bundler.watch().unwrap();
+    assert!(entry_code(&next_output(&mut rx).await).contains("'before'"));
+    transformed.lock().unwrap().clear();
+
+    // Only the module that added the watch file is transformed again.
+    let changed_files = write_until_rebuild(&mut rx, &dir.join("template.txt"), "after").await;
+    assert_eq!(changed_files, [dir.join("template.txt").to_string_lossy()]);
+    assert!(entry_code(&next_output(&mut rx).await).contains("'after'"));
+    let transformed = transformed.lock().unwrap().clone();
+    assert!(!transformed.is_empty());
+    assert!(transformed.iter().all(|id| id.ends_with("main.js")), "{transformed:?}");
+  }
+
+  #[tokio::test(flavor = "multi_thread")]
+  async fn test_watch_poll() {
+    use rolldown_common::{InputItem, WatchOptions, WatchPollOptions};
+
+    use crate::{BundlerBuilder, BundlerOptions};
+
+    let dir = create_test_dir("rolldown_test_watch_poll");
+    std::fs::write(dir.join("main.js"), "console.log('before')\n").unwrap();
+
+    let bundler = BundlerBuilder::default()
+      .with_options(BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(dir.clone()),
+        watch: Some(WatchOptions {
+          skip_write: Some(true),
+          poll: Some(WatchPollOptions { interval: Some(50) }),
+          ..Default::default()
+        }),
+        ..Default::default()
+      })
+      .build();
+    let mut rx = bundler.watch().unwrap();
+    next_output(&mut rx).await;
+
+    let changed_files =
+      write_until_rebuild(&mut rx, &dir.join("main.js"), "console.log('after')\n").await;
+    assert_eq!(changed_files, [dir.join("main.js").to_string_lossy()]);
+    assert!(entry_code(&next_output(&mut rx).await).contains("console.log('after')"));
+
+    // Contents are compared as well, so a change within the same second as the last one is noticed.
+    let modified = std::fs::metadata(dir.join("main.js")).unwrap().modified().unwrap();
+    let mut changed_files = None;
+    for _ in 0..10 {
+      std::fs::write(dir.join("main.js"), "console.log('again')\n").unwrap();
+      let file = std::fs::File::options().write(true).open(dir.join("main.js")).unwrap();
+      file.set_modified(modified).unwrap();
+      match tokio::time::timeout(Duration::from_millis(500), rx.recv()).await {
+        Ok(Some(WatcherEvent::BundleStart { changed_files: files })) => {
+          changed_files = Some(files);
+          break;
+        }
+        Ok(_) => panic!("A rebuild should start"),
+        Err(_) => continue,
+      }
+    }
+    assert_eq!(
+      changed_files.expect("A rebuild should start"),
+      [dir.join("main.js").to_string_lossy()]
+    );
+    assert!(entry_code(&next_output(&mut rx).await).contains("console.log('again')"));
+  }
+}
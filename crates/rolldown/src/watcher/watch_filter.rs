@@ -0,0 +1,41 @@
+use std::path::Path;
+
+use sugar_path::SugarPath;
+
+/// Decides whether changes of a file should trigger a rebuild, according to `watch.include` and `watch.exclude`.
+pub struct WatchFilter {
+  include: Vec<glob::Pattern>,
+  exclude: Vec<glob::Pattern>,
+}
+
+impl WatchFilter {
+  /// Patterns are relative to `cwd`.
+  pub fn new(include: &[String], exclude: &[String], cwd: &Path) -> anyhow::Result<Self> {
+    let compile = |patterns: &[String]| {
+      patterns
+        .iter()
+        .map(|pattern| glob::Pattern::new(&cwd.join(pattern).normalize().to_string_lossy()))
+        .collect::<Result<Vec<_>, _>>()
+    };
+    Ok(Self { include: compile(include)?, exclude: compile(exclude)? })
+  }
+
+  pub fn is_watched(&self, path: &Path) -> bool {
+    (self.include.is_empty() || self.include.iter().any(|pattern| pattern.matches_path(path)))
+      && !self.exclude.iter().any(|pattern| pattern.matches_path(path))
+  }
+}
+
+#[test]
+fn test_watch_filter() {
+  let cwd = Path::new("/project");
+  let filter =
+    WatchFilter::new(&["src/**".to_string()], &["**/node_modules/**".to_string()], cwd).unwrap();
+  assert!(filter.is_watched(Path::new("/project/src/main.js")));
+  assert!(!filter.is_watched(Path::new("/project/src/node_modules/foo/index.js")));
+  assert!(!filter.is_watched(Path::new("/project/rolldown.config.js")));
+
+  let filter = WatchFilter::new(&[], &["dist/**".to_string()], cwd).unwrap();
+  assert!(filter.is_watched(Path::new("/project/rolldown.config.js")));
+  assert!(!filter.is_watched(Path::new("/project/dist/main.js")));
+}
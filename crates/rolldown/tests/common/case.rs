@@ -20,7 +20,6 @@ impl Case {
   }
 
   pub fn run(self) {
-    std::env::set_var("ROLLDOWN_TEST", "1");
     tokio::runtime::Runtime::new().unwrap().block_on(self.run_inner());
   }
 
@@ -72,12 +71,16 @@ impl Case {
     assets.sort_by_key(|c| c.filename().to_string());
     let artifacts = assets
       .iter()
-      .filter(|asset| !asset.filename().contains("$runtime$") && matches!(asset, Output::Chunk(_)))
+      .filter(|asset| {
+        !asset.filename().contains("$runtime$")
+          && !asset.filename().contains("rolldown-runtime")
+          && matches!(asset, Output::Chunk(_))
+      })
       .flat_map(|asset| {
         [
           Cow::Owned(format!("## {}\n", asset.filename())),
           "```js".into(),
-          Cow::Borrowed(asset.content().trim()),
+          String::from_utf8_lossy(asset.content_as_bytes()).trim().to_string().into(),
           "```".into(),
         ]
       })
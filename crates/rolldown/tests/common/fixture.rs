@@ -4,7 +4,7 @@ use std::{
   process::Command,
 };
 
-use rolldown::{BundleOutput, Bundler, OutputFormat, SourceMapType};
+use rolldown::{BundleOutput, BundlerBuilder, OutputFormat, SourceMapType};
 use rolldown_testing::test_config::{read_test_config, TestConfig};
 
 fn default_test_input_item() -> rolldown::InputItem {
@@ -151,7 +151,8 @@ impl Fixture {
       bundle_options.sourcemap = Some(SourceMapType::File);
     }
 
-    let mut bundler = Bundler::new(bundle_options);
+    let mut bundler =
+      BundlerBuilder::default().with_options(bundle_options).with_test_mode(true).build();
 
     if write_to_disk && fixture_path.join("dist").is_dir() {
       std::fs::remove_dir_all(fixture_path.join("dist")).unwrap();
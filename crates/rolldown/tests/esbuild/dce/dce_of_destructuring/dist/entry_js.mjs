@@ -0,0 +1,8 @@
+
+// entry.js
+var KEEP1 = x;
+var [remove3] = [];
+var [remove4,...remove5] = [...[1, 2], 3];
+var [,,remove6] = [, , 3];
+var [KEEP2] = [x];
+var [KEEP3] = [...{}];
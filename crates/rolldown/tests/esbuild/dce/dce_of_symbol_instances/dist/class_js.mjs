@@ -0,0 +1,14 @@
+
+// class.js
+class Keep1 {
+	*[Symbol.iterator](){
+	}
+	[keep];
+
+}
+class Keep2 {
+	[keep];
+
+	*[Symbol.iterator](){
+	}
+}
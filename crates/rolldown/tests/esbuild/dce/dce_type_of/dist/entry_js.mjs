@@ -0,0 +1,3 @@
+
+// entry.js
+typeof x_REMOVE;
@@ -0,0 +1,5 @@
+
+// entry.js
+var hasBar = typeof bar !== 'undefined';
+if (false) console.log(hasBar);
+
@@ -0,0 +1,11 @@
+
+// entry.js
+const myFunc = () => {
+	DROP:	{
+		console.log('drop');
+	}
+	console.log('keep');
+};
+var entry_default = myFunc;
+
+export { entry_default as default };
\ No newline at end of file
@@ -0,0 +1,8 @@
+
+// entry.js
+function empty() {
+}
+function id(x) {
+	return x;
+}
+for (var y = empty(); false;) ;for (var z = id(123); false;) ;
\ No newline at end of file
@@ -0,0 +1,3 @@
+
+// node_modules/demo-pkg/keep/this/file.js
+console.log('this should be kept');
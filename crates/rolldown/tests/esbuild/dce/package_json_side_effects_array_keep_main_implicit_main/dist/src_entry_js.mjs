@@ -0,0 +1,19 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/index-module.js
+var index_module_ns, foo;
+var init_index_module = __esmMin(() => {
+	index_module_ns = {};
+	__export(index_module_ns, {
+		foo:() => foo
+	});
+	foo = 123;
+	console.log('TEST FAILED');
+});
+
+// src/require-demo-pkg.js
+init_index_module(),__toCommonJS(index_module_ns);
+
+// src/entry.js
+init_index_module();
+console.log('unused import');
@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/index-module.js
+console.log('this should be kept');
+
+// src/entry.js
+console.log('unused import');
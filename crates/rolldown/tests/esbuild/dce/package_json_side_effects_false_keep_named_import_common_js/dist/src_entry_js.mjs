@@ -0,0 +1,11 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/index.js
+var require_demo_pkg_index = __commonJSMin((exports, module) => {
+	exports.foo = 123;
+	console.log('hello');
+});
+
+// src/entry.js
+var import_demo_pkg_index = __toESM(require_demo_pkg_index());
+console.log(import_demo_pkg_index.foo);
@@ -0,0 +1,7 @@
+
+// node_modules/demo-pkg/index.js
+const foo = 123;
+console.log('hello');
+
+// src/entry.js
+console.log(foo);
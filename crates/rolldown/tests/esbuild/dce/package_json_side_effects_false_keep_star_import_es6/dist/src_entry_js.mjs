@@ -0,0 +1,12 @@
+import { __export } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/index.js
+var demo_pkg_index_ns = {};
+__export(demo_pkg_index_ns, {
+	foo:() => foo
+});
+const foo = 123;
+console.log('hello');
+
+// src/entry.js
+console.log(demo_pkg_index_ns);
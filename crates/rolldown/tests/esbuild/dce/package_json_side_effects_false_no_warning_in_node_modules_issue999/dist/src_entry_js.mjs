@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/index.js
+console.log('unused import');
+
+// src/entry.js
+console.log('used import');
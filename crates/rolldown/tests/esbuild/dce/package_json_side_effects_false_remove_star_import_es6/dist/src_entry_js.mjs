@@ -0,0 +1,3 @@
+
+// src/entry.js
+console.log('unused import');
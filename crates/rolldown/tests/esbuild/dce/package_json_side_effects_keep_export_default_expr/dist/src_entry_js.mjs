@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/index.js
+var demo_pkg_index_default = exprWithSideEffects();
+
+// src/entry.js
+console.log(demo_pkg_index_default);
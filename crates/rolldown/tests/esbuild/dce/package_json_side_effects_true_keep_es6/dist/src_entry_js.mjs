@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/index.js
+console.log('hello');
+
+// src/entry.js
+console.log('unused import');
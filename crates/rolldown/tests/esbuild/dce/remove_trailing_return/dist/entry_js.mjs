@@ -0,0 +1,31 @@
+
+// entry.js
+function foo() {
+	if (a) 	b();
+
+	return;
+}
+function bar() {
+	if (a) 	b();
+
+	return KEEP_ME;
+}
+var entry_default = [foo, bar, function() {
+	if (a) 	b();
+
+	return;
+}, function() {
+	if (a) 	b();
+
+	return KEEP_ME;
+}, () => {
+	if (a) 	b();
+
+	return;
+}, () => {
+	if (a) 	b();
+
+	return KEEP_ME;
+},];
+
+export { entry_default as default };
\ No newline at end of file
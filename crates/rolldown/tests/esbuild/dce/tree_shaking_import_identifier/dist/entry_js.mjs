@@ -0,0 +1,23 @@
+import { __export } from "./$runtime$.mjs";
+
+// b.js
+var b_ns = {};
+__export(b_ns, {
+	Base:() => Base
+});
+class Base {
+}
+
+// a.js
+var a_ns = {};
+__export(a_ns, {
+	Keep:() => Keep,
+	REMOVE:() => REMOVE
+});
+class Keep extends b_ns.Base {
+}
+class REMOVE extends b_ns.Base {
+}
+
+// entry.js
+new a_ns.Keep();
@@ -0,0 +1,23 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// lib.js
+var keep1, keep2;
+var init_lib = __esmMin(() => {
+	keep1 = () => 'keep1';
+	keep2 = () => 'keep2';
+});
+
+// cjs.js
+var cjs_ns, cjs_default;
+var init_cjs = __esmMin(() => {
+	cjs_ns = {};
+	__export(cjs_ns, {
+		default:() => cjs_default
+	});
+	init_lib();
+	cjs_default = keep2();
+});
+
+// entry.js
+init_lib();
+console.log(keep1(), (init_cjs(),__toCommonJS(cjs_ns)));
@@ -0,0 +1,5 @@
+
+// entry.js
+function keep() {
+}
+keep();
@@ -0,0 +1,7 @@
+
+// entry.js
+let keep;
+ ++keep;
+ --keep;
+keep++;
+keep--;
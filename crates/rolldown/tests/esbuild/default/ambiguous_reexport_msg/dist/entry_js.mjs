@@ -0,0 +1,11 @@
+
+// a.js
+let a = 1, x$1 = 2;
+
+// b.js
+let b = 3;
+
+// c.js
+let c = 4, x = 5;
+
+export { a, b, c };
\ No newline at end of file
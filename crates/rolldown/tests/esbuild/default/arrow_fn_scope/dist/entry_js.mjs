@@ -0,0 +1,12 @@
+
+// entry.js
+tests = {
+	0:(x$1=y$2 => x$1 + y$2, y$1) => x$1 + y$1,
+	1:(y$1, x$1=y$2 => x$1 + y$2) => x$1 + y$1,
+	2:(x$1=(y$2=z$3 => x$1 + y$2 + z$3, z$2) => x$1 + y$2 + z$2, y$1, z$1) => x$1 + y$1 + z$1,
+	3:(y$1, z$1, x$1=(z$2, y$2=z$3 => x$1 + y$2 + z$3) => x$1 + y$2 + z$2) => x$1 + y$1 + z$1,
+	4:((x = y$1 => x + y$1,y),x + y),
+	5:((y,x = y$1 => x + y$1),x + y),
+	6:((x = (y$1=z$2 => x + y$1 + z$2, z$1) => x + y$1 + z$1,y,z),x + y + z),
+	7:((y,z,x = (z$1, y$1=z$2 => x + y$1 + z$2) => x + y$1 + z$1),x + y + z)
+};
@@ -0,0 +1,4 @@
+import "http://example.com/code.js";
+import "https://example.com/code.js";
+import "//example.com/code.js";
+import "data:application/javascript;base64,ZXhwb3J0IGRlZmF1bHQgMTIz";
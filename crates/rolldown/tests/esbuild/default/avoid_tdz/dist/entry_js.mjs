@@ -0,0 +1,13 @@
+
+// entry.js
+class Foo {
+	static foo=new Foo();
+
+}
+let foo = Foo.foo;
+console.log(foo);
+class Bar {
+}
+let bar = 123;
+
+export { Bar, bar };
\ No newline at end of file
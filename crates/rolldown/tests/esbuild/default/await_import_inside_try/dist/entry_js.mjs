@@ -0,0 +1,8 @@
+
+// entry.js
+async function main(name) {
+	try{
+		return await import(name);
+	}catch{
+	}}
+main('fs');
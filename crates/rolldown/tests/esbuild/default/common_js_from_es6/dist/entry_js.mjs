@@ -0,0 +1,30 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// foo.js
+function foo$1() {
+	return 'foo';
+}
+var foo_ns;
+var init_foo = __esmMin(() => {
+	foo_ns = {};
+	__export(foo_ns, {
+		foo:() => foo$1
+	});
+});
+
+// bar.js
+function bar$1() {
+	return 'bar';
+}
+var bar_ns;
+var init_bar = __esmMin(() => {
+	bar_ns = {};
+	__export(bar_ns, {
+		bar:() => bar$1
+	});
+});
+
+// entry.js
+const {foo} = (init_foo(),__toCommonJS(foo_ns));
+console.log(foo(), bar());
+const {bar} = (init_bar(),__toCommonJS(bar_ns));
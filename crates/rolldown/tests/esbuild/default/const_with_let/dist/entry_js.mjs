@@ -0,0 +1,15 @@
+
+// entry.js
+const a = 1;
+console.log(a);
+if (true) {
+	const b = 2;
+	console.log(b);
+}
+if (true) {
+	const b = 3;
+	unknownFn(b);
+}
+for (const c = x;;) console.log(c);
+for (const d in x) console.log(d);
+for (const e of  x) console.log(e);
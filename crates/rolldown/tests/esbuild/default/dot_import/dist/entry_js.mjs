@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// index.js
+var require_dot_import_index = __commonJSMin((exports, module) => {
+	exports.x = 123;
+});
+
+// entry.js
+var import_dot_import_index = __toESM(require_dot_import_index());
+console.log(import_dot_import_index.x);
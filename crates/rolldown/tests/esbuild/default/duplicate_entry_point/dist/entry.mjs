@@ -0,0 +1,3 @@
+
+// entry.js
+console.log(123);
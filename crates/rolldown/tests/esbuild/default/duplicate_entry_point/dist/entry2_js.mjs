@@ -0,0 +1 @@
+import "./entry.mjs";
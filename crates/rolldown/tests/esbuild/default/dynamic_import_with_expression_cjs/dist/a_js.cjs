@@ -0,0 +1,4 @@
+
+// a.js
+import('foo');
+import(foo());
@@ -0,0 +1,10 @@
+import { __esmMin, __toCommonJS } from "./$runtime$.mjs";
+
+// types.mjs
+var types_ns;
+var init_types = __esmMin(() => {
+	types_ns = {};
+});
+
+// entry.js
+console.log((init_types(),__toCommonJS(types_ns)));
@@ -0,0 +1,20 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.foo = function() {
+		return 'foo';
+	};
+});
+
+// bar.js
+var require_bar = __commonJSMin((exports, module) => {
+	exports.bar = function() {
+		return 'bar';
+	};
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+var import_bar = __toESM(require_bar());
+console.log((0,import_foo.foo)(), (0,import_bar.bar)());
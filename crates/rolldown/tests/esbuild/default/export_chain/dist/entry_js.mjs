@@ -0,0 +1,5 @@
+
+// bar.js
+const c = 123;
+
+export { c as a };
\ No newline at end of file
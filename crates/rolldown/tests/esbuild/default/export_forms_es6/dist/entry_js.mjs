@@ -0,0 +1,23 @@
+import { __export } from "./$runtime$.mjs";
+
+// a.js
+const abc = undefined;
+
+// b.js
+var b_ns = {};
+__export(b_ns, {
+	xyz:() => xyz
+});
+const xyz = null;
+
+// entry.js
+var entry_default = 123;
+var v = 234;
+let l = 234;
+const c = 234;
+function Fn() {
+}
+class Class {
+}
+
+export { Class as C, Class, Fn, abc, b_ns as b, c, entry_default as default, l, v };
\ No newline at end of file
@@ -0,0 +1,13 @@
+"use strict";
+
+
+// \0<runtime>
+var __defProp = Object.defineProperty;
+var __export = (target, all) => {
+	for (var name in all) 	__defProp(target, name, {
+		get:all[name],
+		enumerable:true
+	});
+};
+
+exports.__export = __export;
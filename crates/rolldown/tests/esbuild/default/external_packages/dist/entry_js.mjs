@@ -0,0 +1,8 @@
+import "pkg1";
+import "pkg2";
+
+// file.js
+console.log('file');
+
+// libs/pkg3.js
+console.log('pkg3');
@@ -0,0 +1,19 @@
+
+// entry.js
+let err;
+try{
+	const x = 1;
+	x = 2;
+}catch(e){
+	err = e;
+}assert(typeof err !== 'undefined');
+function foo() {
+	let err$1;
+	try{
+		const y = 1;
+		y = 2;
+	}catch(e){
+		err$1 = e;
+	}	assert(typeof err$1 !== 'undefined');
+}
+foo();
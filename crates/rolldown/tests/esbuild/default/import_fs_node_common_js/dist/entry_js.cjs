@@ -0,0 +1,8 @@
+"use strict";
+
+const { __toESM } = require("./$runtime$.cjs");
+const fs = __toESM(require("fs"));
+const { default: defaultValue, readFileSync } = __toESM(require("fs"));
+
+// entry.js
+console.log(fs, readFileSync, defaultValue);
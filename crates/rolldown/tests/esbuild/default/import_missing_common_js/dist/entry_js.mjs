@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.x = 123;
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+if (false) {
+	console.log((0,import_foo.default)(import_foo.x, import_foo.y));
+}
@@ -0,0 +1,7 @@
+
+// entry.js
+if (false) {
+	import(name).then(pass, fail);
+	import(name).then(pass).catch(fail);
+	import(name).catch(fail);
+}
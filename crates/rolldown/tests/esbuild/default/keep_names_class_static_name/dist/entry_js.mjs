@@ -0,0 +1,74 @@
+
+// entry.js
+class A {
+	static foo;
+
+}
+class B {
+	static name;
+
+}
+class C {
+	static name(){
+	}
+}
+class D {
+	static get name(){
+	}
+}
+class E {
+	static set name(x){
+	}
+}
+class F {
+	static ['name']=0;
+
+}
+let a = class a$1 {
+	static foo;
+
+};
+let b = class b$1 {
+	static name;
+
+};
+let c = class c$1 {
+	static name(){
+	}
+};
+let d = class d$1 {
+	static get name(){
+	}
+};
+let e = class e$1 {
+	static set name(x){
+	}
+};
+let f = class f$1 {
+	static ['name']=0;
+
+};
+let a2 = class {
+	static foo;
+
+};
+let b2 = class {
+	static name;
+
+};
+let c2 = class {
+	static name(){
+	}
+};
+let d2 = class {
+	static get name(){
+	}
+};
+let e2 = class {
+	static set name(x){
+	}
+};
+let f2 = class {
+	static ['name']=0;
+
+};
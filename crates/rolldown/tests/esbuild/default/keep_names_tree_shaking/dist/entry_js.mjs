@@ -0,0 +1,14 @@
+
+// entry.js
+function fnStmtKeep() {
+}
+x = fnStmtKeep;
+let fnExprKeep = function keep() {
+};
+x = fnExprKeep;
+class clsStmtKeep {
+}
+new clsStmtKeep();
+let clsExprKeep = class keep {
+};
+new clsExprKeep();
@@ -0,0 +1,7 @@
+
+// \0<runtime>
+var __commonJSMin = (cb, mod) => () => (mod || cb((mod = {
+	exports:{}
+}).exports, mod),mod.exports);
+
+export { __commonJSMin };
\ No newline at end of file
@@ -0,0 +1,15 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	module.exports = function() {
+		return 123;
+	};
+});
+
+// entry.js
+function nestedScope() {
+	const fn = require_foo();
+	console.log(fn());
+}
+nestedScope();
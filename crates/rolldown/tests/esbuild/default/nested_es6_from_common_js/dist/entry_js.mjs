@@ -0,0 +1,14 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.fn = function() {
+		return 123;
+	};
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+(() => {
+	console.log((0,import_foo.fn)());
+})();
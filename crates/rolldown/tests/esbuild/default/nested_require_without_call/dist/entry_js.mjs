@@ -0,0 +1,6 @@
+
+// entry.js
+(() => {
+	const req = require;
+	req('./entry');
+})();
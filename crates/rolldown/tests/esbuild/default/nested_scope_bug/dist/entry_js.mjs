@@ -0,0 +1,12 @@
+
+// entry.js
+(() => {
+	function a() {
+		b();
+	}
+	{
+		var b = () => {
+		};
+	}
+	a();
+})();
@@ -0,0 +1,13 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	class Foo {
+	}
+	module.exports = {
+		Foo
+	};
+});
+
+// entry.js
+new (require_foo()).Foo();
@@ -0,0 +1,3 @@
+
+// d.js
+console.log('d');
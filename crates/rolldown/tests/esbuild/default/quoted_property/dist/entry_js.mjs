@@ -0,0 +1,4 @@
+import * as ns from "ext";
+
+// entry.js
+console.log(ns.mustBeUnquoted, ns['mustBeQuoted']);
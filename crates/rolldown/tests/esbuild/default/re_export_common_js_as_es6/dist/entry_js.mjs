@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.bar = 123;
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+
+var bar = import_foo.bar;
+export { bar };
\ No newline at end of file
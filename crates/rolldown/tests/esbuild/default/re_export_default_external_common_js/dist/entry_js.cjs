@@ -0,0 +1,14 @@
+"use strict";
+
+const { __export, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+const { default: foo } = __toESM(require("foo"));
+const { default: bar } = __toESM(require("bar"));
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	bar:() => bar,
+	foo:() => foo
+});
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
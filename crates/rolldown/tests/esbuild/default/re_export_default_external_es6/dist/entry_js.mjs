@@ -0,0 +1,4 @@
+import { default as foo } from "foo";
+import { default as bar } from "bar";
+
+export { bar, foo };
\ No newline at end of file
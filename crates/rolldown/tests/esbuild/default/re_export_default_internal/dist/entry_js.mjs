@@ -0,0 +1,8 @@
+
+// foo.js
+var foo_default = 'foo';
+
+// bar.js
+var bar_default = 'bar';
+
+export { bar_default as bar, foo_default as foo };
\ No newline at end of file
@@ -0,0 +1,9 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// dir/index.js
+var require_dir_index = __commonJSMin((exports, module) => {
+	module.exports = 123;
+});
+
+// entry.js
+console.log(require_dir_index());
@@ -0,0 +1,6 @@
+
+// dir/index.js
+var dir_index_default = 123;
+
+// entry.js
+console.log(dir_index_default);
@@ -0,0 +1,15 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// is-main.js
+var require_is_main = __commonJSMin((exports, module) => {
+	module.exports = require.main === module;
+});
+
+// entry.js
+var require_entry = __commonJSMin((exports, module) => {
+	console.log('is main:', require.main === module);
+	console.log(require_is_main());
+	console.log('cache:', require.cache);
+});
+
+export default require_entry();
@@ -0,0 +1,9 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// index.js
+var require_require_parent_dir_common_js_index = __commonJSMin((exports, module) => {
+	module.exports = 123;
+});
+
+// dir/entry.js
+console.log(require_require_parent_dir_common_js_index());
@@ -0,0 +1,6 @@
+
+// index.js
+var require_parent_dir_es6_index_default = 123;
+
+// dir/entry.js
+console.log(require_parent_dir_es6_index_default);
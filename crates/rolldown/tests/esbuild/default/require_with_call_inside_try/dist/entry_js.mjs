@@ -0,0 +1,13 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// entry.js
+var require_entry = __commonJSMin((exports, module) => {
+	try{
+		const supportsColor = require('supports-color');
+		if (supportsColor && (supportsColor.stderr || supportsColor).level >= 2) {
+			exports.colors = [];
+		}
+	}catch(error){
+	}});
+
+export default require_entry();
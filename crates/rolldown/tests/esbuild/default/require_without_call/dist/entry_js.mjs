@@ -0,0 +1,6 @@
+
+// entry.js
+if (false) {
+	const req = require;
+	req('./entry');
+}
@@ -0,0 +1,9 @@
+
+// entry.js
+try{
+	oldLocale = globalLocale._abbr;
+	var aliasedRequire = require;
+	aliasedRequire('./locale/' + name);
+	getSetGlobalLocale(oldLocale);
+}catch(e){
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+
+// entry.js
+var entry_default = {
+	foo_:0,
+	_bar_:1
+};
+
+export { entry_default as default };
\ No newline at end of file
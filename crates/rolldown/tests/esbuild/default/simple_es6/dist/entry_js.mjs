@@ -0,0 +1,8 @@
+
+// foo.js
+function fn() {
+	return 123;
+}
+
+// entry.js
+console.log(fn());
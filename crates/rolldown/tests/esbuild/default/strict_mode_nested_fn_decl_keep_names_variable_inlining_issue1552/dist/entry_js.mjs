@@ -0,0 +1,14 @@
+
+// entry.js
+function outer() {
+	{
+		function inner() {
+			return Math.random();
+		}
+		const x = inner();
+		console.log(x);
+	}
+}
+outer();
+
+export { outer };
\ No newline at end of file
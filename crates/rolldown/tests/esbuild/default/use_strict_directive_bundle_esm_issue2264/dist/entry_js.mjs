@@ -0,0 +1,5 @@
+
+// entry.js
+let a = 1;
+
+export { a };
\ No newline at end of file
@@ -0,0 +1,9 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	exports.foo = process;
+});
+
+// entry.js
+console.log(require_cjs());
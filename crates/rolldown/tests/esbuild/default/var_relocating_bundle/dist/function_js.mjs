@@ -0,0 +1,8 @@
+
+// function.js
+function x() {
+	var a;
+	for (var b; 0;) 	;	for (var e of  []) 	;	for (var {f, x:[g]} of  []) 	;	for (var h in {}) 	;	for (var {j, x:[k]} in {}) 	;	function l() {
+	}
+}
+x();
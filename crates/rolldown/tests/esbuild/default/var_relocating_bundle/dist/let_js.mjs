@@ -0,0 +1,5 @@
+
+// let.js
+if (true) {
+	let a;
+	for (let b; 0;) 	;	for (let e of  []) 	;	for (let {f, x:[g]} of  []) 	;	for (let h in {}) 	;	for (let {j, x:[k]} in {}) 	;}
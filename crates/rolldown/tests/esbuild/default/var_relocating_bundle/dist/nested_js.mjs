@@ -0,0 +1,7 @@
+
+// nested.js
+if (true) {
+	var a;
+	for (var b; 0;) 	;	for (var e of  []) 	;	for (var {f, x:[g]} of  []) 	;	for (var h in {}) 	;	for (var {j, x:[k]} in {}) 	;	function l() {
+	}
+}
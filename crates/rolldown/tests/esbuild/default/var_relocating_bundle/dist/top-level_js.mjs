@@ -0,0 +1,5 @@
+
+// top-level.js
+var a;
+for (var b; 0;) ;for (var e of  []) ;for (var {f, x:[g]} of  []) ;for (var h in {}) ;for (var {j, x:[k]} in {}) ;function l() {
+}
@@ -0,0 +1,43 @@
+"use strict";
+
+
+// \0<runtime>
+var __create = Object.create;
+var __defProp = Object.defineProperty;
+var __getOwnPropDesc = Object.getOwnPropertyDescriptor;
+var __getOwnPropNames = Object.getOwnPropertyNames;
+var __getProtoOf = Object.getPrototypeOf;
+var __hasOwnProp = Object.prototype.hasOwnProperty;
+var __commonJSMin = (cb, mod) => () => (mod || cb((mod = {
+	exports:{}
+}).exports, mod),mod.exports);
+var __export = (target, all) => {
+	for (var name in all) 	__defProp(target, name, {
+		get:all[name],
+		enumerable:true
+	});
+};
+var __copyProps = (to, from, except, desc) => {
+	if (from && typeof from === 'object' || typeof from === 'function') 	for (var keys = __getOwnPropNames(from), i = 0, n = keys.length, key; i < n; i++) 	{
+		key = keys[i];
+		if ( !__hasOwnProp.call(to, key) && key !== except) 		__defProp(to, key, {
+			get:(k => from[k]).bind(null, key),
+			enumerable: !(desc = __getOwnPropDesc(from, key)) || desc.enumerable
+		});
+
+	}
+
+	return to;
+};
+var __toESM = (mod, isNodeMode, target) => (target = mod != null ? __create(__getProtoOf(mod)) : {},__copyProps(isNodeMode ||  !mod ||  !mod.__esModule ? __defProp(target, 'default', {
+	value:mod,
+	enumerable:true
+}) : target, mod));
+var __toCommonJS = mod => __copyProps(__defProp({}, '__esModule', {
+	value:true
+}), mod);
+
+exports.__commonJSMin = __commonJSMin;
+exports.__export = __export;
+exports.__toCommonJS = __toCommonJS;
+exports.__toESM = __toESM;
@@ -0,0 +1,15 @@
+const { __commonJSMin, __export, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.foo = 123;
+});
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	bar:() => import_foo.bar
+});
+var import_foo = __toESM(require_foo());
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
@@ -0,0 +1,17 @@
+const { __commonJSMin, __export, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.foo = 123;
+});
+
+// bar.js
+var import_foo = __toESM(require_foo());
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	y:() => import_foo.x
+});
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
@@ -0,0 +1,13 @@
+"use strict";
+
+const { __export, __toCommonJS } = require("./$runtime$.cjs");
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	foo:() => foo,
+	ns:() => entry_ns
+});
+const foo = 123;
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
@@ -0,0 +1,11 @@
+import { __export } from "./$runtime$.mjs";
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	foo:() => foo,
+	ns:() => entry_ns
+});
+const foo = 123;
+
+export { foo, entry_ns as ns };
\ No newline at end of file
@@ -0,0 +1,12 @@
+"use strict";
+
+const { __export, __toCommonJS } = require("./$runtime$.cjs");
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	foo:() => foo
+});
+const foo = 123;
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
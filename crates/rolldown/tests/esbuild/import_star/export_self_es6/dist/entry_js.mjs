@@ -0,0 +1,5 @@
+
+// entry.js
+const foo = 123;
+
+export { foo };
\ No newline at end of file
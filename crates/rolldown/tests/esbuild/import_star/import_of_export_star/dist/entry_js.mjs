@@ -0,0 +1,10 @@
+
+// bar.js
+statement();
+statement();
+statement();
+statement();
+const bar = 123;
+
+// entry.js
+console.log(bar);
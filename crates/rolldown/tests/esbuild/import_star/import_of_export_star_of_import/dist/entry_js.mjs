@@ -0,0 +1,12 @@
+
+// baz.js
+const value = 123;
+
+// foo.js
+statement();
+statement();
+statement();
+statement();
+
+// entry.js
+console.log(value);
@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// entry.js
+var require_entry = __commonJSMin((exports, module) => {
+	var import_entry = __toESM(require_entry());
+	exports.foo = 123;
+	console.log(import_entry.foo);
+});
+
+export default require_entry();
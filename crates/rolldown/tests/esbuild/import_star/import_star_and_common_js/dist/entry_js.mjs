@@ -0,0 +1,16 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// foo.js
+var foo_ns, foo;
+var init_foo = __esmMin(() => {
+	foo_ns = {};
+	__export(foo_ns, {
+		foo:() => foo
+	});
+	foo = 123;
+});
+
+// entry.js
+init_foo();
+const ns2 = (init_foo(),__toCommonJS(foo_ns));
+console.log(foo_ns.foo, ns2.foo);
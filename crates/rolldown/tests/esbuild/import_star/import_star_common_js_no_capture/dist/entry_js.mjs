@@ -0,0 +1,11 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.foo = 123;
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+let foo = 234;
+console.log(import_foo.foo, import_foo.foo, foo);
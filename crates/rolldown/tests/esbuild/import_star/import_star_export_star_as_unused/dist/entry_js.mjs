@@ -0,0 +1,12 @@
+import { __export } from "./$runtime$.mjs";
+
+// foo.js
+var foo_ns = {};
+__export(foo_ns, {
+	foo:() => foo$1
+});
+const foo$1 = 123;
+
+// entry.js
+let foo = 234;
+console.log(foo);
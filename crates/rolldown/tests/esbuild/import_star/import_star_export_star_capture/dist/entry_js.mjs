@@ -0,0 +1,14 @@
+import { __export } from "./$runtime$.mjs";
+
+// foo.js
+const foo$1 = 123;
+
+// bar.js
+var bar_ns = {};
+__export(bar_ns, {
+	foo:() => foo$1
+});
+
+// entry.js
+let foo = 234;
+console.log(bar_ns, bar_ns.foo, foo);
@@ -0,0 +1,4 @@
+
+// entry.js
+let foo = 234;
+console.log(foo);
@@ -0,0 +1,13 @@
+import { __export } from "./$runtime$.mjs";
+
+// folders/child/foo.js
+const foo = () => 'hi there';
+
+// folders/index.js
+var folders_index_ns = {};
+__export(folders_index_ns, {
+	foo:() => foo
+});
+
+// entry.js
+console.log(JSON.stringify(folders_index_ns));
@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.x = 123;
+});
+
+// entry.js
+var import_foo = __toESM(require_foo());
+console.log(import_foo, import_foo.foo);
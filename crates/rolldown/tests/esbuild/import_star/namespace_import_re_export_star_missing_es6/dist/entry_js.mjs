@@ -0,0 +1,13 @@
+import { __export } from "./$runtime$.mjs";
+
+// bar.js
+const x = 123;
+
+// foo.js
+var foo_ns = {};
+__export(foo_ns, {
+	x:() => x
+});
+
+// entry.js
+console.log(foo_ns, foo_ns.foo);
@@ -0,0 +1,11 @@
+import { __export } from "./$runtime$.mjs";
+
+// foo.js
+var foo_ns = {};
+__export(foo_ns, {
+	foo:() => foo,
+	ns:() => foo_ns
+});
+const foo = 123;
+
+export { foo };
\ No newline at end of file
@@ -0,0 +1,11 @@
+import { __export } from "./$runtime$.mjs";
+
+// bar.js
+var bar_ns = {};
+__export(bar_ns, {
+	x:() => x
+});
+const x = 123;
+
+// entry.js
+console.log(bar_ns, bar_ns.foo);
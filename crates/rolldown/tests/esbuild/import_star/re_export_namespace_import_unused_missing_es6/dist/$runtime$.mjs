@@ -0,0 +1,11 @@
+
+// \0<runtime>
+var __defProp = Object.defineProperty;
+var __export = (target, all) => {
+	for (var name in all) 	__defProp(target, name, {
+		get:all[name],
+		enumerable:true
+	});
+};
+
+export { __export };
\ No newline at end of file
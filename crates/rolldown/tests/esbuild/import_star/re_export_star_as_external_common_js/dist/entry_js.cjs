@@ -0,0 +1,12 @@
+"use strict";
+
+const { __export, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+const out = __toESM(require("foo"));
+
+// entry.js
+var entry_ns = {};
+__export(entry_ns, {
+	out:() => out
+});
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
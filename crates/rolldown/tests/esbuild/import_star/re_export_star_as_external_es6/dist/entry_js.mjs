@@ -0,0 +1,3 @@
+import * as out from "foo";
+
+export { out };
\ No newline at end of file
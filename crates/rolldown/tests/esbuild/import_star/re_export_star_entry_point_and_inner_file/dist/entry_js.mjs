@@ -0,0 +1,18 @@
+import { __export } from "./$runtime$.mjs";
+
+// a.js
+const A = 1;
+const B = '2';
+
+// b.js
+const C = 1;
+const D = '2';
+
+// inner.js
+var inner_ns = {};
+__export(inner_ns, {
+	C:() => C,
+	D:() => D
+});
+
+export { A, B, inner_ns as inner };
\ No newline at end of file
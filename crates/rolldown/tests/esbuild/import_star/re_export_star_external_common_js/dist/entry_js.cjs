@@ -0,0 +1,10 @@
+"use strict";
+
+const { __reExport, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+const import_foo = __toESM(require("foo"));
+
+// entry.js
+var entry_ns = {};
+__reExport(entry_ns, import_foo);
+
+module.exports = __toCommonJS(entry_ns)
\ No newline at end of file
@@ -0,0 +1,3 @@
+import "foo";
+
+export * from "foo";
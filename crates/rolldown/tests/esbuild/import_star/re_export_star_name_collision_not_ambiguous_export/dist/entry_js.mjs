@@ -0,0 +1,5 @@
+
+// c.js
+let x = 1, y = 2;
+
+export { x, y };
\ No newline at end of file
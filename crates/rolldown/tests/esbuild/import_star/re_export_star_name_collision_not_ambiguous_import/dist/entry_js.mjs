@@ -0,0 +1,6 @@
+
+// c.js
+let x = 1, y = 2;
+
+// entry.js
+console.log(x, y);
@@ -0,0 +1,6 @@
+
+// a.js
+let x = 1;
+
+// entry.js
+console.log(x);
@@ -0,0 +1,6 @@
+
+// b.js
+let x = 1;
+
+// entry.js
+console.log(x);
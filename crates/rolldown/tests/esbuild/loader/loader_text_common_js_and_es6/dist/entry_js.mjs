@@ -0,0 +1,18 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// y.txt
+var y_default = 'y';
+
+// x.txt
+var x_ns, x_default;
+var init_x = __esmMin(() => {
+	x_ns = {};
+	__export(x_ns, {
+		default:() => x_default
+	});
+	x_default = 'x';
+});
+
+// entry.js
+const x_txt = (init_x(),__toCommonJS(x_ns));
+console.log(x_txt, y_default);
@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// entry.js
+var require_entry = __commonJSMin((exports, module) => {
+	exports.foo = async() => this;
+});
+
+export default require_entry();
@@ -0,0 +1,9 @@
+
+// other.js
+let bar = async() => {
+};
+
+// entry.js
+let foo = async() => void 0;
+
+export { bar, foo };
\ No newline at end of file
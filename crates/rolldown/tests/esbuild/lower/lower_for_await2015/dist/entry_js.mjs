@@ -0,0 +1,13 @@
+
+// entry.js
+var entry_default = [async() => {
+	for  await(x of  y) 	z(x);
+}, async() => {
+	for  await(x.y of  y) 	z(x);
+}, async() => {
+	for  await(let x$1 of  y) 	z(x$1);
+}, async() => {
+	for  await(const x$1 of  y) 	z(x$1);
+},];
+
+export { entry_default as default };
\ No newline at end of file
@@ -0,0 +1,11 @@
+
+// entry.js
+class A {
+	#a;
+
+	f(){
+		this.#a ??= 1;
+	}
+}
+
+export { A };
\ No newline at end of file
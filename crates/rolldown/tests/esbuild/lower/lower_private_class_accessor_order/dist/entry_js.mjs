@@ -0,0 +1,10 @@
+
+// entry.js
+class Foo {
+	bar=this.#foo;
+
+	get #foo(){
+		return 123;
+	}
+}
+console.log(new Foo().bar === 123);
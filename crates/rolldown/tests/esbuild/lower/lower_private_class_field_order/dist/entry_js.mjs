@@ -0,0 +1,9 @@
+
+// entry.js
+class Foo {
+	#foo=123;
+
+	bar=this.#foo;
+
+}
+console.log(new Foo().bar === 123);
@@ -0,0 +1,18 @@
+
+// entry.js
+class Foo {
+	static bar=Foo.#foo;
+
+	static get #foo(){
+		return 123;
+	}
+}
+console.log(Foo.bar === 123);
+class FooThis {
+	static bar=this.#foo;
+
+	static get #foo(){
+		return 123;
+	}
+}
+console.log(FooThis.bar === 123);
@@ -0,0 +1,16 @@
+
+// entry.js
+class Foo {
+	static #foo=123;
+
+	static bar=Foo.#foo;
+
+}
+console.log(Foo.bar === 123);
+class FooThis {
+	static #foo=123;
+
+	static bar=this.#foo;
+
+}
+console.log(FooThis.bar === 123);
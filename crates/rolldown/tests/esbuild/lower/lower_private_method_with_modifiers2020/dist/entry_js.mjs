@@ -0,0 +1,18 @@
+
+// entry.js
+class Foo {
+	*#g(){
+	}
+	async #a(){
+	}
+	async *#ag(){
+	}
+	static *#sg(){
+	}
+	static async #sa(){
+	}
+	static async *#sag(){
+	}
+}
+
+export { Foo };
\ No newline at end of file
@@ -0,0 +1,13 @@
+
+// entry.js
+class Foo extends Object {
+	static FOO;
+
+	constructor(){
+		super();
+	}
+	#foo;
+
+}
+
+export { Foo };
\ No newline at end of file
@@ -0,0 +1,7 @@
+
+// entry.js
+function foo(RegExp) {
+	return new RegExp(/./d, 'd');
+}
+
+export { foo };
\ No newline at end of file
@@ -0,0 +1,15 @@
+
+// src/demo-pkg/no-ext-browser/index.js
+let browser$1 = 'browser';
+
+// src/demo-pkg/no-ext/index.js
+let node = 'node';
+
+// src/demo-pkg/ext-browser/index.js
+let browser = 'browser';
+
+// src/entry.js
+console.log(browser$1);
+console.log(node);
+console.log(browser);
+console.log(browser);
@@ -0,0 +1,9 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// src/node_modules/pkg/sub/foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	require('sub');
+});
+
+// src/entry.js
+require_foo();
@@ -0,0 +1,14 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// src/node_modules/sub/index.js
+var require_sub_index = __commonJSMin((exports, module) => {
+	works();
+});
+
+// src/node_modules/pkg/sub/foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	require_sub_index();
+});
+
+// src/entry.js
+require_foo();
@@ -0,0 +1,15 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// node_modules/component-indexof/index.js
+var require_component_indexof_index = __commonJSMin((exports, module) => {
+	module.exports = function() {
+		return 234;
+	};
+});
+
+// node_modules/component-classes/index.js
+try{
+	var index = require_component_indexof_index();
+}catch(err){
+	var index = require_component_indexof_index();
+}
\ No newline at end of file
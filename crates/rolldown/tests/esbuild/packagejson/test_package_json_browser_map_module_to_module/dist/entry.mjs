@@ -0,0 +1,20 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/node-pkg-browser/index.js
+var require_node_pkg_browser_index = __commonJSMin((exports, module) => {
+	module.exports = function() {
+		return 123;
+	};
+});
+
+// node_modules/demo-pkg/index.js
+var require_demo_pkg_index = __commonJSMin((exports, module) => {
+	const fn$1 = require_node_pkg_browser_index();
+	module.exports = function() {
+		return fn$1();
+	};
+});
+
+// src/entry.js
+var import_demo_pkg_index = __toESM(require_demo_pkg_index());
+console.log((0,import_demo_pkg_index.default)());
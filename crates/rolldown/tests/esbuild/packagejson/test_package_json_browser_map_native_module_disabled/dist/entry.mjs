@@ -0,0 +1,17 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// (ignored) node_modules/demo-pkg
+var require_demo_pkg = __commonJSMin((exports, module) => {
+});
+
+// node_modules/demo-pkg/index.js
+var require_demo_pkg_index = __commonJSMin((exports, module) => {
+	const fs = require_demo_pkg();
+	module.exports = function() {
+		return fs.readFile();
+	};
+});
+
+// src/entry.js
+var import_demo_pkg_index = __toESM(require_demo_pkg_index());
+console.log((0,import_demo_pkg_index.default)());
@@ -0,0 +1,17 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// (ignored) node_modules/demo-pkg/util-node.js
+var require_util_node = __commonJSMin((exports, module) => {
+});
+
+// node_modules/demo-pkg/main.js
+var require_main = __commonJSMin((exports, module) => {
+	const util = require_util_node();
+	module.exports = function(obj) {
+		return util.inspect(obj);
+	};
+});
+
+// src/entry.js
+var import_main = __toESM(require_main());
+console.log((0,import_main.default)());
@@ -0,0 +1,18 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/util-browser/index.js
+var require_util_browser_index = __commonJSMin((exports, module) => {
+	module.exports = 'util-browser';
+});
+
+// node_modules/demo-pkg/main.js
+var require_main = __commonJSMin((exports, module) => {
+	const util = require_util_browser_index();
+	module.exports = function() {
+		return ['main', util];
+	};
+});
+
+// src/entry.js
+var import_main = __toESM(require_main());
+console.log((0,import_main.default)());
@@ -0,0 +1,15 @@
+
+// src/demo-pkg/no-ext-browser.js
+let browser$1 = 'browser';
+
+// src/demo-pkg/no-ext.js
+let node = 'node';
+
+// src/demo-pkg/ext-browser.js
+let browser = 'browser';
+
+// src/entry.js
+console.log(browser$1);
+console.log(node);
+console.log(browser);
+console.log(browser);
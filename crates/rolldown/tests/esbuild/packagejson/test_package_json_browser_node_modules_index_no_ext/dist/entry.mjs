@@ -0,0 +1,15 @@
+
+// node_modules/demo-pkg/no-ext-browser/index.js
+let browser$1 = 'browser';
+
+// node_modules/demo-pkg/no-ext/index.js
+let node = 'node';
+
+// node_modules/demo-pkg/ext-browser/index.js
+let browser = 'browser';
+
+// src/entry.js
+console.log(browser$1);
+console.log(node);
+console.log(browser);
+console.log(browser);
@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/main.browser.js
+var require_main_browser = __commonJSMin((exports, module) => {
+	module.exports = function() {
+		return 123;
+	};
+});
+
+// src/entry.js
+var import_main_browser = __toESM(require_main_browser());
+console.log((0,import_main_browser.default)());
@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/browser.js
+var require_browser = __commonJSMin((exports, module) => {
+	module.exports = function() {
+		return 123;
+	};
+});
+
+// src/entry.js
+var import_browser = __toESM(require_browser());
+console.log((0,import_browser.default)());
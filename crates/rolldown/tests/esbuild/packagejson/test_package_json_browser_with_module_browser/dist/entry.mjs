@@ -0,0 +1,8 @@
+
+// node_modules/demo-pkg/main.browser.esm.js
+function main_browser_esm_default() {
+	return 123;
+}
+
+// src/entry.js
+console.log(main_browser_esm_default());
@@ -0,0 +1,9 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// (ignored) 
+var require_test_package_json_disabled_type_module_issue3367 = __commonJSMin((exports, module) => {
+});
+
+// entry.js
+var import_test_package_json_disabled_type_module_issue3367 = __toESM(require_test_package_json_disabled_type_module_issue3367());
+(0,import_test_package_json_disabled_type_module_issue3367.default)();
@@ -0,0 +1,18 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/module.browser.js
+var module_browser_ns, module_browser_default;
+var init_module_browser = __esmMin(() => {
+	module_browser_ns = {};
+	__export(module_browser_ns, {
+		default:() => module_browser_default
+	});
+	module_browser_default = 'browser module';
+});
+
+// src/test-main.js
+console.log((init_module_browser(),__toCommonJS(module_browser_ns)));
+
+// src/test-module.js
+init_module_browser();
+console.log(module_browser_default);
@@ -0,0 +1,18 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/module.js
+var module_ns, module_default;
+var init_module = __esmMin(() => {
+	module_ns = {};
+	__export(module_ns, {
+		default:() => module_default
+	});
+	module_default = 'module';
+});
+
+// src/test-index.js
+console.log((init_module(),__toCommonJS(module_ns)));
+
+// src/test-module.js
+init_module();
+console.log(module_default);
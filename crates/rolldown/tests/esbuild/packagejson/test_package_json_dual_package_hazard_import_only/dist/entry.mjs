@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/module.js
+var module_default = 'module';
+
+// src/entry.js
+console.log(module_default);
@@ -0,0 +1,3 @@
+
+// node_modules/pkg/browser.js
+console.log('SUCCESS');
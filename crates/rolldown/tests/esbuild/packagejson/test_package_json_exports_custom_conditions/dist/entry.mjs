@@ -0,0 +1,3 @@
+
+// node_modules/pkg1/custom2.js
+console.log('SUCCESS');
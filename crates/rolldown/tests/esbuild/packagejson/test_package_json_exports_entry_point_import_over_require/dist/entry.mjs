@@ -0,0 +1,3 @@
+
+// node_modules/pkg/import.js
+console.log('SUCCESS');
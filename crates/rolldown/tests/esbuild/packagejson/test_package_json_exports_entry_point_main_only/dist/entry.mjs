@@ -0,0 +1,3 @@
+
+// node_modules/pkg/main.js
+console.log('SUCCESS');
@@ -0,0 +1,3 @@
+
+// node_modules/pkg/module.js
+console.log('SUCCESS');
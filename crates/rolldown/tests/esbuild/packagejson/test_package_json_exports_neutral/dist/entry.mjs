@@ -0,0 +1,3 @@
+
+// node_modules/pkg/default.js
+console.log('SUCCESS');
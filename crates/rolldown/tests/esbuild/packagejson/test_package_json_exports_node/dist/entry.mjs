@@ -0,0 +1,3 @@
+
+// node_modules/pkg/node.js
+console.log('SUCCESS');
@@ -0,0 +1,3 @@
+
+// node_modules/pkg1/dir/bar.js
+console.log('SUCCESS');
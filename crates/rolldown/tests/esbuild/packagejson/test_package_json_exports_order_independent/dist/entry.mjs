@@ -0,0 +1,6 @@
+
+// node_modules/pkg1/2/bar.js
+console.log('SUCCESS');
+
+// node_modules/pkg2/1/bar.js
+console.log('SUCCESS');
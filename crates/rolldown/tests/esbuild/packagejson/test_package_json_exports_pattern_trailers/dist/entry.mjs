@@ -0,0 +1,9 @@
+
+// node_modules/pkg/dir/baz-foo.js
+console.log('works');
+
+// node_modules/pkg2/public/abc.js
+console.log('abc');
+
+// node_modules/pkg2/public/xyz.js
+console.log('xyz');
@@ -0,0 +1,9 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// node_modules/pkg/require.js
+var require_require = __commonJSMin((exports, module) => {
+	console.log('SUCCESS');
+});
+
+// src/entry.js
+require_require();
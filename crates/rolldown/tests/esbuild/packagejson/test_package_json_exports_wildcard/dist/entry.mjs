@@ -0,0 +1,6 @@
+
+// node_modules/pkg1/file.js
+console.log('SUCCESS');
+
+// node_modules/pkg1/file2.js
+console.log('SUCCESS');
@@ -0,0 +1,14 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// src/foo-require.js
+var require_foo_require = __commonJSMin((exports, module) => {
+	module.exports = 'foo';
+});
+
+// src/index.js
+var require_src_index = __commonJSMin((exports, module) => {
+	module.exports = 'index';
+	console.log(require_src_index(), require_foo_require());
+});
+
+export default require_src_index();
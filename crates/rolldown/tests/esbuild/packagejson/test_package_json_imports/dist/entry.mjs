@@ -0,0 +1,12 @@
+
+// src/a.js
+console.log('a.js');
+
+// src/b.js
+console.log('b.js');
+
+// src/some-star/c.js
+console.log('c.js');
+
+// src/some-slash/d.js
+console.log('d.js');
@@ -0,0 +1,6 @@
+
+// foo1/bar/index.js
+console.log(bar);
+
+// foo2/bar/index.js
+console.log(bar);
@@ -0,0 +1,12 @@
+
+// src/node_modules/pkg/a.js
+console.log('a.js');
+
+// src/node_modules/pkg/b.js
+console.log('b.js');
+
+// src/node_modules/pkg/some-star/c.js
+console.log('c.js');
+
+// src/node_modules/pkg/some-slash/d.js
+console.log('d.js');
@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// node_modules/demo-pkg/a.js
+var require_a = __commonJSMin((exports, module) => {
+	module.exports = 'a';
+});
+
+// src/entry.js
+var import_a = __toESM(require_a());
+console.log(import_a.default);
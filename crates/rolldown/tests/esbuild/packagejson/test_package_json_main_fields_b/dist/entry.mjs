@@ -0,0 +1,6 @@
+
+// node_modules/demo-pkg/b.js
+var b_default = 'b';
+
+// src/entry.js
+console.log(b_default);
@@ -0,0 +1,6 @@
+import { default as fs } from "node:fs";
+import { default as http } from "node:http";
+
+// entry.js
+fs.readFileSync();
+http.createServer();
@@ -0,0 +1,5 @@
+import { foo, setFoo } from "./shared.mjs";
+
+// a.js
+setFoo(123);
+console.log(foo);
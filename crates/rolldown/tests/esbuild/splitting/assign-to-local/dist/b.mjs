@@ -0,0 +1,4 @@
+import { foo } from "./shared.mjs";
+
+// b.js
+console.log(foo);
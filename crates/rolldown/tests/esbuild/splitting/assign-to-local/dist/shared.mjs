@@ -0,0 +1,8 @@
+
+// shared.js
+let foo;
+function setFoo(value) {
+	foo = value;
+}
+
+export { foo, setFoo };
\ No newline at end of file
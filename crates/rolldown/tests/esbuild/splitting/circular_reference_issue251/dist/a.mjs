@@ -0,0 +1 @@
+export { p, q } from "./a~1.mjs";
@@ -0,0 +1,8 @@
+
+// b.js
+var q = 6;
+
+// a.js
+var p = 5;
+
+export { p, q };
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { setValue } from "./shared.mjs";
+
+// a.js
+setValue(123);
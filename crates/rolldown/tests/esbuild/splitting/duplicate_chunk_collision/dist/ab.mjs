@@ -0,0 +1,3 @@
+
+// ab.js
+console.log(123);
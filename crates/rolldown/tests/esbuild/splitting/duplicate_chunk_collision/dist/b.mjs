@@ -0,0 +1 @@
+import "./ab.mjs";
@@ -0,0 +1 @@
+import "./d~1.mjs";
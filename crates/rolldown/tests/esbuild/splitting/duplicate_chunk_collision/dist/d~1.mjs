@@ -0,0 +1,3 @@
+
+// cd.js
+console.log(123);
@@ -0,0 +1,5 @@
+
+// foo.js
+let bar = 123;
+
+export { bar };
\ No newline at end of file
@@ -0,0 +1,3 @@
+
+// main.js
+import('./foo.mjs').then(({bar}) => console.log(bar));
@@ -0,0 +1,3 @@
+import { require_foo } from "./foo~1.mjs";
+
+export default require_foo();
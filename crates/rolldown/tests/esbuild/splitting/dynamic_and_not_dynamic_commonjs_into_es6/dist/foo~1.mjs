@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+	exports.bar = 123;
+});
+
+export { require_foo };
\ No newline at end of file
@@ -0,0 +1,6 @@
+import { __toESM } from "./$runtime$.mjs";
+import { require_foo } from "./foo~1.mjs";
+
+// main.js
+var import_foo = __toESM(require_foo());
+import('./foo.mjs').then(({default:{bar:b}}) => console.log(import_foo.bar, b));
@@ -0,0 +1 @@
+export { bar } from "./foo~1.mjs";
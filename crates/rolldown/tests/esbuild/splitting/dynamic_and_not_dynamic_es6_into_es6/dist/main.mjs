@@ -0,0 +1,4 @@
+import { bar } from "./foo~1.mjs";
+
+// main.js
+import('./foo.mjs').then(({bar:b}) => console.log(bar, b));
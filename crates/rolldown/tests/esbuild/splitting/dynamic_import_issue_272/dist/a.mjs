@@ -0,0 +1,3 @@
+
+// a.js
+import('./b.mjs');
@@ -0,0 +1,5 @@
+
+// b.js
+var b_default = 1;
+
+export { b_default as default };
\ No newline at end of file
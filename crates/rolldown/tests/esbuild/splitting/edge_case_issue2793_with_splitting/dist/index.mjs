@@ -0,0 +1,8 @@
+
+// src/a.js
+const A = 42;
+
+// src/b.js
+const B = async() => (await import('./index.mjs')).A;
+
+export { A, B };
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { foo, init_a } from "./a~1.mjs";
+
+init_a();
+export { foo };
\ No newline at end of file
@@ -0,0 +1,12 @@
+import { __esmMin, __export } from "./$runtime$.mjs";
+
+// a.js
+var a_ns, foo;
+var init_a = __esmMin(() => {
+	a_ns = {};
+	__export(a_ns, {
+		foo:() => foo
+	});
+});
+
+export { a_ns, foo, init_a };
\ No newline at end of file
@@ -0,0 +1,7 @@
+import { __toCommonJS } from "./$runtime$.mjs";
+import { a_ns, init_a } from "./a~1.mjs";
+
+// b.js
+let bar = (init_a(),__toCommonJS(a_ns));
+
+export { bar };
\ No newline at end of file
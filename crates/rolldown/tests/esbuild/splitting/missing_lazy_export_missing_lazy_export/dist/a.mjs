@@ -0,0 +1,4 @@
+import { foo } from "./common.mjs";
+
+// a.js
+console.log(foo());
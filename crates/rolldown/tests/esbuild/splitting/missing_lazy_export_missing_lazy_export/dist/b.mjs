@@ -0,0 +1,4 @@
+import { bar } from "./common.mjs";
+
+// b.js
+console.log(bar());
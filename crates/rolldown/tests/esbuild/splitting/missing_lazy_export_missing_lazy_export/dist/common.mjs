@@ -0,0 +1,16 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// empty.js
+var require_empty = __commonJSMin((exports, module) => {
+});
+
+// common.js
+var import_empty = __toESM(require_empty());
+function foo() {
+	return [import_empty, import_empty.missing];
+}
+function bar() {
+	return [import_empty.missing];
+}
+
+export { bar, foo };
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { shared_default } from "./shared.mjs";
+
+// src/a.js
+console.log(shared_default);
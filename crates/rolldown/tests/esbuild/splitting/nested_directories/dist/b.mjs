@@ -0,0 +1,4 @@
+import { shared_default } from "./shared.mjs";
+
+// src/b.js
+console.log( -shared_default);
@@ -0,0 +1,5 @@
+
+// shared.js
+var shared_default = 123;
+
+export { shared_default };
\ No newline at end of file
@@ -0,0 +1 @@
+export { a } from "./a~1.mjs";
@@ -0,0 +1,5 @@
+
+// a.js
+const a = 1;
+
+export { a };
\ No newline at end of file
@@ -0,0 +1,3 @@
+import { a } from "./a~1.mjs";
+
+export { a };
\ No newline at end of file
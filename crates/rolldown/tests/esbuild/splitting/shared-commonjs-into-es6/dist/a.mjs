@@ -0,0 +1,5 @@
+import { require_shared } from "./shared.mjs";
+
+// a.js
+const {foo} = require_shared();
+console.log(foo);
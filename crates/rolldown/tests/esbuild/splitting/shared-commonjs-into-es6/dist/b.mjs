@@ -0,0 +1,5 @@
+import { require_shared } from "./shared.mjs";
+
+// b.js
+const {foo} = require_shared();
+console.log(foo);
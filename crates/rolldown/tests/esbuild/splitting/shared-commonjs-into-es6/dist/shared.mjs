@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// shared.js
+var require_shared = __commonJSMin((exports, module) => {
+	exports.foo = 123;
+});
+
+export { require_shared };
\ No newline at end of file
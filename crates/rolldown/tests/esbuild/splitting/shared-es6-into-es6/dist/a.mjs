@@ -0,0 +1,4 @@
+import { foo } from "./shared.mjs";
+
+// a.js
+console.log(foo);
@@ -0,0 +1,5 @@
+
+// shared.js
+let foo = 123;
+
+export { foo };
\ No newline at end of file
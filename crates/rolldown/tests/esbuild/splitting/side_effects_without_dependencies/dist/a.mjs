@@ -0,0 +1,4 @@
+import { a } from "./shared.mjs";
+
+// a.js
+console.log(a);
@@ -0,0 +1,4 @@
+import { b } from "./shared.mjs";
+
+// b.js
+console.log(b);
@@ -0,0 +1,7 @@
+
+// shared.js
+let a = 1;
+let b = 2;
+console.log('side effect');
+
+export { a, b };
\ No newline at end of file
@@ -0,0 +1,42 @@
+import { __commonJSMin, __esmMin, __export, __toCommonJS, __toESM } from "./$runtime$.mjs";
+
+// esm.js
+function esm_default_fn() {
+}
+function esm_named_fn() {
+}
+function hoisted_fn() {
+	const bar = 1;
+}
+var esm_ns, esm_named_var, esm_named_class, hoisted_var, hoisted_class;
+var init_esm = __esmMin(() => {
+	esm_ns = {};
+	__export(esm_ns, {
+		default:() => esm_default_fn,
+		esm_named_class:() => esm_named_class,
+		esm_named_fn:() => esm_named_fn,
+		esm_named_var:() => esm_named_var
+	});
+	esm_named_var = 1;
+	esm_named_class = class {
+	};
+	hoisted_var = 1;
+	hoisted_class = class {
+	};
+});
+
+// commonjs.js
+var require_commonjs$1 = __commonJSMin((exports, module) => {
+	var value = (init_esm(),__toCommonJS(esm_ns));
+	module.exports = value;
+});
+
+// main.js
+var import_commonjs = __toESM(require_commonjs$1());
+init_esm();
+console.log(import_commonjs.default, esm_default_fn, esm_named_var, esm_named_fn, esm_named_class);
+const require_commonjs = () => {
+};
+function esm_default_fn$1() {
+}
+console.log(require_commonjs, esm_default_fn$1);
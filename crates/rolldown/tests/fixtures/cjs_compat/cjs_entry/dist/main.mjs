@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// main.js
+var require_main = __commonJSMin((exports, module) => {
+	module.exports = 'main';
+});
+
+export default require_main();
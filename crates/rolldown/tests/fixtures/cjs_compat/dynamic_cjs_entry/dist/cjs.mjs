@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = 'cjs';
+});
+
+export default require_cjs();
@@ -0,0 +1,5 @@
+
+// main.js
+var main_default = import('./cjs.mjs');
+
+export { main_default as default };
\ No newline at end of file
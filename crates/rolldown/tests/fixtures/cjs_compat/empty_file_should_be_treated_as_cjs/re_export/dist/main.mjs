@@ -0,0 +1,16 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// star-empty.js
+var require_star_empty = __commonJSMin((exports, module) => {
+});
+
+// default-empty.js
+var require_default_empty = __commonJSMin((exports, module) => {
+});
+
+// main.js
+var import_star_empty = __toESM(require_star_empty());
+var import_default_empty = __toESM(require_default_empty());
+
+var defaultImport = import_default_empty.default;
+export { defaultImport, import_star_empty as staImport };
\ No newline at end of file
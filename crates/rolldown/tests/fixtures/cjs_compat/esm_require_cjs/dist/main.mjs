@@ -0,0 +1,11 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = 'cjs';
+});
+
+// main.js
+const cjs = require_cjs();
+
+export { cjs };
\ No newline at end of file
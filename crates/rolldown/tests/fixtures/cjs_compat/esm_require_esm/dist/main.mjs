@@ -0,0 +1,16 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// esm.js
+var esm_ns, esm_default;
+var init_esm = __esmMin(() => {
+	esm_ns = {};
+	__export(esm_ns, {
+		default:() => esm_default
+	});
+	esm_default = 'esm';
+});
+
+// main.js
+const esm = (init_esm(),__toCommonJS(esm_ns));
+
+export { esm };
\ No newline at end of file
@@ -0,0 +1,14 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// esm.js
+var esm_ns, esm_default;
+var init_esm = __esmMin(() => {
+	esm_ns = {};
+	__export(esm_ns, {
+		default:() => esm_default
+	});
+	esm_default = 'esm';
+});
+
+// main.js
+init_esm(),__toCommonJS(esm_ns);
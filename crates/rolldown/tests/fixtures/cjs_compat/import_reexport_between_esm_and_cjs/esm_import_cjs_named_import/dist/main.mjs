@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	exports.a = 1;
+});
+
+// main.js
+var import_commonjs = __toESM(require_commonjs());
+console.log(import_commonjs.a, 1);
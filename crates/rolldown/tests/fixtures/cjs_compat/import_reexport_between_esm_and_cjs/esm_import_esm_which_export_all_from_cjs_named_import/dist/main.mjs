@@ -0,0 +1,13 @@
+import { __commonJSMin, __reExport, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	exports.a = 1;
+});
+
+// proxy.js
+var proxy_ns = {};
+__reExport(proxy_ns, __toESM(require_commonjs()));
+
+// main.js
+console.log(proxy_ns.a, 1);
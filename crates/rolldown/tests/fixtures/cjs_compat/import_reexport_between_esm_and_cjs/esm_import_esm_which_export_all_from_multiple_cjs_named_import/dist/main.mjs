@@ -0,0 +1,19 @@
+import { __commonJSMin, __reExport, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	exports.a = 1;
+});
+
+// commonjs2.js
+var require_commonjs2 = __commonJSMin((exports, module) => {
+	exports.a = 2;
+});
+
+// proxy.js
+var proxy_ns = {};
+__reExport(proxy_ns, __toESM(require_commonjs()));
+__reExport(proxy_ns, __toESM(require_commonjs2()));
+
+// main.js
+console.log(proxy_ns.a, 1);
@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	module.exports = 'foo';
+});
+
+// main.js
+var import_commonjs = __toESM(require_commonjs());
+
+var commonjs_default = import_commonjs.default;
+export { commonjs_default as default };
\ No newline at end of file
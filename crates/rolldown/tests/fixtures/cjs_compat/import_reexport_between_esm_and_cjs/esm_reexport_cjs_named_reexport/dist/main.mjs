@@ -0,0 +1,12 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	exports.a = 1;
+});
+
+// main.js
+var import_commonjs = __toESM(require_commonjs());
+
+var a = import_commonjs.a;
+export { a };
\ No newline at end of file
@@ -0,0 +1,14 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports.a = {};
+});
+
+// main.js
+var import_cjs = __toESM(require_cjs());
+var import_cjs$1 = __toESM(require_cjs());
+
+var a = import_cjs.a;
+var a2 = import_cjs$1.a;
+export { a, a2 };
\ No newline at end of file
@@ -0,0 +1,32 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// esm-export-cjs-export.js
+module.exports = 1;
+
+// foo.js
+var require_foo = __commonJSMin((exports, module) => {
+});
+
+// esm-export-cjs-require.js
+require_foo();
+
+// esm-import-cjs-export.js
+var require_esm_import_cjs_export = __commonJSMin((exports, module) => {
+	var import_foo = __toESM(require_foo());
+	module.exports = 1;
+});
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = 1;
+});
+
+// esm-import-cjs-require.js
+var import_cjs = __toESM(require_cjs());
+require_foo();
+console.log(import_cjs.a);
+
+// main.js
+var import_esm_import_cjs_export = __toESM(require_esm_import_cjs_export());
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
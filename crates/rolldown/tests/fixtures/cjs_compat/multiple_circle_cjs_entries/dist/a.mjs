@@ -0,0 +1,3 @@
+import { require_a } from "./a~1.mjs";
+
+export default require_a();
@@ -0,0 +1,15 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// b.js
+var require_b = __commonJSMin((exports, module) => {
+	var import_a = __toESM(require_a());
+	module.exports = 'b';
+});
+
+// a.js
+var require_a = __commonJSMin((exports, module) => {
+	var import_b = __toESM(require_b());
+	module.exports = 'a';
+});
+
+export { require_a, require_b };
\ No newline at end of file
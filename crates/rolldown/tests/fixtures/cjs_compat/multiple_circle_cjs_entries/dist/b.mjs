@@ -0,0 +1,3 @@
+import { require_b } from "./a~1.mjs";
+
+export default require_b();
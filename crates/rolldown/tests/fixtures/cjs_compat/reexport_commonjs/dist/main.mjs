@@ -0,0 +1,27 @@
+import { __commonJSMin, __export, __reExport, __toESM } from "./$runtime$.mjs";
+
+// commonjs.js
+var require_commonjs = __commonJSMin((exports, module) => {
+	module.exports = {
+		bar:1
+	};
+});
+
+// esm.js
+const value = 1;
+
+// foo.js
+var foo_ns = {};
+__export(foo_ns, {
+	bar:() => import_commonjs$1.bar,
+	value:() => value
+});
+__reExport(foo_ns, __toESM(require_commonjs()));
+var import_commonjs$1 = __toESM(require_commonjs());
+
+// main.js
+var import_commonjs = __toESM(require_commonjs());
+console.log(foo_ns, import_commonjs$1.bar, value, foo_ns.foo);
+
+var bar = import_commonjs.bar;
+export { bar };
\ No newline at end of file
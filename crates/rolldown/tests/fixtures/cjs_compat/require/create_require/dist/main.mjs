@@ -0,0 +1,6 @@
+import { createRequire } from "node:module";
+
+// main.js
+const require = createRequire(import.meta.url);
+const assert = require('node:assert');
+assert.equal(1, 1);
@@ -0,0 +1,39 @@
+import { __commonJSMin, __dynamicRequire, __toESM } from "./$runtime$.mjs";
+import { default as assert } from "node:assert";
+
+// node_modules/moment/locale/de.js
+var require_de = __commonJSMin((exports, module) => {
+	module.exports = {
+		months:['Januar', 'Februar']
+	};
+});
+
+// node_modules/moment/locale/fr.js
+var require_fr = __commonJSMin((exports, module) => {
+	module.exports = {
+		months:['janvier', 'f\u{e9}vrier']
+	};
+});
+
+// node_modules/moment/moment.js
+var require_moment = __commonJSMin((exports, module) => {
+	var locales = {};
+	function loadLocale$1(name) {
+		if ( !locales[name]) {
+			locales[name] = __dynamicRequire({
+				'./locale/de.js':() => require_de(),
+				'./locale/de':() => require_de(),
+				'./locale/fr.js':() => require_fr(),
+				'./locale/fr':() => require_fr()
+			}, './locale/' + name);
+		}
+		return locales[name];
+	}
+	exports.loadLocale = loadLocale$1;
+});
+
+// main.js
+var import_moment = __toESM(require_moment());
+assert.strictEqual((0,import_moment.loadLocale)('de').months[0], 'Januar');
+assert.strictEqual((0,import_moment.loadLocale)('fr.js'), (0,import_moment.loadLocale)('fr'));
+assert.throws(() => (0,import_moment.loadLocale)('it'), /Could not dynamically require "\.\/locale\/it"/);
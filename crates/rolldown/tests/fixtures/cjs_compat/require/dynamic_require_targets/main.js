@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { loadLocale } from 'moment'
+
+assert.strictEqual(loadLocale('de').months[0], 'Januar')
+// Locales are required with and without the extension, and both resolve to the same module.
+assert.strictEqual(loadLocale('fr.js'), loadLocale('fr'))
+// Targets of other packages aren't bundled into the lookup of `moment`.
+assert.throws(() => loadLocale('it'), /Could not dynamically require "\.\/locale\/it"/)
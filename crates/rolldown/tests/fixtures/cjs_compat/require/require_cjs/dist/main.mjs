@@ -0,0 +1,12 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = {};
+});
+
+// main.js
+require_cjs();
+var cjs = require_cjs();
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
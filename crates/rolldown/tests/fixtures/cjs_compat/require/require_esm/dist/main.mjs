@@ -0,0 +1,13 @@
+import { __esmMin, __toCommonJS } from "./$runtime$.mjs";
+
+// esm.js
+var esm_ns;
+var init_esm = __esmMin(() => {
+	esm_ns = {};
+});
+
+// main.js
+init_esm(),__toCommonJS(esm_ns);
+var esm = (init_esm(),__toCommonJS(esm_ns));
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
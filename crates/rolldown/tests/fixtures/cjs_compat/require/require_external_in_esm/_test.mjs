@@ -0,0 +1,4 @@
+import assert from 'assert'
+import { sep } from './dist/main.mjs'
+
+assert.equal(typeof sep, 'string')
@@ -0,0 +1,8 @@
+import { createRequire as __rolldown_createRequire } from "node:module";
+var require = __rolldown_createRequire(import.meta.url);
+
+// main.js
+const path = require('node:path');
+const sep = path.sep;
+
+export { sep };
\ No newline at end of file
@@ -0,0 +1,3 @@
+const path = require('node:path')
+
+export const sep = path.sep
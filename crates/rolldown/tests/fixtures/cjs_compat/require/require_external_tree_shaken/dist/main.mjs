@@ -0,0 +1,5 @@
+
+// lib.js
+const used = 'used';
+
+export { used };
\ No newline at end of file
@@ -0,0 +1,6 @@
+export const used = 'used'
+
+// Tree shaken, so the chunk doesn't need `require` created from `import.meta.url`.
+export function unused() {
+  return require('node:fs')
+}
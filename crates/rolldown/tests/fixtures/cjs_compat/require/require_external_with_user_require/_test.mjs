@@ -0,0 +1,5 @@
+import assert from 'assert'
+import { sep, describe } from './dist/main.mjs'
+
+assert.equal(typeof sep, 'string')
+assert.equal(describe(), 'local x user')
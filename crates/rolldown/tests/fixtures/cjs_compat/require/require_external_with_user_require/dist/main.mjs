@@ -0,0 +1,15 @@
+import { createRequire as __rolldown_createRequire } from "node:module";
+var require = __rolldown_createRequire(import.meta.url);
+
+// path.js
+const path = require('node:path');
+const sep = path.sep;
+
+// local.js
+function require$1(id) {
+	return `local ${id}`;
+}
+const __rolldown_createRequire$1 = 'user';
+const describe = () => `${require$1('x')} ${__rolldown_createRequire$1}`;
+
+export { describe, sep };
\ No newline at end of file
@@ -0,0 +1,7 @@
+// Declarations named after the injected `require` shim must not clash with it.
+function require(id) {
+  return `local ${id}`
+}
+const __rolldown_createRequire = 'user'
+
+export const describe = () => `${require('x')} ${__rolldown_createRequire}`
@@ -0,0 +1,4 @@
+import { sep } from './path.js'
+import { describe } from './local.js'
+
+export { sep, describe }
@@ -0,0 +1,48 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+import { default as assert } from "node:assert";
+
+// only_default.js
+var only_default_ns, only_default_default;
+var init_only_default = __esmMin(() => {
+	only_default_ns = {};
+	__export(only_default_ns, {
+		default:() => only_default_default
+	});
+	only_default_default = 'only default';
+});
+
+// named.js
+var named_ns, named$1;
+var init_named = __esmMin(() => {
+	named_ns = {};
+	__export(named_ns, {
+		named:() => named$1
+	});
+	named$1 = 'named';
+});
+
+// mixed.js
+var mixed_ns, mixed_default, named;
+var init_mixed = __esmMin(() => {
+	mixed_ns = {};
+	__export(mixed_ns, {
+		default:() => mixed_default,
+		named:() => named
+	});
+	mixed_default = 'mixed default';
+	named = 'mixed named';
+});
+
+// main.js
+const plain = value => value && typeof value === 'object' ? {
+	...value
+} : value;
+assert.deepStrictEqual(plain((init_only_default(),only_default_ns.default)), 'only default');
+assert.deepStrictEqual(plain((init_named(),__toCommonJS(named_ns))), {
+	named:'named'
+});
+const loadMixed = enabled => enabled ? (init_mixed(),__toCommonJS(mixed_ns)) : null;
+assert.deepStrictEqual(plain(loadMixed(true)), {
+	default:'mixed default',
+	named:'mixed named'
+});
@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+
+const plain = (value) => (value && typeof value === 'object' ? { ...value } : value)
+
+// Top-level requires
+assert.deepStrictEqual(plain(require('./only_default.js')), { default: 'only default' })
+assert.deepStrictEqual(plain(require('./named.js')), { named: 'named' })
+
+// Conditional require
+const loadMixed = (enabled) => (enabled ? require('./mixed.js') : null)
+assert.deepStrictEqual(plain(loadMixed(true)), { default: 'mixed default', named: 'mixed named' })
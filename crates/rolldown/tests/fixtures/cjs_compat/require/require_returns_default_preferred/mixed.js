@@ -0,0 +1,2 @@
+export default 'mixed default'
+export const named = 'mixed named'
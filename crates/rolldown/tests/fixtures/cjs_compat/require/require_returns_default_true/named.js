@@ -0,0 +1 @@
+export const named = 'named'
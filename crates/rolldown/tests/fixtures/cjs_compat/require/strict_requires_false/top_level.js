@@ -0,0 +1,4 @@
+import { log } from './log.js'
+
+log.push('top level')
+export const value = 'top level'
@@ -0,0 +1,4 @@
+import { log } from './log.js'
+
+log.push('conditional')
+export const value = 'conditional'
@@ -0,0 +1,45 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+import { default as assert } from "node:assert";
+
+// log.js
+var log;
+var init_log = __esmMin(() => {
+	log = [];
+});
+
+// top_level.js
+var top_level_ns, value$2;
+var init_top_level = __esmMin(() => {
+	top_level_ns = {};
+	__export(top_level_ns, {
+		value:() => value$2
+	});
+	init_log();
+	log.push('top level');
+	value$2 = 'top level';
+});
+
+// conditional.js
+var conditional_ns, value$1;
+var init_conditional = __esmMin(() => {
+	conditional_ns = {};
+	__export(conditional_ns, {
+		value:() => value$1
+	});
+	init_log();
+	log.push('conditional');
+	value$1 = 'conditional';
+});
+
+// main.js
+init_log();
+log.push('main');
+const {value} = (init_top_level(),__toCommonJS(top_level_ns));
+assert.strictEqual(value, 'top level');
+if (log.length > 10) {
+	init_conditional(),__toCommonJS(conditional_ns);
+}
+const load = () => (init_conditional(),__toCommonJS(conditional_ns)).value;
+assert.deepStrictEqual(log, ['main', 'top level']);
+assert.strictEqual(load(), 'conditional');
+assert.deepStrictEqual(log.slice(2), ['conditional']);
@@ -0,0 +1,16 @@
+import assert from 'node:assert'
+import { log } from './log.js'
+
+log.push('main')
+// The top-level require is wrapped and executed where it's required.
+const { value } = require('./top_level.js')
+assert.strictEqual(value, 'top level')
+
+// The conditional require is wrapped either way, so the module only runs if it's required.
+if (log.length > 10) {
+  require('./conditional.js')
+}
+const load = () => require('./conditional.js').value
+assert.deepStrictEqual(log, ['main', 'top level'])
+assert.strictEqual(load(), 'conditional')
+assert.deepStrictEqual(log.slice(2), ['conditional'])
@@ -0,0 +1,13 @@
+"use strict";
+
+const { __toESM } = require("./$runtime$.cjs");
+const { default: assert } = __toESM(require("node:assert"));
+
+// main.js
+assert.throws(() => require('./missing.js'), {
+	code:'MODULE_NOT_FOUND'
+});
+const name = 'missing.js';
+assert.throws(() => require('./' + name), {
+	code:'MODULE_NOT_FOUND'
+});
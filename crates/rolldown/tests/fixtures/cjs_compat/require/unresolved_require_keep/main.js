@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+
+// The requires are kept as they are, so they fail at runtime like in Node.js.
+assert.throws(() => require('./missing.js'), { code: 'MODULE_NOT_FOUND' })
+const name = 'missing.js'
+assert.throws(() => require('./' + name), { code: 'MODULE_NOT_FOUND' })
@@ -0,0 +1,10 @@
+import { default as assert } from "node:assert";
+
+// main.js
+assert.throws(() => (() => {
+	throw new Error('Could not resolve "./missing.js"');
+})(), /Could not resolve "\.\/missing\.js"/);
+const name = 'missing.js';
+assert.throws(() => (() => {
+	throw new Error('Could not resolve dynamic require');
+})(), /Could not resolve dynamic require/);
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+
+// The requires are replaced with expressions that throw at runtime, so the output doesn't need `require`.
+assert.throws(() => require('./missing.js'), /Could not resolve "\.\/missing\.js"/)
+const name = 'missing.js'
+assert.throws(() => require('./' + name), /Could not resolve dynamic require/)
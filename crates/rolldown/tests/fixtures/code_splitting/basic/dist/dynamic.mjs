@@ -0,0 +1,3 @@
+
+// dynamic.js
+console.log('dynamic');
@@ -0,0 +1,4 @@
+import "./share.mjs";
+
+// main1.js
+import('./dynamic.mjs');
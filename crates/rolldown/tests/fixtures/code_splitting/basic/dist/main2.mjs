@@ -0,0 +1 @@
+import "./share.mjs";
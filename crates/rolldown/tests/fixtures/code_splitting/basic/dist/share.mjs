@@ -0,0 +1,3 @@
+
+// share.js
+console.log('shared');
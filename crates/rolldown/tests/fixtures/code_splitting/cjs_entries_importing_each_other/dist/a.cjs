@@ -0,0 +1,6 @@
+"use strict";
+
+const { __toCommonJS } = require("./$runtime$.cjs");
+const { a, a_ns, getB } = require("./a~1.cjs");
+
+module.exports = __toCommonJS(a_ns)
\ No newline at end of file
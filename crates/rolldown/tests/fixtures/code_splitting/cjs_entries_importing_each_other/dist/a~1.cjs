@@ -0,0 +1,28 @@
+"use strict";
+
+const { __export } = require("./$runtime$.cjs");
+
+// b.js
+var b_ns = {};
+__export(b_ns, {
+	b:() => b,
+	getA:() => getA
+});
+const b = 'b';
+const getA = () => a;
+
+// a.js
+var a_ns = {};
+__export(a_ns, {
+	a:() => a,
+	getB:() => getB
+});
+const a = 'a';
+const getB = () => b;
+
+exports.a = a;
+exports.a_ns = a_ns;
+exports.b = b;
+exports.b_ns = b_ns;
+exports.getA = getA;
+exports.getB = getB;
@@ -0,0 +1,6 @@
+"use strict";
+
+const { __toCommonJS } = require("./$runtime$.cjs");
+const { b, b_ns, getA } = require("./a~1.cjs");
+
+module.exports = __toCommonJS(b_ns)
\ No newline at end of file
@@ -0,0 +1,3 @@
+
+// main1.js
+console.log('main1');
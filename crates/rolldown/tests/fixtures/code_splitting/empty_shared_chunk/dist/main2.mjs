@@ -0,0 +1,3 @@
+
+// main2.js
+console.log('main2');
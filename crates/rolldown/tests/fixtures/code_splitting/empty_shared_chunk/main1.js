@@ -0,0 +1,2 @@
+import './share'
+console.log('main1')
\ No newline at end of file
@@ -0,0 +1,3 @@
+
+// entry.js
+globalThis.sideEffectExecuted = true;
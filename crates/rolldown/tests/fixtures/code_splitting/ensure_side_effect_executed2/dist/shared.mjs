@@ -0,0 +1,3 @@
+
+// shared.js
+globalThis.sideEffectExecuted = true;
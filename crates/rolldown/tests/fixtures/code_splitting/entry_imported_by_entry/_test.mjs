@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import other, { value } from './dist/other.mjs'
+
+assert.equal(other, 'other')
+assert.equal(value, 1)
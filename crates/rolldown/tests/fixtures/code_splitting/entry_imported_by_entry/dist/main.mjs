@@ -0,0 +1,4 @@
+import { value } from "./other~1.mjs";
+
+// main.js
+console.log(value);
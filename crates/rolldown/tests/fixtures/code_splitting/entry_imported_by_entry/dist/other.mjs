@@ -0,0 +1 @@
+export { other_default as default, value } from "./other~1.mjs";
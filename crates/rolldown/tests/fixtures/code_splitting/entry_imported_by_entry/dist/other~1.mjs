@@ -0,0 +1,6 @@
+
+// other.js
+const value = 1;
+var other_default = 'other';
+
+export { other_default, value };
\ No newline at end of file
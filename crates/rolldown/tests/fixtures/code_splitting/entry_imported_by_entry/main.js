@@ -0,0 +1,3 @@
+import { value } from './other';
+
+console.log(value);
@@ -0,0 +1 @@
+export { devil as '😈' } from "./foo~1.mjs";
@@ -0,0 +1,5 @@
+
+// foo.js
+const devil = 'devil';
+
+export { devil };
\ No newline at end of file
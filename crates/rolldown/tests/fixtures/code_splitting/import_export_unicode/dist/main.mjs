@@ -0,0 +1,6 @@
+import { devil } from "./foo~1.mjs";
+
+// main.js
+const moduleFoo = import('./foo.mjs');
+
+export { moduleFoo, devil as '😈' };
\ No newline at end of file
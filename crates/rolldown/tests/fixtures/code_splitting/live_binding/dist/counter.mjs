@@ -0,0 +1,8 @@
+
+// counter.js
+let count = 0;
+function increase() {
+	count += 1;
+}
+
+export { count, increase };
\ No newline at end of file
@@ -0,0 +1,8 @@
+import { count, increase } from "./counter.mjs";
+
+// main.js
+setTimeout(() => {
+	increase();
+	if (count !== 1) 	throw new Error('`count` should be updated');
+
+});
@@ -0,0 +1,7 @@
+import { count } from "./counter.mjs";
+
+// other.js
+setTimeout(() => {
+	if (count !== 1) 	throw new Error('`count` should be updated by other chunks');
+
+}, 10);
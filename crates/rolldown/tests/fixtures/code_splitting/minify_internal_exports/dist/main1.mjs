@@ -0,0 +1,4 @@
+import { a as share } from "./share.mjs";
+
+// main1.js
+console.log(share);
@@ -0,0 +1,4 @@
+import { a as share } from "./share.mjs";
+
+// main2.js
+console.log(share);
@@ -0,0 +1,5 @@
+
+// share.js
+const share = 'share';
+
+export { share as a };
\ No newline at end of file
@@ -0,0 +1,2 @@
+import { share } from './share'
+console.log(share)
\ No newline at end of file
@@ -0,0 +1 @@
+export const share = 'share'
\ No newline at end of file
@@ -0,0 +1 @@
+exports.b = 2
\ No newline at end of file
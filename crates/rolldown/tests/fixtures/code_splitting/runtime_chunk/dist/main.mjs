@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./rolldown-runtime.mjs";
+
+// a.js
+var require_a = __commonJSMin((exports, module) => {
+	exports.a = 1;
+});
+
+// main.js
+var import_a = __toESM(require_a());
+console.log(import_a.a);
@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./rolldown-runtime.mjs";
+
+// b.js
+var require_b = __commonJSMin((exports, module) => {
+	exports.b = 2;
+});
+
+// main2.js
+var import_b = __toESM(require_b());
+console.log(import_b.b);
@@ -0,0 +1,2 @@
+import { b } from './b.js'
+console.log(b)
\ No newline at end of file
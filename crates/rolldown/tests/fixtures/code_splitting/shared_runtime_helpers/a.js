@@ -0,0 +1 @@
+exports.a = 1
\ No newline at end of file
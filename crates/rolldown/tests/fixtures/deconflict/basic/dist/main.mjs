@@ -0,0 +1,9 @@
+import { default as assert } from "assert";
+
+// a.js
+const a$1 = 'a.js';
+
+// main.js
+const a = 'main.js';
+assert.equal(a, 'main.js');
+assert.equal(a$1, 'a.js');
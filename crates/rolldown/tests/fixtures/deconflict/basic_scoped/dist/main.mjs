@@ -0,0 +1,13 @@
+import { default as assert } from "assert";
+
+// a.js
+const a$1 = 'a.js';
+
+// main.js
+const a = 'main.js';
+function foo(a$1$1) {
+	return [a$1$1, a, a$1];
+}
+assert.deepEqual(foo('foo'), ['foo', 'main.js', 'a.js']);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,27 @@
+import { default as assert } from "assert";
+
+// names.js
+const [a$1,b$1,c$1,d$1,e$1] = ['a2', 'b2', 'c2', 'd2', 'e2'];
+
+// main.js
+const [a,b,c,d,e] = ['a', 'b', 'c', 'd', 'e'];
+function foo(a$1$1, {b$1:b$1$1}, {c$1:c$1$1='c$1'}, {d$1:d$1$1}={
+	d$1:'d$1'
+}, {e$1:e$1$1=''}={
+	e$1:'e$1'
+}) {
+	return {
+		main:[a, b, c, d, e],
+		names:[a$1, b$1, c$1, d$1, e$1],
+		params:[a$1$1, b$1$1, c$1$1, d$1$1, e$1$1]
+	};
+}
+const ret = foo('a$1', {
+	b$1:'b$1'
+}, {}, undefined, undefined);
+assert.deepStrictEqual(ret.main, ['a', 'b', 'c', 'd', 'e']);
+assert.deepStrictEqual(ret.names, ['a2', 'b2', 'c2', 'd2', 'e2']);
+assert.deepStrictEqual(ret.params, ['a$1', 'b$1', 'c$1', 'd$1', 'e$1']);
+
+export { a, b, c, d, e };
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
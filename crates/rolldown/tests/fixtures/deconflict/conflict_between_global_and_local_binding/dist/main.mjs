@@ -0,0 +1,12 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+import { default as assert } from "node:assert";
+
+// main.js
+var require_main = __commonJSMin((exports, module) => {
+	module.exports = 'main';
+	globalThis.__commonJS = 0;
+	__commonJS = 1;
+	assert.equal(__commonJS, 1);
+});
+
+export default require_main();
@@ -0,0 +1,44 @@
+import { default as assert } from "assert";
+
+// names.js
+const [a$1] = ['a2'];
+const {b:b$1} = {
+	b:'b2'
+};
+const {b:c$1} = {
+	b:'c2'
+};
+const {d:d$1=''} = {
+	d:'d2'
+};
+const {d:e$1=''} = {
+	d:'e2'
+};
+
+// main.js
+const [a] = ['a1'];
+const {b} = {
+	b:'b1'
+};
+const {b:c} = {
+	b:'c1'
+};
+const {d=''} = {
+	d:'d1'
+};
+const {d:e=''} = {
+	d:'e1'
+};
+assert.equal(a, 'a1');
+assert.equal(a$1, 'a2');
+assert.equal(b, 'b1');
+assert.equal(b$1, 'b2');
+assert.equal(c, 'c1');
+assert.equal(c$1, 'c2');
+assert.equal(d, 'd1');
+assert.equal(d$1, 'd2');
+assert.equal(e, 'e1');
+assert.equal(e$1, 'e2');
+
+export { a, a$1 as a2, b, b$1 as b2, c, c$1 as c2, d, d$1 as d2, e, e$1 as e2 };
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
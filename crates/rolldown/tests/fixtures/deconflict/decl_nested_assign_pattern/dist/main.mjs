@@ -0,0 +1,24 @@
+import { default as assert } from "assert";
+
+// names.js
+const {foo:{bar:{baz:baz$1=''}={}}={}} = {
+	foo:{
+		bar:{
+			baz:'baz2'
+		}
+	}
+};
+
+// main.js
+const {foo:{bar:{baz=''}={}}={}} = {
+	foo:{
+		bar:{
+			baz:'baz'
+		}
+	}
+};
+assert.strictEqual(baz, 'baz');
+assert.strictEqual(baz$1, 'baz2');
+
+export { baz, baz$1 as baz2 };
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
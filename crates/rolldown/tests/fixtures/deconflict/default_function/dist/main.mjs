@@ -0,0 +1,19 @@
+import { default as assert } from "assert";
+
+// foo.js
+const a$1 = 1;
+function foo$1(a$1$1) {
+	console.log(a$1$1, a$1);
+}
+
+// bar.js
+var bar_default = {
+	foo:foo$1
+};
+
+// main.js
+const a = 2;
+const {foo} = bar_default;
+assert.strictEqual(typeof foo, 'function');
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,13 @@
+import { default as assert } from "node:assert";
+
+// shared.js
+const a$2 = 'shared.js';
+
+// main.js
+const a = 'a';
+const a$1 = 'a$1';
+assert.equal(a$2, 'shared.js');
+assert.equal(a, 'a');
+assert.equal(a$1, 'a$1');
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
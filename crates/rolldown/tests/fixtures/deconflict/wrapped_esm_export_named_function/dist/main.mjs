@@ -0,0 +1,29 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+import { default as assert } from "assert";
+
+// foo.js
+function foo$1(a$1$1) {
+	console.log(a$1$1, a$1);
+}
+var foo_ns, a$1;
+var init_foo = __esmMin(() => {
+	foo_ns = {};
+	__export(foo_ns, {
+		foo:() => foo$1
+	});
+	a$1 = 1;
+});
+
+// bar.js
+init_foo();
+var bar_default = {
+	foo:foo$1
+};
+
+// main.js
+const a = 2;
+const {foo} = bar_default;
+assert.strictEqual(typeof foo, 'function');
+init_foo(),__toCommonJS(foo_ns);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,2 @@
+await Promise.resolve()
+console.log('done')
@@ -0,0 +1,2 @@
+export const button = 123
+export const label = "label"
@@ -0,0 +1,3 @@
+import { buton } from "./foo"
+
+console.log(buton)
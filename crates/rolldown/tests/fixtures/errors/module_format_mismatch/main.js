@@ -0,0 +1,3 @@
+import { a } from './foo.cjs';
+
+console.log(a);
@@ -0,0 +1,3 @@
+import { readFileSync } from 'fs'
+
+console.log(readFileSync)
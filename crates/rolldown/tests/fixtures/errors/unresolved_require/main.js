@@ -0,0 +1,6 @@
+import loadPlugin from 'pkg'
+
+const missing = require('./missing.js')
+const locale = require('./locale/' + navigator.language)
+
+console.log(loadPlugin, missing, locale)
@@ -0,0 +1,3 @@
+import fs = require('fs')
+
+export = fs
@@ -0,0 +1,19 @@
+import { default as assert } from "node:assert";
+import { default as fs } from "node:fs";
+
+// a.svg?url
+var a_default = 'assets/a-WSeR8ZTz.svg';
+
+// b.svg?url
+var b_default = 'assets/a-WSeR8ZTz.svg';
+
+// other.svg?url
+var other_default = 'assets/other-YMRYmlTx.svg';
+
+// main.js
+assert.match(a_default, /^assets\/a-[\w-]+\.svg$/);
+assert.strictEqual(b_default, a_default);
+assert.strictEqual(new URL('./assets/a-WSeR8ZTz.svg', import.meta.url).href, new URL(a_default, import.meta.url).href);
+assert.notStrictEqual(other_default, a_default);
+const emitted = fs.readdirSync(new URL('./assets/', import.meta.url)).sort();
+assert.deepStrictEqual(emitted, [a_default, other_default].map(url => url.slice('assets/'.length)).sort());
@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import a from './a.svg?url'
+import b from './b.svg?url'
+import other from './other.svg?url'
+
+// Assets with the same content are emitted once, by the smallest of their filenames.
+assert.match(a, /^assets\/a-[\w-]+\.svg$/)
+assert.strictEqual(b, a)
+assert.strictEqual(new URL('./b.svg', import.meta.url).href, new URL(a, import.meta.url).href)
+assert.notStrictEqual(other, a)
+
+const emitted = fs.readdirSync(new URL('./assets/', import.meta.url)).sort()
+assert.deepStrictEqual(emitted, [a, other].map((url) => url.slice('assets/'.length)).sort())
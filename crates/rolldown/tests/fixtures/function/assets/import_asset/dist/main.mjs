@@ -0,0 +1,9 @@
+import { default as assert } from "node:assert";
+import { default as fs } from "node:fs";
+
+// icon.svg
+var icon_default = 'assets/icon-Tku0FhgY.svg';
+
+// main.js
+assert.match(icon_default, /^assets\/icon-[\w-]+\.svg$/);
+assert.strictEqual(fs.readFileSync(new URL(icon_default, import.meta.url), 'utf8'), fs.readFileSync(new URL('../icon.svg', import.meta.url), 'utf8'));
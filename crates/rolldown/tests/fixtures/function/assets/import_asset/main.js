@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import url from './icon.svg'
+
+// The asset is emitted and imported as its URL relative to the output directory.
+assert.match(url, /^assets\/icon-[\w-]+\.svg$/)
+assert.strictEqual(
+  fs.readFileSync(new URL(url, import.meta.url), 'utf8'),
+  fs.readFileSync(new URL('../icon.svg', import.meta.url), 'utf8'),
+)
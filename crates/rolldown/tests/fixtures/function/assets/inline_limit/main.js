@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import emitted from './dot.svg'
+import inlined from './dot.svg?inline'
+
+const content = fs.readFileSync(new URL('../dot.svg', import.meta.url))
+
+// The asset is larger than `assetsInlineLimit`, so it's emitted, unless `?inline` forces inlining it.
+assert.match(emitted, /^assets\/dot-[\w-]+\.svg$/)
+assert.deepStrictEqual(fs.readFileSync(new URL(emitted, import.meta.url)), content)
+assert.strictEqual(inlined, `data:image/svg+xml;base64,${content.toString('base64')}`)
@@ -0,0 +1,14 @@
+import { default as assert } from "node:assert";
+import { default as fs } from "node:fs";
+
+// dot.svg
+var dot_default$1 = 'data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHZpZXdCb3g9IjAgMCAxIDEiPjxyZWN0IHdpZHRoPSIxIiBoZWlnaHQ9IjEiIC8+PC9zdmc+Cg==';
+
+// dot.svg?url
+var dot_default = 'assets/dot--EiqktmW.svg';
+
+// main.js
+const content = fs.readFileSync(new URL('../dot.svg', import.meta.url));
+assert.strictEqual(dot_default$1, `data:image/svg+xml;base64,${content.toString('base64')}`);
+assert.match(dot_default, /^assets\/dot-[\w-]+\.svg$/);
+assert.deepStrictEqual(fs.readFileSync(new URL(dot_default, import.meta.url)), content);
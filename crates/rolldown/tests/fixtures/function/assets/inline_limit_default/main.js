@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import inlined from './dot.svg'
+import emitted from './dot.svg?url'
+
+const content = fs.readFileSync(new URL('../dot.svg', import.meta.url))
+
+// Assets smaller than 4096 bytes are inlined, unless `?url` forces emitting them.
+assert.strictEqual(inlined, `data:image/svg+xml;base64,${content.toString('base64')}`)
+assert.match(emitted, /^assets\/dot-[\w-]+\.svg$/)
+assert.deepStrictEqual(fs.readFileSync(new URL(emitted, import.meta.url)), content)
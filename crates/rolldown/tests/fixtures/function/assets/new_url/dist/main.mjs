@@ -0,0 +1,7 @@
+import { default as assert } from "node:assert";
+import { default as fs } from "node:fs";
+
+// main.js
+const url = new URL('./assets/logo-XRAA3Isa.svg', import.meta.url);
+assert.match(url.pathname, /\/dist\/assets\/logo-[\w-]+\.svg$/);
+assert.strictEqual(fs.readFileSync(url, 'utf8'), fs.readFileSync(new URL('../images/logo.svg', import.meta.url), 'utf8'));
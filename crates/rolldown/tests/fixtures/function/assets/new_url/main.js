@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// The referenced file is emitted, and the URL points at it relative to the chunk.
+const url = new URL('./images/logo.svg', import.meta.url)
+assert.match(url.pathname, /\/dist\/assets\/logo-[\w-]+\.svg$/)
+assert.strictEqual(
+  fs.readFileSync(url, 'utf8'),
+  fs.readFileSync(new URL('../images/logo.svg', import.meta.url), 'utf8'),
+)
@@ -0,0 +1,11 @@
+"use strict";
+
+const { __toESM } = require("./$runtime$.cjs");
+const { default: assert } = __toESM(require("node:assert"));
+const { default: fs } = __toESM(require("node:fs"));
+const { default: path } = __toESM(require("node:path"));
+
+// main.js
+const url = new URL('./assets/logo-XRAA3Isa.svg', require('url').pathToFileURL(__filename));
+assert.match(url.pathname, /\/dist\/assets\/logo-[\w-]+\.svg$/);
+assert.strictEqual(fs.readFileSync(url, 'utf8'), fs.readFileSync(path.join(__dirname, '../images/logo.svg'), 'utf8'));
@@ -0,0 +1,11 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+
+// `import.meta.url` is replaced with the URL of `__filename` in CommonJS.
+const url = new URL('./images/logo.svg', import.meta.url)
+assert.match(url.pathname, /\/dist\/assets\/logo-[\w-]+\.svg$/)
+assert.strictEqual(
+  fs.readFileSync(url, 'utf8'),
+  fs.readFileSync(path.join(__dirname, '../images/logo.svg'), 'utf8'),
+)
@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { loadA, loadB } from './dist/main.mjs'
+
+assert.equal((await loadA()).default, 'a')
+assert.equal((await loadB()).default, 'b')
@@ -0,0 +1,5 @@
+
+// a.js
+var a_default = 'a';
+
+export { a_default as default };
\ No newline at end of file
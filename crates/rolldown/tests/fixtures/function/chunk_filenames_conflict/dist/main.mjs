@@ -0,0 +1,6 @@
+
+// main.js
+const loadA = () => import('./chunks/chunk.mjs');
+const loadB = () => import('./chunks/chunk2.mjs');
+
+export { loadA, loadB };
\ No newline at end of file
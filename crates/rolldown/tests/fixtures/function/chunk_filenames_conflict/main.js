@@ -0,0 +1,2 @@
+export const loadA = () => import('./a.js')
+export const loadB = () => import('./b.js')
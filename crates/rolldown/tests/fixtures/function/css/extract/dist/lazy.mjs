@@ -0,0 +1,5 @@
+
+// lazy.js
+const lazy = 'lazy';
+
+export { lazy };
\ No newline at end of file
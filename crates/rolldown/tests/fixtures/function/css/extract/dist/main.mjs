@@ -0,0 +1,12 @@
+import { default as assert } from "node:assert";
+import { default as fs } from "node:fs";
+
+// main.js
+const assetsDir = new URL('./assets/', import.meta.url);
+const stylesheets = Object.fromEntries(fs.readdirSync(assetsDir).filter(filename => filename.endsWith('.css')).map(filename => [filename.split('-')[0], fs.readFileSync(new URL(filename, assetsDir), 'utf8')]));
+assert.deepStrictEqual(Object.keys(stylesheets).sort(), ['c', 'main']);
+assert.match(stylesheets.main, /\.a \{[^]*\.b \{/);
+assert.doesNotMatch(stylesheets.main, /\.c \{/);
+assert.match(stylesheets.c, /\.c \{/);
+assert.doesNotMatch(stylesheets.c, /\.[ab] \{/);
+import('./lazy.mjs').then(({lazy}) => assert.strictEqual(lazy, 'lazy'));
@@ -0,0 +1,3 @@
+import './c.css'
+
+export const lazy = 'lazy'
@@ -0,0 +1,22 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import './a.css'
+import './b.css'
+
+const assetsDir = new URL('./assets/', import.meta.url)
+const stylesheets = Object.fromEntries(
+  fs
+    .readdirSync(assetsDir)
+    .filter((filename) => filename.endsWith('.css'))
+    .map((filename) => [filename.split('-')[0], fs.readFileSync(new URL(filename, assetsDir), 'utf8')]),
+)
+
+// Each chunk gets the stylesheets of its own modules, concatenated in execution order. The stylesheet of an entry
+// chunk is named after the entry, the one of any other chunk after its first stylesheet.
+assert.deepStrictEqual(Object.keys(stylesheets).sort(), ['c', 'main'])
+assert.match(stylesheets.main, /\.a \{[^]*\.b \{/)
+assert.doesNotMatch(stylesheets.main, /\.c \{/)
+assert.match(stylesheets.c, /\.c \{/)
+assert.doesNotMatch(stylesheets.c, /\.[ab] \{/)
+
+import('./lazy.js').then(({ lazy }) => assert.strictEqual(lazy, 'lazy'))
@@ -0,0 +1,5 @@
+
+// async.js
+const value = 'async';
+
+export { value };
\ No newline at end of file
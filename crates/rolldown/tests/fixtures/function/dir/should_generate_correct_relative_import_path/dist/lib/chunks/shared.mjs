@@ -0,0 +1,5 @@
+
+// shared.js
+const value = 'shared';
+
+export { value };
\ No newline at end of file
@@ -0,0 +1,6 @@
+
+// main.js
+const value = void 0;
+if (value === undefined) ;
+
+export { value };
\ No newline at end of file
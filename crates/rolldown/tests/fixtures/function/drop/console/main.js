@@ -0,0 +1,6 @@
+console.log('dropped')
+console.warn.apply(console, ['dropped'])
+console['error']('dropped')
+const value = console.info('dropped')
+if (value === undefined) console.debug('dropped')
+export { value }
@@ -0,0 +1,8 @@
+
+// main.js
+function check(value) {
+	if ( !value) 	;
+	return value;
+}
+
+export { check };
\ No newline at end of file
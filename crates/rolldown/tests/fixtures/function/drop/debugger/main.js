@@ -0,0 +1,5 @@
+debugger
+export function check(value) {
+  if (!value) debugger
+  return value
+}
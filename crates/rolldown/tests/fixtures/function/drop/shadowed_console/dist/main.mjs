@@ -0,0 +1,17 @@
+import { default as assert } from "node:assert";
+
+// local.js
+const messages = [];
+const console$1 = {
+	log:message => messages.push(message)
+};
+console$1.log('module');
+
+// main.js
+function log(console$2) {
+	console$2.log('parameter');
+}
+log({
+	log:message => messages.push(message)
+});
+assert.deepStrictEqual(messages, ['module', 'parameter']);
@@ -0,0 +1,5 @@
+export const messages = []
+
+// A variable named `console` shadows the global one, so its calls are kept.
+const console = { log: (message) => messages.push(message) }
+console.log('module')
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import { messages } from './local.js'
+
+console.log('dropped')
+
+// So does a parameter.
+function log(console) {
+  console.log('parameter')
+}
+log({ log: (message) => messages.push(message) })
+
+assert.deepStrictEqual(messages, ['module', 'parameter'])
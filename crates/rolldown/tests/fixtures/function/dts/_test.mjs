@@ -0,0 +1,17 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { sum, version } from './dist/main.mjs'
+
+assert.equal(sum(1, 2), 3)
+assert.equal(version, '1.0.0')
+assert.equal(
+  fs.readFileSync(new URL('./dist/main.d.mts', import.meta.url), 'utf8'),
+  `import type { Readable } from 'node:stream'
+declare function add(a: number, b: number): number
+interface Options {
+  input: Readable
+}
+declare const version: string
+export { Options, add as sum, version };
+`,
+)
@@ -0,0 +1,7 @@
+import type { Readable } from 'node:stream'
+declare function add(a: number, b: number): number
+interface Options {
+  input: Readable
+}
+declare const version: string
+export { Options, add as sum, version };
@@ -0,0 +1,10 @@
+
+// utils/index.js
+function add(a, b) {
+	return a + b;
+}
+
+// main.js
+const version = '1.0.0';
+
+export { add as sum, version };
\ No newline at end of file
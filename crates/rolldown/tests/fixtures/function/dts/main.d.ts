@@ -0,0 +1,3 @@
+export { add as sum } from './utils'
+export * from './types'
+export declare const version: string
@@ -0,0 +1,5 @@
+import type { Readable } from 'node:stream'
+
+export interface Options {
+  input: Readable
+}
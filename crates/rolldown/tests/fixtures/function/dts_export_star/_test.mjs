@@ -0,0 +1,17 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { defaults, name } from './dist/main.mjs'
+
+assert.deepEqual(defaults, { minify: false })
+assert.equal(name, 'options')
+// Names re-exported through both levels of `export *` are exported by the bundled declaration file
+assert.equal(
+  fs.readFileSync(new URL('./dist/main.d.mts', import.meta.url), 'utf8'),
+  `interface Options {
+  minify: boolean
+}
+declare const defaults: Options
+declare const name: string
+export { Options, defaults, name };
+`,
+)
@@ -0,0 +1,4 @@
+export interface Options {
+  minify: boolean
+}
+export declare const defaults: Options
@@ -0,0 +1,6 @@
+interface Options {
+  minify: boolean
+}
+declare const defaults: Options
+declare const name: string
+export { Options, defaults, name };
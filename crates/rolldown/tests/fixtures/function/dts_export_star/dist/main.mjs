@@ -0,0 +1,10 @@
+
+// defaults.js
+const defaults = {
+	minify:false
+};
+
+// options.js
+const name = 'options';
+
+export { defaults, name };
\ No newline at end of file
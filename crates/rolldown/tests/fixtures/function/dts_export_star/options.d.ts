@@ -0,0 +1,2 @@
+export * from './defaults'
+export declare const name: string
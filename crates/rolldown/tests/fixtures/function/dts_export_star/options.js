@@ -0,0 +1,2 @@
+export * from './defaults.js'
+export const name = 'options'
@@ -0,0 +1,6 @@
+import { value } from "../chunks/shared.mjs";
+
+// a.js
+const asyncValue = import('../chunks/async.mjs');
+
+export { asyncValue, value };
\ No newline at end of file
@@ -0,0 +1,12 @@
+import * as ext1 from "external";
+import * as ext from "external";
+import { a, a as a$1, a as a1, b, b as b$1, b as b1 } from "external";
+
+// foo.js
+const a1$1 = 1;
+console.log(a1$1);
+
+// main.js
+console.log(ext, a1, b1, ext1, a, b);
+
+export { a$1 as a, b$1 as b };
\ No newline at end of file
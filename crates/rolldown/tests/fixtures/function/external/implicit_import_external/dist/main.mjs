@@ -0,0 +1,7 @@
+import * as assert_star from "node:assert";
+import { default as assert, equal } from "node:assert";
+
+// main.js
+assert.equal(1, 1);
+assert_star.equal(1, 1);
+equal(1, 1);
@@ -0,0 +1,9 @@
+import { default as assert } from "node:assert";
+import * as ns from "pkg/data.json" with { "type": "json" };
+import { default as data } from "pkg/data.json" with { "type": "json" };
+
+// main.js
+assert.deepStrictEqual(data, {
+	name:'data'
+});
+assert.strictEqual(ns.default, data);
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import data from 'pkg/data.json' with { type: 'json' }
+import * as ns from 'pkg/data.json' with { type: 'json' }
+
+// Attributes of external imports are kept, or Node.js would refuse to import JSON.
+assert.deepStrictEqual(data, { name: 'data' })
+assert.strictEqual(ns.default, data)
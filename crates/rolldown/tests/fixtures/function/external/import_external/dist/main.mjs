@@ -0,0 +1,9 @@
+import * as ext$1 from "external";
+import * as ext from "external";
+import { a, a as a$1, b, b as b$1 } from "external";
+
+// foo.js
+console.log(ext$1, a$1, b$1);
+
+// main.js
+console.log(ext, a, b);
@@ -0,0 +1,8 @@
+import "ext1";
+import "ext2";
+
+// foo.js
+const a = 'foo';
+
+// main.js
+console.log(a);
@@ -0,0 +1,4 @@
+import { value } from "./share.mjs";
+
+// entry.js
+console.log(value);
@@ -0,0 +1,5 @@
+import { value } from "./share.mjs";
+import { default as assert } from "assert";
+
+// main.js
+assert(value === 1);
@@ -0,0 +1,5 @@
+
+// share.js
+const value = 1;
+
+export { value };
\ No newline at end of file
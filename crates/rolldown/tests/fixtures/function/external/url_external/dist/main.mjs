@@ -0,0 +1,5 @@
+import { foo } from "//example.com/protocol-relative.js";
+import { bar } from "https://example.com/absolute.js";
+
+// main.js
+console.log(foo, bar);
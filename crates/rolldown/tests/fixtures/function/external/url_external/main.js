@@ -0,0 +1,4 @@
+import { foo } from '//example.com/protocol-relative.js'
+import { bar } from 'https://example.com/absolute.js'
+
+console.log(foo, bar)
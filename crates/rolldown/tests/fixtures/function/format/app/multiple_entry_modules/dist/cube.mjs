@@ -0,0 +1,9 @@
+// square.js
+export default function square(x) {
+	return x * x;
+}
+// cube.js
+var {default:square} = __static_import('square.js');
+export default function cube(x) {
+	return square(x) * x;
+}
\ No newline at end of file
@@ -0,0 +1,8 @@
+// hyper-cube.js
+var {default:cube} = __static_import('cube.js');
+export default function hyperCube(x) {
+	return cube(x) * x;
+}
+// main.js
+var {default:hyperCube} = __static_import('hyper-cube.js');
+console.log(hyperCube(5));
@@ -0,0 +1,3 @@
+// other-entry.js
+var {default:cube} = __static_import('cube.js');
+console.log(cube(5));
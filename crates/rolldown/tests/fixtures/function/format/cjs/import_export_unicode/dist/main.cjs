@@ -0,0 +1,14 @@
+"use strict";
+
+const { __export, __toCommonJS } = require("./$runtime$.cjs");
+
+// foo.js
+const devil = 'devil';
+
+// main.js
+var main_ns = {};
+__export(main_ns, {
+	'\u{1f608}':() => devil
+});
+
+module.exports = __toCommonJS(main_ns)
\ No newline at end of file
@@ -0,0 +1,11 @@
+"use strict";
+
+
+// counter.js
+let count = 0;
+function increase() {
+	count += 1;
+}
+
+Object.defineProperty(exports, 'count', { enumerable: true, get: () => count });
+exports.increase = increase;
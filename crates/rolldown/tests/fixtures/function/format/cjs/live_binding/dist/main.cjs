@@ -0,0 +1,11 @@
+"use strict";
+
+const require_counter = require("./counter.cjs");
+const { increase } = require_counter;
+
+// main.js
+setTimeout(() => {
+	increase();
+	if (require_counter.count !== 1) 	throw new Error('`count` should be updated');
+
+});
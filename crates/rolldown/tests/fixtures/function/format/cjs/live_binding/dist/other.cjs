@@ -0,0 +1,9 @@
+"use strict";
+
+const require_counter = require("./counter.cjs");
+
+// other.js
+setTimeout(() => {
+	if (require_counter.count !== 1) 	throw new Error('`count` should be updated by other chunks');
+
+}, 10);
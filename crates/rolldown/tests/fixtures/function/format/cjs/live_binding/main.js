@@ -0,0 +1,6 @@
+import { count, increase } from './counter';
+
+setTimeout(() => {
+  increase();
+  if (count !== 1) throw new Error('`count` should be updated');
+});
@@ -0,0 +1,5 @@
+import { count } from './counter';
+
+setTimeout(() => {
+  if (count !== 1) throw new Error('`count` should be updated by other chunks');
+}, 10);
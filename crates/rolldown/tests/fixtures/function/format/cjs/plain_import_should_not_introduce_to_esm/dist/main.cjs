@@ -0,0 +1,5 @@
+"use strict";
+
+require("foo");
+require("bar");
+require("baz");
@@ -0,0 +1,6 @@
+"use strict";
+
+const { __toCommonJS } = require("./$runtime$.cjs");
+const { main_ns, value } = require("./main.cjs");
+
+module.exports = __toCommonJS(main_ns)
\ No newline at end of file
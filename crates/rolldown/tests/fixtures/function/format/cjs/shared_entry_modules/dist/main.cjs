@@ -0,0 +1,13 @@
+"use strict";
+
+const { __export } = require("./$runtime$.cjs");
+
+// main.js
+var main_ns = {};
+__export(main_ns, {
+	value:() => value
+});
+const value = 'main';
+
+exports.main_ns = main_ns;
+exports.value = value;
@@ -0,0 +1,5 @@
+
+// foo.js
+const devil = 'devil';
+
+export { devil as '😈' };
\ No newline at end of file
@@ -0,0 +1,10 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+assert.equal(globalThis.loaded, true)
+const html = fs.readFileSync(new URL('./dist/index.html', import.meta.url), 'utf8')
+assert.match(
+  html,
+  /<head>\n    <link rel="stylesheet" href="assets\/index-[\w-]+\.css"><script type="module" src="index\.mjs"><\/script>\n  <\/head>/,
+)
+assert.doesNotMatch(html, /main\.js|style\.css/)
@@ -0,0 +1,3 @@
+
+// main.js
+globalThis.loaded = true;
@@ -0,0 +1 @@
+globalThis.loaded = true
@@ -0,0 +1,10 @@
+
+// lib.js
+const value = 'lib';
+
+// main.js
+console.log(value);
+
+/*! Shared notice */
+// @license lib v2.0.0 | Apache-2.0
+/*! main v1.0.0 | MIT */
\ No newline at end of file
@@ -0,0 +1,6 @@
+
+// lib.js
+const value = 'lib';
+
+// main.js
+console.log(value);
@@ -0,0 +1,10 @@
+
+// lib.js
+/*! Shared notice */
+// @license lib v2.0.0 | Apache-2.0
+const value = 'lib';
+
+// main.js
+/*! main v1.0.0 | MIT */
+/*! Shared notice */
+console.log(value);
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const code = fs.readFileSync(new URL('./dist/main.mjs', import.meta.url), 'utf8')
+assert.match(code, /\/\*! For license information please see main\.mjs\.LEGAL\.txt \*\/$/)
+assert.doesNotMatch(code, /Shared notice|@license/)
+
+const legal = fs.readFileSync(new URL('./dist/main.mjs.LEGAL.txt', import.meta.url), 'utf8')
+assert.strictEqual(
+  legal,
+  '/*! Shared notice */\n\n// @license lib v2.0.0 | Apache-2.0\n\n/*! main v1.0.0 | MIT */\n',
+)
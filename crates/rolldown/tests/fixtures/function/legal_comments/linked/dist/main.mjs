@@ -0,0 +1,8 @@
+
+// lib.js
+const value = 'lib';
+
+// main.js
+console.log(value);
+
+/*! For license information please see main.mjs.LEGAL.txt */
\ No newline at end of file
@@ -0,0 +1,3 @@
+/*! Shared notice */
+// @license lib v2.0.0 | Apache-2.0
+export const value = 'lib'
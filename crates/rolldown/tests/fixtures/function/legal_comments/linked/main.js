@@ -0,0 +1,6 @@
+/*! main v1.0.0 | MIT */
+/*! Shared notice */
+import { value } from './lib.js'
+
+// A regular comment that is never kept
+console.log(value)
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+assert.ok(!fs.existsSync(new URL('./dist/main.mjs.LEGAL.txt', import.meta.url)))
@@ -0,0 +1,7 @@
+import { default as assert } from "node:assert";
+
+// foo.json2
+var foo_default = ['foo'];
+
+// main.js
+assert.deepStrictEqual(foo_default, ['foo']);
@@ -0,0 +1,12 @@
+import { default as assert } from "node:assert";
+
+// foo.json
+const foo = 1;
+var foo_default = {
+	foo
+};
+
+// main.js
+assert.deepStrictEqual(foo_default, {
+	foo:foo
+});
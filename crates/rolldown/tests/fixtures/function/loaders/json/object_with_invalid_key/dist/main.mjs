@@ -0,0 +1,12 @@
+import { default as assert } from "node:assert";
+
+// foo.json
+const key_0 = 1;
+var foo_default = {
+	'\u{1f608}':key_0
+};
+
+// main.js
+assert.deepStrictEqual(foo_default, {
+	'\u{1f608}':key_0
+});
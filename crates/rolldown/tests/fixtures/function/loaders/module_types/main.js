@@ -0,0 +1,3 @@
+import assert from 'node:assert'
+import value from './foo.json2'
+assert.deepStrictEqual(value, ['foo'])
\ No newline at end of file
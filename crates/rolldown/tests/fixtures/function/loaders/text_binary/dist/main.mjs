@@ -0,0 +1,12 @@
+import { default as assert } from "node:assert";
+
+// shader.frag
+var shader_default = 'void main() {\n  gl_FragColor = vec4(1.0);\n}\n';
+
+// data.bin
+var data_default = Uint8Array.from(atob('AAH+/w=='), c => c.charCodeAt(0));
+
+// main.js
+assert.strictEqual(shader_default, 'void main() {\n  gl_FragColor = vec4(1.0);\n}\n');
+assert.ok(data_default instanceof  Uint8Array);
+assert.deepStrictEqual([...data_default], [0x00, 0x01, 0xfe, 0xff]);
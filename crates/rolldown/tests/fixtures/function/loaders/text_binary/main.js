@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import shader from './shader.frag'
+import data from './data.bin'
+
+// The byte order mark is not part of the text.
+assert.strictEqual(shader, 'void main() {\n  gl_FragColor = vec4(1.0);\n}\n')
+// Bytes that are not valid utf8 are kept.
+assert.ok(data instanceof Uint8Array)
+assert.deepStrictEqual([...data], [0x00, 0x01, 0xfe, 0xff])
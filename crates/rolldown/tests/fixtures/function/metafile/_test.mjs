@@ -0,0 +1,22 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const metafile = JSON.parse(fs.readFileSync(new URL('./dist/meta.json', import.meta.url), 'utf8'))
+
+assert.deepStrictEqual(Object.keys(metafile.inputs), ['main.js', 'shared.js', 'cjs.js'])
+assert.deepStrictEqual(metafile.inputs['main.js'].imports[0], {
+  path: 'node:path',
+  kind: 'import-statement',
+  original: 'node:path',
+  external: true,
+})
+assert.deepStrictEqual(metafile.inputs['cjs.js'].wrap, { kind: 'cjs', reason: 'commonjs' })
+
+const main = metafile.outputs['dist/main.mjs']
+assert.strictEqual(main.entryPoint, 'main.js')
+assert.deepStrictEqual(main.exports, ['joined'])
+assert.deepStrictEqual(Object.keys(main.inputs), ['shared.js', 'cjs.js', 'main.js'])
+for (const input of Object.values(main.inputs)) {
+  assert.ok(input.bytesInOutput > 0)
+}
+assert.ok(main.imports.some((item) => item.path === 'node:path' && item.external))
@@ -0,0 +1,18 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+import { default as path } from "node:path";
+
+// shared.js
+const value = 'shared';
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = {
+		name:'cjs'
+	};
+});
+
+// main.js
+var import_cjs = __toESM(require_cjs());
+const joined = path.join(value, import_cjs.default.name);
+
+export { joined };
\ No newline at end of file
@@ -0,0 +1,5 @@
+import path from 'node:path'
+import { value } from './shared.js'
+import cjs from './cjs.js'
+
+export const joined = path.join(value, cjs.name)
@@ -0,0 +1,24 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import { required, lazy, legacy, script } from './dist/main.mjs'
+
+assert.strictEqual(legacy, 'legacy')
+assert.strictEqual(required, 'dep')
+assert.strictEqual(lazy().value, 'side')
+assert.strictEqual(globalThis.script, true)
+assert.ok(script)
+
+const metafile = JSON.parse(fs.readFileSync(new URL('./dist/meta.json', import.meta.url), 'utf8'))
+const wraps = Object.fromEntries(
+  Object.entries(metafile.inputs).map(([path, input]) => [path, input.wrap]),
+)
+
+assert.deepStrictEqual(wraps, {
+  'main.js': undefined,
+  'legacy.js': { kind: 'cjs', reason: 'commonjs' },
+  'script.js': { kind: 'cjs', reason: 'imported-as-commonjs', importer: 'main.js' },
+  'required.js': { kind: 'cjs', reason: 'required', importer: 'main.js' },
+  'lazy.js': { kind: 'esm', reason: 'required', importer: 'main.js' },
+  'dep.js': { kind: 'cjs', reason: 'required', importer: 'required.js' },
+  'side.js': { kind: 'esm', reason: 'imported-by-wrapped', importer: 'lazy.js' },
+})
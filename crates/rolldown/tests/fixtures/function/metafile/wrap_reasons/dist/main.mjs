@@ -0,0 +1,47 @@
+import { __commonJSMin, __esmMin, __export, __toCommonJS, __toESM } from "./$runtime$.mjs";
+
+// legacy.js
+var require_legacy = __commonJSMin((exports, module) => {
+	module.exports = 'legacy';
+});
+
+// script.js
+var require_script = __commonJSMin((exports, module) => {
+	globalThis.script = true;
+});
+
+// dep.js
+var require_dep = __commonJSMin((exports, module) => {
+	exports.dep = 'dep';
+});
+
+// required.js
+var require_required = __commonJSMin((exports, module) => {
+	const {dep} = require_dep();
+	module.exports = dep;
+});
+
+// side.js
+var side;
+var init_side = __esmMin(() => {
+	side = 'side';
+});
+
+// lazy.js
+var lazy_ns;
+var init_lazy = __esmMin(() => {
+	lazy_ns = {};
+	__export(lazy_ns, {
+		value:() => side
+	});
+	init_side();
+});
+
+// main.js
+var import_legacy = __toESM(require_legacy());
+var import_script = __toESM(require_script());
+const required = require_required();
+const lazy = () => (init_lazy(),__toCommonJS(lazy_ns));
+
+var legacy = import_legacy.default;
+export { lazy, legacy, required, import_script as script };
\ No newline at end of file
@@ -0,0 +1 @@
+export { side as value } from './side.js'
@@ -0,0 +1,6 @@
+import legacy from './legacy.js'
+import * as script from './script.js'
+
+export const required = require('./required.js')
+export const lazy = () => require('./lazy.js')
+export { legacy, script }
@@ -0,0 +1,3 @@
+const { dep } = require('./dep.js')
+
+module.exports = dep
@@ -0,0 +1 @@
+globalThis.script = true
@@ -0,0 +1 @@
+export const side = 'side'
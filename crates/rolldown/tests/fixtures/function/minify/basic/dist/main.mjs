@@ -0,0 +1,2 @@
+import {default as assert} from 'node:assert';function add(a,b){return a+b}assert.strictEqual(add(1,2),3);
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
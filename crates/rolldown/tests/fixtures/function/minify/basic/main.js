@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import { add } from './math.js'
+
+// Comments are removed along with whitespace
+assert.strictEqual(add(1, 2), 3)
@@ -0,0 +1,9 @@
+import { default as assert } from "node:assert";
+
+// src/paths.js
+const dir = '/src';
+const file = '/src/paths.js';
+
+// main.js
+assert.strictEqual(file, '/src/paths.js');
+assert.strictEqual(dir, '/src');
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import { dir, file } from './src/paths.js'
+
+// Other platforms have no file system, so the paths of the module relative to `cwd` are used.
+assert.strictEqual(file, '/src/paths.js')
+assert.strictEqual(dir, '/src')
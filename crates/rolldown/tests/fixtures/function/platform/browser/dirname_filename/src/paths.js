@@ -0,0 +1,2 @@
+export const dir = __dirname
+export const file = __filename
@@ -0,0 +1,16 @@
+import { fileURLToPath as __rolldown_fileURLToPath } from "node:url";
+var __filename = __rolldown_fileURLToPath(import.meta.url);
+import { dirname as __rolldown_dirname } from "node:path";
+var __dirname = __rolldown_dirname(__filename);
+import { default as assert } from "node:assert";
+import { default as path } from "node:path";
+import { fileURLToPath } from "node:url";
+
+// src/paths.js
+const dir = __dirname;
+const file = __filename;
+
+// main.js
+assert.strictEqual(file, fileURLToPath(import.meta.url));
+assert.strictEqual(path.basename(file), 'main.mjs');
+assert.strictEqual(dir, path.dirname(file));
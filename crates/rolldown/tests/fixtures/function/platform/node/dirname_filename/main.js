@@ -0,0 +1,9 @@
+import assert from 'node:assert'
+import path from 'node:path'
+import { fileURLToPath } from 'node:url'
+import { dir, file } from './src/paths.js'
+
+// In ESM output, the paths are derived from `import.meta.url` of the chunk.
+assert.strictEqual(file, fileURLToPath(import.meta.url))
+assert.strictEqual(path.basename(file), 'main.mjs')
+assert.strictEqual(dir, path.dirname(file))
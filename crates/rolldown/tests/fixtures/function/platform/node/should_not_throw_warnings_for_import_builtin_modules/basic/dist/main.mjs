@@ -0,0 +1,7 @@
+import { createRequire as __rolldown_createRequire } from "node:module";
+var require = __rolldown_createRequire(import.meta.url);
+import * as fs from "node:fs";
+import * as nodeFs from "node:fs";
+
+// main.js
+console.log(fs, nodeFs, require('node:path'), require('node:path'));
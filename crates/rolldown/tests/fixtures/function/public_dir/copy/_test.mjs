@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// Files are copied with their names and contents as they are, including the ones in nested directories.
+const read = (file) => fs.readFileSync(new URL(`./dist/${file}`, import.meta.url), 'utf8')
+assert.strictEqual(read('robots.txt'), 'User-agent: *\n')
+assert.strictEqual(read('images/logo.svg'), '<svg></svg>')
+assert.match(read('main.mjs'), /console\.log\('main'\)/)
@@ -0,0 +1,15 @@
+import { default as assert } from "node:assert";
+
+// local.js
+const calls = [];
+function foo$1() {
+	calls.push('foo');
+}
+foo$1();
+const console$1 = {
+	log:() => calls.push('console.log')
+};
+console$1.log();
+
+// main.js
+assert.deepStrictEqual(calls, ['foo', 'console.log']);
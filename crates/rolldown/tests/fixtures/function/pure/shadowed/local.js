@@ -0,0 +1,9 @@
+export const calls = []
+
+// Calls of local functions named as pure functions still have side effects.
+function foo() {
+  calls.push('foo')
+}
+foo()
+const console = { log: () => calls.push('console.log') }
+console.log()
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { calls } from './local.js'
+
+// Unused calls of the global ones are removed, so `foo` doesn't need to be defined.
+foo()
+console.log('removed')
+
+assert.deepStrictEqual(calls, ['foo', 'console.log'])
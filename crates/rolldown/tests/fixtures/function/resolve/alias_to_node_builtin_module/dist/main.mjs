@@ -0,0 +1,6 @@
+import { createRequire as __rolldown_createRequire } from "node:module";
+var require = __rolldown_createRequire(import.meta.url);
+import { readFile } from "node:fs";
+
+// main.js
+require('node:fs');
@@ -0,0 +1,9 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+
+// (ignored) node_modules/package/util.js
+var require_util = __commonJSMin((exports, module) => {
+});
+
+// node_modules/package/index.js
+var import_util = __toESM(require_util());
+console.log(import_util.default);
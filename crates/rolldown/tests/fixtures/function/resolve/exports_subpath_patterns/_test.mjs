@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import { root, feature, hello, util } from './dist/main.mjs'
+
+assert.strictEqual(root, 'root')
+assert.strictEqual(feature, 'a')
+assert.strictEqual(hello, 'hello')
+assert.strictEqual(util, 'format')
@@ -0,0 +1,14 @@
+
+// node_modules/pkg/src/root.js
+const root = 'root';
+
+// node_modules/pkg/src/features/a/index.js
+const feature = 'a';
+
+// node_modules/pkg/src/locales/en/en.js
+const hello = 'hello';
+
+// node_modules/pkg/src/utils/format.js
+const util = 'format';
+
+export { feature, hello, root, util };
\ No newline at end of file
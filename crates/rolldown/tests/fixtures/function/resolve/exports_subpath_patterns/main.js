@@ -0,0 +1,6 @@
+import { root } from 'pkg/root'
+import { feature } from 'pkg/features/a'
+import { hello } from 'pkg/locales/en'
+import { util } from 'pkg/utils/format'
+
+export { root, feature, hello, util }
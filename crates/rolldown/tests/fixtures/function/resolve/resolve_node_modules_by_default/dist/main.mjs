@@ -0,0 +1,11 @@
+
+// node_modules/is-plain-obj/index.js
+function isPlainObject(value) {
+	if (typeof value !== 'object' || value === null) {
+		return false;
+	}
+	const prototype = Object.getPrototypeOf(value);
+	return (prototype === null || prototype === Object.prototype || Object.getPrototypeOf(prototype) === null) &&  !(Symbol.toStringTag in  value) &&  !(Symbol.iterator in  value);
+}
+
+export { isPlainObject as default };
\ No newline at end of file
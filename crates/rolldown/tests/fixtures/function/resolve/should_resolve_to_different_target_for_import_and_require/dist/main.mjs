@@ -0,0 +1,14 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// node_modules/package/esm-output.js
+const output = 'esm';
+
+// node_modules/package/cjs-output.js
+var require_cjs_output = __commonJSMin((exports, module) => {
+	module.exports.output = 'cjs';
+});
+
+// main.js
+const {output:requireOutput} = require_cjs_output();
+
+export { output as importOutput, requireOutput };
\ No newline at end of file
@@ -0,0 +1,14 @@
+import { __esmMin, __toCommonJS } from "./$runtime$.mjs";
+
+// foo.js
+var foo_ns, missing;
+var init_foo = __esmMin(() => {
+	foo_ns = {};
+	missing = void 0;
+});
+
+// main.js
+init_foo();
+init_foo(),__toCommonJS(foo_ns);
+
+export { missing };
\ No newline at end of file
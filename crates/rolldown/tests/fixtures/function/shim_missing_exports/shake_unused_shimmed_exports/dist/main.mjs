@@ -0,0 +1,5 @@
+
+// foo.js
+var missing = void 0;
+
+export { missing };
\ No newline at end of file
@@ -0,0 +1,13 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const map = JSON.parse(fs.readFileSync(new URL('./dist/main.mjs.map', import.meta.url), 'utf8'))
+assert.deepStrictEqual(map.sources, ['../main.js'])
+assert.deepStrictEqual(map.names, [])
+// Each line has a single mapping at its start, which maps to the start of a line of the source. The generated and the
+// original columns are 0, `A` in base64 VLQ.
+const segments = map.mappings.split(';').filter(Boolean)
+assert.strictEqual(segments.length, 2)
+for (const segment of segments) {
+  assert.match(segment, /^A[A-Za-z0-9+/]{2}A$/)
+}
@@ -0,0 +1,6 @@
+
+// main.js
+const message = ['hello', 'world'].join(' ');
+console.log(message, message.length);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,2 @@
+const message = ['hello', 'world'].join(' ')
+console.log(message, message.length)
@@ -0,0 +1,5 @@
+
+// main.js
+console.log('main');
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
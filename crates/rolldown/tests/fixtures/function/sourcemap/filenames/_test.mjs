@@ -0,0 +1,13 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const dist = new URL('./dist/', import.meta.url)
+const chunk = fs.readdirSync(dist).find((file) => file.endsWith('.mjs'))
+const [, hash] = chunk.match(/^main-(.+)\.mjs$/)
+// `[chunkhash]` is the hash in the filename of the chunk, which refers to the `.map` file.
+const map = `maps/main-${hash.slice(0, 8)}.js.map`
+const code = fs.readFileSync(new URL(chunk, dist), 'utf8')
+assert(code.endsWith(`//# sourceMappingURL=${map}`), code)
+// Sources are relative to the `.map` file.
+const { sources } = JSON.parse(fs.readFileSync(new URL(map, dist), 'utf8'))
+assert.deepStrictEqual(sources, ['../../main.js'])
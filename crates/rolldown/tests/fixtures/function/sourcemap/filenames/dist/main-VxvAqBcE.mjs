@@ -0,0 +1,5 @@
+
+// main.js
+console.log('main');
+
+//# sourceMappingURL=maps/main-VxvAqBcE.js.map
\ No newline at end of file
@@ -0,0 +1,7 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// The `.map` file is emitted, but not referenced by the chunk.
+assert(fs.existsSync(new URL('./dist/main.mjs.map', import.meta.url)))
+const code = fs.readFileSync(new URL('./dist/main.mjs', import.meta.url), 'utf8')
+assert(!code.includes('sourceMappingURL'))
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// Sources in `node_modules` are ignored by default.
+const map = JSON.parse(fs.readFileSync(new URL('./dist/main.mjs.map', import.meta.url), 'utf8'))
+assert.strictEqual(map.sources.length, 3)
+const ignored = map.x_google_ignoreList.map((index) => map.sources[index])
+assert.deepStrictEqual(ignored, ['../node_modules/dep/index.js'])
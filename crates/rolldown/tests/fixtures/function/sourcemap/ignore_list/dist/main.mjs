@@ -0,0 +1,11 @@
+
+// node_modules/dep/index.js
+const dep = 'dep';
+
+// util.js
+const util = 'util';
+
+// main.js
+console.log(dep, util);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,3 @@
+import { dep } from 'dep'
+import { util } from './util.js'
+console.log(dep, util)
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+assert(!fs.existsSync(new URL('./dist/main.mjs.map', import.meta.url)))
@@ -0,0 +1,5 @@
+
+// main.js
+console.log('main');
+
+//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjozLCJmaWxlIjoibWFpbi5tanMiLCJuYW1lcyI6W10sInNvdXJjZXMiOlsiLi4vbWFpbi5qcyJdLCJzb3VyY2VzQ29udGVudCI6WyJjb25zb2xlLmxvZygnbWFpbicpXG4iXSwibWFwcGluZ3MiOiI7O0FBQUEsUUFBUSxJQUFJLE9BQU8ifQ==
\ No newline at end of file
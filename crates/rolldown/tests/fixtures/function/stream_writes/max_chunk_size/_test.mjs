@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// Chunks are measured as they are written, and written anyway.
+const code = fs.readFileSync(new URL('./dist/main.mjs', import.meta.url), 'utf8')
+assert(code.includes("const value = 'value'"), code)
@@ -0,0 +1,5 @@
+
+// main.js
+const value = 'value';
+
+export { value };
\ No newline at end of file
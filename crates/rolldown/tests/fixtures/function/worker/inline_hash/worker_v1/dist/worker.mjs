@@ -0,0 +1,3 @@
+
+// worker.js
+postMessage('version 1');
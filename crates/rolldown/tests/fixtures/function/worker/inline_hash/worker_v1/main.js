@@ -0,0 +1,4 @@
+// Only the worker differs between `worker_v1` and `worker_v2`, so the hashes of `main` differ because of the worker.
+import InlineWorker from './worker.js?worker&inline'
+
+export const createWorker = () => new InlineWorker()
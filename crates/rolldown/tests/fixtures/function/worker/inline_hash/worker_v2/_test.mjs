@@ -0,0 +1,5 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+const code = fs.readFileSync(new URL('./dist/main.mjs', import.meta.url), 'utf8')
+assert(code.includes('version 2'))
@@ -0,0 +1,13 @@
+
+// worker.js?worker&inline
+function WorkerWrapper(options) {
+	return new Worker(URL.createObjectURL(new Blob(["\n// worker.js\npostMessage('version 2');\n"], { type: "text/javascript" })), {
+		type:'module',
+		...options
+	});
+}
+
+// main.js
+const createWorker = () => new WorkerWrapper();
+
+export { createWorker };
\ No newline at end of file
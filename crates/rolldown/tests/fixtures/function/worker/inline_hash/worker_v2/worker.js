@@ -0,0 +1 @@
+postMessage('version 2')
@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+
+// Both workers are embedded, the inner one inside the code of the outer one.
+const code = fs.readFileSync(new URL('./dist/main.mjs', import.meta.url), 'utf8')
+assert(!code.includes('rolldown-inline-worker:'))
+assert(code.includes('outer received'))
+assert(code.includes('hello from the inner worker'))
@@ -0,0 +1,5 @@
+
+// inner.js
+postMessage('hello from the inner worker');
+
+//# sourceMappingURL=inner.mjs.map
\ No newline at end of file
@@ -0,0 +1,14 @@
+
+// outer.js?worker&inline
+function WorkerWrapper(options) {
+	return new Worker(URL.createObjectURL(new Blob(["\n// inner.js?worker&inline\nfunction WorkerWrapper(options) {\n	return new Worker(URL.createObjectURL(new Blob([\"\\n// inner.js\\npostMessage('hello from the inner worker');\\n\"], { type: \"text/javascript\" })), {\n		type:'module',\n		...options\n	});\n}\n\n// outer.js\nconst inner = new WorkerWrapper();\ninner.onmessage = event => postMessage(`outer received ${event.data}`);\n"], { type: "text/javascript" })), {
+		type:'module',
+		...options
+	});
+}
+
+// main.js
+const createWorker = () => new WorkerWrapper();
+
+export { createWorker };
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
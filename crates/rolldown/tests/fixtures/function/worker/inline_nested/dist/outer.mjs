@@ -0,0 +1,14 @@
+
+// inner.js?worker&inline
+function WorkerWrapper(options) {
+	return new Worker(URL.createObjectURL(new Blob(["\n// inner.js\npostMessage('hello from the inner worker');\n"], { type: "text/javascript" })), {
+		type:'module',
+		...options
+	});
+}
+
+// outer.js
+const inner = new WorkerWrapper();
+inner.onmessage = event => postMessage(`outer received ${event.data}`);
+
+//# sourceMappingURL=outer.mjs.map
\ No newline at end of file
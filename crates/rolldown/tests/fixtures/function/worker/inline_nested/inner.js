@@ -0,0 +1 @@
+postMessage('hello from the inner worker')
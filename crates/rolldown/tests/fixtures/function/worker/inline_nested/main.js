@@ -0,0 +1,3 @@
+import OuterWorker from './outer.js?worker&inline'
+
+export const createWorker = () => new OuterWorker()
@@ -0,0 +1,4 @@
+import InnerWorker from './inner.js?worker&inline'
+
+const inner = new InnerWorker()
+inner.onmessage = (event) => postMessage(`outer received ${event.data}`)
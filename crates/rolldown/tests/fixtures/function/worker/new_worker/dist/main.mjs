@@ -0,0 +1,19 @@
+import { default as assert } from "node:assert";
+import { Worker as NodeWorker } from "node:worker_threads";
+
+// main.js
+globalThis.Worker = NodeWorker;
+const worker = new Worker(new URL('./worker.mjs', import.meta.url), {
+	type:'module'
+});
+let received;
+worker.once('message', answer => {
+	received = answer;
+	worker.terminate();
+});
+process.once('exit', () => assert.strictEqual(received, 42));
+const createSharedWorker = () => new SharedWorker(new URL('./shared_worker.mjs', import.meta.url), {
+	type:'module'
+});
+
+export { createSharedWorker };
\ No newline at end of file
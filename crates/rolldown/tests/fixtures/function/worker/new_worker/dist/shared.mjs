@@ -0,0 +1,5 @@
+
+// shared.js
+const answer = 42;
+
+export { answer };
\ No newline at end of file
@@ -0,0 +1,4 @@
+import { answer } from "./shared.mjs";
+
+// shared_worker.js
+onconnect = event => event.ports[0].postMessage(answer);
@@ -0,0 +1,5 @@
+import { answer } from "./shared.mjs";
+import { parentPort } from "node:worker_threads";
+
+// worker.js
+parentPort.postMessage(answer);
@@ -0,0 +1,17 @@
+import assert from 'node:assert'
+import { Worker as NodeWorker } from 'node:worker_threads'
+
+// Stands in for the `Worker` global of browsers, which is what references to workers are detected by.
+globalThis.Worker = NodeWorker
+
+// The worker is bundled as a separate entry chunk, and the URL points at that chunk.
+const worker = new Worker(new URL('./worker.js', import.meta.url), { type: 'module' })
+let received
+worker.once('message', (answer) => {
+  received = answer
+  worker.terminate()
+})
+process.once('exit', () => assert.strictEqual(received, 42))
+
+export const createSharedWorker = () =>
+  new SharedWorker(new URL('./shared_worker.js', import.meta.url), { type: 'module' })
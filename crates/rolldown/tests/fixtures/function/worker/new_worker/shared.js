@@ -0,0 +1 @@
+export const answer = 42
@@ -0,0 +1,3 @@
+import { answer } from './shared.js'
+
+onconnect = (event) => event.ports[0].postMessage(answer)
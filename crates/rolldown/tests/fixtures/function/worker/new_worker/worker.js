@@ -0,0 +1,4 @@
+import { parentPort } from 'node:worker_threads'
+import { answer } from './shared.js'
+
+parentPort.postMessage(answer)
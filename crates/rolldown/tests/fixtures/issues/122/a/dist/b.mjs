@@ -0,0 +1,3 @@
+
+// b.js
+console.log('b');
@@ -0,0 +1,3 @@
+
+// c.js
+console.log('c');
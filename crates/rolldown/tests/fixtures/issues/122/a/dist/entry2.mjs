@@ -0,0 +1,2 @@
+import "./c.mjs";
+import "./b.mjs";
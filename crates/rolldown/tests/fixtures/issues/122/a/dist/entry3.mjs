@@ -0,0 +1,5 @@
+import "./c.mjs";
+import "./b.mjs";
+
+// a.js
+console.log('a');
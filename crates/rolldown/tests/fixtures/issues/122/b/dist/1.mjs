@@ -0,0 +1,3 @@
+
+// 1.js
+console.log(1);
@@ -0,0 +1,3 @@
+
+// 2.js
+console.log(2);
@@ -0,0 +1,5 @@
+import "./1.mjs";
+import "./2.mjs";
+
+// a.js
+console.log('a');
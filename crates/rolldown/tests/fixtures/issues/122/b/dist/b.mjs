@@ -0,0 +1,5 @@
+import "./1.mjs";
+import "./2.mjs";
+
+// b.js
+console.log('a');
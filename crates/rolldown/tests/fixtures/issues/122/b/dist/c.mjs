@@ -0,0 +1,4 @@
+import "./1.mjs";
+
+// c.js
+console.log('c');
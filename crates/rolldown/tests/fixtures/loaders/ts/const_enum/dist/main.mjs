@@ -0,0 +1,4 @@
+
+// main.ts
+const move = direction => `moving ${direction}`;
+console.log(move(0), move(2), move( -1), move('right'));
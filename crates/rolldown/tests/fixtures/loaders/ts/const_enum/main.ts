@@ -0,0 +1,5 @@
+import { Direction } from './direction'
+
+const move = (direction: Direction): string => `moving ${direction}`
+
+console.log(move(Direction.Up), move(Direction.Down), move(Direction.Left), move(Direction['Right']))
@@ -0,0 +1,6 @@
+export const enum Direction {
+  Up,
+  Down = Up + 2,
+  Left = -1,
+  Right = 'right',
+}
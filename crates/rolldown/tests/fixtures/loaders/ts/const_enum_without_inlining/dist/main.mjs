@@ -0,0 +1,15 @@
+
+// direction.ts
+var Direction = {
+	Up:0,
+	'0':'Up',
+	Down:2,
+	'2':'Down',
+	Left: -1,
+	'-1':'Left',
+	Right:'right'
+};
+
+// main.ts
+const move = direction => `moving ${direction}`;
+console.log(move(Direction.Up), move(Direction.Down), move(Direction.Left), move(Direction['Right']));
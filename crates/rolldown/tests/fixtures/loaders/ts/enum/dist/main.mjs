@@ -0,0 +1,20 @@
+
+// main.ts
+let Color = function(Color$1) {
+	Color$1[Color$1['Red'] = 0] = 'Red';
+	Color$1[Color$1['Green'] = 2] = 'Green';
+	Color$1['Blue'] = 'blue';
+	return Color$1;
+}({});
+let Size = function(Size$1) {
+	Size$1[Size$1['Small'] = Math.random()] = 'Small';
+	Size$1[Size$1['Medium'] = 1 + Size$1['Small']] = 'Medium';
+	Size$1[Size$1['Large'] = Size$1.Medium * 2] = 'Large';
+	return Size$1;
+}({});
+Color = function(Color$1) {
+	Color$1[Color$1['Purple'] = 10] = 'Purple';
+	return Color$1;
+}(Color || {});
+
+export { Color, Size };
\ No newline at end of file
@@ -0,0 +1,15 @@
+export enum Color {
+  Red,
+  Green = Red + 2,
+  Blue = 'blue',
+}
+
+export enum Size {
+  Small = Math.random(),
+  Medium,
+  Large = Medium * 2,
+}
+
+export enum Color {
+  Purple = 10,
+}
@@ -0,0 +1,6 @@
+import assert from 'assert'
+import { Geometry } from './dist/main.mjs'
+
+assert.equal(Geometry.double(2), 4)
+assert.equal(Geometry.half, 0.5)
+assert.equal(Geometry.Shapes.sides.triangle, 3)
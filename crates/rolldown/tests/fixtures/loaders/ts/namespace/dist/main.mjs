@@ -0,0 +1,21 @@
+
+// main.ts
+let Geometry;
+(function(_Geometry) {
+	const unit = _Geometry.unit = 1;
+	function double(value) {
+		return value * unit * 2;
+	}
+	_Geometry.double = double;
+	let Shapes;
+	(function(_Shapes) {
+		const sides = _Shapes.sides = {
+			triangle:3
+		};
+	})(Shapes || (Shapes = _Geometry.Shapes || (_Geometry.Shapes = {})));
+})(Geometry || (Geometry = {}));
+(function(_Geometry) {
+	const half = _Geometry.half = Geometry.double(Geometry.unit) / 4;
+})(Geometry || (Geometry = {}));
+
+export { Geometry };
\ No newline at end of file
@@ -0,0 +1,15 @@
+export namespace Geometry {
+  export const unit = 1
+
+  export function double(value: number): number {
+    return value * unit * 2
+  }
+
+  export namespace Shapes {
+    export const sides = { triangle: 3 }
+  }
+}
+
+export namespace Geometry {
+  export const half = Geometry.double(Geometry.unit) / 4
+}
@@ -0,0 +1,29 @@
+
+// types.ts
+const origin = {
+	x:0,
+	y:0
+};
+
+// main.ts
+class Shape {
+}
+class Circle extends Shape {
+	constructor(center, radius){
+		super();
+		this.center = center;
+		this.radius = radius;
+	}
+	area(){
+		return Math.PI * this.radius ** 2;
+	}
+	pair(){
+		return [this.center.x, this.center.y];
+	}
+}
+function first(items) {
+	return items[0];
+}
+const circle = new Circle(origin, first([1]));
+
+export { Circle, circle };
\ No newline at end of file
@@ -0,0 +1,30 @@
+import type { Pair } from './types'
+import { origin, Point } from './types'
+
+declare const DEBUG: boolean
+
+abstract class Shape {
+  declare kind: string
+  abstract area(): number
+}
+
+export class Circle extends Shape {
+  constructor(private readonly center: Point, public radius: number) {
+    super()
+  }
+
+  area(): number {
+    return Math.PI * this.radius ** 2
+  }
+
+  pair(): Pair {
+    return [this.center.x, this.center.y]
+  }
+}
+
+function first<T>(items: T[]): T
+function first(items: unknown[]) {
+  return items[0]
+}
+
+export const circle = new Circle(origin as Point, first<number>([1])!) satisfies Shape
@@ -0,0 +1,8 @@
+export interface Point {
+  x: number
+  y: number
+}
+
+export type Pair = [number, number]
+
+export const origin: Point = { x: 0, y: 0 }
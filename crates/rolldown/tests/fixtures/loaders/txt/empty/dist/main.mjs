@@ -0,0 +1,6 @@
+
+// x.txt
+var x_default = '';
+
+// main.js
+console.log(x_default);
@@ -0,0 +1,6 @@
+
+// c.js
+let x = 1;
+let y = 2;
+
+export { x, y };
\ No newline at end of file
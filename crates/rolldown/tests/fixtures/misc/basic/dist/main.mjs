@@ -0,0 +1,9 @@
+
+// shared.js
+const a$1 = 'shared.js';
+
+// main.js
+const a = 'index.js';
+console.log(a, a$1);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,7 @@
+
+// a.js
+const a$1 = 'a.js';
+
+// main.js
+const a = 'index.js';
+console.log(a, a$1);
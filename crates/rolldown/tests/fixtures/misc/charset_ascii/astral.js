@@ -0,0 +1,2 @@
+export const 𐊧 = 'face 😀'
+export const pattern = /😀/
@@ -0,0 +1,13 @@
+import { default as assert } from "node:assert";
+
+// astral.js
+const \u{102A7} = 'face \u{1f600}';
+const pattern = /\uD83D\uDE00/;
+
+// main.js
+const caf\u00E9 = 'caf\u{e9} \u{1f600}';
+assert.strictEqual(\u{102A7}, 'face \u{1f600}');
+assert.ok(pattern.test('\u{1f600}'));
+assert.strictEqual(caf\u00E9, 'caf\u{e9} \u{1f600}');
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
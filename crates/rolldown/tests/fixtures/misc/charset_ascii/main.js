@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { 𐊧, pattern } from './astral.js'
+
+const café = 'café 😀'
+
+assert.strictEqual(𐊧, 'face \u{1F600}')
+assert.ok(pattern.test('😀'))
+assert.strictEqual(café, 'café 😀')
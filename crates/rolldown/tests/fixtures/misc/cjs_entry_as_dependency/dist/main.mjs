@@ -0,0 +1,10 @@
+import { __commonJSMin, __toESM } from "./$runtime$.mjs";
+import { require_main2 } from "./main2~1.mjs";
+
+// main.js
+var require_main = __commonJSMin((exports, module) => {
+	var import_main2 = __toESM(require_main2());
+	module.exports = import_main2.default;
+});
+
+export default require_main();
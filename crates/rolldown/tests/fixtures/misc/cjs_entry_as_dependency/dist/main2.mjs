@@ -0,0 +1,3 @@
+import { require_main2 } from "./main2~1.mjs";
+
+export default require_main2();
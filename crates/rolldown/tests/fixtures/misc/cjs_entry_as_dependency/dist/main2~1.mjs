@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// main2.js
+var require_main2 = __commonJSMin((exports, module) => {
+	module.exports = {};
+});
+
+export { require_main2 };
\ No newline at end of file
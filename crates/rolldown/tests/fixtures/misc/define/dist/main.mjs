@@ -0,0 +1,12 @@
+
+// shadowed.js
+function readEnv(process) {
+	return process.env.NODE_ENV;
+}
+
+// main.js
+if ('production' !== 'production') {
+	console.log('development');
+}
+console.log('https://example.com', false, process.env.MISSING);
+console.log(readEnv(globalThis.process));
@@ -0,0 +1,7 @@
+import { readEnv } from './shadowed.js'
+
+if (process.env.NODE_ENV !== 'production') {
+  console.log('development')
+}
+console.log(import.meta.env.API_URL, __DEV__, process.env.MISSING)
+console.log(readEnv(globalThis.process))
@@ -0,0 +1,3 @@
+export function readEnv(process) {
+  return process.env.NODE_ENV
+}
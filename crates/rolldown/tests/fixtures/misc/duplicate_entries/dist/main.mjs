@@ -0,0 +1 @@
+export { main_default as default } from "./main~1.mjs";
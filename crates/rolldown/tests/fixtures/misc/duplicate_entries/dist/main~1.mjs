@@ -0,0 +1,5 @@
+
+// main.js
+var main_default = {};
+
+export { main_default };
\ No newline at end of file
@@ -0,0 +1,14 @@
+"use strict";
+
+const { __export, __reExport, __toCommonJS, __toESM } = require("./$runtime$.cjs");
+const import_ext = __toESM(require("ext"));
+
+// main.js
+var main_ns = {};
+__export(main_ns, {
+	value:() => value
+});
+__reExport(main_ns, import_ext);
+const value = 1;
+
+module.exports = __toCommonJS(main_ns)
\ No newline at end of file
@@ -0,0 +1,2 @@
+export * from 'ext'
+export const value = 1
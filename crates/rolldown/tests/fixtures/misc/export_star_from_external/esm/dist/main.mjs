@@ -0,0 +1,7 @@
+import "ext";
+
+// main.js
+const value = 1;
+
+export * from "ext";
+export { value };
\ No newline at end of file
@@ -0,0 +1,9 @@
+import { __reExport } from "./$runtime$.mjs";
+import * as import_ext from "ext";
+
+// reexport.js
+var reexport_ns = {};
+__reExport(reexport_ns, import_ext);
+
+// main.js
+console.log(reexport_ns.x, reexport_ns);
@@ -0,0 +1,4 @@
+import { x } from './reexport.js'
+import * as ns from './reexport.js'
+
+console.log(x, ns)
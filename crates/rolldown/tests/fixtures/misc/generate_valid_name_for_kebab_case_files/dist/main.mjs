@@ -0,0 +1,8 @@
+import { __commonJSMin } from "./$runtime$.mjs";
+
+// react-dom.js
+var require_react_dom = __commonJSMin((exports, module) => {
+	module.exports = 'react-dom';
+});
+
+export default require_react_dom();
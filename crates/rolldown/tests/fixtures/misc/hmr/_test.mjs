@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+import { hot, value } from './dist/main.mjs'
+
+assert.strictEqual(value, 'dep')
+assert.strictEqual(hot, globalThis.__rolldown_hmr__.hot('main.js'))
+// Modules register their exports, so updates of other modules could import them.
+assert.strictEqual(globalThis.__rolldown_hmr__.load('dep.js').value, 'dep')
+assert.strictEqual(globalThis.__rolldown_hmr__.load('main.js').value, 'dep')
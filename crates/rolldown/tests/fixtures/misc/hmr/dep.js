@@ -0,0 +1,3 @@
+export const value = 'dep'
+
+import.meta.hot.accept()
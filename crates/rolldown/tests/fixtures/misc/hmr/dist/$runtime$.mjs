@@ -0,0 +1,89 @@
+
+// \0<runtime>
+var __defProp = Object.defineProperty;
+var __getOwnPropDesc = Object.getOwnPropertyDescriptor;
+var __getOwnPropNames = Object.getOwnPropertyNames;
+var __hasOwnProp = Object.prototype.hasOwnProperty;
+var __export = (target, all) => {
+	for (var name in all) 	__defProp(target, name, {
+		get:all[name],
+		enumerable:true
+	});
+};
+var __copyProps = (to, from, except, desc) => {
+	if (from && typeof from === 'object' || typeof from === 'function') 	for (var keys = __getOwnPropNames(from), i = 0, n = keys.length, key; i < n; i++) 	{
+		key = keys[i];
+		if ( !__hasOwnProp.call(to, key) && key !== except) 		__defProp(to, key, {
+			get:(k => from[k]).bind(null, key),
+			enumerable: !(desc = __getOwnPropDesc(from, key)) || desc.enumerable
+		});
+
+	}
+
+	return to;
+};
+var __reExport = (target, mod, secondTarget) => (__copyProps(target, mod, 'default'),secondTarget && __copyProps(secondTarget, mod, 'default'));
+var __rolldown_hmr__ = globalThis.__rolldown_hmr__ || (globalThis.__rolldown_hmr__ = (() => {
+	var modules = new Map();
+	var contexts = new Map();
+	var createContext = (id, data) => {
+		var context = {
+			data:data || {},
+			acceptCallbacks:[],
+			disposeCallbacks:[],
+			accept(callback){
+				context.acceptCallbacks.push(callback || (() => {
+				}));
+			},
+			dispose(callback){
+				context.disposeCallbacks.push(callback);
+			}
+		};
+		contexts.set(id, context);
+		return context;
+	};
+	var hmr = {
+		hot:id => contexts.get(id) || createContext(id),
+		register(id, getExports){
+			modules.set(id, getExports);
+		},
+		load(id){
+			if ( !modules.has(id)) 			throw Error('Module "' + id + '" is not loaded');
+
+			return modules.get(id)();
+		},
+		import:id => Promise.resolve().then(() => hmr.load(id)),
+		reExport:(target, mod) => __reExport(target, mod),
+		reload(){
+			if (globalThis.location) 			globalThis.location.reload();
+
+		},
+		applyUpdates(updates){
+			var accepted = updates.every(([id,factory]) => {
+				var context = contexts.get(id);
+				return factory && context && context.acceptCallbacks.length > 0;
+			});
+			if ( !accepted) {
+				hmr.reload();
+				return false;
+			}
+			try{
+				updates.forEach(([id,factory]) => {
+					var context = contexts.get(id);
+					var data = {};
+					context.disposeCallbacks.forEach(callback => callback(data));
+					createContext(id, data);
+					var exports = factory();
+					modules.set(id, () => exports);
+					context.acceptCallbacks.forEach(callback => callback(exports));
+				});
+			}catch(error){
+				hmr.reload();
+				throw error;
+			}			return true;
+		}
+	};
+	return hmr;
+})());
+
+export { __export, __rolldown_hmr__ };
\ No newline at end of file
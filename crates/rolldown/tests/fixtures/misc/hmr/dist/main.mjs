@@ -0,0 +1,21 @@
+import { __export, __rolldown_hmr__ } from "./$runtime$.mjs";
+
+// dep.js
+var dep_ns = {};
+__export(dep_ns, {
+	value:() => value
+});
+const value = 'dep';
+__rolldown_hmr__.hot('dep.js').accept();
+__rolldown_hmr__.register('dep.js', () => dep_ns);
+
+// main.js
+var main_ns = {};
+__export(main_ns, {
+	hot:() => hot,
+	value:() => value
+});
+const hot = __rolldown_hmr__.hot('main.js');
+__rolldown_hmr__.register('main.js', () => main_ns);
+
+export { hot, value };
\ No newline at end of file
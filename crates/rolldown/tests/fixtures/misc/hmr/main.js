@@ -0,0 +1,4 @@
+import { value } from './dep.js'
+
+export const hot = import.meta.hot
+export { value }
@@ -0,0 +1,3 @@
+
+// pages/about.js
+console.log('about');
@@ -0,0 +1,3 @@
+
+// pages/home.js
+console.log('home');
@@ -0,0 +1,6 @@
+
+// main.js
+const test = 'hello world';
+var main_default = test;
+
+export { main_default as default };
\ No newline at end of file
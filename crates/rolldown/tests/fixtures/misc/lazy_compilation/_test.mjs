@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+
+// The dev server could define the client before the bundle runs, which the runtime keeps.
+const requested = []
+globalThis.__rolldown_lazy__ = {
+  async load(id) {
+    requested.push(id)
+    return { name: 'page' }
+  },
+}
+const { page } = await import('./dist/main.mjs')
+
+assert.deepStrictEqual(await page, { name: 'page' })
+assert.deepStrictEqual(requested, ['page.js'])
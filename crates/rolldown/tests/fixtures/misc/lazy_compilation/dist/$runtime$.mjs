@@ -0,0 +1,10 @@
+
+// \0<runtime>
+var __rolldown_lazy__ = globalThis.__rolldown_lazy__ || (globalThis.__rolldown_lazy__ = {
+	endpoint:'/@rolldown/lazy/',
+	load(id){
+		return import(globalThis.__rolldown_lazy__.endpoint + encodeURIComponent(id));
+	}
+});
+
+export { __rolldown_lazy__ };
\ No newline at end of file
@@ -0,0 +1,5 @@
+
+// main.js
+const page = import('./page.mjs').then(m => m.default);
+
+export { page };
\ No newline at end of file
@@ -0,0 +1,6 @@
+import { __rolldown_lazy__ } from "./$runtime$.mjs";
+
+// \0rolldown-lazy:page.js
+var page_default = globalThis.__rolldown_lazy__.load('page.js');
+
+export { page_default as default };
\ No newline at end of file
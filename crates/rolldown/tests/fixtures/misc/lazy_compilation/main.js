@@ -0,0 +1 @@
+export const page = import('./page.js')
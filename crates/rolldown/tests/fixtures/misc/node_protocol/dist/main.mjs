@@ -0,0 +1,5 @@
+import { readFileSync, writeFileSync } from "node:fs";
+import { default as test } from "node:test";
+
+// main.js
+console.log(readFileSync, writeFileSync, test);
@@ -0,0 +1,5 @@
+import { readFileSync } from 'fs'
+import { writeFileSync } from 'node:fs'
+import test from 'node:test'
+
+console.log(readFileSync, writeFileSync, test)
@@ -0,0 +1,12 @@
+import { default as assert } from "assert";
+
+// foo.js
+var foo_default = 'foo';
+
+// main.js
+const value = {
+	foo:foo_default
+};
+assert.strictEqual(value.foo, 'foo');
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
@@ -0,0 +1,5 @@
+
+// main.js
+if ('production' !== 'production') {
+	console.log('development');
+}
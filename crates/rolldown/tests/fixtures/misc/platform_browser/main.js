@@ -0,0 +1,3 @@
+if (process.env.NODE_ENV !== 'production') {
+  console.log('development')
+}
@@ -0,0 +1,5 @@
+import { readFileSync } from "node:fs";
+import { default as path } from "node:path";
+
+// main.js
+console.log(readFileSync, path.join, process.env.NODE_ENV);
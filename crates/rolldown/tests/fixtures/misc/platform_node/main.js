@@ -0,0 +1,4 @@
+import { readFileSync } from 'node:fs'
+import path from 'path'
+
+console.log(readFileSync, path.join, process.env.NODE_ENV)
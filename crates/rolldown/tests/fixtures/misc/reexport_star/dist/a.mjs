@@ -0,0 +1,10 @@
+import { __export } from "./$runtime$.mjs";
+
+// a.js
+var a_ns = {};
+__export(a_ns, {
+	abc:() => abc
+});
+const abc = undefined;
+
+export { a_ns };
\ No newline at end of file
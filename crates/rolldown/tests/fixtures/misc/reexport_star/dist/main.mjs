@@ -0,0 +1,3 @@
+import { a_ns } from "./a.mjs";
+
+export { a_ns as a };
\ No newline at end of file
@@ -0,0 +1,5 @@
+
+// foo.js
+const value = 1;
+
+export { value as foo };
\ No newline at end of file
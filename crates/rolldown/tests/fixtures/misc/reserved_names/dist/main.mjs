@@ -0,0 +1,8 @@
+
+// main.js
+const jQuery$1 = 'jQuery';
+const char$1 = 'char';
+function log(int$1) {
+	console.log(jQuery$1, char$1, int$1);
+}
+log(1);
@@ -0,0 +1,8 @@
+const jQuery = 'jQuery';
+const char = 'char';
+
+function log(int) {
+  console.log(jQuery, char, int);
+}
+
+log(1);
@@ -0,0 +1,6 @@
+
+// src/value.js
+const value = 'value';
+
+// src/<stdin>
+console.log(value);
@@ -0,0 +1,11 @@
+const { __commonJSMin, __toESM } = require("./$runtime$.cjs");
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	module.exports = function foo$1(arguments) {
+	};
+});
+
+// main.js
+var import_cjs = __toESM(require_cjs());
+console.log(import_cjs.default);
@@ -0,0 +1,13 @@
+"use strict";
+
+const { __commonJSMin, __toESM } = require("./$runtime$.cjs");
+
+// cjs.js
+var require_cjs = __commonJSMin((exports, module) => {
+	console.log('This chunk should have "use strict" at the top.');
+	module.exports = {};
+});
+
+// main.js
+var import_cjs = __toESM(require_cjs());
+console.log(import_cjs.default);
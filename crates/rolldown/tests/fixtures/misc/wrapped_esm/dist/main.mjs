@@ -0,0 +1,27 @@
+import { __esmMin, __export, __toCommonJS } from "./$runtime$.mjs";
+
+// foo.js
+function foo() {
+}
+var foo_ns, a, b, c, bar, baz;
+var init_foo = __esmMin(() => {
+	foo_ns = {};
+	__export(foo_ns, {
+		a:() => a,
+		b:() => b,
+		bar:() => bar,
+		c:() => c,
+		default:() => baz,
+		foo:() => foo
+	});
+	[b] = [],[c=1] = [];
+	bar = class {
+	};
+	baz = class {
+	};
+});
+
+// main.js
+init_foo(),__toCommonJS(foo_ns);
+
+//# sourceMappingURL=main.mjs.map
\ No newline at end of file
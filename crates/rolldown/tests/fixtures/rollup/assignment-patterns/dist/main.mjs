@@ -0,0 +1,35 @@
+import { default as assert } from "assert";
+
+// other.js
+const bar = 'bar';
+const baz = 'baz';
+const x = 'x';
+const items = 'items';
+const p = 'p';
+const q = 'q';
+const r = 'r';
+const s = 's';
+
+// main.js
+function foo(bar$1=1, {baz:baz$1}={
+	baz:2
+}, [[[,x$1=3]=[]]=[]]=[], ...items$1) {
+	bar$1 += 1;
+	baz$1 += 1;
+	x$1 += 1;
+	let {p:p$1, q:q$1} = {
+		p:4,
+		q:5
+	};
+	let [r$1,s$1] = [6, 7];
+	p$1++;
+	q$1 += 1;
+	r$1 = 7;
+	s$1 = 6;
+	return bar$1 + baz$1 + x$1 + items$1.length + p$1 + q$1 + r$1 + s$1;
+}
+assert.equal(foo(), 33);
+assert.equal(foo(2), 34);
+assert.equal(foo(2, {
+	baz:3
+}, [[[99, 10]]], 'a', 'b', 'c'), 45);
@@ -0,0 +1,12 @@
+import { default as assert } from "assert";
+
+// main.js
+let result = 'FAIL';
+const unused = {
+	...{
+		get prop(){
+			result = 'PASS';
+		}
+	}
+};
+assert.strictEqual(result, 'PASS');
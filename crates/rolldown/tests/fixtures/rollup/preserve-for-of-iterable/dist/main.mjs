@@ -0,0 +1,33 @@
+import { default as assert } from "assert";
+
+// iterables.js
+let dirty;
+const zeroToFive = {
+	[Symbol.iterator](){
+		return {
+			current:0,
+			last:5,
+			next(){
+				const ret = this.current < this.last ? {
+					done:false,
+					value:this.current++
+				} : {
+					done:true
+				};
+				dirty = this.current;
+				return ret;
+			}
+		};
+	}
+};
+
+// loops.js
+const iterate = iterable => {
+	for (const value of  iterable) 	{
+	}
+};
+
+// main.js
+assert.equal(dirty, undefined);
+iterate(zeroToFive);
+assert.equal(dirty, 5);
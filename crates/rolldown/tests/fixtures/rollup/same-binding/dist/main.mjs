@@ -0,0 +1,6 @@
+
+// shared.js
+const foo = 1;
+
+// main.js
+assert.strictEqual(foo, 1);
@@ -0,0 +1,13 @@
+import { default as assert } from "assert";
+
+// main.js
+let foo, unused;
+null,{foo} = {
+	foo:'bar'
+};
+assert.strictEqual(foo, 'bar');
+const assign = () => unused = {foo} = {
+	foo:'baz'
+};
+assign();
+assert.strictEqual(foo, 'baz');
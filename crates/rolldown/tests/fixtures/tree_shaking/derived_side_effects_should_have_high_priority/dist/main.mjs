@@ -0,0 +1,6 @@
+
+// another/b/index.js
+const b = 100;
+
+// main.js
+console.log(b);
@@ -0,0 +1,3 @@
+
+// indirect-side-effect.js
+console.log('should be kept');
@@ -0,0 +1,6 @@
+
+// foo.json
+const shouldBeReserved = 'shouldBeReserved';
+
+// main.js
+console.log(shouldBeReserved);
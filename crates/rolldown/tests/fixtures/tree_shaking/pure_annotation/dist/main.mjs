@@ -0,0 +1,16 @@
+
+// a.js
+const a = 100;
+
+// c.js
+function test() {
+}
+const c = test();
+
+// d.js
+function test2() {
+}
+const d = test2(), e = test2();
+
+// main.js
+console.log(`a: `, a);
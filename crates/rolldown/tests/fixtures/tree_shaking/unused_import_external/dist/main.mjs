@@ -0,0 +1 @@
+import { default as assert } from "assert";
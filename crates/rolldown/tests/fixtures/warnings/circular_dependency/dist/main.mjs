@@ -0,0 +1,12 @@
+
+// c.js
+const c = () => typeof a;
+
+// b.js
+const b = () => c();
+
+// a.js
+const a = () => b();
+
+// main.js
+console.log(a());
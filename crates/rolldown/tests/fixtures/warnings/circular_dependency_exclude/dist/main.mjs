@@ -0,0 +1,15 @@
+
+// b.js
+const b = () => typeof a;
+
+// a.js
+const a = () => b();
+
+// vendor/tokenizer.js
+const tokenize = () => typeof parse;
+
+// vendor/parser.js
+const parse = () => tokenize();
+
+// main.js
+console.log(a(), parse());
@@ -0,0 +1,4 @@
+import { a } from './a.js'
+import { parse } from './vendor/parser.js'
+
+console.log(a(), parse())
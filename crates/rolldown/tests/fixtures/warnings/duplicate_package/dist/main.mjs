@@ -0,0 +1,12 @@
+
+// node_modules/a/index.js
+const one = 1;
+
+// node_modules/b/node_modules/a/index.js
+const two = 2;
+
+// node_modules/b/index.js
+const b = two;
+
+// main.js
+console.log(one, b);
@@ -0,0 +1,3 @@
+import { one } from 'a'
+import { b } from 'b'
+console.log(one, b)
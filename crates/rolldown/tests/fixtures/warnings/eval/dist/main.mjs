@@ -0,0 +1,3 @@
+
+// main.js
+console.log(eval);
@@ -0,0 +1,6 @@
+
+// vendor/legacy.js
+eval('1 + 1');
+
+// main.js
+console.log('main');
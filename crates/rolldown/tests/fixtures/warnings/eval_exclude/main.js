@@ -0,0 +1,2 @@
+import './vendor/legacy.js'
+console.log('main')
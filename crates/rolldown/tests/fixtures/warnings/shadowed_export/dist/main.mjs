@@ -0,0 +1,8 @@
+
+// utils.js
+const parse = 1;
+
+// main.js
+const format = 'main';
+
+export { format, parse };
\ No newline at end of file
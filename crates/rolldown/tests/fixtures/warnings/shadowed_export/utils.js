@@ -0,0 +1,2 @@
+export const format = 'utils'
+export const parse = 1
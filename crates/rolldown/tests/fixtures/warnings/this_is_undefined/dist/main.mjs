@@ -0,0 +1,6 @@
+
+// dep.js
+console.log('dep');
+
+// main.js
+console.log(void 0);
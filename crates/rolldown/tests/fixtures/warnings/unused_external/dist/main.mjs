@@ -0,0 +1,4 @@
+import { readFileSync } from "node:fs";
+
+// main.js
+console.log(readFileSync);
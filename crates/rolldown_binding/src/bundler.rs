@@ -1,43 +1,97 @@
-use std::path::PathBuf;
+use std::sync::Arc;
 
 #[cfg(not(target_family = "wasm"))]
 use crate::worker_manager::WorkerManager;
 use crate::{
-  options::{BindingInputOptions, BindingOnLog, BindingOutputOptions},
+  options::{BindingInputOptions, BindingOutputOptions},
   parallel_js_plugin_registry::ParallelJsPluginRegistry,
-  types::{
-    binding_log::BindingLog, binding_log_level::BindingLogLevel,
-    binding_outputs::FinalBindingOutputs,
-  },
+  types::{binding_module_info::BindingModuleInfo, binding_outputs::FinalBindingOutputs},
   utils::{normalize_binding_options::normalize_binding_options, try_init_custom_trace_subscriber},
+  watcher::request_lazy_compilation,
+};
+use napi::{
+  tokio::sync::{Mutex, MutexGuard},
+  Env,
 };
-use napi::{tokio::sync::Mutex, Env};
 use napi_derive::napi;
-use rolldown::Bundler as NativeBundler;
-use rolldown_error::{BuildError, DiagnosticOptions};
+use rolldown::{Bundler as NativeBundler, LazyCompilation};
+use rolldown_error::{BuildError, DiagnosticRenderer};
 
 #[napi]
 pub struct Bundler {
   inner: Mutex<NativeBundler>,
-  on_log: BindingOnLog,
-  log_level: Option<BindingLogLevel>,
-  cwd: PathBuf,
+  /// Only exists with `lazyCompilation`, which could be requested while building.
+  lazy_compilation: Option<Arc<LazyCompilation>>,
 }
 
 #[napi]
 impl Bundler {
   #[napi(constructor)]
-  #[cfg_attr(target_family = "wasm", allow(unused))]
   pub fn new(
     env: Env,
-    mut input_options: BindingInputOptions,
+    input_options: BindingInputOptions,
     output_options: BindingOutputOptions,
     parallel_plugins_registry: Option<ParallelJsPluginRegistry>,
   ) -> napi::Result<Self> {
-    try_init_custom_trace_subscriber(env);
+    let inner =
+      Self::create_native_bundler(env, input_options, output_options, parallel_plugins_registry)?;
+    let lazy_compilation = inner.lazy_compilation();
+    Ok(Self { inner: Mutex::new(inner), lazy_compilation })
+  }
+
+  #[napi]
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub async fn write(&self) -> napi::Result<FinalBindingOutputs> {
+    self.write_impl().await
+  }
 
-    let log_level = input_options.log_level.take();
-    let on_log = input_options.on_log.take();
+  #[napi]
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub async fn generate(&self) -> napi::Result<FinalBindingOutputs> {
+    self.generate_impl().await
+  }
+
+  #[napi]
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub async fn scan(&self) -> napi::Result<()> {
+    self.scan_impl().await
+  }
+
+  #[napi]
+  #[tracing::instrument(level = "debug", skip_all)]
+  pub async fn why(&self, id: String) -> napi::Result<Vec<Vec<String>>> {
+    self.why_impl(&id).await
+  }
+
+  /// Ids of modules in the module graph of the last build.
+  #[napi]
+  pub fn get_module_ids(&self) -> napi::Result<Vec<String>> {
+    Ok(self.lock_idle()?.module_ids())
+  }
+
+  /// Imports, importers and exports of the module with `id` in the module graph of the last build.
+  #[napi]
+  pub fn get_module_info(&self, id: String) -> napi::Result<Option<BindingModuleInfo>> {
+    Ok(self.lock_idle()?.module_info(&id).map(BindingModuleInfo::new))
+  }
+
+  /// Bundle the module with `id`, which proxy modules of `lazyCompilation` pass to `__rolldown_lazy__.load(id)`, as
+  /// usual since the next build.
+  #[napi]
+  pub fn request_lazy_compilation(&self, id: String) -> napi::Result<()> {
+    request_lazy_compilation(self.lazy_compilation.as_deref(), &id)
+  }
+}
+
+impl Bundler {
+  #[cfg_attr(target_family = "wasm", allow(unused))]
+  pub(crate) fn create_native_bundler(
+    env: Env,
+    input_options: BindingInputOptions,
+    output_options: BindingOutputOptions,
+    parallel_plugins_registry: Option<ParallelJsPluginRegistry>,
+  ) -> napi::Result<NativeBundler> {
+    try_init_custom_trace_subscriber(env);
 
     #[cfg(target_family = "wasm")]
     // if we don't perform this warmup, the following call to `std::fs` will stuck
@@ -63,51 +117,41 @@ impl Bundler {
       worker_manager,
     )?;
 
-    Ok(Self {
-      cwd: ret.bundler_options.cwd.clone().unwrap_or_else(|| std::env::current_dir().unwrap()),
-      inner: Mutex::new(NativeBundler::with_plugins(ret.bundler_options, ret.plugins)),
-      log_level,
-      on_log,
-    })
-  }
-
-  #[napi]
-  #[tracing::instrument(level = "debug", skip_all)]
-  pub async fn write(&self) -> napi::Result<FinalBindingOutputs> {
-    self.write_impl().await
-  }
-
-  #[napi]
-  #[tracing::instrument(level = "debug", skip_all)]
-  pub async fn generate(&self) -> napi::Result<FinalBindingOutputs> {
-    self.generate_impl().await
+    Ok(NativeBundler::with_plugins(ret.bundler_options, ret.plugins))
   }
 
-  #[napi]
-  #[tracing::instrument(level = "debug", skip_all)]
-  pub async fn scan(&self) -> napi::Result<()> {
-    self.scan_impl().await
+  /// The bundler can't be read while a build is in progress.
+  fn lock_idle(&self) -> napi::Result<MutexGuard<'_, NativeBundler>> {
+    self.inner.try_lock().map_err(|_| {
+      napi::Error::from_reason("Failed to lock the bundler. Is another operation in progress?")
+    })
   }
-}
 
-impl Bundler {
   #[allow(clippy::significant_drop_tightening)]
   pub async fn scan_impl(&self) -> napi::Result<()> {
     let mut bundler_core = self.inner.try_lock().map_err(|_| {
       napi::Error::from_reason("Failed to lock the bundler. Is another operation in progress?")
     })?;
 
-    let output = Self::handle_result(bundler_core.scan().await)?;
+    let mut output = Self::handle_result(bundler_core.scan().await)?;
+    bundler_core.emit_logs(&mut output.warnings, &mut output.errors).await;
 
     if !output.errors.is_empty() {
-      return Err(self.handle_errors(output.errors));
+      return Err(Self::handle_errors(bundler_core.diagnostic_renderer(), &output.errors));
     }
 
-    self.handle_warnings(output.warnings).await;
-
     Ok(())
   }
 
+  #[allow(clippy::significant_drop_tightening)]
+  pub async fn why_impl(&self, id: &str) -> napi::Result<Vec<Vec<String>>> {
+    let mut bundler_core = self.inner.try_lock().map_err(|_| {
+      napi::Error::from_reason("Failed to lock the bundler. Is another operation in progress?")
+    })?;
+
+    bundler_core.why(id).await.map_err(|e| napi::Error::from_reason(e.to_string()))
+  }
+
   #[allow(clippy::significant_drop_tightening)]
   pub async fn write_impl(&self) -> napi::Result<FinalBindingOutputs> {
     let mut bundler_core = self.inner.try_lock().map_err(|_| {
@@ -117,11 +161,9 @@ impl Bundler {
     let outputs = Self::handle_result(bundler_core.write().await)?;
 
     if !outputs.errors.is_empty() {
-      return Err(self.handle_errors(outputs.errors));
+      return Err(Self::handle_errors(bundler_core.diagnostic_renderer(), &outputs.errors));
     }
 
-    self.handle_warnings(outputs.warnings).await;
-
     Ok(FinalBindingOutputs::new(outputs.assets))
   }
 
@@ -134,11 +176,9 @@ impl Bundler {
     let outputs = Self::handle_result(bundler_core.generate().await)?;
 
     if !outputs.errors.is_empty() {
-      return Err(self.handle_errors(outputs.errors));
+      return Err(Self::handle_errors(bundler_core.diagnostic_renderer(), &outputs.errors));
     }
 
-    self.handle_warnings(outputs.warnings).await;
-
     Ok(FinalBindingOutputs::new(outputs.assets))
   }
 
@@ -146,38 +186,10 @@ impl Bundler {
     result.map_err(|e| napi::Error::from_reason(format!("Rolldown internal error: {e}")))
   }
 
-  fn handle_errors(&self, errs: Vec<BuildError>) -> napi::Error {
-    errs.into_iter().for_each(|err| {
-      eprintln!(
-        "{}",
-        err.into_diagnostic_with(&DiagnosticOptions { cwd: self.cwd.clone() }).to_color_string()
-      );
+  fn handle_errors(renderer: &DiagnosticRenderer, errs: &[BuildError]) -> napi::Error {
+    errs.iter().for_each(|err| {
+      eprintln!("{}", renderer.render(err));
     });
     napi::Error::from_reason("Build failed")
   }
-
-  #[allow(clippy::print_stdout, unused_must_use)]
-  async fn handle_warnings(&self, warnings: Vec<BuildError>) {
-    if let Some(log_level) = self.log_level {
-      if log_level == BindingLogLevel::Silent {
-        return;
-      }
-    }
-
-    if let Some(on_log) = self.on_log.as_ref() {
-      for warning in warnings {
-        on_log
-          .call_async((
-            BindingLogLevel::Warn.to_string(),
-            BindingLog {
-              code: warning.kind().to_string(),
-              message: warning
-                .into_diagnostic_with(&DiagnosticOptions { cwd: self.cwd.clone() })
-                .to_color_string(),
-            },
-          ))
-          .await;
-      }
-    }
-  }
 }
@@ -0,0 +1,91 @@
+use std::sync::Mutex;
+
+use tokio::sync::{oneshot, Semaphore, SemaphorePermit};
+
+/// Default of `maxParallelJsHooks`
+const DEFAULT_MAX_PARALLEL_JS_HOOKS: usize = 128;
+
+/// Bounds the number of calls of JS hooks queued on the JS thread. Modules wait for a permit before calling JS, so a scan
+/// of thousands of modules doesn't flood the queue of the JS thread, and the scan stage slows down to the pace of JS.
+/// Permits are only held until JS returns, not while awaiting returned promises, so hooks calling `this.resolve(...)`
+/// don't starve the nested hook calls they wait for.
+#[derive(Debug)]
+pub struct JsHookQueue {
+  permits: Semaphore,
+}
+
+impl JsHookQueue {
+  pub fn new(max_parallel_js_hooks: Option<u32>) -> Self {
+    let permits = max_parallel_js_hooks.map_or(DEFAULT_MAX_PARALLEL_JS_HOOKS, |max| max as usize);
+    Self { permits: Semaphore::new(permits.max(1)) }
+  }
+
+  pub async fn acquire(&self) -> SemaphorePermit<'_> {
+    self.permits.acquire().await.expect("Semaphore should not be closed")
+  }
+}
+
+type PendingCall<Args, Ret> = (Args, oneshot::Sender<napi::Result<Ret>>);
+
+/// Gathers calls of a hook made at the same time into a single call of JS. Each call yields once to let concurrent calls
+/// join, then the first one to resume calls JS with all pending calls.
+pub struct HookBatcher<Args, Ret> {
+  pending: Mutex<Vec<PendingCall<Args, Ret>>>,
+}
+
+impl<Args, Ret> Default for HookBatcher<Args, Ret> {
+  fn default() -> Self {
+    Self { pending: Mutex::default() }
+  }
+}
+
+impl<Args, Ret> std::fmt::Debug for HookBatcher<Args, Ret> {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    f.debug_struct("HookBatcher").finish_non_exhaustive()
+  }
+}
+
+impl<Args, Ret> HookBatcher<Args, Ret> {
+  /// `call_batch` is called with arguments of the batch, and returns the result of each call in the same order, so a
+  /// failing call doesn't fail other calls of the batch. Every call fails only if JS couldn't be called at all.
+  pub async fn call<F, Fut>(&self, args: Args, call_batch: F) -> napi::Result<Ret>
+  where
+    F: FnOnce(Vec<Args>) -> Fut,
+    Fut: std::future::Future<Output = napi::Result<Vec<napi::Result<Ret>>>>,
+  {
+    let (sender, receiver) = oneshot::channel();
+    self.pending.lock().expect("Pending hook calls should not be poisoned").push((args, sender));
+
+    tokio::task::yield_now().await;
+    let batch =
+      std::mem::take(&mut *self.pending.lock().expect("Pending hook calls should not be poisoned"));
+    if !batch.is_empty() {
+      let (batch_args, senders): (Vec<_>, Vec<_>) = batch.into_iter().unzip();
+      match call_batch(batch_args).await {
+        Ok(rets) if rets.len() == senders.len() => {
+          for (sender, ret) in senders.into_iter().zip(rets) {
+            // The caller may have been dropped
+            let _ = sender.send(ret);
+          }
+        }
+        Ok(rets) => {
+          let reason =
+            format!("Expected {} results of the batch, but got {}", senders.len(), rets.len());
+          for sender in senders {
+            let _ = sender.send(Err(napi::Error::new(napi::Status::InvalidArg, reason.clone())));
+          }
+        }
+        Err(err) => {
+          for sender in senders {
+            let _ = sender.send(Err(napi::Error::new(err.status, err.reason.clone())));
+          }
+        }
+      }
+    }
+
+    receiver.await.unwrap_or_else(|_| {
+      // The call running the batch was dropped before it finished
+      Err(napi::Error::new(napi::Status::Cancelled, "The batch of hook calls was cancelled"))
+    })
+  }
+}
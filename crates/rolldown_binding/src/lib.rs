@@ -8,8 +8,10 @@
 static ALLOC: mimalloc::MiMalloc = mimalloc::MiMalloc;
 
 pub mod bundler;
+mod js_hook_queue;
 pub mod options;
 pub mod parallel_js_plugin_registry;
 pub mod types;
 pub mod utils;
+pub mod watcher;
 mod worker_manager;
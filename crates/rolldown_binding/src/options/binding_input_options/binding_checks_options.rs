@@ -0,0 +1,27 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingChecksOptions {
+  #[napi(ts_type = "'error' | 'warn'")]
+  pub top_level_await: Option<String>,
+  #[napi(ts_type = "'error' | 'warn'")]
+  pub import_meta: Option<String>,
+  #[napi(ts_type = "'error' | 'warn'")]
+  pub circular_chunks: Option<String>,
+}
+
+impl TryFrom<BindingChecksOptions> for rolldown::ChecksOptions {
+  type Error = String;
+
+  fn try_from(value: BindingChecksOptions) -> Result<Self, Self::Error> {
+    let level =
+      |level: Option<String>| level.as_deref().map(rolldown::CheckLevel::try_from).transpose();
+    Ok(Self {
+      top_level_await: level(value.top_level_await)?,
+      import_meta: level(value.import_meta)?,
+      circular_chunks: level(value.circular_chunks)?,
+    })
+  }
+}
@@ -0,0 +1,15 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingCircularDependencyOptions {
+  pub exclude: Option<Vec<String>>,
+  pub error: Option<bool>,
+}
+
+impl From<BindingCircularDependencyOptions> for rolldown::CircularDependencyOptions {
+  fn from(value: BindingCircularDependencyOptions) -> Self {
+    Self { exclude: value.exclude, error: value.error }
+  }
+}
@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingCssModulesOptions {
+  pub scoped_name: Option<String>,
+  #[napi(ts_type = "'asIs' | 'camelCase' | 'camelCaseOnly' | 'dashes' | 'dashesOnly'")]
+  pub locals_convention: Option<String>,
+}
+
+impl TryFrom<BindingCssModulesOptions> for rolldown::CssModulesOptions {
+  type Error = String;
+
+  fn try_from(value: BindingCssModulesOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      scoped_name: value.scoped_name,
+      locals_convention: value
+        .locals_convention
+        .as_deref()
+        .map(rolldown::LocalsConvention::try_from)
+        .transpose()?,
+    })
+  }
+}
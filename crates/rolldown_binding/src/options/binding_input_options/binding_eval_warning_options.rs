@@ -0,0 +1,14 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingEvalWarningOptions {
+  pub exclude: Option<Vec<String>>,
+}
+
+impl From<BindingEvalWarningOptions> for rolldown::EvalWarningOptions {
+  fn from(value: BindingEvalWarningOptions) -> Self {
+    Self { exclude: value.exclude }
+  }
+}
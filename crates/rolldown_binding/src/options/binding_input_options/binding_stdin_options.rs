@@ -0,0 +1,25 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingStdinOptions {
+  pub code: String,
+  pub resolve_dir: Option<String>,
+  #[napi(
+    ts_type = "'js' | 'jsx' | 'ts' | 'tsx' | 'json' | 'text' | 'base64' | 'dataurl' | 'binary' | 'empty' | 'asset' | 'css' | 'wasm' | 'html'"
+  )]
+  pub loader: Option<String>,
+}
+
+impl TryFrom<BindingStdinOptions> for rolldown::StdinOptions {
+  type Error = String;
+
+  fn try_from(value: BindingStdinOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      code: value.code,
+      resolve_dir: value.resolve_dir,
+      loader: value.loader.as_deref().map(rolldown::Loader::try_from).transpose()?,
+    })
+  }
+}
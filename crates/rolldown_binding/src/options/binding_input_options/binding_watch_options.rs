@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingWatchOptions {
+  pub include: Option<Vec<String>>,
+  pub exclude: Option<Vec<String>>,
+  pub build_delay: Option<u32>,
+  pub skip_write: Option<bool>,
+  pub poll: Option<BindingWatchPollOptions>,
+}
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingWatchPollOptions {
+  pub interval: Option<u32>,
+}
+
+impl From<BindingWatchOptions> for rolldown::WatchOptions {
+  fn from(value: BindingWatchOptions) -> Self {
+    Self {
+      include: value.include,
+      exclude: value.exclude,
+      build_delay: value.build_delay.map(u64::from),
+      skip_write: value.skip_write,
+      poll: value
+        .poll
+        .map(|poll| rolldown::WatchPollOptions { interval: poll.interval.map(u64::from) }),
+    }
+  }
+}
@@ -1,17 +1,32 @@
 // cSpell:disable
 
+use std::collections::HashMap;
+
 use crate::types::{binding_log::BindingLog, binding_log_level::BindingLogLevel};
 use derivative::Derivative;
-use napi::threadsafe_function::ThreadsafeFunction;
+use napi::{bindgen_prelude::Buffer, threadsafe_function::ThreadsafeFunction};
 use napi_derive::napi;
 use serde::Deserialize;
 
-use self::{binding_input_item::BindingInputItem, binding_resolve_options::BindingResolveOptions};
+use self::{
+  binding_checks_options::BindingChecksOptions,
+  binding_circular_dependency_options::BindingCircularDependencyOptions,
+  binding_css_modules_options::BindingCssModulesOptions,
+  binding_eval_warning_options::BindingEvalWarningOptions, binding_input_item::BindingInputItem,
+  binding_resolve_options::BindingResolveOptions, binding_stdin_options::BindingStdinOptions,
+  binding_watch_options::BindingWatchOptions,
+};
 
-use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
+use super::plugin::{BindingBuiltinPlugin, BindingPluginOrParallelJsPluginPlaceholder};
 
+mod binding_checks_options;
+mod binding_circular_dependency_options;
+mod binding_css_modules_options;
+mod binding_eval_warning_options;
 mod binding_input_item;
 mod binding_resolve_options;
+mod binding_stdin_options;
+mod binding_watch_options;
 
 #[napi(object, object_to_js = false)]
 #[derive(Deserialize, Default, Derivative)]
@@ -33,7 +48,10 @@ pub struct BindingInputOptions {
     ts_type = "undefined | ((source: string, importer: string | undefined, isResolved: boolean) => boolean)"
   )]
   pub external: Option<ThreadsafeFunction<(String, Option<String>, bool), bool, false>>,
+  /// Strings listed in `external`, which are warned about if no import matches them.
+  pub external_ids: Option<Vec<String>>,
   pub input: Vec<BindingInputItem>,
+  pub stdin: Option<BindingStdinOptions>,
   // makeAbsoluteExternalsRelative?: boolean | 'ifRelativeSource';
   // /** @deprecated Use the "manualChunks" output option instead. */
   // manualChunks?: ManualChunksOption;
@@ -44,6 +62,8 @@ pub struct BindingInputOptions {
   // onwarn?: WarningHandlerWithDefault;
   // perf?: boolean;
   pub plugins: Vec<BindingPluginOrParallelJsPluginPlaceholder>,
+  /// Plugins implemented in Rust, placed at their indexes of `plugins`.
+  pub builtin_plugins: Option<Vec<BindingBuiltinPlugin>>,
   pub resolve: Option<BindingResolveOptions>,
   // preserveEntrySignatures?: PreserveEntrySignaturesOption;
   // /** @deprecated Use the "preserveModules" output option instead. */
@@ -55,8 +75,45 @@ pub struct BindingInputOptions {
   // watch?: WatcherOptions | false;
   #[napi(ts_type = "'node' | 'browser' | 'neutral'")]
   pub platform: Option<String>,
+  pub node_protocol: Option<bool>,
+  #[napi(ts_type = "Array<'console' | 'debugger'>")]
+  pub drop: Option<Vec<String>>,
+  pub pure: Option<Vec<String>>,
+  pub define: Option<HashMap<String, String>>,
+  pub env: Option<HashMap<String, String>>,
+  pub strict_requires: Option<bool>,
+  #[napi(ts_type = "'true' | 'false' | 'auto' | 'preferred'")]
+  pub require_returns_default: Option<String>,
+  pub dynamic_require_targets: Option<Vec<String>>,
+  #[napi(ts_type = "'error' | 'keep' | 'stub'")]
+  pub unresolved_require: Option<String>,
+  pub inline_const_enums: Option<bool>,
+  #[derivative(Debug = "ignore")]
+  #[serde(skip_deserializing)]
+  #[napi(ts_type = "(file: string, content: Buffer) => boolean | undefined")]
+  pub assets_inline_limit: Option<ThreadsafeFunction<(String, Buffer), Option<bool>, false>>,
+  pub css_modules: Option<BindingCssModulesOptions>,
+  pub circular_dependency: Option<BindingCircularDependencyOptions>,
+  pub eval_warning: Option<BindingEvalWarningOptions>,
+  pub checks: Option<BindingChecksOptions>,
+  #[napi(
+    ts_type = "Record<string, 'js' | 'jsx' | 'ts' | 'tsx' | 'json' | 'text' | 'base64' | 'dataurl' | 'binary' | 'empty' | 'asset' | 'css' | 'wasm' | 'html'>"
+  )]
+  pub module_types: Option<HashMap<String, String>>,
+  pub public_dir: Option<String>,
+  pub watch: Option<BindingWatchOptions>,
+  pub hmr: Option<bool>,
+  pub lazy_compilation: Option<bool>,
+  pub cache_dir: Option<String>,
+  pub threads: Option<u32>,
+  pub max_parallel_file_ops: Option<u32>,
+  /// Maximum number of calls of `resolveId`, `load` and `transform` hooks of JS plugins in flight. Defaults to `128`.
+  pub max_parallel_js_hooks: Option<u32>,
+  pub profile: Option<bool>,
+  pub profile_trace_file: Option<String>,
   #[serde(skip_deserializing)]
   pub log_level: Option<BindingLogLevel>,
+  pub collapse_warnings: Option<bool>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void")]
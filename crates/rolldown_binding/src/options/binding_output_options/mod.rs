@@ -19,9 +19,22 @@ pub struct BindingOutputOptions {
   // dynamicImportFunction: string | undefined;
   pub entry_file_names: Option<String>,
   pub chunk_file_names: Option<String>,
+  pub asset_file_names: Option<String>,
+  pub sourcemap_file_names: Option<String>,
+  #[napi(ts_type = "'ascii' | 'utf8'")]
+  pub charset: Option<String>,
+  #[napi(ts_type = "'inline' | 'eof' | 'linked' | 'external' | 'none'")]
+  pub legal_comments: Option<String>,
+  pub css_minify: Option<bool>,
+  pub minify: Option<bool>,
+  pub stream_writes: Option<bool>,
+  #[napi(ts_type = "'base64' | 'base36' | 'hex'")]
+  pub hash_characters: Option<String>,
+  #[napi(ts_type = "'xxhash3' | 'blake3'")]
+  pub hash_algorithm: Option<String>,
+  pub hash_salt: Option<String>,
 
   // amd: NormalizedAmdOptions;
-  // assetFileNames: string | ((chunkInfo: PreRenderedAsset) => string);
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>")]
@@ -51,7 +64,6 @@ pub struct BindingOutputOptions {
   // interop: GetInterop;
   // intro: () => string | Promise<string>;
   // manualChunks: ManualChunksOption;
-  // minifyInternalExports: boolean;
   // name: string | undefined;
   // namespaceToStringTag: boolean;
   // noConflict: boolean;
@@ -62,8 +74,18 @@ pub struct BindingOutputOptions {
   // preserveModules: boolean;
   // preserveModulesRoot: string | undefined;
   // sanitizeFileName: (fileName: string) => string;
-  #[napi(ts_type = "'file' | 'inline' | 'hidden'")]
+  #[napi(ts_type = "'file' | 'inline' | 'hidden' | 'cheap'")]
   pub sourcemap: Option<String>,
+  pub sourcemap_debug_ids: Option<bool>,
+  pub validate: Option<bool>,
+  pub reserved_names: Option<Vec<String>>,
+  pub es3_safe_identifiers: Option<bool>,
+  pub dts: Option<bool>,
+  pub runtime_chunk: Option<bool>,
+  pub minify_internal_exports: Option<bool>,
+  pub metafile: Option<String>,
+  pub report_compressed_size: Option<bool>,
+  pub max_chunk_size: Option<u32>,
   #[derivative(Debug = "ignore")]
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(source: string, sourcemapPath: string) => boolean")]
@@ -0,0 +1,37 @@
+use std::collections::HashMap;
+
+use rolldown_plugin::{BoxPlugin, ReplacePlugin};
+use serde::Deserialize;
+
+/// A plugin implemented in Rust, which takes the place of the `undefined` placeholder at `index` of `plugins`, so it
+/// runs in order with Js plugins around it.
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingBuiltinPlugin {
+  pub index: u32,
+  #[napi(ts_type = "'builtin:replace'")]
+  pub name: String,
+  /// Options of `builtin:replace`
+  pub replace: Option<BindingReplacePluginOptions>,
+}
+
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Debug, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct BindingReplacePluginOptions {
+  pub values: HashMap<String, String>,
+}
+
+impl TryFrom<BindingBuiltinPlugin> for BoxPlugin {
+  type Error = String;
+
+  fn try_from(value: BindingBuiltinPlugin) -> Result<Self, Self::Error> {
+    match value.name.as_str() {
+      "builtin:replace" => {
+        Ok(Box::new(ReplacePlugin::new(value.replace.unwrap_or_default().values)))
+      }
+      _ => Err(format!("Unknown builtin plugin: {:?}", value.name)),
+    }
+  }
+}
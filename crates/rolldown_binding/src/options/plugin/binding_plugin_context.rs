@@ -36,6 +36,11 @@ impl BindingPluginContext {
       external: info.is_external,
     }))
   }
+
+  #[napi]
+  pub fn add_watch_file(&self, file: String) {
+    self.inner.add_watch_file(&file);
+  }
 }
 
 impl From<SharedPluginContext> for BindingPluginContext {
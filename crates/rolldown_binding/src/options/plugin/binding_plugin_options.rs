@@ -10,7 +10,7 @@ use super::{
   binding_plugin_context::BindingPluginContext,
   binding_transform_context::BindingTransformPluginContext,
   types::{
-    binding_hook_load_output::BindingHookLoadOutput,
+    binding_hook_load_output::{BindingHookLoadOutput, BindingHookLoadResult},
     binding_hook_render_chunk_output::BindingHookRenderChunkOutput,
     binding_hook_resolve_id_extra_options::BindingHookResolveIdExtraOptions,
     binding_hook_resolve_id_output::BindingHookResolveIdOutput,
@@ -25,6 +25,8 @@ pub type BindingPluginOrParallelJsPluginPlaceholder = Option<BindingPluginOption
 #[serde(rename_all = "camelCase")]
 pub struct BindingPluginOptions {
   pub name: String,
+  /// Identifies the plugin and its options in `cacheDir`. Defaults to the name.
+  pub cache_key: Option<String>,
 
   #[serde(skip_deserializing)]
   #[napi(ts_type = "(ctx: BindingPluginContext) => MaybePromise<VoidNullable>")]
@@ -52,12 +54,13 @@ pub struct BindingPluginOptions {
     >,
   >,
 
+  /// Called with ids of a batch of modules, and returns the result of loading each of them in the same order.
   #[serde(skip_deserializing)]
   #[napi(
-    ts_type = "(ctx: BindingPluginContext, id: string) => MaybePromise<VoidNullable<BindingHookLoadOutput>>"
+    ts_type = "(ctx: BindingPluginContext, ids: Array<string>) => MaybePromise<Array<BindingHookLoadResult>>"
   )]
   pub load:
-    Option<MaybeAsyncJsCallback<(BindingPluginContext, String), Option<BindingHookLoadOutput>>>,
+    Option<MaybeAsyncJsCallback<(BindingPluginContext, Vec<String>), Vec<BindingHookLoadResult>>>,
 
   #[serde(skip_deserializing)]
   #[napi(
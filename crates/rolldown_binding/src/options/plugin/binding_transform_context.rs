@@ -24,6 +24,11 @@ impl BindingTransformPluginContext {
     sourcemap.to_json_string().map_err(|e| napi::Error::from_reason(format!("{e:?}")))
   }
 
+  #[napi]
+  pub fn add_watch_file(&self, file: String) {
+    self.inner.add_watch_file(&file);
+  }
+
   #[napi]
   pub fn inner(&self) -> BindingPluginContext {
     Arc::clone(&self.inner.inner).into()
@@ -1,15 +1,24 @@
-use crate::types::{
-  binding_module_info::BindingModuleInfo, binding_outputs::BindingOutputs,
-  js_callback::MaybeAsyncJsCallbackExt,
+use crate::{
+  js_hook_queue::{HookBatcher, JsHookQueue},
+  types::{
+    binding_module_info::BindingModuleInfo, binding_outputs::BindingOutputs,
+    js_callback::MaybeAsyncJsCallbackExt,
+  },
 };
 use rolldown_plugin::Plugin;
 use std::{borrow::Cow, ops::Deref, sync::Arc};
 
-use super::{binding_transform_context::BindingTransformPluginContext, BindingPluginOptions};
+use super::{
+  binding_transform_context::BindingTransformPluginContext,
+  types::binding_hook_load_output::BindingHookLoadOutput, BindingPluginOptions,
+};
 
 #[derive(Debug)]
 pub struct JsPlugin {
   pub(crate) inner: BindingPluginOptions,
+  /// Shared by JS plugins of the bundler
+  queue: Arc<JsHookQueue>,
+  load_batcher: HookBatcher<String, Option<BindingHookLoadOutput>>,
 }
 
 impl Deref for JsPlugin {
@@ -21,13 +30,12 @@ impl Deref for JsPlugin {
 }
 
 impl JsPlugin {
-  #[cfg_attr(target_family = "wasm", allow(unused))]
-  pub(super) fn new(inner: BindingPluginOptions) -> Self {
-    Self { inner }
+  pub(super) fn new(inner: BindingPluginOptions, queue: Arc<JsHookQueue>) -> Self {
+    Self { inner, queue, load_batcher: HookBatcher::default() }
   }
 
-  pub(crate) fn new_boxed(inner: BindingPluginOptions) -> Box<dyn Plugin> {
-    Box::new(Self { inner })
+  pub(crate) fn new_boxed(inner: BindingPluginOptions, queue: Arc<JsHookQueue>) -> Box<dyn Plugin> {
+    Box::new(Self::new(inner, queue))
   }
 }
 
@@ -37,6 +45,11 @@ impl Plugin for JsPlugin {
     Cow::Owned(self.name.clone())
   }
 
+  // Options of JavaScript plugins live in closures, so they're keyed by `cacheKey` instead.
+  fn cache_key(&self) -> Cow<'static, str> {
+    Cow::Owned(self.cache_key.clone().unwrap_or_else(|| self.name.clone()))
+  }
+
   // --- Build hooks ---
 
   async fn build_start(
@@ -56,12 +69,15 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookResolveIdReturn {
     if let Some(cb) = &self.resolve_id {
       Ok(
-        cb.await_call((
-          Arc::clone(ctx).into(),
-          args.source.to_string(),
-          args.importer.map(str::to_string),
-          args.options.clone().into(),
-        ))
+        cb.await_queued_call(
+          &self.queue,
+          (
+            Arc::clone(ctx).into(),
+            args.source.to_string(),
+            args.importer.map(str::to_string),
+            args.options.clone().into(),
+          ),
+        )
         .await?
         .map(Into::into),
       )
@@ -77,11 +93,10 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookResolveIdReturn {
     if let Some(cb) = &self.resolve_dynamic_import {
       Ok(
-        cb.await_call((
-          Arc::clone(ctx).into(),
-          args.source.to_string(),
-          args.importer.map(str::to_string),
-        ))
+        cb.await_queued_call(
+          &self.queue,
+          (Arc::clone(ctx).into(), args.source.to_string(), args.importer.map(str::to_string)),
+        )
         .await?
         .map(Into::into),
       )
@@ -97,7 +112,12 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookLoadReturn {
     if let Some(cb) = &self.load {
       Ok(
-        cb.await_call((Arc::clone(ctx).into(), args.id.to_string()))
+        self
+          .load_batcher
+          .call(args.id.to_string(), |ids| async {
+            let results = cb.await_queued_call(&self.queue, (Arc::clone(ctx).into(), ids)).await?;
+            Ok(results.into_iter().map(Into::into).collect())
+          })
           .await?
           .map(TryInto::try_into)
           .transpose()?,
@@ -114,11 +134,14 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookTransformReturn {
     if let Some(cb) = &self.transform {
       Ok(
-        cb.await_call((
-          BindingTransformPluginContext::new(unsafe { std::mem::transmute(ctx) }),
-          args.code.to_string(),
-          args.id.to_string(),
-        ))
+        cb.await_queued_call(
+          &self.queue,
+          (
+            BindingTransformPluginContext::new(unsafe { std::mem::transmute(ctx) }),
+            args.code.to_string(),
+            args.id.to_string(),
+          ),
+        )
         .await?
         .map(TryInto::try_into)
         .transpose()?,
@@ -202,6 +225,10 @@ impl Plugin for JsPlugin {
     Ok(())
   }
 
+  fn has_output_hooks(&self) -> bool {
+    self.generate_bundle.is_some() || self.write_bundle.is_some()
+  }
+
   async fn generate_bundle(
     &self,
     ctx: &rolldown_plugin::SharedPluginContext,
@@ -1,3 +1,4 @@
+mod binding_builtin_plugin;
 pub mod binding_plugin_context;
 mod binding_plugin_options;
 mod binding_transform_context;
@@ -5,6 +6,7 @@ mod js_plugin;
 mod parallel_js_plugin;
 pub mod types;
 
+pub use binding_builtin_plugin::*;
 pub use binding_plugin_options::*;
 pub use js_plugin::*;
 pub use parallel_js_plugin::*;
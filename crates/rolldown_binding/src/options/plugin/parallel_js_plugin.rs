@@ -7,7 +7,7 @@ use futures::future::{self, BoxFuture};
 #[cfg(not(target_family = "wasm"))]
 use rolldown_plugin::Plugin;
 
-use crate::worker_manager::WorkerManager;
+use crate::{js_hook_queue::JsHookQueue, worker_manager::WorkerManager};
 
 #[cfg(not(target_family = "wasm"))]
 use super::BindingPluginOptions;
@@ -25,8 +25,13 @@ impl ParallelJsPlugin {
   pub fn new_boxed(
     plugins: Vec<BindingPluginOptions>,
     worker_manager: Arc<WorkerManager>,
+    queue: &Arc<JsHookQueue>,
   ) -> Box<dyn Plugin> {
-    let plugins = plugins.into_iter().map(JsPlugin::new).collect::<Vec<_>>().into_boxed_slice();
+    let plugins = plugins
+      .into_iter()
+      .map(|plugin| JsPlugin::new(plugin, Arc::clone(queue)))
+      .collect::<Vec<_>>()
+      .into_boxed_slice();
     Box::new(Self { plugins, worker_manager })
   }
 
@@ -63,6 +68,10 @@ impl Plugin for ParallelJsPlugin {
     self.first_plugin().name()
   }
 
+  fn cache_key(&self) -> Cow<'static, str> {
+    self.first_plugin().cache_key()
+  }
+
   // --- Build hooks ---
 
   async fn build_start(
@@ -136,6 +145,10 @@ impl Plugin for ParallelJsPlugin {
 
   // --- Output hooks ---
 
+  fn has_output_hooks(&self) -> bool {
+    self.first_plugin().has_output_hooks()
+  }
+
   async fn generate_bundle(
     &self,
     ctx: &rolldown_plugin::SharedPluginContext,
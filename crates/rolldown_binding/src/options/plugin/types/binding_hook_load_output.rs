@@ -13,6 +13,25 @@ pub struct BindingHookLoadOutput {
   pub side_effects: Option<BindingHookSideEffects>,
 }
 
+/// The result of loading one module of a batch. A hook that throws only fails the load of its own module.
+#[napi_derive::napi(object)]
+#[derive(Deserialize, Default, Derivative)]
+#[serde(rename_all = "camelCase")]
+#[derivative(Debug)]
+pub struct BindingHookLoadResult {
+  pub output: Option<BindingHookLoadOutput>,
+  pub error: Option<String>,
+}
+
+impl From<BindingHookLoadResult> for napi::Result<Option<BindingHookLoadOutput>> {
+  fn from(value: BindingHookLoadResult) -> Self {
+    match value.error {
+      Some(reason) => Err(napi::Error::new(napi::Status::GenericFailure, reason)),
+      None => Ok(value.output),
+    }
+  }
+}
+
 impl TryFrom<BindingHookLoadOutput> for rolldown_plugin::HookLoadOutput {
   type Error = anyhow::Error;
 
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use derivative::Derivative;
 use serde::Deserialize;
 
@@ -8,10 +10,19 @@ use serde::Deserialize;
 pub struct BindingHookResolveIdExtraOptions {
   pub is_entry: bool,
   pub kind: String,
+  pub attributes: HashMap<String, String>,
 }
 
 impl From<rolldown_plugin::HookResolveIdExtraOptions> for BindingHookResolveIdExtraOptions {
   fn from(value: rolldown_plugin::HookResolveIdExtraOptions) -> Self {
-    Self { is_entry: value.is_entry, kind: value.kind.to_string() }
+    Self {
+      is_entry: value.is_entry,
+      kind: value.kind.to_string(),
+      attributes: value
+        .attributes
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .collect(),
+    }
   }
 }
@@ -1,7 +1,46 @@
 use napi_derive::napi;
 
-#[napi]
+/// A plain object, so it could be serialized with `JSON.stringify`.
+#[napi(object)]
 pub struct BindingLog {
+  /// Stable code of the log, such as `UNRESOLVED_IMPORT`.
   pub code: String,
   pub message: String,
+  /// The message with excerpts of the code it's about, as printed to the terminal. Colored if stderr supports colors.
+  pub frame: Option<String>,
+  /// Ids of modules the log is about, such as the modules of an import cycle.
+  pub ids: Option<Vec<String>>,
+  /// Name of the plugin that emitted the log.
+  pub plugin: Option<String>,
+  pub loc: Option<BindingLogLocation>,
+  /// How to fix the problem, if it could be guessed.
+  pub help: Option<String>,
+}
+
+#[napi(object)]
+pub struct BindingLogLocation {
+  /// Relative to `cwd`
+  pub file: String,
+  /// Starts from 1
+  pub line: u32,
+  /// Starts from 0
+  pub column: u32,
+}
+
+impl From<rolldown_common::Log> for BindingLog {
+  fn from(value: rolldown_common::Log) -> Self {
+    Self {
+      code: value.code,
+      message: value.message,
+      frame: value.frame,
+      ids: value.ids,
+      plugin: value.plugin,
+      loc: value.loc.map(|loc| BindingLogLocation {
+        file: loc.file,
+        line: loc.line,
+        column: loc.column,
+      }),
+      help: value.help,
+    }
+  }
 }
@@ -11,6 +11,7 @@ pub struct BindingModuleInfo {
   pub imported_ids: Vec<String>,
   pub dynamically_imported_ids: Vec<String>,
   pub is_entry: bool,
+  pub exports: Vec<String>,
 }
 
 #[napi]
@@ -27,6 +28,7 @@ impl BindingModuleInfo {
         .map(|id| id.to_string())
         .collect(),
       is_entry: inner.is_entry,
+      exports: inner.exports.iter().map(ToString::to_string).collect(),
       inner,
     }
   }
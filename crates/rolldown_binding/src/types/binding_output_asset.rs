@@ -1,4 +1,6 @@
+use napi::{bindgen_prelude::Buffer, Either};
 use napi_derive::napi;
+use rolldown_common::AssetSource;
 
 #[napi]
 pub struct BindingOutputAsset {
@@ -17,12 +19,18 @@ impl BindingOutputAsset {
   }
 
   #[napi(getter)]
-  pub fn source(&self) -> String {
-    self.inner.source.clone()
+  pub fn source(&self) -> Either<String, Buffer> {
+    match &self.inner.source {
+      AssetSource::String(value) => Either::A(value.clone()),
+      AssetSource::Buffer(value) => Either::B(value.clone().into()),
+    }
   }
 
   #[napi(setter, js_name = "source")]
-  pub fn set_source(&mut self, source: String) {
-    self.inner.source = source;
+  pub fn set_source(&mut self, source: Either<String, Buffer>) {
+    self.inner.source = match source {
+      Either::A(value) => AssetSource::String(value),
+      Either::B(value) => AssetSource::Buffer(value.to_vec()),
+    };
   }
 }
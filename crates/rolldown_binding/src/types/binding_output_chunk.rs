@@ -108,6 +108,11 @@ impl BindingOutputChunk {
     self.inner.sourcemap_filename.clone()
   }
 
+  #[napi(getter)]
+  pub fn css_file_name(&self) -> Option<String> {
+    self.inner.css_filename.clone()
+  }
+
   #[napi(getter)]
   pub fn preliminary_file_name(&self) -> String {
     self.inner.preliminary_filename.to_string()
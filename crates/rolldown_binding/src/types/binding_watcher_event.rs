@@ -0,0 +1,70 @@
+use napi_derive::napi;
+use rolldown::WatcherEvent;
+
+#[napi(object)]
+pub struct BindingWatcherEvent {
+  #[napi(ts_type = "'BUNDLE_START' | 'BUNDLE_END' | 'ERROR' | 'HMR_UPDATE' | 'RESTART'")]
+  pub code: String,
+  /// Files changed since the last build, for `BUNDLE_START` of rebuilds.
+  pub changed_files: Option<Vec<String>>,
+  /// Milliseconds the build took, for `BUNDLE_END`.
+  pub duration: Option<f64>,
+  /// Number of errors of the build, for `BUNDLE_END`. Diagnostics of the build are printed to stderr already.
+  pub error_count: Option<u32>,
+  /// Number of warnings of the build, for `BUNDLE_END`.
+  pub warning_count: Option<u32>,
+  /// The error that stopped the build, for `ERROR`.
+  pub error: Option<String>,
+  /// The update chunk of modules changed since the last build, for `HMR_UPDATE`.
+  pub hmr_update: Option<BindingHmrUpdate>,
+}
+
+/// Send `code` to the browser and run it to apply the update. It's written to `filename` along with other chunks, unless
+/// `watch.skipWrite` is enabled.
+#[napi(object)]
+pub struct BindingHmrUpdate {
+  pub filename: String,
+  pub code: String,
+  /// Stable ids of updated modules, as `import.meta.hot` refers to.
+  pub module_ids: Vec<String>,
+}
+
+impl BindingWatcherEvent {
+  fn new(code: &str) -> Self {
+    Self {
+      code: code.to_string(),
+      changed_files: None,
+      duration: None,
+      error_count: None,
+      warning_count: None,
+      error: None,
+      hmr_update: None,
+    }
+  }
+}
+
+impl From<WatcherEvent> for BindingWatcherEvent {
+  fn from(value: WatcherEvent) -> Self {
+    match value {
+      WatcherEvent::BundleStart { changed_files } => {
+        Self { changed_files: Some(changed_files), ..Self::new("BUNDLE_START") }
+      }
+      WatcherEvent::BundleEnd { duration, output } => Self {
+        duration: Some(duration.as_secs_f64() * 1000.0),
+        error_count: Some(u32::try_from(output.errors.len()).unwrap_or(u32::MAX)),
+        warning_count: Some(u32::try_from(output.warnings.len()).unwrap_or(u32::MAX)),
+        ..Self::new("BUNDLE_END")
+      },
+      WatcherEvent::Error(err) => Self { error: Some(err.to_string()), ..Self::new("ERROR") },
+      WatcherEvent::RestartDueToConfigChange => Self::new("RESTART"),
+      WatcherEvent::HmrUpdate(update) => Self {
+        hmr_update: Some(BindingHmrUpdate {
+          filename: update.filename,
+          code: update.code,
+          module_ids: update.module_ids,
+        }),
+        ..Self::new("HMR_UPDATE")
+      },
+    }
+  }
+}
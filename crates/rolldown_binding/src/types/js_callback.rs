@@ -6,6 +6,8 @@ use napi::{
 };
 use rolldown_utils::debug::pretty_type_name;
 
+use crate::js_hook_queue::JsHookQueue;
+
 /// `JsCallback`  is a type alias for `ThreadsafeFunction`. It represents a JavaScript function that passed to Rust side.
 /// Related concepts are complex, so we use `JsCallback` to simplify the mental model. For details, please refer to:
 /// - https://napi.rs/docs/compat-mode/concepts/thread-safe-function.en
@@ -74,6 +76,15 @@ pub type MaybeAsyncJsCallback<Args, Ret> =
 pub trait MaybeAsyncJsCallbackExt<Args, Ret> {
   /// Call Js function asynchronously in rust. If the Js function returns `Promise<T>`, it will unwrap/await the promise and return `T`.
   fn await_call(&self, args: Args) -> impl Future<Output = Result<Ret, napi::Error>> + Send;
+
+  /// Same as `await_call`, but waits for a permit of `queue` before calling the Js function. The permit is released once
+  /// the Js function returns, before awaiting the returned promise. Hooks awaiting `this.resolve(...)` or `this.load(...)`
+  /// wait for nested hook calls, which need permits of their own.
+  fn await_queued_call(
+    &self,
+    queue: &JsHookQueue,
+    args: Args,
+  ) -> impl Future<Output = Result<Ret, napi::Error>> + Send;
 }
 
 impl<Args, Ret> MaybeAsyncJsCallbackExt<Args, Ret> for JsCallback<Args, Either<Promise<Ret>, Ret>>
@@ -84,24 +95,47 @@ where
 {
   #[allow(clippy::manual_async_fn)]
   fn await_call(&self, args: Args) -> impl Future<Output = Result<Ret, napi::Error>> + Send {
+    async move { settle::<Self, Ret>(self.call_async(args).await?).await }
+  }
+
+  #[allow(clippy::manual_async_fn)]
+  fn await_queued_call(
+    &self,
+    queue: &JsHookQueue,
+    args: Args,
+  ) -> impl Future<Output = Result<Ret, napi::Error>> + Send {
     async move {
-      match self.call_async(args).await? {
-        Either::A(Either::A(promise)) => promise.await,
-        Either::A(Either::B(ret)) => Ok(ret),
-        Either::B(_unknown) => {
-          // TODO: should provide more information about the unknown return value
-          let js_type = "unknown";
-          let expected_rust_type = pretty_type_name::<Ret>();
+      let ret = {
+        let _permit = queue.acquire().await;
+        self.call_async(args).await?
+      };
+      settle::<Self, Ret>(ret).await
+    }
+  }
+}
+
+/// Unwrap/await the value returned by a Js function called through `Callback`.
+async fn settle<Callback, Ret>(
+  ret: Either<Either<Promise<Ret>, Ret>, UnknownReturnValue>,
+) -> Result<Ret, napi::Error>
+where
+  Ret: 'static + Send + FromNapiValue,
+{
+  match ret {
+    Either::A(Either::A(promise)) => promise.await,
+    Either::A(Either::B(ret)) => Ok(ret),
+    Either::B(_unknown) => {
+      // TODO: should provide more information about the unknown return value
+      let js_type = "unknown";
+      let expected_rust_type = pretty_type_name::<Ret>();
 
-          Err(napi::Error::new(
-            napi::Status::InvalidArg,
-            format!(
-              "UNKNOWN_RETURN_VALUE. Cannot convert {js_type} to `{expected_rust_type}` in {}.",
-              pretty_type_name::<Self>(),
-            ),
-          ))
-        }
-      }
+      Err(napi::Error::new(
+        napi::Status::InvalidArg,
+        format!(
+          "UNKNOWN_RETURN_VALUE. Cannot convert {js_type} to `{expected_rust_type}` in {}.",
+          pretty_type_name::<Callback>(),
+        ),
+      ))
     }
   }
 }
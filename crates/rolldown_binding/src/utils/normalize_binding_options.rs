@@ -1,14 +1,19 @@
 #[cfg_attr(target_family = "wasm", allow(unused))]
 use crate::{
+  js_hook_queue::JsHookQueue,
   options::plugin::JsPlugin,
   options::plugin::ParallelJsPlugin,
   types::{binding_rendered_chunk::RenderedChunk, js_callback::MaybeAsyncJsCallbackExt},
   worker_manager::WorkerManager,
 };
-use rolldown::{AddonOutputOption, BundlerOptions, IsExternal, OutputFormat, Platform};
+use napi::bindgen_prelude::Buffer;
+use rolldown::{
+  AddonOutputOption, BundlerOptions, Charset, DropKind, HashAlgorithm, HashCharacters, IsExternal,
+  LegalComments, OutputFormat, Platform, RequireReturnsDefault, UnresolvedRequire,
+};
 use rolldown_plugin::BoxPlugin;
+use std::collections::HashMap;
 use std::path::PathBuf;
-#[cfg(not(target_family = "wasm"))]
 use std::sync::Arc;
 
 #[cfg_attr(target_family = "wasm", allow(unused))]
@@ -31,18 +36,15 @@ fn normalize_addon_option(
   })
 }
 
-pub fn normalize_binding_options(
-  input_options: crate::options::BindingInputOptions,
-  output_options: crate::options::BindingOutputOptions,
-  #[cfg(not(target_family = "wasm"))] mut parallel_plugins_map: Option<
-    crate::parallel_js_plugin_registry::PluginValues,
-  >,
-  #[cfg(not(target_family = "wasm"))] worker_manager: Option<WorkerManager>,
-) -> napi::Result<NormalizeBindingOptionsReturn> {
-  debug_assert!(PathBuf::from(&input_options.cwd) != PathBuf::from("/"), "{input_options:#?}");
-  let cwd = PathBuf::from(input_options.cwd);
+fn invalid_option(err: impl ToString) -> napi::Error {
+  napi::Error::new(napi::Status::GenericFailure, err)
+}
 
-  let external = input_options.external.map(|ts_fn| {
+fn normalize_external(
+  input_options: &mut crate::options::BindingInputOptions,
+) -> Option<IsExternal> {
+  let external_ids = input_options.external_ids.take().unwrap_or_default();
+  input_options.external.take().map(|ts_fn| {
     IsExternal::from_closure(move |source, importer, is_resolved| {
       let source = source.to_string();
       let importer = importer.map(ToString::to_string);
@@ -54,8 +56,117 @@ pub fn normalize_binding_options(
           .map_err(anyhow::Error::from)
       })
     })
+    .with_ids(external_ids)
+  })
+}
+
+fn normalize_input_options(
+  mut input_options: crate::options::BindingInputOptions,
+) -> napi::Result<BundlerOptions> {
+  debug_assert!(PathBuf::from(&input_options.cwd) != PathBuf::from("/"), "{input_options:#?}");
+  let cwd = PathBuf::from(std::mem::take(&mut input_options.cwd));
+  let external = normalize_external(&mut input_options);
+
+  let assets_inline_limit = input_options.assets_inline_limit.map(|ts_fn| {
+    rolldown::AssetsInlineLimit::Fn(Box::new(move |file, content| {
+      let ts_fn = ts_fn.clone();
+      let file = file.to_string();
+      let content = Buffer::from(content.to_vec());
+      Box::pin(async move { ts_fn.call_async((file, content)).await.map_err(anyhow::Error::from) })
+    }))
+  });
+
+  let on_log = input_options.on_log.map(|ts_fn| {
+    rolldown::OnLog::new(Box::new(move |level, log| {
+      let ts_fn = ts_fn.clone();
+      Box::pin(async move {
+        ts_fn.call_async((level.to_string(), log.into())).await.map_err(anyhow::Error::from)
+      })
+    }))
   });
 
+  Ok(BundlerOptions {
+    input: Some(input_options.input.into_iter().map(Into::into).collect()),
+    stdin: input_options.stdin.map(TryInto::try_into).transpose().map_err(invalid_option)?,
+    cwd: cwd.into(),
+    external,
+    treeshake: true.into(),
+    resolve: input_options.resolve.map(Into::into),
+    platform: input_options
+      .platform
+      .as_deref()
+      .map(Platform::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    node_protocol: input_options.node_protocol,
+    shim_missing_exports: input_options.shim_missing_exports,
+    drop: input_options
+      .drop
+      .map(|drop| {
+        drop.iter().map(|kind| DropKind::try_from(kind.as_str())).collect::<Result<Vec<_>, _>>()
+      })
+      .transpose()
+      .map_err(invalid_option)?,
+    pure: input_options.pure,
+    define: input_options.define,
+    env: input_options.env,
+    strict_requires: input_options.strict_requires,
+    require_returns_default: input_options
+      .require_returns_default
+      .as_deref()
+      .map(RequireReturnsDefault::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    dynamic_require_targets: input_options.dynamic_require_targets,
+    unresolved_require: input_options
+      .unresolved_require
+      .as_deref()
+      .map(UnresolvedRequire::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    assets_inline_limit,
+    inline_const_enums: input_options.inline_const_enums,
+    css_modules: input_options
+      .css_modules
+      .map(TryInto::try_into)
+      .transpose()
+      .map_err(invalid_option)?,
+    circular_dependency: input_options.circular_dependency.map(Into::into),
+    eval_warning: input_options.eval_warning.map(Into::into),
+    checks: input_options.checks.map(TryInto::try_into).transpose().map_err(invalid_option)?,
+    public_dir: input_options.public_dir,
+    watch: input_options.watch.map(Into::into),
+    hmr: input_options.hmr,
+    lazy_compilation: input_options.lazy_compilation,
+    cache_dir: input_options.cache_dir,
+    threads: input_options.threads.map(|threads| threads as usize),
+    max_parallel_file_ops: input_options.max_parallel_file_ops.map(|ops| ops as usize),
+    profile: input_options.profile,
+    profile_trace_file: input_options.profile_trace_file,
+    log_level: input_options.log_level.map(Into::into),
+    collapse_warnings: input_options.collapse_warnings,
+    on_log,
+    module_types: input_options
+      .module_types
+      .map(|module_types| {
+        module_types
+          .into_iter()
+          .map(|(ext, value)| {
+            rolldown::Loader::try_from(value.as_str()).map(|loader| (ext, loader))
+          })
+          .collect::<Result<_, _>>()
+      })
+      .transpose()
+      .map_err(invalid_option)?,
+    ..Default::default()
+  })
+}
+
+/// Fills the output related fields of `bundler_options`, which only has the input ones yet.
+fn normalize_output_options(
+  output_options: crate::options::BindingOutputOptions,
+  bundler_options: BundlerOptions,
+) -> napi::Result<BundlerOptions> {
   let sourcemap_ignore_list = output_options.sourcemap_ignore_list.map(|ts_fn| {
     rolldown::SourceMapIgnoreList::new(Box::new(move |source, sourcemap_path| {
       let ts_fn = ts_fn.clone();
@@ -78,23 +189,51 @@ pub fn normalize_binding_options(
     }))
   });
 
-  let bundler_options = BundlerOptions {
-    input: Some(input_options.input.into_iter().map(Into::into).collect()),
-    cwd: cwd.into(),
-    external,
-    treeshake: true.into(),
-    resolve: input_options.resolve.map(Into::into),
-    platform: input_options
-      .platform
-      .as_deref()
-      .map(Platform::try_from)
-      .transpose()
-      .map_err(|err| napi::Error::new(napi::Status::GenericFailure, err))?,
-    shim_missing_exports: input_options.shim_missing_exports,
+  Ok(BundlerOptions {
     entry_filenames: output_options.entry_file_names,
     chunk_filenames: output_options.chunk_file_names,
+    asset_filenames: output_options.asset_file_names,
+    sourcemap_filenames: output_options.sourcemap_file_names,
     dir: output_options.dir,
+    charset: output_options
+      .charset
+      .as_deref()
+      .map(Charset::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    legal_comments: output_options
+      .legal_comments
+      .as_deref()
+      .map(LegalComments::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    css_minify: output_options.css_minify,
+    minify: output_options.minify,
+    stream_writes: output_options.stream_writes,
+    hash_characters: output_options
+      .hash_characters
+      .as_deref()
+      .map(HashCharacters::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    hash_algorithm: output_options
+      .hash_algorithm
+      .as_deref()
+      .map(HashAlgorithm::try_from)
+      .transpose()
+      .map_err(invalid_option)?,
+    hash_salt: output_options.hash_salt,
     sourcemap: output_options.sourcemap.map(Into::into),
+    sourcemap_debug_ids: output_options.sourcemap_debug_ids,
+    validate: output_options.validate,
+    reserved_names: output_options.reserved_names,
+    es3_safe_identifiers: output_options.es3_safe_identifiers,
+    dts: output_options.dts,
+    runtime_chunk: output_options.runtime_chunk,
+    minify_internal_exports: output_options.minify_internal_exports,
+    metafile: output_options.metafile,
+    report_compressed_size: output_options.report_compressed_size,
+    max_chunk_size: output_options.max_chunk_size.map(|size| size as usize),
     banner: normalize_addon_option(output_options.banner),
     footer: normalize_addon_option(output_options.footer),
     sourcemap_ignore_list,
@@ -104,41 +243,77 @@ pub fn normalize_binding_options(
       "cjs" => OutputFormat::Cjs,
       _ => panic!("Invalid format: {format_str}"),
     }),
-    loaders: None,
-  };
+    ..bundler_options
+  })
+}
+
+pub fn normalize_binding_options(
+  mut input_options: crate::options::BindingInputOptions,
+  mut output_options: crate::options::BindingOutputOptions,
+  #[cfg(not(target_family = "wasm"))] mut parallel_plugins_map: Option<
+    crate::parallel_js_plugin_registry::PluginValues,
+  >,
+  #[cfg(not(target_family = "wasm"))] worker_manager: Option<WorkerManager>,
+) -> napi::Result<NormalizeBindingOptionsReturn> {
+  let js_plugins = std::mem::take(&mut input_options.plugins)
+    .into_iter()
+    .chain(std::mem::take(&mut output_options.plugins));
+  let builtin_plugins = input_options.builtin_plugins.take();
+  let max_parallel_js_hooks = input_options.max_parallel_js_hooks;
+
+  let bundler_options =
+    normalize_output_options(output_options, normalize_input_options(input_options)?)?;
+
+  bundler_options.validate().map_err(|errors| {
+    let message = errors.iter().map(ToString::to_string).collect::<Vec<_>>().join("\n");
+    napi::Error::new(napi::Status::InvalidArg, message)
+  })?;
 
   #[cfg(not(target_family = "wasm"))]
   // Deal with plugins
   let worker_manager = worker_manager.map(Arc::new);
+  let js_hook_queue = Arc::new(JsHookQueue::new(max_parallel_js_hooks));
 
-  #[cfg(not(target_family = "wasm"))]
-  let plugins: Vec<BoxPlugin> = input_options
-    .plugins
+  // Builtin plugins are placed at their indexes, so they keep their order among Js plugins.
+  let mut builtin_plugins = builtin_plugins
+    .unwrap_or_default()
     .into_iter()
-    .chain(output_options.plugins)
+    .map(|plugin| (plugin.index as usize, plugin))
+    .collect::<HashMap<_, _>>();
+
+  #[cfg(not(target_family = "wasm"))]
+  let plugins: Vec<BoxPlugin> = js_plugins
     .enumerate()
     .map(|(index, plugin)| {
-      plugin.map_or_else(
+      if let Some(builtin_plugin) = builtin_plugins.remove(&index) {
+        return BoxPlugin::try_from(builtin_plugin);
+      }
+      Ok(plugin.map_or_else(
         || {
           let plugins = parallel_plugins_map
             .as_mut()
             .and_then(|plugin| plugin.remove(&index))
             .unwrap_or_default();
           let worker_manager = worker_manager.as_ref().unwrap();
-          ParallelJsPlugin::new_boxed(plugins, Arc::clone(worker_manager))
+          ParallelJsPlugin::new_boxed(plugins, Arc::clone(worker_manager), &js_hook_queue)
         },
-        |plugin| JsPlugin::new_boxed(plugin),
-      )
+        |plugin| JsPlugin::new_boxed(plugin, Arc::clone(&js_hook_queue)),
+      ))
     })
-    .collect::<Vec<_>>();
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err))?;
 
   #[cfg(target_family = "wasm")]
-  let plugins: Vec<BoxPlugin> = input_options
-    .plugins
-    .into_iter()
-    .chain(output_options.plugins)
-    .filter_map(|plugin| plugin.map(|plugin| JsPlugin::new_boxed(plugin)))
-    .collect::<Vec<_>>();
+  let plugins: Vec<BoxPlugin> = js_plugins
+    .enumerate()
+    .filter_map(|(index, plugin)| {
+      if let Some(builtin_plugin) = builtin_plugins.remove(&index) {
+        return Some(BoxPlugin::try_from(builtin_plugin));
+      }
+      plugin.map(|plugin| Ok(JsPlugin::new_boxed(plugin, Arc::clone(&js_hook_queue))))
+    })
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|err| napi::Error::new(napi::Status::InvalidArg, err))?;
 
   Ok(NormalizeBindingOptionsReturn { bundler_options, plugins })
 }
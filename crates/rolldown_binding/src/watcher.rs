@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use crate::{
+  bundler::Bundler,
+  options::{BindingInputOptions, BindingOutputOptions},
+  parallel_js_plugin_registry::ParallelJsPluginRegistry,
+  types::{binding_watcher_event::BindingWatcherEvent, js_callback::JsCallback},
+};
+use napi::{tokio::sync::Mutex, Env};
+use napi_derive::napi;
+use rolldown::{Bundler as NativeBundler, LazyCompilation};
+
+#[napi]
+pub struct BindingWatcher {
+  /// Taken once watching starts, since watching owns the bundler.
+  inner: Mutex<Option<NativeBundler>>,
+  /// Only exists with `lazyCompilation`.
+  lazy_compilation: Option<Arc<LazyCompilation>>,
+}
+
+#[napi]
+impl BindingWatcher {
+  #[napi(constructor)]
+  pub fn new(
+    env: Env,
+    input_options: BindingInputOptions,
+    output_options: BindingOutputOptions,
+    parallel_plugins_registry: Option<ParallelJsPluginRegistry>,
+  ) -> napi::Result<Self> {
+    let inner = Bundler::create_native_bundler(
+      env,
+      input_options,
+      output_options,
+      parallel_plugins_registry,
+    )?;
+    let lazy_compilation = inner.lazy_compilation();
+    Ok(Self { inner: Mutex::new(Some(inner)), lazy_compilation })
+  }
+
+  /// Bundle the module with `id`, which proxy modules of `lazyCompilation` pass to `__rolldown_lazy__.load(id)`, as
+  /// usual since the next build. Watching rebuilds once a module is requested for the first time.
+  #[napi]
+  pub fn request_lazy_compilation(&self, id: String) -> napi::Result<()> {
+    request_lazy_compilation(self.lazy_compilation.as_deref(), &id)
+  }
+
+  /// Build, then rebuild whenever watched files change, calling `listener` with events of each build. The returned
+  /// promise doesn't resolve while watching.
+  #[napi(ts_args_type = "listener: (event: BindingWatcherEvent) => void")]
+  pub async fn start(&self, listener: JsCallback<BindingWatcherEvent, ()>) -> napi::Result<()> {
+    let bundler = self
+      .inner
+      .lock()
+      .await
+      .take()
+      .ok_or_else(|| napi::Error::from_reason("The watcher is already started"))?;
+    let mut events = bundler.watch().map_err(|e| napi::Error::from_reason(e.to_string()))?;
+    while let Some(event) = events.recv().await {
+      listener.call_async(event.into()).await?;
+    }
+    Ok(())
+  }
+}
+
+pub(crate) fn request_lazy_compilation(
+  lazy_compilation: Option<&LazyCompilation>,
+  id: &str,
+) -> napi::Result<()> {
+  let lazy_compilation =
+    lazy_compilation.ok_or_else(|| napi::Error::from_reason("`lazyCompilation` is not enabled"))?;
+  lazy_compilation.request(id);
+  Ok(())
+}
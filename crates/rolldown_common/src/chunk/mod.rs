@@ -5,6 +5,8 @@ use crate::{
 };
 pub mod types;
 
+use std::borrow::Cow;
+
 use rolldown_rstr::Rstr;
 use rolldown_utils::{path_ext::PathExt, BitSet};
 use rustc_hash::FxHashMap;
@@ -30,6 +32,10 @@ pub struct Chunk {
   pub bits: BitSet,
   pub imports_from_other_chunks: Vec<(ChunkId, Vec<CrossChunkImportItem>)>,
   pub imports_from_external_modules: Vec<(ExternalModuleId, Vec<NamedImport>)>,
+  /// Symbols imported from other chunks that are reassigned after their declarations. In CommonJS output, they're read
+  /// as properties of the `exports` of their chunks on each access, so they stay live. Values are the symbol the
+  /// `exports` is bound to and the name of the property.
+  pub live_imports_from_other_chunks: FxHashMap<SymbolRef, (SymbolRef, Rstr)>,
   // meaningless if the chunk is an entrypoint
   pub exports_to_other_chunks: FxHashMap<SymbolRef, Rstr>,
 }
@@ -44,6 +50,22 @@ impl Chunk {
     Self { exec_order: u32::MAX, modules, name, bits, kind, ..Self::default() }
   }
 
+  /// Whether the chunk is an entry whose module is merged into a shared chunk, because other entries import it. The
+  /// chunk has no modules then, and re-exports bindings of the entry module from the shared chunk, so the entry keeps
+  /// its own file and exports.
+  pub fn is_facade(&self) -> bool {
+    matches!(self.kind, ChunkKind::EntryPoint { .. }) && self.modules.is_empty()
+  }
+
+  /// The entry module of entry chunks, or the first executed module of common chunks. Unlike `modules`, it's
+  /// available for facades.
+  pub fn representative_module(&self) -> NormalModuleId {
+    match self.kind {
+      ChunkKind::EntryPoint { module, .. } => module,
+      ChunkKind::Common => self.modules[0],
+    }
+  }
+
   pub fn filename_template<'a>(
     &mut self,
     options: &'a NormalizedBundlerOptions,
@@ -66,10 +88,14 @@ impl Chunk {
   }
 
   pub fn import_path_for(&self, importee: &Chunk) -> String {
+    self.relative_path_for(importee.absolute_preliminary_filename.as_ref().unwrap())
+  }
+
+  /// Path of the file relative to this chunk, which always starts with `.`.
+  pub fn relative_path_for(&self, absolute_path: &str) -> String {
     let importer_dir =
       self.absolute_preliminary_filename.as_ref().unwrap().as_path().parent().unwrap();
-    let importee_filename = importee.absolute_preliminary_filename.as_ref().unwrap();
-    let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
+    let import_path = absolute_path.relative(importer_dir).as_path().expect_to_slash();
 
     if import_path.starts_with('.') {
       import_path
@@ -77,4 +103,15 @@ impl Chunk {
       format!("./{import_path}")
     }
   }
+
+  /// The specifier that imports the external module named `name` from this chunk. Absolute paths, which externals
+  /// resolved by plugins might be, are made relative to the chunk, so the output doesn't depend on where the project is.
+  /// Protocol-relative URLs, such as `//example.com/lib.js`, are kept as is.
+  pub fn external_import_path_for<'a>(&self, name: &'a str) -> Cow<'a, str> {
+    if name.as_path().is_absolute() && !name.starts_with("//") {
+      Cow::Owned(self.relative_path_for(name))
+    } else {
+      Cow::Borrowed(name)
+    }
+  }
 }
@@ -0,0 +1,135 @@
+use std::{collections::HashMap, path::PathBuf};
+
+use crate::{Loader, SourceMapIgnoreList};
+
+use super::{
+  types::{
+    assets_inline_limit::AssetsInlineLimit, charset::Charset, checks::ChecksOptions,
+    circular_dependency::CircularDependencyOptions, css_modules::CssModulesOptions,
+    drop_kind::DropKind, eval_warning::EvalWarningOptions, hash_algorithm::HashAlgorithm,
+    hash_characters::HashCharacters, input_item::InputItem, is_external::IsExternal,
+    legal_comments::LegalComments, log_level::LogLevel, on_log::OnLog, output_format::OutputFormat,
+    output_option::AddonOutputOption, platform::Platform,
+    require_returns_default::RequireReturnsDefault, resolve_options::ResolveOptions,
+    source_map_type::SourceMapType, sourcemap_path_transform::SourceMapPathTransform,
+    stdin::StdinOptions, unresolved_require::UnresolvedRequire, watch_options::WatchOptions,
+  },
+  validate::BundlerOptionsError,
+  BundlerOptions,
+};
+
+/// Build `BundlerOptions` with chained calls, which validates the options once they are complete.
+///
+/// ```ignore
+/// let options = BundlerOptions::builder()
+///   .input(["./src/main.js"])
+///   .format(OutputFormat::Cjs)
+///   .sourcemap(SourceMapType::File)
+///   .build()?;
+/// ```
+#[derive(Debug, Default)]
+pub struct BundlerOptionsBuilder {
+  options: BundlerOptions,
+}
+
+impl BundlerOptions {
+  pub fn builder() -> BundlerOptionsBuilder {
+    BundlerOptionsBuilder::default()
+  }
+}
+
+/// Generate a setter for each option, which accepts anything convertible to the type of the option.
+macro_rules! setters {
+  ($($field:ident: $ty:ty,)*) => {
+    $(
+      #[must_use]
+      pub fn $field(mut self, value: impl Into<$ty>) -> Self {
+        self.options.$field = Some(value.into());
+        self
+      }
+    )*
+  };
+}
+
+impl BundlerOptionsBuilder {
+  /// Entries of the bundle. Items could be paths, or `InputItem`s to name the entries.
+  #[must_use]
+  pub fn input<I: Into<InputItem>>(mut self, input: impl IntoIterator<Item = I>) -> Self {
+    self.options.input = Some(input.into_iter().map(Into::into).collect());
+    self
+  }
+
+  setters! {
+    stdin: StdinOptions,
+    cwd: PathBuf,
+    external: IsExternal,
+    treeshake: bool,
+    platform: Platform,
+    node_protocol: bool,
+    shim_missing_exports: bool,
+    drop: Vec<DropKind>,
+    pure: Vec<String>,
+    define: HashMap<String, String>,
+    env: HashMap<String, String>,
+    strict_requires: bool,
+    require_returns_default: RequireReturnsDefault,
+    dynamic_require_targets: Vec<String>,
+    unresolved_require: UnresolvedRequire,
+    inline_const_enums: bool,
+    assets_inline_limit: AssetsInlineLimit,
+    css_modules: CssModulesOptions,
+    circular_dependency: CircularDependencyOptions,
+    eval_warning: EvalWarningOptions,
+    checks: ChecksOptions,
+    public_dir: String,
+    watch: WatchOptions,
+    hmr: bool,
+    lazy_compilation: bool,
+    cache_dir: String,
+    threads: usize,
+    max_parallel_file_ops: usize,
+    profile: bool,
+    profile_trace_file: String,
+    log_level: LogLevel,
+    collapse_warnings: bool,
+    on_log: OnLog,
+    entry_filenames: String,
+    chunk_filenames: String,
+    asset_filenames: String,
+    sourcemap_filenames: String,
+    dir: String,
+    format: OutputFormat,
+    charset: Charset,
+    legal_comments: LegalComments,
+    css_minify: bool,
+    minify: bool,
+    stream_writes: bool,
+    hash_characters: HashCharacters,
+    hash_algorithm: HashAlgorithm,
+    hash_salt: String,
+    sourcemap: SourceMapType,
+    sourcemap_debug_ids: bool,
+    validate: bool,
+    reserved_names: Vec<String>,
+    es3_safe_identifiers: bool,
+    dts: bool,
+    runtime_chunk: bool,
+    minify_internal_exports: bool,
+    metafile: String,
+    report_compressed_size: bool,
+    max_chunk_size: usize,
+    banner: AddonOutputOption,
+    footer: AddonOutputOption,
+    sourcemap_ignore_list: SourceMapIgnoreList,
+    sourcemap_path_transform: SourceMapPathTransform,
+    module_types: HashMap<String, Loader>,
+    loaders: HashMap<String, Loader>,
+    resolve: ResolveOptions,
+  }
+
+  /// Validate the options with `BundlerOptions::validate`, returning all invalid options if any.
+  pub fn build(self) -> Result<BundlerOptions, Vec<BundlerOptionsError>> {
+    self.options.validate()?;
+    Ok(self.options)
+  }
+}
@@ -8,12 +8,20 @@ use serde::{Deserialize, Deserializer};
 use crate::{Loader, SourceMapIgnoreList};
 
 use self::types::{
-  input_item::InputItem, is_external::IsExternal, output_format::OutputFormat,
-  output_option::AddonOutputOption, platform::Platform, resolve_options::ResolveOptions,
+  assets_inline_limit::AssetsInlineLimit, charset::Charset, checks::ChecksOptions,
+  circular_dependency::CircularDependencyOptions, css_modules::CssModulesOptions,
+  drop_kind::DropKind, eval_warning::EvalWarningOptions, hash_algorithm::HashAlgorithm,
+  hash_characters::HashCharacters, input_item::InputItem, is_external::IsExternal,
+  legal_comments::LegalComments, log_level::LogLevel, on_log::OnLog, output_format::OutputFormat,
+  output_option::AddonOutputOption, platform::Platform,
+  require_returns_default::RequireReturnsDefault, resolve_options::ResolveOptions,
   source_map_type::SourceMapType, sourcemap_path_transform::SourceMapPathTransform,
+  stdin::StdinOptions, unresolved_require::UnresolvedRequire, watch_options::WatchOptions,
 };
 
+pub mod builder;
 pub mod types;
+pub mod validate;
 
 #[derive(Default, Debug)]
 #[cfg_attr(
@@ -23,7 +31,14 @@ pub mod types;
 )]
 pub struct BundlerOptions {
   // --- options for input
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_input"),
+    schemars(with = "Option<self::types::input_item::InputOption>")
+  )]
   pub input: Option<Vec<InputItem>>,
+  /// Bundle the code as an entry named `stdin`, besides entries of `input`.
+  pub stdin: Option<StdinOptions>,
   pub cwd: Option<PathBuf>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
@@ -32,14 +47,175 @@ pub struct BundlerOptions {
   )]
   pub external: Option<IsExternal>,
   pub treeshake: Option<bool>,
+  /// The platform the bundle runs on, which decides the default conditions and main fields of resolving, and whether
+  /// builtin modules of Node.js are external or errors. For `browser`, `process.env.NODE_ENV` is defined as
+  /// `"production"` unless it's set by `define` or `env`. Defaults to `browser`.
   pub platform: Option<Platform>,
+  /// Whether to import builtin modules of Node.js with the `node:` prefix, such as `node:fs` for `fs`, which is
+  /// supported since Node.js 14.18. If `false`, the prefix is removed unless the module requires it, such as
+  /// `node:test`. Either way, `fs` and `node:fs` are imported as the same module. If unset, builtin modules are
+  /// imported as they're resolved. Only applies to `platform: "node"`.
+  pub node_protocol: Option<bool>,
   pub shim_missing_exports: Option<bool>,
+  /// Remove `console.*` calls and/or `debugger` statements from the bundled modules.
+  pub drop: Option<Vec<DropKind>>,
+  /// Names of global functions, such as `"foo"` or `"console.log"`, whose calls are considered side effect free.
+  /// Unused calls to them would be removed by tree shaking.
+  pub pure: Option<Vec<String>>,
+  /// Replace global expressions, such as `process.env.DEBUG` or `import.meta.env.API_URL`, with values written as
+  /// JavaScript, such as `"\"https://example.com\""`. Values could be literals, `undefined` or identifier paths.
+  /// Local variables shadowing the root of an expression are respected.
+  pub define: Option<HashMap<String, String>>,
+  /// Environment variables exposed as `import.meta.env.KEY` and `process.env.KEY`, with the string values. It's a
+  /// shorthand of `define`, which takes precedence. `NODE_ENV` defaults to `"production"` once `env` is set.
+  pub env: Option<HashMap<String, String>>,
+  /// Whether to always wrap ES modules that are `require`d, so they are evaluated lazily when `require(...)` is called.
+  /// If `false`, ES modules that are only `require`d unconditionally at the top level are hoisted and not wrapped.
+  /// Defaults to `true`.
+  pub strict_requires: Option<bool>,
+  /// What `require('esm-module')` returns inside bundled CommonJS modules. Defaults to `false`, which returns the namespace object.
+  pub require_returns_default: Option<RequireReturnsDefault>,
+  /// Glob patterns, relative to `cwd`, of modules that could be required by `require(...)` calls with runtime-computed paths,
+  /// such as `require('./locale/' + name)`. Matched modules are bundled and looked up at runtime. Each call only looks up
+  /// modules under the directory its leading relative path points to, or under the directory of the requiring module.
+  pub dynamic_require_targets: Option<Vec<String>>,
+  /// How to handle `require(...)` calls that can't be resolved or whose argument isn't a string literal. Defaults to `error`, except in modules under `node_modules`, whose requires are kept, since dependencies often require optional packages that may be missing.
+  pub unresolved_require: Option<UnresolvedRequire>,
+  /// Whether accesses of members of `const enum`s imported from TypeScript modules, such as `Direction.Up`, are
+  /// replaced with the values of the members. If `false`, `const enum`s are compiled like regular enums and accessed
+  /// at runtime. Defaults to `true`.
+  pub inline_const_enums: Option<bool>,
+  /// Assets smaller than the limit in bytes are inlined as base64 `data:` URLs instead of being emitted as files.
+  /// A function could be passed to decide for each asset. Defaults to `4096`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_assets_inline_limit"),
+    schemars(with = "Option<usize>")
+  )]
+  pub assets_inline_limit: Option<AssetsInlineLimit>,
+  /// Options for `.module.css` files.
+  pub css_modules: Option<CssModulesOptions>,
+  /// Options for warnings of import cycles.
+  pub circular_dependency: Option<CircularDependencyOptions>,
+  /// Options for warnings of `eval` uses.
+  pub eval_warning: Option<EvalWarningOptions>,
+  /// Whether constructs that the output format can't express, such as top-level `await` in `cjs`, are errors or
+  /// warnings.
+  pub checks: Option<ChecksOptions>,
+  /// Directory, relative to `cwd`, whose files are copied to the output directory as they are while writing.
+  /// A file that has the same name as an emitted chunk or asset is an error.
+  pub public_dir: Option<String>,
+  /// Options for watch mode.
+  pub watch: Option<WatchOptions>,
+  /// Experimental. Rewrite `import.meta.hot` to HMR contexts of modules, and register exports of modules with the HMR
+  /// runtime. In watch mode, rebuilds emit an update chunk of changed modules, which applies the update once it's run
+  /// in the browser. Defaults to `false`.
+  pub hmr: Option<bool>,
+  /// Experimental. Replace modules imported by `import()` with proxy modules, which ask the dev server to compile the
+  /// module on its first import with `globalThis.__rolldown_lazy__.load(id)`. The runtime imports
+  /// `/@rolldown/lazy/<id>` from the dev server by default, which should call `requestLazyCompilation(id)`. Requested
+  /// modules are bundled as usual since the next build. Defaults to `false`.
+  pub lazy_compilation: Option<bool>,
+  /// Directory, relative to `cwd`, to keep results of the `transform` hook and resolutions of imports in across builds.
+  /// Modules whose code before `transform` and files added by `this.addWatchFile` are unchanged skip `transform` hooks
+  /// and resolving imports, unless a plugin asks for transforming with `shouldTransformCachedModule`. The cache is
+  /// invalidated as a whole when the version of rolldown, the plugins, their `cacheKey`s or resolve options change.
+  /// Disabled by default.
+  pub cache_dir: Option<String>,
+  /// Number of threads to link and render modules with. Defaults to the number of CPUs. Lower it when the bundler
+  /// shares the machine with other busy work, such as a dev server or parallel CI jobs.
+  pub threads: Option<usize>,
+  /// Maximum number of modules to load, transform and resolve imports of at the same time. Unlimited by default.
+  pub max_parallel_file_ops: Option<usize>,
+  /// Report how long each stage of the build and each plugin hook takes after the build, as an info log passed to
+  /// `onLog`. Times of resolving, loading and parsing modules are summed over all modules. Defaults to `false`.
+  pub profile: Option<bool>,
+  /// File, relative to `cwd`, to write the recorded times to in the Chrome trace event format, which could be opened
+  /// in `chrome://tracing` or Perfetto. Implies `profile`.
+  pub profile_trace_file: Option<String>,
+  /// The minimum level of logs to report. Defaults to `info`.
+  pub log_level: Option<LogLevel>,
+  /// Collapse warnings of the same code after the first 5 into a single warning counting them, such as the cycles of
+  /// a vendored library. Set it to `false` to report all of them. Defaults to `true`.
+  pub collapse_warnings: Option<bool>,
+  /// Called with each warning of the build and each log of plugins at or above `logLevel`. Returning an error
+  /// escalates the log to an error that fails the build. Without it, warnings are returned in `BundleOutput`, and
+  /// other logs of plugins are dropped.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, skip_deserializing),
+    schemars(skip)
+  )]
+  pub on_log: Option<OnLog>,
   // --- options for output
   pub entry_filenames: Option<String>,
   pub chunk_filenames: Option<String>,
+  /// Filename template of emitted assets. Supports `[name]`, `[hash]`, `[ext]` and `[extname]`. Defaults to `assets/[name]-[hash][extname]`.
+  pub asset_filenames: Option<String>,
+  /// Filename template of emitted `.map` files. Supports `[name]`, `[hash]` of the sourcemap and `[chunkhash]` of the
+  /// chunk. By default, `.map` files are placed next to their chunks.
+  pub sourcemap_filenames: Option<String>,
   pub dir: Option<String>,
   pub format: Option<OutputFormat>,
+  /// Names that generated and renamed variables never use, besides keywords, strict mode reserved words and
+  /// well-known globals, which are always avoided. E.g. globals defined by the host page.
+  pub reserved_names: Option<Vec<String>>,
+  /// Whether to also avoid words that are reserved in ES3 but not in ES5, such as `int` and `char`, so the output runs
+  /// in legacy engines. Defaults to `false`.
+  pub es3_safe_identifiers: Option<bool>,
+  /// Experimental. Bundle the TypeScript declaration file next to each entry module, e.g. `main.d.ts` of `main.ts`,
+  /// with the declaration files it imports through relative specifiers into one declaration file per entry chunk.
+  /// Declaration files are expected to be written in the style of `isolatedDeclarations`. Defaults to `false`.
+  pub dts: Option<bool>,
+  /// Put the rolldown runtime, which holds helpers and the wrappers of wrapped modules, into a chunk of its own named
+  /// `rolldown-runtime`. The chunk is named by `entry_filenames`, so that hashes of other chunks don't change when only the
+  /// runtime changes, as long as `entry_filenames` has no `[hash]`. Defaults to `false`.
+  pub runtime_chunk: Option<bool>,
+  /// Whether to export bindings of shared chunks to other chunks under short names, such as `a` and `b`, which makes
+  /// the output smaller. Defaults to `false`, which keeps the names of the bindings, so code-split output is easier to
+  /// debug.
+  pub minify_internal_exports: Option<bool>,
+  /// Whether to escape non-ASCII characters in the output. Defaults to `utf8`.
+  pub charset: Option<Charset>,
+  /// Where to put legal comments of bundled modules. Defaults to `none`.
+  pub legal_comments: Option<LegalComments>,
+  /// Whether to minify emitted stylesheets. Defaults to `false`.
+  pub css_minify: Option<bool>,
+  /// Whether to minify emitted chunks by removing whitespace and comments. Names aren't mangled and code isn't
+  /// compressed. Legal comments are only kept if `legalComments` writes them to a separate file. Runs after the
+  /// `renderChunk` hook. Defaults to `false`.
+  pub minify: Option<bool>,
+  /// Write chunks and their sourcemaps to disk as soon as their hashes are finalized, instead of keeping them in memory
+  /// until the end of the build. Chunks in the output have empty code and no sourcemaps then, so it can't be used with
+  /// plugins that have `generateBundle` or `writeBundle` hooks. Only applies to `write`. Defaults to `false`.
+  pub stream_writes: Option<bool>,
+  /// Characters of `[hash]` in filenames of chunks, assets and sourcemaps. Defaults to `base64`.
+  pub hash_characters: Option<HashCharacters>,
+  /// Algorithm of `[hash]` in filenames of chunks, assets and sourcemaps. Defaults to `xxhash3`.
+  pub hash_algorithm: Option<HashAlgorithm>,
+  /// A string mixed into `[hash]` of chunks, assets and sourcemaps. Changing it changes all hashes without changing the
+  /// content, e.g. to bust caches of files served with a different configuration. No salt by default.
+  pub hash_salt: Option<String>,
+  /// Generate sourcemaps of chunks. `File` emits `.map` files referenced by `//# sourceMappingURL=` comments, `Inline`
+  /// appends the sourcemap as a `data:` URL and `Hidden` emits `.map` files without the comments.
+  /// No sourcemaps are generated by default.
   pub sourcemap: Option<SourceMapType>,
+  /// Inject `//# debugId=` comments into chunks and matching `debugId` fields into their sourcemaps, so error
+  /// reporting services can match them without URLs. Defaults to `false`.
+  pub sourcemap_debug_ids: Option<bool>,
+  /// Parse each rendered chunk again before writing it, and fail the build with the syntax errors found, pointing at
+  /// the chunk and the module the invalid code comes from. Catches bugs of plugins and the renderer before they ship.
+  /// Defaults to `false`.
+  pub validate: Option<bool>,
+  /// Emit a JSON file with this name describing inputs and outputs of the build, in the format of esbuild's metafile,
+  /// so tools analyzing bundles built by esbuild work with rolldown too. The path is relative to `dir`. Inputs wrapped
+  /// in closures list the reason as `wrap`, to help restructuring the code for flatter output.
+  pub metafile: Option<String>,
+  /// After writing, report a table of the size of each chunk, with estimates of its size compressed by gzip and brotli,
+  /// as an info log passed to `onLog`. Defaults to `false`.
+  pub report_compressed_size: Option<bool>,
+  /// Fail the build if a written chunk is bigger than this number of bytes. Sizes are counted before compression.
+  pub max_chunk_size: Option<usize>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, deserialize_with = "deserialize_addon"),
@@ -52,12 +228,17 @@ pub struct BundlerOptions {
     schemars(with = "Option<String>")
   )]
   pub footer: Option<AddonOutputOption>,
+  /// Called with each source of sourcemaps, relative to the `.map` file, and the absolute path of the `.map` file.
+  /// Sources it returns `true` for are listed in the `x_google_ignoreList` extension, so devtools collapse their
+  /// frames. Defaults to ignoring sources whose paths contain `node_modules`.
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, skip_deserializing),
     schemars(skip)
   )]
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
+  /// Called with each source of sourcemaps, relative to the `.map` file, and the absolute path of the `.map` file.
+  /// The returned path replaces the source, e.g. to use a `webpack://` style scheme.
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(default, skip_deserializing),
@@ -65,12 +246,25 @@ pub struct BundlerOptions {
   )]
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
 
-  /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
+  /// Builtin loaders of files, keyed by the file extension. The extension should start with a `.`. E.g. `".txt"`.
+  /// Files with unknown extensions are loaded as JavaScript. The `jsx` and `tsx` loaders are not supported yet.
+  pub module_types: Option<HashMap<String, Loader>>,
+  /// Deprecated alias of `module_types`, which wins for extensions listed in both. Using it is reported with a
+  /// `DEPRECATED_FEATURE` warning.
   pub loaders: Option<HashMap<String, Loader>>,
   // --- options for resolve
   pub resolve: Option<ResolveOptions>,
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_input<'de, D>(deserializer: D) -> Result<Option<Vec<InputItem>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<self::types::input_item::InputOption>::deserialize(deserializer)?;
+  Ok(deserialized.map(Into::into))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_external<'de, D>(deserializer: D) -> Result<Option<IsExternal>, D::Error>
 where
@@ -88,3 +282,14 @@ where
   let deserialized = Option::<String>::deserialize(deserializer)?;
   Ok(deserialized.map(|s| AddonOutputOption::String(Some(s))))
 }
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_assets_inline_limit<'de, D>(
+  deserializer: D,
+) -> Result<Option<AssetsInlineLimit>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<usize>::deserialize(deserializer)?;
+  Ok(deserialized.map(AssetsInlineLimit::Limit))
+}
@@ -0,0 +1,41 @@
+use std::fmt::Debug;
+use std::{future::Future, pin::Pin};
+
+/// Returns `Some(true)` to inline the asset, `Some(false)` to emit it, or `None` to fall back to the default limit.
+pub type AssetsInlineLimitFn = dyn Fn(&str, &[u8]) -> Pin<Box<(dyn Future<Output = anyhow::Result<Option<bool>>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+pub enum AssetsInlineLimit {
+  /// Assets smaller than the given bytes are inlined.
+  Limit(usize),
+  Fn(Box<AssetsInlineLimitFn>),
+}
+
+impl AssetsInlineLimit {
+  pub const DEFAULT_LIMIT: usize = 4096;
+
+  pub async fn should_inline(&self, file: &str, content: &[u8]) -> anyhow::Result<bool> {
+    match self {
+      Self::Limit(limit) => Ok(content.len() < *limit),
+      Self::Fn(value) => {
+        Ok(value(file, content).await?.unwrap_or(content.len() < Self::DEFAULT_LIMIT))
+      }
+    }
+  }
+}
+
+impl Default for AssetsInlineLimit {
+  fn default() -> Self {
+    Self::Limit(Self::DEFAULT_LIMIT)
+  }
+}
+
+impl Debug for AssetsInlineLimit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Limit(value) => write!(f, "AssetsInlineLimit::Limit({value:?})"),
+      Self::Fn(_) => write!(f, "AssetsInlineLimit::Fn(...)"),
+    }
+  }
+}
@@ -0,0 +1,30 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum Charset {
+  /// Escape all non-ASCII characters in the output, e.g. `"é"` becomes `"\u00E9"`.
+  Ascii,
+  /// Keep non-ASCII characters as they are.
+  #[default]
+  Utf8,
+}
+
+impl TryFrom<&str> for Charset {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "ascii" => Ok(Self::Ascii),
+      "utf8" => Ok(Self::Utf8),
+      _ => Err(format!("Unknown charset: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,47 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Whether constructs that the output format can't express fail the build or are reported as warnings.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ChecksOptions {
+  /// Top-level `await` in formats other than `esm`, whose chunks can't wait for modules. Defaults to `error`.
+  pub top_level_await: Option<CheckLevel>,
+  /// `import.meta` in the `cjs` format, where it's a syntax error. `new URL('./foo.png', import.meta.url)` is
+  /// rewritten and not reported. Defaults to `error`.
+  pub import_meta: Option<CheckLevel>,
+  /// Chunks of the `cjs` format importing each other in a cycle, where `require` returns exports of a chunk that
+  /// haven't been assigned yet. Defaults to `warn`.
+  pub circular_chunks: Option<CheckLevel>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum CheckLevel {
+  /// Fail the build.
+  Error,
+  /// Report a warning and generate the output anyway.
+  Warn,
+}
+
+impl TryFrom<&str> for CheckLevel {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, <Self as TryFrom<&str>>::Error> {
+    match value {
+      "error" => Ok(Self::Error),
+      "warn" => Ok(Self::Warn),
+      _ => Err(format!("Unknown check level: {value:?}")),
+    }
+  }
+}
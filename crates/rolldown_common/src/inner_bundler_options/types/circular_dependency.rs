@@ -0,0 +1,19 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for the `CIRCULAR_DEPENDENCY` warning, which lists the modules of each import cycle.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CircularDependencyOptions {
+  /// Glob patterns, relative to `cwd`, of modules whose cycles are known to be safe, such as `**/node_modules/**`.
+  /// A cycle is not reported if any of its modules matches.
+  pub exclude: Option<Vec<String>>,
+  /// Report cycles as errors that fail the build instead of warnings. Defaults to `false`.
+  pub error: Option<bool>,
+}
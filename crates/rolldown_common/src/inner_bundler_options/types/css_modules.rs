@@ -0,0 +1,54 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for `.module.css` files, whose class names are scoped to the file and exported as an object.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct CssModulesOptions {
+  /// Pattern of scoped class names. Supports `[name]`, `[local]` and `[hash]`. Defaults to `[name]_[local]_[hash:5]`.
+  pub scoped_name: Option<String>,
+  /// How class names are exposed in the exported object. Defaults to `asIs`.
+  pub locals_convention: Option<LocalsConvention>,
+}
+
+/// How class names of CSS modules are exposed in the exported object.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LocalsConvention {
+  /// Keep class names as they are.
+  #[default]
+  AsIs,
+  /// Expose both the original and the camelCased class names.
+  CamelCase,
+  /// Only expose the camelCased class names.
+  CamelCaseOnly,
+  /// Expose both the original class names and the ones with only dashes camelCased.
+  Dashes,
+  /// Only expose the class names with dashes camelCased.
+  DashesOnly,
+}
+
+impl TryFrom<&str> for LocalsConvention {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "asIs" => Ok(Self::AsIs),
+      "camelCase" => Ok(Self::CamelCase),
+      "camelCaseOnly" => Ok(Self::CamelCaseOnly),
+      "dashes" => Ok(Self::Dashes),
+      "dashesOnly" => Ok(Self::DashesOnly),
+      _ => Err(format!("Unknown localsConvention: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,76 @@
+/// The value an expression is replaced with by `define`. Values are written as JavaScript, so string values need to
+/// be quoted, such as `"\"production\""`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DefineValue {
+  String(String),
+  Number(f64),
+  Bool(bool),
+  Null,
+  Undefined,
+  /// An identifier or a chain of property accesses on it, such as `globalThis` or `window.config`.
+  Path(Vec<String>),
+}
+
+impl DefineValue {
+  /// Returns `None` if the value isn't a literal, `undefined` or an identifier path, which isn't supported.
+  pub fn parse(value: &str) -> Option<Self> {
+    let value = value.trim();
+    if value == "undefined" {
+      return Some(Self::Undefined);
+    }
+    match serde_json::from_str::<serde_json::Value>(value) {
+      Ok(serde_json::Value::String(value)) => return Some(Self::String(value)),
+      Ok(serde_json::Value::Number(value)) => return value.as_f64().map(Self::Number),
+      Ok(serde_json::Value::Bool(value)) => return Some(Self::Bool(value)),
+      Ok(serde_json::Value::Null) => return Some(Self::Null),
+      Ok(_) => return None,
+      Err(_) => {}
+    }
+    let path = value.split('.').map(ToString::to_string).collect::<Vec<_>>();
+    path.iter().all(|part| is_identifier(part)).then_some(Self::Path(path))
+  }
+}
+
+/// An expression to replace and the value to replace it with.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DefineReplacement {
+  /// Parts of the expression, such as `["process", "env", "NODE_ENV"]`. `import.meta` is kept as a single part, since
+  /// it's the root of the expression like an identifier.
+  pub key: Vec<String>,
+  pub value: DefineValue,
+}
+
+impl DefineReplacement {
+  /// Returns `None` if the key isn't an identifier path, or the value isn't supported.
+  pub fn parse(key: &str, value: &str) -> Option<Self> {
+    Some(Self { key: parse_key(key)?, value: DefineValue::parse(value)? })
+  }
+}
+
+fn parse_key(key: &str) -> Option<Vec<String>> {
+  let (root, rest) = if let Some(rest) = key.strip_prefix("import.meta") {
+    ("import.meta", rest)
+  } else {
+    let end = key.find('.').unwrap_or(key.len());
+    if !is_identifier(&key[..end]) {
+      return None;
+    }
+    key.split_at(end)
+  };
+  let mut parts = vec![root.to_string()];
+  if !rest.is_empty() {
+    for part in rest.strip_prefix('.')?.split('.') {
+      if !is_identifier(part) {
+        return None;
+      }
+      parts.push(part.to_string());
+    }
+  }
+  Some(parts)
+}
+
+fn is_identifier(value: &str) -> bool {
+  let mut chars = value.chars();
+  chars.next().is_some_and(|c| c.is_alphabetic() || c == '_' || c == '$')
+    && chars.all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
@@ -0,0 +1,29 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum DropKind {
+  /// Remove calls to the global `console.*`, such as `console.log(...)` and `console.warn.apply(...)`.
+  Console,
+  /// Remove `debugger` statements.
+  Debugger,
+}
+
+impl TryFrom<&str> for DropKind {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "console" => Ok(Self::Console),
+      "debugger" => Ok(Self::Debugger),
+      _ => Err(format!("Unknown drop kind: {value:?}")),
+    }
+  }
+}
@@ -0,0 +1,18 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for the `EVAL` warning, which points at uses of `eval`. Code evaluated by `eval` may not see variables
+/// renamed while bundling.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct EvalWarningOptions {
+  /// Glob patterns, relative to `cwd`, of modules whose uses of `eval` are known to be safe, such as
+  /// `**/node_modules/**`.
+  pub exclude: Option<Vec<String>>,
+}
@@ -23,6 +23,8 @@ impl From<String> for FilenameTemplate {
 pub struct FileNameRenderOptions<'me> {
   pub name: Option<&'me str>,
   pub hash: Option<&'me str>,
+  /// Extension without the leading dot, such as `png`.
+  pub ext: Option<&'me str>,
 }
 
 impl FilenameTemplate {
@@ -34,6 +36,9 @@ impl FilenameTemplate {
     if let Some(hash) = options.hash {
       tmp = tmp.replace("[hash]", hash);
     }
+    if let Some(ext) = options.ext {
+      tmp = tmp.replace("[extname]", &format!(".{ext}")).replace("[ext]", ext);
+    }
     tmp
   }
 }
@@ -0,0 +1,31 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Algorithm of `[hash]` in filenames. Hashes are stable across builds as long as the algorithm stays the same.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum HashAlgorithm {
+  /// 128-bit XXH3, which is fast but not cryptographic.
+  #[default]
+  Xxhash3,
+  /// BLAKE3, which is cryptographic and still fast on large inputs.
+  Blake3,
+}
+
+impl TryFrom<&str> for HashAlgorithm {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "xxhash3" => Ok(Self::Xxhash3),
+      "blake3" => Ok(Self::Blake3),
+      _ => Err(format!("Unknown hash algorithm: {value:?}")),
+    }
+  }
+}
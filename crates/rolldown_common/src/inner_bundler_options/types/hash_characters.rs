@@ -0,0 +1,34 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Characters of `[hash]` in filenames.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum HashCharacters {
+  /// URL-safe base64 characters, i.e. `A-Z`, `a-z`, `0-9`, `-` and `_`.
+  #[default]
+  Base64,
+  /// `0-9` and `a-z`, for file systems and servers that are case-insensitive.
+  Base36,
+  /// `0-9` and `a-f`.
+  Hex,
+}
+
+impl TryFrom<&str> for HashCharacters {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "base64" => Ok(Self::Base64),
+      "base36" => Ok(Self::Base36),
+      "hex" => Ok(Self::Hex),
+      _ => Err(format!("Unknown hash characters: {value:?}")),
+    }
+  }
+}
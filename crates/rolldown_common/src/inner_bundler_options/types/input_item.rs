@@ -1,9 +1,12 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use std::collections::BTreeMap;
+
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
 use serde::Deserialize;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 #[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
 pub struct InputItem {
   pub name: Option<String>,
@@ -15,3 +18,31 @@ impl From<String> for InputItem {
     Self { name: None, import: value }
   }
 }
+
+impl From<&str> for InputItem {
+  fn from(value: &str) -> Self {
+    Self { name: None, import: value.to_string() }
+  }
+}
+
+/// `input` in config files is either a list of entries, or a map from names of entries to their paths. Paths could
+/// be glob patterns, such as `src/pages/**/*.ts`, which are expanded to the matched files when the build starts.
+#[cfg(feature = "deserialize_bundler_options")]
+#[derive(Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum InputOption {
+  Items(Vec<InputItem>),
+  Named(BTreeMap<String, String>),
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+impl From<InputOption> for Vec<InputItem> {
+  fn from(value: InputOption) -> Self {
+    match value {
+      InputOption::Items(items) => items,
+      InputOption::Named(named) => {
+        named.into_iter().map(|(name, import)| InputItem { name: Some(name), import }).collect()
+      }
+    }
+  }
+}
@@ -12,13 +12,17 @@ type Inner = dyn Fn(
   + Sync
   + 'static;
 
-pub struct IsExternal(Box<Inner>);
+pub struct IsExternal {
+  f: Box<Inner>,
+  /// Ids listed in `external`, which are warned about if no import matches them.
+  ids: Vec<String>,
+}
 
 impl Deref for IsExternal {
   type Target = Inner;
 
   fn deref(&self) -> &Self::Target {
-    &*self.0
+    &*self.f
   }
 }
 
@@ -34,14 +38,27 @@ impl IsExternal {
       + Sync
       + 'static,
   {
-    Self(Box::new(f))
+    Self { f: Box::new(f), ids: vec![] }
   }
 
   pub fn from_vec(value: Vec<String>) -> Self {
+    let ids = value.clone();
     Self::from_closure(move |source, _, _| {
       let result = value.iter().any(|item| item == source);
       Box::pin(async move { Ok(result) })
     })
+    .with_ids(ids)
+  }
+
+  /// Set ids listed in `external`, for `external` given as a closure that matches them.
+  #[must_use]
+  pub fn with_ids(mut self, ids: Vec<String>) -> Self {
+    self.ids = ids;
+    self
+  }
+
+  pub fn ids(&self) -> &[String] {
+    &self.ids
   }
 }
 
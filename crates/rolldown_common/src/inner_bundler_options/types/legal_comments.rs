@@ -0,0 +1,46 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Legal comments are block comments starting with `/*!` or comments containing `@license` or `@preserve`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LegalComments {
+  /// Keep legal comments at the top of the module they come from.
+  Inline,
+  /// Move all legal comments to the end of the chunk.
+  Eof,
+  /// Write legal comments to a `[chunk].LEGAL.txt` file and link to it with a comment at the end of the chunk.
+  Linked,
+  /// Write legal comments to a `[chunk].LEGAL.txt` file without linking to it.
+  External,
+  /// Drop all legal comments, like modules are rendered without this option.
+  #[default]
+  None,
+}
+
+impl LegalComments {
+  pub fn is_extracted_to_file(&self) -> bool {
+    matches!(self, Self::Linked | Self::External)
+  }
+}
+
+impl TryFrom<&str> for LegalComments {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "inline" => Ok(Self::Inline),
+      "eof" => Ok(Self::Eof),
+      "linked" => Ok(Self::Linked),
+      "external" => Ok(Self::External),
+      "none" => Ok(Self::None),
+      _ => Err(format!("Unknown legal comments option: {value:?}")),
+    }
+  }
+}
@@ -11,6 +11,52 @@ use serde::Deserialize;
 #[derive(Debug, Clone, Copy)]
 pub enum Loader {
   Js,
+  Jsx,
+  Ts,
+  Tsx,
   Json,
   Text,
+  /// Export the content of the file encoded in base64 as the default export.
+  Base64,
+  /// Export the content of the file as a `data:` URL as the default export.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(rename = "dataurl"))]
+  DataUrl,
+  /// Export the content of the file as a `Uint8Array` as the default export.
+  Binary,
+  /// Treat the file as an empty module.
+  Empty,
+  /// Emit the file to the output directory and export its URL as the default export.
+  Asset,
+  /// Extract the stylesheet into a `.css` file of the chunk that includes the module. The module itself exports nothing.
+  Css,
+  /// Instantiate the WebAssembly module and export its exports. Its imports are imported from the modules they name.
+  /// Import it with `?url` to get the URL of the emitted file instead.
+  Wasm,
+  /// Bundle the module scripts and stylesheets referenced by the document. HTML entries are emitted as `.html` files
+  /// referencing the bundled chunk and its stylesheet.
+  Html,
+}
+
+impl TryFrom<&str> for Loader {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "js" => Ok(Self::Js),
+      "jsx" => Ok(Self::Jsx),
+      "ts" => Ok(Self::Ts),
+      "tsx" => Ok(Self::Tsx),
+      "json" => Ok(Self::Json),
+      "text" => Ok(Self::Text),
+      "base64" => Ok(Self::Base64),
+      "dataurl" => Ok(Self::DataUrl),
+      "binary" => Ok(Self::Binary),
+      "empty" => Ok(Self::Empty),
+      "asset" => Ok(Self::Asset),
+      "css" => Ok(Self::Css),
+      "wasm" => Ok(Self::Wasm),
+      "html" => Ok(Self::Html),
+      _ => Err(format!("Unknown module type: {value:?}")),
+    }
+  }
 }
@@ -0,0 +1,75 @@
+use std::fmt::Display;
+
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Level of a log. As `logLevel`, the minimum level of logs to report, and `silent` drops all logs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LogLevel {
+  Silent,
+  Warn,
+  #[default]
+  Info,
+  Debug,
+}
+
+impl LogLevel {
+  fn priority(self) -> u8 {
+    match self {
+      Self::Debug => 0,
+      Self::Info => 1,
+      Self::Warn => 2,
+      Self::Silent => 3,
+    }
+  }
+
+  /// Whether logs of this level are reported with `log_level` as `logLevel`.
+  pub fn is_enabled_by(self, log_level: LogLevel) -> bool {
+    self != Self::Silent && self.priority() >= log_level.priority()
+  }
+
+  pub fn as_str(self) -> &'static str {
+    match self {
+      Self::Silent => "silent",
+      Self::Warn => "warn",
+      Self::Info => "info",
+      Self::Debug => "debug",
+    }
+  }
+}
+
+impl TryFrom<&str> for LogLevel {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "silent" => Ok(Self::Silent),
+      "warn" => Ok(Self::Warn),
+      "info" => Ok(Self::Info),
+      "debug" => Ok(Self::Debug),
+      _ => Err(format!("Unknown logLevel: {value:?}")),
+    }
+  }
+}
+
+impl Display for LogLevel {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    self.as_str().fmt(f)
+  }
+}
+
+#[test]
+fn test_log_level_is_enabled_by() {
+  assert!(LogLevel::Warn.is_enabled_by(LogLevel::Info));
+  assert!(LogLevel::Info.is_enabled_by(LogLevel::Info));
+  assert!(!LogLevel::Debug.is_enabled_by(LogLevel::Info));
+  assert!(LogLevel::Debug.is_enabled_by(LogLevel::Debug));
+  assert!(!LogLevel::Warn.is_enabled_by(LogLevel::Silent));
+}
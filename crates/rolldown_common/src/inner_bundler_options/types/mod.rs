@@ -1,12 +1,29 @@
+pub mod assets_inline_limit;
+pub mod charset;
+pub mod checks;
+pub mod circular_dependency;
+pub mod css_modules;
+pub mod define;
+pub mod drop_kind;
+pub mod eval_warning;
 pub mod filename_template;
+pub mod hash_algorithm;
+pub mod hash_characters;
 pub mod input_item;
 pub mod is_external;
+pub mod legal_comments;
 pub mod loader;
+pub mod log_level;
 pub mod normalized_bundler_options;
+pub mod on_log;
 pub mod output_format;
 pub mod output_option;
 pub mod platform;
+pub mod require_returns_default;
 pub mod resolve_options;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
+pub mod stdin;
+pub mod unresolved_require;
+pub mod watch_options;
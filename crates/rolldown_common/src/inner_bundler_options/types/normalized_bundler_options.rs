@@ -8,31 +8,189 @@ use rustc_hash::FxHashMap;
 use crate::Loader;
 
 use super::{
-  filename_template::FilenameTemplate, input_item::InputItem, is_external::IsExternal,
-  output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
-  source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
-  sourcemap_path_transform::SourceMapPathTransform,
+  assets_inline_limit::AssetsInlineLimit, charset::Charset, checks::CheckLevel,
+  css_modules::LocalsConvention, define::DefineReplacement, drop_kind::DropKind,
+  filename_template::FilenameTemplate, hash_algorithm::HashAlgorithm,
+  hash_characters::HashCharacters, input_item::InputItem, is_external::IsExternal,
+  legal_comments::LegalComments, log_level::LogLevel, on_log::OnLog, output_format::OutputFormat,
+  output_option::AddonOutputOption, platform::Platform,
+  require_returns_default::RequireReturnsDefault, source_map_type::SourceMapType,
+  sourcemap_ignore_list::SourceMapIgnoreList, sourcemap_path_transform::SourceMapPathTransform,
+  stdin::NormalizedStdin, unresolved_require::UnresolvedRequire,
 };
 
+bitflags::bitflags! {
+  #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+  pub struct BundlerFlags: u32 {
+    const STRICT_REQUIRES = 1 << 0;
+    const CIRCULAR_DEPENDENCY_ERROR = 1 << 1;
+    const WATCH_SKIP_WRITE = 1 << 2;
+    const HMR = 1 << 3;
+    const LAZY_COMPILATION = 1 << 4;
+    const PROFILE = 1 << 5;
+    const COLLAPSE_WARNINGS = 1 << 6;
+    const CSS_MINIFY = 1 << 7;
+    const STREAM_WRITES = 1 << 8;
+    const SOURCEMAP_DEBUG_IDS = 1 << 9;
+    const VALIDATE = 1 << 10;
+    const ES3_SAFE_IDENTIFIERS = 1 << 11;
+    const DTS = 1 << 12;
+    const RUNTIME_CHUNK = 1 << 13;
+    const MINIFY_INTERNAL_EXPORTS = 1 << 14;
+    const REPORT_COMPRESSED_SIZE = 1 << 15;
+    const INLINE_CONST_ENUMS = 1 << 16;
+    /// Set with `BundlerBuilder::with_test_mode` by tests of fixtures, not by any option.
+    const TEST_MODE = 1 << 17;
+    const MINIFY = 1 << 18;
+  }
+}
+
 #[derive(Debug)]
 pub struct NormalizedBundlerOptions {
   // --- Input
   pub input: Vec<InputItem>,
+  pub stdin: Option<NormalizedStdin>,
   pub cwd: PathBuf,
   pub external: Option<IsExternal>,
   pub treeshake: bool,
+  /// Other boolean options, see their accessors.
+  pub flags: BundlerFlags,
   pub platform: Platform,
+  /// Whether builtin modules of Node.js are imported with the `node:` prefix. They're imported as resolved if unset.
+  pub node_protocol: Option<bool>,
   pub shim_missing_exports: bool,
+  pub drop: Vec<DropKind>,
+  pub pure: Vec<String>,
+  /// Replacements of `define` and `env`, sorted by keys.
+  pub define: Vec<DefineReplacement>,
+  pub require_returns_default: RequireReturnsDefault,
+  pub dynamic_require_targets: Vec<String>,
+  /// Unset if the handling depends on whether a module is a dependency.
+  pub unresolved_require: Option<UnresolvedRequire>,
+  pub assets_inline_limit: AssetsInlineLimit,
+  pub css_modules_scoped_name: String,
+  pub css_modules_locals_convention: LocalsConvention,
+  /// Glob patterns relative to `cwd`
+  pub circular_dependency_exclude: Vec<String>,
+  /// Glob patterns relative to `cwd`
+  pub eval_warning_exclude: Vec<String>,
+  pub checks_top_level_await: CheckLevel,
+  pub checks_import_meta: CheckLevel,
+  pub checks_circular_chunks: CheckLevel,
   /// The key is the extension. Unlike `BundlerOptions`, the extension doesn't start with a dot.
-  pub loaders: FxHashMap<String, Loader>,
+  pub module_types: FxHashMap<String, Loader>,
+  /// Absolute path of the public directory.
+  pub public_dir: Option<PathBuf>,
+  /// Glob patterns relative to `cwd`. Empty means all watched files.
+  pub watch_include: Vec<String>,
+  pub watch_exclude: Vec<String>,
+  /// In milliseconds
+  pub watch_build_delay: u64,
+  /// Milliseconds between checks of the polling watcher. `None` means using events of the file system.
+  pub watch_poll_interval: Option<u64>,
+  /// Absolute path of the cache directory.
+  pub cache_dir: Option<PathBuf>,
+  pub threads: Option<usize>,
+  pub max_parallel_file_ops: Option<usize>,
+  pub profile_trace_file: Option<PathBuf>,
+  pub log_level: LogLevel,
+  pub on_log: Option<OnLog>,
   // --- Output
   pub entry_filenames: FilenameTemplate,
   pub chunk_filenames: FilenameTemplate,
+  pub asset_filenames: FilenameTemplate,
+  pub sourcemap_filenames: Option<FilenameTemplate>,
   pub dir: String,
   pub format: OutputFormat,
-  pub sourcemap: SourceMapType,
+  pub charset: Charset,
+  pub legal_comments: LegalComments,
+  pub hash_characters: HashCharacters,
+  pub hash_algorithm: HashAlgorithm,
+  pub hash_salt: Option<String>,
+  pub sourcemap: Option<SourceMapType>,
+  pub reserved_names: Vec<String>,
+  pub metafile: Option<String>,
+  pub max_chunk_size: Option<usize>,
   pub banner: Option<AddonOutputOption>,
   pub footer: Option<AddonOutputOption>,
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
 }
+
+impl NormalizedBundlerOptions {
+  pub fn strict_requires(&self) -> bool {
+    self.flags.contains(BundlerFlags::STRICT_REQUIRES)
+  }
+
+  pub fn circular_dependency_error(&self) -> bool {
+    self.flags.contains(BundlerFlags::CIRCULAR_DEPENDENCY_ERROR)
+  }
+
+  pub fn watch_skip_write(&self) -> bool {
+    self.flags.contains(BundlerFlags::WATCH_SKIP_WRITE)
+  }
+
+  pub fn hmr(&self) -> bool {
+    self.flags.contains(BundlerFlags::HMR)
+  }
+
+  pub fn lazy_compilation(&self) -> bool {
+    self.flags.contains(BundlerFlags::LAZY_COMPILATION)
+  }
+
+  pub fn profile(&self) -> bool {
+    self.flags.contains(BundlerFlags::PROFILE)
+  }
+
+  pub fn collapse_warnings(&self) -> bool {
+    self.flags.contains(BundlerFlags::COLLAPSE_WARNINGS)
+  }
+
+  pub fn css_minify(&self) -> bool {
+    self.flags.contains(BundlerFlags::CSS_MINIFY)
+  }
+
+  pub fn stream_writes(&self) -> bool {
+    self.flags.contains(BundlerFlags::STREAM_WRITES)
+  }
+
+  pub fn sourcemap_debug_ids(&self) -> bool {
+    self.flags.contains(BundlerFlags::SOURCEMAP_DEBUG_IDS)
+  }
+
+  pub fn validate(&self) -> bool {
+    self.flags.contains(BundlerFlags::VALIDATE)
+  }
+
+  pub fn es3_safe_identifiers(&self) -> bool {
+    self.flags.contains(BundlerFlags::ES3_SAFE_IDENTIFIERS)
+  }
+
+  pub fn dts(&self) -> bool {
+    self.flags.contains(BundlerFlags::DTS)
+  }
+
+  pub fn runtime_chunk(&self) -> bool {
+    self.flags.contains(BundlerFlags::RUNTIME_CHUNK)
+  }
+
+  pub fn minify_internal_exports(&self) -> bool {
+    self.flags.contains(BundlerFlags::MINIFY_INTERNAL_EXPORTS)
+  }
+
+  pub fn report_compressed_size(&self) -> bool {
+    self.flags.contains(BundlerFlags::REPORT_COMPRESSED_SIZE)
+  }
+
+  pub fn inline_const_enums(&self) -> bool {
+    self.flags.contains(BundlerFlags::INLINE_CONST_ENUMS)
+  }
+
+  pub fn minify(&self) -> bool {
+    self.flags.contains(BundlerFlags::MINIFY)
+  }
+
+  pub fn test_mode(&self) -> bool {
+    self.flags.contains(BundlerFlags::TEST_MODE)
+  }
+}
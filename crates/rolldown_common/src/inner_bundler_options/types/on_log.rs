@@ -0,0 +1,31 @@
+use std::fmt::Debug;
+use std::{future::Future, pin::Pin};
+
+use crate::Log;
+
+use super::log_level::LogLevel;
+
+type OnLogFn = dyn Fn(LogLevel, Log) -> Pin<Box<(dyn Future<Output = anyhow::Result<()>> + Send + 'static)>>
+  + Send
+  + Sync;
+
+/// Called with each warning of the build and each log of plugins at or above `logLevel`. Logs passed to it are not
+/// returned as warnings of the build, so it could suppress them. Returning an error escalates the log to an error
+/// that fails the build.
+pub struct OnLog(Box<OnLogFn>);
+
+impl Debug for OnLog {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "OnLog::Fn(...)")
+  }
+}
+
+impl OnLog {
+  pub fn new(f: Box<OnLogFn>) -> Self {
+    Self(f)
+  }
+
+  pub async fn call(&self, level: LogLevel, log: Log) -> anyhow::Result<()> {
+    self.0(level, log).await
+  }
+}
@@ -19,4 +19,12 @@ impl OutputFormat {
   pub fn requires_scope_hoisting(&self) -> bool {
     matches!(self, Self::Esm | Self::Cjs)
   }
+
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Self::Esm => "esm",
+      Self::Cjs => "cjs",
+      Self::App => "app",
+    }
+  }
 }
@@ -7,9 +7,12 @@ use serde::Deserialize;
 #[cfg_attr(feature = "deserialize_bundler_options", derive(Deserialize, JsonSchema))]
 #[cfg_attr(feature = "deserialize_bundler_options", serde(rename_all = "camelCase"))]
 pub enum Platform {
-  /// Represents the Node.js platform.
+  /// Node.js. Builtin modules of Node.js are external, and packages are resolved with the `node` condition and the
+  /// `main` and `module` fields.
   Node,
+  /// Browsers. Packages are resolved with the `browser` condition and the `browser`, `module` and `main` fields.
   Browser,
+  /// Neither of them. No platform specific condition or field is used, and builtin modules of Node.js are errors.
   Neutral,
 }
 
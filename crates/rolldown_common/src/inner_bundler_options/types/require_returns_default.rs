@@ -0,0 +1,39 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// What `require('esm-module')` returns inside bundled CommonJS modules.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum RequireReturnsDefault {
+  /// Always return the namespace object.
+  #[default]
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(rename = "false"))]
+  False,
+  /// Always return the default export, even if it doesn't exist.
+  #[cfg_attr(feature = "deserialize_bundler_options", serde(rename = "true"))]
+  True,
+  /// Return the default export if the module only has a default export. Otherwise, return the namespace object.
+  Auto,
+  /// Return the default export if it exists. Otherwise, return the namespace object.
+  Preferred,
+}
+
+impl TryFrom<&str> for RequireReturnsDefault {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, Self::Error> {
+    match value {
+      "false" => Ok(Self::False),
+      "true" => Ok(Self::True),
+      "auto" => Ok(Self::Auto),
+      "preferred" => Ok(Self::Preferred),
+      _ => Err(format!("Unknown requireReturnsDefault: {value:?}")),
+    }
+  }
+}
@@ -9,6 +9,9 @@ pub enum SourceMapType {
   File,
   Inline,
   Hidden,
+  /// Like `File`, but only lines are mapped. Columns and names are dropped, which makes sourcemaps much faster to
+  /// generate for large apps.
+  Cheap,
 }
 
 impl SourceMapType {
@@ -23,6 +26,7 @@ impl From<String> for SourceMapType {
       "file" => SourceMapType::File,
       "inline" => SourceMapType::Inline,
       "hidden" => SourceMapType::Hidden,
+      "cheap" => SourceMapType::Cheap,
       _ => unreachable!("unknown sourcemap type"),
     }
   }
@@ -0,0 +1,32 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+use crate::Loader;
+
+/// The path of the entry built from `stdin`, under `resolveDir`.
+pub const STDIN_FILE_NAME: &str = "<stdin>";
+
+/// Code to bundle as an entry without a file, such as code generated by scripts. It's `input: { stdin }` in JavaScript.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct StdinOptions {
+  pub code: String,
+  /// The directory that relative imports of the code are resolved from, relative to `cwd`. Defaults to `cwd`.
+  pub resolve_dir: Option<String>,
+  /// How to load the code. Defaults to `js`.
+  pub loader: Option<Loader>,
+}
+
+#[derive(Debug)]
+pub struct NormalizedStdin {
+  pub code: String,
+  /// The absolute id of the entry, which is `<stdin>` under the resolve directory.
+  pub id: String,
+  pub loader: Loader,
+}
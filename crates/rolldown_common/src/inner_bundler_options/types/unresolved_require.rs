@@ -0,0 +1,34 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// How to handle `require(...)` calls that can't be resolved or whose argument isn't a string literal.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum UnresolvedRequire {
+  /// Report an error.
+  #[default]
+  Error,
+  /// Keep the `require(...)` call as it is.
+  Keep,
+  /// Replace the `require(...)` call with an expression that throws an error at runtime.
+  Stub,
+}
+
+impl TryFrom<&str> for UnresolvedRequire {
+  type Error = String;
+
+  fn try_from(value: &str) -> Result<Self, <Self as TryFrom<&str>>::Error> {
+    match value {
+      "error" => Ok(Self::Error),
+      "keep" => Ok(Self::Keep),
+      "stub" => Ok(Self::Stub),
+      _ => Err(format!("Unknown unresolvedRequire: {value:?}")),
+    }
+  }
+}
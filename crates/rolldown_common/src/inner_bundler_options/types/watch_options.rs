@@ -0,0 +1,38 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Options for `Bundler::watch`.
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WatchOptions {
+  /// Glob patterns, relative to `cwd`, of files whose changes trigger rebuilds. Defaults to all watched files.
+  pub include: Option<Vec<String>>,
+  /// Glob patterns, relative to `cwd`, of files whose changes never trigger rebuilds, such as `**/node_modules/**`.
+  pub exclude: Option<Vec<String>>,
+  /// Milliseconds to wait for further changes before rebuilding. Changes keep postponing the rebuild until none
+  /// happens within the delay, so a burst of changes triggers a single rebuild. Defaults to `20`.
+  pub build_delay: Option<u64>,
+  /// Generate the output in watch mode without writing it to disk, so the consumer could serve it from memory.
+  /// Defaults to `false`.
+  pub skip_write: Option<bool>,
+  /// Detect changes by checking modification times and contents of watched files periodically, instead of relying on
+  /// events of the file system. Useful for network file systems and some containers, which don't deliver such events.
+  pub poll: Option<WatchPollOptions>,
+}
+
+#[derive(Debug, Default)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct WatchPollOptions {
+  /// Milliseconds between checks. Defaults to `100`.
+  pub interval: Option<u64>,
+}
@@ -0,0 +1,264 @@
+use std::fmt::Display;
+
+use rustc_hash::FxHashSet;
+
+use super::{
+  types::{define::DefineReplacement, loader::Loader, output_format::OutputFormat},
+  BundlerOptions,
+};
+
+/// An option that is invalid on its own or conflicts with other options, found before the build starts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BundlerOptionsError {
+  /// Name of the invalid option, as it's written in the config, such as `hmr`.
+  pub option: &'static str,
+  pub message: String,
+}
+
+impl BundlerOptionsError {
+  pub fn new(option: &'static str, message: impl Into<String>) -> Self {
+    Self { option, message: message.into() }
+  }
+}
+
+impl Display for BundlerOptionsError {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    write!(f, "Invalid value for option \"{}\": {}", self.option, self.message)
+  }
+}
+
+impl std::error::Error for BundlerOptionsError {}
+
+impl BundlerOptions {
+  /// Check combinations of options that can't work together, which would otherwise be ignored silently or fail in
+  /// the middle of the build. All problems are returned, so they could be fixed at once.
+  pub fn validate(&self) -> Result<(), Vec<BundlerOptionsError>> {
+    let mut errors = vec![];
+
+    let mut names = FxHashSet::default();
+    for name in self.input.iter().flatten().filter_map(|item| item.name.as_deref()) {
+      if !names.insert(name) {
+        errors.push(BundlerOptionsError::new(
+          "input",
+          format!("Multiple entries are named \"{name}\", but names of entries must be unique."),
+        ));
+      }
+    }
+
+    let mut invalid_defines = self
+      .define
+      .iter()
+      .flatten()
+      .filter(|(key, value)| DefineReplacement::parse(key, value).is_none())
+      .map(|(key, _)| key.as_str())
+      .collect::<Vec<_>>();
+    invalid_defines.sort_unstable();
+    for key in invalid_defines {
+      errors.push(BundlerOptionsError::new(
+        "define",
+        format!(
+          "\"{key}\" can't be replaced. Keys must be identifier paths, such as \"process.env.DEBUG\", and values must be literals, `undefined` or identifier paths."
+        ),
+      ));
+    }
+
+    // `app` doesn't scope hoist modules, which is where `import.meta.hot` and lazy imports are rewritten.
+    if matches!(self.format, Some(OutputFormat::App)) {
+      if self.hmr == Some(true) {
+        errors.push(BundlerOptionsError::new(
+          "hmr",
+          "HMR is only supported with the \"esm\" and \"cjs\" output formats.",
+        ));
+      }
+      if self.lazy_compilation == Some(true) {
+        errors.push(BundlerOptionsError::new(
+          "lazyCompilation",
+          "Lazy compilation is only supported with the \"esm\" and \"cjs\" output formats.",
+        ));
+      }
+    }
+
+    let glob_options = [
+      ("dynamicRequireTargets", self.dynamic_require_targets.as_ref()),
+      ("evalWarning.exclude", self.eval_warning.as_ref().and_then(|o| o.exclude.as_ref())),
+      (
+        "circularDependency.exclude",
+        self.circular_dependency.as_ref().and_then(|o| o.exclude.as_ref()),
+      ),
+    ];
+    for (option, patterns) in glob_options {
+      for pattern in patterns.into_iter().flatten() {
+        if let Err(err) = glob::Pattern::new(pattern) {
+          errors.push(BundlerOptionsError::new(
+            option,
+            format!("\"{pattern}\" is not a valid glob pattern. {err}."),
+          ));
+        }
+      }
+    }
+
+    // JSX isn't transformed yet, so modules loaded this way would fail to parse in the middle of the build.
+    let is_jsx = |loader: &Loader| matches!(loader, Loader::Jsx | Loader::Tsx);
+    for (option, module_types) in [("moduleTypes", &self.module_types), ("loaders", &self.loaders)]
+    {
+      let mut jsx_extensions = module_types
+        .iter()
+        .flatten()
+        .filter(|(_, loader)| is_jsx(loader))
+        .map(|(ext, _)| ext.as_str())
+        .collect::<Vec<_>>();
+      jsx_extensions.sort_unstable();
+      for ext in jsx_extensions {
+        errors.push(BundlerOptionsError::new(
+          option,
+          format!("\"{ext}\" can't be loaded as JSX, which is not supported yet. Transform it with a plugin instead."),
+        ));
+      }
+    }
+    if self.stdin.as_ref().and_then(|stdin| stdin.loader.as_ref()).is_some_and(is_jsx) {
+      errors.push(BundlerOptionsError::new(
+        "stdin",
+        "The code can't be loaded as JSX, which is not supported yet. Transform it with a plugin instead.",
+      ));
+    }
+
+    if self.sourcemap.is_none() {
+      if self.sourcemap_debug_ids == Some(true) {
+        errors.push(BundlerOptionsError::new(
+          "sourcemapDebugIds",
+          "Debug ids are written to sourcemaps, so \"sourcemap\" must be enabled as well.",
+        ));
+      }
+      if self.sourcemap_filenames.is_some() {
+        errors.push(BundlerOptionsError::new(
+          "sourcemapFileNames",
+          "No sourcemap is emitted unless \"sourcemap\" is enabled.",
+        ));
+      }
+    }
+
+    if errors.is_empty() {
+      Ok(())
+    } else {
+      Err(errors)
+    }
+  }
+
+  /// Deprecated options that are set, paired with the options replacing them. They still work, but are reported with
+  /// a warning in every build.
+  pub fn deprecated_options(&self) -> Vec<(&'static str, &'static str)> {
+    let mut deprecated = vec![];
+    if self.loaders.is_some() {
+      deprecated.push(("loaders", "moduleTypes"));
+    }
+    deprecated
+  }
+}
+
+#[test]
+fn test_validate() {
+  use std::collections::HashMap;
+
+  use super::{
+    builder::BundlerOptionsBuilder,
+    types::{
+      circular_dependency::CircularDependencyOptions, eval_warning::EvalWarningOptions,
+      input_item::InputItem,
+    },
+  };
+
+  let invalid_options = |options: BundlerOptionsBuilder| {
+    options.build().unwrap_err().into_iter().map(|error| error.option).collect::<Vec<_>>()
+  };
+
+  assert!(BundlerOptions::builder().input(["./main.js"]).build().is_ok());
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().input([
+      InputItem { name: Some("main".to_string()), import: "./a.js".to_string() },
+      InputItem { name: Some("main".to_string()), import: "./b.js".to_string() },
+    ])),
+    ["input"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().define(HashMap::from([
+      ("process.env.DEBUG".to_string(), "true".to_string()),
+      ("1 + 1".to_string(), "2".to_string()),
+    ]))),
+    ["define"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().format(OutputFormat::App).hmr(true)),
+    ["hmr"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().format(OutputFormat::App).lazy_compilation(true)),
+    ["lazyCompilation"]
+  );
+  assert_eq!(
+    invalid_options(
+      BundlerOptions::builder()
+        .dynamic_require_targets(vec!["./locale/*.js".to_string(), "./locale/[.js".to_string()])
+    ),
+    ["dynamicRequireTargets"]
+  );
+  assert_eq!(
+    invalid_options(
+      BundlerOptions::builder()
+        .eval_warning(EvalWarningOptions { exclude: Some(vec!["**/vendor/[*.js".to_string()]) })
+        .circular_dependency(CircularDependencyOptions {
+          exclude: Some(vec!["**/node_modules/**".to_string(), "src/[a.js".to_string()]),
+          error: None,
+        })
+    ),
+    ["evalWarning.exclude", "circularDependency.exclude"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().module_types(HashMap::from([
+      (".txt".to_string(), Loader::Text),
+      (".jsx".to_string(), Loader::Jsx),
+      (".tsx".to_string(), Loader::Tsx),
+    ]))),
+    ["moduleTypes", "moduleTypes"]
+  );
+  assert_eq!(
+    invalid_options(
+      BundlerOptions::builder().loaders(HashMap::from([(".jsx".to_string(), Loader::Jsx)]))
+    ),
+    ["loaders"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().stdin(super::types::stdin::StdinOptions {
+      code: "export default <div />".to_string(),
+      resolve_dir: None,
+      loader: Some(Loader::Jsx),
+    })),
+    ["stdin"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().sourcemap_debug_ids(true)),
+    ["sourcemapDebugIds"]
+  );
+  assert_eq!(
+    invalid_options(BundlerOptions::builder().sourcemap_filenames("[name].map")),
+    ["sourcemapFileNames"]
+  );
+  // All invalid options are reported at once.
+  assert_eq!(
+    invalid_options(
+      BundlerOptions::builder().format(OutputFormat::App).hmr(true).sourcemap_debug_ids(true)
+    ),
+    ["hmr", "sourcemapDebugIds"]
+  );
+}
+
+#[test]
+fn test_deprecated_options() {
+  use std::collections::HashMap;
+
+  assert!(BundlerOptions::default().deprecated_options().is_empty());
+  let options = BundlerOptions {
+    loaders: Some(HashMap::from([(".txt".to_string(), Loader::Text)])),
+    ..Default::default()
+  };
+  assert_eq!(options.deprecated_options(), [("loaders", "moduleTypes")]);
+}
@@ -8,20 +8,39 @@ mod types;
 /// the same code in `rolldown` crate again.
 pub mod bundler_options {
   pub use crate::inner_bundler_options::{
+    builder::BundlerOptionsBuilder,
     types::{
+      assets_inline_limit::{AssetsInlineLimit, AssetsInlineLimitFn},
+      charset::Charset,
+      checks::{CheckLevel, ChecksOptions},
+      circular_dependency::CircularDependencyOptions,
+      css_modules::{CssModulesOptions, LocalsConvention},
+      define::{DefineReplacement, DefineValue},
+      drop_kind::DropKind,
+      eval_warning::EvalWarningOptions,
       filename_template::{FileNameRenderOptions, FilenameTemplate},
+      hash_algorithm::HashAlgorithm,
+      hash_characters::HashCharacters,
       input_item::InputItem,
       is_external::IsExternal,
+      legal_comments::LegalComments,
       loader::Loader,
-      normalized_bundler_options::NormalizedBundlerOptions,
+      log_level::LogLevel,
+      normalized_bundler_options::{BundlerFlags, NormalizedBundlerOptions},
+      on_log::OnLog,
       output_format::OutputFormat,
       output_option::{AddonFunction, AddonOutputOption},
       platform::Platform,
+      require_returns_default::RequireReturnsDefault,
       resolve_options::ResolveOptions,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
+      stdin::{NormalizedStdin, StdinOptions, STDIN_FILE_NAME},
+      unresolved_require::UnresolvedRequire,
+      watch_options::{WatchOptions, WatchPollOptions},
     },
+    validate::BundlerOptionsError,
     BundlerOptions,
   };
 }
@@ -35,25 +54,33 @@ pub use crate::{
     },
     Chunk,
   },
+  module::asset_module::AssetModule,
   module::external_module::ExternalModule,
   module::normal_module::NormalModule,
+  types::asset_module_id::AssetModuleId,
   types::ast_scopes::AstScopes,
   types::bundler_file_system::BundlerFileSystem,
   types::chunk_id::ChunkId,
   types::chunk_kind::ChunkKind,
+  types::const_enum::{ConstEnumMembers, ConstEnumValue},
   types::entry_point::{EntryPoint, EntryPointKind},
   types::exports_kind::ExportsKind,
   types::external_module_id::ExternalModuleId,
-  types::import_record::{ImportKind, ImportRecord, ImportRecordId, RawImportRecord},
+  types::import_record::{
+    ImportAttributes, ImportKind, ImportRecord, ImportRecordId, ImportRecordMeta, RawImportRecord,
+  },
   types::importer_record::ImporterRecord,
+  types::log::{Log, LogLocation},
   types::module_id::ModuleId,
   types::module_info::ModuleInfo,
-  types::module_table::{ExternalModuleVec, ModuleTable, NormalModuleVec},
+  types::module_path::ModulePath,
+  types::module_table::{AssetModuleVec, ExternalModuleVec, ModuleTable, NormalModuleVec},
   types::module_type::ModuleType,
   types::named_export::LocalExport,
   types::named_import::{NamedImport, Specifier},
+  types::node_globals::NodeGlobals,
   types::normal_module_id::NormalModuleId,
-  types::output::{Output, OutputAsset},
+  types::output::{AssetSource, Output, OutputAsset},
   types::output_chunk::OutputChunk,
   types::package_json::PackageJson,
   types::rendered_chunk::RenderedChunk,
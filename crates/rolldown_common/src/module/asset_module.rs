@@ -0,0 +1,13 @@
+use crate::{AssetModuleId, NormalModuleId, ResourceId};
+
+/// A file, such as an image or a font, that is imported by JavaScript but emitted to the output directory as it is.
+#[derive(Debug)]
+pub struct AssetModule {
+  pub id: AssetModuleId,
+  /// The normal module that exports the public URL of this asset. The asset is only emitted if the module is included.
+  pub module_id: NormalModuleId,
+  pub resource_id: ResourceId,
+  /// Filename relative to the output directory, which is rendered from `assetFileNames`.
+  pub filename: String,
+  pub source: Vec<u8>,
+}
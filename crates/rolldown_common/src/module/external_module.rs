@@ -1,4 +1,4 @@
-use crate::{ExternalModuleId, ImportRecord, ImportRecordId};
+use crate::{ExternalModuleId, ImportAttributes, ImportRecord, ImportRecordId};
 use oxc_index::IndexVec;
 
 #[derive(Debug)]
@@ -7,10 +7,18 @@ pub struct ExternalModule {
   pub exec_order: u32,
   pub name: String,
   pub import_records: IndexVec<ImportRecordId, ImportRecord>,
+  /// Import attributes that need to be preserved while importing this external module in ESM output.
+  pub attributes: ImportAttributes,
 }
 
 impl ExternalModule {
   pub fn new(id: ExternalModuleId, resource_id: String) -> Self {
-    Self { id, exec_order: u32::MAX, name: resource_id, import_records: IndexVec::default() }
+    Self {
+      id,
+      exec_order: u32::MAX,
+      name: resource_id,
+      import_records: IndexVec::default(),
+      attributes: vec![],
+    }
   }
 }
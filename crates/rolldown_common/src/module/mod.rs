@@ -1,2 +1,3 @@
+pub mod asset_module;
 pub mod external_module;
 pub mod normal_module;
@@ -2,11 +2,11 @@ use std::{fmt::Debug, sync::Arc};
 
 use crate::side_effects::DeterminedSideEffects;
 use crate::{
-  types::ast_scopes::AstScopes, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecord,
-  ImportRecordId, LocalExport, ModuleId, ModuleInfo, ModuleType, NamedImport, NormalModuleId,
-  ResourceId, StmtInfo, StmtInfos, SymbolRef,
+  types::ast_scopes::AstScopes, ConstEnumMembers, DebugStmtInfoForTreeShaking, ExportsKind,
+  ImportRecord, ImportRecordId, LocalExport, ModuleId, ModuleInfo, ModuleType, NamedImport,
+  NodeGlobals, NormalModuleId, PackageJson, ResourceId, StmtInfo, StmtInfos, SymbolRef,
 };
-use oxc::span::Span;
+use oxc::{semantic::SymbolId, span::Span};
 use oxc_index::IndexVec;
 use rolldown_rstr::Rstr;
 use rustc_hash::{FxHashMap, FxHashSet};
@@ -41,6 +41,28 @@ pub struct NormalModule {
   pub scope: AstScopes,
   pub default_export_ref: SymbolRef,
   pub sourcemap_chain: Vec<rolldown_sourcemap::SourceMap>,
+  /// Legal comments, such as `/*! ... */`, collected from the source. See `BundlerOptions::legal_comments`.
+  pub legal_comments: Vec<String>,
+  /// Whether the module references the Node.js globals `__dirname` and `__filename`.
+  pub node_globals: NodeGlobals,
+  /// `require(...)` calls with runtime-computed paths and import records of modules matched by `dynamicRequireTargets`.
+  pub dynamic_requires: FxHashMap<Span, Vec<ImportRecordId>>,
+  /// Members of `const enum`s declared at the top level of TypeScript modules. Accesses of them from other modules
+  /// are replaced with their values, unless `inlineConstEnums` is `false`.
+  pub const_enums: FxHashMap<SymbolId, ConstEnumMembers>,
+  /// `this` expressions at the top level of ES modules, which are rewritten to `undefined`.
+  pub top_level_this_spans: FxHashSet<Span>,
+  /// The first `await` outside of functions. Checked by `checks.topLevelAwait` for formats other than `esm`.
+  pub top_level_await_span: Option<Span>,
+  /// The first `import.meta` that's left in the output. Checked by `checks.importMeta` for the `cjs` format.
+  pub import_meta_span: Option<Span>,
+  /// Filenames of assets referenced by `new URL('./foo.png', import.meta.url)`, keyed by the span of the `NewExpression`.
+  /// For asset modules, it also contains the filename exported by the module, keyed by the span of the string literal.
+  pub asset_urls: FxHashMap<Span, String>,
+  /// The stylesheet of the module, if it's loaded by `Loader::Css`. It's emitted as part of the `.css` file of the chunk.
+  pub css: Option<String>,
+  /// The document of the module, if it's loaded by `Loader::Html`. It's emitted as the `.html` file of the entry chunk.
+  pub html: Option<String>,
   pub is_included: bool,
   // the ids of all modules that statically import this module
   pub importers: Vec<ResourceId>,
@@ -51,6 +73,8 @@ pub struct NormalModule {
   // the module ids imported by this module via dynamic import()
   pub dynamically_imported_ids: Vec<ResourceId>,
   pub side_effects: DeterminedSideEffects,
+  /// The closest `package.json` of the module, found while resolving it.
+  pub package_json: Option<Arc<PackageJson>>,
 }
 
 impl NormalModule {
@@ -90,6 +114,11 @@ impl NormalModule {
       },
       imported_ids: self.imported_ids.clone(),
       dynamically_imported_ids: self.dynamically_imported_ids.clone(),
+      exports: {
+        let mut value = self.named_exports.keys().cloned().collect::<Vec<_>>();
+        value.sort_unstable();
+        value
+      },
     }
   }
 
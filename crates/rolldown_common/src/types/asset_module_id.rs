@@ -0,0 +1,3 @@
+oxc_index::define_index_type! {
+    pub struct AssetModuleId = u32;
+}
@@ -31,6 +31,14 @@ impl AstScopes {
   ) -> impl Iterator<Item = &Reference> + '_ {
     self.resolved_references[symbol_id].iter().map(|reference_id| &self.references[*reference_id])
   }
+
+  /// Whether the symbol is assigned after its declaration, such as `count++` for `let count = 0`.
+  pub fn is_reassigned(&self, symbol_id: SymbolId) -> bool {
+    // Symbols created by the bundler don't exist in the AST, so they have no references.
+    self.resolved_references.get(symbol_id).map_or(false, |reference_ids| {
+      reference_ids.iter().any(|reference_id| self.references[*reference_id].is_write())
+    })
+  }
 }
 
 impl std::ops::Deref for AstScopes {
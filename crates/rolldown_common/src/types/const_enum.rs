@@ -0,0 +1,28 @@
+use rolldown_rstr::Rstr;
+use rustc_hash::FxHashMap;
+
+/// The value of a member of a TypeScript `const enum`, which is known while compiling the module.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConstEnumValue {
+  Number(f64),
+  String(Rstr),
+}
+
+impl ConstEnumValue {
+  /// The value written as JavaScript, such as `1`, `-0.5` or `"foo"`.
+  pub fn to_js(&self) -> String {
+    match self {
+      Self::Number(value) if value.is_nan() => "NaN".to_string(),
+      Self::Number(value) if value.is_infinite() => {
+        if value.is_sign_negative() { "-Infinity" } else { "Infinity" }.to_string()
+      }
+      Self::Number(value) => value.to_string(),
+      Self::String(value) => {
+        serde_json::to_string(value.as_str()).expect("Strings should be serializable")
+      }
+    }
+  }
+}
+
+/// Members of a `const enum` whose values are known, by their names.
+pub type ConstEnumMembers = FxHashMap<Rstr, ConstEnumValue>;
@@ -44,15 +44,31 @@ impl Display for ImportKind {
   }
 }
 
+/// Attributes of `import ... from '...' with { type: 'json' }`, kept in source order.
+pub type ImportAttributes = Vec<(Rstr, Rstr)>;
+
+bitflags::bitflags! {
+  #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+  pub struct ImportRecordMeta: u8 {
+    const CONTAINS_IMPORT_STAR = 1;
+    const CONTAINS_IMPORT_DEFAULT = 1 << 1;
+    const IS_PLAIN_IMPORT = 1 << 2;
+    /// Whether the `require(...)` is called unconditionally at the top level of the module, such as
+    /// `const foo = require('./foo')`, rather than inside a function or a conditional branch.
+    const IS_TOP_LEVEL = 1 << 3;
+    /// Whether the module request couldn't be resolved and is treated as an external module.
+    const IS_UNRESOLVED = 1 << 4;
+  }
+}
+
 #[derive(Debug)]
 pub struct RawImportRecord {
   // Module Request
   pub module_request: Rstr,
   pub kind: ImportKind,
   pub namespace_ref: SymbolRef,
-  pub contains_import_star: bool,
-  pub contains_import_default: bool,
-  pub is_plain_import: bool,
+  pub meta: ImportRecordMeta,
+  pub attributes: ImportAttributes,
 }
 
 impl RawImportRecord {
@@ -61,9 +77,8 @@ impl RawImportRecord {
       module_request: specifier,
       kind,
       namespace_ref,
-      contains_import_default: false,
-      contains_import_star: false,
-      is_plain_import: false,
+      meta: ImportRecordMeta::empty(),
+      attributes: vec![],
     }
   }
 
@@ -73,9 +88,8 @@ impl RawImportRecord {
       resolved_module,
       kind: self.kind,
       namespace_ref: self.namespace_ref,
-      contains_import_star: self.contains_import_star,
-      contains_import_default: self.contains_import_default,
-      is_plain_import: self.is_plain_import,
+      meta: self.meta,
+      attributes: self.attributes,
     }
   }
 }
@@ -87,7 +101,6 @@ pub struct ImportRecord {
   pub resolved_module: ModuleId,
   pub kind: ImportKind,
   pub namespace_ref: SymbolRef,
-  pub contains_import_star: bool,
-  pub contains_import_default: bool,
-  pub is_plain_import: bool,
+  pub meta: ImportRecordMeta,
+  pub attributes: ImportAttributes,
 }
@@ -0,0 +1,28 @@
+/// A warning or a report of the build, or a log emitted by a plugin, as passed to `onLog`.
+#[derive(Debug, Clone)]
+pub struct Log {
+  /// Such as `CIRCULAR_DEPENDENCY`, `PROFILE_SUMMARY` and `CHUNK_SIZES` for reports of `profile` and
+  /// `reportCompressedSize`, or `PLUGIN_WARNING` and `PLUGIN_LOG` for logs of plugins.
+  pub code: String,
+  pub message: String,
+  /// The message with excerpts of the code it's about, as printed to the terminal. Colored if stderr supports colors.
+  pub frame: Option<String>,
+  /// Ids of modules the log is about, such as the modules of an import cycle.
+  pub ids: Option<Vec<String>>,
+  /// Name of the plugin that emitted the log.
+  pub plugin: Option<String>,
+  /// The position in the code the log is about.
+  pub loc: Option<LogLocation>,
+  /// How to fix the problem, if it could be guessed.
+  pub help: Option<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct LogLocation {
+  /// Path of the file relative to `cwd`
+  pub file: String,
+  /// Starts from 1
+  pub line: u32,
+  /// Starts from 0
+  pub column: u32,
+}
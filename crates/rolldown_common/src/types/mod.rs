@@ -1,18 +1,23 @@
+pub mod asset_module_id;
 pub mod ast_scopes;
 pub mod bundler_file_system;
 pub mod chunk_id;
 pub mod chunk_kind;
+pub mod const_enum;
 pub mod entry_point;
 pub mod exports_kind;
 pub mod external_module_id;
 pub mod import_record;
 pub mod importer_record;
+pub mod log;
 pub mod module_id;
 pub mod module_info;
+pub mod module_path;
 pub mod module_table;
 pub mod module_type;
 pub mod named_export;
 pub mod named_import;
+pub mod node_globals;
 pub mod normal_module_id;
 pub mod output;
 pub mod output_chunk;
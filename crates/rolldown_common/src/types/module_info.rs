@@ -1,5 +1,7 @@
 use std::sync::Arc;
 
+use rolldown_rstr::Rstr;
+
 use crate::ResourceId;
 
 #[derive(Debug)]
@@ -11,4 +13,6 @@ pub struct ModuleInfo {
   pub dynamic_importers: Vec<ResourceId>,
   pub imported_ids: Vec<ResourceId>,
   pub dynamically_imported_ids: Vec<ResourceId>,
+  /// Sorted names exported by the module. Names re-exported by `export *` are only included once modules are linked.
+  pub exports: Vec<Rstr>,
 }
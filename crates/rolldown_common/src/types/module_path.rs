@@ -0,0 +1,122 @@
+use std::{
+  fmt::{Debug, Display},
+  hash::{Hash, Hasher},
+  ops::Deref,
+  sync::{Arc, Mutex, OnceLock},
+};
+
+use rustc_hash::FxHashSet;
+
+/// An interned path of a module, or another id of it returned by plugins. Equal paths share one allocation, so cloning
+/// is cheap, and comparing only looks at the pointer. Hashing still looks at the path, since hashes of filenames end
+/// up in output filenames and have to be the same across builds.
+#[derive(Clone)]
+pub struct ModulePath(Arc<str>);
+
+fn interned_paths() -> &'static Mutex<FxHashSet<Arc<str>>> {
+  static INTERNED_PATHS: OnceLock<Mutex<FxHashSet<Arc<str>>>> = OnceLock::new();
+  INTERNED_PATHS.get_or_init(Mutex::default)
+}
+
+impl ModulePath {
+  pub fn new(path: &str) -> Self {
+    let mut paths = interned_paths().lock().expect("Interned paths should not be poisoned");
+    if let Some(interned) = paths.get(path) {
+      return Self(Arc::clone(interned));
+    }
+    let interned: Arc<str> = path.into();
+    paths.insert(Arc::clone(&interned));
+    Self(interned)
+  }
+
+  pub fn as_str(&self) -> &str {
+    &self.0
+  }
+
+  /// Free paths that are no longer used anywhere. Called before each build, so paths of deleted files don't pile up
+  /// in watch mode.
+  pub fn release_unused() {
+    let mut paths = interned_paths().lock().expect("Interned paths should not be poisoned");
+    paths.retain(|path| Arc::strong_count(path) > 1);
+  }
+}
+
+impl PartialEq for ModulePath {
+  fn eq(&self, other: &Self) -> bool {
+    Arc::ptr_eq(&self.0, &other.0)
+  }
+}
+
+impl Eq for ModulePath {}
+
+impl Hash for ModulePath {
+  fn hash<H: Hasher>(&self, state: &mut H) {
+    self.as_str().hash(state);
+  }
+}
+
+impl PartialOrd for ModulePath {
+  fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+    Some(self.cmp(other))
+  }
+}
+
+impl Ord for ModulePath {
+  fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+    self.as_str().cmp(other.as_str())
+  }
+}
+
+impl Deref for ModulePath {
+  type Target = str;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl AsRef<str> for ModulePath {
+  fn as_ref(&self) -> &str {
+    &self.0
+  }
+}
+
+impl Debug for ModulePath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Debug::fmt(self.as_str(), f)
+  }
+}
+
+impl Display for ModulePath {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    Display::fmt(self.as_str(), f)
+  }
+}
+
+impl From<&str> for ModulePath {
+  fn from(value: &str) -> Self {
+    Self::new(value)
+  }
+}
+
+impl From<String> for ModulePath {
+  fn from(value: String) -> Self {
+    Self::new(&value)
+  }
+}
+
+impl From<&String> for ModulePath {
+  fn from(value: &String) -> Self {
+    Self::new(value)
+  }
+}
+
+#[test]
+fn test_module_path() {
+  let a = ModulePath::new("/src/test_module_path.js");
+  let b = ModulePath::from("/src/test_module_path.js".to_string());
+  assert_eq!(a, b);
+  assert!(Arc::ptr_eq(&a.0, &b.0));
+  assert_ne!(a, ModulePath::new("/src/test_module_path.ts"));
+  assert_eq!(a.as_str(), "/src/test_module_path.js");
+}
@@ -1,11 +1,15 @@
-use crate::{ExternalModule, ExternalModuleId, NormalModule, NormalModuleId};
+use crate::{
+  AssetModule, AssetModuleId, ExternalModule, ExternalModuleId, NormalModule, NormalModuleId,
+};
 use oxc_index::IndexVec;
 
 pub type NormalModuleVec = IndexVec<NormalModuleId, NormalModule>;
 pub type ExternalModuleVec = IndexVec<ExternalModuleId, ExternalModule>;
+pub type AssetModuleVec = IndexVec<AssetModuleId, AssetModule>;
 
 #[derive(Debug)]
 pub struct ModuleTable {
   pub normal_modules: NormalModuleVec,
   pub external_modules: ExternalModuleVec,
+  pub asset_modules: AssetModuleVec,
 }
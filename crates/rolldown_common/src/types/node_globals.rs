@@ -0,0 +1,8 @@
+bitflags::bitflags! {
+  /// Node.js globals referenced by a module, which need to be shimmed in ESM output.
+  #[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+  pub struct NodeGlobals: u8 {
+    const DIRNAME = 1;
+    const FILENAME = 1 << 1;
+  }
+}
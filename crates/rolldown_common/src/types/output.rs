@@ -1,9 +1,37 @@
 use crate::OutputChunk;
 
+/// Content of an asset, which could be either text or binary data.
+#[derive(Debug, Clone)]
+pub enum AssetSource {
+  String(String),
+  Buffer(Vec<u8>),
+}
+
+impl AssetSource {
+  pub fn as_bytes(&self) -> &[u8] {
+    match self {
+      Self::String(value) => value.as_bytes(),
+      Self::Buffer(value) => value,
+    }
+  }
+}
+
+impl From<String> for AssetSource {
+  fn from(value: String) -> Self {
+    Self::String(value)
+  }
+}
+
+impl From<Vec<u8>> for AssetSource {
+  fn from(value: Vec<u8>) -> Self {
+    Self::Buffer(value)
+  }
+}
+
 #[derive(Debug)]
 pub struct OutputAsset {
   pub filename: String,
-  pub source: String,
+  pub source: AssetSource,
 }
 
 #[derive(Debug)]
@@ -20,10 +48,10 @@ impl Output {
     }
   }
 
-  pub fn content(&self) -> &str {
+  pub fn content_as_bytes(&self) -> &[u8] {
     match self {
-      Self::Chunk(chunk) => &chunk.code,
-      Self::Asset(asset) => &asset.source,
+      Self::Chunk(chunk) => chunk.code.as_bytes(),
+      Self::Asset(asset) => asset.source.as_bytes(),
     }
   }
 }
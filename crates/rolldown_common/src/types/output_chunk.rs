@@ -23,5 +23,7 @@ pub struct OutputChunk {
   pub code: String,
   pub map: Option<SourceMap>,
   pub sourcemap_filename: Option<String>,
+  /// Filename of the stylesheet extracted from modules in this chunk.
+  pub css_filename: Option<String>,
   pub preliminary_filename: String,
 }
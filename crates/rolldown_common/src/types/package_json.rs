@@ -17,6 +17,14 @@ impl PackageJson {
     Self { side_effects: SideEffects::from_description(&raw), raw, path }
   }
 
+  pub fn name(&self) -> Option<&str> {
+    self.raw.get("name").and_then(|v| v.as_str())
+  }
+
+  pub fn version(&self) -> Option<&str> {
+    self.raw.get("version").and_then(|v| v.as_str())
+  }
+
   pub fn r#type(&self) -> Option<&str> {
     self.raw.get("type").and_then(|v| v.as_str())
   }
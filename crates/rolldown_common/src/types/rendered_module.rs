@@ -1,4 +1,6 @@
 #[derive(Debug, Clone)]
 pub struct RenderedModule {
   pub code: Option<String>,
+  /// Length of the code of the module in the chunk, which is known even if `code` isn't kept.
+  pub rendered_length: usize,
 }
@@ -1,10 +1,10 @@
-use std::{path::Path, sync::Arc};
+use std::path::Path;
 
-use super::resource_id::stabilize_resource_id;
+use super::{module_path::ModulePath, resource_id::stabilize_resource_id};
 
 #[derive(Debug, Clone)]
 pub struct ResolvedPath {
-  pub path: Arc<str>,
+  pub path: ModulePath,
   pub ignored: bool,
 }
 
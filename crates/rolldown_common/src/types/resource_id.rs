@@ -1,16 +1,15 @@
-use std::{
-  path::{Path, PathBuf},
-  sync::Arc,
-};
+use std::path::{Path, PathBuf};
 
 use rolldown_utils::path_ext::PathExt;
 use sugar_path::SugarPath;
 
+use super::module_path::ModulePath;
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone)]
-pub struct ResourceId(Arc<str>);
+pub struct ResourceId(ModulePath);
 
 impl ResourceId {
-  pub fn new(value: impl Into<Arc<str>>) -> Self {
+  pub fn new(value: impl Into<ModulePath>) -> Self {
     Self(value.into())
   }
 
@@ -43,8 +42,8 @@ impl From<String> for ResourceId {
   }
 }
 
-impl From<Arc<str>> for ResourceId {
-  fn from(value: Arc<str>) -> Self {
+impl From<ModulePath> for ResourceId {
+  fn from(value: ModulePath) -> Self {
     Self(value)
   }
 }
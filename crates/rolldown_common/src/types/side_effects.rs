@@ -56,7 +56,7 @@ pub(crate) fn glob_match_with_normalized_pattern(pattern: &str, path: &str) -> b
   glob_match::glob_match(&normalized_glob, path.trim_start_matches("./"))
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum HookSideEffects {
   True,
   False,
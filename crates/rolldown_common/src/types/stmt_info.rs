@@ -1,4 +1,5 @@
 use oxc_index::IndexVec;
+use rolldown_rstr::Rstr;
 use rustc_hash::FxHashMap;
 
 use crate::{ImportRecordId, SymbolRef};
@@ -77,6 +78,9 @@ pub struct StmtInfo {
   // here instead of `SymbolId`.
   /// Top level symbols referenced by this statement.
   pub referenced_symbols: Vec<SymbolRef>,
+  /// Imported symbols that this statement only accesses members of, such as `Foo.Bar`, with the names of the members.
+  /// If they turn out to be `const enum`s, the accesses are inlined and the symbols aren't referenced anymore.
+  pub member_only_references: FxHashMap<SymbolRef, Vec<Rstr>>,
   pub side_effect: bool,
   pub is_included: bool,
   pub import_records: Vec<ImportRecordId>,
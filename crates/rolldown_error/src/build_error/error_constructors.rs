@@ -7,13 +7,37 @@ use oxc::span::Span;
 use rolldown_resolver::ResolveError;
 
 use super::BuildError;
+use crate::event_kind::EventKind;
 
 use crate::events::{
-  circular_dependency::CircularDependency, eval::Eval, external_entry::ExternalEntry,
-  forbid_const_assign::ForbidConstAssign, missing_export::MissingExport,
-  sourcemap_error::SourceMapError, unresolved_entry::UnresolvedEntry,
+  chunk_too_large::ChunkTooLarge,
+  circular_chunk::CircularChunk,
+  circular_dependency::CircularDependency,
+  deprecated_option::DeprecatedOption,
+  duplicate_package::{DuplicatePackage, DuplicatePackages},
+  eval::Eval,
+  external_entry::ExternalEntry,
+  file_name_conflict::FileNameConflict,
+  forbid_const_assign::ForbidConstAssign,
+  generated_empty_chunk::GeneratedEmptyChunk,
+  invalid_output::InvalidOutput,
+  invalid_tla_format::InvalidTlaFormat,
+  missing_export::MissingExport,
+  module_format_mismatch::ModuleFormatMismatch,
+  namespace_conflict::NamespaceConflict,
+  plugin_log::PluginLog,
+  shadowed_export::ShadowedExport,
+  similar_warnings::SimilarWarnings,
+  sourcemap_error::SourceMapError,
+  this_is_undefined::ThisIsUndefined,
+  unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
-  unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal, NapiError,
+  unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
+  unsupported_dynamic_require::UnsupportedDynamicRequire,
+  unsupported_import_meta::UnsupportedImportMeta,
+  unsupported_typescript::UnsupportedTypeScript,
+  unused_external::UnusedExternal,
+  NapiError,
 };
 
 impl BuildError {
@@ -33,7 +57,22 @@ impl BuildError {
   }
 
   pub fn unresolved_import(specifier: impl Into<String>, importer: impl Into<PathBuf>) -> Self {
-    Self::new_inner(UnresolvedImport { specifier: specifier.into(), importer: importer.into() })
+    Self::new_inner(UnresolvedImport {
+      specifier: specifier.into(),
+      importer: importer.into(),
+      is_node_builtin: false,
+    })
+  }
+
+  pub fn unresolved_node_builtin(
+    specifier: impl Into<String>,
+    importer: impl Into<PathBuf>,
+  ) -> Self {
+    Self::new_inner(UnresolvedImport {
+      specifier: specifier.into(),
+      importer: importer.into(),
+      is_node_builtin: true,
+    })
   }
 
   pub fn sourcemap_error(error: oxc::sourcemap::Error) -> Self {
@@ -62,6 +101,7 @@ impl BuildError {
     importer_source: Arc<str>,
     imported_specifier: String,
     imported_specifier_span: Span,
+    available_exports: Vec<String>,
   ) -> Self {
     Self::new_inner(MissingExport {
       stable_importer,
@@ -69,9 +109,39 @@ impl BuildError {
       importer_source,
       imported_specifier,
       imported_specifier_span,
+      available_exports,
     })
   }
 
+  pub fn plugin_log(plugin: String, message: String, is_warning: bool) -> Self {
+    Self::new_inner(PluginLog { plugin, message, is_warning })
+  }
+
+  pub fn namespace_conflict(
+    reexporting_module: String,
+    name: String,
+    sources: Vec<String>,
+  ) -> Self {
+    Self::new_inner(NamespaceConflict { reexporting_module, name, sources }).with_severity_warning()
+  }
+
+  pub fn invalid_tla_format(
+    filename: String,
+    source: Arc<str>,
+    span: Span,
+    format: &'static str,
+  ) -> Self {
+    Self::new_inner(InvalidTlaFormat { filename, source, span, format })
+  }
+
+  pub fn circular_chunk(chunks: Vec<String>) -> Self {
+    Self::new_inner(CircularChunk { chunks })
+  }
+
+  pub fn similar_warnings(kind: EventKind, count: usize) -> Self {
+    Self::new_inner(SimilarWarnings { kind, count }).with_severity_warning()
+  }
+
   // --- Rolldown related
 
   pub fn forbid_const_assign(
@@ -84,6 +154,61 @@ impl BuildError {
     Self::new_inner(ForbidConstAssign { filename, source, name, reference_span, re_assign_span })
   }
 
+  pub fn invalid_output(
+    chunk: String,
+    code: Arc<str>,
+    span: Span,
+    reason: String,
+    module: Option<String>,
+  ) -> Self {
+    Self::new_inner(InvalidOutput { chunk, code, span, reason, module })
+  }
+
+  pub fn unused_external(id: String) -> Self {
+    Self::new_inner(UnusedExternal { id }).with_severity_warning()
+  }
+
+  pub fn shadowed_export(entry: String, name: String, shadowed_module: String) -> Self {
+    Self::new_inner(ShadowedExport { entry, name, shadowed_module }).with_severity_warning()
+  }
+
+  pub fn deprecated_option(option: &'static str, replacement: &'static str) -> Self {
+    Self::new_inner(DeprecatedOption { option, replacement }).with_severity_warning()
+  }
+
+  pub fn file_name_conflict(filename: String) -> Self {
+    Self::new_inner(FileNameConflict { filename }).with_severity_warning()
+  }
+
+  pub fn generated_empty_chunk(chunk: String) -> Self {
+    Self::new_inner(GeneratedEmptyChunk { chunk }).with_severity_warning()
+  }
+
+  pub fn chunk_too_large(chunk: String, size: usize, max_size: usize) -> Self {
+    Self::new_inner(ChunkTooLarge { chunk, size, max_size })
+  }
+
+  pub fn unsupported_import_meta(filename: String, source: Arc<str>, span: Span) -> Self {
+    Self::new_inner(UnsupportedImportMeta { filename, source, span })
+  }
+
+  pub fn unsupported_dynamic_require(filename: String, source: Arc<str>, span: Span) -> Self {
+    Self::new_inner(UnsupportedDynamicRequire { filename, source, span })
+  }
+
+  pub fn unsupported_typescript(
+    filename: String,
+    source: Arc<str>,
+    span: Span,
+    syntax: &'static str,
+  ) -> Self {
+    Self::new_inner(UnsupportedTypeScript { filename, source, span, syntax })
+  }
+
+  pub fn duplicate_packages(packages: Vec<DuplicatePackage>) -> Self {
+    Self::new_inner(DuplicatePackages { packages }).with_severity_warning()
+  }
+
   pub fn napi_error(status: String, reason: String) -> Self {
     Self::new_inner(NapiError { status, reason })
   }
@@ -91,4 +216,18 @@ impl BuildError {
   pub fn eval(filename: String, source: Arc<str>, span: Span) -> Self {
     Self::new_inner(Eval { filename, span, source })
   }
+
+  pub fn this_is_undefined(filename: String, source: Arc<str>, span: Span) -> Self {
+    Self::new_inner(ThisIsUndefined { filename, span, source })
+  }
+
+  pub fn module_format_mismatch(
+    filename: String,
+    source: Arc<str>,
+    span: Span,
+    is_esm: bool,
+    reason: &'static str,
+  ) -> Self {
+    Self::new_inner(ModuleFormatMismatch { filename, source, span, is_esm, reason })
+  }
 }
@@ -3,7 +3,9 @@ pub mod severity;
 use std::fmt::Display;
 
 use crate::{
-  diagnostic::Diagnostic, events::BuildEvent, types::diagnostic_options::DiagnosticOptions,
+  diagnostic::Diagnostic,
+  events::BuildEvent,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
 };
 
 use self::severity::Severity;
@@ -31,6 +33,26 @@ impl BuildError {
     self.inner.kind()
   }
 
+  pub fn severity(&self) -> &Severity {
+    &self.severity
+  }
+
+  pub fn message_with(&self, opts: &DiagnosticOptions) -> String {
+    self.inner.message(opts)
+  }
+
+  pub fn ids_with(&self, opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    self.inner.ids(opts)
+  }
+
+  pub fn location_with(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    self.inner.location(opts)
+  }
+
+  pub fn help(&self) -> Option<String> {
+    self.inner.help()
+  }
+
   #[must_use]
   pub fn with_source(
     mut self,
@@ -46,6 +68,12 @@ impl BuildError {
     self
   }
 
+  #[must_use]
+  pub fn with_severity_error(mut self) -> Self {
+    self.severity = Severity::Error;
+    self
+  }
+
   pub fn into_diagnostic(self) -> Diagnostic {
     self.into_diagnostic_with(&DiagnosticOptions::default())
   }
@@ -53,6 +81,15 @@ impl BuildError {
   pub fn into_diagnostic_with(self, opts: &DiagnosticOptions) -> Diagnostic {
     let mut diagnostic =
       Diagnostic::new(self.kind().to_string(), self.inner.message(opts), self.severity);
+    diagnostic.help = self.inner.help();
+    self.inner.on_diagnostic(&mut diagnostic, opts);
+    diagnostic
+  }
+
+  pub fn to_diagnostic_with(&self, opts: &DiagnosticOptions) -> Diagnostic {
+    let mut diagnostic =
+      Diagnostic::new(self.kind().to_string(), self.inner.message(opts), self.severity.clone());
+    diagnostic.help = self.inner.help();
     self.inner.on_diagnostic(&mut diagnostic, opts);
     diagnostic
   }
@@ -1,5 +1,5 @@
 use crate::build_error::severity::Severity;
-use ariadne::{sources, Config, Label, Report, ReportBuilder, ReportKind};
+use ariadne::{sources, Color, Config, Label, Report, ReportBuilder, ReportKind};
 use std::{fmt::Display, ops::Range, sync::Arc};
 
 #[derive(Debug, Clone)]
@@ -12,6 +12,8 @@ pub struct Diagnostic {
   pub(crate) files: Vec<(/* filename */ Arc<str>, /* file content */ Arc<str>)>,
   pub(crate) labels: Vec<Label<(/* filename */ Arc<str>, Range<usize>)>>,
   pub(crate) severity: Severity,
+  pub(crate) help: Option<String>,
+  pub(crate) note: Option<String>,
 }
 
 type AriadneReportBuilder = ReportBuilder<'static, (Arc<str>, Range<usize>)>;
@@ -19,7 +21,15 @@ type AriadneReport = Report<'static, (Arc<str>, Range<usize>)>;
 
 impl Diagnostic {
   pub(crate) fn new(kind: String, summary: String, severity: Severity) -> Self {
-    Self { kind, title: summary, files: Vec::default(), labels: Vec::default(), severity }
+    Self {
+      kind,
+      title: summary,
+      files: Vec::default(),
+      labels: Vec::default(),
+      severity,
+      help: None,
+      note: None,
+    }
   }
 
   pub(crate) fn add_file(
@@ -47,7 +57,7 @@ impl Diagnostic {
     self
   }
 
-  fn init_report_builder(&mut self) -> AriadneReportBuilder {
+  fn init_report_builder(&mut self, color: bool) -> AriadneReportBuilder {
     let mut builder = AriadneReport::build(
       match self.severity {
         Severity::Error => ReportKind::Error,
@@ -59,15 +69,28 @@ impl Diagnostic {
     .with_code(self.kind.clone())
     .with_message(self.title.clone());
 
+    // Ariadne writes the color of labels even if `Config::with_color` is off, so it's only set for colored output
+    let label_color = match self.severity {
+      Severity::Error => Color::Red,
+      Severity::Warning => Color::Yellow,
+    };
     for label in self.labels.clone() {
-      builder = builder.with_label(label);
+      builder = builder.with_label(if color { label.with_color(label_color) } else { label });
+    }
+
+    if let Some(help) = &self.help {
+      builder = builder.with_help(help);
+    }
+
+    if let Some(note) = &self.note {
+      builder = builder.with_note(note);
     }
 
     builder
   }
 
   pub fn convert_to_string(&self, color: bool) -> String {
-    let builder = self.clone().init_report_builder();
+    let builder = self.clone().init_report_builder(color);
     let mut output = Vec::new();
     builder
       .with_config(Config::default().with_color(color))
@@ -0,0 +1,76 @@
+use crate::{build_error::BuildError, types::diagnostic_options::DiagnosticOptions};
+use std::io::IsTerminal;
+
+/// Renders errors and warnings of builds for terminals: the message, followed by excerpts of the code it's about with
+/// line numbers and the span underlined. The bundler builds one for its options, so the CLI and watch mode print
+/// diagnostics the same way.
+#[derive(Debug, Clone)]
+pub struct DiagnosticRenderer {
+  opts: DiagnosticOptions,
+  color: bool,
+}
+
+impl DiagnosticRenderer {
+  /// Colors are used if stderr supports them. See `supports_color`.
+  pub fn new(opts: DiagnosticOptions) -> Self {
+    Self { opts, color: supports_color() }
+  }
+
+  #[must_use]
+  pub fn with_color(mut self, color: bool) -> Self {
+    self.color = color;
+    self
+  }
+
+  pub fn color(&self) -> bool {
+    self.color
+  }
+
+  pub fn options(&self) -> &DiagnosticOptions {
+    &self.opts
+  }
+
+  pub fn render(&self, error: &BuildError) -> String {
+    error.to_diagnostic_with(&self.opts).convert_to_string(self.color)
+  }
+
+  pub fn render_all(&self, errors: &[BuildError]) -> String {
+    errors.iter().map(|error| self.render(error)).collect::<Vec<_>>().join("\n")
+  }
+}
+
+/// Whether stderr supports ANSI colors. `NO_COLOR` and `FORCE_COLOR=0` disable colors, other values of `FORCE_COLOR`
+/// enable them. Otherwise, colors are used if stderr is a terminal that isn't `dumb`.
+pub fn supports_color() -> bool {
+  detect_color(
+    std::env::var("FORCE_COLOR").ok().as_deref(),
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty()),
+    std::env::var("TERM").ok().as_deref(),
+    std::io::stderr().is_terminal(),
+  )
+}
+
+fn detect_color(
+  force_color: Option<&str>,
+  no_color: bool,
+  term: Option<&str>,
+  is_terminal: bool,
+) -> bool {
+  match force_color {
+    Some("0" | "false") => false,
+    Some(_) => true,
+    None if no_color => false,
+    None => is_terminal && term != Some("dumb"),
+  }
+}
+
+#[test]
+fn test_detect_color() {
+  assert!(detect_color(None, false, Some("xterm-256color"), true));
+  assert!(!detect_color(None, false, Some("xterm-256color"), false));
+  assert!(!detect_color(None, false, Some("dumb"), true));
+  assert!(!detect_color(None, true, Some("xterm-256color"), true));
+  assert!(detect_color(Some("1"), true, None, false));
+  assert!(detect_color(Some(""), false, None, false));
+  assert!(!detect_color(Some("0"), false, Some("xterm-256color"), true));
+}
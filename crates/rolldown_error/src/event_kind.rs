@@ -1,5 +1,8 @@
 use std::fmt::Display;
 
+/// Stable code of a diagnostic, shown as `[CODE]` in messages and passed as `code` of logs. Tools could match on it
+/// to filter diagnostics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventKind {
   // --- These kinds are copied from rollup: https://github.com/rollup/rollup/blob/0b665c31833525c923c0fc20f43ebfca748c6670/src/utils/logs.ts#L102-L179
   UnresolvedEntry,
@@ -8,8 +11,26 @@ pub enum EventKind {
   CircularDependency,
   SourcemapError,
   MissingExport,
+  PluginWarning,
+  PluginLog,
+  ThisIsUndefined,
+  NamespaceConflict,
+  InvalidTlaFormat,
+  CircularChunk,
+  FileNameConflict,
+  DeprecatedFeature,
   // --- These kinds are rolldown specific
   IllegalReassignment,
+  InvalidOutput,
+  DuplicatePackage,
+  UnusedExternal,
+  ShadowedExport,
+  ChunkTooLarge,
+  UnsupportedImportMeta,
+  UnsupportedDynamicRequire,
+  UnsupportedTypeScript,
+  ModuleFormatMismatch,
+  GeneratedEmptyChunk,
   // !! Only add new kind if it's not covered by the kinds from rollup !!
 
   // TODO remove following kinds
@@ -17,20 +38,44 @@ pub enum EventKind {
   IoError,
 }
 
-impl Display for EventKind {
-  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl EventKind {
+  pub fn as_str(self) -> &'static str {
     match self {
       // --- Copied from rollup
-      EventKind::UnresolvedEntry => write!(f, "UNRESOLVED_ENTRY"),
-      EventKind::UnresolvedImport => write!(f, "UNRESOLVED_IMPORT"),
-      EventKind::IllegalReassignment => write!(f, "ILLEGAL_REASSIGNMENT"),
-      EventKind::Eval => write!(f, "EVAL"),
-      EventKind::SourcemapError => write!(f, "SOURCEMAP_ERROR"),
-      EventKind::CircularDependency => write!(f, "CIRCULAR_DEPENDENCY"),
-      EventKind::MissingExport => write!(f, "MISSING_EXPORT"),
+      Self::UnresolvedEntry => "UNRESOLVED_ENTRY",
+      Self::UnresolvedImport => "UNRESOLVED_IMPORT",
+      Self::IllegalReassignment => "ILLEGAL_REASSIGNMENT",
+      Self::Eval => "EVAL",
+      Self::SourcemapError => "SOURCEMAP_ERROR",
+      Self::CircularDependency => "CIRCULAR_DEPENDENCY",
+      Self::MissingExport => "MISSING_EXPORT",
+      Self::PluginWarning => "PLUGIN_WARNING",
+      Self::PluginLog => "PLUGIN_LOG",
+      Self::ThisIsUndefined => "THIS_IS_UNDEFINED",
+      Self::NamespaceConflict => "NAMESPACE_CONFLICT",
+      Self::InvalidTlaFormat => "INVALID_TLA_FORMAT",
+      Self::CircularChunk => "CIRCULAR_CHUNK",
+      Self::FileNameConflict => "FILE_NAME_CONFLICT",
+      Self::DeprecatedFeature => "DEPRECATED_FEATURE",
       // --- Rolldown specific
-      EventKind::NapiError => write!(f, "NAPI_ERROR"),
-      EventKind::IoError => write!(f, "IO_ERROR"),
+      Self::InvalidOutput => "INVALID_OUTPUT",
+      Self::DuplicatePackage => "DUPLICATE_PACKAGE",
+      Self::UnusedExternal => "UNUSED_EXTERNAL",
+      Self::ShadowedExport => "SHADOWED_EXPORT",
+      Self::ChunkTooLarge => "CHUNK_TOO_LARGE",
+      Self::UnsupportedImportMeta => "UNSUPPORTED_IMPORT_META",
+      Self::UnsupportedDynamicRequire => "UNSUPPORTED_DYNAMIC_REQUIRE",
+      Self::UnsupportedTypeScript => "UNSUPPORTED_TYPESCRIPT",
+      Self::ModuleFormatMismatch => "MODULE_FORMAT_MISMATCH",
+      Self::GeneratedEmptyChunk => "GENERATED_EMPTY_CHUNK",
+      Self::NapiError => "NAPI_ERROR",
+      Self::IoError => "IO_ERROR",
     }
   }
 }
+
+impl Display for EventKind {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    self.as_str().fmt(f)
+  }
+}
@@ -0,0 +1,31 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// A chunk bigger than `output.maxChunkSize`.
+#[derive(Debug)]
+pub struct ChunkTooLarge {
+  pub chunk: String,
+  pub size: usize,
+  pub max_size: usize,
+}
+
+impl BuildEvent for ChunkTooLarge {
+  fn kind(&self) -> EventKind {
+    EventKind::ChunkTooLarge
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "Chunk \"{}\" is {} bytes, exceeding the limit of {} bytes set by \"output.maxChunkSize\".",
+      self.chunk, self.size, self.max_size
+    )
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(
+      "Load parts of the chunk with dynamic `import()`, so they're split into separate chunks."
+        .to_string(),
+    )
+  }
+}
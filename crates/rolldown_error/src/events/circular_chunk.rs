@@ -0,0 +1,27 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// Chunks of the `cjs` format importing each other in a cycle, found by `checks.circularChunks`.
+#[derive(Debug)]
+pub struct CircularChunk {
+  /// Names of chunks in the cycle, starting and ending with the same chunk.
+  pub chunks: Vec<String>,
+}
+
+impl BuildEvent for CircularChunk {
+  fn kind(&self) -> EventKind {
+    EventKind::CircularChunk
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "Circular chunk: {}. `require` returns exports of chunks in the cycle before they're assigned.",
+      self.chunks.join(" -> ")
+    )
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Use the \"esm\" output format, or set `checks.circularChunks` to \"error\" to fail the build for cycles of chunks.".to_string())
+  }
+}
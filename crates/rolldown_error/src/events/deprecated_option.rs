@@ -0,0 +1,23 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DeprecatedOption {
+  pub option: &'static str,
+  pub replacement: &'static str,
+}
+
+impl BuildEvent for DeprecatedOption {
+  fn kind(&self) -> EventKind {
+    EventKind::DeprecatedFeature
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("The \"{}\" option is deprecated. Use \"{}\" instead.", self.option, self.replacement)
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(format!("Rename \"{}\" to \"{}\" in the config.", self.option, self.replacement))
+  }
+}
@@ -0,0 +1,59 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// A package bundled from more than one directory of `node_modules`.
+#[derive(Debug, Clone)]
+pub struct DuplicatePackage {
+  pub name: String,
+  pub copies: Vec<PackageCopy>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PackageCopy {
+  pub version: Option<String>,
+  /// Directory of the copy, relative to `cwd`.
+  pub path: String,
+  /// The shortest chain of imports from each entry to the copy, as stable ids of modules.
+  pub import_chains: Vec<Vec<String>>,
+}
+
+#[derive(Debug)]
+pub struct DuplicatePackages {
+  pub packages: Vec<DuplicatePackage>,
+}
+
+impl BuildEvent for DuplicatePackages {
+  fn kind(&self) -> EventKind {
+    EventKind::DuplicatePackage
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    let mut message = String::from("Multiple copies of packages are bundled:");
+    for package in &self.packages {
+      for copy in &package.copies {
+        let version = copy.version.as_deref().unwrap_or("unknown version");
+        message.push_str(&format!("\n- \"{}\" ({version}) at {}", package.name, copy.path));
+        for chain in &copy.import_chains {
+          message.push_str(&format!("\n    {}", chain.join(" -> ")));
+        }
+      }
+    }
+    message
+  }
+
+  fn ids(&self, _opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    Some(
+      self
+        .packages
+        .iter()
+        .flat_map(|package| &package.copies)
+        .flat_map(|copy| copy.import_chains.iter().filter_map(|chain| chain.last().cloned()))
+        .collect(),
+    )
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Deduplicate the package with the package manager, or point imports of it to a single copy with `resolve.alias`.".to_string())
+  }
+}
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use oxc::span::Span;
 
-use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+use crate::{
+  diagnostic::Diagnostic,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
 
 use super::BuildEvent;
 
@@ -22,6 +25,14 @@ impl BuildEvent for Eval {
     format!("Use of eval in '{}' is strongly discouraged as it poses security risks and may cause issues with minification.", self.filename)
   }
 
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Variables may be renamed while bundling, and `eval` would not see the new names. Use `evalWarning.exclude` to silence the warning for modules known to be safe.".to_string())
+  }
+
   fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
     let filename = opts.stabilize_path(&self.filename);
 
@@ -0,0 +1,30 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// Two outputs resolve to the same file, so one overwrites the other when written. Filenames are compared
+/// case-insensitively, as on Windows and macOS.
+#[derive(Debug)]
+pub struct FileNameConflict {
+  pub filename: String,
+}
+
+impl BuildEvent for FileNameConflict {
+  fn kind(&self) -> EventKind {
+    EventKind::FileNameConflict
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "The emitted file \"{}\" overwrites a previously emitted file of the same name, ignoring case.",
+      self.filename
+    )
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(
+      "Include `[name]` or `[hash]` in the filename options, so emitted files get distinct names."
+        .to_string(),
+    )
+  }
+}
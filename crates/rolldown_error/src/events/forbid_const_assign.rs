@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use oxc::span::Span;
 
-use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+use crate::{
+  diagnostic::Diagnostic,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
 
 use super::BuildEvent;
 
@@ -23,6 +26,14 @@ impl BuildEvent for ForbidConstAssign {
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!("Unexpected re-assignment of const variable `{0}` at {1}", self.name, self.filename)
   }
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(
+      opts.stabilize_path(&self.filename),
+      &self.source,
+      self.re_assign_span.start,
+    ))
+  }
+
   fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
     let filename = opts.stabilize_path(&self.filename);
     diagnostic.title = format!("Unexpected re-assignment of const variable `{0}`", self.name);
@@ -0,0 +1,19 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// Every module of a shared chunk was tree-shaken, so the chunk is dropped instead of being emitted as an empty file.
+#[derive(Debug)]
+pub struct GeneratedEmptyChunk {
+  pub chunk: String,
+}
+
+impl BuildEvent for GeneratedEmptyChunk {
+  fn kind(&self) -> EventKind {
+    EventKind::GeneratedEmptyChunk
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("Generated an empty chunk: \"{}\". It's not emitted.", self.chunk)
+  }
+}
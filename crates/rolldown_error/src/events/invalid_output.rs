@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// A syntax error in the rendered code of a chunk, found by `output.validate`.
+#[derive(Debug)]
+pub struct InvalidOutput {
+  pub chunk: String,
+  pub code: Arc<str>,
+  pub span: Span,
+  pub reason: String,
+  /// The module the code around the error is rendered from, if it could be told.
+  pub module: Option<String>,
+}
+
+impl BuildEvent for InvalidOutput {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::InvalidOutput
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    match &self.module {
+      Some(module) => format!(
+        "Chunk \"{}\" is not valid JavaScript: {}. The code is rendered from \"{module}\".",
+        self.chunk, self.reason
+      ),
+      None => format!("Chunk \"{}\" is not valid JavaScript: {}.", self.chunk, self.reason),
+    }
+  }
+
+  fn location(&self, _opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(self.chunk.clone(), &self.code, self.span.start))
+  }
+
+  fn ids(&self, _opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    self.module.clone().map(|module| vec![module])
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {
+    diagnostic.title = format!("Chunk \"{}\" is not valid JavaScript", self.chunk);
+
+    let file_id = diagnostic.add_file(self.chunk.clone(), Arc::clone(&self.code));
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, self.reason.clone());
+
+    diagnostic.note = Some(match &self.module {
+      Some(module) => format!("The code is rendered from \"{module}\". A plugin transforming it, or the renderer of rolldown, may have produced invalid code."),
+      None => "A plugin transforming the chunk, or the renderer of rolldown, may have produced invalid code.".to_string(),
+    });
+  }
+}
@@ -0,0 +1,55 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// Top-level `await` in a module bundled to a format that can't wait for modules, found by `checks.topLevelAwait`.
+#[derive(Debug)]
+pub struct InvalidTlaFormat {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+  pub format: &'static str,
+}
+
+impl BuildEvent for InvalidTlaFormat {
+  fn kind(&self) -> EventKind {
+    EventKind::InvalidTlaFormat
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Module format \"{}\" does not support top-level await, which is used in '{}'. Use the \"esm\" output format instead.",
+      self.format,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Set `checks.topLevelAwait` to \"warn\" to bundle the module anyway.".to_string())
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title =
+      format!("Module format \"{}\" does not support top-level await.", self.format);
+
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.filename), Arc::clone(&self.source));
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Top-level await is used here.".to_string(),
+    );
+  }
+}
@@ -1,10 +1,17 @@
 use oxc::span::Span;
 
-use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+use crate::{
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+  utils::find_similar_names,
+};
 use std::sync::Arc;
 
 use super::BuildEvent;
 
+/// Number of exports listed in the note before the rest are elided
+const MAX_LISTED_EXPORTS: usize = 10;
+
 #[derive(Debug)]
 pub struct MissingExport {
   pub stable_importer: String,
@@ -12,6 +19,37 @@ pub struct MissingExport {
   pub importer_source: Arc<str>,
   pub imported_specifier: String,
   pub imported_specifier_span: Span,
+  /// Sorted names of exports of the importee
+  pub available_exports: Vec<String>,
+}
+
+impl MissingExport {
+  fn did_you_mean(&self) -> Option<String> {
+    let similar_names = find_similar_names(&self.imported_specifier, &self.available_exports);
+    if similar_names.is_empty() {
+      return None;
+    }
+    let similar_names =
+      similar_names.iter().map(|name| format!(r#""{name}""#)).collect::<Vec<_>>().join(" or ");
+    Some(format!("Did you mean to import {similar_names} instead?"))
+  }
+
+  fn exports_note(&self) -> String {
+    if self.available_exports.is_empty() {
+      return format!(r#""{}" has no exports."#, self.stable_importee);
+    }
+    let mut listed = self
+      .available_exports
+      .iter()
+      .take(MAX_LISTED_EXPORTS)
+      .map(|name| format!(r#""{name}""#))
+      .collect::<Vec<_>>()
+      .join(", ");
+    if self.available_exports.len() > MAX_LISTED_EXPORTS {
+      listed.push_str(&format!(" and {} more", self.available_exports.len() - MAX_LISTED_EXPORTS));
+    }
+    format!(r#""{}" exports {listed}."#, self.stable_importee)
+  }
 }
 
 impl BuildEvent for MissingExport {
@@ -20,10 +58,26 @@ impl BuildEvent for MissingExport {
   }
 
   fn message(&self, _opts: &DiagnosticOptions) -> String {
-    format!(
+    let message = format!(
       r#""{}" is not exported by "{}", imported by "{}"."#,
       self.imported_specifier, &self.stable_importee, &self.stable_importer
-    )
+    );
+    match self.did_you_mean() {
+      Some(suggestion) => format!("{message} {suggestion}"),
+      None => message,
+    }
+  }
+
+  fn location(&self, _opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(
+      self.stable_importer.clone(),
+      &self.importer_source,
+      self.imported_specifier_span.start,
+    ))
+  }
+
+  fn help(&self) -> Option<String> {
+    self.did_you_mean()
   }
 
   fn on_diagnostic(
@@ -42,5 +96,7 @@ impl BuildEvent for MissingExport {
       self.imported_specifier_span.start..self.imported_specifier_span.end,
       "Missing export".to_string(),
     );
+
+    diagnostic.note = Some(self.exports_note());
   }
 }
@@ -1,18 +1,38 @@
 use std::fmt::Debug;
 
 use crate::{
-  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
 };
 
+pub mod chunk_too_large;
+pub mod circular_chunk;
 pub mod circular_dependency;
+pub mod deprecated_option;
+pub mod duplicate_package;
 pub mod eval;
 pub mod external_entry;
+pub mod file_name_conflict;
 pub mod forbid_const_assign;
+pub mod generated_empty_chunk;
+pub mod invalid_output;
+pub mod invalid_tla_format;
 pub mod missing_export;
+pub mod module_format_mismatch;
+pub mod namespace_conflict;
+pub mod plugin_log;
+pub mod shadowed_export;
+pub mod similar_warnings;
 pub mod sourcemap_error;
+pub mod this_is_undefined;
 pub mod unresolved_entry;
 pub mod unresolved_import;
 pub mod unresolved_import_treated_as_external;
+pub mod unsupported_dynamic_require;
+pub mod unsupported_import_meta;
+pub mod unsupported_typescript;
+pub mod unused_external;
 
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
@@ -20,6 +40,21 @@ pub trait BuildEvent: Debug + Sync + Send {
   fn message(&self, opts: &DiagnosticOptions) -> String;
 
   fn on_diagnostic(&self, _diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {}
+
+  /// Ids of modules the event is about, for consumers of logs that want more than the message.
+  fn ids(&self, _opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    None
+  }
+
+  /// The position in the code the event is about.
+  fn location(&self, _opts: &DiagnosticOptions) -> Option<Location> {
+    None
+  }
+
+  /// How to fix the problem, if it could be guessed.
+  fn help(&self) -> Option<String> {
+    None
+  }
 }
 
 impl<T: BuildEvent + 'static> From<T> for Box<dyn BuildEvent>
@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// A module whose format, decided by its extension or the `type` field of the nearest `package.json` like Node.js
+/// does, doesn't match the syntax it's written in.
+#[derive(Debug)]
+pub struct ModuleFormatMismatch {
+  pub filename: String,
+  pub source: Arc<str>,
+  /// The first `module`/`exports` reference of an ES module, or the first `import`/`export` of a CommonJS module.
+  pub span: Span,
+  /// Whether the module is an ES module by its format.
+  pub is_esm: bool,
+  /// What decides the format, such as `the ".mjs" extension`.
+  pub reason: &'static str,
+}
+
+impl ModuleFormatMismatch {
+  fn title(&self) -> String {
+    if self.is_esm {
+      format!(
+        "The module is an ES module because of {}, where `module` and `exports` are not defined.",
+        self.reason
+      )
+    } else {
+      format!(
+        "The module is a CommonJS module because of {}, where `import` and `export` statements are not allowed.",
+        self.reason
+      )
+    }
+  }
+}
+
+impl BuildEvent for ModuleFormatMismatch {
+  fn kind(&self) -> EventKind {
+    EventKind::ModuleFormatMismatch
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!("{} Found in '{}'.", self.title(), opts.stabilize_path(&self.filename))
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(if self.is_esm {
+      "Use `export` statements, or rename the file to \".cjs\".".to_string()
+    } else {
+      "Use `require` and `module.exports`, or rename the file to \".mjs\".".to_string()
+    })
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = self.title();
+
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.filename), Arc::clone(&self.source));
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      if self.is_esm { "CommonJS is used here." } else { "ES module syntax is used here." }
+        .to_string(),
+    );
+  }
+}
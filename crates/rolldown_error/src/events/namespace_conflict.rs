@@ -0,0 +1,37 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct NamespaceConflict {
+  pub reexporting_module: String,
+  pub name: String,
+  pub sources: Vec<String>,
+}
+
+impl BuildEvent for NamespaceConflict {
+  fn kind(&self) -> EventKind {
+    EventKind::NamespaceConflict
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let sources = self
+      .sources
+      .iter()
+      .map(|source| format!("\"{}\"", opts.stabilize_path(source)))
+      .collect::<Vec<_>>();
+    let sources = match sources.split_last() {
+      Some((last, rest)) if !rest.is_empty() => format!("{} and {last}", rest.join(", ")),
+      _ => sources.join(""),
+    };
+    format!(
+      "Conflicting namespaces: \"{}\" re-exports \"{}\" from one of the modules {sources} (will be ignored).",
+      opts.stabilize_path(&self.reexporting_module),
+      self.name
+    )
+  }
+
+  fn ids(&self, opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    Some(self.sources.iter().map(|source| opts.stabilize_path(source)).collect())
+  }
+}
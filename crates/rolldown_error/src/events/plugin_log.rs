@@ -0,0 +1,26 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// A log emitted by a plugin with `this.warn`, `this.info` or `this.debug`.
+#[derive(Debug)]
+pub struct PluginLog {
+  pub plugin: String,
+  pub message: String,
+  /// `PLUGIN_WARNING` for warnings, and `PLUGIN_LOG` for other levels.
+  pub is_warning: bool,
+}
+
+impl BuildEvent for PluginLog {
+  fn kind(&self) -> EventKind {
+    if self.is_warning {
+      EventKind::PluginWarning
+    } else {
+      EventKind::PluginLog
+    }
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("[plugin {}] {}", self.plugin, self.message)
+  }
+}
@@ -0,0 +1,37 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// An export of an entry hiding the export of the same name from a module it re-exports with `export *`.
+#[derive(Debug)]
+pub struct ShadowedExport {
+  pub entry: String,
+  pub name: String,
+  pub shadowed_module: String,
+}
+
+impl BuildEvent for ShadowedExport {
+  fn kind(&self) -> EventKind {
+    EventKind::ShadowedExport
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Entry \"{}\" exports \"{}\", which shadows the export of the same name re-exported from \"{}\" by `export *`.",
+      opts.stabilize_path(&self.entry),
+      self.name,
+      opts.stabilize_path(&self.shadowed_module)
+    )
+  }
+
+  fn ids(&self, opts: &DiagnosticOptions) -> Option<Vec<String>> {
+    Some(vec![opts.stabilize_path(&self.entry), opts.stabilize_path(&self.shadowed_module)])
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(format!(
+      "Rename one of the exports, or re-export \"{}\" explicitly to make the choice clear.",
+      self.name
+    ))
+  }
+}
@@ -0,0 +1,24 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+/// Stands for warnings of the same kind omitted after the first few, so hundreds of them, such as the cycles of a
+/// vendored library, don't bury the rest. Keeps the kind of the omitted warnings, so filters on codes still apply.
+#[derive(Debug)]
+pub struct SimilarWarnings {
+  pub kind: EventKind,
+  pub count: usize,
+}
+
+impl BuildEvent for SimilarWarnings {
+  fn kind(&self) -> EventKind {
+    self.kind
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!(
+      "{} similar {} warnings were omitted. Set `collapseWarnings: false` to report all of them.",
+      self.count, self.kind
+    )
+  }
+}
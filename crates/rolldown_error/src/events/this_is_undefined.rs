@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct ThisIsUndefined {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+}
+
+impl BuildEvent for ThisIsUndefined {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::ThisIsUndefined
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "The 'this' keyword is equivalent to 'undefined' at the top level of an ES module, and has been rewritten in '{}'.",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let filename = opts.stabilize_path(&self.filename);
+
+    diagnostic.title =
+      "The 'this' keyword is equivalent to 'undefined' at the top level of an ES module, and has been rewritten."
+        .to_string();
+
+    let file_id = diagnostic.add_file(filename, Arc::clone(&self.source));
+
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "Rewritten to `undefined` here.".to_string(),
+    );
+  }
+}
@@ -7,6 +7,8 @@ use std::path::PathBuf;
 pub struct UnresolvedImport {
   pub(crate) specifier: String,
   pub(crate) importer: PathBuf,
+  /// Whether the specifier is a builtin module of Node.js, which isn't available on the target platform.
+  pub(crate) is_node_builtin: bool,
 }
 
 impl BuildEvent for UnresolvedImport {
@@ -15,6 +17,20 @@ impl BuildEvent for UnresolvedImport {
   }
 
   fn message(&self, opts: &DiagnosticOptions) -> String {
-    format!("Could not resolve {} from {}.", self.specifier, opts.stabilize_path(&self.importer))
+    let message =
+      format!("Could not resolve {} from {}.", self.specifier, opts.stabilize_path(&self.importer));
+    match self.help() {
+      Some(help) => format!("{message} {help}"),
+      None => message,
+    }
+  }
+
+  fn help(&self) -> Option<String> {
+    self.is_node_builtin.then(|| {
+      format!(
+        "\"{}\" is a builtin module of Node.js. Set `platform` to \"node\" if the code runs in Node.js.",
+        self.specifier
+      )
+    })
   }
 }
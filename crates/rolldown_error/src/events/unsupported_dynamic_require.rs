@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// `require(...)` whose argument isn't a string literal, found while `unresolvedRequire` is `error`.
+#[derive(Debug)]
+pub struct UnsupportedDynamicRequire {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+}
+
+impl BuildEvent for UnsupportedDynamicRequire {
+  fn kind(&self) -> EventKind {
+    EventKind::UnsupportedDynamicRequire
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "`require(...)` with a non-literal argument in '{}' could not be resolved while bundling.",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Use `dynamicRequireTargets` to bundle the modules it could require, or set `unresolvedRequire` to \"keep\" or \"stub\".".to_string())
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title =
+      "`require(...)` with a non-literal argument could not be resolved while bundling."
+        .to_string();
+
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.filename), Arc::clone(&self.source));
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "The required path is only known at runtime.".to_string(),
+    );
+  }
+}
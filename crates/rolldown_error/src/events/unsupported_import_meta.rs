@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// `import.meta` in a module bundled to the `cjs` format, found by `checks.importMeta`.
+#[derive(Debug)]
+pub struct UnsupportedImportMeta {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+}
+
+impl BuildEvent for UnsupportedImportMeta {
+  fn kind(&self) -> EventKind {
+    EventKind::UnsupportedImportMeta
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Module format \"cjs\" does not support `import.meta`, which is used in '{}'. It would be a syntax error in the output.",
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Use `__filename` or `__dirname` in CommonJS, or set `checks.importMeta` to \"warn\" to bundle the module anyway.".to_string())
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = "Module format \"cjs\" does not support `import.meta`.".to_string();
+
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.filename), Arc::clone(&self.source));
+    diagnostic.add_label(
+      &file_id,
+      self.span.start..self.span.end,
+      "`import.meta` is used here.".to_string(),
+    );
+  }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic,
+  event_kind::EventKind,
+  types::{diagnostic_options::DiagnosticOptions, location::Location},
+};
+
+use super::BuildEvent;
+
+/// TypeScript syntax that only works when the module is compiled to CommonJS, such as `export =`.
+#[derive(Debug)]
+pub struct UnsupportedTypeScript {
+  pub filename: String,
+  pub source: Arc<str>,
+  pub span: Span,
+  pub syntax: &'static str,
+}
+
+impl BuildEvent for UnsupportedTypeScript {
+  fn kind(&self) -> EventKind {
+    EventKind::UnsupportedTypeScript
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "TypeScript {} in '{}' are not supported yet.",
+      self.syntax,
+      opts.stabilize_path(&self.filename)
+    )
+  }
+
+  fn location(&self, opts: &DiagnosticOptions) -> Option<Location> {
+    Some(Location::from_offset(opts.stabilize_path(&self.filename), &self.source, self.span.start))
+  }
+
+  fn help(&self) -> Option<String> {
+    Some("Rewrite it in plain JavaScript, or compile the module with a plugin instead.".to_string())
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    diagnostic.title = format!("TypeScript {} are not supported yet.", self.syntax);
+
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(&self.filename), Arc::clone(&self.source));
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, "Used here.".to_string());
+  }
+}
@@ -0,0 +1,22 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct UnusedExternal {
+  pub id: String,
+}
+
+impl BuildEvent for UnusedExternal {
+  fn kind(&self) -> EventKind {
+    EventKind::UnusedExternal
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    format!("\"{}\" is listed in \"external\", but no module imports it.", self.id)
+  }
+
+  fn help(&self) -> Option<String> {
+    Some(format!("Remove \"{}\" from \"external\" if it's no longer a dependency.", self.id))
+  }
+}
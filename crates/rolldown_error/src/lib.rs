@@ -1,11 +1,17 @@
 mod build_error;
 mod diagnostic;
+mod diagnostic_renderer;
 mod event_kind;
 mod events;
 mod types;
+mod utils;
 
 pub use crate::{
+  build_error::severity::Severity,
   build_error::{BuildError, BuildResult},
+  diagnostic_renderer::{supports_color, DiagnosticRenderer},
   event_kind::EventKind,
+  events::duplicate_package::{DuplicatePackage, PackageCopy},
   types::diagnostic_options::DiagnosticOptions,
+  types::location::Location,
 };
@@ -2,6 +2,7 @@ use std::path::{Path, PathBuf};
 
 use sugar_path::SugarPath;
 
+#[derive(Debug, Clone)]
 pub struct DiagnosticOptions {
   pub cwd: PathBuf,
 }
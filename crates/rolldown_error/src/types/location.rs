@@ -0,0 +1,32 @@
+/// Where in a file a diagnostic points to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+  /// Path of the file relative to `cwd`
+  pub file: String,
+  /// Starts from 1
+  pub line: u32,
+  /// Starts from 0, counted in UTF-16 code units like columns of sourcemaps.
+  pub column: u32,
+}
+
+impl Location {
+  pub fn from_offset(file: String, source: &str, offset: u32) -> Self {
+    let before = &source[..(offset as usize).min(source.len())];
+    let line_start = before.rfind('\n').map_or(0, |index| index + 1);
+    let line = before.matches('\n').count() + 1;
+    let column = before[line_start..].encode_utf16().count();
+    Self {
+      file,
+      line: u32::try_from(line).unwrap_or(u32::MAX),
+      column: u32::try_from(column).unwrap_or(u32::MAX),
+    }
+  }
+}
+
+#[test]
+fn test_location_from_offset() {
+  let source = "import a from './a'\nconsole.log(a, eval('1'))\n";
+  let location = Location::from_offset("main.js".to_string(), source, 35);
+  assert_eq!(location, Location { file: "main.js".to_string(), line: 2, column: 15 });
+  assert_eq!(Location::from_offset("main.js".to_string(), source, 0).column, 0);
+}
@@ -1 +1,2 @@
 pub mod diagnostic_options;
+pub mod location;
@@ -0,0 +1,40 @@
+/// Candidates that look like a misspelling of `name`, from the most similar. Used to suggest fixes in errors.
+pub fn find_similar_names<'a>(name: &str, candidates: &'a [String]) -> Vec<&'a str> {
+  let max_distance = (name.chars().count() / 3).max(1);
+  let mut similar = candidates
+    .iter()
+    .filter(|candidate| candidate.as_str() != name)
+    .filter_map(|candidate| {
+      let distance =
+        if candidate.eq_ignore_ascii_case(name) { 0 } else { edit_distance(name, candidate) };
+      (distance <= max_distance).then_some((distance, candidate.as_str()))
+    })
+    .collect::<Vec<_>>();
+  similar.sort_by_key(|(distance, _)| *distance);
+  similar.into_iter().take(3).map(|(_, candidate)| candidate).collect()
+}
+
+/// Levenshtein distance between `a` and `b`, counted in chars.
+fn edit_distance(a: &str, b: &str) -> usize {
+  let b = b.chars().collect::<Vec<_>>();
+  let mut prev_row = (0..=b.len()).collect::<Vec<_>>();
+  let mut row = vec![0; b.len() + 1];
+  for (i, a_char) in a.chars().enumerate() {
+    row[0] = i + 1;
+    for (j, b_char) in b.iter().enumerate() {
+      let substitution = prev_row[j] + usize::from(a_char != *b_char);
+      row[j + 1] = substitution.min(prev_row[j + 1] + 1).min(row[j] + 1);
+    }
+    std::mem::swap(&mut prev_row, &mut row);
+  }
+  prev_row[b.len()]
+}
+
+#[test]
+fn test_find_similar_names() {
+  let candidates =
+    ["button".to_string(), "Button".to_string(), "bottom".to_string(), "default".to_string()];
+  assert_eq!(find_similar_names("buton", &candidates), vec!["button"]);
+  assert_eq!(find_similar_names("BUTTON", &candidates), vec!["button", "Button"]);
+  assert!(find_similar_names("foo", &candidates).is_empty());
+}
@@ -7,6 +7,10 @@ impl FileSystem for Box<dyn FileSystem> {
     self.as_ref().create_dir_all(path)
   }
 
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.as_ref().read(path)
+  }
+
   fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
     self.as_ref().write(path, content)
   }
@@ -14,6 +18,18 @@ impl FileSystem for Box<dyn FileSystem> {
   fn exists(&self, path: &Path) -> bool {
     self.as_ref().exists(path)
   }
+
+  fn is_file(&self, path: &Path) -> bool {
+    self.as_ref().is_file(path)
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self.as_ref().is_dir(path)
+  }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    self.as_ref().read_dir(path)
+  }
 }
 
 impl oxc_resolver::FileSystem for Box<dyn FileSystem> {
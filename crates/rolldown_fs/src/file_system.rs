@@ -1,4 +1,7 @@
-use std::{io, path::Path};
+use std::{
+  io,
+  path::{Path, PathBuf},
+};
 
 use oxc_resolver::FileSystem as OxcResolverFileSystem;
 
@@ -13,6 +16,11 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   /// * See [std::fs::create_dir_all]
   fn create_dir_all(&self, path: &Path) -> io::Result<()>;
 
+  /// # Errors
+  ///
+  /// * See [std::fs::read]
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>>;
+
   /// # Errors
   ///
   /// * See [std::fs::write]
@@ -22,4 +30,17 @@ pub trait FileSystem: Send + Sync + OxcResolverFileSystem {
   ///
   /// * See [std::path::Path::exists]
   fn exists(&self, path: &Path) -> bool;
+
+  /// See [std::path::Path::is_file]
+  fn is_file(&self, path: &Path) -> bool;
+
+  /// See [std::path::Path::is_dir]
+  fn is_dir(&self, path: &Path) -> bool;
+
+  /// Paths of the entries in the directory, in no particular order.
+  ///
+  /// # Errors
+  ///
+  /// * See [std::fs::read_dir]
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>>;
 }
@@ -5,6 +5,7 @@ mod memory;
 pub use memory::MemoryFileSystem;
 #[cfg(feature = "os")]
 mod os;
-pub use crate::file_system::FileSystem;
+mod shared;
+pub use crate::{file_system::FileSystem, shared::SharedFileSystem};
 #[cfg(feature = "os")]
 pub use os::OsFileSystem;
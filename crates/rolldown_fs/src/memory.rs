@@ -56,24 +56,62 @@ impl FileSystem for MemoryFileSystem {
   }
 
   fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+    // Create all missing ancestors, as `std::fs::create_dir_all` does
+    for path in path.ancestors().collect::<Vec<_>>().iter().rev() {
+      if !self.exists(path) {
+        self
+          .fs
+          .create_dir(&path.to_string_lossy())
+          .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
+      }
+    }
+    Ok(())
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    let mut buf = Vec::new();
     self
       .fs
-      .create_dir(&path.to_string_lossy())
-      .map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+      .open_file(&path.to_string_lossy())
+      .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?
+      .read_to_end(&mut buf)?;
+    Ok(buf)
   }
 
   fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
-    _ = self
+    self
       .fs
       .create_file(&path.to_string_lossy())
       .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?
-      .write(content)
-      .map_err(|err| io::Error::new(io::ErrorKind::Other, err))?;
-    Ok(())
+      .write_all(content)
   }
 
   fn exists(&self, path: &Path) -> bool {
-    self.fs.exists(path.to_string_lossy().as_ref()).is_ok()
+    self.fs.exists(path.to_string_lossy().as_ref()).unwrap_or(false)
+  }
+
+  fn is_file(&self, path: &Path) -> bool {
+    self
+      .fs
+      .metadata(path.to_string_lossy().as_ref())
+      .is_ok_and(|metadata| metadata.file_type == vfs::VfsFileType::File)
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self
+      .fs
+      .metadata(path.to_string_lossy().as_ref())
+      .is_ok_and(|metadata| metadata.file_type == vfs::VfsFileType::Directory)
+  }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    // Paths of `MemoryFS` have no trailing `/`, and the root is the empty path.
+    let names = self
+      .fs
+      .read_dir(path.to_string_lossy().trim_end_matches('/'))
+      .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?;
+    // `add_file` creates `/` itself as an entry of the root, named by the empty string.
+    Ok(names.filter(|name| !name.is_empty()).map(|name| path.join(name)).collect())
   }
 }
 
@@ -113,7 +151,7 @@ impl OxcResolverFileSystem for MemoryFileSystem {
 mod tests {
   use crate::{FileSystem as _, MemoryFileSystem};
   use oxc_resolver::FileSystem;
-  use std::path::Path;
+  use std::path::{Path, PathBuf};
 
   #[test]
   pub fn test_memory_file_system() -> Result<(), String> {
@@ -136,10 +174,11 @@ mod tests {
       fs.read_to_string(Path::new("/module_1.js")).map_err(|err| err.to_string())?
     );
 
-    let ret = fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.kind());
-    assert_eq!(Err(std::io::ErrorKind::Other), ret);
-
-    fs.create_dir_all(Path::new("/module_2")).map_err(|err| err.to_string())?;
+    fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.to_string())?;
+    assert!(fs.exists(Path::new("/module_2")));
+    assert!(fs.is_dir(Path::new("/module_2")));
+    assert!(fs.is_file(Path::new("/module_1.js")));
+    assert!(!fs.is_file(Path::new("/module_2")));
     fs.create_dir_all(Path::new("/module_2/utils")).map_err(|err| err.to_string())?;
 
     let utils_content = b"export const name = \"utils\"";
@@ -151,6 +190,10 @@ mod tests {
       fs.read_to_string(Path::new("/module_2/utils/index.js")).map_err(|err| err.to_string())?
     );
 
+    let mut entries = fs.read_dir(Path::new("/")).map_err(|err| err.to_string())?;
+    entries.sort();
+    assert_eq!(entries, ["/index.js", "/module_1.js", "/module_2"].map(PathBuf::from));
+
     Ok(())
   }
 }
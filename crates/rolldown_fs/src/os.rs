@@ -20,6 +20,10 @@ impl FileSystem for OsFileSystem {
     std::fs::create_dir_all(path)
   }
 
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    std::fs::read(path)
+  }
+
   fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
     std::fs::write(path, content)
   }
@@ -27,6 +31,18 @@ impl FileSystem for OsFileSystem {
   fn exists(&self, path: &Path) -> bool {
     path.exists()
   }
+
+  fn is_file(&self, path: &Path) -> bool {
+    path.is_file()
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    path.is_dir()
+  }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    std::fs::read_dir(path)?.map(|entry| entry.map(|entry| entry.path())).collect()
+  }
 }
 
 impl OxcResolverFileSystem for OsFileSystem {
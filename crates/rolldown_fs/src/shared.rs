@@ -0,0 +1,85 @@
+use std::{
+  fmt, io,
+  path::{Path, PathBuf},
+  sync::Arc,
+};
+
+use oxc_resolver::{FileMetadata, FileSystem as OxcResolverFileSystem};
+
+use crate::file_system::FileSystem;
+
+/// A file system chosen at runtime, such as the in-memory one or one provided by the host, when rolldown runs in
+/// environments without access to the disk like browsers. Defaults to [crate::OsFileSystem].
+#[derive(Clone)]
+pub struct SharedFileSystem(Arc<dyn FileSystem>);
+
+impl SharedFileSystem {
+  pub fn new(fs: impl FileSystem + 'static) -> Self {
+    Self(Arc::new(fs))
+  }
+}
+
+#[cfg(feature = "os")]
+impl Default for SharedFileSystem {
+  fn default() -> Self {
+    Self::new(crate::OsFileSystem)
+  }
+}
+
+impl fmt::Debug for SharedFileSystem {
+  fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    f.debug_tuple("SharedFileSystem").finish()
+  }
+}
+
+impl FileSystem for SharedFileSystem {
+  fn remove_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.remove_dir_all(path)
+  }
+
+  fn create_dir_all(&self, path: &Path) -> io::Result<()> {
+    self.0.create_dir_all(path)
+  }
+
+  fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+    self.0.read(path)
+  }
+
+  fn write(&self, path: &Path, content: &[u8]) -> io::Result<()> {
+    self.0.write(path, content)
+  }
+
+  fn exists(&self, path: &Path) -> bool {
+    self.0.exists(path)
+  }
+
+  fn is_file(&self, path: &Path) -> bool {
+    self.0.is_file(path)
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self.0.is_dir(path)
+  }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    self.0.read_dir(path)
+  }
+}
+
+impl OxcResolverFileSystem for SharedFileSystem {
+  fn read_to_string(&self, path: &Path) -> io::Result<String> {
+    self.0.read_to_string(path)
+  }
+
+  fn metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.metadata(path)
+  }
+
+  fn symlink_metadata(&self, path: &Path) -> io::Result<FileMetadata> {
+    self.0.symlink_metadata(path)
+  }
+
+  fn canonicalize(&self, path: &Path) -> io::Result<PathBuf> {
+    self.0.canonicalize(path)
+  }
+}
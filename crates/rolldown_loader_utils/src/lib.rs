@@ -1,5 +1,7 @@
 mod json_to_esm;
 mod text_to_esm;
+mod wasm_to_esm;
 
 pub use json_to_esm::json_to_esm;
 pub use text_to_esm::text_to_esm;
+pub use wasm_to_esm::wasm_to_esm;
@@ -0,0 +1,222 @@
+use rolldown_utils::ecma_script::is_validate_identifier_name;
+
+/// Generate a module that instantiates the WebAssembly module and re-exports its exports. Imports of the WebAssembly
+/// module are imported from the modules they name, which is what the ESM integration proposal and wasm-pack's
+/// `bundler` target expect.
+///
+/// The `.wasm` file is referenced by `new URL('./{file_name}', import.meta.url)`, so it's emitted as an asset. It's
+/// read from the file system on Node.js and fetched on other platforms.
+pub fn wasm_to_esm(file_name: &str, wasm: &[u8], is_node: bool) -> anyhow::Result<String> {
+  let WasmModuleInfo { imports, exports } = parse_wasm_module(wasm)?;
+
+  let mut source = String::new();
+  let mut import_modules: Vec<&str> = vec![];
+  for (module, _) in &imports {
+    if !import_modules.contains(&module.as_str()) {
+      import_modules.push(module);
+    }
+  }
+  for (idx, module) in import_modules.iter().enumerate() {
+    source.push_str(&format!(
+      "import * as __rolldown_wasm_import_{idx} from {};\n",
+      serde_json::to_string(module)?
+    ));
+  }
+  if is_node {
+    source.push_str("import { readFile as __rolldown_readFile } from \"node:fs/promises\";\n");
+  }
+
+  source.push_str(&format!(
+    "const __rolldown_wasm_url = new URL({}, import.meta.url);\n",
+    serde_json::to_string(&format!("./{file_name}"))?
+  ));
+  let bytes = if is_node {
+    "await __rolldown_readFile(__rolldown_wasm_url)"
+  } else {
+    "await (await fetch(__rolldown_wasm_url)).arrayBuffer()"
+  };
+  let import_object = import_modules
+    .iter()
+    .enumerate()
+    .map(|(idx, module)| {
+      let items = imports
+        .iter()
+        .filter(|(m, _)| m == module)
+        .map(|(_, name)| {
+          let name = serde_json::to_string(name)?;
+          Ok(format!("{name}: __rolldown_wasm_import_{idx}[{name}]"))
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+      Ok(format!("{}: {{ {} }}", serde_json::to_string(module)?, items.join(", ")))
+    })
+    .collect::<anyhow::Result<Vec<_>>>()?;
+  source.push_str(&format!(
+    "const {{ instance: __rolldown_wasm_instance }} = await WebAssembly.instantiate({bytes}, {{ {} }});\n",
+    import_object.join(", ")
+  ));
+
+  for (idx, name) in exports.iter().enumerate() {
+    let value = format!("__rolldown_wasm_instance.exports[{}]", serde_json::to_string(name)?);
+    if is_validate_identifier_name(name) && name != "default" {
+      source.push_str(&format!("export const {name} = {value};\n"));
+    } else {
+      let valid_id = format!("key_{idx}");
+      source.push_str(&format!("const {valid_id} = {value};\n"));
+      source.push_str(&format!("export {{ {valid_id} as {} }};\n", serde_json::to_string(name)?));
+    }
+  }
+  Ok(source)
+}
+
+struct WasmModuleInfo {
+  /// Module names and field names of imports
+  imports: Vec<(String, String)>,
+  exports: Vec<String>,
+}
+
+fn parse_wasm_module(wasm: &[u8]) -> anyhow::Result<WasmModuleInfo> {
+  const IMPORT_SECTION: u8 = 2;
+  const EXPORT_SECTION: u8 = 7;
+
+  let mut reader = WasmReader { bytes: wasm, pos: 0 };
+  if reader.take(4)? != b"\0asm" {
+    anyhow::bail!("Invalid WebAssembly module: missing magic header");
+  }
+  reader.take(4)?;
+
+  let mut info = WasmModuleInfo { imports: vec![], exports: vec![] };
+  while reader.pos < wasm.len() {
+    let id = reader.byte()?;
+    let size = reader.u32()? as usize;
+    let section_end = reader.pos + size;
+    match id {
+      IMPORT_SECTION => {
+        for _ in 0..reader.u32()? {
+          let module = reader.name()?;
+          let name = reader.name()?;
+          reader.skip_import_desc()?;
+          info.imports.push((module, name));
+        }
+      }
+      EXPORT_SECTION => {
+        for _ in 0..reader.u32()? {
+          let name = reader.name()?;
+          // Kind and index
+          reader.byte()?;
+          reader.u32()?;
+          info.exports.push(name);
+        }
+      }
+      _ => {}
+    }
+    reader.pos = section_end;
+  }
+  Ok(info)
+}
+
+struct WasmReader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> WasmReader<'a> {
+  fn take(&mut self, len: usize) -> anyhow::Result<&'a [u8]> {
+    let Some(bytes) = self.bytes.get(self.pos..self.pos + len) else {
+      anyhow::bail!("Invalid WebAssembly module: unexpected end of file");
+    };
+    self.pos += len;
+    Ok(bytes)
+  }
+
+  fn byte(&mut self) -> anyhow::Result<u8> {
+    Ok(self.take(1)?[0])
+  }
+
+  /// Read an unsigned LEB128 integer.
+  fn u32(&mut self) -> anyhow::Result<u32> {
+    let mut result = 0u32;
+    for shift in (0..35).step_by(7) {
+      let byte = self.byte()?;
+      result |= u32::from(byte & 0x7f) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(result);
+      }
+    }
+    anyhow::bail!("Invalid WebAssembly module: integer is too long")
+  }
+
+  fn name(&mut self) -> anyhow::Result<String> {
+    let len = self.u32()? as usize;
+    Ok(String::from_utf8(self.take(len)?.to_vec())?)
+  }
+
+  fn skip_limits(&mut self) -> anyhow::Result<()> {
+    let flags = self.byte()?;
+    self.u32()?;
+    if flags & 1 != 0 {
+      self.u32()?;
+    }
+    Ok(())
+  }
+
+  fn skip_import_desc(&mut self) -> anyhow::Result<()> {
+    match self.byte()? {
+      // Function with its type index
+      0 => {
+        self.u32()?;
+      }
+      // Table with its element type and limits
+      1 => {
+        self.byte()?;
+        self.skip_limits()?;
+      }
+      // Memory
+      2 => self.skip_limits()?,
+      // Global with its value type and mutability
+      3 => {
+        self.take(2)?;
+      }
+      // Tag with its attribute and type index
+      4 => {
+        self.byte()?;
+        self.u32()?;
+      }
+      kind => anyhow::bail!("Invalid WebAssembly module: unknown import kind {kind}"),
+    }
+    Ok(())
+  }
+}
+
+#[test]
+fn test_wasm_to_esm() {
+  let wasm = [
+    b"\0asm".as_slice(),
+    &[1, 0, 0, 0],
+    // Import section: `./foo_bg.js` `log` as a function of type 0
+    &[2, 19, 1, 11],
+    b"./foo_bg.js",
+    &[3],
+    b"log",
+    &[0, 0],
+    // Export section: `memory` and `add-one`
+    &[7, 20, 2, 6],
+    b"memory",
+    &[2, 0, 7],
+    b"add-one",
+    &[0, 1],
+  ]
+  .concat();
+  assert_eq!(
+    wasm_to_esm("foo_bg.wasm", &wasm, false).unwrap(),
+    [
+      "import * as __rolldown_wasm_import_0 from \"./foo_bg.js\";\n",
+      "const __rolldown_wasm_url = new URL(\"./foo_bg.wasm\", import.meta.url);\n",
+      "const { instance: __rolldown_wasm_instance } = await WebAssembly.instantiate(await (await fetch(__rolldown_wasm_url)).arrayBuffer(), { \"./foo_bg.js\": { \"log\": __rolldown_wasm_import_0[\"log\"] } });\n",
+      "export const memory = __rolldown_wasm_instance.exports[\"memory\"];\n",
+      "const key_1 = __rolldown_wasm_instance.exports[\"add-one\"];\n",
+      "export { key_1 as \"add-one\" };\n",
+    ]
+    .concat()
+  );
+  assert!(wasm_to_esm("foo.wasm", b"\0asm\x01", false).is_err());
+}
@@ -386,6 +386,86 @@ impl<'ast> AstSnippet<'ast> {
     )
   }
 
+  /// `[promise].then((m) => m.default)`
+  pub fn then_default_expr(&self, promise: ast::Expression<'ast>) -> ast::Expression<'ast> {
+    let mut statements = allocator::Vec::new_in(self.alloc);
+    statements.push(ast::Statement::ExpressionStatement(
+      ast::ExpressionStatement {
+        expression: self.literal_prop_access_member_expr_expr("m", "default"),
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    ));
+    let mut arrow_expr = ast::ArrowFunctionExpression {
+      expression: true,
+      body: ast::FunctionBody { statements, ..TakeIn::dummy(self.alloc) }.into_in(self.alloc),
+      ..TakeIn::dummy(self.alloc)
+    };
+    arrow_expr.params.items.push(ast::FormalParameter {
+      pattern: ast::BindingPattern {
+        kind: ast::BindingPatternKind::BindingIdentifier(self.id("m", SPAN).into_in(self.alloc)),
+        ..TakeIn::dummy(self.alloc)
+      },
+      ..TakeIn::dummy(self.alloc)
+    });
+    let mut arguments = allocator::Vec::new_in(self.alloc);
+    arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr.into_in(self.alloc)));
+    ast::Expression::CallExpression(
+      ast::CallExpression {
+        callee: ast::Expression::from(ast::MemberExpression::StaticMemberExpression(
+          ast::StaticMemberExpression {
+            object: promise,
+            property: ast::IdentifierName { name: self.atom("then"), ..TakeIn::dummy(self.alloc) },
+            ..TakeIn::dummy(self.alloc)
+          }
+          .into_in(self.alloc),
+        )),
+        arguments,
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    )
+  }
+
+  /// `(() => { throw new Error(message) })()`
+  pub fn throw_error_iife_expr(&self, message: PassedStr) -> ast::Expression<'ast> {
+    let mut arguments = allocator::Vec::new_in(self.alloc);
+    arguments
+      .push(ast::Argument::StringLiteral(self.string_literal(message, SPAN).into_in(self.alloc)));
+    let error_expr = ast::Expression::NewExpression(
+      ast::NewExpression {
+        span: SPAN,
+        callee: self.id_ref_expr("Error", SPAN),
+        arguments,
+        type_parameters: None,
+      }
+      .into_in(self.alloc),
+    );
+    let mut statements = allocator::Vec::new_in(self.alloc);
+    statements.push(ast::Statement::ThrowStatement(
+      ast::ThrowStatement { span: SPAN, argument: error_expr }.into_in(self.alloc),
+    ));
+    let arrow_expr = ast::Expression::ArrowFunctionExpression(
+      ast::ArrowFunctionExpression {
+        expression: false,
+        body: ast::FunctionBody { statements, ..TakeIn::dummy(self.alloc) }.into_in(self.alloc),
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    );
+    ast::Expression::CallExpression(
+      ast::CallExpression {
+        callee: ast::Expression::ParenthesizedExpression(
+          ast::ParenthesizedExpression { expression: arrow_expr, ..TakeIn::dummy(self.alloc) }
+            .into_in(self.alloc),
+        ),
+        arguments: allocator::Vec::new_in(self.alloc),
+        ..TakeIn::dummy(self.alloc)
+      }
+      .into_in(self.alloc),
+    )
+  }
+
   // `undefined` is acting like identifier, it might be shadowed by user code.
   pub fn void_zero(&self) -> ast::Expression<'ast> {
     ast::Expression::UnaryExpression(
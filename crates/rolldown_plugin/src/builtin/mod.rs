@@ -0,0 +1,5 @@
+//! Plugins implemented in Rust, which could be placed anywhere among Js plugins in `plugins`.
+
+mod replace;
+
+pub use replace::ReplacePlugin;
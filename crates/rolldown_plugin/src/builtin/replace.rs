@@ -0,0 +1,84 @@
+use std::{borrow::Cow, collections::HashMap};
+
+use string_wizard::{MagicString, SourceMapOptions};
+
+use crate::{
+  HookLoadOutput, HookTransformArgs, HookTransformReturn, Plugin, TransformPluginContext,
+};
+
+/// Replace strings in the code of modules, like `@rollup/plugin-replace`. A key only matches a whole word that isn't
+/// followed by `.`, so `process.env.NODE_ENV` doesn't match in `process.env.NODE_ENV_X` or
+/// `process.env.NODE_ENV.length`. Unlike `define`, the code isn't parsed, so it works on code that other plugins
+/// haven't transformed to Js yet.
+#[derive(Debug)]
+pub struct ReplacePlugin {
+  /// Sorted by length in descending order, so longer keys win over keys that are their prefixes.
+  values: Vec<(String, String)>,
+}
+
+impl ReplacePlugin {
+  pub fn new(values: HashMap<String, String>) -> Self {
+    let mut values = values.into_iter().filter(|(key, _)| !key.is_empty()).collect::<Vec<_>>();
+    values.sort_by(|(a, _), (b, _)| b.len().cmp(&a.len()).then_with(|| a.cmp(b)));
+    Self { values }
+  }
+
+  /// The key and the value to replace with, if a key matches at `start` of `code`.
+  fn match_at(&self, code: &str, start: usize) -> Option<&(String, String)> {
+    let before = code[..start].chars().next_back();
+    self.values.iter().find(|(key, _)| {
+      if !code[start..].starts_with(key.as_str()) {
+        return false;
+      }
+      let after = code[start + key.len()..].chars().next();
+      let splits_word_before = key.starts_with(is_word_char) && before.is_some_and(is_word_char);
+      let splits_word_after = key.ends_with(is_word_char) && after.is_some_and(is_word_char);
+      !splits_word_before && !splits_word_after && after != Some('.')
+    })
+  }
+}
+
+fn is_word_char(c: char) -> bool {
+  c.is_alphanumeric() || c == '_' || c == '$'
+}
+
+#[async_trait::async_trait]
+impl Plugin for ReplacePlugin {
+  fn name(&self) -> Cow<'static, str> {
+    Cow::Borrowed("builtin:replace")
+  }
+
+  async fn transform(
+    &self,
+    _ctx: &TransformPluginContext<'_>,
+    args: &HookTransformArgs,
+  ) -> HookTransformReturn {
+    let code = args.code.as_str();
+    let mut magic_string = None;
+    let mut pos = 0;
+    while let Some(c) = code[pos..].chars().next() {
+      if let Some((key, value)) = self.match_at(code, pos) {
+        magic_string.get_or_insert_with(|| MagicString::new(code)).update(
+          pos,
+          pos + key.len(),
+          value.clone(),
+        );
+        pos += key.len();
+      } else {
+        pos += c.len_utf8();
+      }
+    }
+    let Some(magic_string) = magic_string else {
+      return Ok(None);
+    };
+    Ok(Some(HookLoadOutput {
+      code: magic_string.to_string(),
+      map: Some(magic_string.source_map(SourceMapOptions {
+        hires: true,
+        include_content: true,
+        source: args.id.into(),
+      })),
+      side_effects: None,
+    }))
+  }
+}
@@ -1,3 +1,4 @@
+mod builtin;
 mod plugin;
 mod plugin_context;
 mod plugin_driver;
@@ -10,9 +11,10 @@ pub mod inner {
 }
 
 pub use crate::{
+  builtin::ReplacePlugin,
   plugin::{
-    BoxPlugin, HookAugmentChunkHashReturn, HookLoadReturn, HookNoopReturn, HookRenderChunkReturn,
-    HookResolveIdReturn, HookTransformReturn, Plugin,
+    BoxPlugin, HookAugmentChunkHashReturn, HookBoolReturn, HookLoadReturn, HookNoopReturn,
+    HookRenderChunkReturn, HookResolveIdReturn, HookTransformReturn, Plugin,
   },
   plugin_context::{PluginContext, SharedPluginContext},
   plugin_driver::{PluginDriver, SharedPluginDriver},
@@ -27,6 +29,8 @@ pub use crate::{
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_extra_options::HookResolveIdExtraOptions,
   types::hook_resolve_id_output::HookResolveIdOutput,
+  types::hook_should_transform_cached_module_args::HookShouldTransformCachedModuleArgs,
   types::hook_transform_args::HookTransformArgs,
+  types::hook_watch_change_args::HookWatchChangeArgs,
   types::plugin_context_resolve_options::PluginContextResolveOptions,
 };
@@ -4,7 +4,8 @@ use super::plugin_context::SharedPluginContext;
 use crate::{
   transform_plugin_context::TransformPluginContext, types::hook_render_error::HookRenderErrorArgs,
   HookBuildEndArgs, HookLoadArgs, HookLoadOutput, HookRenderChunkArgs, HookRenderChunkOutput,
-  HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdOutput, HookTransformArgs,
+  HookResolveDynamicImportArgs, HookResolveIdArgs, HookResolveIdOutput,
+  HookShouldTransformCachedModuleArgs, HookTransformArgs, HookWatchChangeArgs,
 };
 use anyhow::Result;
 use rolldown_common::{ModuleInfo, Output, RenderedChunk};
@@ -13,6 +14,7 @@ pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
 pub type HookTransformReturn = Result<Option<HookLoadOutput>>;
 pub type HookLoadReturn = Result<Option<HookLoadOutput>>;
 pub type HookNoopReturn = Result<()>;
+pub type HookBoolReturn = Result<Option<bool>>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 
@@ -20,6 +22,13 @@ pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
+  /// Identifies the plugin and its options in `cacheDir`, so results of `transform` hooks cached on disk are only used
+  /// with the same plugins configured the same way. Defaults to the `Debug` representation, which includes options
+  /// stored in the plugin.
+  fn cache_key(&self) -> Cow<'static, str> {
+    Cow::Owned(format!("{self:?}"))
+  }
+
   // The `option` hook consider call at node side.
 
   // --- Build hooks ---
@@ -75,6 +84,26 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     Ok(())
   }
 
+  /// Called when the module is found in the persistent cache of `cacheDir`, whose code before `transform` hasn't
+  /// changed. Returning `true` transforms the module again instead of using the cached code, such as when the
+  /// transformation depends on other files.
+  async fn should_transform_cached_module(
+    &self,
+    _ctx: &SharedPluginContext,
+    _args: &HookShouldTransformCachedModuleArgs,
+  ) -> HookBoolReturn {
+    Ok(None)
+  }
+
+  /// Called in watch mode before a rebuild, with all the changes coalesced into the rebuild.
+  async fn watch_change(
+    &self,
+    _ctx: &SharedPluginContext,
+    _args: &HookWatchChangeArgs,
+  ) -> HookNoopReturn {
+    Ok(())
+  }
+
   // --- Generate hooks ---
 
   #[allow(clippy::ptr_arg)]
@@ -106,6 +135,12 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     Ok(())
   }
 
+  /// Whether the plugin implements `generate_bundle` or `write_bundle`, which see the code of chunks. Such plugins
+  /// can't be used with `streamWrites`, since code of chunks is dropped once written.
+  fn has_output_hooks(&self) -> bool {
+    false
+  }
+
   #[allow(clippy::ptr_arg)]
   async fn generate_bundle(
     &self,
@@ -1,6 +1,6 @@
 use std::sync::{Arc, Weak};
 
-use rolldown_common::ResolvedRequestInfo;
+use rolldown_common::{Log, LogLevel, ResolvedRequestInfo};
 use rolldown_resolver::{ResolveError, Resolver};
 
 use crate::{
@@ -12,6 +12,7 @@ pub type SharedPluginContext = std::sync::Arc<PluginContext>;
 
 #[derive(Debug)]
 pub struct PluginContext {
+  pub(crate) plugin_name: String,
   pub(crate) resolver: Arc<Resolver>,
   pub(crate) plugin_driver: Weak<PluginDriver>,
 }
@@ -33,8 +34,51 @@ impl PluginContext {
       &plugin_driver,
       specifier,
       importer,
-      HookResolveIdExtraOptions { is_entry: false, kind: extra_options.import_kind },
+      HookResolveIdExtraOptions {
+        is_entry: false,
+        kind: extra_options.import_kind,
+        attributes: vec![],
+      },
     )
     .await
   }
+
+  /// Emit a `PLUGIN_WARNING` log, which is passed to `onLog` or returned as a warning of the build.
+  pub fn warn(&self, message: impl Into<String>) {
+    self.log(LogLevel::Warn, "PLUGIN_WARNING", message.into());
+  }
+
+  /// Emit a `PLUGIN_LOG` log of the `info` level, which is only passed to `onLog`.
+  pub fn info(&self, message: impl Into<String>) {
+    self.log(LogLevel::Info, "PLUGIN_LOG", message.into());
+  }
+
+  /// Emit a `PLUGIN_LOG` log of the `debug` level, which is only passed to `onLog`.
+  pub fn debug(&self, message: impl Into<String>) {
+    self.log(LogLevel::Debug, "PLUGIN_LOG", message.into());
+  }
+
+  fn log(&self, level: LogLevel, code: &str, message: String) {
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.add_log(
+        level,
+        Log {
+          code: code.to_string(),
+          message,
+          frame: None,
+          ids: None,
+          plugin: Some(self.plugin_name.clone()),
+          loc: None,
+          help: None,
+        },
+      );
+    }
+  }
+
+  /// Watch the file in watch mode, even if it's not part of the module graph.
+  pub fn add_watch_file(&self, file: &str) {
+    if let Some(plugin_driver) = self.plugin_driver.upgrade() {
+      plugin_driver.add_watch_file(file);
+    }
+  }
 }
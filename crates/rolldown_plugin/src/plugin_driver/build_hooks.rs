@@ -2,7 +2,8 @@ use std::sync::Arc;
 
 use crate::{
   HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveDynamicImportArgs,
-  HookResolveIdArgs, HookResolveIdReturn, HookTransformArgs, PluginDriver, TransformPluginContext,
+  HookResolveIdArgs, HookResolveIdReturn, HookShouldTransformCachedModuleArgs, HookTransformArgs,
+  HookWatchChangeArgs, PluginDriver, TransformPluginContext,
 };
 use anyhow::Result;
 use rolldown_common::{side_effects::HookSideEffects, ModuleInfo};
@@ -15,7 +16,13 @@ impl PluginDriver {
     let ret = {
       #[cfg(not(target_arch = "wasm32"))]
       {
-        block_on_spawn_all(self.plugins.iter().map(|(plugin, ctx)| plugin.build_start(ctx))).await
+        block_on_spawn_all(
+          self
+            .plugins
+            .iter()
+            .map(|(plugin, ctx)| self.timed(plugin, "buildStart", plugin.build_start(ctx))),
+        )
+        .await
       }
       #[cfg(target_arch = "wasm32")]
       {
@@ -25,7 +32,7 @@ impl PluginDriver {
         // I guess we need some rust experts here.
         let mut futures = vec![];
         for (plugin, ctx) in &self.plugins {
-          futures.push(plugin.build_start(ctx));
+          futures.push(self.timed(plugin, "buildStart", plugin.build_start(ctx)));
         }
         block_on_spawn_all(futures.into_iter()).await
       }
@@ -40,7 +47,7 @@ impl PluginDriver {
 
   pub async fn resolve_id(&self, args: &HookResolveIdArgs<'_>) -> HookResolveIdReturn {
     for (plugin, ctx) in &self.plugins {
-      if let Some(r) = plugin.resolve_id(ctx, args).await? {
+      if let Some(r) = self.timed(plugin, "resolveId", plugin.resolve_id(ctx, args)).await? {
         return Ok(Some(r));
       }
     }
@@ -54,7 +61,9 @@ impl PluginDriver {
     args: &HookResolveDynamicImportArgs<'_>,
   ) -> HookResolveIdReturn {
     for (plugin, ctx) in &self.plugins {
-      if let Some(r) = plugin.resolve_dynamic_import(ctx, args).await? {
+      if let Some(r) =
+        self.timed(plugin, "resolveDynamicImport", plugin.resolve_dynamic_import(ctx, args)).await?
+      {
         return Ok(Some(r));
       }
     }
@@ -63,7 +72,7 @@ impl PluginDriver {
 
   pub async fn load(&self, args: &HookLoadArgs<'_>) -> HookLoadReturn {
     for (plugin, ctx) in &self.plugins {
-      if let Some(r) = plugin.load(ctx, args).await? {
+      if let Some(r) = self.timed(plugin, "load", plugin.load(ctx, args)).await? {
         return Ok(Some(r));
       }
     }
@@ -80,10 +89,13 @@ impl PluginDriver {
   ) -> Result<String> {
     let mut code = args.code.to_string();
     for (plugin, ctx) in &self.plugins {
-      if let Some(r) = plugin
-        .transform(
-          &TransformPluginContext::new(Arc::clone(ctx), sourcemap_chain, original_code, args.id),
-          &HookTransformArgs { id: args.id, code: &code },
+      let transform_ctx =
+        TransformPluginContext::new(Arc::clone(ctx), sourcemap_chain, original_code, args.id);
+      if let Some(r) = self
+        .timed(
+          plugin,
+          "transform",
+          plugin.transform(&transform_ctx, &HookTransformArgs { id: args.id, code: &code }),
         )
         .await?
       {
@@ -109,14 +121,44 @@ impl PluginDriver {
 
   pub async fn module_parsed(&self, module_info: Arc<ModuleInfo>) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.module_parsed(ctx, Arc::clone(&module_info)).await?;
+      self
+        .timed(plugin, "moduleParsed", plugin.module_parsed(ctx, Arc::clone(&module_info)))
+        .await?;
     }
     Ok(())
   }
 
   pub async fn build_end(&self, args: Option<&HookBuildEndArgs>) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.build_end(ctx, args).await?;
+      self.timed(plugin, "buildEnd", plugin.build_end(ctx, args)).await?;
+    }
+    Ok(())
+  }
+
+  /// Returns `true` if any plugin asks to transform the cached module again.
+  pub async fn should_transform_cached_module(
+    &self,
+    args: &HookShouldTransformCachedModuleArgs<'_>,
+  ) -> Result<bool> {
+    for (plugin, ctx) in &self.plugins {
+      if self
+        .timed(
+          plugin,
+          "shouldTransformCachedModule",
+          plugin.should_transform_cached_module(ctx, args),
+        )
+        .await?
+        == Some(true)
+      {
+        return Ok(true);
+      }
+    }
+    Ok(false)
+  }
+
+  pub async fn watch_change(&self, args: &HookWatchChangeArgs<'_>) -> HookNoopReturn {
+    for (plugin, ctx) in &self.plugins {
+      self.timed(plugin, "watchChange", plugin.watch_change(ctx, args)).await?;
     }
     Ok(())
   }
@@ -1,6 +1,15 @@
-use std::sync::{Arc, Weak};
+use std::{
+  future::Future,
+  sync::{Arc, Mutex, Weak},
+  time::Instant,
+};
 
+use rolldown_common::{Log, LogLevel};
 use rolldown_resolver::Resolver;
+use rolldown_utils::{
+  indexmap::{FxIndexMap, FxIndexSet},
+  profiler::SharedProfiler,
+};
 
 use crate::{plugin_context::SharedPluginContext, BoxPlugin, PluginContext};
 
@@ -11,17 +20,30 @@ pub type SharedPluginDriver = Arc<PluginDriver>;
 
 pub struct PluginDriver {
   plugins: Vec<(BoxPlugin, SharedPluginContext)>,
+  /// Files whose changes trigger rebuilds in watch mode, mapped to ids of modules depending on them. Modules depend on
+  /// their own files and files added by `this.addWatchFile` in their `transform` hooks.
+  watch_files: Mutex<FxIndexMap<String, FxIndexSet<String>>>,
+  /// Only exists with `profile`.
+  profiler: Option<SharedProfiler>,
+  /// Logs emitted by plugins since they were last taken
+  logs: Mutex<Vec<(LogLevel, Log)>>,
 }
 
 impl PluginDriver {
-  pub fn new_shared(plugins: Vec<BoxPlugin>, resolver: &Arc<Resolver>) -> SharedPluginDriver {
+  pub fn new_shared(
+    plugins: Vec<BoxPlugin>,
+    resolver: &Arc<Resolver>,
+    profiler: Option<SharedProfiler>,
+  ) -> SharedPluginDriver {
     Arc::new_cyclic(|plugin_driver| {
       let with_context = plugins
         .into_iter()
         .map(|plugin| {
+          let plugin_name = plugin.name().to_string();
           (
             plugin,
             PluginContext {
+              plugin_name,
               plugin_driver: Weak::clone(plugin_driver),
               resolver: Arc::clone(resolver),
             }
@@ -30,7 +52,86 @@ impl PluginDriver {
         })
         .collect::<Vec<_>>();
 
-      Self { plugins: with_context }
+      Self {
+        plugins: with_context,
+        watch_files: Mutex::default(),
+        profiler,
+        logs: Mutex::default(),
+      }
     })
   }
+
+  /// Run the hook of the plugin, recording how long it takes with `profile`.
+  async fn timed<T>(
+    &self,
+    plugin: &BoxPlugin,
+    hook: &'static str,
+    fut: impl Future<Output = T>,
+  ) -> T {
+    let Some(profiler) = &self.profiler else {
+      return fut.await;
+    };
+    let start = Instant::now();
+    let ret = fut.await;
+    profiler.record("hook", format!("{}:{hook}", plugin.name()), start);
+    ret
+  }
+
+  pub fn add_log(&self, level: LogLevel, log: Log) {
+    self.logs.lock().expect("Plugin logs should not be poisoned").push((level, log));
+  }
+
+  /// Logs emitted by plugins in the order they were emitted, leaving none behind for the next build.
+  pub fn take_logs(&self) -> Vec<(LogLevel, Log)> {
+    std::mem::take(&mut *self.logs.lock().expect("Plugin logs should not be poisoned"))
+  }
+
+  pub fn add_watch_file(&self, file: &str) {
+    let mut watch_files = self.watch_files.lock().expect("Watch files should not be poisoned");
+    if !watch_files.contains_key(file) {
+      watch_files.insert(file.to_string(), FxIndexSet::default());
+    }
+  }
+
+  pub fn add_module_watch_file(&self, module_id: &str, file: &str) {
+    let mut watch_files = self.watch_files.lock().expect("Watch files should not be poisoned");
+    let dependents = watch_files.entry(file.to_string()).or_default();
+    if !dependents.contains(module_id) {
+      dependents.insert(module_id.to_string());
+    }
+  }
+
+  pub fn watch_files(&self) -> Vec<String> {
+    self.watch_files.lock().expect("Watch files should not be poisoned").keys().cloned().collect()
+  }
+
+  /// Forget modules that aren't kept, along with files only they depend on. Files added outside of `transform` hooks
+  /// don't belong to any module, so they're kept.
+  pub fn retain_watch_files(&self, is_module_kept: impl Fn(&str) -> bool) {
+    let mut watch_files = self.watch_files.lock().expect("Watch files should not be poisoned");
+    watch_files.retain(|_, dependents| {
+      if dependents.is_empty() {
+        return true;
+      }
+      dependents.retain(|module_id| is_module_kept(module_id));
+      !dependents.is_empty()
+    });
+  }
+
+  /// Files the module depends on, besides its own file.
+  pub fn watch_files_of(&self, module_id: &str) -> Vec<String> {
+    let own_file = module_id.split_once('?').map_or(module_id, |(path, _query)| path);
+    let watch_files = self.watch_files.lock().expect("Watch files should not be poisoned");
+    watch_files
+      .iter()
+      .filter(|(file, dependents)| *file != own_file && dependents.contains(module_id))
+      .map(|(file, _)| file.clone())
+      .collect()
+  }
+
+  /// Ids of modules that need to be rebuilt if the file changes.
+  pub fn modules_depending_on(&self, file: &str) -> Vec<String> {
+    let watch_files = self.watch_files.lock().expect("Watch files should not be poisoned");
+    watch_files.get(file).map(|dependents| dependents.iter().cloned().collect()).unwrap_or_default()
+  }
 }
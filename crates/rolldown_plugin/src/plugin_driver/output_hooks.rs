@@ -8,7 +8,7 @@ use rolldown_sourcemap::SourceMap;
 impl PluginDriver {
   pub async fn render_start(&self) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.render_start(ctx).await?;
+      self.timed(plugin, "renderStart", plugin.render_start(ctx)).await?;
     }
     Ok(())
   }
@@ -19,7 +19,7 @@ impl PluginDriver {
   ) -> Result<(String, Vec<SourceMap>)> {
     let mut sourcemap_chain = vec![];
     for (plugin, ctx) in &self.plugins {
-      if let Some(r) = plugin.render_chunk(ctx, &args).await? {
+      if let Some(r) = self.timed(plugin, "renderChunk", plugin.render_chunk(ctx, &args)).await? {
         args.code = r.code;
         if let Some(map) = r.map {
           sourcemap_chain.push(map);
@@ -32,7 +32,9 @@ impl PluginDriver {
   pub async fn augment_chunk_hash(&self, chunk: &RenderedChunk) -> HookAugmentChunkHashReturn {
     let mut hash = String::new();
     for (plugin, ctx) in &self.plugins {
-      if let Some(plugin_hash) = plugin.augment_chunk_hash(ctx, chunk).await? {
+      if let Some(plugin_hash) =
+        self.timed(plugin, "augmentChunkHash", plugin.augment_chunk_hash(ctx, chunk)).await?
+      {
         hash.push_str(&plugin_hash);
       }
     }
@@ -41,21 +43,21 @@ impl PluginDriver {
 
   pub async fn render_error(&self, args: &HookRenderErrorArgs) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.render_error(ctx, args).await?;
+      self.timed(plugin, "renderError", plugin.render_error(ctx, args)).await?;
     }
     Ok(())
   }
 
   pub async fn generate_bundle(&self, bundle: &mut Vec<Output>, is_write: bool) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.generate_bundle(ctx, bundle, is_write).await?;
+      self.timed(plugin, "generateBundle", plugin.generate_bundle(ctx, bundle, is_write)).await?;
     }
     Ok(())
   }
 
   pub async fn write_bundle(&self, bundle: &mut Vec<Output>) -> HookNoopReturn {
     for (plugin, ctx) in &self.plugins {
-      plugin.write_bundle(ctx, bundle).await?;
+      self.timed(plugin, "writeBundle", plugin.write_bundle(ctx, bundle)).await?;
     }
     Ok(())
   }
@@ -34,6 +34,13 @@ impl<'a> TransformPluginContext<'a> {
     }
   }
 
+  /// Watch the file in watch mode, and rebuild the transformed module if it changes.
+  pub fn add_watch_file(&self, file: &str) {
+    if let Some(plugin_driver) = self.inner.plugin_driver.upgrade() {
+      plugin_driver.add_module_watch_file(self.id, file);
+    }
+  }
+
   fn create_sourcemap(&self) -> SourceMap {
     let magic_string = MagicString::new(self.original_code);
     magic_string.source_map(SourceMapOptions {
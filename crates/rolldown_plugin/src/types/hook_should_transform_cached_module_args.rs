@@ -0,0 +1,6 @@
+#[derive(Debug)]
+pub struct HookShouldTransformCachedModuleArgs<'a> {
+  pub id: &'a str,
+  /// The code of the module transformed in a previous build.
+  pub code: &'a str,
+}
@@ -0,0 +1,5 @@
+#[derive(Debug)]
+pub struct HookWatchChangeArgs<'a> {
+  /// Absolute paths of files changed since the last build, in the order they were first changed.
+  pub changed_files: &'a [String],
+}
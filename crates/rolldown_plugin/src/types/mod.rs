@@ -8,5 +8,7 @@ pub mod hook_resolve_dynamic_import_args;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_extra_options;
 pub mod hook_resolve_id_output;
+pub mod hook_should_transform_cached_module_args;
 pub mod hook_transform_args;
+pub mod hook_watch_change_args;
 pub mod plugin_context_resolve_options;
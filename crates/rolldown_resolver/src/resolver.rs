@@ -3,7 +3,7 @@ use itertools::Itertools;
 use rolldown_common::{
   ImportKind, ModuleType, PackageJson, Platform, ResolveOptions, ResolvedPath,
 };
-use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_fs::{FileSystem, SharedFileSystem};
 use std::{
   path::{Path, PathBuf},
   sync::Arc,
@@ -17,7 +17,7 @@ use oxc_resolver::{
 
 #[derive(Debug)]
 #[allow(dead_code)]
-pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
+pub struct Resolver<T: FileSystem + Default = SharedFileSystem> {
   cwd: PathBuf,
   default_resolver: ResolverGeneric<T>,
   import_resolver: ResolverGeneric<T>,
@@ -92,7 +92,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       extension_alias: vec![],
       extensions: raw_resolve
         .extensions
-        .unwrap_or_else(|| [".jsx", ".js"].into_iter().map(str::to_string).collect()),
+        .unwrap_or_else(|| [".jsx", ".js", ".ts"].into_iter().map(str::to_string).collect()),
       fallback: vec![],
       fully_specified: false,
       main_fields,
@@ -133,6 +133,14 @@ impl<F: FileSystem + Default> Resolver<F> {
   pub fn cwd(&self) -> &PathBuf {
     &self.cwd
   }
+
+  /// Forget cached file system lookups, so changes of files are picked up by the next build.
+  pub fn clear_cache(&self) {
+    self.default_resolver.clear_cache();
+    self.import_resolver.clear_cache();
+    self.require_resolver.clear_cache();
+    self.package_json_cache.clear();
+  }
 }
 
 #[derive(Debug)]
@@ -1,11 +1,13 @@
 // cSpell:disable
+use std::borrow::Cow;
+
 use oxc::sourcemap::{ConcatSourceMapBuilder, SourceMap};
 
 use crate::lines_count;
 
 pub trait Source {
   fn sourcemap(&self) -> Option<&SourceMap>;
-  fn content(&self) -> &String;
+  fn content(&self) -> &str;
   fn lines_count(&self) -> u32;
   #[allow(clippy::wrong_self_convention)]
   fn into_concat_source(
@@ -16,22 +18,24 @@ pub trait Source {
   );
 }
 
-pub struct RawSource {
-  content: String,
+/// Code without a sourcemap. Borrowed code, such as static snippets and comments kept in modules, is referenced
+/// instead of being copied into owned strings.
+pub struct RawSource<'a> {
+  content: Cow<'a, str>,
 }
 
-impl RawSource {
-  pub fn new(content: String) -> Self {
-    Self { content }
+impl<'a> RawSource<'a> {
+  pub fn new(content: impl Into<Cow<'a, str>>) -> Self {
+    Self { content: content.into() }
   }
 }
 
-impl Source for RawSource {
+impl<'a> Source for RawSource<'a> {
   fn sourcemap(&self) -> Option<&SourceMap> {
     None
   }
 
-  fn content(&self) -> &String {
+  fn content(&self) -> &str {
     &self.content
   }
 
@@ -49,24 +53,24 @@ impl Source for RawSource {
   }
 }
 
-pub struct SourceMapSource {
-  content: String,
+pub struct SourceMapSource<'a> {
+  content: Cow<'a, str>,
   sourcemap: SourceMap,
   lines_count: u32,
 }
 
-impl SourceMapSource {
-  pub fn new(content: String, sourcemap: SourceMap, lines_count: u32) -> Self {
-    Self { content, sourcemap, lines_count }
+impl<'a> SourceMapSource<'a> {
+  pub fn new(content: impl Into<Cow<'a, str>>, sourcemap: SourceMap, lines_count: u32) -> Self {
+    Self { content: content.into(), sourcemap, lines_count }
   }
 }
 
-impl Source for SourceMapSource {
+impl<'a> Source for SourceMapSource<'a> {
   fn sourcemap(&self) -> Option<&SourceMap> {
     Some(&self.sourcemap)
   }
 
-  fn content(&self) -> &String {
+  fn content(&self) -> &str {
     &self.content
   }
 
@@ -89,31 +93,40 @@ impl Source for SourceMapSource {
   }
 }
 
+/// A rope of sources joined by newlines. Sources are either owned or borrowed, and stay separate pieces until the
+/// content is needed.
 #[derive(Default)]
-pub struct ConcatSource {
-  inner: Vec<Box<dyn Source + Send>>,
-  prepend_source: Vec<Box<dyn Source + Send>>,
+pub struct ConcatSource<'a> {
+  inner: Vec<Box<dyn Source + Send + 'a>>,
+  prepend_source: Vec<Box<dyn Source + Send + 'a>>,
   enable_sourcemap: bool,
 }
 
-impl ConcatSource {
-  pub fn add_source(&mut self, source: Box<dyn Source + Send>) {
+impl<'a> ConcatSource<'a> {
+  pub fn add_source(&mut self, source: Box<dyn Source + Send + 'a>) {
     if source.sourcemap().is_some() {
       self.enable_sourcemap = true;
     }
     self.inner.push(source);
   }
 
-  pub fn add_prepend_source(&mut self, source: Box<dyn Source + Send>) {
+  pub fn add_prepend_source(&mut self, source: Box<dyn Source + Send + 'a>) {
     if source.sourcemap().is_some() {
       self.enable_sourcemap = true;
     }
     self.prepend_source.push(source);
   }
 
+  /// Copy the sources once into a string of the final size.
   #[allow(clippy::cast_possible_truncation)]
   pub fn content_and_sourcemap(self) -> (String, Option<SourceMap>) {
-    let mut final_source = String::new();
+    let final_len = self
+      .prepend_source
+      .iter()
+      .chain(self.inner.iter())
+      .map(|source| source.content().len() + 1)
+      .sum::<usize>();
+    let mut final_source = String::with_capacity(final_len);
     let mut sourcemap_builder = self.enable_sourcemap.then_some(ConcatSourceMapBuilder::default());
     let mut line_offset = 0;
     let source_len = self.prepend_source.len() + self.inner.len();
@@ -168,4 +181,19 @@ mod tests {
     let expected = "{\"version\":3,\"names\":[],\"sources\":[\"index.ts\"],\"sourcesContent\":[\"function sayHello(name: string) {\\n  console.log(`Hello, ${name}`);\\n}\\n\"],\"mappings\":\";;;AAAA,SAAS,QAAQ,CAAC,IAAY;IAC5B,OAAO,CAAC,GAAG,CAAC,iBAAU,IAAI,CAAE,CAAC,CAAC;AAChC,CAAC\"}";
     assert_eq!(map, expected);
   }
+
+  #[test]
+  fn concat_borrowed_sources() {
+    let comments = vec!["/*! a */".to_string(), "/*! b */".to_string()];
+    let mut concat_source = ConcatSource::default();
+    for comment in &comments {
+      concat_source.add_source(Box::new(RawSource::new(comment.as_str())));
+    }
+    concat_source.add_source(Box::new(RawSource::new("console.log()".to_string())));
+    concat_source.add_prepend_source(Box::new(RawSource::new("// banner")));
+
+    let (content, map) = concat_source.content_and_sourcemap();
+    assert_eq!(content, "// banner\n/*! a */\n/*! b */\nconsole.log()");
+    assert!(map.is_none());
+  }
 }
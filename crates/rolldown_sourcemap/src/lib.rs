@@ -51,6 +51,64 @@ pub fn collapse_sourcemaps(mut sourcemap_chain: Vec<&SourceMap>) -> Option<Sourc
   Some(sourcemap_builder.into_sourcemap())
 }
 
+/// Keep only the first mapping of each generated line and drop columns and names. Such sourcemaps are much cheaper to
+/// build and concatenate, while still pointing at the right lines of the original sources.
+pub fn to_line_only_sourcemap(sourcemap: &SourceMap) -> SourceMap {
+  let mut sourcemap_builder = SourceMapBuilder::default();
+  let mut last_dst_line = None;
+
+  for token in sourcemap.get_source_view_tokens() {
+    if last_dst_line == Some(token.get_dst_line()) {
+      continue;
+    }
+    let Some((source, source_content)) = token.get_source_and_content() else {
+      continue;
+    };
+    last_dst_line = Some(token.get_dst_line());
+    let source_id = sourcemap_builder.add_source_and_content(source, source_content);
+    sourcemap_builder.add_token(
+      token.get_dst_line(),
+      0,
+      token.get_src_line(),
+      0,
+      Some(source_id),
+      None,
+    );
+  }
+
+  sourcemap_builder.into_sourcemap()
+}
+
+/// Move generated columns of mappings after text on a line was replaced with text of a different length. Each shift is
+/// `(line, column, delta)`, and moves mappings at or after `column` of `line` by `delta`.
+pub fn shift_columns(sourcemap: &SourceMap, shifts: &[(u32, u32, i64)]) -> SourceMap {
+  let mut sourcemap_builder = SourceMapBuilder::default();
+
+  for token in sourcemap.get_source_view_tokens() {
+    let (dst_line, dst_col) = (token.get_dst_line(), token.get_dst_col());
+    let delta = shifts
+      .iter()
+      .filter(|(line, column, _)| *line == dst_line && *column <= dst_col)
+      .map(|(_, _, delta)| delta)
+      .sum::<i64>();
+    let dst_col = u32::try_from(i64::from(dst_col) + delta).unwrap_or(0);
+    let source_id = token.get_source_and_content().map(|(source, source_content)| {
+      sourcemap_builder.add_source_and_content(source, source_content)
+    });
+    let name_id = token.get_name().map(|name| sourcemap_builder.add_name(name));
+    sourcemap_builder.add_token(
+      dst_line,
+      dst_col,
+      token.get_src_line(),
+      token.get_src_col(),
+      source_id,
+      name_id,
+    );
+  }
+
+  sourcemap_builder.into_sourcemap()
+}
+
 #[cfg(test)]
 mod tests {
   use crate::SourceMap;
@@ -91,4 +149,30 @@ mod tests {
 
     assert_eq!(&result, expected);
   }
+
+  #[test]
+  fn compose_rewritten_chunk() {
+    use oxc::sourcemap::SourceMapBuilder;
+
+    // `// banner\nfunction add(a, b) {\n  return a + b;\n}` rendered from `add.js`
+    let mut builder = SourceMapBuilder::default();
+    let source_id =
+      builder.add_source_and_content("add.js", "function add(a, b) {\n  return a + b;\n}");
+    builder.add_token(1, 0, 0, 0, Some(source_id), None);
+    builder.add_token(2, 2, 1, 2, Some(source_id), None);
+    let chunk_map = builder.into_sourcemap();
+
+    // Minified to `function add(n,r){return n+r}`
+    let mut builder = SourceMapBuilder::default();
+    let source_id = builder.add_source_and_content("chunk.js", "");
+    builder.add_token(0, 0, 1, 0, Some(source_id), None);
+    builder.add_token(0, 18, 2, 2, Some(source_id), None);
+    let minify_map = builder.into_sourcemap();
+
+    let map = super::collapse_sourcemaps(vec![&chunk_map, &minify_map]).unwrap();
+    let lookup_table = map.generate_lookup_table();
+    let token = map.lookup_source_view_token(&lookup_table, 0, 18).unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (1, 2));
+    assert_eq!(token.get_source_and_content().map(|(source, _)| source), Some("add.js"));
+  }
 }
@@ -1,6 +1,41 @@
 use regex::Regex;
 use std::borrow::Cow;
 
+/// Words that can't be names of variables in strict mode code, besides keywords. ES modules are always strict.
+pub const STRICT_MODE_RESERVED_WORDS: [&str; 11] = [
+  "arguments",
+  "eval",
+  "implements",
+  "interface",
+  "let",
+  "package",
+  "private",
+  "protected",
+  "public",
+  "static",
+  "yield",
+];
+
+/// Words that are reserved in ES3, but not in ES5 and later.
+pub const ES3_RESERVED_WORDS: [&str; 16] = [
+  "abstract",
+  "boolean",
+  "byte",
+  "char",
+  "double",
+  "final",
+  "float",
+  "goto",
+  "int",
+  "long",
+  "native",
+  "short",
+  "synchronized",
+  "throws",
+  "transient",
+  "volatile",
+];
+
 pub fn is_validate_identifier_name(name: &str) -> bool {
   oxc_syntax::identifier::is_identifier_name(name)
 }
@@ -6,8 +6,10 @@ pub mod debug;
 pub mod ecma_script;
 pub mod futures;
 pub mod indexmap;
+pub mod node_builtins;
 pub mod path_buf_ext;
 pub mod path_ext;
+pub mod profiler;
 pub mod rayon;
 pub mod rustc_hash;
 pub mod xxhash;
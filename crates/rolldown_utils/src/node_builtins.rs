@@ -0,0 +1,92 @@
+/// Sorted names of builtin modules of Node.js, which could be imported without the `node:` prefix.
+const NODE_BUILTINS: &[&str] = &[
+  "assert",
+  "assert/strict",
+  "async_hooks",
+  "buffer",
+  "child_process",
+  "cluster",
+  "console",
+  "constants",
+  "crypto",
+  "dgram",
+  "diagnostics_channel",
+  "dns",
+  "dns/promises",
+  "domain",
+  "events",
+  "fs",
+  "fs/promises",
+  "http",
+  "http2",
+  "https",
+  "inspector",
+  "inspector/promises",
+  "module",
+  "net",
+  "os",
+  "path",
+  "path/posix",
+  "path/win32",
+  "perf_hooks",
+  "process",
+  "punycode",
+  "querystring",
+  "readline",
+  "readline/promises",
+  "repl",
+  "stream",
+  "stream/consumers",
+  "stream/promises",
+  "stream/web",
+  "string_decoder",
+  "sys",
+  "timers",
+  "timers/promises",
+  "tls",
+  "trace_events",
+  "tty",
+  "url",
+  "util",
+  "util/types",
+  "v8",
+  "vm",
+  "wasi",
+  "worker_threads",
+  "zlib",
+];
+
+/// Whether the specifier refers to a builtin module of Node.js, such as `fs` or `node:fs`.
+pub fn is_node_builtin(specifier: &str) -> bool {
+  specifier.starts_with("node:") || NODE_BUILTINS.binary_search(&specifier).is_ok()
+}
+
+/// The specifier of a builtin module of Node.js with or without the `node:` prefix. Modules that could only be
+/// imported with the prefix, such as `node:test`, always keep it.
+pub fn node_builtin_specifier(specifier: &str, with_prefix: bool) -> String {
+  let name = specifier.strip_prefix("node:").unwrap_or(specifier);
+  if with_prefix || NODE_BUILTINS.binary_search(&name).is_err() {
+    format!("node:{name}")
+  } else {
+    name.to_string()
+  }
+}
+
+#[test]
+fn test_is_node_builtin() {
+  assert!(NODE_BUILTINS.windows(2).all(|pair| pair[0] < pair[1]));
+  assert!(is_node_builtin("fs"));
+  assert!(is_node_builtin("fs/promises"));
+  assert!(is_node_builtin("node:test"));
+  assert!(!is_node_builtin("fs-extra"));
+  assert!(!is_node_builtin("./fs"));
+}
+
+#[test]
+fn test_node_builtin_specifier() {
+  assert_eq!(node_builtin_specifier("fs", false), "fs");
+  assert_eq!(node_builtin_specifier("node:fs", false), "fs");
+  assert_eq!(node_builtin_specifier("node:test", false), "node:test");
+  assert_eq!(node_builtin_specifier("fs/promises", true), "node:fs/promises");
+  assert_eq!(node_builtin_specifier("node:fs", true), "node:fs");
+}
@@ -0,0 +1,78 @@
+use std::{
+  cell::Cell,
+  sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+  },
+  time::{Duration, Instant},
+};
+
+pub type SharedProfiler = Arc<Profiler>;
+
+/// A span of time spent in a stage of the build or in a plugin hook.
+#[derive(Debug, Clone)]
+pub struct ProfileEvent {
+  /// `stage` for stages of the build, `module` for work on a single module and `hook` for plugin hooks.
+  pub category: &'static str,
+  /// Name of the stage, or `{plugin}:{hook}` for plugin hooks.
+  pub name: String,
+  /// Since the start of the build
+  pub start: Duration,
+  pub duration: Duration,
+  /// Index of the thread the span ended on, for laying out trace events.
+  pub thread: u64,
+}
+
+/// Records how long stages of the build and plugin hooks take. Only exists with `profile`.
+#[derive(Debug)]
+pub struct Profiler {
+  start: Mutex<Instant>,
+  events: Mutex<Vec<ProfileEvent>>,
+}
+
+impl Default for Profiler {
+  fn default() -> Self {
+    Self { start: Mutex::new(Instant::now()), events: Mutex::default() }
+  }
+}
+
+impl Profiler {
+  /// Clear events of the last build, and measure times of the following events from now on.
+  pub fn start_build(&self) {
+    *self.start.lock().expect("Profiler should not be poisoned") = Instant::now();
+    self.events.lock().expect("Profiler should not be poisoned").clear();
+  }
+
+  /// Record a span from `start` to now.
+  pub fn record(&self, category: &'static str, name: impl Into<String>, start: Instant) {
+    let duration = start.elapsed();
+    let build_start = *self.start.lock().expect("Profiler should not be poisoned");
+    let event = ProfileEvent {
+      category,
+      name: name.into(),
+      start: start.saturating_duration_since(build_start),
+      duration,
+      thread: current_thread_index(),
+    };
+    self.events.lock().expect("Profiler should not be poisoned").push(event);
+  }
+
+  /// Events recorded since the last `start_build`, in the order they end.
+  pub fn events(&self) -> Vec<ProfileEvent> {
+    self.events.lock().expect("Profiler should not be poisoned").clone()
+  }
+}
+
+fn current_thread_index() -> u64 {
+  static NEXT_THREAD_INDEX: AtomicU64 = AtomicU64::new(0);
+  thread_local! {
+    static THREAD_INDEX: Cell<Option<u64>> = const { Cell::new(None) };
+  }
+  THREAD_INDEX.with(|index| {
+    index.get().unwrap_or_else(|| {
+      let new_index = NEXT_THREAD_INDEX.fetch_add(1, Ordering::Relaxed);
+      index.set(Some(new_index));
+      new_index
+    })
+  })
+}
@@ -61,6 +61,44 @@ pub use rayon::iter::{
   IntoParallelIterator, IntoParallelRefIterator, ParallelBridge, ParallelIterator,
 };
 
+/// The pool that parallel iterators run on. Without a size, rayon's global pool is used, which has a thread per CPU.
+#[derive(Clone, Default)]
+pub struct ThreadPool {
+  #[cfg(not(target_family = "wasm"))]
+  pool: Option<std::sync::Arc<rayon::ThreadPool>>,
+}
+
+impl ThreadPool {
+  #[cfg_attr(target_family = "wasm", allow(unused_variables))]
+  pub fn new(num_threads: Option<usize>) -> Self {
+    #[cfg(not(target_family = "wasm"))]
+    {
+      let pool = num_threads.map(|num_threads| {
+        std::sync::Arc::new(
+          rayon::ThreadPoolBuilder::new()
+            .num_threads(num_threads)
+            .build()
+            .expect("Failed to build the thread pool"),
+        )
+      });
+      Self { pool }
+    }
+    #[cfg(target_family = "wasm")]
+    {
+      Self {}
+    }
+  }
+
+  /// Run `op` in the pool, so parallel iterators called in it use the pool.
+  pub fn install<R: Send>(&self, op: impl FnOnce() -> R + Send) -> R {
+    #[cfg(not(target_family = "wasm"))]
+    if let Some(pool) = &self.pool {
+      return pool.install(op);
+    }
+    op()
+  }
+}
+
 fn _usages() {
   let mut demo = vec![1, 2, 3, 4, 5];
   demo.iter().par_bridge().for_each(|_| {});
@@ -70,3 +108,22 @@ fn _usages() {
   // demo.par_iter_mut().for_each(|_| {});
   demo.clone().into_par_iter().for_each(|_| {});
 }
+
+#[cfg(not(target_family = "wasm"))]
+#[test]
+fn test_thread_pool() {
+  // Parallel iterators run in the pool, which has the given number of threads.
+  let threads = ThreadPool::new(Some(2)).install(|| {
+    (0..100)
+      .into_par_iter()
+      .map(|_| rayon::current_thread_index().expect("Should run in the pool"))
+      .collect::<std::collections::HashSet<_>>()
+  });
+  assert!(!threads.is_empty() && threads.iter().all(|index| *index < 2), "{threads:?}");
+  assert_eq!(ThreadPool::new(Some(2)).install(rayon::current_num_threads), 2);
+  // Without a size, the global pool is used.
+  assert_eq!(
+    ThreadPool::new(None).install(rayon::current_num_threads),
+    rayon::current_num_threads()
+  );
+}
@@ -1,11 +1,6 @@
 type MaybePromise<T> = T | Promise<T>
 type Nullable<T> = T | null | undefined
 type VoidNullable<T = void> = T | null | undefined | void
-export class BindingLog {
-  code: string
-  message: string
-}
-
 export class BindingModuleInfo {
   id: string
   importers: Array<string>
@@ -13,13 +8,14 @@ export class BindingModuleInfo {
   importedIds: Array<string>
   dynamicallyImportedIds: Array<string>
   isEntry: boolean
+  exports: Array<string>
   get code(): string | null
 }
 
 export class BindingOutputAsset {
   get fileName(): string
-  get source(): string
-  set source(source: string)
+  get source(): string | Buffer
+  set source(source: string | Buffer)
 }
 
 export class BindingOutputChunk {
@@ -38,6 +34,7 @@ export class BindingOutputChunk {
   get map(): string | null
   set map(map: string)
   get sourcemapFileName(): string | null
+  get cssFileName(): string | null
   get preliminaryFileName(): string
 }
 
@@ -50,18 +47,44 @@ export class BindingOutputs {
 
 export class BindingPluginContext {
   resolve(specifier: string, importer?: string | undefined | null, extraOptions?: BindingPluginContextResolveOptions | undefined | null): Promise<BindingPluginContextResolvedId | null>
+  addWatchFile(file: string): void
 }
 
 export class BindingTransformPluginContext {
   getCombinedSourcemap(): string
+  addWatchFile(file: string): void
   inner(): BindingPluginContext
 }
 
+export class BindingWatcher {
+  constructor(inputOptions: BindingInputOptions, outputOptions: BindingOutputOptions, parallelPluginsRegistry?: ParallelJsPluginRegistry | undefined | null)
+  /**
+   * Build, then rebuild whenever watched files change, calling `listener` with events of each build. The returned
+   * promise doesn't resolve while watching.
+   */
+  /**
+   * Bundle the module with `id`, which proxy modules of `lazyCompilation` pass to `__rolldown_lazy__.load(id)`, as
+   * usual since the next build. Watching rebuilds once a module is requested for the first time.
+   */
+  requestLazyCompilation(id: string): void
+  start(listener: (event: BindingWatcherEvent) => void): Promise<void>
+}
+
 export class Bundler {
   constructor(inputOptions: BindingInputOptions, outputOptions: BindingOutputOptions, parallelPluginsRegistry?: ParallelJsPluginRegistry | undefined | null)
   write(): Promise<FinalBindingOutputs>
   generate(): Promise<FinalBindingOutputs>
   scan(): Promise<void>
+  why(id: string): Promise<Array<Array<string>>>
+  /** Ids of modules in the module graph of the last build. */
+  getModuleIds(): Array<string>
+  /** Imports, importers and exports of the module with `id` in the module graph of the last build. */
+  getModuleInfo(id: string): BindingModuleInfo | null
+  /**
+   * Bundle the module with `id`, which proxy modules of `lazyCompilation` pass to `__rolldown_lazy__.load(id)`, as
+   * usual since the next build.
+   */
+  requestLazyCompilation(id: string): void
 }
 
 /**
@@ -84,12 +107,60 @@ export interface AliasItem {
   replacements: Array<string>
 }
 
+/**
+ * A plugin implemented in Rust, which takes the place of the `undefined` placeholder at `index` of `plugins`, so it
+ * runs in order with Js plugins around it.
+ */
+export interface BindingBuiltinPlugin {
+  index: number
+  name: 'builtin:replace'
+  /** Options of `builtin:replace` */
+  replace?: BindingReplacePluginOptions
+}
+
+export interface BindingChecksOptions {
+  topLevelAwait?: 'error' | 'warn'
+  importMeta?: 'error' | 'warn'
+  circularChunks?: 'error' | 'warn'
+}
+
+export interface BindingCircularDependencyOptions {
+  exclude?: Array<string>
+  error?: boolean
+}
+
+export interface BindingCssModulesOptions {
+  scopedName?: string
+  localsConvention?: 'asIs' | 'camelCase' | 'camelCaseOnly' | 'dashes' | 'dashesOnly'
+}
+
+export interface BindingEvalWarningOptions {
+  exclude?: Array<string>
+}
+
+/**
+ * Send `code` to the browser and run it to apply the update. It's written to `filename` along with other chunks, unless
+ * `watch.skipWrite` is enabled.
+ */
+export interface BindingHmrUpdate {
+  filename: string
+  code: string
+  /** Stable ids of updated modules, as `import.meta.hot` refers to. */
+  moduleIds: Array<string>
+}
+
 export interface BindingHookLoadOutput {
   code: string
   map?: string
   sideEffects?: BindingHookSideEffects
 }
 
+/** The result of loading one module of a batch. A hook that throws only fails the load of its own module. */
+export interface BindingHookLoadResult {
+  output?: BindingHookLoadOutput
+  error?: string
+}
+
 export interface BindingHookRenderChunkOutput {
   code: string
   map?: string
@@ -98,6 +169,7 @@ export interface BindingHookRenderChunkOutput {
 export interface BindingHookResolveIdExtraOptions {
   isEntry: boolean
   kind: string
+  attributes: Record<string, string>
 }
 
 export interface BindingHookResolveIdOutput {
@@ -119,16 +191,65 @@ export interface BindingInputItem {
 
 export interface BindingInputOptions {
   external?: undefined | ((source: string, importer: string | undefined, isResolved: boolean) => boolean)
+  /** Strings listed in `external`, which are warned about if no import matches them. */
+  externalIds?: Array<string>
   input: Array<BindingInputItem>
+  stdin?: BindingStdinOptions
   plugins: Array<BindingPluginOrParallelJsPluginPlaceholder>
+  /** Plugins implemented in Rust, placed at their indexes of `plugins`. */
+  builtinPlugins?: Array<BindingBuiltinPlugin>
   resolve?: BindingResolveOptions
   shimMissingExports?: boolean
   platform?: 'node' | 'browser' | 'neutral'
+  nodeProtocol?: boolean
+  drop?: Array<'console' | 'debugger'>
+  pure?: Array<string>
+  define?: Record<string, string>
+  env?: Record<string, string>
+  strictRequires?: boolean
+  requireReturnsDefault?: 'true' | 'false' | 'auto' | 'preferred'
+  dynamicRequireTargets?: Array<string>
+  unresolvedRequire?: 'error' | 'keep' | 'stub'
+  inlineConstEnums?: boolean
+  assetsInlineLimit?: (file: string, content: Buffer) => boolean | undefined
+  cssModules?: BindingCssModulesOptions
+  circularDependency?: BindingCircularDependencyOptions
+  evalWarning?: BindingEvalWarningOptions
+  checks?: BindingChecksOptions
+  moduleTypes?: Record<string, 'js' | 'jsx' | 'ts' | 'tsx' | 'json' | 'text' | 'base64' | 'dataurl' | 'binary' | 'empty' | 'asset' | 'css' | 'wasm' | 'html'>
+  publicDir?: string
+  watch?: BindingWatchOptions
+  hmr?: boolean
+  lazyCompilation?: boolean
+  cacheDir?: string
+  threads?: number
+  maxParallelFileOps?: number
+  /** Maximum number of calls of `resolveId`, `load` and `transform` hooks of JS plugins in flight. Defaults to `128`. */
+  maxParallelJsHooks?: number
+  profile?: boolean
+  profileTraceFile?: string
   logLevel?: BindingLogLevel
+  collapseWarnings?: boolean
   onLog: (logLevel: 'debug' | 'warn' | 'info', log: BindingLog) => void
   cwd: string
 }
 
+/** A plain object, so it could be serialized with `JSON.stringify`. */
+export interface BindingLog {
+  /** Stable code of the log, such as `UNRESOLVED_IMPORT`. */
+  code: string
+  message: string
+  /** The message with excerpts of the code it's about, as printed to the terminal. Colored if stderr supports colors. */
+  frame?: string
+  /** Ids of modules the log is about, such as the modules of an import cycle. */
+  ids?: Array<string>
+  /** Name of the plugin that emitted the log. */
+  plugin?: string
+  loc?: BindingLogLocation
+  /** How to fix the problem, if it could be guessed. */
+  help?: string
+}
+
 export enum BindingLogLevel {
   Silent = 0,
   Warn = 1,
@@ -136,18 +257,47 @@ export enum BindingLogLevel {
   Debug = 3
 }
 
+export interface BindingLogLocation {
+  /** Relative to `cwd` */
+  file: string
+  /** Starts from 1 */
+  line: number
+  /** Starts from 0 */
+  column: number
+}
+
 export interface BindingOutputOptions {
   entryFileNames?: string
   chunkFileNames?: string
+  assetFileNames?: string
+  sourcemapFileNames?: string
+  charset?: 'ascii' | 'utf8'
+  legalComments?: 'inline' | 'eof' | 'linked' | 'external' | 'none'
+  cssMinify?: boolean
+  minify?: boolean
+  streamWrites?: boolean
+  hashCharacters?: 'base64' | 'base36' | 'hex'
+  hashAlgorithm?: 'xxhash3' | 'blake3'
+  hashSalt?: string
   banner?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   dir?: string
   exports?: 'default' | 'named' | 'none' | 'auto'
   footer?: (chunk: RenderedChunk) => MaybePromise<VoidNullable<string>>
   format?: 'esm' | 'cjs'
   plugins: Array<BindingPluginOrParallelJsPluginPlaceholder>
-  sourcemap?: 'file' | 'inline' | 'hidden'
+  sourcemap?: 'file' | 'inline' | 'hidden' | 'cheap'
+  sourcemapDebugIds?: boolean
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
+  validate?: boolean
+  reservedNames?: Array<string>
+  es3SafeIdentifiers?: boolean
+  dts?: boolean
+  runtimeChunk?: boolean
+  minifyInternalExports?: boolean
+  metafile?: string
+  reportCompressedSize?: boolean
+  maxChunkSize?: number
 }
 
 export interface BindingPluginContextResolvedId {
@@ -161,10 +311,12 @@ export interface BindingPluginContextResolveOptions {
 
 export interface BindingPluginOptions {
   name: string
+  /** Identifies the plugin and its options in `cacheDir`. Defaults to the name. */
+  cacheKey?: string
   buildStart?: (ctx: BindingPluginContext) => MaybePromise<VoidNullable>
   resolveId?: (ctx: BindingPluginContext, specifier: string, importer: Nullable<string>, options: BindingHookResolveIdExtraOptions) => MaybePromise<VoidNullable<BindingHookResolveIdOutput>>
   resolveDynamicImport?: (ctx: BindingPluginContext, specifier: string, importer: Nullable<string>) => MaybePromise<VoidNullable<BindingHookResolveIdOutput>>
-  load?: (ctx: BindingPluginContext, id: string) => MaybePromise<VoidNullable<BindingHookLoadOutput>>
+  load?: (ctx: BindingPluginContext, ids: Array<string>) => MaybePromise<Array<BindingHookLoadResult>>
   transform?: (ctx:  BindingTransformPluginContext, id: string, code: string) => MaybePromise<VoidNullable<BindingHookLoadOutput>>
   moduleParsed?: (ctx: BindingPluginContext, module: BindingModuleInfo) => MaybePromise<VoidNullable>
   buildEnd?: (ctx: BindingPluginContext, error: Nullable<string>) => MaybePromise<VoidNullable>
@@ -185,6 +337,10 @@ export interface BindingRenderedModule {
   code?: string
 }
 
+export interface BindingReplacePluginOptions {
+  values: Record<string, string>
+}
+
 export interface BindingResolveOptions {
   alias?: Array<AliasItem>
   aliasFields?: Array<Array<string>>
@@ -198,6 +354,40 @@ export interface BindingResolveOptions {
   tsconfigFilename?: string
 }
 
+export interface BindingStdinOptions {
+  code: string
+  resolveDir?: string
+  loader?: 'js' | 'jsx' | 'ts' | 'tsx' | 'json' | 'text' | 'base64' | 'dataurl' | 'binary' | 'empty' | 'asset' | 'css' | 'wasm' | 'html'
+}
+
+export interface BindingWatchOptions {
+  include?: Array<string>
+  exclude?: Array<string>
+  buildDelay?: number
+  skipWrite?: boolean
+  poll?: BindingWatchPollOptions
+}
+
+export interface BindingWatchPollOptions {
+  interval?: number
+}
+
+export interface BindingWatcherEvent {
+  code: 'BUNDLE_START' | 'BUNDLE_END' | 'ERROR' | 'HMR_UPDATE' | 'RESTART'
+  /** Files changed since the last build, for `BUNDLE_START` of rebuilds. */
+  changedFiles?: Array<string>
+  /** Milliseconds the build took, for `BUNDLE_END`. */
+  duration?: number
+  /** Number of errors of the build, for `BUNDLE_END`. Diagnostics of the build are printed to stderr already. */
+  errorCount?: number
+  /** Number of warnings of the build, for `BUNDLE_END`. */
+  warningCount?: number
+  /** The error that stopped the build, for `ERROR`. */
+  error?: string
+  /** The update chunk of modules changed since the last build, for `HMR_UPDATE`. */
+  hmrUpdate?: BindingHmrUpdate
+}
+
 export function registerPlugins(id: number, plugins: PluginsInSingleWorker): void
 
 export interface RenderedChunk {
@@ -1,11 +1,25 @@
 import { performance } from 'node:perf_hooks'
 import { rolldown } from '@src/rolldown'
 import type { RolldownOptions, RolldownOutput, RollupOutput } from '../../index'
+import type { OutputOptions } from '../../options/output-options'
 import { arraify } from '../../utils/index'
 import { ensureConfig, logger } from '../utils'
 import * as colors from '../colors'
 
-export async function bundle(configPath: string) {
+export interface BundleCliOptions {
+  profile?: boolean
+  maxChunkSize?: number
+  format?: string
+  dir?: string
+  sourcemap?: boolean
+  minify?: boolean
+  env?: Record<string, string>
+}
+
+export async function bundle(
+  configPath: string,
+  cliOptions: BundleCliOptions = {},
+) {
   const config = await ensureConfig(configPath)
 
   if (!config) {
@@ -15,9 +29,50 @@ export async function bundle(configPath: string) {
 
   const configList = arraify(config)
 
-  for (const config of configList) {
-    await bundleInner(config)
+  for (let config of configList) {
+    if (cliOptions.profile) {
+      config = { ...config, profile: true }
+    }
+    if (cliOptions.env) {
+      config = { ...config, env: { ...config.env, ...cliOptions.env } }
+    }
+    config = {
+      ...config,
+      output: { ...config.output, ...outputOptions(cliOptions) },
+    }
+    try {
+      await bundleInner(config)
+    } catch (err) {
+      // Diagnostics of the build, with code frames, are already printed to stderr
+      logger.error(err instanceof Error ? err.message : err)
+      process.exit(1)
+    }
+  }
+}
+
+/**
+ * Output options set by CLI arguments, which override the ones in the config
+ */
+export function outputOptions(cliOptions: BundleCliOptions): OutputOptions {
+  const options: OutputOptions = {}
+  if (cliOptions.format !== undefined) {
+    options.format = cliOptions.format as OutputOptions['format']
+  }
+  if (cliOptions.dir !== undefined) {
+    options.dir = cliOptions.dir
+  }
+  if (cliOptions.sourcemap) {
+    options.sourcemap = true
+  }
+  if (cliOptions.minify) {
+    options.minify = true
+    options.cssMinify = true
+  }
+  if (cliOptions.maxChunkSize !== undefined) {
+    options.maxChunkSize = cliOptions.maxChunkSize
+    options.reportCompressedSize = true
   }
+  return options
 }
 
 async function bundleInner(options: RolldownOptions) {
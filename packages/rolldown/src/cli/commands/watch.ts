@@ -0,0 +1,132 @@
+import process from 'node:process'
+import nodePath from 'node:path'
+import type { BindingWatcherEvent } from '../../binding'
+import { arraify, createWatcher } from '../../utils/index'
+import { ensureConfig, logger } from '../utils'
+import * as colors from '../colors'
+import { type BundleCliOptions, outputOptions } from './bundle'
+
+export interface WatchCliOptions extends BundleCliOptions {
+  /**
+   * Clear the screen before each rebuild. Defaults to `true`, and it's always
+   * disabled if stdout isn't a TTY.
+   */
+  clearScreen?: boolean
+}
+
+export async function watch(
+  configPath: string,
+  cliOptions: WatchCliOptions = {},
+) {
+  const config = await ensureConfig(configPath)
+
+  if (!config) {
+    logger.error(`No configuration found at ${configPath}`)
+    process.exit(1)
+  }
+
+  // Logs of CI are plain text, so cursor movement and colors only clutter them
+  const plain = !process.stdout.isTTY
+  const reporter = plain
+    ? createPlainReporter()
+    : createPrettyReporter(cliOptions.clearScreen !== false)
+
+  await Promise.all(
+    arraify(config).map(async (config) => {
+      if (cliOptions.profile) {
+        config = { ...config, profile: true }
+      }
+      if (cliOptions.env) {
+        config = { ...config, env: { ...config.env, ...cliOptions.env } }
+      }
+      const output = { ...config.output, ...outputOptions(cliOptions) }
+      const { watcher } = await createWatcher(config, output)
+      await watcher.start(reporter)
+    }),
+  )
+}
+
+function relativeToCwd(file: string): string {
+  return nodePath.relative(process.cwd(), file) || file
+}
+
+function createPrettyReporter(clearScreen: boolean) {
+  let isRebuild = false
+  return (event: BindingWatcherEvent) => {
+    switch (event.code) {
+      case 'BUNDLE_START': {
+        if (isRebuild && clearScreen) {
+          // Clear the screen and the scrollback, then move the cursor home
+          process.stdout.write('\x1B[2J\x1B[3J\x1B[H')
+        }
+        const changedFiles = event.changedFiles ?? []
+        if (changedFiles.length > 0) {
+          logger.info(
+            `${colors.dim('Changed')} ${changedFiles.map((file) => colors.cyan(relativeToCwd(file))).join(', ')}`,
+          )
+        }
+        logger.start(isRebuild ? 'Rebuilding...' : 'Building...')
+        isRebuild = true
+        break
+      }
+      case 'BUNDLE_END': {
+        const duration = colors.bold(`${event.duration!.toFixed(2)} ms`)
+        if (event.errorCount) {
+          logger.error(
+            `Build failed with ${event.errorCount} ${event.errorCount === 1 ? 'error' : 'errors'} in ${duration}`,
+          )
+        } else {
+          logger.success(`Built in ${duration}`)
+        }
+        logger.info(colors.dim('Waiting for changes...'))
+        break
+      }
+      case 'ERROR':
+        logger.error(event.error)
+        break
+      case 'HMR_UPDATE': {
+        const { filename, moduleIds } = event.hmrUpdate!
+        logger.info(
+          `${colors.dim('HMR update')} ${colors.cyan(filename)} ${colors.dim('for')} ${moduleIds.join(', ')}`,
+        )
+        break
+      }
+    }
+  }
+}
+
+/**
+ * One line per event with a timestamp, so builds could be told apart in logs
+ */
+function createPlainReporter() {
+  const log = (message: string) => {
+    console.log(`[${new Date().toISOString()}] ${message}`)
+  }
+  return (event: BindingWatcherEvent) => {
+    switch (event.code) {
+      case 'BUNDLE_START': {
+        const changedFiles = event.changedFiles ?? []
+        if (changedFiles.length > 0) {
+          log(`changed: ${changedFiles.map(relativeToCwd).join(', ')}`)
+        }
+        log('build started')
+        break
+      }
+      case 'BUNDLE_END':
+        log(
+          event.errorCount
+            ? `build failed with ${event.errorCount} error(s) in ${event.duration!.toFixed(2)} ms`
+            : `build finished in ${event.duration!.toFixed(2)} ms`,
+        )
+        break
+      case 'ERROR':
+        log(`error: ${event.error}`)
+        break
+      case 'HMR_UPDATE':
+        log(
+          `hmr update ${event.hmrUpdate!.filename} for ${event.hmrUpdate!.moduleIds.join(', ')}`,
+        )
+        break
+    }
+  }
+}
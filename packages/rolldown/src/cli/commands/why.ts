@@ -0,0 +1,36 @@
+import { rolldown } from '@src/rolldown'
+import { arraify } from '../../utils/index'
+import { ensureConfig, logger } from '../utils'
+import * as colors from '../colors'
+
+export async function why(configPath: string, id: string) {
+  const config = await ensureConfig(configPath)
+
+  if (!config) {
+    logger.error(`No configuration found at ${configPath}`)
+    process.exit(1)
+  }
+
+  for (const options of arraify(config)) {
+    const build = await rolldown(options)
+    const chains = await build.why(id)
+    await build.destroy()
+
+    if (chains.length === 0) {
+      logger.info(`${colors.cyan(id)} is not included in the bundle`)
+      continue
+    }
+    logger.info(
+      `${colors.cyan(id)} is included by ${chains.length} ${chains.length === 1 ? 'entry' : 'entries'}`,
+    )
+    for (const chain of chains) {
+      logger.log(
+        chain
+          .map((module, index) =>
+            index === chain.length - 1 ? colors.cyan(module) : module,
+          )
+          .join(colors.dim(' -> ')),
+      )
+    }
+  }
+}
@@ -1 +1,8 @@
-export const DEFAULT_CONFIG_FILENAME = 'rolldown.config.js'
+/**
+ * Config files looked up in the current directory when `-c` is used without a value, in order
+ */
+export const DEFAULT_CONFIG_FILENAMES = [
+  'rolldown.config.js',
+  'rolldown.config.mjs',
+  'rolldown.config.ts',
+]
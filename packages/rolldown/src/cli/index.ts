@@ -2,13 +2,30 @@ import process from 'node:process'
 import parseArgs from 'mri'
 import { defineCommand, runMain, showUsage } from 'citty'
 import { bundle } from './commands/bundle'
+import { why } from './commands/why'
+import { watch } from './commands/watch'
 import { version, description } from '../../package.json' assert { type:
   'json' }
-import { DEFAULT_CONFIG_FILENAME } from './constants'
+import { DEFAULT_CONFIG_FILENAMES } from './constants'
+import { findDefaultConfig, logger } from './utils'
+import { arraify } from '../utils/index'
 
 interface ParsedArgs {
   config?: string | true
   c?: string | true
+  profile?: boolean
+  why?: string
+  'max-chunk-size'?: number
+  format?: string
+  dir?: string
+  sourcemap?: boolean
+  minify?: boolean
+  // Repeated `--env` flags are parsed as an array
+  env?: string | string[]
+  watch?: boolean
+  w?: boolean
+  // `--clearScreen=false` is parsed as a string
+  clearScreen?: boolean | string
   // `citty` intercept the help option, so we don't need to deal with it
   // help?: boolean
   // h?: boolean
@@ -25,7 +42,53 @@ const main = defineCommand({
       type: 'string',
       alias: 'c',
       description:
-        'Use this config file (if argument is used but value is unspecified, defaults to `rolldown.config.js`)',
+        'Use this config file (if argument is used but value is unspecified, defaults to `rolldown.config.(js|mjs|ts)`)',
+    },
+    format: {
+      type: 'string',
+      description: 'Format of the output, `esm` or `cjs`',
+    },
+    dir: {
+      type: 'string',
+      description: 'Directory of the output',
+    },
+    sourcemap: {
+      type: 'boolean',
+      description: 'Generate sourcemaps of chunks',
+    },
+    minify: {
+      type: 'boolean',
+      description: 'Minify emitted chunks and stylesheets',
+    },
+    env: {
+      type: 'string',
+      description:
+        'Expose `KEY=value` as `import.meta.env.KEY` and `process.env.KEY`, which could be repeated',
+    },
+    watch: {
+      type: 'boolean',
+      alias: 'w',
+      description: 'Rebuild when files of the bundle change',
+    },
+    clearScreen: {
+      type: 'boolean',
+      description:
+        'Clear the screen before each rebuild in watch mode, which could be disabled with `--clearScreen=false`',
+    },
+    profile: {
+      type: 'boolean',
+      description:
+        'Print how long each stage of the build and each plugin hook takes',
+    },
+    why: {
+      type: 'string',
+      description:
+        'Print chains of imports from entries that include this module in the bundle, instead of bundling',
+    },
+    'max-chunk-size': {
+      type: 'string',
+      description:
+        'Fail the build if a chunk is bigger than this number of bytes, and print the size of each chunk compressed by gzip and brotli',
     },
     help: {
       type: 'boolean',
@@ -40,9 +103,40 @@ const main = defineCommand({
     if (argConfig) {
       // If config is specified, we will ignore other arguments and bundle with the specified config
       if (argConfig == true) {
-        argConfig = DEFAULT_CONFIG_FILENAME
+        const defaultConfig = await findDefaultConfig(
+          process.cwd(),
+          DEFAULT_CONFIG_FILENAMES,
+        )
+        if (!defaultConfig) {
+          logger.error(
+            `No config file found. Expected one of ${DEFAULT_CONFIG_FILENAMES.join(', ')}`,
+          )
+          process.exit(1)
+        }
+        argConfig = defaultConfig
+      }
+      const bundleOptions = {
+        profile: parsedArgs.profile,
+        maxChunkSize: parsedArgs['max-chunk-size'],
+        format: parsedArgs.format,
+        dir: parsedArgs.dir,
+        sourcemap: parsedArgs.sourcemap,
+        minify: parsedArgs.minify,
+        env: parseEnvArgs(parsedArgs.env),
+      }
+      if (parsedArgs.why) {
+        await why(argConfig, parsedArgs.why)
+      } else if (parsedArgs.watch || parsedArgs.w) {
+        // Watching doesn't return until the process is interrupted
+        await watch(argConfig, {
+          ...bundleOptions,
+          clearScreen:
+            parsedArgs.clearScreen !== false &&
+            parsedArgs.clearScreen !== 'false',
+        })
+      } else {
+        await bundle(argConfig, bundleOptions)
       }
-      await bundle(argConfig)
       process.exit(0)
       return
     }
@@ -51,4 +145,22 @@ const main = defineCommand({
   },
 })
 
+function parseEnvArgs(
+  env: ParsedArgs['env'],
+): Record<string, string> | undefined {
+  if (env === undefined) {
+    return undefined
+  }
+  const parsed: Record<string, string> = {}
+  for (const item of arraify(env)) {
+    const index = item.indexOf('=')
+    if (index <= 0) {
+      logger.error(`Invalid --env ${item}. Expected \`KEY=value\``)
+      process.exit(1)
+    }
+    parsed[item.slice(0, index)] = item.slice(index + 1)
+  }
+  return parsed
+}
+
 runMain(main)
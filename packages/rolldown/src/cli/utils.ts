@@ -1,6 +1,9 @@
 import { pathToFileURL } from 'node:url'
 import nodePath from 'node:path'
+import { isBuiltin } from 'node:module'
+import fs from 'node:fs/promises'
 import { createConsola } from 'consola'
+import { rolldown } from '@src/rolldown'
 import type { ConfigExport } from '../types/config-export'
 
 /**
@@ -20,15 +23,84 @@ export async function ensureConfig(configPath: string): Promise<ConfigExport> {
   }
 
   // Ensure the path is recognized by Node.js in windows
-  const fileUrl = pathToFileURL(configPath).toString()
+  const url =
+    nodePath.extname(configPath) === '.ts'
+      ? await bundleTsConfig(configPath)
+      : pathToFileURL(configPath).toString()
 
-  const configExports = await import(fileUrl)
+  const configExports = await import(url)
 
   // TODO: Could add more validation/diagnostics here to emit a nice error message
   return configExports.default
 }
 
-const SUPPORTED_CONFIG_FORMATS = ['.js', '.mjs', '.cjs']
+/**
+ * Node.js can't import TypeScript, so the config is bundled to an ES module imported from a `data:` URL, without
+ * writing any file. Packages imported by the config are resolved from it and left external as absolute file URLs,
+ * since bare specifiers can't be resolved from `data:` URLs.
+ */
+async function bundleTsConfig(configPath: string): Promise<string> {
+  // `this.resolve` calls `resolveId` of the plugin again, which leaves these sources to the builtin resolver
+  const resolving = new Set<string>()
+  const build = await rolldown({
+    input: configPath,
+    platform: 'node',
+    plugins: [
+      {
+        name: 'rolldown:externalize-config-deps',
+        async resolveId(source, importer) {
+          if (source.startsWith('.') || nodePath.isAbsolute(source)) {
+            return
+          }
+          if (isBuiltin(source)) {
+            return { id: source, external: true }
+          }
+          const key = `${importer}\0${source}`
+          if (resolving.has(key)) {
+            return
+          }
+          resolving.add(key)
+          try {
+            const resolved = await this.resolve(source, importer)
+            if (!resolved || resolved.external) {
+              return { id: source, external: true }
+            }
+            return { id: pathToFileURL(resolved.id).toString(), external: true }
+          } finally {
+            resolving.delete(key)
+          }
+        },
+      },
+    ],
+  })
+  try {
+    const { output } = await build.generate({ format: 'esm' })
+    return `data:text/javascript;base64,${Buffer.from(output[0].code).toString('base64')}`
+  } finally {
+    await build.destroy()
+  }
+}
+
+/**
+ * The first of the default config files that exists in `cwd`
+ */
+export async function findDefaultConfig(
+  cwd: string,
+  filenames: string[],
+): Promise<string | undefined> {
+  for (const filename of filenames) {
+    const path = nodePath.join(cwd, filename)
+    const exists = await fs.access(path).then(
+      () => true,
+      () => false,
+    )
+    if (exists) {
+      return path
+    }
+  }
+}
+
+const SUPPORTED_CONFIG_FORMATS = ['.js', '.mjs', '.cjs', '.ts']
 
 /**
  * Check whether the configuration file is supported
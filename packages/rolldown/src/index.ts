@@ -3,12 +3,23 @@ import type { InputOptions } from './options/input-options'
 import type { OutputOptions } from './options/output-options'
 import type { RolldownOptions } from './types/rolldown-options'
 import type { Plugin } from './plugin'
-import { defineParallelPlugin, DefineParallelPluginResult } from './plugin'
+import {
+  defineParallelPlugin,
+  DefineParallelPluginResult,
+  replacePlugin,
+} from './plugin'
 import { defineConfig } from './utils/define-config'
 import { rolldown, experimental_scan } from './rolldown'
 import { ConfigExport } from './types/config-export'
+import type { ModuleInfo } from './types/module-info'
 
-export { defineConfig, defineParallelPlugin, rolldown, experimental_scan }
+export {
+  defineConfig,
+  defineParallelPlugin,
+  replacePlugin,
+  rolldown,
+  experimental_scan,
+}
 
 export type {
   RolldownOutputChunk,
@@ -19,6 +30,7 @@ export type {
   Plugin,
   DefineParallelPluginResult,
   ConfigExport,
+  ModuleInfo,
 }
 
 // Exports for compatibility
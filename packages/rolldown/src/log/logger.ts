@@ -146,6 +146,11 @@ const defaultPrintLog: LogHandler = (level, log) => {
 }
 
 const getExtendedLogMessage = (log: RollupLog): string => {
+  // Frames rendered by the bundler already include the location and the message
+  if (log.frame && log.code !== 'PLUGIN_WARNING' && log.code !== 'PLUGIN_LOG') {
+    return log.frame
+  }
+
   let prefix = ''
 
   if (log.plugin) {
@@ -1,4 +1,4 @@
-import type { BindingInputOptions } from '../binding'
+import type { BindingBuiltinPlugin, BindingInputOptions } from '../binding'
 import nodePath from 'node:path'
 import { bindingifyPlugin } from '../plugin/bindingify-plugin'
 import type { NormalizedInputOptions } from './normalized-input-options'
@@ -12,12 +12,15 @@ export function bindingifyInputOptions(
 ): BindingInputOptions {
   return {
     input: bindingifyInput(options.input),
+    stdin: options.stdin,
     plugins: options.plugins.map((plugin) => {
-      if ('_parallel' in plugin) {
+      // Parallel and builtin plugins are placed by their indexes in Rust
+      if ('_parallel' in plugin || '_builtin' in plugin) {
         return undefined
       }
       return bindingifyPlugin(plugin, options, outputOptions)
     }),
+    builtinPlugins: bindingifyBuiltinPlugins(options.plugins),
     cwd: options.cwd ?? process.cwd(),
     external: options.external
       ? (function bindingifyExternal() {
@@ -39,6 +42,12 @@ export function bindingifyInputOptions(
           }
         })()
       : undefined,
+    externalIds:
+      options.external && typeof options.external !== 'function'
+        ? arraify(options.external).filter(
+            (id): id is string => typeof id === 'string',
+          )
+        : undefined,
     resolve: options.resolve
       ? (function bindingifyResolve() {
           const { alias, ...rest } = options.resolve
@@ -55,11 +64,49 @@ export function bindingifyInputOptions(
         })()
       : undefined,
     platform: options.platform,
+    nodeProtocol: options.nodeProtocol,
     shimMissingExports: options.shimMissingExports,
+    drop: options.drop,
+    pure: options.pure,
+    define: options.define,
+    env: options.env,
+    strictRequires: options.strictRequires,
+    requireReturnsDefault:
+      options.requireReturnsDefault === undefined
+        ? undefined
+        : String(options.requireReturnsDefault),
+    dynamicRequireTargets: options.dynamicRequireTargets,
+    unresolvedRequire: options.unresolvedRequire,
+    inlineConstEnums: options.inlineConstEnums,
+    assetsInlineLimit: bindingifyAssetsInlineLimit(options.assetsInlineLimit),
+    cssModules: options.cssModules,
+    circularDependency: options.circularDependency,
+    evalWarning: options.evalWarning,
+    checks: options.checks,
+    moduleTypes: options.moduleTypes,
+    publicDir: options.publicDir,
+    watch: options.watch,
+    hmr: options.hmr,
+    lazyCompilation: options.lazyCompilation,
+    cacheDir: options.cacheDir,
+    threads: options.threads,
+    maxParallelFileOps: options.maxParallelFileOps,
+    maxParallelJsHooks: options.maxParallelJsHooks,
+    profile: options.profile,
+    profileTraceFile: options.profileTraceFile,
     // @ts-ignore TODO The typing should import from binding
     logLevel: bindingifyLogLevel(options.logLevel),
+    collapseWarnings: options.collapseWarnings,
     onLog: (level, log) => {
-      options.onLog(level, { code: log.code, message: log.message })
+      options.onLog(level, {
+        code: log.code,
+        message: log.message,
+        frame: log.frame,
+        ids: log.ids,
+        plugin: log.plugin,
+        loc: log.loc,
+        help: log.help,
+      })
     },
   }
 }
@@ -90,12 +137,37 @@ function bindingifyLogLevel(
   }
 }
 
+function bindingifyAssetsInlineLimit(
+  assetsInlineLimit: NormalizedInputOptions['assetsInlineLimit'],
+): BindingInputOptions['assetsInlineLimit'] {
+  if (typeof assetsInlineLimit === 'number') {
+    return (_file, content) => content.length < assetsInlineLimit
+  }
+  if (typeof assetsInlineLimit === 'function') {
+    return (file, content) => assetsInlineLimit(file, content) ?? undefined
+  }
+  return undefined
+}
+
+function bindingifyBuiltinPlugins(
+  plugins: NormalizedInputOptions['plugins'],
+): BindingBuiltinPlugin[] {
+  const builtinPlugins: BindingBuiltinPlugin[] = []
+  for (const [index, plugin] of plugins.entries()) {
+    if ('_builtin' in plugin) {
+      builtinPlugins.push({ index, ...plugin._builtin })
+    }
+  }
+  return builtinPlugins
+}
+
 function bindingifyInput(
   input: NormalizedInputOptions['input'],
 ): BindingInputOptions['input'] {
   if (Array.isArray(input)) {
     return input.map((src) => {
-      const name = nodePath.parse(src).name
+      // Entries matched by glob patterns are named after their paths by rolldown
+      const name = isGlob(src) ? undefined : nodePath.parse(src).name
       return {
         name,
         import: src,
@@ -107,3 +179,7 @@ function bindingifyInput(
     })
   }
 }
+
+function isGlob(src: string): boolean {
+  return src.includes('*') || src.includes('?')
+}
@@ -9,10 +9,30 @@ export function bindingifyOutputOptions(
     format,
     exports,
     sourcemap,
+    sourcemapDebugIds,
+    validate,
+    reservedNames,
+    es3SafeIdentifiers,
+    dts,
+    runtimeChunk,
+    minifyInternalExports,
+    metafile,
+    reportCompressedSize,
+    maxChunkSize,
     sourcemapIgnoreList,
     sourcemapPathTransform,
     entryFileNames,
     chunkFileNames,
+    assetFileNames,
+    sourcemapFileNames,
+    charset,
+    legalComments,
+    cssMinify,
+    minify,
+    streamWrites,
+    hashCharacters,
+    hashAlgorithm,
+    hashSalt,
     banner,
     footer,
   } = outputOptions
@@ -28,12 +48,32 @@ export function bindingifyOutputOptions(
     })(),
     exports,
     sourcemap: bindingifySourcemap(sourcemap),
+    sourcemapDebugIds,
+    validate,
+    reservedNames,
+    es3SafeIdentifiers,
+    dts,
+    runtimeChunk,
+    minifyInternalExports,
+    metafile,
+    reportCompressedSize,
+    maxChunkSize,
     sourcemapIgnoreList,
     sourcemapPathTransform,
     banner,
     footer,
     entryFileNames,
     chunkFileNames,
+    assetFileNames,
+    sourcemapFileNames,
+    charset,
+    legalComments,
+    cssMinify,
+    minify,
+    streamWrites,
+    hashCharacters,
+    hashAlgorithm,
+    hashSalt,
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
   }
@@ -49,11 +89,16 @@ function bindingifySourcemap(
     case 'inline':
       return 'inline'
 
-    case false:
-    case undefined:
     case 'hidden':
       return 'hidden'
 
+    case 'cheap':
+      return 'cheap'
+
+    case false:
+    case undefined:
+      return undefined
+
     default:
       throw new Error(`unknown sourcemap: ${sourcemap}`)
   }
@@ -1,4 +1,4 @@
-import type { Plugin, ParallelPlugin } from '../plugin'
+import type { Plugin, ParallelPlugin, BuiltinPlugin } from '../plugin'
 import { z } from 'zod'
 import * as zodExt from '../utils/zod-ext'
 import {
@@ -9,9 +9,41 @@ import {
   RollupLogWithStringSchema,
 } from '../log/logging'
 
+const loaderSchema = z
+  .literal('js')
+  .or(z.literal('jsx'))
+  .or(z.literal('ts'))
+  .or(z.literal('tsx'))
+  .or(z.literal('json'))
+  .or(z.literal('text'))
+  .or(z.literal('base64'))
+  .or(z.literal('dataurl'))
+  .or(z.literal('binary'))
+  .or(z.literal('empty'))
+  .or(z.literal('asset'))
+  .or(z.literal('css'))
+  .or(z.literal('wasm'))
+  .or(z.literal('html'))
+
+const stdinOptionsSchema = z.strictObject({
+  code: z.string(),
+  /** The directory that relative imports of the code are resolved from. Defaults to `cwd`. */
+  resolveDir: z.string().optional(),
+  /** Defaults to `js`. */
+  loader: loaderSchema.optional(),
+})
+
 const inputOptionsSchema = z.strictObject({
-  input: z.string().or(z.string().array()).or(z.record(z.string())).optional(),
-  plugins: zodExt.phantom<Plugin | ParallelPlugin>().array().optional(),
+  input: z
+    .string()
+    .or(z.string().array())
+    .or(z.record(z.string()))
+    .or(z.strictObject({ stdin: stdinOptionsSchema }))
+    .optional(),
+  plugins: zodExt
+    .phantom<Plugin | ParallelPlugin | BuiltinPlugin>()
+    .array()
+    .optional(),
   external: zodExt
     .stringOrRegExp()
     .or(zodExt.stringOrRegExp().array())
@@ -43,8 +75,89 @@ const inputOptionsSchema = z.strictObject({
     .or(z.literal('browser'))
     .or(z.literal('neutral'))
     .optional(),
+  nodeProtocol: z.boolean().optional(),
   shimMissingExports: z.boolean().optional(),
+  drop: z.array(z.literal('console').or(z.literal('debugger'))).optional(),
+  pure: zodExt.optionalStringArray(),
+  define: z.record(z.string()).optional(),
+  env: z.record(z.string()).optional(),
+  strictRequires: z.boolean().optional(),
+  requireReturnsDefault: z
+    .boolean()
+    .or(z.literal('auto'))
+    .or(z.literal('preferred'))
+    .optional(),
+  dynamicRequireTargets: zodExt.optionalStringArray(),
+  unresolvedRequire: z
+    .literal('error')
+    .or(z.literal('keep'))
+    .or(z.literal('stub'))
+    .optional(),
+  inlineConstEnums: z.boolean().optional(),
+  assetsInlineLimit: z
+    .number()
+    .or(
+      z
+        .function()
+        .args(z.string(), zodExt.phantom<Buffer>())
+        .returns(zodExt.voidNullableWith(z.boolean())),
+    )
+    .optional(),
+  cssModules: z
+    .strictObject({
+      scopedName: z.string().optional(),
+      localsConvention: z
+        .literal('asIs')
+        .or(z.literal('camelCase'))
+        .or(z.literal('camelCaseOnly'))
+        .or(z.literal('dashes'))
+        .or(z.literal('dashesOnly'))
+        .optional(),
+    })
+    .optional(),
+  circularDependency: z
+    .strictObject({
+      exclude: z.array(z.string()).optional(),
+      error: z.boolean().optional(),
+    })
+    .optional(),
+  evalWarning: z
+    .strictObject({
+      exclude: z.array(z.string()).optional(),
+    })
+    .optional(),
+  checks: z
+    .strictObject({
+      topLevelAwait: z.literal('error').or(z.literal('warn')).optional(),
+      importMeta: z.literal('error').or(z.literal('warn')).optional(),
+      circularChunks: z.literal('error').or(z.literal('warn')).optional(),
+    })
+    .optional(),
+  moduleTypes: z.record(loaderSchema).optional(),
+  publicDir: z.string().optional(),
+  watch: z
+    .strictObject({
+      include: z.array(z.string()).optional(),
+      exclude: z.array(z.string()).optional(),
+      buildDelay: z.number().optional(),
+      skipWrite: z.boolean().optional(),
+      poll: z
+        .strictObject({
+          interval: z.number().optional(),
+        })
+        .optional(),
+    })
+    .optional(),
+  hmr: z.boolean().optional(),
+  lazyCompilation: z.boolean().optional(),
+  cacheDir: z.string().optional(),
+  threads: z.number().optional(),
+  maxParallelFileOps: z.number().optional(),
+  maxParallelJsHooks: z.number().optional(),
+  profile: z.boolean().optional(),
+  profileTraceFile: z.string().optional(),
   logLevel: LogLevelOptionSchema.optional(),
+  collapseWarnings: z.boolean().optional(),
   onLog: z
     .function()
     .args(
@@ -69,3 +182,4 @@ const inputOptionsSchema = z.strictObject({
 })
 
 export type InputOptions = z.infer<typeof inputOptionsSchema>
+export type StdinOptions = z.infer<typeof stdinOptionsSchema>
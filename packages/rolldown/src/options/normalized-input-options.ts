@@ -3,13 +3,14 @@ import type {
   RollupLog,
   NormalizedInputOptions as RollupNormalizedInputOptions,
 } from '../rollup'
-import type { InputOptions } from './input-options'
-import type { Plugin, ParallelPlugin } from '../plugin'
+import type { InputOptions, StdinOptions } from './input-options'
+import type { Plugin, ParallelPlugin, BuiltinPlugin } from '../plugin'
 import type { LogLevel } from '../log/logging'
 
 export interface NormalizedInputOptions extends InputOptions {
   input: RollupNormalizedInputOptions['input']
-  plugins: (Plugin | ParallelPlugin)[]
+  stdin?: StdinOptions
+  plugins: (Plugin | ParallelPlugin | BuiltinPlugin)[]
   onLog: (level: LogLevel, log: RollupLog) => void
   logLevel: LogLevelOption
 }
@@ -15,11 +15,31 @@ export interface NormalizedOutputOptions extends OutputOptions {
   dir: string | undefined
   format: InternalModuleFormat
   exports: 'named'
-  sourcemap: boolean | 'inline' | 'hidden'
+  sourcemap: boolean | 'inline' | 'hidden' | 'cheap'
+  sourcemapDebugIds: boolean
+  validate: boolean
+  reservedNames: string[]
+  es3SafeIdentifiers: boolean
+  dts: boolean
+  runtimeChunk: boolean
+  minifyInternalExports: boolean
+  metafile: string | undefined
+  reportCompressedSize: boolean
+  maxChunkSize: number | undefined
   sourcemapIgnoreList: SourcemapIgnoreListOption
   sourcemapPathTransform: SourcemapPathTransformOption | undefined
   banner: AddonFunction
   footer: AddonFunction
   entryFileNames: string
   chunkFileNames: string
+  assetFileNames: string
+  sourcemapFileNames: string | undefined
+  charset: 'ascii' | 'utf8'
+  legalComments: 'inline' | 'eof' | 'linked' | 'external' | 'none'
+  cssMinify: boolean
+  minify: boolean
+  streamWrites: boolean
+  hashCharacters: 'base64' | 'base36' | 'hex'
+  hashAlgorithm: 'xxhash3' | 'blake3'
+  hashSalt: string | undefined
 }
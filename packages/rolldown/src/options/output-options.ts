@@ -22,7 +22,18 @@ const outputOptionsSchema = z.strictObject({
     .boolean()
     .or(z.literal('inline'))
     .or(z.literal('hidden'))
+    .or(z.literal('cheap'))
     .optional(),
+  sourcemapDebugIds: z.boolean().optional(),
+  validate: z.boolean().optional(),
+  reservedNames: z.array(z.string()).optional(),
+  es3SafeIdentifiers: z.boolean().optional(),
+  dts: z.boolean().optional(),
+  runtimeChunk: z.boolean().optional(),
+  minifyInternalExports: z.boolean().optional(),
+  metafile: z.string().optional(),
+  reportCompressedSize: z.boolean().optional(),
+  maxChunkSize: z.number().optional(),
   sourcemapIgnoreList: z
     .boolean()
     .or(zodExt.phantom<SourcemapIgnoreListOption>())
@@ -34,6 +45,26 @@ const outputOptionsSchema = z.strictObject({
   footer: z.string().or(addonFunctionSchema).optional(),
   entryFileNames: z.string().optional(),
   chunkFileNames: z.string().optional(),
+  assetFileNames: z.string().optional(),
+  sourcemapFileNames: z.string().optional(),
+  charset: z.literal('ascii').or(z.literal('utf8')).optional(),
+  legalComments: z
+    .literal('inline')
+    .or(z.literal('eof'))
+    .or(z.literal('linked'))
+    .or(z.literal('external'))
+    .or(z.literal('none'))
+    .optional(),
+  cssMinify: z.boolean().optional(),
+  minify: z.boolean().optional(),
+  streamWrites: z.boolean().optional(),
+  hashCharacters: z
+    .literal('base64')
+    .or(z.literal('base36'))
+    .or(z.literal('hex'))
+    .optional(),
+  hashAlgorithm: z.literal('xxhash3').or(z.literal('blake3')).optional(),
+  hashSalt: z.string().optional(),
 })
 
 export type OutputOptions = z.infer<typeof outputOptionsSchema>
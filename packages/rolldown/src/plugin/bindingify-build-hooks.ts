@@ -1,7 +1,9 @@
 import { normalizeHook } from '../utils/normalize-hook'
 import type {
   BindingHookLoadOutput,
+  BindingHookLoadResult,
   BindingHookResolveIdOutput,
+  BindingPluginContext,
   BindingPluginOptions,
 } from '../binding'
 
@@ -175,7 +177,10 @@ export function bindingifyLoad(
   }
   const [handler, _optionsIgnoredSofar] = normalizeHook(hook)
 
-  return async (ctx, id) => {
+  const load = async (
+    ctx: BindingPluginContext,
+    id: string,
+  ): Promise<BindingHookLoadOutput | undefined> => {
     const ret = await handler.call(new PluginContext(options, ctx, plugin), id)
 
     if (ret == null) {
@@ -216,6 +221,20 @@ export function bindingifyLoad(
 
     return result
   }
+
+  // Modules are loaded in batches to cross the boundary between Rust and JS fewer times. Each module gets its own
+  // result, so a throwing hook only fails the module it was called for.
+  return (ctx, ids) =>
+    Promise.all(
+      ids.map((id) =>
+        load(ctx, id).then(
+          (output): BindingHookLoadResult => ({ output }),
+          (error): BindingHookLoadResult => ({
+            error: error instanceof Error ? error.message : String(error),
+          }),
+        ),
+      ),
+    )
 }
 
 export function bindingifyModuleParsed(
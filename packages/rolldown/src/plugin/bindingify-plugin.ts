@@ -30,6 +30,7 @@ export function bindingifyPlugin(
 ): BindingPluginOptions {
   return {
     name: plugin.name ?? 'unknown',
+    cacheKey: plugin.cacheKey,
     buildStart: bindingifyBuildStart(plugin, options),
     resolveId: bindingifyResolveId(plugin, options),
     resolveDynamicImport: bindingifyResolveDynamicImport(plugin, options),
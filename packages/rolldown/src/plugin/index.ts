@@ -1,4 +1,5 @@
 import type {
+  BindingBuiltinPlugin,
   BindingHookResolveIdExtraOptions,
   RenderedChunk,
 } from '../binding'
@@ -38,6 +39,12 @@ export type ResolveIdResult =
 export interface Plugin {
   name?: string
 
+  /**
+   * Identifies the plugin and its options in `cacheDir`, so results of `transform` hooks cached on disk are only used
+   * with the same plugins configured the same way. Defaults to the name.
+   */
+  cacheKey?: string
+
   onLog?: Hook<
     (
       this: MinimalPluginContext,
@@ -188,3 +195,16 @@ export function defineParallelPlugin<Options>(
     return { _parallel: { fileUrl: pathToFileURL(pluginPath).href, options } }
   }
 }
+
+export type BuiltinPlugin = {
+  /** @internal */
+  _builtin: Omit<BindingBuiltinPlugin, 'index'>
+}
+
+/**
+ * Replace strings in the code of modules, like `@rollup/plugin-replace`, but
+ * implemented in Rust. It runs in its position among other plugins.
+ */
+export function replacePlugin(values: Record<string, string>): BuiltinPlugin {
+  return { _builtin: { name: 'builtin:replace', replace: { values } } }
+}
@@ -12,6 +12,7 @@ export class PluginContext {
   warn: LoggingFunction
   error: (error: RollupError | string) => never
   resolve: BindingPluginContext['resolve']
+  addWatchFile: (id: string) => void
 
   constructor(
     options: NormalizedInputOptions,
@@ -46,5 +47,6 @@ export class PluginContext {
       return error(logPluginError(normalizeLog(e), pluginName))
     }
     this.resolve = context.resolve.bind(context)
+    this.addWatchFile = (id) => context.addWatchFile(id)
   }
 }
@@ -10,7 +10,7 @@ export class PluginDriver {
   public callOptionsHook(inputOptions: NormalizedInputOptions) {
     const logLevel = inputOptions.logLevel
     const plugins = inputOptions.plugins.filter(
-      (plugin) => !('_parallel' in plugin),
+      (plugin) => !('_parallel' in plugin) && !('_builtin' in plugin),
     ) as Plugin[]
     const logger = inputOptions.onLog
 
@@ -19,6 +19,7 @@ export class TransformPluginContext {
   error: (error: RollupError | string) => never
   resolve: BindingPluginContext['resolve']
   getCombinedSourcemap: () => SourceMap
+  addWatchFile: (id: string) => void
 
   constructor(
     inner: BindingTransformPluginContext,
@@ -51,5 +52,6 @@ export class TransformPluginContext {
     }
     this.resolve = context.resolve
     this.getCombinedSourcemap = () => JSON.parse(inner.getCombinedSourcemap())
+    this.addWatchFile = (id) => inner.addWatchFile(id)
   }
 }
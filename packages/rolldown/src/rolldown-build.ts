@@ -4,6 +4,8 @@ import { createBundler, transformToRollupOutput } from './utils'
 import type { RolldownOutput } from './types/rolldown-output'
 import type { HasProperty, TypeAssert } from './utils/type-assert'
 import type { InputOptions } from './options/input-options'
+import type { ModuleInfo } from './types/module-info'
+import { transformModuleInfo } from './utils/transform-module-info'
 
 export class RolldownBuild {
   #inputOptions: InputOptions
@@ -39,6 +41,42 @@ export class RolldownBuild {
     return transformToRollupOutput(output)
   }
 
+  /**
+   * Explain why the module with `id` is in the bundle, with the shortest
+   * chain of imports to it from each entry. `id` could be relative to `cwd`.
+   */
+  async why(id: string): Promise<string[][]> {
+    const bundler = await this.#getBundler({})
+    return bundler.why(id)
+  }
+
+  /**
+   * Ids of modules in the module graph of the last build, which is empty
+   * before the first build.
+   */
+  getModuleIds(): string[] {
+    return this.#bundler?.getModuleIds() ?? []
+  }
+
+  /**
+   * Imports, importers and exports of the module with `id` in the module graph
+   * of the last build. `id` could be relative to `cwd`.
+   */
+  getModuleInfo(id: string): ModuleInfo | null {
+    const info = this.#bundler?.getModuleInfo(id)
+    return info ? transformModuleInfo(info) : null
+  }
+
+  /**
+   * Bundle the module with `id` as usual since the next build, with
+   * `lazyCompilation`. It's the id proxy modules pass to
+   * `__rolldown_lazy__.load(id)` in the browser.
+   */
+  async requestLazyCompilation(id: string): Promise<void> {
+    const bundler = await this.#getBundler({})
+    bundler.requestLazyCompilation(id)
+  }
+
   async destroy(): Promise<void> {
     await this.#stopWorkers?.()
   }
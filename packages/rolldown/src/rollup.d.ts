@@ -21,6 +21,8 @@ export interface RollupLog {
   code?: string
   exporter?: string
   frame?: string
+  /** How to fix the problem, if it could be guessed. Rolldown specific. */
+  help?: string
   hook?: string
   id?: string
   ids?: string[]
@@ -10,4 +10,5 @@ export interface ModuleInfo {
   importedIds: string[]
   dynamicallyImportedIds: string[]
   isEntry: boolean
+  exports: string[]
 }
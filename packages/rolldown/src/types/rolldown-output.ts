@@ -44,12 +44,16 @@ export interface RolldownOutputChunk {
   moduleIds: string[]
   map: SourceMap | null
   sourcemapFileName: string | null
+  cssFileName: string | null
   preliminaryFileName: string
 }
 
 function _assertRolldownOutputChunk() {
   type _ = TypeAssert<
-    IsPropertiesEqual<Omit<RolldownOutputChunk, 'modules' | 'map'>, OutputChunk>
+    IsPropertiesEqual<
+      Omit<RolldownOutputChunk, 'modules' | 'map' | 'cssFileName'>,
+      OutputChunk
+    >
   >
 }
 
@@ -1,5 +1,11 @@
 import { bindingifyInputOptions } from '@src/options/bindingify-input-options'
-import { Bundler } from '../binding'
+import {
+  BindingWatcher,
+  Bundler,
+  type BindingInputOptions,
+  type BindingOutputOptions,
+  type ParallelJsPluginRegistry,
+} from '../binding'
 import type { InputOptions } from '../options/input-options'
 import type { OutputOptions } from '../options/output-options'
 import { initializeParallelPlugins } from './initialize-parallel-plugins'
@@ -12,6 +18,38 @@ export async function createBundler(
   inputOptions: InputOptions,
   outputOptions: OutputOptions,
 ): Promise<{ bundler: Bundler; stopWorkers?: () => Promise<void> }> {
+  const { binding, stopWorkers } = await createBinding(
+    Bundler,
+    inputOptions,
+    outputOptions,
+  )
+  return { bundler: binding, stopWorkers }
+}
+
+/**
+ * Create the watcher, which owns a bundler of its own that rebuilds on changes
+ */
+export async function createWatcher(
+  inputOptions: InputOptions,
+  outputOptions: OutputOptions,
+): Promise<{ watcher: BindingWatcher; stopWorkers?: () => Promise<void> }> {
+  const { binding, stopWorkers } = await createBinding(
+    BindingWatcher,
+    inputOptions,
+    outputOptions,
+  )
+  return { watcher: binding, stopWorkers }
+}
+
+async function createBinding<T>(
+  BindingClass: new (
+    inputOptions: BindingInputOptions,
+    outputOptions: BindingOutputOptions,
+    parallelPluginsRegistry?: ParallelJsPluginRegistry,
+  ) => T,
+  inputOptions: InputOptions,
+  outputOptions: OutputOptions,
+): Promise<{ binding: T; stopWorkers?: () => Promise<void> }> {
   const pluginDriver = new PluginDriver()
   // Convert `InputOptions` to `NormalizedInputOptions`.
   const normalizedInputOptions = await normalizeInputOptions(inputOptions)
@@ -31,7 +69,7 @@ export async function createBundler(
     )
 
     return {
-      bundler: new Bundler(
+      binding: new BindingClass(
         bindingInputOptions,
         bindingifyOutputOptions(normalizedOutputOptions),
         parallelPluginInitResult?.registry,
@@ -1,6 +1,6 @@
 import { Worker } from 'node:worker_threads'
 import { availableParallelism } from 'node:os'
-import type { ParallelPlugin, Plugin } from '../plugin'
+import type { BuiltinPlugin, ParallelPlugin, Plugin } from '../plugin'
 import { ParallelJsPluginRegistry } from '../binding'
 
 export type WorkerData = {
@@ -16,7 +16,7 @@ type ParallelPluginInfo = {
 }
 
 export async function initializeParallelPlugins(
-  plugins: (Plugin | ParallelPlugin)[],
+  plugins: (Plugin | ParallelPlugin | BuiltinPlugin)[],
 ) {
   const pluginInfos: ParallelPluginInfo[] = []
   for (const [index, plugin] of plugins.entries()) {
@@ -1,6 +1,6 @@
 import { getLogger, getOnLog } from '../log/logger'
 import { LOG_LEVEL_INFO } from '../log/logging'
-import type { InputOptions } from '../options/input-options'
+import type { InputOptions, StdinOptions } from '../options/input-options'
 import type { NormalizedInputOptions } from '../options/normalized-input-options'
 import { normalizePluginOption } from './normalize-plugin-option'
 
@@ -11,10 +11,16 @@ export async function normalizeInputOptions(
   const plugins = await normalizePluginOption(config.plugins)
   const logLevel = config.logLevel || LOG_LEVEL_INFO
   const onLog = getLogger(
-    plugins.filter((plugin) => !('_parallel' in plugin)) as Plugin[],
+    plugins.filter(
+      (plugin) => !('_parallel' in plugin) && !('_builtin' in plugin),
+    ) as Plugin[],
     getOnLog(config, logLevel),
     logLevel,
   )
+  // `input: { stdin }` bundles the given code instead of files
+  if (isStdinInput(input)) {
+    return { ...rest, input: [], stdin: input.stdin, plugins, logLevel, onLog }
+  }
   return {
     ...rest,
     input: input ? (typeof input === 'string' ? [input] : input) : [],
@@ -23,3 +29,13 @@ export async function normalizeInputOptions(
     onLog,
   }
 }
+
+function isStdinInput(
+  input: InputOptions['input'],
+): input is { stdin: StdinOptions } {
+  return (
+    typeof input === 'object' &&
+    !Array.isArray(input) &&
+    typeof input.stdin === 'object'
+  )
+}
@@ -10,16 +10,46 @@ export function normalizeOutputOptions(
     format,
     exports,
     sourcemap,
+    sourcemapDebugIds,
+    validate,
+    reservedNames,
+    es3SafeIdentifiers,
+    dts,
+    runtimeChunk,
+    minifyInternalExports,
+    metafile,
+    reportCompressedSize,
+    maxChunkSize,
     sourcemapIgnoreList,
     sourcemapPathTransform,
     entryFileNames,
     chunkFileNames,
+    assetFileNames,
+    sourcemapFileNames,
+    charset,
+    legalComments,
+    cssMinify,
+    minify,
+    streamWrites,
+    hashCharacters,
+    hashAlgorithm,
+    hashSalt,
   } = opts
   return {
     dir: dir,
     format: getFormat(format),
     exports: exports ?? 'named',
     sourcemap: sourcemap ?? false,
+    sourcemapDebugIds: sourcemapDebugIds ?? false,
+    validate: validate ?? false,
+    reservedNames: reservedNames ?? [],
+    es3SafeIdentifiers: es3SafeIdentifiers ?? false,
+    dts: dts ?? false,
+    runtimeChunk: runtimeChunk ?? false,
+    minifyInternalExports: minifyInternalExports ?? false,
+    metafile,
+    reportCompressedSize: reportCompressedSize ?? false,
+    maxChunkSize,
     sourcemapIgnoreList:
       typeof sourcemapIgnoreList === 'function'
         ? sourcemapIgnoreList
@@ -32,6 +62,16 @@ export function normalizeOutputOptions(
     footer: getAddon(opts, 'footer'),
     entryFileNames: entryFileNames ?? '[name].js',
     chunkFileNames: chunkFileNames ?? '[name]-[hash].js',
+    assetFileNames: assetFileNames ?? 'assets/[name]-[hash][extname]',
+    sourcemapFileNames,
+    charset: charset ?? 'utf8',
+    legalComments: legalComments ?? 'none',
+    cssMinify: cssMinify ?? false,
+    minify: minify ?? false,
+    streamWrites: streamWrites ?? false,
+    hashCharacters: hashCharacters ?? 'base64',
+    hashAlgorithm: hashAlgorithm ?? 'xxhash3',
+    hashSalt,
     plugins: [],
   }
 }
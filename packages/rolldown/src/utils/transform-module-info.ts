@@ -16,5 +16,6 @@ export function transformModuleInfo(info: BindingModuleInfo): ModuleInfo {
     importedIds: info.importedIds,
     dynamicallyImportedIds: info.dynamicallyImportedIds,
     isEntry: info.isEntry,
+    exports: info.exports,
   }
 }
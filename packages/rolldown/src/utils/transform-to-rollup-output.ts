@@ -52,6 +52,7 @@ function transformToRollupOutputChunk(
       chunk.map = JSON.stringify(map)
     },
     sourcemapFileName: chunk.sourcemapFileName || null,
+    cssFileName: chunk.cssFileName || null,
     preliminaryFileName: chunk.preliminaryFileName,
   }
 }
@@ -65,8 +66,8 @@ function transformToRollupOutputAsset(
     get source() {
       return asset.source
     },
-    set source(source: string) {
-      asset.source = source
+    set source(source: string | Uint8Array) {
+      asset.source = typeof source === 'string' ? source : Buffer.from(source)
     },
   }
 }
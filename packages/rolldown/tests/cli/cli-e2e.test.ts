@@ -1,5 +1,7 @@
 import { describe, test, it, expect } from 'vitest'
 import { execSync } from 'node:child_process'
+import fs from 'node:fs'
+import nodePath from 'node:path'
 import { $ } from 'execa'
 
 import { projectDir, testsDir } from '@tests/utils'
@@ -33,6 +35,24 @@ describe('config', () => {
       const status = await $({ cwd })`rolldown -c rolldown.config.js`
       expect(status.exitCode).toBe(0)
     })
+    it('should bundle with a TypeScript config', async () => {
+      const cwd = cliFixturesDir('ts-config')
+      const dir = nodePath.join(cwd, 'dist')
+      fs.rmSync(dir, { recursive: true, force: true })
+      const status = await $({ cwd })`rolldown -c rolldown.config.ts`
+      expect(status.exitCode).toBe(0)
+      expect(fs.readdirSync(nodePath.join(dir, 'ts-config'))).toEqual([
+        'index.mjs',
+      ])
+      // The bundled config isn't written next to it
+      expect(fs.readdirSync(cwd).sort()).toEqual([
+        'dist',
+        'index.js',
+        'input.ts',
+        'rolldown.config.ts',
+      ])
+      fs.rmSync(dir, { recursive: true, force: true })
+    })
     it('should not bundle in ext-js-syntax-esm', async () => {
       const cwd = cliFixturesDir('ext-js-syntax-esm')
       try {
@@ -43,3 +63,115 @@ describe('config', () => {
     })
   })
 })
+
+describe('output flags', () => {
+  it('should override output options of the config', async () => {
+    const cwd = cliFixturesDir('output-flags')
+    const dir = nodePath.join(cwd, 'dist-flags')
+    fs.rmSync(dir, { recursive: true, force: true })
+    const status =
+      await $({ cwd })`rolldown -c rolldown.config.mjs --format cjs --dir dist-flags --sourcemap`
+    expect(status.exitCode).toBe(0)
+    const files = fs.readdirSync(dir)
+    const chunk = files.find((file) => !file.endsWith('.map'))!
+    expect(files).toContain(`${chunk}.map`)
+    expect(fs.readFileSync(nodePath.join(dir, chunk), 'utf-8')).toMatch(
+      /^"use strict";/,
+    )
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+
+  it('should minify chunks with --minify', async () => {
+    const cwd = cliFixturesDir('output-flags')
+    const dir = nodePath.join(cwd, 'dist-minify')
+    fs.rmSync(dir, { recursive: true, force: true })
+    const status =
+      await $({ cwd })`rolldown -c rolldown.config.mjs --dir dist-minify --minify`
+    expect(status.exitCode).toBe(0)
+    const [chunk] = fs.readdirSync(dir)
+    const code = fs.readFileSync(nodePath.join(dir, chunk), 'utf-8')
+    expect(code.trim()).not.toContain('\n')
+    expect(code).toContain('hello, world!')
+    fs.rmSync(dir, { recursive: true, force: true })
+  })
+})
+
+describe('diagnostics', () => {
+  it('should print errors with code frames and fail', async () => {
+    const cwd = cliFixturesDir('missing-export')
+    const result = await $({
+      cwd,
+      reject: false,
+    })`rolldown -c rolldown.config.mjs`
+    expect(result.exitCode).toBe(1)
+    expect(result.stderr).toContain('[MISSING_EXPORT]')
+    expect(result.stderr).toMatch(/╭─\[index\.js:1:\d+\]/)
+    expect(result.stderr).toContain("import { missing } from './foo.js'")
+  })
+})
+
+describe('watch', () => {
+  it('should print plain status lines when stdout is not a TTY', async () => {
+    const cwd = cliFixturesDir('ext-mjs')
+    const subprocess = $({
+      cwd,
+      reject: false,
+    })`rolldown -c rolldown.config.mjs --watch`
+    let stdout = ''
+    await new Promise<void>((resolve) => {
+      subprocess.stdout!.on('data', (data) => {
+        stdout += data
+        if (stdout.includes('build finished')) {
+          resolve()
+        }
+      })
+    })
+    subprocess.kill()
+    await subprocess
+    expect(stdout).toMatch(/^\[.+\] build started$/m)
+    expect(stdout).toMatch(/^\[.+\] build finished in \d+\.\d{2} ms$/m)
+  })
+
+  it('should print changed files and errors of rebuilds', async () => {
+    const cwd = cliFixturesDir('watch')
+    const file = nodePath.join(cwd, 'index.js')
+    const code = fs.readFileSync(file, 'utf-8')
+    const subprocess = $({
+      cwd,
+      reject: false,
+    })`rolldown -c rolldown.config.mjs --watch`
+    let stdout = ''
+    // Resolves once `line` is printed after what's printed so far
+    const printed = (line: string) => {
+      const from = stdout.length
+      return new Promise<void>((resolve) => {
+        const onData = () => {
+          if (stdout.slice(from).includes(line)) {
+            subprocess.stdout!.off('data', onData)
+            resolve()
+          }
+        }
+        subprocess.stdout!.on('data', onData)
+      })
+    }
+    subprocess.stdout!.on('data', (data) => {
+      stdout += data
+    })
+    try {
+      await printed('build finished')
+      const failed = printed('error:')
+      fs.writeFileSync(file, 'export default {')
+      await failed
+      const rebuilt = printed('build finished')
+      fs.writeFileSync(file, code)
+      await rebuilt
+    } finally {
+      fs.writeFileSync(file, code)
+      subprocess.kill()
+      await subprocess
+    }
+    expect(stdout).toMatch(/^\[.+\] changed: index\.js$/m)
+    expect(stdout).toMatch(/^\[.+\] error: .+/m)
+    expect(stdout.match(/build finished/g)).toHaveLength(2)
+  })
+})
@@ -0,0 +1 @@
+export const input: string = './index.js'
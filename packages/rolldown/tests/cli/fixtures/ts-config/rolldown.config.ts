@@ -0,0 +1,10 @@
+import nodePath from 'node:path'
+import { defineConfig } from 'rolldown'
+import { input } from './input'
+
+export default defineConfig({
+  input,
+  output: {
+    dir: nodePath.join('dist', 'ts-config'),
+  },
+})
@@ -0,0 +1,33 @@
+import { defineTest } from '@tests'
+import { replacePlugin } from 'rolldown'
+import { expect, vi } from 'vitest'
+
+const afterFn = vi.fn()
+
+export default defineTest({
+  config: {
+    plugins: [
+      {
+        name: 'before',
+        transform(code) {
+          return { code: code.replace('__BEFORE__', '__REPLACE_ME__') }
+        },
+      },
+      replacePlugin({ __REPLACE_ME__: '"replaced"' }),
+      {
+        name: 'after',
+        transform(code) {
+          afterFn()
+          // The builtin plugin runs between Js plugins around it
+          expect(code).toBe('console.log("replaced", __AFTER__)\n')
+          return { code: code.replace('__AFTER__', '__REPLACE_ME__') }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(afterFn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain('__REPLACE_ME__')
+    expect(output.output[0].code).not.toContain('__AFTER__')
+  },
+})
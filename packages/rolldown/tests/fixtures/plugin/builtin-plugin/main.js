@@ -0,0 +1 @@
+console.log(__BEFORE__, __AFTER__)
@@ -0,0 +1,34 @@
+import { defineTest } from '@tests'
+import { expect, vi } from 'vitest'
+
+const resolveFn = vi.fn()
+
+export default defineTest({
+  config: {
+    // Fewer permits than modules calling `this.resolve` at the same time
+    maxParallelJsHooks: 2,
+    plugins: [
+      {
+        name: 'test-plugin-context',
+        async resolveId(source, importer) {
+          if (source.startsWith('virtual:')) {
+            const ret = await this.resolve(`\0${source}`, importer)
+            resolveFn()
+            return ret
+          }
+          if (source.startsWith('\0virtual:')) {
+            return source
+          }
+        },
+        load(id) {
+          if (id.startsWith('\0virtual:')) {
+            return `export default '${id.slice('\0virtual:'.length)}'`
+          }
+        },
+      },
+    ],
+  },
+  afterTest: () => {
+    expect(resolveFn).toHaveBeenCalledTimes(6)
+  },
+})
@@ -0,0 +1,8 @@
+import a from 'virtual:a'
+import b from 'virtual:b'
+import c from 'virtual:c'
+import d from 'virtual:d'
+import e from 'virtual:e'
+import f from 'virtual:f'
+
+console.log(a, b, c, d, e, f)
@@ -0,0 +1,5 @@
+import { foo } from './foo.js'
+export * from './bar.js'
+
+console.log(foo)
+import('./lazy.js')
@@ -0,0 +1,33 @@
+import { expect, test } from 'vitest'
+import path from 'node:path'
+import { rolldown } from 'rolldown'
+
+test('expose the module graph of the last build', async () => {
+  const cwd = import.meta.dirname
+  const build = await rolldown({ input: './main.js', cwd })
+  expect(build.getModuleIds()).toEqual([])
+
+  await build.generate()
+  expect(
+    build
+      .getModuleIds()
+      .map((id) => path.relative(cwd, id))
+      .sort(),
+  ).toEqual(['bar.js', 'foo.js', 'lazy.js', 'main.js'])
+
+  const main = build.getModuleInfo('main.js')!
+  expect(main.isEntry).toBe(true)
+  expect(main.exports).toEqual(['bar'])
+  expect(main.importedIds).toEqual([
+    path.join(cwd, 'foo.js'),
+    path.join(cwd, 'bar.js'),
+  ])
+  expect(main.dynamicallyImportedIds).toEqual([path.join(cwd, 'lazy.js')])
+
+  const lazy = build.getModuleInfo(path.join(cwd, 'lazy.js'))!
+  expect(lazy.isEntry).toBe(false)
+  expect(lazy.dynamicImporters).toEqual([path.join(cwd, 'main.js')])
+  expect(lazy.exports).toEqual(['default'])
+
+  expect(build.getModuleInfo('missing.js')).toBeNull()
+})
@@ -58,3 +58,29 @@ describe('Plugin buildEnd hook', async () => {
     expect(buildEndFn).toHaveBeenCalledTimes(1)
   })
 })
+
+test('Plugin load hook only fails modules it throws for', async () => {
+  const build = await rolldown({
+    input: ['virtual:a', 'virtual:b', 'virtual:c'],
+    cwd: import.meta.dirname,
+    plugins: [
+      {
+        resolveId(id) {
+          if (id.startsWith('virtual:')) {
+            return id
+          }
+        },
+        load(id) {
+          if (id === 'virtual:b') {
+            throw new Error(`cannot load ${id}`)
+          }
+          return `export default '${id}'`
+        },
+      },
+    ],
+  })
+  // Modules are loaded in a single batch, but the error belongs to `virtual:b` only
+  const error = await build.write({}).catch((error) => error)
+  expect(error).toBeInstanceOf(Error)
+  expect(error.message.match(/cannot load virtual:b/g)).toHaveLength(1)
+})